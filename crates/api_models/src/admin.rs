@@ -1,6 +1,9 @@
-use common_utils::pii;
+use std::collections::HashMap;
+
+use common_utils::{custom_serde, pii};
 use masking::{Secret, StrongSecret};
 use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
 use super::payments::AddressDetails;
@@ -31,10 +34,18 @@ pub struct CreateMerchantAccount {
     /// Webhook related details
     pub webhook_details: Option<WebhookDetails>,
 
+    /// Static headers to be sent along with every webhook to the merchant, such as an
+    /// authorization header expected by the webhook endpoint. The headers are stored encrypted.
+    #[schema(value_type = Option<Object>, example = json!({"Authorization": "Bearer abc123"}))]
+    pub outgoing_webhook_custom_headers: Option<HashMap<String, Secret<String>>>,
+
     /// The routing algorithm to be used for routing payments to desired connectors
     #[schema(value_type = Option<Object>,example = json!({"type": "single", "data": "stripe"}))]
     pub routing_algorithm: Option<serde_json::Value>,
 
+    /// The surcharges levied on payments depending on their payment method
+    pub surcharge_config: Option<SurchargeConfig>,
+
     /// A boolean value to indicate if the merchant is a sub-merchant under a master or a parent merchant. By default, its value is false.
     #[schema(default = false, example = false)]
     pub sub_merchants_enabled: Option<bool>,
@@ -77,7 +88,8 @@ pub struct MerchantAccountResponse {
     #[schema(example = "NewAge Retailer")]
     pub merchant_name: Option<String>,
 
-    /// API key that will be used for server side API access
+    /// API key that will be used for server side API access. It is returned only when the
+    /// merchant account is created, further keys can be managed through the API keys endpoints.
     #[schema(value_type = Option<String>, example = "Ah2354543543523")]
     pub api_key: Option<StrongSecret<String>>,
 
@@ -109,6 +121,10 @@ pub struct MerchantAccountResponse {
     #[schema(value_type = Option<RoutingAlgorithm>, max_length = 255, example = "custom")]
     pub routing_algorithm: Option<serde_json::Value>,
 
+    /// The surcharges levied on payments depending on their payment method
+    #[schema(value_type = Option<SurchargeConfig>)]
+    pub surcharge_config: Option<serde_json::Value>,
+
     /// A boolean value to indicate if the merchant is a sub-merchant under a master or a parent merchant. By default, its value is false.
     #[schema(default = false, example = false)]
     pub sub_merchants_enabled: Option<bool>,
@@ -128,6 +144,10 @@ pub struct MerchantAccountResponse {
     /// An identifier for the vault used to store payment method information.
     #[schema(example = "locker_abc123")]
     pub locker_id: Option<String>,
+
+    /// The secret used to sign the webhooks sent to the merchant
+    #[schema(value_type = Option<String>, example = "whsec_Ah2354543543523")]
+    pub webhook_signing_secret: Option<StrongSecret<String>>,
 }
 
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
@@ -176,6 +196,53 @@ pub struct MerchantDetails {
 #[serde(tag = "type", content = "data", rename_all = "snake_case")]
 pub enum RoutingAlgorithm {
     Single(api_enums::RoutableConnectors),
+    /// Connectors in order of preference. The payment is processed by the first connector, and
+    /// retried with the next one if the previous one failed with a retryable error.
+    Priority(Vec<api_enums::RoutableConnectors>),
+    /// Connectors along with the percentage of payments to be routed to each of them. The
+    /// percentages must add up to 100.
+    VolumeSplit(Vec<ConnectorVolumeSplit>),
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorVolumeSplit {
+    pub connector: api_enums::RoutableConnectors,
+    /// Percentage of payments to be routed to the connector
+    pub split: u8,
+}
+
+/// The surcharges levied by the merchant on payments, in addition to their amount.
+#[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct SurchargeConfig {
+    /// Rules deciding the surcharge of a payment. A rule specific to the card network of the
+    /// payment takes precedence over a rule for its payment method as a whole.
+    pub rules: Vec<SurchargeRule>,
+    /// Percentage of the surcharge charged as tax on the surcharge
+    #[schema(example = 18.0)]
+    pub tax_on_surcharge_percentage: Option<f64>,
+}
+
+#[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct SurchargeRule {
+    /// The payment method which the surcharge is levied on
+    #[schema(value_type = PaymentMethodType, example = "card")]
+    pub payment_method: api_enums::PaymentMethodType,
+    /// The card network which the surcharge is levied on, if it is specific to a card network
+    #[schema(value_type = Option<CardNetwork>, example = "american_express")]
+    pub card_network: Option<api_enums::CardNetwork>,
+    pub surcharge: Surcharge,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, ToSchema, Serialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum Surcharge {
+    /// A fixed amount in the lowest denomination of the currency of the payment
+    Fixed(i64),
+    /// A percentage of the amount of the payment
+    Percentage(f64),
 }
 
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
@@ -216,6 +283,54 @@ pub struct DeleteResponse {
     pub deleted: bool,
 }
 
+#[derive(Debug, Serialize)]
+pub struct WebhookSigningSecretResponse {
+    pub merchant_id: String,
+    /// The new secret used to sign the webhooks sent to the merchant
+    pub webhook_signing_secret: StrongSecret<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MerchantWebhookConfigRequest {
+    /// Whether webhooks are sent to the merchant, defaults to true
+    pub enabled: Option<bool>,
+    /// The URL to which webhooks are sent, overriding the URL in the webhook details of the
+    /// merchant account
+    pub webhook_url: Option<String>,
+    /// The event types for which webhooks are sent, webhooks are sent for all event types if this
+    /// is not set
+    pub enabled_events: Option<Vec<api_enums::EventType>>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct MerchantWebhookConfigResponse {
+    pub merchant_id: String,
+    pub enabled: bool,
+    pub webhook_url: Option<String>,
+    pub enabled_events: Option<Vec<api_enums::EventType>>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct PaymentAuditLogResponse {
+    pub payment_id: String,
+    pub merchant_id: String,
+    /// The updates of the payment, oldest first
+    pub entries: Vec<PaymentAuditLogEntry>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct PaymentAuditLogEntry {
+    /// The status of the payment before the update
+    pub previous_status: api_enums::IntentStatus,
+    /// The status of the payment after the update
+    pub status: api_enums::IntentStatus,
+    /// The actor on whose behalf the payment was updated
+    pub actor: api_enums::AuditActor,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
 #[derive(Default, Debug, Deserialize, Serialize)]
 pub struct MerchantId {
     pub merchant_id: String,
@@ -283,6 +398,12 @@ pub struct PaymentConnectorCreate {
     /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
     #[schema(value_type = Option<Object>,max_length = 255,example = json!({ "city": "NY", "unit": "245" }))]
     pub metadata: Option<serde_json::Value>,
+    /// The country of the business for which the connector account is used. Along with the business label, it distinguishes multiple accounts of the same connector, and is matched against the business details of a payment.
+    #[schema(max_length = 2, example = "US")]
+    pub business_country: Option<String>,
+    /// The label of the business for which the connector account is used, such as a region or a brand.
+    #[schema(max_length = 64, example = "food")]
+    pub business_label: Option<String>,
 }
 /// Details of all the payment methods enabled for the connector for the given merchant account
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -308,11 +429,11 @@ pub struct PaymentMethods {
     pub accepted_countries: Option<Vec<String>>,
     /// Minimum amount supported by the processor. To be represented in the lowest denomination of the target currency (For example, for USD it should be in cents)
     #[schema(example = 1)]
-    pub minimum_amount: Option<i32>,
+    pub minimum_amount: Option<i64>,
     /// Maximum amount supported by the processor. To be represented in the lowest denomination of
     /// the target currency (For example, for USD it should be in cents)
     #[schema(example = 1313)]
-    pub maximum_amount: Option<i32>,
+    pub maximum_amount: Option<i64>,
     /// Boolean to enable recurring payments / mandates. Default is true.
     #[schema(default = true, example = false)]
     pub recurring_enabled: bool,
@@ -0,0 +1,90 @@
+use common_utils::custom_serde;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums;
+
+/// The time range over which the payment metrics are computed.
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentAnalyticsRequest {
+    /// The start of the time range, inclusive
+    #[serde(with = "custom_serde::iso8601")]
+    pub start_time: PrimitiveDateTime,
+    /// The end of the time range, exclusive. Defaults to the current time
+    #[serde(default, with = "custom_serde::iso8601::option")]
+    pub end_time: Option<PrimitiveDateTime>,
+}
+
+/// Metrics of the payment attempts made in a time range.
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct PaymentAnalyticsResponse {
+    #[serde(with = "custom_serde::iso8601")]
+    pub start_time: PrimitiveDateTime,
+    #[serde(with = "custom_serde::iso8601")]
+    pub end_time: PrimitiveDateTime,
+    #[serde(flatten)]
+    pub overall: PaymentMetrics,
+    /// The most frequent reasons for which payment attempts failed, most frequent first
+    pub decline_reasons: Vec<DeclineReasonMetric>,
+    pub by_connector: Vec<ConnectorMetrics>,
+    pub by_currency: Vec<CurrencyMetrics>,
+    pub by_payment_method: Vec<PaymentMethodMetrics>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, ToSchema)]
+pub struct PaymentMetrics {
+    /// The number of payment attempts made
+    pub total_attempts: i64,
+    /// The number of payment attempts which were authorized, including the ones which were
+    /// captured afterwards
+    pub authorized_attempts: i64,
+    /// The number of payment attempts which were charged
+    pub successful_attempts: i64,
+    /// The percentage of payment attempts which were authorized, absent if no attempts were made
+    pub authorization_rate: Option<f64>,
+    /// The percentage of payment attempts which were charged, absent if no attempts were made
+    pub success_rate: Option<f64>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, ToSchema)]
+pub struct DeclineReasonMetric {
+    /// The error code returned by the connector
+    pub error_code: Option<String>,
+    /// The error message returned by the connector
+    pub error_message: Option<String>,
+    /// The number of payment attempts which failed with this reason
+    pub count: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct ConnectorMetrics {
+    /// The connector through which the payment attempts were made, absent for the attempts which
+    /// were not routed to a connector
+    pub connector: Option<String>,
+    #[serde(flatten)]
+    pub metrics: PaymentMetrics,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct CurrencyMetrics {
+    pub currency: Option<enums::Currency>,
+    #[serde(flatten)]
+    pub metrics: PaymentMetrics,
+    /// The total amount of the payment attempts which were charged, in the lowest denomination of
+    /// the currency
+    pub successful_amount: i64,
+    /// The total amount of the fees charged by connectors for disputes in the time range, net of
+    /// the dispute fees refunded, in the lowest denomination of the currency
+    pub dispute_fee_amount: i64,
+    /// The amount charged net of the dispute fees, in the lowest denomination of the currency
+    pub net_amount: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct PaymentMethodMetrics {
+    pub payment_method: Option<enums::PaymentMethodType>,
+    #[serde(flatten)]
+    pub metrics: PaymentMetrics,
+}
@@ -0,0 +1,78 @@
+use common_utils::custom_serde;
+use masking::StrongSecret;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CreateApiKeyRequest {
+    /// A unique name for the API key to help you identify it
+    #[schema(max_length = 64, example = "Sandbox integration key")]
+    pub name: String,
+    /// A description to provide more context about the API key
+    #[schema(
+        max_length = 256,
+        example = "Key used by our developers to integrate with the sandbox environment"
+    )]
+    pub description: Option<String>,
+    /// The time after which the API key can no longer be used, the key never expires if not
+    /// specified
+    #[serde(default, with = "custom_serde::iso8601::option")]
+    pub expires_at: Option<PrimitiveDateTime>,
+}
+
+/// The response of an API key creation, which is the only response containing the plaintext API
+/// key. Only the hash of the key is stored, so the key cannot be retrieved again.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CreateApiKeyResponse {
+    /// The identifier for the API key
+    #[schema(max_length = 64, example = "key_5hEEqkgJUyuxgSKGArHA4mWSnX")]
+    pub key_id: String,
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+    pub name: String,
+    pub description: Option<String>,
+    /// The plaintext API key, which is to be passed in the `api-key` header of requests
+    #[schema(value_type = String, example = "snd_0b5d1aa2ce1a4d4b8ac0b23f0e4a0f1c")]
+    pub api_key: StrongSecret<String>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "custom_serde::iso8601::option")]
+    pub expires_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RetrieveApiKeyResponse {
+    /// The identifier for the API key
+    #[schema(max_length = 64, example = "key_5hEEqkgJUyuxgSKGArHA4mWSnX")]
+    pub key_id: String,
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+    pub name: String,
+    pub description: Option<String>,
+    /// The first few characters of the API key, to help you identify the key
+    #[schema(example = "snd_0b5d1aa2")]
+    pub prefix: String,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "custom_serde::iso8601::option")]
+    pub expires_at: Option<PrimitiveDateTime>,
+    /// The time at which the API key was last used to authenticate a request
+    #[serde(with = "custom_serde::iso8601::option")]
+    pub last_used: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RevokeApiKeyResponse {
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+    /// The identifier for the API key
+    #[schema(max_length = 64, example = "key_5hEEqkgJUyuxgSKGArHA4mWSnX")]
+    pub key_id: String,
+    /// Indicates whether the API key was revoked
+    pub revoked: bool,
+}
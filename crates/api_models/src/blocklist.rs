@@ -0,0 +1,55 @@
+use common_utils::custom_serde;
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BlocklistAddRequest {
+    /// The kind of data to block
+    #[schema(value_type = BlocklistKind, example = "email")]
+    pub kind: api_enums::BlocklistKind,
+    /// The data to block. For card fingerprints, this is the card number, of which only the
+    /// fingerprint is stored.
+    #[schema(value_type = String, example = "fraudster@example.com")]
+    pub data: Secret<String>,
+    /// The time after which the entry no longer blocks payments, entries without an expiry block
+    /// payments until they are deleted
+    #[serde(default, with = "custom_serde::iso8601::option")]
+    pub expires_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct BlocklistResponse {
+    /// The identifier for the blocklist entry
+    #[schema(example = "bl_mbabizu24mvu3mela5njyhpit4")]
+    pub entry_id: String,
+    #[schema(value_type = BlocklistKind, example = "email")]
+    pub kind: api_enums::BlocklistKind,
+    /// The blocked data. For card fingerprints, this is the fingerprint of the card number.
+    #[schema(example = "fraudster@example.com")]
+    pub data: String,
+    #[serde(with = "custom_serde::iso8601::option")]
+    pub expires_at: Option<PrimitiveDateTime>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BlocklistListConstraints {
+    /// Only list the entries blocking this kind of data
+    pub kind: Option<api_enums::BlocklistKind>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct BlocklistDeleteResponse {
+    /// The identifier for the blocklist entry
+    #[schema(example = "bl_mbabizu24mvu3mela5njyhpit4")]
+    pub entry_id: String,
+    /// Indicates whether the blocklist entry was deleted
+    pub deleted: bool,
+}
@@ -44,6 +44,17 @@ pub struct CustomerDeleteResponse {
     pub payment_methods_deleted: bool,
 }
 
+#[derive(Default, Debug, Deserialize, Serialize)]
+pub struct CustomerRedactResponse {
+    pub customer_id: String,
+    pub customer_redacted: bool,
+    /// The number of saved payment methods of the customer which were deleted
+    pub payment_methods_deleted: i32,
+    /// The number of payments of the customer from which the references to the customer were
+    /// removed
+    pub payment_intents_anonymized: i32,
+}
+
 pub fn generate_customer_id() -> String {
     common_utils::generate_id(consts::ID_LENGTH, "cus")
 }
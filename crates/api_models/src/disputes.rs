@@ -1 +1,94 @@
+use common_utils::custom_serde;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
 
+use crate::enums;
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+pub struct DisputeResponse {
+    /// The identifier for the dispute
+    pub dispute_id: String,
+    /// The identifier for the payment which is being disputed
+    pub payment_id: String,
+    /// The identifier for the payment attempt which is being disputed
+    pub attempt_id: String,
+    /// The disputed amount, in the lowest denomination of the currency
+    pub amount: i64,
+    pub currency: String,
+    pub dispute_status: enums::DisputeStatus,
+    /// The connector through which the disputed payment was processed
+    pub connector: String,
+    /// The status of the dispute as sent by the connector
+    pub connector_status: String,
+    /// The identifier for the dispute at the connector
+    pub connector_dispute_id: String,
+    /// The reason for the dispute as sent by the connector
+    pub connector_reason: Option<String>,
+    /// The reason code for the dispute as sent by the connector
+    pub connector_reason_code: Option<String>,
+    /// The time by which the dispute has to be challenged
+    #[serde(with = "custom_serde::iso8601::option")]
+    pub challenge_required_by: Option<PrimitiveDateTime>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+    /// The status of the challenge of the dispute, once evidence has been submitted for it
+    pub challenge_status: Option<enums::DisputeChallengeStatus>,
+}
+
+/// The evidence for challenging a dispute. Files are referenced by the identifiers they were
+/// uploaded with to the connector, and each kind of evidence can be described in text as well.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubmitEvidenceRequest {
+    /// File proving that the goods or services were delivered to the customer
+    pub proof_of_delivery_file: Option<String>,
+    /// Description of the delivery of the goods or services
+    pub proof_of_delivery_text: Option<String>,
+    /// File containing the invoice or receipt of the payment
+    pub receipt_file: Option<String>,
+    pub receipt_text: Option<String>,
+    /// File containing the communication with the customer about the payment
+    pub customer_communication_file: Option<String>,
+    pub customer_communication_text: Option<String>,
+    /// File containing the refund or cancellation policy shown to the customer
+    pub refund_policy_file: Option<String>,
+    pub refund_policy_text: Option<String>,
+    /// File containing the agreement of the customer to recurring payments
+    pub recurring_transaction_agreement_file: Option<String>,
+    /// File containing any other evidence supporting the challenge
+    pub additional_evidence_file: Option<String>,
+    pub additional_evidence_text: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq, Deserialize, Serialize)]
+pub struct DisputeListConstraints {
+    pub limit: Option<i64>,
+    pub dispute_status: Option<enums::DisputeStatus>,
+    pub connector: Option<String>,
+    #[serde(default, with = "custom_serde::iso8601::option")]
+    pub created: Option<PrimitiveDateTime>,
+    #[serde(default, rename = "created.lt", with = "custom_serde::iso8601::option")]
+    pub created_lt: Option<PrimitiveDateTime>,
+    #[serde(default, rename = "created.gt", with = "custom_serde::iso8601::option")]
+    pub created_gt: Option<PrimitiveDateTime>,
+    #[serde(
+        default,
+        rename = "created.lte",
+        with = "custom_serde::iso8601::option"
+    )]
+    pub created_lte: Option<PrimitiveDateTime>,
+    #[serde(
+        default,
+        rename = "created.gte",
+        with = "custom_serde::iso8601::option"
+    )]
+    pub created_gte: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct DisputeListResponse {
+    pub data: Vec<DisputeResponse>,
+}
@@ -121,6 +121,10 @@ pub enum ConnectorType {
     BankingEntities,
     /// All types of non-banking financial institutions including Insurance, Credit / Lending etc
     NonBankingFinance,
+    /// 3DS servers which authenticate cardholders independently of the payment processor
+    AuthenticationProcessor,
+    /// Fraud and risk management services which screen payments before or after authorization
+    FrmProcessor,
 }
 
 #[allow(clippy::upper_case_acronyms)]
@@ -262,6 +266,62 @@ pub enum Currency {
 #[strum(serialize_all = "snake_case")]
 pub enum EventType {
     PaymentSucceeded,
+    PaymentFailed,
+    RefundSucceeded,
+    RefundFailed,
+    DisputeOpened,
+    DisputeChallenged,
+    DisputeWon,
+    DisputeLost,
+    MandateActive,
+    MandateRevoked,
+    PayoutSucceeded,
+    PayoutFailed,
+    PayoutCancelled,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum EventClass {
+    Payments,
+    Refunds,
+    Disputes,
+    Mandates,
+    Payouts,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum EventObjectType {
+    PaymentDetails,
+    RefundDetails,
+    DisputeDetails,
+    MandateDetails,
+    PayoutDetails,
 }
 
 #[derive(
@@ -290,6 +350,7 @@ pub enum IntentStatus {
     #[default]
     RequiresConfirmation,
     RequiresCapture,
+    PartiallyCaptured,
 }
 
 #[derive(
@@ -419,6 +480,10 @@ pub enum WalletIssuer {
     GooglePay,
     ApplePay,
     Paypal,
+    /// PayPal, with the customer being redirected to PayPal to approve the payment
+    #[serde(rename = "paypal_redirect")]
+    #[strum(serialize = "paypal_redirect")]
+    PaypalRedirect,
 }
 
 #[derive(
@@ -516,12 +581,18 @@ pub enum Connector {
     Cybersource,
     #[default]
     Dummy,
+    #[cfg(feature = "dummy_connector")]
+    DummyConnector,
     Fiserv,
     Globalpay,
     Klarna,
+    Netcetera,
+    Paypal,
     Payu,
     Rapyd,
+    Razorpay,
     Shift4,
+    Signifyd,
     Stripe,
     Worldline,
     Worldpay,
@@ -548,11 +619,15 @@ pub enum RoutableConnectors {
     Braintree,
     Checkout,
     Cybersource,
+    #[cfg(feature = "dummy_connector")]
+    DummyConnector,
     Fiserv,
     Globalpay,
     Klarna,
+    Paypal,
     Payu,
     Rapyd,
+    Razorpay,
     Shift4,
     Stripe,
     Worldline,
@@ -568,6 +643,41 @@ pub enum SupportedWallets {
     Gpay,
 }
 
+/// Result of the address verification (AVS) check performed by the card issuer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AvsResult {
+    /// Both the street address and the postal code matched.
+    Match,
+    /// Only the street address matched.
+    AddressMatch,
+    /// Only the postal code matched.
+    PostalCodeMatch,
+    /// Neither the street address nor the postal code matched.
+    NoMatch,
+    /// The check could not be performed, for example because the issuer does not support it or
+    /// was unavailable.
+    Unavailable,
+    /// The check resulted in an error and may be retried.
+    Error,
+}
+
+/// Result of the card security code (CVV) check performed by the card issuer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CvvResult {
+    /// The security code matched.
+    Match,
+    /// The security code did not match.
+    NoMatch,
+    /// The security code was not checked.
+    NotProcessed,
+    /// The security code should have been present on the card, but was not provided.
+    NotProvided,
+    /// The issuer does not support the check.
+    Unavailable,
+}
+
 impl From<AttemptStatus> for IntentStatus {
     fn from(s: AttemptStatus) -> Self {
         match s {
@@ -598,3 +708,405 @@ impl From<AttemptStatus> for IntentStatus {
         }
     }
 }
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DisputeStatus {
+    #[default]
+    DisputeOpened,
+    DisputeChallenged,
+    DisputeWon,
+    DisputeLost,
+}
+
+/// The status of the challenge of a dispute, once evidence has been submitted for it
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DisputeChallengeStatus {
+    #[default]
+    Submitted,
+    UnderReview,
+    Won,
+    Lost,
+}
+
+/// The status of a payout
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutStatus {
+    /// The payout has been created, and is yet to be submitted to the connector
+    #[default]
+    RequiresConfirmation,
+    /// The payout has been submitted to the connector, and is waiting to be fulfilled
+    RequiresFulfillment,
+    /// The payout has been fulfilled, and is being processed by the connector
+    Pending,
+    Success,
+    Failed,
+    Cancelled,
+}
+
+/// The kind of account that a payout is made to
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutType {
+    #[default]
+    Bank,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CaptureStatus {
+    #[default]
+    Started,
+    Charged,
+    Pending,
+    Failed,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentLinkStatus {
+    /// The link can be used for paying
+    #[default]
+    Active,
+    /// The payment has been made through the link
+    Completed,
+    /// The link has expired or the payment has been cancelled
+    Expired,
+}
+
+/// The actor on whose behalf a payment was updated
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditActor {
+    /// An API request, made by the merchant or their customer
+    #[default]
+    Api,
+    /// A webhook received from the connector
+    Webhook,
+    /// A task run by the scheduler, such as the synchronization of a payment with the connector
+    Scheduler,
+}
+
+/// The records which are included in an export
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ExportObject {
+    #[default]
+    Payments,
+    Refunds,
+    Disputes,
+    /// The rows of a refund batch, which are exported through the refund batch report
+    RefundBatch,
+}
+
+/// The format of the file generated by an export
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ExportFormat {
+    #[default]
+    Csv,
+    Json,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ExportStatus {
+    /// The file is being generated
+    #[default]
+    Pending,
+    /// The file has been generated and can be downloaded
+    Succeeded,
+    /// The file could not be generated
+    Failed,
+}
+
+/// The decision of a fraud and risk management service on a payment.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FrmDecision {
+    /// The payment is allowed to proceed
+    Approve,
+    /// The payment is allowed to proceed, but is not captured until it has been reviewed
+    Review,
+    /// The payment is blocked
+    Reject,
+}
+
+/// The kind of data which is blocked by a blocklist entry
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum BlocklistKind {
+    /// The fingerprint of a card number
+    CardFingerprint,
+    /// The email address of a customer
+    Email,
+    /// The IP address from which a payment is made
+    IpAddress,
+}
+
+/// The network of a card, which surcharges can be specific to
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case", ascii_case_insensitive)]
+pub enum CardNetwork {
+    Visa,
+    Mastercard,
+    AmericanExpress,
+    Discover,
+    Jcb,
+    DinersClub,
+    UnionPay,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReconReportStatus {
+    /// The entries of the report are being reconciled
+    #[default]
+    Pending,
+    /// All entries of the report have been reconciled
+    Completed,
+    /// The report could not be reconciled
+    Failed,
+}
+
+/// The kind of transaction settled by an entry of a settlement report
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case", ascii_case_insensitive)]
+pub enum ReconEntryType {
+    Payment,
+    Refund,
+}
+
+/// The reason an entry of a settlement report does not match the payment or refund it settles
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReconMismatchReason {
+    /// No payment or refund with the connector transaction id was found
+    NotFound,
+    /// The settled amount differs from the amount of the payment or refund
+    AmountMismatch,
+    /// The settlement currency differs from the currency of the payment or refund
+    CurrencyMismatch,
+    /// The payment or refund was settled although it has not succeeded
+    StatusMismatch,
+}
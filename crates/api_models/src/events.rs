@@ -0,0 +1,73 @@
+use common_utils::custom_serde;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::enums;
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct EventResponse {
+    /// The identifier for the event
+    pub event_id: String,
+    pub event_type: enums::EventType,
+    pub event_class: enums::EventClass,
+    /// Whether the webhook for this event was acknowledged by the merchant
+    pub is_webhook_notified: bool,
+    /// The identifier for the payment to which the event's object belongs
+    pub intent_reference_id: Option<String>,
+    /// The identifier for the object the event is about
+    pub primary_object_id: String,
+    pub primary_object_type: enums::EventObjectType,
+    /// The webhook body which was sent to the merchant for this event
+    pub content: Option<serde_json::Value>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq, Deserialize, Serialize)]
+pub struct EventListConstraints {
+    pub limit: Option<i64>,
+    pub event_type: Option<enums::EventType>,
+    /// Filter events by whether their webhook was delivered to the merchant
+    pub is_webhook_notified: Option<bool>,
+    #[serde(default, rename = "created.lt", with = "custom_serde::iso8601::option")]
+    pub created_lt: Option<PrimitiveDateTime>,
+    #[serde(default, rename = "created.gt", with = "custom_serde::iso8601::option")]
+    pub created_gt: Option<PrimitiveDateTime>,
+    #[serde(
+        default,
+        rename = "created.lte",
+        with = "custom_serde::iso8601::option"
+    )]
+    pub created_lte: Option<PrimitiveDateTime>,
+    #[serde(
+        default,
+        rename = "created.gte",
+        with = "custom_serde::iso8601::option"
+    )]
+    pub created_gte: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct EventListResponse {
+    pub data: Vec<EventResponse>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct WebhookDeliveryAttemptResponse {
+    /// The number of the delivery attempt, starting from 1
+    pub attempt_number: i32,
+    /// The HTTP status code returned by the merchant's webhook endpoint, if it could be reached
+    pub response_code: Option<i32>,
+    /// Time taken by the delivery attempt, in milliseconds
+    pub latency_ms: i64,
+    /// The reason for the failure of the delivery attempt
+    pub error_message: Option<String>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct WebhookDeliveryAttemptListResponse {
+    pub event_id: String,
+    pub data: Vec<WebhookDeliveryAttemptResponse>,
+}
@@ -0,0 +1,70 @@
+use common_utils::custom_serde;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ExportCreateRequest {
+    /// The records to export, which are selected by the time they were created
+    #[schema(value_type = ExportObject, example = "payments")]
+    pub object: api_enums::ExportObject,
+    /// The format of the generated file, defaults to CSV
+    #[schema(value_type = Option<ExportFormat>, example = "csv")]
+    #[serde(default)]
+    pub format: api_enums::ExportFormat,
+    /// The start of the time range, inclusive
+    #[serde(with = "custom_serde::iso8601")]
+    pub start_time: PrimitiveDateTime,
+    /// The end of the time range, exclusive
+    #[serde(with = "custom_serde::iso8601")]
+    pub end_time: PrimitiveDateTime,
+    /// Whether to pseudonymize the customer identifiers and card data, and to leave out the free
+    /// form fields, so that the file can be shared with third parties
+    #[serde(default)]
+    pub anonymize: bool,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, ToSchema)]
+pub struct ExportResponse {
+    /// The identifier for the export
+    #[schema(example = "exp_mbabizu24mvu3mela5njyhpit4")]
+    pub export_id: String,
+    #[schema(value_type = ExportObject, example = "payments")]
+    pub object: api_enums::ExportObject,
+    #[schema(value_type = ExportFormat, example = "csv")]
+    pub format: api_enums::ExportFormat,
+    #[serde(with = "custom_serde::iso8601")]
+    pub start_time: PrimitiveDateTime,
+    #[serde(with = "custom_serde::iso8601")]
+    pub end_time: PrimitiveDateTime,
+    #[schema(value_type = ExportStatus, example = "succeeded")]
+    pub status: api_enums::ExportStatus,
+    /// The number of records in the generated file, present once the export has succeeded
+    pub row_count: Option<i32>,
+    /// The signed URL from which the generated file can be downloaded without authentication,
+    /// present once the export has succeeded. A new URL is issued on every retrieval.
+    pub download_url: Option<String>,
+    /// The time after which the download URL can no longer be used
+    #[serde(with = "custom_serde::iso8601::option")]
+    pub download_url_expires_at: Option<PrimitiveDateTime>,
+    /// The reason the export failed
+    pub error_message: Option<String>,
+    /// The refund batch whose rows are exported, present for refund batch exports
+    pub batch_id: Option<String>,
+    /// Whether the customer identifiers and card data are pseudonymized in the generated file
+    pub anonymized: bool,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+/// The signature of an export download URL.
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize)]
+pub struct ExportDownloadParams {
+    /// Unix timestamp after which the URL can no longer be used
+    pub expires_at: i64,
+    /// Hex encoded HMAC-SHA256 of the export and the expiry
+    pub signature: String,
+}
@@ -0,0 +1,38 @@
+use serde::Serialize;
+
+/// The health of the services the router depends on.
+#[derive(Debug, Clone, Serialize)]
+pub struct RouterHealthCheckResponse {
+    pub master_database: ComponentHealth,
+    pub replica_database: ComponentHealth,
+    pub redis: ComponentHealth,
+    /// The reachability of the canary connector, checked only by the readiness check and only if
+    /// a canary connector is configured
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub canary_connector: Option<ComponentHealth>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ComponentHealth {
+    pub healthy: bool,
+    /// The error encountered while checking the service, if it is unhealthy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// The number of milliseconds taken by the check, including the time waited for a connection
+    pub latency_ms: u64,
+    /// The state of the connection pool, absent if the service is not reached through a pool
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pool: Option<ConnectionPoolHealth>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ConnectionPoolHealth {
+    pub max_connections: u32,
+    pub open_connections: u32,
+    /// The number of open connections which are not in use, absent for Redis, whose connections
+    /// are shared by concurrent commands
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub idle_connections: Option<u32>,
+    /// Whether a request needing a connection would have to wait for one
+    pub exhausted: bool,
+}
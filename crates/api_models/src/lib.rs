@@ -1,14 +1,23 @@
 #![forbid(unsafe_code)]
 pub mod admin;
+pub mod analytics;
+pub mod api_keys;
 pub mod bank_accounts;
+pub mod blocklist;
 pub mod cards;
 pub mod customers;
 pub mod disputes;
 pub mod enums;
+pub mod events;
+pub mod exports;
 pub mod files;
+pub mod health_check;
 pub mod mandates;
+pub mod ops;
+pub mod payment_links;
 pub mod payment_methods;
 pub mod payments;
 pub mod payouts;
+pub mod recon;
 pub mod refunds;
 pub mod webhooks;
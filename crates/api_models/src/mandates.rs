@@ -14,7 +14,7 @@ pub struct MandateRevokedResponse {
     pub status: api_enums::MandateStatus,
 }
 
-#[derive(Default, Debug, Deserialize, Serialize)]
+#[derive(Clone, Default, Debug, Deserialize, Serialize)]
 pub struct MandateResponse {
     pub mandate_id: String,
     pub status: api_enums::MandateStatus,
@@ -24,7 +24,7 @@ pub struct MandateResponse {
     pub customer_acceptance: Option<payments::CustomerAcceptance>,
 }
 
-#[derive(Default, Debug, Deserialize, Serialize)]
+#[derive(Clone, Default, Debug, Deserialize, Serialize)]
 pub struct MandateCardDetails {
     pub last4_digits: Option<String>,
     pub card_exp_month: Option<Secret<String>>,
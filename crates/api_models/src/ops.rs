@@ -0,0 +1,114 @@
+use common_utils::custom_serde;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::enums;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProcessRequeueRequest {
+    /// Business status of the finished processes to be requeued, defaults to the processes which exhausted their retries
+    pub business_status: Option<String>,
+    /// Restricts the requeue to the processes of the given runner
+    pub runner: Option<String>,
+    /// Maximum number of processes to be requeued
+    pub limit: Option<i64>,
+    /// Only report the processes which would be requeued, without requeuing them
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentsResyncRequest {
+    /// Payment attempts which have not been updated for these many hours are synced with the connector
+    pub stuck_for_hours: i64,
+    /// Maximum number of payment attempts to be synced
+    pub limit: Option<i64>,
+    /// Only report the payment attempts which would be synced, without scheduling the sync
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EventsReplayRequest {
+    /// Merchant whose webhooks are to be resent
+    pub merchant_id: String,
+    /// Events created at or after this time are replayed
+    #[serde(with = "custom_serde::iso8601")]
+    pub start_time: PrimitiveDateTime,
+    /// Events created before this time are replayed
+    #[serde(with = "custom_serde::iso8601")]
+    pub end_time: PrimitiveDateTime,
+    /// Restricts the replay to the events of the given type
+    pub event_type: Option<enums::EventType>,
+    /// Also resend the webhooks which the merchant already received, by default only the undelivered ones are resent
+    #[serde(default)]
+    pub include_delivered: bool,
+    /// Maximum number of events to be replayed
+    pub limit: Option<i64>,
+    /// Only report the events which would be replayed, without resending their webhooks
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct OpsActionResponse {
+    /// Identifier of the batch which performs the action, absent for dry runs
+    pub batch_id: Option<String>,
+    /// Progress of the batch, absent for dry runs
+    pub status: Option<OpsBatchStatus>,
+    pub dry_run: bool,
+    /// Number of records matching the request
+    pub matched_count: usize,
+    /// Number of records on which the action was performed
+    pub processed_count: usize,
+    /// Number of records which were left untouched
+    pub skipped_count: usize,
+    /// Number of records on which the action failed
+    pub failed_count: usize,
+    pub items: Vec<OpsActionItem>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OpsActionItem {
+    pub id: String,
+    pub status: OpsActionStatus,
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OpsBatchStatus {
+    Pending,
+    Processing,
+    Completed,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OpsActionStatus {
+    /// The batch has not reached the record yet
+    Pending,
+    WouldProcess,
+    Processed,
+    Skipped,
+    Failed,
+}
+
+impl OpsActionResponse {
+    pub fn push(&mut self, id: String, status: OpsActionStatus, message: Option<String>) {
+        match status {
+            OpsActionStatus::Pending | OpsActionStatus::WouldProcess => {}
+            OpsActionStatus::Processed => self.processed_count += 1,
+            OpsActionStatus::Skipped => self.skipped_count += 1,
+            OpsActionStatus::Failed => self.failed_count += 1,
+        }
+        self.items.push(OpsActionItem {
+            id,
+            status,
+            message,
+        });
+    }
+}
@@ -0,0 +1,35 @@
+use common_utils::custom_serde;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentLinkCreateRequest {
+    /// The identifier for the payment which is to be paid through the link. The payment has to be
+    /// awaiting a payment method or a confirmation.
+    #[schema(max_length = 64, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: String,
+    /// The number of seconds after which the link expires, defaults to 900 seconds
+    #[schema(example = 900)]
+    pub expires_in: Option<u32>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+pub struct PaymentLinkResponse {
+    /// The identifier for the payment link
+    #[schema(example = "plink_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_link_id: String,
+    /// The identifier for the payment which is paid through the link
+    pub payment_id: String,
+    /// The URL of the hosted checkout page which can be shared with the customer
+    pub link: String,
+    #[schema(value_type = PaymentLinkStatus, example = "active")]
+    pub status: api_enums::PaymentLinkStatus,
+    #[serde(with = "custom_serde::iso8601")]
+    pub expires_at: PrimitiveDateTime,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
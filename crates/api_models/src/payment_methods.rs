@@ -188,6 +188,20 @@ pub struct ListPaymentMethod {
     pub recurring_enabled: bool,
     pub installment_payment_enabled: bool,
     pub payment_experience: Option<Vec<PaymentExperience>>,
+    /// The surcharges levied on the payment with the payment method, which are calculated when
+    /// the payment methods are listed for a payment
+    #[serde(skip_deserializing)]
+    pub surcharge_details: Option<Vec<SurchargeDetailsResponse>>,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug, serde::Serialize)]
+pub struct SurchargeDetailsResponse {
+    /// The card network which the surcharge is specific to, if any
+    pub card_network: Option<api_enums::CardNetwork>,
+    /// The surcharge levied on the payment, in the lowest denomination of its currency
+    pub surcharge_amount: i64,
+    /// The tax charged on the surcharge
+    pub tax_on_surcharge_amount: i64,
 }
 
 /// We need a custom serializer to only send relevant fields in ListPaymentMethodResponse
@@ -203,6 +217,7 @@ impl serde::Serialize for ListPaymentMethod {
         let mut state = serializer.serialize_struct("ListPaymentMethod", 4)?;
         state.serialize_field("payment_method", &self.payment_method)?;
         state.serialize_field("payment_experience", &self.payment_experience)?;
+        state.serialize_field("surcharge_details", &self.surcharge_details)?;
         match self.payment_method {
             api_enums::PaymentMethodType::Wallet | api_enums::PaymentMethodType::PayLater => {
                 state.serialize_field("payment_method_issuers", &self.payment_method_issuers)?;
@@ -1,6 +1,6 @@
 use std::num::NonZeroI64;
 
-use common_utils::{errors, ext_traits::Encode, pii};
+use common_utils::{errors, ext_traits::Encode, pii, types::MinorUnit};
 use masking::{PeekInterface, Secret};
 use router_derive::Setter;
 use time::PrimitiveDateTime;
@@ -132,6 +132,15 @@ pub struct PaymentsRequest {
         "java_script_enabled":true
     }"#)]
     pub browser_info: Option<serde_json::Value>,
+    /// The country of the business for which the payment is made. Along with the business label, it decides the connector account through which the payment is processed, when the merchant has multiple accounts of the same connector.
+    #[schema(max_length = 2, example = "US")]
+    pub business_country: Option<String>,
+    /// The label of the business for which the payment is made, such as a region or a brand.
+    #[schema(max_length = 64, example = "food")]
+    pub business_label: Option<String>,
+    /// The currency in which the merchant wants the payment to be settled, if it differs from the currency of the payment. The amount is converted with the exchange rate at the time of the payment, and the converted amount is sent to the connector.
+    #[schema(value_type = Option<Currency>, example = "EUR")]
+    pub settlement_currency: Option<api_enums::Currency>,
 }
 
 #[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone, Copy, PartialEq, Eq)]
@@ -156,6 +165,18 @@ impl From<i64> for Amount {
     }
 }
 
+impl From<Amount> for MinorUnit {
+    fn from(amount: Amount) -> Self {
+        Self::new(i64::from(amount))
+    }
+}
+
+impl From<MinorUnit> for Amount {
+    fn from(amount: MinorUnit) -> Self {
+        Self::from(amount.get_amount_as_i64())
+    }
+}
+
 #[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone)]
 #[serde(deny_unknown_fields)]
 pub struct PaymentsRedirectRequest {
@@ -215,6 +236,7 @@ pub enum MandateTxnType {
 pub struct MandateIds {
     pub mandate_id: String,
     pub connector_mandate_id: Option<String>,
+    pub network_transaction_id: Option<String>,
 }
 
 impl MandateIds {
@@ -222,6 +244,7 @@ impl MandateIds {
         Self {
             mandate_id,
             connector_mandate_id: None,
+            network_transaction_id: None,
         }
     }
 }
@@ -357,6 +380,44 @@ pub enum PayLaterData {
     },
 }
 
+#[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct UpiData {
+    /// The virtual payment address of the customer, to which a collect request is sent. The
+    /// customer pays through their UPI app instead, if this is not provided.
+    #[schema(value_type = Option<String>, example = "successtest@iata")]
+    pub vpa_id: Option<Secret<String>>,
+}
+
+#[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BankDebitData {
+    /// Payment through an ACH debit, from a bank account in the US
+    AchBankDebit {
+        /// The name of the holder of the bank account
+        #[schema(value_type = String, example = "John Doe")]
+        account_holder_name: Secret<String>,
+        /// The number of the bank account
+        #[schema(value_type = String, example = "000123456789")]
+        account_number: Secret<String>,
+        /// The ABA routing number of the bank
+        #[schema(value_type = String, example = "110000000")]
+        routing_number: Secret<String>,
+        /// The acceptance by the customer of the mandate, with which the bank account is debited
+        mandate_acceptance: CustomerAcceptance,
+    },
+    /// Payment through a SEPA Direct Debit, from a bank account in the SEPA area
+    SepaBankDebit {
+        /// The name of the holder of the bank account
+        #[schema(value_type = String, example = "John Doe")]
+        account_holder_name: Secret<String>,
+        /// The international bank account number of the bank account
+        #[schema(value_type = String, example = "DE89370400440532013000")]
+        iban: Secret<String>,
+        /// The acceptance by the customer of the mandate, with which the bank account is debited
+        mandate_acceptance: CustomerAcceptance,
+    },
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Default, serde::Deserialize, serde::Serialize, ToSchema)]
 pub enum PaymentMethod {
     #[serde(rename(deserialize = "card"))]
@@ -370,6 +431,10 @@ pub enum PaymentMethod {
     PayLater(PayLaterData),
     #[serde(rename(deserialize = "paypal"))]
     Paypal,
+    #[serde(rename(deserialize = "upi"))]
+    Upi(UpiData),
+    #[serde(rename(deserialize = "bank_debit"))]
+    BankDebit(BankDebitData),
 }
 
 #[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -377,7 +442,8 @@ pub struct WalletData {
     /// The issuer of the wallet
     #[schema(value_type = WalletIssuer)]
     pub issuer_name: api_enums::WalletIssuer,
-    /// Token generated for the wallet
+    /// Token generated for the wallet, required for Google Pay and Apple Pay payments. For Google
+    /// Pay, this is the `tokenizationData.token` of the payment data returned by the wallet
     pub token: Option<String>,
 }
 
@@ -397,6 +463,8 @@ pub enum PaymentMethodDataResponse {
     Wallet(WalletData),
     PayLater(PayLaterData),
     Paypal,
+    Upi,
+    BankDebit,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
@@ -515,7 +583,7 @@ pub struct PhoneDetails {
     pub country_code: Option<String>,
 }
 
-#[derive(Debug, Clone, Default, Eq, PartialEq, serde::Deserialize)]
+#[derive(Debug, Clone, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct PaymentsCaptureRequest {
     pub payment_id: Option<String>,
     pub merchant_id: Option<String>,
@@ -570,14 +638,14 @@ pub struct PaymentsResponse {
     #[schema(value_type = IntentStatus, example = "failed", default = "requires_confirmation")]
     pub status: api_enums::IntentStatus,
     /// The payment amount. Amount for the payment in lowest denomination of the currency. (i.e) in cents for USD denomination, in paisa for INR denomination etc.,
-    #[schema(example = 100)]
-    pub amount: i64,
+    #[schema(value_type = i64, example = 100)]
+    pub amount: MinorUnit,
     /// The maximum amount that could be captured from the payment
-    #[schema(minimum = 100, example = 6540)]
-    pub amount_capturable: Option<i64>,
+    #[schema(value_type = Option<i64>, minimum = 100, example = 6540)]
+    pub amount_capturable: Option<MinorUnit>,
     /// The amount which is already captured from the payment
-    #[schema(minimum = 100, example = 6540)]
-    pub amount_received: Option<i64>,
+    #[schema(value_type = Option<i64>, minimum = 100, example = 6540)]
+    pub amount_received: Option<MinorUnit>,
     /// The connector used for the payment
     #[schema(example = "stripe")]
     pub connector: Option<String>,
@@ -600,6 +668,9 @@ pub struct PaymentsResponse {
     /// List of refund that happened on this intent
     #[schema(value_type = Option<Vec<RefundResponse>>)]
     pub refunds: Option<Vec<refunds::RefundResponse>>,
+    /// List of captures made against the authorization of this intent
+    #[schema(value_type = Option<Vec<CaptureResponse>>)]
+    pub captures: Option<Vec<CaptureResponse>>,
     /// A unique identifier to link the payment to a mandate, can be use instead of payment_method_data
     #[schema(max_length = 255, example = "mandate_iwer89rnjef349dni3")]
     pub mandate_id: Option<String>,
@@ -630,6 +701,9 @@ pub struct PaymentsResponse {
     /// Provide a reference to a stored payment method
     #[schema(example = "187282ab-40ef-47a9-9206-5099ba31e432")]
     pub payment_token: Option<String>,
+    /// The identifier of the payment method saved for the customer, which can be used for future payments
+    #[schema(example = "pm_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub payment_method_id: Option<String>,
     /// The shipping address for the payment
     pub shipping: Option<Address>,
     /// The billing address for the payment
@@ -668,6 +742,71 @@ pub struct PaymentsResponse {
     /// If there was an error while calling the connector the error message is received here
     #[schema(example = "Failed while verifying the card")]
     pub error_message: Option<String>,
+    /// Results of the checks performed by the card issuer while authorizing the payment
+    pub payment_checks: Option<PaymentChecks>,
+    /// The decision of the fraud and risk management service which screened the payment
+    #[schema(value_type = Option<FrmDecision>, example = "approve")]
+    pub frm_decision: Option<api_enums::FrmDecision>,
+    /// The currency in which the payment is settled, if it differs from the currency of the payment
+    #[schema(value_type = Option<Currency>, example = "EUR")]
+    pub settlement_currency: Option<api_enums::Currency>,
+    /// The amount of the payment converted to the settlement currency, in its lowest denomination
+    #[schema(example = 5520)]
+    pub settlement_amount: Option<i64>,
+    /// The exchange rate with which the amount of the payment was converted to the settlement currency
+    #[schema(example = "0.92")]
+    pub fx_rate: Option<String>,
+    /// The surcharge levied by the merchant for the payment method of the payment, which is charged in addition to the amount
+    #[schema(example = 120)]
+    pub surcharge_amount: Option<i64>,
+    /// The tax charged on the surcharge
+    #[schema(example = 22)]
+    pub tax_on_surcharge_amount: Option<i64>,
+    /// Whether the payment was processed against the sandbox of the connector
+    #[schema(example = false)]
+    pub test_mode: Option<bool>,
+}
+
+/// A single capture made against the authorization of a payment.
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
+pub struct CaptureResponse {
+    /// Unique identifier for the capture
+    #[schema(example = "cap_mbabizu24mvu3mela5njyhpit4")]
+    pub capture_id: String,
+    /// The status of the capture
+    #[schema(value_type = CaptureStatus, example = "charged")]
+    pub status: api_enums::CaptureStatus,
+    /// The amount captured, in the lowest denomination of the currency
+    #[schema(example = 6540)]
+    pub amount: i64,
+    /// The currency of the captured amount
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+    /// The connector the capture was made with
+    #[schema(example = "stripe")]
+    pub connector: String,
+    /// The reference of the capture at the connector
+    pub connector_capture_id: Option<String>,
+    /// The error message if the capture failed
+    pub error_message: Option<String>,
+    /// The error code if the capture failed
+    pub error_code: Option<String>,
+    /// Time when the capture was created
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+/// Results of the address and security code checks performed by the card issuer, normalized
+/// across connectors.
+#[derive(Clone, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct PaymentChecks {
+    /// Result of the address verification (AVS) check
+    #[schema(value_type = Option<AvsResult>, example = "match")]
+    pub avs_result: Option<api_enums::AvsResult>,
+    /// Result of the card security code (CVV) check
+    #[schema(value_type = Option<CvvResult>, example = "match")]
+    pub cvv_result: Option<api_enums::CvvResult>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize)]
@@ -692,12 +831,83 @@ pub struct PaymentListConstraints {
     #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
     #[serde(rename = "created.gte")]
     pub created_gte: Option<PrimitiveDateTime>,
+    pub status: Option<api_enums::IntentStatus>,
+    pub currency: Option<api_enums::Currency>,
+    /// The connector through which the latest attempt of the payment was made
+    pub connector: Option<api_enums::Connector>,
+    #[serde(rename = "amount.lte")]
+    pub amount_lte: Option<i64>,
+    #[serde(rename = "amount.gte")]
+    pub amount_gte: Option<i64>,
+    /// Restricts the listing to test payments or to live payments
+    pub test_mode: Option<bool>,
+}
+
+/// Constraints for listing the payments of the merchant accounts under an organization.
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct OrganizationPaymentListConstraints {
+    /// Restricts the listing to a single merchant account of the organization
+    pub merchant_id: Option<String>,
+    pub customer_id: Option<String>,
+    pub starting_after: Option<String>,
+    pub ending_before: Option<String>,
+    #[serde(default = "default_limit")]
+    pub limit: i64,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created: Option<PrimitiveDateTime>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    #[serde(rename = "created.lt")]
+    pub created_lt: Option<PrimitiveDateTime>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    #[serde(rename = "created.gt")]
+    pub created_gt: Option<PrimitiveDateTime>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    #[serde(rename = "created.lte")]
+    pub created_lte: Option<PrimitiveDateTime>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    #[serde(rename = "created.gte")]
+    pub created_gte: Option<PrimitiveDateTime>,
+    pub status: Option<api_enums::IntentStatus>,
+    pub currency: Option<api_enums::Currency>,
+    /// The connector through which the latest attempt of the payment was made
+    pub connector: Option<api_enums::Connector>,
+    #[serde(rename = "amount.lte")]
+    pub amount_lte: Option<i64>,
+    #[serde(rename = "amount.gte")]
+    pub amount_gte: Option<i64>,
+    /// Restricts the listing to test payments or to live payments
+    pub test_mode: Option<bool>,
+}
+
+impl From<OrganizationPaymentListConstraints> for PaymentListConstraints {
+    fn from(item: OrganizationPaymentListConstraints) -> Self {
+        Self {
+            customer_id: item.customer_id,
+            starting_after: item.starting_after,
+            ending_before: item.ending_before,
+            limit: item.limit,
+            created: item.created,
+            created_lt: item.created_lt,
+            created_gt: item.created_gt,
+            created_lte: item.created_lte,
+            created_gte: item.created_gte,
+            status: item.status,
+            currency: item.currency,
+            connector: item.connector,
+            amount_lte: item.amount_lte,
+            amount_gte: item.amount_gte,
+            test_mode: item.test_mode,
+        }
+    }
 }
 
 #[derive(Clone, Debug, serde::Serialize)]
 pub struct PaymentListResponse {
     pub size: usize,
     pub data: Vec<PaymentsResponse>,
+    /// Whether there are more payments to be listed after the ones in this page
+    pub has_more: bool,
 }
 
 #[derive(Setter, Clone, Default, Debug, Eq, PartialEq, serde::Serialize)]
@@ -848,6 +1058,7 @@ impl From<PaymentsSessionRequest> for PaymentsSessionResponse {
             session_token: vec![],
             payment_id: item.payment_id,
             client_secret,
+            connector_client_keys: vec![],
         }
     }
 }
@@ -919,6 +1130,8 @@ impl From<PaymentMethod> for PaymentMethodDataResponse {
             PaymentMethod::PayLater(pay_later_data) => Self::PayLater(pay_later_data),
             PaymentMethod::Wallet(wallet_data) => Self::Wallet(wallet_data),
             PaymentMethod::Paypal => Self::Paypal,
+            PaymentMethod::Upi(_) => Self::Upi,
+            PaymentMethod::BankDebit(_) => Self::BankDebit,
         }
     }
 }
@@ -956,6 +1169,7 @@ pub struct PaymentsRetrieveRequest {
     pub force_sync: bool,
     pub param: Option<String>,
     pub connector: Option<String>,
+    pub client_secret: Option<String>,
 }
 
 #[derive(Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
@@ -982,6 +1196,9 @@ pub struct Metadata {
 pub struct PaymentsSessionRequest {
     pub payment_id: String,
     pub client_secret: String,
+    /// The wallets to create session tokens for, those of all the wallets enabled by the merchant
+    /// are created if it is empty or not given
+    #[serde(default)]
     pub wallets: Vec<api_enums::SupportedWallets>,
 }
 
@@ -1067,17 +1284,29 @@ pub enum SessionToken {
     },
 }
 
+/// Public key of a connector, used by the SDK to initialise the client side library of the
+/// connector, such as for collecting card details in its hosted fields.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ConnectorClientKey {
+    pub connector: String,
+    pub client_key: String,
+}
+
 #[derive(Default, Debug, serde::Serialize, Clone)]
 pub struct PaymentsSessionResponse {
     pub payment_id: String,
     pub client_secret: Secret<String, pii::ClientSecret>,
     pub session_token: Vec<SessionToken>,
+    /// Client keys of the connectors of the merchant which have one configured in their metadata
+    pub connector_client_keys: Vec<ConnectorClientKey>,
 }
 
 #[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone)]
 pub struct PaymentRetrieveBody {
     pub merchant_id: Option<String>,
     pub force_sync: Option<bool>,
+    /// The client secret of the payment, required when retrieving it with the publishable key
+    pub client_secret: Option<String>,
 }
 #[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone)]
 pub struct PaymentsCancelRequest {
@@ -1086,11 +1315,34 @@ pub struct PaymentsCancelRequest {
     pub cancellation_reason: Option<String>,
 }
 
+#[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentsCloneRequest {
+    #[serde(skip)]
+    pub payment_id: String,
+    /// Attaches the saved payment method used for the original payment to the cloned payment
+    #[serde(default)]
+    pub reuse_payment_method: bool,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct PaymentsClientSecretResponse {
+    /// The identifier for the payment
+    pub payment_id: String,
+    /// The new client secret of the payment, which replaces the previous one
+    #[schema(value_type = String, example = "pay_U42c409qyHwOkWo3vK60_secret_el9ksDkiB8hi6j9N78yo")]
+    pub client_secret: Secret<String>,
+    /// The time after which the client secret expires
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub client_secret_expiry: PrimitiveDateTime,
+}
+
 #[derive(Default, Debug, serde::Deserialize, serde::Serialize)]
 pub struct PaymentsStartRequest {
     pub payment_id: String,
     pub merchant_id: String,
-    pub txn_id: String,
+    /// The attempt for which the shopper is being redirected, if it is present in the redirect URL
+    pub txn_id: Option<String>,
 }
 
 mod payment_id_type {
@@ -1 +1,106 @@
+use common_utils::{custom_serde, pii};
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
 
+use crate::enums as api_enums;
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutCreateRequest {
+    /// Unique identifier for the payout. If not provided, it will be generated
+    #[schema(max_length = 64, example = "payout_mbabizu24mvu3mela5njyhpit4")]
+    pub payout_id: Option<String>,
+    /// The amount to be paid out, in the lowest denomination of the currency
+    #[schema(example = 1000)]
+    pub amount: i64,
+    #[schema(value_type = Currency, example = "EUR")]
+    pub currency: api_enums::Currency,
+    /// The connector through which the payout is to be made
+    #[schema(value_type = Connector, example = "adyen")]
+    pub connector: api_enums::Connector,
+    /// The kind of account that the payout is made to
+    #[schema(value_type = PayoutType, example = "bank")]
+    pub payout_type: api_enums::PayoutType,
+    /// The identifier for the customer receiving the payout
+    #[schema(max_length = 64, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: Option<String>,
+    /// The email address of the customer receiving the payout
+    #[schema(max_length = 255, value_type = Option<String>, example = "johntest@test.com")]
+    pub email: Option<Secret<String, pii::Email>>,
+    /// The account details of the recipient, required when the payout is confirmed
+    pub payout_method_data: Option<PayoutMethodData>,
+    /// Whether to submit the payout to the connector along with creating it
+    #[schema(default = false, example = true)]
+    pub confirm: Option<bool>,
+    /// An arbitrary string attached to the payout
+    #[schema(max_length = 255, example = "Seller payout for January")]
+    pub description: Option<String>,
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>, example = r#"{ "city": "NY", "unit": "245" }"#)]
+    pub metadata: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutConfirmRequest {
+    /// The account details of the recipient
+    pub payout_method_data: PayoutMethodData,
+    /// The email address of the customer receiving the payout
+    #[schema(max_length = 255, value_type = Option<String>, example = "johntest@test.com")]
+    pub email: Option<Secret<String, pii::Email>>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PayoutMethodData {
+    Bank(BankPayout),
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+pub struct BankPayout {
+    /// The International Bank Account Number of the recipient
+    #[schema(value_type = String, example = "NL46TEST0136169112")]
+    pub iban: Secret<String>,
+    /// The Bank Identifier Code of the recipient's bank
+    #[schema(value_type = Option<String>, example = "ABNANL2A")]
+    pub bic: Option<Secret<String>>,
+    /// The name of the recipient's bank
+    #[schema(example = "ABN AMRO")]
+    pub bank_name: Option<String>,
+    /// The two-letter ISO 3166-1 code of the country of the recipient's bank
+    #[schema(example = "NL")]
+    pub bank_country_code: String,
+    /// The name of the account holder
+    #[schema(value_type = String, example = "John Test")]
+    pub bank_owner_name: Secret<String>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+pub struct PayoutResponse {
+    /// The identifier for the payout
+    pub payout_id: String,
+    /// The identifier for the merchant account
+    pub merchant_id: String,
+    /// The identifier for the customer receiving the payout
+    pub customer_id: Option<String>,
+    /// The amount to be paid out, in the lowest denomination of the currency
+    pub amount: i64,
+    pub currency: String,
+    pub payout_type: api_enums::PayoutType,
+    pub status: api_enums::PayoutStatus,
+    /// The connector through which the payout is made
+    pub connector: String,
+    /// The identifier for the payout at the connector
+    pub connector_payout_id: Option<String>,
+    /// The error code sent by the connector, if the payout failed
+    pub error_code: Option<String>,
+    /// The error message sent by the connector, if the payout failed
+    pub error_message: Option<String>,
+    pub description: Option<String>,
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<serde_json::Value>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
@@ -0,0 +1,77 @@
+use common_utils::custom_serde;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ReconReportCreateRequest {
+    /// The connector which settled the entries of the report
+    #[schema(example = "stripe")]
+    pub connector: String,
+    /// The settlement report in CSV format, with a header row and the columns
+    /// `connector_transaction_id`, `type` (`payment` or `refund`), `amount` in the lowest
+    /// denomination of the currency and `currency`
+    #[schema(example = "connector_transaction_id,type,amount,currency\npi_123,payment,6540,USD")]
+    pub file_content: String,
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq, Deserialize, Serialize)]
+pub struct ReconReportListConstraints {
+    pub limit: Option<i64>,
+    pub connector: Option<String>,
+    pub status: Option<api_enums::ReconReportStatus>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, ToSchema)]
+pub struct ReconReportResponse {
+    /// The identifier for the report
+    #[schema(example = "recon_mbabizu24mvu3mela5njyhpit4")]
+    pub report_id: String,
+    #[schema(example = "stripe")]
+    pub connector: String,
+    #[schema(value_type = ReconReportStatus, example = "completed")]
+    pub status: api_enums::ReconReportStatus,
+    /// The number of entries in the report, present once the report has been reconciled
+    pub total_entries: Option<i32>,
+    /// The number of entries matching the payment or refund they settle
+    pub matched_entries: Option<i32>,
+    /// The number of entries not matching the payment or refund they settle
+    pub mismatched_entries: Option<i32>,
+    /// The reason the report could not be reconciled
+    pub error_message: Option<String>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    /// The entries not matching the payment or refund they settle, present only when a single
+    /// report is retrieved
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mismatches: Option<Vec<ReconMismatchResponse>>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, ToSchema)]
+pub struct ReconMismatchResponse {
+    #[schema(value_type = ReconEntryType, example = "payment")]
+    pub entry_type: api_enums::ReconEntryType,
+    #[schema(example = "pi_123")]
+    pub connector_transaction_id: String,
+    /// The amount settled by the connector
+    pub amount: i64,
+    /// The currency in which the connector settled the amount
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+    #[schema(value_type = ReconMismatchReason, example = "amount_mismatch")]
+    pub reason: api_enums::ReconMismatchReason,
+    /// The amount which was expected to be settled, if the payment or refund was found
+    pub expected_amount: Option<i64>,
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub expected_currency: Option<api_enums::Currency>,
+    pub payment_id: Option<String>,
+    pub refund_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, ToSchema)]
+pub struct ReconReportListResponse {
+    pub data: Vec<ReconReportResponse>,
+}
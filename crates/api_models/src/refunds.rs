@@ -5,7 +5,7 @@ use utoipa::ToSchema;
 
 use crate::enums;
 
-#[derive(Default, Debug, ToSchema, Clone, Deserialize)]
+#[derive(Default, Debug, ToSchema, Clone, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct RefundRequest {
     /// Unique Identifier for the Refund. This is to ensure idempotency for multiple partial refund initiated against the same payment. If the identifiers is not defined by the merchant, this filed shall be auto generated and provide in the API response. It is recommended to generate uuid(v4) as the refund_id.
@@ -45,6 +45,22 @@ pub struct RefundRequest {
     pub metadata: Option<serde_json::Value>,
 }
 
+/// Refund of a payment whose connector account has been disabled, issued by an operator through another account of the same connector
+#[derive(Debug, ToSchema, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RefundAlternateAccountRequest {
+    /// The identifier for the Merchant Account whose payment is to be refunded
+    #[schema(max_length = 255, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+
+    /// Label of the connector account through which the refund is to be issued. It has to be an account of the connector of the original payment, so that the connector knows the payment being refunded
+    #[schema(example = "stripe_US_food")]
+    pub connector_label: String,
+
+    /// The refund to be issued
+    pub refund: RefundRequest,
+}
+
 #[derive(Default, Debug, ToSchema, Clone, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct RefundUpdateRequest {
@@ -57,7 +73,7 @@ pub struct RefundUpdateRequest {
     pub metadata: Option<serde_json::Value>,
 }
 
-#[derive(Default, Debug, Clone, ToSchema, Deserialize)]
+#[derive(Default, Debug, Clone, ToSchema, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum RefundType {
     #[default]
@@ -76,6 +92,12 @@ pub struct RefundResponse {
     #[schema(value_type = Option<Object>)]
     pub metadata: Option<serde_json::Value>,
     pub error_message: Option<String>,
+    /// The connector through which the refund was processed
+    pub connector: String,
+    /// Label of the connector account through which the refund was issued, populated when it is not the account of the original payment
+    pub alternate_connector_label: Option<String>,
+    /// Indicates that the refund was issued outside the connector account of the original payment and has to be reconciled manually
+    pub reconciliation_required: bool,
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
     pub created_at: Option<PrimitiveDateTime>,
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
@@ -86,6 +108,11 @@ pub struct RefundResponse {
 pub struct RefundListRequest {
     pub payment_id: Option<String>,
     pub limit: Option<i64>,
+    /// The number of refunds to skip, used along with `limit` for paginating the refunds
+    pub offset: Option<i64>,
+    pub status: Option<RefundStatus>,
+    /// The connector through which the refunds were processed
+    pub connector: Option<String>,
     #[serde(default, with = "custom_serde::iso8601::option")]
     pub created: Option<PrimitiveDateTime>,
     #[serde(default, rename = "created.lt", with = "custom_serde::iso8601::option")]
@@ -111,6 +138,44 @@ pub struct RefundListResponse {
     pub data: Vec<RefundResponse>,
 }
 
+#[derive(Debug, ToSchema, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RefundBatchRequest {
+    /// The refunds to be initiated as part of the batch. The refunds are processed asynchronously, the status of the batch can be fetched using the batch_id returned in the response
+    pub refunds: Vec<RefundRequest>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+pub struct RefundBatchResponse {
+    /// Unique identifier of the batch
+    pub batch_id: String,
+    pub status: RefundBatchStatus,
+    /// Number of refunds in the batch
+    pub total_count: usize,
+    /// Number of refunds in the batch which have been processed
+    pub processed_count: usize,
+    /// Outcome of each refund in the batch, in the order of the request
+    pub results: Vec<RefundBatchRowResult>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RefundBatchStatus {
+    Pending,
+    Processing,
+    Completed,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+pub struct RefundBatchRowResult {
+    /// Position of the refund in the batch request
+    pub row: usize,
+    pub payment_id: String,
+    pub refund_id: Option<String>,
+    pub status: Option<RefundStatus>,
+    pub error_message: Option<String>,
+}
+
 #[derive(Debug, Eq, Clone, PartialEq, Default, Deserialize, Serialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum RefundStatus {
@@ -2,18 +2,27 @@ use common_utils::custom_serde;
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
-use crate::{enums as api_enums, payments};
+use crate::{disputes, enums as api_enums, mandates, payments, payouts, refunds};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum IncomingWebhookEvent {
     PaymentIntentFailure,
     PaymentIntentSuccess,
+    RefundFailure,
+    RefundSuccess,
+    DisputeOpened,
+    DisputeChallenged,
+    DisputeWon,
+    DisputeLost,
 }
 
 pub enum WebhookFlow {
     Payment,
     Refund,
+    Dispute,
+    Mandate,
+    Payout,
     Subscription,
 }
 
@@ -22,6 +31,12 @@ impl From<IncomingWebhookEvent> for WebhookFlow {
         match evt {
             IncomingWebhookEvent::PaymentIntentFailure => Self::Payment,
             IncomingWebhookEvent::PaymentIntentSuccess => Self::Payment,
+            IncomingWebhookEvent::RefundFailure => Self::Refund,
+            IncomingWebhookEvent::RefundSuccess => Self::Refund,
+            IncomingWebhookEvent::DisputeOpened
+            | IncomingWebhookEvent::DisputeChallenged
+            | IncomingWebhookEvent::DisputeWon
+            | IncomingWebhookEvent::DisputeLost => Self::Dispute,
         }
     }
 }
@@ -47,4 +62,29 @@ pub struct OutgoingWebhook {
 #[serde(tag = "type", content = "object", rename_all = "snake_case")]
 pub enum OutgoingWebhookContent {
     PaymentDetails(payments::PaymentsResponse),
+    RefundDetails(refunds::RefundResponse),
+    DisputeDetails(disputes::DisputeResponse),
+    MandateDetails(mandates::MandateResponse),
+    PayoutDetails(payouts::PayoutResponse),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TestWebhookRequest {
+    /// The type of the event of which a sample webhook is to be sent
+    pub event_type: api_enums::EventType,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TestWebhookResponse {
+    pub event_id: String,
+    pub event_type: api_enums::EventType,
+    /// Whether the webhook endpoint of the merchant responded with a successful status code
+    pub delivered: bool,
+    /// The HTTP status code returned by the webhook endpoint of the merchant, if it could be reached
+    pub response_code: Option<i32>,
+    /// Time taken by the delivery, in milliseconds
+    pub latency_ms: i64,
+    /// The reason for the failure of the delivery
+    pub error_message: Option<String>,
 }
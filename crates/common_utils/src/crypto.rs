@@ -94,6 +94,34 @@ impl DecodeMessage for NoAlgorithm {
     }
 }
 
+/// Represents the HMAC-SHA-1 algorithm, for connectors which still sign their messages with it
+#[derive(Debug)]
+pub struct HmacSha1;
+
+impl SignMessage for HmacSha1 {
+    fn sign_message(
+        &self,
+        secret: &[u8],
+        msg: &[u8],
+    ) -> CustomResult<Vec<u8>, errors::CryptoError> {
+        let key = hmac::Key::new(hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY, secret);
+        Ok(hmac::sign(&key, msg).as_ref().to_vec())
+    }
+}
+
+impl VerifySignature for HmacSha1 {
+    fn verify_signature(
+        &self,
+        secret: &[u8],
+        signature: &[u8],
+        msg: &[u8],
+    ) -> CustomResult<bool, errors::CryptoError> {
+        let key = hmac::Key::new(hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY, secret);
+
+        Ok(hmac::verify(&key, msg, signature).is_ok())
+    }
+}
+
 /// Represents the HMAC-SHA-256 algorithm
 #[derive(Debug)]
 pub struct HmacSha256;
@@ -259,6 +287,28 @@ mod crypto_tests {
         assert!(!wrong_verified);
     }
 
+    #[test]
+    fn test_hmac_sha1_verify_signature() {
+        let right_signature = hex::decode("9e1ed9945d638e02299d11366a5ecf147ddf5a1b")
+            .expect("Right signature decoding");
+        let wrong_signature = hex::decode("9e1ed9945d638e02299d11366a5ecf147ddf5a1c")
+            .expect("Wrong signature decoding");
+        let secret = "hmac_secret_1234".as_bytes();
+        let data = r#"{"type":"payment_intent"}"#.as_bytes();
+
+        let right_verified = super::HmacSha1
+            .verify_signature(secret, &right_signature, data)
+            .expect("Right signature verification result");
+
+        assert!(right_verified);
+
+        let wrong_verified = super::HmacSha1
+            .verify_signature(secret, &wrong_signature, data)
+            .expect("Wrong signature verification result");
+
+        assert!(!wrong_verified);
+    }
+
     #[test]
     fn test_hmac_sha512_sign_message() {
         let message = r#"{"type":"payment_intent"}"#.as_bytes();
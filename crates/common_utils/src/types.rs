@@ -0,0 +1,224 @@
+//! Types that can be used in other crates
+
+use diesel::{
+    backend::{Backend, RawValue},
+    deserialize::{self, FromSql},
+    serialize::{self, Output, ToSql},
+    sql_types, AsExpression, FromSqlRow,
+};
+use error_stack::{report, IntoReport, ResultExt};
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{CustomResult, ParsingError};
+
+/// A currency whose amounts are expressed in a minor unit, which a fixed number of minor units
+/// make up one major unit of.
+pub trait CurrencyUnit {
+    /// The number of digits after the decimal point in the major unit of the currency, which is
+    /// the exponent relating the major unit to the minor unit.
+    fn number_of_digits_after_decimal_point(&self) -> u8;
+}
+
+/// An amount in the minor unit of its currency, such as cents for USD or yen for JPY. This is
+/// how amounts are stored and passed around, conversions to the major unit of the currency are
+/// only done where the major unit is expected, such as in some connector requests.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    Deserialize,
+    AsExpression,
+    FromSqlRow,
+)]
+#[serde(transparent)]
+#[diesel(sql_type = sql_types::BigInt)]
+pub struct MinorUnit(i64);
+
+impl MinorUnit {
+    /// Creates an amount from its value in the minor unit of the currency
+    pub const fn new(value: i64) -> Self {
+        Self(value)
+    }
+
+    /// An amount of zero
+    pub const fn zero() -> Self {
+        Self(0)
+    }
+
+    /// The value of the amount in the minor unit of the currency
+    pub const fn get_amount_as_i64(self) -> i64 {
+        self.0
+    }
+
+    /// Formats the amount in the major unit of the currency, with as many digits after the
+    /// decimal point as the currency has. For instance 1005 is "10.05" in USD, "1005" in JPY and
+    /// "1.005" in KWD.
+    pub fn to_major_unit_as_string<C: CurrencyUnit>(self, currency: &C) -> String {
+        let digits = currency.number_of_digits_after_decimal_point();
+        if digits == 0 {
+            return self.0.to_string();
+        }
+
+        let divisor = 10_u64.pow(u32::from(digits));
+        let value = self.0.unsigned_abs();
+        format!(
+            "{}{}.{:0width$}",
+            if self.0 < 0 { "-" } else { "" },
+            value / divisor,
+            value % divisor,
+            width = usize::from(digits)
+        )
+    }
+
+    /// Converts the amount to the major unit of the currency as a float, for connectors which
+    /// expect amounts as numbers in the major unit.
+    #[allow(clippy::as_conversions)]
+    pub fn to_major_unit_as_f64<C: CurrencyUnit>(self, currency: &C) -> f64 {
+        let digits = i32::from(currency.number_of_digits_after_decimal_point());
+        self.0 as f64 / 10_f64.powi(digits)
+    }
+
+    /// Parses an amount formatted in the major unit of the currency, such as "10.05" for USD.
+    /// Amounts with more digits after the decimal point than the currency has are rejected
+    /// instead of being rounded.
+    pub fn from_major_unit_as_string<C: CurrencyUnit>(
+        amount: &str,
+        currency: &C,
+    ) -> CustomResult<Self, ParsingError> {
+        let digits = usize::from(currency.number_of_digits_after_decimal_point());
+        let (is_negative, amount) = match amount.strip_prefix('-') {
+            Some(amount) => (true, amount),
+            None => (false, amount),
+        };
+        let (major, minor) = amount.split_once('.').unwrap_or((amount, ""));
+
+        let is_valid = !major.is_empty()
+            && major.bytes().all(|byte| byte.is_ascii_digit())
+            && minor.bytes().all(|byte| byte.is_ascii_digit())
+            && minor.len() <= digits;
+        if !is_valid {
+            return Err(report!(ParsingError))
+                .attach_printable_lazy(|| format!("Invalid amount in the major unit: {amount}"));
+        }
+
+        let value = format!("{major}{minor:0<digits$}")
+            .parse::<i64>()
+            .into_report()
+            .change_context(ParsingError)
+            .attach_printable("Amount is out of range")?;
+        Ok(Self(if is_negative { -value } else { value }))
+    }
+
+    /// Converts an amount in the major unit of the currency to its minor unit, rounding it to the
+    /// nearest minor unit.
+    #[allow(clippy::as_conversions)]
+    pub fn from_major_unit_as_f64<C: CurrencyUnit>(
+        amount: f64,
+        currency: &C,
+    ) -> CustomResult<Self, ParsingError> {
+        let digits = i32::from(currency.number_of_digits_after_decimal_point());
+        let value = (amount * 10_f64.powi(digits)).round();
+
+        // i64::MAX is rounded up to 2^63 when converted to f64, which is itself out of range
+        if value.is_finite() && value >= i64::MIN as f64 && value < i64::MAX as f64 {
+            Ok(Self(value as i64))
+        } else {
+            Err(report!(ParsingError))
+                .attach_printable_lazy(|| format!("Amount is out of range: {amount}"))
+        }
+    }
+}
+
+impl std::fmt::Display for MinorUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<DB> FromSql<sql_types::BigInt, DB> for MinorUnit
+where
+    DB: Backend,
+    i64: FromSql<sql_types::BigInt, DB>,
+{
+    fn from_sql(value: RawValue<'_, DB>) -> deserialize::Result<Self> {
+        i64::from_sql(value).map(Self)
+    }
+}
+
+impl<DB> ToSql<sql_types::BigInt, DB> for MinorUnit
+where
+    DB: Backend,
+    i64: ToSql<sql_types::BigInt, DB>,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        self.0.to_sql(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    struct Digits(u8);
+
+    impl CurrencyUnit for Digits {
+        fn number_of_digits_after_decimal_point(&self) -> u8 {
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_to_major_unit_as_string() {
+        let amount = |value, digits| MinorUnit::new(value).to_major_unit_as_string(&Digits(digits));
+        assert_eq!(amount(1005, 2), "10.05");
+        assert_eq!(amount(5, 2), "0.05");
+        assert_eq!(amount(-1005, 2), "-10.05");
+        assert_eq!(amount(1005, 0), "1005");
+        assert_eq!(amount(1005, 3), "1.005");
+        assert_eq!(amount(i64::MAX, 2), "92233720368547758.07");
+    }
+
+    #[test]
+    fn test_from_major_unit_as_string() {
+        let amount = |value, digits| {
+            MinorUnit::from_major_unit_as_string(value, &Digits(digits))
+                .map(MinorUnit::get_amount_as_i64)
+        };
+        assert_eq!(amount("10.05", 2).unwrap(), 1005);
+        assert_eq!(amount("10.5", 2).unwrap(), 1050);
+        assert_eq!(amount("10", 2).unwrap(), 1000);
+        assert_eq!(amount("-0.05", 2).unwrap(), -5);
+        assert_eq!(amount("1005", 0).unwrap(), 1005);
+        assert_eq!(amount("1.005", 3).unwrap(), 1005);
+        assert!(amount("10.005", 2).is_err());
+        assert!(amount("10.05", 0).is_err());
+        assert!(amount("1e3", 2).is_err());
+        assert!(amount(".5", 2).is_err());
+        assert!(amount("92233720368547758.08", 2).is_err());
+    }
+
+    #[test]
+    fn test_major_unit_as_f64() {
+        let amount = MinorUnit::new(1005);
+        assert_eq!(amount.to_major_unit_as_f64(&Digits(2)), 10.05);
+        assert_eq!(
+            MinorUnit::from_major_unit_as_f64(10.05, &Digits(2)).unwrap(),
+            amount
+        );
+        assert_eq!(
+            MinorUnit::from_major_unit_as_f64(1005.0, &Digits(0)).unwrap(),
+            amount
+        );
+        assert!(MinorUnit::from_major_unit_as_f64(f64::NAN, &Digits(2)).is_err());
+        assert!(MinorUnit::from_major_unit_as_f64(f64::MAX, &Digits(2)).is_err());
+        assert!(MinorUnit::from_major_unit_as_f64(2_f64.powi(63), &Digits(0)).is_err());
+    }
+}
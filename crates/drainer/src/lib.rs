@@ -3,129 +3,261 @@ pub mod errors;
 pub mod services;
 pub mod settings;
 mod utils;
-use std::sync::Arc;
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
 
-use storage_models::kv;
+use error_stack::report;
+use router_env::logger;
+use storage_models::{errors::DatabaseError, kv, StorageResult};
 
 use crate::{connection::pg_connection, services::Store};
 
+/// Drains the streams until the drainer is asked to shut down, after which the streams being
+/// drained are given the shutdown timeout to be drained.
 pub async fn start_drainer(
     store: Arc<Store>,
     number_of_streams: u8,
-    max_read_count: u64,
+    conf: settings::DrainerSettings,
 ) -> errors::DrainerResult<()> {
     let mut stream_index: u8 = 0;
+    let shutdown_requested = Arc::new(AtomicBool::new(false));
+    let active_handlers = Arc::new(AtomicUsize::new(0));
+    tokio::spawn(wait_for_shutdown_signal(shutdown_requested.clone()));
 
-    loop {
+    while !shutdown_requested.load(Ordering::Relaxed) {
         if utils::is_stream_available(stream_index, store.clone()).await {
-            tokio::spawn(drainer_handler(store.clone(), stream_index, max_read_count));
+            active_handlers.fetch_add(1, Ordering::SeqCst);
+            let active_handlers = active_handlers.clone();
+            let handler = drainer_handler(store.clone(), stream_index, conf.clone());
+            tokio::spawn(async move {
+                let result = handler.await;
+                active_handlers.fetch_sub(1, Ordering::SeqCst);
+                result
+            });
         }
         stream_index = utils::increment_stream_index(stream_index, number_of_streams);
     }
+
+    logger::info!("Shutting down, waiting for the streams being drained");
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(conf.shutdown_timeout);
+    while active_handlers.load(Ordering::SeqCst) > 0 && tokio::time::Instant::now() < deadline {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+
+    let remaining = active_handlers.load(Ordering::SeqCst);
+    if remaining > 0 {
+        logger::warn!(
+            %remaining,
+            "Shutdown timeout elapsed before the streams being drained were drained"
+        );
+    }
+    Ok(())
+}
+
+/// Flags the drainer to shut down when the process receives `SIGTERM` or `SIGINT`.
+#[allow(clippy::expect_used)]
+async fn wait_for_shutdown_signal(shutdown_requested: Arc<AtomicBool>) {
+    let mut sigterm = tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+        .expect("Failed to listen for SIGTERM");
+    tokio::select! {
+        _ = sigterm.recv() => logger::info!("Received SIGTERM"),
+        _ = tokio::signal::ctrl_c() => logger::info!("Received SIGINT"),
+    }
+    shutdown_requested.store(true, Ordering::Relaxed);
 }
 
 async fn drainer_handler(
     store: Arc<Store>,
     stream_index: u8,
-    max_read_count: u64,
+    conf: settings::DrainerSettings,
 ) -> errors::DrainerResult<()> {
     let stream_name = utils::get_drainer_stream_name(store.clone(), stream_index);
-    let drainer_result = drainer(store.clone(), max_read_count, stream_name.as_str()).await;
+    let drainer_result = drainer(store.clone(), &conf, stream_name.as_str()).await;
 
-    if let Err(_e) = drainer_result {
-        //TODO: LOG errors
+    if let Err(error) = drainer_result {
+        logger::error!(?error, %stream_name, "Failed to drain stream");
     }
 
     let flag_stream_name = utils::get_stream_key_flag(store.clone(), stream_index);
-    //TODO: USE THE RESULT FOR LOGGING
-    utils::make_stream_available(flag_stream_name.as_str(), store.redis_conn.as_ref()).await
+    utils::make_stream_available(flag_stream_name.as_str(), store.redis_conn.as_ref())
+        .await
+        .map_err(|error| {
+            logger::error!(?error, %stream_name, "Failed to make stream available");
+            error
+        })
 }
 
+/// Applies the entries of the stream to the database in the order they were added to the stream,
+/// and trims the applied entries from the stream.
+///
+/// The entries of a payment are always added to the same stream, so processing the entries of a
+/// stream in order preserves the order of the operations on a payment. If an entry can't be
+/// applied even after retrying, the entry and the ones after it are left in the stream, to be
+/// applied with the next read of the stream. An entry which fails in `max_entry_attempts` reads
+/// is moved to the dead letter stream instead, so that it does not block the stream forever.
 async fn drainer(
     store: Arc<Store>,
-    max_read_count: u64,
+    conf: &settings::DrainerSettings,
     stream_name: &str,
 ) -> errors::DrainerResult<()> {
     let stream_read =
-        utils::read_from_stream(stream_name, max_read_count, store.redis_conn.as_ref()).await?; // this returns the error.
+        utils::read_from_stream(stream_name, conf.max_read_count, store.redis_conn.as_ref())
+            .await?; // this returns the error.
 
     // parse_stream_entries returns error if no entries is found, handle it
-    let (entries, last_entry_id) = utils::parse_stream_entries(&stream_read, stream_name)?;
+    let (entries, _) = utils::parse_stream_entries(&stream_read, stream_name)?;
 
-    let read_count = entries.len();
-
-    // TODO: Handle errors when deserialization fails and when DB error occurs
-    for entry in entries {
-        let typed_sql = entry.1.get("typed_sql").map_or(String::new(), Clone::clone);
-        let result = serde_json::from_str::<kv::DBOperation>(&typed_sql);
-        let db_op = match result {
-            Ok(f) => f,
-            Err(_err) => continue, // TODO: handle error
-        };
-
-        let conn = pg_connection(&store.master_pool).await;
-        let insert_op = "insert";
-        let update_op = "update";
-        let payment_intent = "payment_intent";
-        let payment_attempt = "payment_attempt";
-        match db_op {
-            // TODO: Handle errors
-            kv::DBOperation::Insert { insertable } => match insertable {
-                kv::Insertable::PaymentIntent(a) => {
-                    macro_util::handle_resp!(a.insert(&conn).await, insert_op, payment_intent)
-                }
-                kv::Insertable::PaymentAttempt(a) => {
-                    macro_util::handle_resp!(a.insert(&conn).await, insert_op, payment_attempt)
-                }
-                kv::Insertable::Refund(a) => {
-                    macro_util::handle_resp!(a.insert(&conn).await, "ins", "ref")
-                }
-            },
-            kv::DBOperation::Update { updatable } => match updatable {
-                kv::Updateable::PaymentIntentUpdate(a) => {
-                    macro_util::handle_resp!(
-                        a.orig.update(&conn, a.update_data).await,
-                        update_op,
-                        payment_intent
+    let mut last_applied_entry_id = None;
+    for (entry_id, entry) in entries {
+        let typed_sql = entry.get("typed_sql").map_or(String::new(), Clone::clone);
+        let failure = match serde_json::from_str::<kv::TypedSql>(&typed_sql) {
+            Ok(typed_sql) => match execute_typed_sql_with_retry(&store, conf, typed_sql).await {
+                Ok(()) => None,
+                Err(error) => {
+                    let attempts = utils::increment_entry_attempts(
+                        stream_name,
+                        entry_id,
+                        store.redis_conn.as_ref(),
                     )
-                }
-                kv::Updateable::PaymentAttemptUpdate(a) => {
-                    macro_util::handle_resp!(
-                        a.orig.update(&conn, a.update_data).await,
-                        update_op,
-                        payment_attempt
-                    )
-                }
-                kv::Updateable::RefundUpdate(a) => {
-                    macro_util::handle_resp!(a.orig.update(&conn, a.update_data).await, "up", "ref")
+                    .await;
+                    match attempts {
+                        Ok(attempts) if attempts >= i64::from(conf.max_entry_attempts) => {
+                            Some(format!("{error:?}"))
+                        }
+                        attempts => {
+                            logger::error!(
+                                ?error,
+                                ?attempts,
+                                %entry_id,
+                                %stream_name,
+                                "Failed to apply stream entry, it would be retried with the next read"
+                            );
+                            break;
+                        }
+                    }
                 }
             },
-            kv::DBOperation::Delete => {
-                // TODO: Implement this
-                println!("Not implemented!");
-            }
+            // An entry which can't be deserialized would never be applied
+            Err(error) => Some(format!("Failed to deserialize stream entry: {error}")),
         };
-    }
 
-    let entries_trimmed =
-        utils::trim_from_stream(stream_name, last_entry_id.as_str(), &store.redis_conn).await?;
+        if let Some(reason) = failure {
+            if let Err(error) = utils::move_to_dead_letter_stream(
+                &store,
+                stream_name,
+                entry_id,
+                entry,
+                reason.clone(),
+            )
+            .await
+            {
+                logger::error!(
+                    ?error,
+                    %reason,
+                    %entry_id,
+                    %stream_name,
+                    "Failed to move stream entry to the dead letter stream"
+                );
+                break;
+            }
+            logger::error!(
+                %reason,
+                %entry_id,
+                %stream_name,
+                dead_letter_stream_name = %store.config.dead_letter_stream_name,
+                "Moved stream entry which could not be applied to the dead letter stream"
+            );
+        }
+        last_applied_entry_id = Some(entry_id);
+    }
 
-    if read_count != entries_trimmed {
-        // TODO: log
+    if let Some(last_applied_entry_id) = last_applied_entry_id {
+        let entries_trimmed =
+            utils::trim_from_stream(stream_name, last_applied_entry_id, &store.redis_conn).await?;
+        logger::debug!(%stream_name, entries_trimmed, "Trimmed applied entries from stream");
     }
 
     Ok(())
 }
 
-mod macro_util {
+async fn execute_typed_sql_with_retry(
+    store: &Store,
+    conf: &settings::DrainerSettings,
+    typed_sql: kv::TypedSql,
+) -> StorageResult<()> {
+    let mut retry_count = 0;
+    loop {
+        match execute_typed_sql(store, typed_sql.clone()).await {
+            Ok(()) => return Ok(()),
+            Err(error) if retry_count < conf.max_retries => {
+                retry_count += 1;
+                logger::warn!(?error, retry_count, "Failed to execute database operation");
+                tokio::time::sleep(Duration::from_millis(
+                    conf.retry_interval * u64::from(retry_count),
+                ))
+                .await;
+            }
+            Err(error) => return Err(error),
+        }
+    }
+}
+
+async fn execute_typed_sql(store: &Store, typed_sql: kv::TypedSql) -> StorageResult<()> {
+    execute_db_operation(store, typed_sql.op).await?;
 
-    macro_rules! handle_resp {
-        ($result:expr,$op_type:expr, $table:expr) => {
-            match $result {
-                Ok(aa) => println!("Ok|{}|{}|{:?}|", $op_type, $table, aa),
-                Err(err) => println!("Err|{}|{}|{:?}|", $op_type, $table, err),
+    // The operation is applied again if recording the event fails, which is harmless as applying
+    // the entries of the stream is idempotent
+    match typed_sql.outbox_event {
+        Some(outbox_event) => {
+            let conn = pg_connection(&store.master_pool).await;
+            match outbox_event.insert(&conn).await {
+                // The event was already recorded, but the drainer stopped before trimming the entry
+                Err(error) if matches!(error.current_context(), DatabaseError::UniqueViolation) => {
+                    Ok(())
+                }
+                result => result.map(|_| ()),
             }
-        };
+        }
+        None => Ok(()),
+    }
+}
+
+async fn execute_db_operation(store: &Store, db_op: kv::DBOperation) -> StorageResult<()> {
+    let conn = pg_connection(&store.master_pool).await;
+    match db_op {
+        kv::DBOperation::Insert { insertable } => {
+            let result = match insertable {
+                kv::Insertable::PaymentIntent(a) => a.insert(&conn).await.map(|_| ()),
+                kv::Insertable::PaymentAttempt(a) => a.insert(&conn).await.map(|_| ()),
+                kv::Insertable::Refund(a) => a.insert(&conn).await.map(|_| ()),
+            };
+            match result {
+                // The entry was already applied, but the drainer stopped before trimming it
+                Err(error) if matches!(error.current_context(), DatabaseError::UniqueViolation) => {
+                    Ok(())
+                }
+                result => result,
+            }
+        }
+        kv::DBOperation::Update { updatable } => match updatable {
+            kv::Updateable::PaymentIntentUpdate(a) => {
+                a.orig.update(&conn, a.update_data).await.map(|_| ())
+            }
+            kv::Updateable::PaymentAttemptUpdate(a) => {
+                a.orig.update(&conn, a.update_data).await.map(|_| ())
+            }
+            kv::Updateable::RefundUpdate(a) => {
+                a.orig.update(&conn, a.update_data).await.map(|_| ())
+            }
+        },
+        // A delete does not identify the row to be deleted, so it fails instead of being dropped,
+        // which ends up moving it to the dead letter stream
+        kv::DBOperation::Delete => Err(report!(DatabaseError::Others)
+            .attach_printable("Delete operations are not supported by the drainer")),
     }
-    pub(crate) use handle_resp;
 }
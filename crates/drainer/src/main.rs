@@ -16,9 +16,8 @@ async fn main() -> DrainerResult<()> {
     let store = std::sync::Arc::new(store);
 
     let number_of_streams = store.config.drainer_num_partitions;
-    let max_read_count = conf.drainer.max_read_count;
 
-    start_drainer(store, number_of_streams, max_read_count).await?;
+    start_drainer(store, number_of_streams, conf.drainer).await?;
 
     Ok(())
 }
@@ -13,6 +13,7 @@ pub struct Store {
 pub struct StoreConfig {
     pub drainer_stream_name: String,
     pub drainer_num_partitions: u8,
+    pub dead_letter_stream_name: String,
 }
 
 impl Store {
@@ -23,6 +24,7 @@ impl Store {
             config: StoreConfig {
                 drainer_stream_name: config.drainer.stream_name.clone(),
                 drainer_num_partitions: config.drainer.num_partitions,
+                dead_letter_stream_name: config.drainer.dead_letter_stream_name.clone(),
             },
         }
     }
@@ -44,6 +44,17 @@ pub struct DrainerSettings {
     pub stream_name: String,
     pub num_partitions: u8,
     pub max_read_count: u64,
+    pub max_retries: u8,
+    /// Interval between retries of a failed database operation in milliseconds, which is
+    /// multiplied by the retry count on each retry.
+    pub retry_interval: u64,
+    /// Number of seconds the streams being drained are given to be drained when the drainer is
+    /// shut down, after which the remaining entries are left in the streams
+    pub shutdown_timeout: u64,
+    /// Number of reads of the stream in which an entry can fail to be applied, after which the
+    /// entry is moved to the dead letter stream so that it stops blocking the entries after it
+    pub max_entry_attempts: u32,
+    pub dead_letter_stream_name: String,
 }
 
 impl Default for Database {
@@ -65,6 +76,11 @@ impl Default for DrainerSettings {
             stream_name: "DRAINER_STREAM".into(),
             num_partitions: 64,
             max_read_count: 100,
+            max_retries: 3,
+            retry_interval: 500,
+            shutdown_timeout: 30,
+            max_entry_attempts: 5,
+            dead_letter_stream_name: "DRAINER_DEAD_LETTER_STREAM".into(),
         }
     }
 }
@@ -101,10 +117,24 @@ impl Database {
 
 impl DrainerSettings {
     fn validate(&self) -> Result<(), errors::DrainerError> {
-        common_utils::fp_utils::when(self.stream_name.is_default_or_empty(), || {
+        use common_utils::fp_utils::when;
+
+        when(self.stream_name.is_default_or_empty(), || {
             Err(errors::DrainerError::ConfigParsingError(
                 "drainer stream name must not be empty".into(),
             ))
+        })?;
+
+        when(self.dead_letter_stream_name.is_default_or_empty(), || {
+            Err(errors::DrainerError::ConfigParsingError(
+                "drainer dead letter stream name must not be empty".into(),
+            ))
+        })?;
+
+        when(self.max_entry_attempts == 0, || {
+            Err(errors::DrainerError::ConfigParsingError(
+                "drainer max entry attempts must be greater than zero".into(),
+            ))
         })
     }
 }
@@ -138,7 +168,8 @@ impl Settings {
                     .try_parsing(true)
                     .separator("__")
                     .list_separator(",")
-                    .with_list_parse_key("redis.cluster_urls"),
+                    .with_list_parse_key("redis.cluster_urls")
+                    .with_list_parse_key("redis.sentinel_urls"),
             )
             .build()?;
 
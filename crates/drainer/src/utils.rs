@@ -11,6 +11,9 @@ use crate::{
 pub type StreamEntries = Vec<(String, HashMap<String, String>)>;
 pub type StreamReadResult = HashMap<String, StreamEntries>;
 
+/// Number of seconds the failed attempts of a stream entry are remembered for
+const ENTRY_ATTEMPTS_TTL: i64 = 7 * 24 * 60 * 60;
+
 pub async fn is_stream_available(stream_index: u8, store: Arc<services::Store>) -> bool {
     let stream_key_flag = get_stream_key_flag(store.clone(), stream_index);
 
@@ -68,6 +71,63 @@ pub async fn trim_from_stream(
     Ok(trim_result + 1)
 }
 
+/// Records a failed attempt at applying the stream entry and returns the number of failed
+/// attempts so far.
+pub async fn increment_entry_attempts(
+    stream_name: &str,
+    entry_id: &str,
+    redis: &redis::RedisConnectionPool,
+) -> errors::DrainerResult<i64> {
+    let attempts_key = get_entry_attempts_key(stream_name, entry_id);
+    let attempts = redis
+        .increment_key(attempts_key.as_str())
+        .await
+        .map_err(DrainerError::from)
+        .into_report()?;
+    redis
+        .set_expiry(attempts_key.as_str(), ENTRY_ATTEMPTS_TTL)
+        .await
+        .map_err(DrainerError::from)
+        .into_report()?;
+    Ok(attempts)
+}
+
+/// Appends the stream entry to the dead letter stream, along with the stream it was read from and
+/// the reason it could not be applied, and forgets its failed attempts.
+pub async fn move_to_dead_letter_stream(
+    store: &services::Store,
+    stream_name: &str,
+    entry_id: &str,
+    entry: &HashMap<String, String>,
+    reason: String,
+) -> errors::DrainerResult<()> {
+    let mut fields: Vec<(String, String)> = entry
+        .iter()
+        .map(|(field, value)| (field.clone(), value.clone()))
+        .collect();
+    fields.push(("stream_name".to_string(), stream_name.to_string()));
+    fields.push(("entry_id".to_string(), entry_id.to_string()));
+    fields.push(("reason".to_string(), reason));
+
+    store
+        .redis_conn
+        .stream_append_entry(
+            store.config.dead_letter_stream_name.as_str(),
+            &redis::RedisEntryId::AutoGeneratedID,
+            fields,
+        )
+        .await
+        .map_err(DrainerError::from)
+        .into_report()?;
+
+    store
+        .redis_conn
+        .delete_key(get_entry_attempts_key(stream_name, entry_id).as_str())
+        .await
+        .map_err(DrainerError::from)
+        .into_report()
+}
+
 pub async fn make_stream_available(
     stream_name_flag: &str,
     redis: &redis::RedisConnectionPool,
@@ -110,6 +170,10 @@ pub(crate) fn get_stream_key_flag(store: Arc<services::Store>, stream_index: u8)
     format!("{}_in_use", get_drainer_stream_name(store, stream_index))
 }
 
+fn get_entry_attempts_key(stream_name: &str, entry_id: &str) -> String {
+    format!("{stream_name}_{entry_id}_attempts")
+}
+
 pub(crate) fn get_drainer_stream_name(store: Arc<services::Store>, stream_index: u8) -> String {
     store.drainer_stream(format!("shard_{}", stream_index).as_str())
 }
@@ -23,6 +23,9 @@ mod strong_secret;
 pub use secret::Secret;
 pub use strong_secret::StrongSecret;
 
+mod maskable;
+pub use maskable::{is_sensitive_key, Maskable, MASKED_VALUE};
+
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
@@ -0,0 +1,86 @@
+//!
+//! Masking of the sensitive data in values which are logged or stored for supportability.
+//!
+
+use serde_json::Value;
+
+/// Placeholder which replaces masked values.
+pub const MASKED_VALUE: &str = "*** masked ***";
+
+/// Keys of the fields which hold sensitive data, in lowercase and without separators.
+const SENSITIVE_KEYS: [&str; 24] = [
+    "number",
+    "cardnumber",
+    "accountnumber",
+    "acctnumber",
+    "pan",
+    "cvc",
+    "cvv",
+    "cvc2",
+    "cvv2",
+    "cvn",
+    "csc",
+    "cardcvc",
+    "cardcvv",
+    "securitycode",
+    "cardsecuritycode",
+    "apikey",
+    "secretkey",
+    "privatekey",
+    "password",
+    "secret",
+    "clientsecret",
+    "authorization",
+    "accesstoken",
+    "iban",
+];
+
+/// Values which can be copied with the sensitive data in them masked, so that the copy can be
+/// logged or stored.
+pub trait Maskable {
+    /// Returns a copy of the value, with the values of the fields holding sensitive data masked.
+    fn masked(&self) -> Self;
+}
+
+impl Maskable for Value {
+    fn masked(&self) -> Self {
+        match self {
+            Self::Object(fields) => Self::Object(
+                fields
+                    .iter()
+                    .map(|(key, value)| {
+                        let value = if is_sensitive_key(key) {
+                            mask(value)
+                        } else {
+                            value.masked()
+                        };
+                        (key.clone(), value)
+                    })
+                    .collect(),
+            ),
+            Self::Array(values) => Self::Array(values.iter().map(Self::masked).collect()),
+            value => value.clone(),
+        }
+    }
+}
+
+/// Whether the field with the key holds sensitive data. For keys of nested form fields, such as
+/// `card[number]`, only the innermost key is considered.
+pub fn is_sensitive_key(key: &str) -> bool {
+    let key = key.trim_end_matches(']');
+    let key = key.rsplit('[').next().unwrap_or(key);
+    let key = key
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .collect::<String>()
+        .to_ascii_lowercase();
+
+    SENSITIVE_KEYS.contains(&key.as_str())
+}
+
+fn mask(value: &Value) -> Value {
+    match value {
+        Value::Null => Value::Null,
+        _ => Value::String(MASKED_VALUE.to_string()),
+    }
+}
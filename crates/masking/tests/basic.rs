@@ -144,3 +144,38 @@ fn for_string() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
 
     Ok(())
 }
+
+#[test]
+fn maskable_json() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    use pii::{Maskable, MASKED_VALUE};
+
+    let value = serde_json::json!({
+        "amount": 1000,
+        "card": {
+            "number": "4242424242424242",
+            "cvc": "123",
+            "expiry_month": "10",
+        },
+        "sources": [{ "apiKey": "sk_test_123", "type": "card" }],
+        "email": null,
+    });
+
+    assert_eq!(
+        value.masked(),
+        serde_json::json!({
+            "amount": 1000,
+            "card": {
+                "number": MASKED_VALUE,
+                "cvc": MASKED_VALUE,
+                "expiry_month": "10",
+            },
+            "sources": [{ "apiKey": MASKED_VALUE, "type": "card" }],
+            "email": null,
+        })
+    );
+    assert!(pii::is_sensitive_key("payment_method_data[card][number]"));
+    assert!(pii::is_sensitive_key("card_cvc"));
+    assert!(!pii::is_sensitive_key("payment_method_data[type]"));
+
+    Ok(())
+}
@@ -14,10 +14,12 @@ use common_utils::{
 };
 use error_stack::{IntoReport, ResultExt};
 use fred::{
-    interfaces::{HashesInterface, KeysInterface, StreamsInterface},
+    interfaces::{
+        HashesInterface, KeysInterface, PubsubInterface, SortedSetsInterface, StreamsInterface,
+    },
     types::{
         Expiration, FromRedis, MultipleIDs, MultipleKeys, MultipleOrderedPairs, MultipleStrings,
-        RedisKey, RedisMap, RedisValue, SetOptions, XCap, XReadResponse,
+        RedisKey, RedisMap, RedisValue, SetOptions, XCap, XReadResponse, ZRange,
     },
 };
 use futures::StreamExt;
@@ -168,6 +170,30 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::SetFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn set_key_if_not_exists_with_expiry<V>(
+        &self,
+        key: &str,
+        value: V,
+        seconds: i64,
+    ) -> CustomResult<SetnxReply, errors::RedisError>
+    where
+        V: TryInto<RedisValue> + Debug,
+        V::Error: Into<fred::error::RedisError>,
+    {
+        self.pool
+            .set(
+                key,
+                value,
+                Some(Expiration::EX(seconds)),
+                Some(SetOptions::NX),
+                false,
+            )
+            .await
+            .into_report()
+            .change_context(errors::RedisError::SetFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn set_expiry(
         &self,
@@ -194,6 +220,28 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::SetExpiryFailed)
     }
 
+    /// Returns the remaining time to live of the key in seconds. Returns `-2` if the key does not
+    /// exist, and `-1` if it exists but has no expiry.
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn get_ttl(&self, key: &str) -> CustomResult<i64, errors::RedisError> {
+        self.pool
+            .ttl(key)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::GetFailed)
+    }
+
+    /// Increments the integer value of the key by one and returns the new value. A key which does
+    /// not exist is set to `0` before being incremented.
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn increment_key(&self, key: &str) -> CustomResult<i64, errors::RedisError> {
+        self.pool
+            .incr(key)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::IncrementFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn set_hash_fields<V>(
         &self,
@@ -352,6 +400,72 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::JsonDeserializationFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn add_sorted_set_member(
+        &self,
+        key: &str,
+        score: f64,
+        member: &str,
+    ) -> CustomResult<(), errors::RedisError> {
+        self.pool
+            .zadd(key, None, None, false, false, (score, member))
+            .await
+            .into_report()
+            .change_context(errors::RedisError::SortedSetAddFailed)
+    }
+
+    /// Returns at most `count` members of the sorted set with a score within `min` and `max`,
+    /// ordered from the highest to the lowest score and skipping the first `offset` members. The
+    /// bounds accept the Redis range syntax, such as `-inf`, `+inf` and `(` for exclusive bounds.
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn get_sorted_set_members_by_score_desc(
+        &self,
+        key: &str,
+        max: &str,
+        min: &str,
+        offset: i64,
+        count: i64,
+    ) -> CustomResult<Vec<String>, errors::RedisError> {
+        let max = ZRange::try_from(max)
+            .into_report()
+            .change_context(errors::RedisError::SortedSetReadFailed)?;
+        let min = ZRange::try_from(min)
+            .into_report()
+            .change_context(errors::RedisError::SortedSetReadFailed)?;
+
+        self.pool
+            .zrevrangebyscore(key, max, min, false, Some((offset, count)))
+            .await
+            .into_report()
+            .change_context(errors::RedisError::SortedSetReadFailed)
+    }
+
+    /// Returns at most `count` members of the sorted set with a score within `min` and `max`,
+    /// ordered from the lowest to the highest score and skipping the first `offset` members. The
+    /// bounds accept the same syntax as [`Self::get_sorted_set_members_by_score_desc`].
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn get_sorted_set_members_by_score_asc(
+        &self,
+        key: &str,
+        min: &str,
+        max: &str,
+        offset: i64,
+        count: i64,
+    ) -> CustomResult<Vec<String>, errors::RedisError> {
+        let min = ZRange::try_from(min)
+            .into_report()
+            .change_context(errors::RedisError::SortedSetReadFailed)?;
+        let max = ZRange::try_from(max)
+            .into_report()
+            .change_context(errors::RedisError::SortedSetReadFailed)?;
+
+        self.pool
+            .zrangebyscore(key, min, max, false, Some((offset, count)))
+            .await
+            .into_report()
+            .change_context(errors::RedisError::SortedSetReadFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn stream_append_entry<F>(
         &self,
@@ -576,6 +690,22 @@ impl super::RedisConnectionPool {
             .into_report()
             .change_context(errors::RedisError::ConsumerGroupClaimFailed)
     }
+
+    /// Publishes the message on the channel, returning the number of subscribers which received
+    /// it.
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn publish(
+        &self,
+        channel: &str,
+        message: String,
+    ) -> CustomResult<usize, errors::RedisError> {
+        self.pool
+            .next()
+            .publish(channel, message)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::PublishFailed)
+    }
 }
 
 #[cfg(test)]
@@ -16,6 +16,8 @@ pub enum RedisError {
     GetFailed,
     #[error("Failed to delete key value in Redis")]
     DeleteFailed,
+    #[error("Failed to increment key value in Redis")]
+    IncrementFailed,
     #[error("Failed to append entry to Redis stream")]
     StreamAppendFailed,
     #[error("Failed to read entries from Redis stream")]
@@ -48,8 +50,18 @@ pub enum RedisError {
     SetHashFieldFailed,
     #[error("Failed to get hash field in Redis")]
     GetHashFieldFailed,
+    #[error("Failed to add member to sorted set in Redis")]
+    SortedSetAddFailed,
+    #[error("Failed to get members of sorted set in Redis")]
+    SortedSetReadFailed,
     #[error("The requested value was not found in Redis")]
     NotFound,
     #[error("Invalid RedisEntryId provided")]
     InvalidRedisEntryId,
+    #[error("Failed to ping Redis")]
+    PingFailed,
+    #[error("Failed to publish message on Redis channel")]
+    PublishFailed,
+    #[error("Failed to subscribe to Redis channel")]
+    SubscribeFailed,
 }
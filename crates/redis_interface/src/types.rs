@@ -13,13 +13,24 @@ use crate::errors;
 pub struct RedisSettings {
     pub host: String,
     pub port: u16,
+    /// Password with which the connections are authenticated
+    pub password: Option<String>,
     pub cluster_enabled: bool,
     pub cluster_urls: Vec<String>,
+    /// Discover the primary through Sentinel, and follow it across failovers
+    pub sentinel_enabled: bool,
+    /// Addresses (`host:port`) of the Sentinel instances
+    pub sentinel_urls: Vec<String>,
+    /// Name of the primary monitored by Sentinel
+    pub sentinel_service_name: String,
     pub use_legacy_version: bool,
     pub pool_size: usize,
     pub reconnect_max_attempts: u32,
+    pub reconnect_strategy: ReconnectStrategy,
     /// Reconnect delay in milliseconds
     pub reconnect_delay: u32,
+    /// Maximum reconnect delay in milliseconds, when the delay grows exponentially
+    pub reconnect_max_delay: u32,
     /// TTL in seconds
     pub default_ttl: u32,
     pub stream_read_count: u64,
@@ -42,6 +53,42 @@ impl RedisSettings {
                 "Redis `cluster_urls` must be specified if `cluster_enabled` is `true`".into(),
             ))
             .into_report()
+        })?;
+
+        when(self.cluster_enabled && self.sentinel_enabled, || {
+            Err(errors::RedisError::InvalidConfiguration(
+                "Redis `cluster_enabled` and `sentinel_enabled` cannot both be `true`".into(),
+            ))
+            .into_report()
+        })?;
+
+        when(
+            self.sentinel_enabled && self.sentinel_urls.is_empty(),
+            || {
+                Err(errors::RedisError::InvalidConfiguration(
+                    "Redis `sentinel_urls` must be specified if `sentinel_enabled` is `true`"
+                        .into(),
+                ))
+                .into_report()
+            },
+        )?;
+
+        when(
+            self.sentinel_enabled && self.sentinel_service_name.is_default_or_empty(),
+            || {
+                Err(errors::RedisError::InvalidConfiguration(
+                    "Redis `sentinel_service_name` must be specified if `sentinel_enabled` is `true`"
+                        .into(),
+                ))
+                .into_report()
+            },
+        )?;
+
+        when(self.reconnect_max_delay < self.reconnect_delay, || {
+            Err(errors::RedisError::InvalidConfiguration(
+                "Redis `reconnect_max_delay` must not be less than `reconnect_delay`".into(),
+            ))
+            .into_report()
         })
     }
 }
@@ -51,18 +98,45 @@ impl Default for RedisSettings {
         Self {
             host: "127.0.0.1".to_string(),
             port: 6379,
+            password: None,
             cluster_enabled: false,
             cluster_urls: vec![],
+            sentinel_enabled: false,
+            sentinel_urls: vec![],
+            sentinel_service_name: String::new(),
             use_legacy_version: false,
             pool_size: 5,
             reconnect_max_attempts: 5,
+            reconnect_strategy: ReconnectStrategy::Constant,
             reconnect_delay: 5,
+            reconnect_max_delay: 1000,
             default_ttl: 300,
             stream_read_count: 1,
         }
     }
 }
 
+/// How the delay between the attempts to reconnect to Redis changes.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReconnectStrategy {
+    /// Wait `reconnect_delay` between attempts
+    #[default]
+    Constant,
+    /// Double the delay after every attempt, starting from `reconnect_delay` up to
+    /// `reconnect_max_delay`
+    Exponential,
+}
+
+/// A change in the state of a connection of the Redis pool.
+#[derive(Debug)]
+pub enum RedisConnectionEvent {
+    /// The connection was established again, possibly to a different server after a failover
+    Reconnected { client_id: String },
+    /// The connection failed, it is re-established according to the reconnect policy
+    Error(fred::error::RedisError),
+}
+
 #[derive(Debug)]
 pub enum RedisEntryId {
     UserSpecifiedID {
@@ -26,6 +26,11 @@ async fn main() -> ApplicationResult<()> {
     let conf = Settings::with_config_path(cmd_line.config_path)
         .expect("Unable to construct application configuration");
     #[allow(clippy::expect_used)]
+    let conf = conf
+        .fetch_secrets()
+        .await
+        .expect("Failed to fetch the secrets from the secrets manager");
+    #[allow(clippy::expect_used)]
     conf.validate()
         .expect("Failed to validate router configuration");
 
@@ -38,8 +43,22 @@ async fn main() -> ApplicationResult<()> {
         .await
         .expect("Failed to create the server");
 
+    #[cfg(feature = "grpc")]
+    let grpc_server = {
+        let state = state.clone();
+        tokio::spawn(async move {
+            if let Err(error) = router::grpc::start_server(state).await {
+                logger::error!(%error, "gRPC server stopped");
+            }
+        })
+    };
+
     let _ = server.await;
 
+    #[cfg(feature = "grpc")]
+    grpc_server.abort();
+
+    logger::info!("Server stopped, closing connections");
     state.store.close().await;
 
     Err(ApplicationError::from(std::io::Error::new(
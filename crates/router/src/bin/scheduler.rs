@@ -18,8 +18,20 @@ async fn main() -> CustomResult<(), errors::ProcessTrackerError> {
     #[allow(clippy::expect_used)]
     let conf = Settings::with_config_path(cmd_line.config_path)
         .expect("Unable to construct application configuration");
+    #[allow(clippy::expect_used)]
+    let conf = conf
+        .fetch_secrets()
+        .await
+        .expect("Failed to fetch the secrets from the secrets manager");
+
+    #[allow(clippy::expect_used)]
+    conf.validate()
+        .expect("Failed to validate scheduler configuration");
 
-    let mut state = routes::AppState::new(conf).await;
+    #[allow(clippy::expect_used)]
+    let mut state = routes::AppState::new(conf)
+        .await
+        .expect("Failed to create the application state");
     let _guard =
         logger::setup(&state.conf.log).map_err(|_| errors::ProcessTrackerError::UnexpectedFlow)?;
 
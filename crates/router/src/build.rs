@@ -5,4 +5,9 @@ fn main() {
     println!("cargo:rustc-env=RUST_MIN_STACK=4194304"); // 4 * 1024 * 1024 = 4 MiB
 
     router_env::vergen::generate_cargo_instructions();
+
+    #[cfg(feature = "grpc")]
+    #[allow(clippy::expect_used)]
+    tonic_build::compile_protos("proto/payments.proto")
+        .expect("Failed to compile the gRPC service definitions");
 }
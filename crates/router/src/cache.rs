@@ -0,0 +1,160 @@
+//! In-memory caches of the records which are read while processing every payment, but which
+//! rarely change.
+
+use std::{future::Future, time::Duration};
+
+use futures::StreamExt;
+use router_env::opentelemetry::KeyValue;
+
+use crate::{
+    configs::settings::CacheSettings, logger, routes::metrics, services::Store, types::storage,
+};
+
+/// The Redis channel on which the invalidations of the cache entries are published to all the
+/// instances of the application.
+pub const INVALIDATION_CHANNEL: &str = "cache_invalidation";
+
+/// A cache of values keyed by merchant ID, whose entries expire after the configured TTL.
+///
+/// The entries are invalidated by all the instances when one of them changes the cached records.
+/// The TTL bounds how long an entry stays stale if the invalidation is not received, for example
+/// while the connection to Redis is being re-established.
+#[derive(Clone)]
+pub struct Cache<V> {
+    name: &'static str,
+    inner: moka::future::Cache<String, V>,
+}
+
+impl<V> Cache<V>
+where
+    V: Clone + Send + Sync + 'static,
+{
+    pub fn new(name: &'static str, settings: &CacheSettings) -> Self {
+        Self {
+            name,
+            inner: moka::future::Cache::builder()
+                .max_capacity(settings.max_capacity)
+                .time_to_live(Duration::from_secs(settings.ttl))
+                .build(),
+        }
+    }
+
+    /// Returns the cached value of the key, or the value returned by `populate` which is cached
+    /// if it was obtained successfully.
+    pub async fn get_or_populate<F, Fut, E>(&self, key: &str, populate: F) -> Result<V, E>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<V, E>>,
+    {
+        let attributes = [KeyValue::new("cache", self.name)];
+        if let Some(value) = self.inner.get(key) {
+            metrics::CACHE_HIT_COUNT.add(&metrics::CONTEXT, 1, &attributes);
+            return Ok(value);
+        }
+
+        metrics::CACHE_MISS_COUNT.add(&metrics::CONTEXT, 1, &attributes);
+        let value = populate().await?;
+        self.inner.insert(key.to_string(), value.clone()).await;
+        Ok(value)
+    }
+
+    pub async fn invalidate(&self, key: &str) {
+        self.inner.invalidate(key).await;
+    }
+}
+
+/// The caches of the [`Store`](crate::services::Store).
+#[derive(Clone)]
+pub struct StoreCache {
+    /// Merchant accounts, keyed by merchant ID
+    pub merchant_accounts: Cache<storage::MerchantAccount>,
+    /// All the merchant connector accounts of a merchant, keyed by merchant ID
+    pub merchant_connector_accounts: Cache<Vec<storage::MerchantConnectorAccount>>,
+}
+
+impl StoreCache {
+    pub fn new(settings: &CacheSettings) -> Self {
+        Self {
+            merchant_accounts: Cache::new("merchant_account", settings),
+            merchant_connector_accounts: Cache::new("merchant_connector_account", settings),
+        }
+    }
+
+    async fn invalidate(&self, invalidation: &CacheInvalidation) {
+        match invalidation.cache {
+            CacheKind::MerchantAccount => {
+                self.merchant_accounts.invalidate(&invalidation.key).await
+            }
+            CacheKind::MerchantConnectorAccount => {
+                self.merchant_connector_accounts
+                    .invalidate(&invalidation.key)
+                    .await
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CacheKind {
+    MerchantAccount,
+    MerchantConnectorAccount,
+}
+
+/// The message published on [`INVALIDATION_CHANNEL`] when a cached record is changed.
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct CacheInvalidation {
+    pub cache: CacheKind,
+    pub key: String,
+}
+
+impl Store {
+    /// Invalidates the cache entry on this instance, and publishes the invalidation for the other
+    /// instances.
+    ///
+    /// Failing to publish the invalidation is only logged, as the record has already been changed
+    /// by then. The other instances pick up the change once their entry expires.
+    pub(crate) async fn invalidate_cache(&self, cache: CacheKind, key: &str) {
+        let invalidation = CacheInvalidation {
+            cache,
+            key: key.to_string(),
+        };
+        self.cache.invalidate(&invalidation).await;
+
+        let message = match serde_json::to_string(&invalidation) {
+            Ok(message) => message,
+            Err(error) => {
+                logger::error!(%error, "Failed to serialize cache invalidation");
+                return;
+            }
+        };
+        if let Err(error) = self.redis_conn.publish(INVALIDATION_CHANNEL, message).await {
+            logger::error!(
+                ?error,
+                ?invalidation,
+                "Failed to publish cache invalidation"
+            );
+        }
+    }
+}
+
+/// Applies the invalidations published by the instances of the application, including the ones
+/// published by this instance, which have already been applied.
+pub async fn listen_for_invalidations(
+    cache: StoreCache,
+    messages: impl futures::Stream<Item = String>,
+) {
+    messages
+        .for_each(|message| {
+            let cache = cache.clone();
+            async move {
+                match serde_json::from_str::<CacheInvalidation>(&message) {
+                    Ok(invalidation) => cache.invalidate(&invalidation).await,
+                    Err(error) => {
+                        logger::error!(%error, %message, "Failed to parse cache invalidation")
+                    }
+                }
+            }
+        })
+        .await
+}
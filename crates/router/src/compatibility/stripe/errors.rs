@@ -52,6 +52,9 @@ pub enum StripeErrorCode {
     #[error(error_type = StripeErrorType::ApiError, code = "internal_server_error", message = "Server is down")]
     InternalServerError,
 
+    #[error(error_type = StripeErrorType::ApiError, code = "service_unavailable", message = "Server is overloaded")]
+    ServiceUnavailable,
+
     #[error(error_type = StripeErrorType::ApiError, code = "internal_server_error", message = "Server is down")]
     DuplicateRefundRequest,
 
@@ -67,6 +70,9 @@ pub enum StripeErrorCode {
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "client_secret_invalid", message = "Expected client secret to be included in the request")]
     ClientSecretNotFound,
 
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "client_secret_invalid", message = "The client secret has expired")]
+    ClientSecretExpired,
+
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such customer")]
     CustomerNotFound,
 
@@ -88,6 +94,30 @@ pub enum StripeErrorCode {
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such mandate")]
     MandateNotFound,
 
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such dispute")]
+    DisputeNotFound,
+
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such event")]
+    EventNotFound,
+
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such payout")]
+    PayoutNotFound,
+
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such payment link")]
+    PaymentLinkNotFound,
+
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such export")]
+    ExportNotFound,
+
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such API key")]
+    ApiKeyNotFound,
+
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such blocklist entry")]
+    BlocklistEntryNotFound,
+
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such recon report")]
+    ReconReportNotFound,
+
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "parameter_missing", message = "Return url is not available")]
     ReturnUrlUnavailable,
 
@@ -100,6 +130,9 @@ pub enum StripeErrorCode {
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "token_already_used", message = "duplicate payment method")]
     DuplicatePaymentMethod,
 
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "token_already_used", message = "duplicate blocklist entry")]
+    DuplicateBlocklistEntry,
+
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "" , message = "deserialization failed: {error_message}")]
     SerdeQsError {
         error_message: String,
@@ -159,6 +192,8 @@ pub enum StripeErrorCode {
     PaymentIntentMandateInvalid { message: String },
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "", message = "The payment with the specified payment_id '{payment_id}' already exists in our records.")]
     DuplicatePayment { payment_id: String },
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "", message = "The payout with the specified payout_id '{payout_id}' already exists in our records.")]
+    DuplicatePayout { payout_id: String },
     // [#216]: https://github.com/juspay/hyperswitch/issues/216
     // Implement the remaining stripe error codes
 
@@ -321,7 +356,8 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
         match value {
             errors::ApiErrorResponse::Unauthorized
             | errors::ApiErrorResponse::InvalidJwtToken
-            | errors::ApiErrorResponse::InvalidEphermeralKey => Self::Unauthorized,
+            | errors::ApiErrorResponse::InvalidEphermeralKey
+            | errors::ApiErrorResponse::WebhookAuthenticationFailed => Self::Unauthorized,
             errors::ApiErrorResponse::InvalidRequestUrl
             | errors::ApiErrorResponse::InvalidHttpMethod => Self::InvalidRequestUrl,
             errors::ApiErrorResponse::MissingRequiredField { field_name } => {
@@ -357,8 +393,12 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::CardExpired { data } => Self::ExpiredCard,
             errors::ApiErrorResponse::RefundNotPossible { connector } => Self::RefundFailed,
             errors::ApiErrorResponse::RefundFailed { data } => Self::RefundFailed, // Nothing at stripe to map
+            errors::ApiErrorResponse::DisputeEvidenceSubmissionFailed { data } => {
+                Self::InternalServerError
+            } // not a stripe code
 
             errors::ApiErrorResponse::InternalServerError => Self::InternalServerError, // not a stripe code
+            errors::ApiErrorResponse::ServiceUnavailable => Self::ServiceUnavailable,
             errors::ApiErrorResponse::IncorrectConnectorNameGiven => Self::InternalServerError,
             errors::ApiErrorResponse::MandateActive => Self::MandateActive, //not a stripe code
             errors::ApiErrorResponse::CustomerRedacted => Self::CustomerRedacted, //not a stripe code
@@ -374,6 +414,14 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
                 Self::MerchantConnectorAccountNotFound
             }
             errors::ApiErrorResponse::MandateNotFound => Self::MandateNotFound,
+            errors::ApiErrorResponse::DisputeNotFound => Self::DisputeNotFound,
+            errors::ApiErrorResponse::EventNotFound => Self::EventNotFound,
+            errors::ApiErrorResponse::PayoutNotFound => Self::PayoutNotFound,
+            errors::ApiErrorResponse::PaymentLinkNotFound => Self::PaymentLinkNotFound,
+            errors::ApiErrorResponse::ExportNotFound => Self::ExportNotFound,
+            errors::ApiErrorResponse::ApiKeyNotFound => Self::ApiKeyNotFound,
+            errors::ApiErrorResponse::BlocklistEntryNotFound => Self::BlocklistEntryNotFound,
+            errors::ApiErrorResponse::ReconReportNotFound => Self::ReconReportNotFound,
             errors::ApiErrorResponse::MandateValidationFailed { reason } => {
                 Self::PaymentIntentMandateInvalid { message: reason }
             }
@@ -383,9 +431,11 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
                 Self::DuplicateMerchantConnectorAccount
             }
             errors::ApiErrorResponse::DuplicatePaymentMethod => Self::DuplicatePaymentMethod,
+            errors::ApiErrorResponse::DuplicateBlocklistEntry => Self::DuplicateBlocklistEntry,
             errors::ApiErrorResponse::ClientSecretInvalid => Self::PaymentIntentInvalidParameter {
                 param: "client_secret".to_owned(),
             },
+            errors::ApiErrorResponse::ClientSecretExpired => Self::ClientSecretExpired,
             errors::ApiErrorResponse::InvalidRequestData { message } => {
                 Self::InvalidRequestData { message }
             }
@@ -416,6 +466,9 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::DuplicatePayment { payment_id } => {
                 Self::DuplicatePayment { payment_id }
             }
+            errors::ApiErrorResponse::DuplicatePayout { payout_id } => {
+                Self::DuplicatePayout { payout_id }
+            }
         }
     }
 }
@@ -438,14 +491,24 @@ impl actix_web::ResponseError for StripeErrorCode {
             | Self::RefundNotFound
             | Self::CustomerNotFound
             | Self::ClientSecretNotFound
+            | Self::ClientSecretExpired
             | Self::PaymentNotFound
             | Self::PaymentMethodNotFound
             | Self::MerchantAccountNotFound
             | Self::MerchantConnectorAccountNotFound
             | Self::MandateNotFound
+            | Self::DisputeNotFound
+            | Self::EventNotFound
+            | Self::PayoutNotFound
+            | Self::PaymentLinkNotFound
+            | Self::ExportNotFound
+            | Self::ApiKeyNotFound
+            | Self::BlocklistEntryNotFound
+            | Self::ReconReportNotFound
             | Self::DuplicateMerchantAccount
             | Self::DuplicateMerchantConnectorAccount
             | Self::DuplicatePaymentMethod
+            | Self::DuplicateBlocklistEntry
             | Self::PaymentFailed
             | Self::VerificationFailed { .. }
             | Self::MaximumRefundCount
@@ -459,12 +522,15 @@ impl actix_web::ResponseError for StripeErrorCode {
             | Self::ResourceIdNotFound
             | Self::PaymentIntentMandateInvalid { .. }
             | Self::PaymentIntentUnexpectedState { .. }
-            | Self::DuplicatePayment { .. } => StatusCode::BAD_REQUEST,
+            | Self::DuplicatePayment { .. }
+            | Self::DuplicatePayout { .. } => StatusCode::BAD_REQUEST,
             Self::RefundFailed
             | Self::InternalServerError
             | Self::MandateActive
             | Self::CustomerRedacted => StatusCode::INTERNAL_SERVER_ERROR,
-            Self::ReturnUrlUnavailable => StatusCode::SERVICE_UNAVAILABLE,
+            Self::ReturnUrlUnavailable | Self::ServiceUnavailable => {
+                StatusCode::SERVICE_UNAVAILABLE
+            }
         }
     }
 
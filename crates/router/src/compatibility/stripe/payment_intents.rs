@@ -67,6 +67,7 @@ pub async fn payment_intents_retrieve(
     state: web::Data<routes::AppState>,
     req: HttpRequest,
     path: web::Path<String>,
+    query_payload: web::Query<types::StripePaymentRetrieveBody>,
 ) -> HttpResponse {
     let payload = payment_types::PaymentsRetrieveRequest {
         resource_id: api_types::PaymentIdType::PaymentIntentId(path.to_string()),
@@ -74,12 +75,14 @@ pub async fn payment_intents_retrieve(
         force_sync: true,
         connector: None,
         param: None,
+        client_secret: query_payload.into_inner().client_secret,
     };
 
-    let (auth_type, auth_flow) = match auth::get_auth_type_and_flow(req.headers()) {
-        Ok(auth) => auth,
-        Err(err) => return api::log_and_return_error_response(report!(err)),
-    };
+    let (auth_type, auth_flow) =
+        match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
+            Ok(auth) => auth,
+            Err(err) => return api::log_and_return_error_response(err),
+        };
 
     wrap::compatibility_api_wrap::<
         _,
@@ -134,11 +137,6 @@ pub async fn payment_intents_update(
 
     payload.payment_id = Some(api_types::PaymentIdType::PaymentIntentId(payment_id));
 
-    let (auth_type, auth_flow) = match auth::get_auth_type_and_flow(req.headers()) {
-        Ok(auth) => auth,
-        Err(err) => return api::log_and_return_error_response(report!(err)),
-    };
-
     wrap::compatibility_api_wrap::<
         _,
         _,
@@ -157,11 +155,11 @@ pub async fn payment_intents_update(
                 merchant_account,
                 payments::PaymentUpdate,
                 req,
-                auth_flow,
+                api::AuthFlow::Merchant,
                 payments::CallConnectorAction::Trigger,
             )
         },
-        &*auth_type,
+        &auth::ApiKeyAuth,
     )
     .await
 }
@@ -297,11 +295,6 @@ pub async fn payment_intents_cancel(
     let mut payload: payment_types::PaymentsCancelRequest = stripe_payload.into();
     payload.payment_id = payment_id;
 
-    let (auth_type, auth_flow) = match auth::get_auth_type_and_flow(req.headers()) {
-        Ok(auth) => auth,
-        Err(err) => return api::log_and_return_error_response(report!(err)),
-    };
-
     wrap::compatibility_api_wrap::<
         _,
         _,
@@ -320,11 +313,11 @@ pub async fn payment_intents_cancel(
                 merchant_account,
                 payments::PaymentCancel,
                 req,
-                auth_flow,
+                api::AuthFlow::Merchant,
                 payments::CallConnectorAction::Trigger,
             )
         },
-        &*auth_type,
+        &auth::ApiKeyAuth,
     )
     .await
 }
@@ -1,5 +1,5 @@
 use api_models::{payments, refunds};
-use common_utils::ext_traits::StringExt;
+use common_utils::{ext_traits::StringExt, types::MinorUnit};
 use error_stack::ResultExt;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -212,7 +212,9 @@ pub enum StripePaymentStatus {
 impl From<api_enums::IntentStatus> for StripePaymentStatus {
     fn from(item: api_enums::IntentStatus) -> Self {
         match item {
-            api_enums::IntentStatus::Succeeded => Self::Succeeded,
+            api_enums::IntentStatus::Succeeded | api_enums::IntentStatus::PartiallyCaptured => {
+                Self::Succeeded
+            }
             api_enums::IntentStatus::Failed => Self::Canceled,
             api_enums::IntentStatus::Processing => Self::Processing,
             api_enums::IntentStatus::RequiresCustomerAction => Self::RequiresAction,
@@ -257,6 +259,11 @@ impl From<StripePaymentCancelRequest> for payments::PaymentsCancelRequest {
     }
 }
 
+#[derive(Debug, Default, Deserialize)]
+pub struct StripePaymentRetrieveBody {
+    pub client_secret: Option<String>,
+}
+
 #[derive(Default, PartialEq, Eq, Deserialize, Clone)]
 pub struct StripeCaptureRequest {
     pub amount_to_capture: Option<i64>,
@@ -284,9 +291,9 @@ impl From<payments::PaymentsResponse> for StripePaymentIntentResponse {
     fn from(resp: payments::PaymentsResponse) -> Self {
         Self {
             object: "payment_intent".to_owned(),
-            amount: resp.amount,
-            amount_received: resp.amount_received,
-            amount_capturable: resp.amount_capturable,
+            amount: resp.amount.get_amount_as_i64(),
+            amount_received: resp.amount_received.map(MinorUnit::get_amount_as_i64),
+            amount_capturable: resp.amount_capturable.map(MinorUnit::get_amount_as_i64),
             currency: resp.currency.to_lowercase(),
             status: StripePaymentStatus::from(resp.status),
             client_secret: resp.client_secret,
@@ -335,6 +342,12 @@ impl TryFrom<StripePaymentListConstraints> for payments::PaymentListConstraints
             created_gt: from_timestamp_to_datetime(item.created_gt)?,
             created_lte: from_timestamp_to_datetime(item.created_lte)?,
             created_gte: from_timestamp_to_datetime(item.created_gte)?,
+            status: None,
+            currency: None,
+            connector: None,
+            amount_lte: None,
+            amount_gte: None,
+            test_mode: None,
         })
     }
 }
@@ -369,7 +382,7 @@ impl From<payments::PaymentListResponse> for StripePaymentIntentListResponse {
         Self {
             object: "list".to_string(),
             url: "/v1/payment_intents".to_string(),
-            has_more: false,
+            has_more: it.has_more,
             data: it.data.into_iter().map(Into::into).collect(),
         }
     }
@@ -184,7 +184,9 @@ pub enum StripeSetupStatus {
 impl From<api_enums::IntentStatus> for StripeSetupStatus {
     fn from(item: api_enums::IntentStatus) -> Self {
         match item {
-            api_enums::IntentStatus::Succeeded => Self::Succeeded,
+            api_enums::IntentStatus::Succeeded | api_enums::IntentStatus::PartiallyCaptured => {
+                Self::Succeeded
+            }
             api_enums::IntentStatus::Failed => Self::Canceled,
             api_enums::IntentStatus::Processing => Self::Processing,
             api_enums::IntentStatus::RequiresCustomerAction => Self::RequiresAction,
@@ -232,6 +234,11 @@ impl From<StripePaymentCancelRequest> for payments::PaymentsCancelRequest {
         }
     }
 }
+
+#[derive(Debug, Default, Deserialize)]
+pub struct StripePaymentRetrieveBody {
+    pub client_secret: Option<String>,
+}
 #[derive(Default, Eq, PartialEq, Serialize)]
 pub struct StripeSetupIntentResponse {
     pub id: Option<String>,
@@ -295,6 +302,12 @@ impl TryFrom<StripePaymentListConstraints> for payments::PaymentListConstraints
             created_gt: from_timestamp_to_datetime(item.created_gt)?,
             created_lte: from_timestamp_to_datetime(item.created_lte)?,
             created_gte: from_timestamp_to_datetime(item.created_gte)?,
+            status: None,
+            currency: None,
+            connector: None,
+            amount_lte: None,
+            amount_gte: None,
+            test_mode: None,
         })
     }
 }
@@ -26,6 +26,7 @@ where
     S: From<Q> + Serialize,
     E: From<errors::ApiErrorResponse> + Serialize + error_stack::Context + actix_web::ResponseError,
     T: std::fmt::Debug,
+    U: auth::AuthInfo,
 {
     let resp = api::server_wrap_util(state, request, payload, func, api_authentication).await;
     match resp {
@@ -68,6 +69,14 @@ where
         Ok(api::ApplicationResponse::Form(form_data)) => api::build_redirection_form(&form_data)
             .respond_to(request)
             .map_into_boxed_body(),
+        Ok(api::ApplicationResponse::PaymentLinkForm(form_data)) => {
+            api::build_payment_link_form(&form_data)
+                .respond_to(request)
+                .map_into_boxed_body()
+        }
+        Ok(api::ApplicationResponse::FileData(file_data)) => {
+            api::http_response_file_data(file_data)
+        }
         Err(error) => {
             logger::error!(api_response_error=?error);
             let pg_error = E::from(error.current_context().clone());
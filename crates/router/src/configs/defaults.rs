@@ -6,6 +6,7 @@ impl Default for super::settings::Server {
             host: "localhost".into(),
             request_body_limit: 16 * 1024, // POST request body is limited to 16KiB
             base_url: "http://localhost:8080".into(),
+            shutdown_timeout: 45,
         }
     }
 }
@@ -19,6 +20,34 @@ impl Default for super::settings::Database {
             port: 5432,
             dbname: String::new(),
             pool_size: 5,
+            connection_timeout: 10,
+        }
+    }
+}
+
+impl Default for super::settings::ReplicaLagSettings {
+    fn default() -> Self {
+        Self {
+            max_lag: 5,
+            check_interval: 1,
+        }
+    }
+}
+
+impl Default for super::settings::CacheSettings {
+    fn default() -> Self {
+        Self {
+            ttl: 300,
+            max_capacity: 10_000,
+        }
+    }
+}
+
+impl Default for super::settings::HealthCheckSettings {
+    fn default() -> Self {
+        Self {
+            canary_connector_url: None,
+            canary_timeout: 2000,
         }
     }
 }
@@ -28,6 +57,12 @@ impl Default for super::settings::Secrets {
         Self {
             jwt_secret: "secret".into(),
             admin_api_key: "test_admin".into(),
+            debug_trace_key: "".into(),
+            master_enc_key: "73ad7bbbbc640c845a150f67d058b279849370cd2c1f3c67c4dd6c869213e13a"
+                .into(),
+            export_signing_key: "export_secret".into(),
+            card_fingerprint_key: "card_fingerprint_secret".into(),
+            anonymization_key: "anonymization_secret".into(),
         }
     }
 }
@@ -45,7 +80,7 @@ impl Default for super::settings::Locker {
 impl Default for super::settings::SupportedConnectors {
     fn default() -> Self {
         Self {
-            wallets: ["klarna", "braintree"].map(Into::into).into(),
+            wallets: ["klarna", "braintree", "applepay"].map(Into::into).into(),
             /* cards: [
                 "adyen",
                 "authorizedotnet",
@@ -67,6 +102,65 @@ impl Default for super::settings::Refund {
         Self {
             max_attempts: 10,
             max_age: 365,
+            max_batch_size: 100,
+            batch_connector_rate_limit: 10,
+            batch_interval: 60,
+        }
+    }
+}
+
+impl Default for super::settings::WebhooksSettings {
+    fn default() -> Self {
+        Self {
+            outgoing_max_attempts: 5,
+            outgoing_retry_interval: 60,
+            outgoing_max_retry_interval: 3600,
+            incoming_event_lock_ttl: 3600,
+        }
+    }
+}
+
+impl Default for super::settings::ExportSettings {
+    fn default() -> Self {
+        Self {
+            max_time_range_days: 90,
+            max_rows: 100_000,
+            download_url_validity: 900,
+        }
+    }
+}
+
+impl Default for super::settings::SecretsManagerSettings {
+    fn default() -> Self {
+        Self {
+            backend: super::settings::SecretsManagerBackend::None,
+            #[cfg(feature = "aws_secrets_manager")]
+            aws_region: String::new(),
+            vault_url: String::new(),
+            vault_token: String::new(),
+            vault_mount: "secret".to_string(),
+        }
+    }
+}
+
+impl Default for super::settings::ForexSettings {
+    fn default() -> Self {
+        Self {
+            base_url: "".into(),
+            api_key: "".into(),
+            cache: super::settings::CacheSettings {
+                ttl: 3600,
+                max_capacity: 1000,
+            },
+        }
+    }
+}
+
+impl Default for super::settings::ConnectorCredentialsSettings {
+    fn default() -> Self {
+        Self {
+            encryption: super::settings::CredentialsEncryptionService::Local,
+            cache: super::settings::CacheSettings::default(),
         }
     }
 }
@@ -77,12 +171,30 @@ impl Default for super::settings::EphemeralConfig {
     }
 }
 
+impl Default for super::settings::ClientSecretConfig {
+    fn default() -> Self {
+        Self { validity: 900 }
+    }
+}
+
+impl Default for super::settings::AccessTokenSettings {
+    fn default() -> Self {
+        Self {
+            refresh_threshold: 60,
+            refresh_lock_ttl: 30,
+            refresh_lock_wait_attempts: 10,
+            refresh_lock_wait_interval: 500,
+        }
+    }
+}
+
 impl Default for super::settings::SchedulerSettings {
     fn default() -> Self {
         Self {
             stream: "SCHEDULER_STREAM".into(),
             consumer_group: "SCHEDULER_GROUP".into(),
             producer: super::settings::ProducerSettings::default(),
+            outbox_relay: super::settings::OutboxRelaySettings::default(),
         }
     }
 }
@@ -99,6 +211,40 @@ impl Default for super::settings::ProducerSettings {
     }
 }
 
+impl Default for super::settings::OutboxRelaySettings {
+    fn default() -> Self {
+        Self {
+            stream: "OUTBOX_EVENTS_STREAM".into(),
+            batch_size: 100,
+            lock_key: "OUTBOX_RELAY_LOCKING_KEY".into(),
+            lock_ttl: 160,
+        }
+    }
+}
+
+#[cfg(feature = "kafka_events")]
+impl Default for super::settings::KafkaSettings {
+    fn default() -> Self {
+        Self {
+            brokers: vec!["localhost:9092".into()],
+            intent_analytics_topic: "hyperswitch-payment-intent-events".into(),
+            attempt_analytics_topic: "hyperswitch-payment-attempt-events".into(),
+            refund_analytics_topic: "hyperswitch-refund-events".into(),
+            anonymize_events: false,
+        }
+    }
+}
+
+#[cfg(feature = "grpc")]
+impl Default for super::settings::GrpcServerSettings {
+    fn default() -> Self {
+        Self {
+            host: "127.0.0.1".into(),
+            port: 50051,
+        }
+    }
+}
+
 #[cfg(feature = "kv_store")]
 impl Default for super::settings::DrainerSettings {
     fn default() -> Self {
@@ -106,6 +252,8 @@ impl Default for super::settings::DrainerSettings {
             stream_name: "DRAINER_STREAM".into(),
             num_partitions: 64,
             max_read_count: 100,
+            hash_tagged_keys: false,
+            write_through: false,
         }
     }
 }
@@ -36,19 +36,35 @@ pub struct Settings {
     pub proxy: Proxy,
     pub env: Env,
     pub master_database: Database,
-    #[cfg(feature = "olap")]
     pub replica_database: Database,
+    pub replica_lag: ReplicaLagSettings,
     pub redis: RedisSettings,
+    pub cache: CacheSettings,
+    pub health_check: HealthCheckSettings,
     pub log: Log,
     pub secrets: Secrets,
+    pub secrets_manager: SecretsManagerSettings,
     pub locker: Locker,
     pub connectors: Connectors,
     pub refund: Refund,
     pub eph_key: EphemeralConfig,
+    pub client_secret: ClientSecretConfig,
+    pub access_token: AccessTokenSettings,
     pub scheduler: Option<SchedulerSettings>,
+    pub webhooks: WebhooksSettings,
+    pub exports: ExportSettings,
+    pub velocity: VelocitySettings,
+    pub customers: CustomerSettings,
+    pub debug_trace: DebugTraceSettings,
+    pub forex: ForexSettings,
+    pub connector_credentials: ConnectorCredentialsSettings,
     #[cfg(feature = "kv_store")]
     pub drainer: DrainerSettings,
     pub jwekey: Jwekey,
+    #[cfg(feature = "kafka_events")]
+    pub kafka: KafkaSettings,
+    #[cfg(feature = "grpc")]
+    pub grpc_server: GrpcServerSettings,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -56,6 +72,47 @@ pub struct Settings {
 pub struct Secrets {
     pub jwt_secret: String,
     pub admin_api_key: String,
+    pub debug_trace_key: String,
+    /// Hex encoded 256-bit key used to encrypt sensitive merchant data stored in the database,
+    /// such as the custom headers of outgoing webhooks and the data keys with which the personal
+    /// data of the merchants' customers is encrypted
+    pub master_enc_key: String,
+    /// Key used to sign the download URLs of merchant data exports
+    pub export_signing_key: String,
+    /// Key used to compute the fingerprints of card numbers added to the blocklists of merchants
+    pub card_fingerprint_key: String,
+    /// Key used to pseudonymize the identifiers and card data in anonymized exports and events
+    pub anonymization_key: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct SecretsManagerSettings {
+    /// Service from which the secrets are fetched at startup. When set, the database passwords,
+    /// the Redis password, `secrets.jwt_secret` and `secrets.master_enc_key` hold the names of
+    /// the secrets instead of their values.
+    pub backend: SecretsManagerBackend,
+    /// Region of AWS Secrets Manager
+    #[cfg(feature = "aws_secrets_manager")]
+    pub aws_region: String,
+    /// Address of the HashiCorp Vault server
+    pub vault_url: String,
+    /// Token with which the application authenticates with HashiCorp Vault
+    pub vault_token: String,
+    /// Path at which the KV version 2 secrets engine is mounted in HashiCorp Vault
+    pub vault_mount: String,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SecretsManagerBackend {
+    /// The secrets are read from the settings
+    None,
+    /// Environment variables named after the secrets
+    Env,
+    #[cfg(feature = "aws_secrets_manager")]
+    AwsSecretsManager,
+    HashicorpVault,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -71,6 +128,116 @@ pub struct Locker {
 pub struct Refund {
     pub max_attempts: usize,
     pub max_age: i64,
+    pub max_batch_size: usize,
+    pub batch_connector_rate_limit: usize,
+    pub batch_interval: i64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct WebhooksSettings {
+    pub outgoing_max_attempts: i32,
+    pub outgoing_retry_interval: i64,
+    pub outgoing_max_retry_interval: i64,
+    /// Number of seconds for which an incoming webhook event is remembered in redis, for
+    /// discarding redeliveries of the event without querying the database
+    pub incoming_event_lock_ttl: i64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ExportSettings {
+    /// Maximum number of days covered by the time range of an export
+    pub max_time_range_days: i64,
+    /// Maximum number of records in an export, exports exceeding it fail so that the merchant can
+    /// request smaller time ranges
+    pub max_rows: i64,
+    /// Number of seconds for which the download URL of an export is valid
+    pub download_url_validity: i64,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct VelocitySettings {
+    /// Limits on the number of payments attempted with the same card, by the same customer or
+    /// from the same IP address. Payments exceeding any of the limits are declined.
+    pub rules: Vec<VelocityRule>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct CustomerSettings {
+    /// Reject payments that reference an unknown customer ID instead of creating the customer
+    pub require_existing_customer: bool,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct VelocityRule {
+    /// What the payments are counted by
+    pub key: VelocityKey,
+    /// Maximum number of payments attempted within the window
+    pub max_attempts: u32,
+    /// Number of seconds over which the payments are counted
+    pub window: i64,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum VelocityKey {
+    /// The card number, the payments made with other payment methods are not counted
+    Card,
+    /// The customer, the payments made without a customer are not counted
+    Customer,
+    /// The IP address of the customer, the payments made without browser information are not
+    /// counted
+    IpAddress,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct DebugTraceSettings {
+    /// Merchants whose requests are traced as if they carried a valid `X-Debug-Trace` header, with
+    /// the given probability
+    pub sampling_rules: Vec<DebugTraceSamplingRule>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct DebugTraceSamplingRule {
+    pub merchant_id: String,
+    /// Fraction of the requests of the merchant which are traced, between 0 and 1
+    pub sample_rate: f64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ForexSettings {
+    /// Base URL of the exchange rate provider, payments cannot be settled in another currency when
+    /// it is empty. The rates are fetched from `{base_url}latest?base={from}&symbols={to}`, which
+    /// must respond with a JSON object holding the rates in a `rates` object keyed by currency.
+    pub base_url: String,
+    /// API key sent to the exchange rate provider as a bearer token, if not empty
+    pub api_key: String,
+    /// In-memory cache of the exchange rates, keyed by currency pair
+    pub cache: CacheSettings,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ConnectorCredentialsSettings {
+    /// Service with which the credentials of merchant connector accounts are encrypted
+    pub encryption: CredentialsEncryptionService,
+    /// In-memory cache of the decrypted credentials, keyed by the digest of the ciphertext
+    pub cache: CacheSettings,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CredentialsEncryptionService {
+    /// Encrypted with `secrets.master_enc_key`
+    Local,
+    /// Encrypted with the AWS KMS key configured in `jwekey`, requires the `kms` feature
+    Kms,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -79,6 +246,28 @@ pub struct EphemeralConfig {
     pub validity: i64,
 }
 
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ClientSecretConfig {
+    /// Number of seconds for which the client secret of a payment can be used
+    pub validity: i64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct AccessTokenSettings {
+    /// Number of seconds before expiry at which a connector access token is refreshed, so that
+    /// it does not expire while a payment is being processed
+    pub refresh_threshold: i64,
+    /// Number of seconds after which the lock taken while refreshing an access token expires
+    pub refresh_lock_ttl: i64,
+    /// Number of times to check for the refreshed access token, while another request holds the
+    /// lock for refreshing it
+    pub refresh_lock_wait_attempts: u32,
+    /// Number of milliseconds to wait between two checks for the refreshed access token
+    pub refresh_lock_wait_interval: u64,
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 #[serde(default)]
 pub struct Jwekey {
@@ -109,6 +298,10 @@ pub struct Server {
     pub host: String,
     pub request_body_limit: usize,
     pub base_url: String,
+    /// Number of seconds the in-flight requests are given to complete when the server is shut
+    /// down, after which they are dropped. Should exceed the timeout of the connector calls, so
+    /// that a payment is not dropped while it is being authorized.
+    pub shutdown_timeout: u64,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -120,6 +313,39 @@ pub struct Database {
     pub port: u16,
     pub dbname: String,
     pub pool_size: u32,
+    /// Number of seconds to wait for a connection from the pool, before giving up
+    pub connection_timeout: u64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ReplicaLagSettings {
+    /// Number of seconds the replica may lag behind the primary while still serving list and
+    /// analytics queries. They are served by the primary while the replica lags behind by more
+    /// than this.
+    pub max_lag: u32,
+    /// Number of seconds between the checks of the replica lag
+    pub check_interval: u64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct CacheSettings {
+    /// Number of seconds after which a cached record is fetched from the database again, which
+    /// bounds how long the changes made through other instances take to be visible
+    pub ttl: u64,
+    /// Maximum number of entries of each cache
+    pub max_capacity: u64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct HealthCheckSettings {
+    /// URL of a connector which is called by the readiness check, to check that the connectors
+    /// can be reached. Any response other than a server error counts as reachable.
+    pub canary_connector_url: Option<String>,
+    /// Number of milliseconds to wait for the response of the canary connector
+    pub canary_timeout: u64,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -138,12 +364,18 @@ pub struct Connectors {
     pub braintree: ConnectorParams,
     pub checkout: ConnectorParams,
     pub cybersource: ConnectorParams,
+    #[cfg(feature = "dummy_connector")]
+    pub dummy_connector: ConnectorParams,
     pub fiserv: ConnectorParams,
     pub globalpay: ConnectorParams,
     pub klarna: ConnectorParams,
+    pub netcetera: ConnectorParams,
+    pub paypal: ConnectorParams,
     pub payu: ConnectorParams,
     pub rapyd: ConnectorParams,
+    pub razorpay: ConnectorParams,
     pub shift4: ConnectorParams,
+    pub signifyd: ConnectorParams,
     pub stripe: ConnectorParams,
     pub worldline: ConnectorParams,
     pub worldpay: ConnectorParams,
@@ -156,6 +388,61 @@ pub struct Connectors {
 #[serde(default)]
 pub struct ConnectorParams {
     pub base_url: String,
+    /// Base URL for the flows which the connector serves from a different API, such as payouts
+    pub secondary_base_url: Option<String>,
+    /// Base URL of the sandbox of the connector, which is called for the merchant connector
+    /// accounts in test mode. Left unset for connectors serving live and test traffic alike.
+    pub sandbox_base_url: Option<String>,
+    /// Secondary base URL of the sandbox of the connector
+    pub sandbox_secondary_base_url: Option<String>,
+}
+
+impl ConnectorParams {
+    fn sandbox(&self) -> Self {
+        Self {
+            base_url: self
+                .sandbox_base_url
+                .clone()
+                .unwrap_or_else(|| self.base_url.clone()),
+            secondary_base_url: self
+                .sandbox_secondary_base_url
+                .clone()
+                .or_else(|| self.secondary_base_url.clone()),
+            ..self.clone()
+        }
+    }
+}
+
+impl Connectors {
+    /// The connector configuration with the base URLs of the connector sandboxes, with which
+    /// the connectors are called for merchant connector accounts in test mode.
+    pub fn sandbox(&self) -> Self {
+        Self {
+            aci: self.aci.sandbox(),
+            adyen: self.adyen.sandbox(),
+            applepay: self.applepay.sandbox(),
+            authorizedotnet: self.authorizedotnet.sandbox(),
+            braintree: self.braintree.sandbox(),
+            checkout: self.checkout.sandbox(),
+            cybersource: self.cybersource.sandbox(),
+            #[cfg(feature = "dummy_connector")]
+            dummy_connector: self.dummy_connector.sandbox(),
+            fiserv: self.fiserv.sandbox(),
+            globalpay: self.globalpay.sandbox(),
+            klarna: self.klarna.sandbox(),
+            netcetera: self.netcetera.sandbox(),
+            paypal: self.paypal.sandbox(),
+            payu: self.payu.sandbox(),
+            rapyd: self.rapyd.sandbox(),
+            razorpay: self.razorpay.sandbox(),
+            shift4: self.shift4.sandbox(),
+            signifyd: self.signifyd.sandbox(),
+            stripe: self.stripe.sandbox(),
+            worldline: self.worldline.sandbox(),
+            worldpay: self.worldpay.sandbox(),
+            supported: self.supported.clone(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -164,6 +451,7 @@ pub struct SchedulerSettings {
     pub stream: String,
     pub consumer_group: String,
     pub producer: ProducerSettings,
+    pub outbox_relay: OutboxRelaySettings,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -177,6 +465,38 @@ pub struct ProducerSettings {
     pub batch_size: usize,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct OutboxRelaySettings {
+    /// The redis stream to which the events recorded in the outbox are published
+    pub stream: String,
+    /// The number of events fetched from the outbox in each run of the relay
+    pub batch_size: i64,
+    pub lock_key: String,
+    pub lock_ttl: i64,
+}
+
+#[cfg(feature = "kafka_events")]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct KafkaSettings {
+    pub brokers: Vec<String>,
+    pub intent_analytics_topic: String,
+    pub attempt_analytics_topic: String,
+    pub refund_analytics_topic: String,
+    /// Whether the customer identifiers and card data in the events are pseudonymized, for
+    /// pipelines which share the events with analysts or vendors
+    pub anonymize_events: bool,
+}
+
+#[cfg(feature = "grpc")]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct GrpcServerSettings {
+    pub host: String,
+    pub port: u16,
+}
+
 #[cfg(feature = "kv_store")]
 #[derive(Debug, Clone, Deserialize)]
 #[serde(default)]
@@ -184,6 +504,11 @@ pub struct DrainerSettings {
     pub stream_name: String,
     pub num_partitions: u8,
     pub max_read_count: u64,
+    /// Whether the keys of the payment hashes in the KV store are Redis Cluster hash tags
+    pub hash_tagged_keys: bool,
+    /// Whether the payment intents of merchants using the KV store are written to the database
+    /// directly, and only cached in Redis, instead of being written through the drainer
+    pub write_through: bool,
 }
 
 impl Settings {
@@ -216,6 +541,7 @@ impl Settings {
                     .separator("__")
                     .list_separator(",")
                     .with_list_parse_key("redis.cluster_urls")
+                    .with_list_parse_key("redis.sentinel_urls")
                     .with_list_parse_key("connectors.supported.wallets"),
             )
             .build()?;
@@ -230,8 +556,10 @@ impl Settings {
     pub fn validate(&self) -> ApplicationResult<()> {
         self.server.validate()?;
         self.master_database.validate()?;
-        #[cfg(feature = "olap")]
         self.replica_database.validate()?;
+        self.replica_lag.validate()?;
+        self.cache.validate()?;
+        self.health_check.validate()?;
         self.redis.validate().map_err(|error| {
             println!("{error}");
             ApplicationError::InvalidConfigurationValueError("Redis configuration".into())
@@ -250,8 +578,15 @@ impl Settings {
             }
         }
         self.secrets.validate()?;
+        self.secrets_manager.validate()?;
         self.locker.validate()?;
         self.connectors.validate()?;
+        self.webhooks.validate()?;
+        self.exports.validate()?;
+        self.velocity.validate()?;
+        self.debug_trace.validate()?;
+        self.forex.validate()?;
+        self.connector_credentials.validate()?;
 
         self.scheduler
             .as_ref()
@@ -260,6 +595,10 @@ impl Settings {
         #[cfg(feature = "kv_store")]
         self.drainer.validate()?;
         self.jwekey.validate()?;
+        #[cfg(feature = "kafka_events")]
+        self.kafka.validate()?;
+        #[cfg(feature = "grpc")]
+        self.grpc_server.validate()?;
 
         Ok(())
     }
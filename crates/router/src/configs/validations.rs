@@ -16,7 +16,66 @@ impl super::settings::Secrets {
             Err(ApplicationError::InvalidConfigurationValueError(
                 "admin API key must not be empty".into(),
             ))
-        })
+        })?;
+
+        when(self.export_signing_key.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "export signing key must not be empty".into(),
+            ))
+        })?;
+
+        when(self.card_fingerprint_key.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "card fingerprint key must not be empty".into(),
+            ))
+        })?;
+
+        when(self.anonymization_key.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "anonymization key must not be empty".into(),
+            ))
+        })?;
+
+        when(
+            hex::decode(&self.master_enc_key).map_or(true, |key| key.len() != 32),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "master encryption key must be a hex encoded 256-bit key".into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::SecretsManagerSettings {
+    pub(crate) fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        let uses_vault = self.backend == super::settings::SecretsManagerBackend::HashicorpVault;
+        when(uses_vault && self.vault_url.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "Vault URL must not be empty".into(),
+            ))
+        })?;
+
+        when(uses_vault && self.vault_token.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "Vault token must not be empty".into(),
+            ))
+        })?;
+
+        #[cfg(feature = "aws_secrets_manager")]
+        when(
+            self.backend == super::settings::SecretsManagerBackend::AwsSecretsManager
+                && self.aws_region.is_default_or_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "AWS Secrets Manager region must not be empty".into(),
+                ))
+            },
+        )?;
+
+        Ok(())
     }
 }
 
@@ -37,7 +96,24 @@ impl super::settings::Locker {
                     "basilisk host must not be empty when mock locker is disabled".into(),
                 ))
             },
-        )
+        )?;
+
+        #[cfg(not(feature = "basilisk_mock"))]
+        when(self.mock_locker, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "mock locker requires the router to be built with the basilisk_mock feature".into(),
+            ))
+        })?;
+
+        // The mock locker stores card details unencrypted in the application database
+        #[cfg(feature = "production")]
+        when(self.mock_locker, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "mock locker must be disabled in production builds".into(),
+            ))
+        })?;
+
+        Ok(())
     }
 }
 
@@ -97,6 +173,55 @@ impl super::settings::Database {
             Err(ApplicationError::InvalidConfigurationValueError(
                 "database name must not be empty".into(),
             ))
+        })?;
+
+        when(self.connection_timeout == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "database connection timeout must be greater than zero".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::ReplicaLagSettings {
+    pub(crate) fn validate(&self) -> Result<(), ApplicationError> {
+        common_utils::fp_utils::when(self.check_interval == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "replica lag check interval must be greater than zero".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::CacheSettings {
+    pub(crate) fn validate(&self) -> Result<(), ApplicationError> {
+        common_utils::fp_utils::when(self.ttl == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "cache TTL must be greater than zero".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::HealthCheckSettings {
+    pub(crate) fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::{ext_traits::ConfigExt, fp_utils::when};
+
+        when(
+            self.canary_connector_url
+                .as_ref()
+                .map_or(false, |url| url.is_default_or_empty()),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "canary connector URL must not be empty".into(),
+                ))
+            },
+        )?;
+
+        when(self.canary_timeout == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "canary connector timeout must be greater than zero".into(),
+            ))
         })
     }
 }
@@ -120,9 +245,15 @@ impl super::settings::Connectors {
         self.braintree.validate()?;
         self.checkout.validate()?;
         self.cybersource.validate()?;
+        #[cfg(feature = "dummy_connector")]
+        self.dummy_connector.validate()?;
         self.globalpay.validate()?;
         self.klarna.validate()?;
+        self.netcetera.validate()?;
+        self.paypal.validate()?;
+        self.razorpay.validate()?;
         self.shift4.validate()?;
+        self.signifyd.validate()?;
         self.stripe.validate()?;
         self.worldpay.validate()?;
 
@@ -134,14 +265,138 @@ impl super::settings::Connectors {
 
 impl super::settings::ConnectorParams {
     pub(crate) fn validate(&self) -> Result<(), ApplicationError> {
-        common_utils::fp_utils::when(self.base_url.is_default_or_empty(), || {
+        use common_utils::fp_utils::when;
+
+        when(self.base_url.is_default_or_empty(), || {
             Err(ApplicationError::InvalidConfigurationValueError(
                 "connector base URL must not be empty".into(),
             ))
+        })?;
+
+        when(
+            self.sandbox_base_url
+                .as_ref()
+                .map_or(false, |sandbox_base_url| sandbox_base_url.is_empty()),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "connector sandbox base URL must not be empty when set".into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::WebhooksSettings {
+    pub(crate) fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.outgoing_max_attempts <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "maximum outgoing webhook delivery attempts must be positive".into(),
+            ))
+        })?;
+
+        when(
+            self.outgoing_retry_interval <= 0
+                || self.outgoing_max_retry_interval < self.outgoing_retry_interval,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "outgoing webhook retry intervals must be positive, with the maximum interval not less than the initial one".into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::ExportSettings {
+    pub(crate) fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.max_time_range_days <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "maximum time range of exports must be positive".into(),
+            ))
+        })?;
+
+        when(self.max_rows <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "maximum number of records in an export must be positive".into(),
+            ))
+        })?;
+
+        when(self.download_url_validity <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "validity of export download URLs must be positive".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::VelocitySettings {
+    pub(crate) fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        self.rules.iter().try_for_each(|rule| {
+            when(rule.max_attempts == 0, || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "maximum number of attempts of velocity rules must be positive".into(),
+                ))
+            })?;
+
+            when(rule.window <= 0, || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "window of velocity rules must be positive".into(),
+                ))
+            })
         })
     }
 }
 
+impl super::settings::DebugTraceSettings {
+    pub(crate) fn validate(&self) -> Result<(), ApplicationError> {
+        self.sampling_rules.iter().try_for_each(|rule| {
+            common_utils::fp_utils::when(!(0.0..=1.0).contains(&rule.sample_rate), || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "sample rate of debug trace sampling rules must be between 0 and 1".into(),
+                ))
+            })
+        })
+    }
+}
+
+impl super::settings::ForexSettings {
+    pub(crate) fn validate(&self) -> Result<(), ApplicationError> {
+        common_utils::fp_utils::when(
+            !self.base_url.is_empty() && !self.base_url.ends_with('/'),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "base URL of the exchange rate provider must end with a slash".into(),
+                ))
+            },
+        )?;
+
+        self.cache.validate()
+    }
+}
+
+impl super::settings::ConnectorCredentialsSettings {
+    pub(crate) fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            cfg!(not(feature = "kms"))
+                && self.encryption == super::settings::CredentialsEncryptionService::Kms,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "KMS encryption of connector credentials requires the `kms` feature".into(),
+                ))
+            },
+        )?;
+
+        self.cache.validate()
+    }
+}
+
 impl super::settings::SchedulerSettings {
     pub(crate) fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
@@ -184,3 +439,38 @@ impl super::settings::DrainerSettings {
         })
     }
 }
+
+#[cfg(feature = "kafka_events")]
+impl super::settings::KafkaSettings {
+    pub(crate) fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.brokers.is_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "kafka brokers must not be empty".into(),
+            ))
+        })?;
+
+        when(
+            self.intent_analytics_topic.is_default_or_empty()
+                || self.attempt_analytics_topic.is_default_or_empty()
+                || self.refund_analytics_topic.is_default_or_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "kafka topics must not be empty".into(),
+                ))
+            },
+        )
+    }
+}
+
+#[cfg(feature = "grpc")]
+impl super::settings::GrpcServerSettings {
+    pub(crate) fn validate(&self) -> Result<(), ApplicationError> {
+        common_utils::fp_utils::when(self.host.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "gRPC server host must not be empty".into(),
+            ))
+        })
+    }
+}
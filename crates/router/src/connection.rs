@@ -1,8 +1,25 @@
-use async_bb8_diesel::{AsyncConnection, ConnectionError};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use async_bb8_diesel::{AsyncConnection, AsyncRunQueryDsl, ConnectionError};
 use bb8::{CustomizeConnection, PooledConnection};
-use diesel::PgConnection;
+use diesel::{
+    sql_types::{Double, Integer, Nullable},
+    PgConnection,
+};
+use error_stack::{IntoReport, ResultExt};
+use futures::StreamExt;
+use redis_interface::RedisConnectionEvent;
+use storage_models::errors::DatabaseError;
 
-use crate::configs::settings::Database;
+use crate::{
+    configs::settings::{Database, ReplicaLagSettings},
+    core::errors::CustomResult,
+    logger,
+    routes::metrics,
+};
 
 pub type PgPool = bb8::Pool<async_bb8_diesel::ConnectionManager<PgConnection>>;
 
@@ -32,6 +49,35 @@ pub async fn redis_connection(
     redis_interface::RedisConnectionPool::new(&conf.redis).await
 }
 
+/// Logs and counts the failures and reconnections of the Redis connections, so that failovers of
+/// the Redis primary, which the pool recovers from by itself, are visible.
+pub async fn monitor_redis_connections(events: impl futures::Stream<Item = RedisConnectionEvent>) {
+    events
+        .for_each(|event| async move {
+            match event {
+                RedisConnectionEvent::Error(error) => {
+                    metrics::REDIS_CONNECTION_ERROR_COUNT.add(&metrics::CONTEXT, 1, &[]);
+                    logger::error!(%error, "Redis connection failed");
+                }
+                RedisConnectionEvent::Reconnected { client_id } => {
+                    metrics::REDIS_RECONNECTION_COUNT.add(&metrics::CONTEXT, 1, &[]);
+                    logger::warn!(%client_id, "Reconnected to Redis");
+                }
+            }
+        })
+        .await
+}
+
+#[cfg(feature = "kafka_events")]
+#[allow(clippy::expect_used)]
+pub fn kafka_producer(
+    conf: &crate::configs::settings::KafkaSettings,
+    anonymization_key: &str,
+) -> crate::services::kafka::KafkaProducer {
+    crate::services::kafka::KafkaProducer::create(conf, anonymization_key)
+        .expect("Failed to create Kafka producer")
+}
+
 #[allow(clippy::expect_used)]
 pub async fn diesel_make_pg_pool(database: &Database, test_transaction: bool) -> PgPool {
     let database_url = format!(
@@ -39,7 +85,9 @@ pub async fn diesel_make_pg_pool(database: &Database, test_transaction: bool) ->
         database.username, database.password, database.host, database.port, database.dbname
     );
     let manager = async_bb8_diesel::ConnectionManager::<PgConnection>::new(database_url);
-    let mut pool = bb8::Pool::builder().max_size(database.pool_size);
+    let mut pool = bb8::Pool::builder()
+        .max_size(database.pool_size)
+        .connection_timeout(std::time::Duration::from_secs(database.connection_timeout));
 
     if test_transaction {
         pool = pool.connection_customizer(Box::new(TestTransaction));
@@ -54,7 +102,83 @@ pub async fn diesel_make_pg_pool(database: &Database, test_transaction: bool) ->
 pub async fn pg_connection(
     pool: &PgPool,
 ) -> PooledConnection<'_, async_bb8_diesel::ConnectionManager<PgConnection>> {
-    pool.get()
+    let start = std::time::Instant::now();
+    let conn = pool
+        .get()
+        .await
+        .expect("Couldn't retrieve PostgreSQL connection");
+    metrics::DB_CONNECTION_WAIT_TIME.record(&metrics::CONTEXT, start.elapsed().as_secs_f64(), &[]);
+    conn
+}
+
+/// Checks that the database is reachable, by running `SELECT 1` on a connection from the pool.
+///
+/// Unlike [`pg_connection`], failing to obtain a connection is reported as an error.
+pub async fn select_one(pool: &PgPool) -> CustomResult<(), DatabaseError> {
+    let conn = pool
+        .get()
         .await
-        .expect("Couldn't retrieve PostgreSQL connection")
+        .into_report()
+        .change_context(DatabaseError::DatabaseConnectionError)?;
+
+    diesel::select(diesel::dsl::sql::<Integer>("1"))
+        .execute_async(&*conn)
+        .await
+        .into_report()
+        .change_context(DatabaseError::Others)
+        .map(|_| ())
+}
+
+/// The number of seconds by which the replica lags behind the primary, if it is known.
+///
+/// The lag is zero while the replica has replayed everything it has received from the primary, so
+/// that a replica of an idle primary is not considered to be lagging behind.
+async fn replica_lag(replica_pool: &PgPool) -> CustomResult<Option<f64>, DatabaseError> {
+    let conn = replica_pool
+        .get()
+        .await
+        .into_report()
+        .change_context(DatabaseError::DatabaseConnectionError)?;
+
+    diesel::select(diesel::dsl::sql::<Nullable<Double>>(
+        "CASE WHEN NOT pg_is_in_recovery() \
+            OR pg_last_wal_receive_lsn() = pg_last_wal_replay_lsn() THEN 0 \
+        ELSE EXTRACT(EPOCH FROM now() - pg_last_xact_replay_timestamp())::FLOAT8 END",
+    ))
+    .get_result_async(&*conn)
+    .await
+    .into_report()
+    .change_context(DatabaseError::Others)
+}
+
+/// Periodically checks the lag of the replica, and flags it as lagging while it lags behind the
+/// primary by more than the configured tolerance, or while the lag cannot be determined.
+pub async fn monitor_replica_lag(
+    replica_pool: PgPool,
+    replica_lagging: Arc<AtomicBool>,
+    settings: ReplicaLagSettings,
+) {
+    let mut interval =
+        tokio::time::interval(std::time::Duration::from_secs(settings.check_interval));
+
+    loop {
+        interval.tick().await;
+
+        let lagging = match replica_lag(&replica_pool).await {
+            Ok(Some(lag)) => lag > f64::from(settings.max_lag),
+            Ok(None) => true,
+            Err(error) => {
+                logger::error!(?error, "Failed to check the replica lag");
+                true
+            }
+        };
+
+        if replica_lagging.swap(lagging, Ordering::Relaxed) != lagging {
+            if lagging {
+                logger::warn!("Replica is lagging behind, serving reads from the primary");
+            } else {
+                logger::info!("Replica has caught up, serving reads from the replica");
+            }
+        }
+    }
 }
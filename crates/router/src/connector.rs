@@ -5,12 +5,18 @@ pub mod authorizedotnet;
 pub mod braintree;
 pub mod checkout;
 pub mod cybersource;
+#[cfg(feature = "dummy_connector")]
+pub mod dummy_connector;
 pub mod fiserv;
 pub mod globalpay;
 pub mod klarna;
+pub mod netcetera;
+pub mod paypal;
 pub mod payu;
 pub mod rapyd;
+pub mod razorpay;
 pub mod shift4;
+pub mod signifyd;
 pub mod stripe;
 pub mod utils;
 pub mod worldline;
@@ -19,6 +25,10 @@ pub mod worldpay;
 pub use self::{
     aci::Aci, adyen::Adyen, applepay::Applepay, authorizedotnet::Authorizedotnet,
     braintree::Braintree, checkout::Checkout, cybersource::Cybersource, fiserv::Fiserv,
-    globalpay::Globalpay, klarna::Klarna, payu::Payu, rapyd::Rapyd, shift4::Shift4, stripe::Stripe,
+    globalpay::Globalpay, klarna::Klarna, netcetera::Netcetera, paypal::Paypal, payu::Payu,
+    rapyd::Rapyd, razorpay::Razorpay, shift4::Shift4, signifyd::Signifyd, stripe::Stripe,
     worldline::Worldline, worldpay::Worldpay,
 };
+
+#[cfg(feature = "dummy_connector")]
+pub use self::dummy_connector::DummyConnector;
@@ -45,12 +45,120 @@ impl ConnectorCommon for Aci {
     }
 }
 
+impl api::ConnectorAccessToken for Aci {}
+
+impl
+    services::ConnectorIntegration<
+        api::AccessTokenAuth,
+        types::AccessTokenRequestData,
+        types::AccessToken,
+    > for Aci
+{
+}
+
+impl api::ConnectorVerifyWebhookSource for Aci {}
+
+impl
+    services::ConnectorIntegration<
+        api::VerifyWebhookSource,
+        types::VerifyWebhookSourceRequestData,
+        types::VerifyWebhookSourceResponseData,
+    > for Aci
+{
+}
+
+impl api::Payout for Aci {}
+impl api::PayoutCreate for Aci {}
+impl api::PayoutFulfill for Aci {}
+impl api::PayoutCancel for Aci {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Aci
+{
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Aci
+{
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Aci
+{
+}
+
+impl api::FraudCheck for Aci {}
+impl api::FraudCheckPreAuth for Aci {}
+impl api::FraudCheckPostAuth for Aci {}
+
+impl
+    services::ConnectorIntegration<
+        api::FraudCheckPre,
+        types::FraudCheckRequestData,
+        types::FraudCheckResponseData,
+    > for Aci
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::FraudCheckPost,
+        types::FraudCheckRequestData,
+        types::FraudCheckResponseData,
+    > for Aci
+{
+}
+
+impl api::ExternalAuthentication for Aci {}
+
+impl
+    services::ConnectorIntegration<
+        api::ThreeDsAuthentication,
+        types::ThreeDsAuthenticationRequestData,
+        types::ThreeDsAuthenticationData,
+    > for Aci
+{
+}
+
+impl api::Dispute for Aci {}
+impl api::SubmitEvidence for Aci {}
+impl api::DefendDispute for Aci {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Aci
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Defend,
+        types::DefendDisputeRequestData,
+        types::DefendDisputeResponse,
+    > for Aci
+{
+}
+
 impl api::Payment for Aci {}
 
 impl api::PaymentAuthorize for Aci {}
 impl api::PaymentSync for Aci {}
 impl api::PaymentVoid for Aci {}
 impl api::PaymentCapture for Aci {}
+impl api::PaymentAuthorizeSessionToken for Aci {}
+
+impl
+    services::ConnectorIntegration<
+        api::AuthorizeSessionToken,
+        types::AuthorizeSessionTokenData,
+        types::AuthorizeSessionTokenResponse,
+    > for Aci
+{
+}
+
 impl api::PaymentSession for Aci {}
 
 impl
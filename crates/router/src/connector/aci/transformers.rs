@@ -57,6 +57,8 @@ pub enum PaymentDetails {
     Wallet,
     Klarna,
     Paypal,
+    Upi,
+    BankDebit,
 }
 
 #[derive(Clone, Eq, PartialEq, Serialize)]
@@ -114,6 +116,8 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for AciPaymentsRequest {
             api::PaymentMethod::PayLater(_) => PaymentDetails::Klarna,
             api::PaymentMethod::Wallet(_) => PaymentDetails::Wallet,
             api::PaymentMethod::Paypal => PaymentDetails::Paypal,
+            api::PaymentMethod::Upi(_) => PaymentDetails::Upi,
+            api::PaymentMethod::BankDebit(_) => PaymentDetails::BankDebit,
         };
 
         let auth = AciAuthType::try_from(&item.connector_auth_type)?;
@@ -47,6 +47,367 @@ impl ConnectorCommon for Adyen {
     }
 }
 
+impl Adyen {
+    /// Payouts are served by the classic Adyen API, which has a different host from the checkout
+    /// API used for payments.
+    fn payout_base_url<'a>(
+        &self,
+        connectors: &'a settings::Connectors,
+    ) -> CustomResult<&'a str, errors::ConnectorError> {
+        connectors
+            .adyen
+            .secondary_base_url
+            .as_deref()
+            .ok_or(errors::ConnectorError::FailedToObtainIntegrationUrl)
+            .into_report()
+    }
+}
+
+impl api::ConnectorAccessToken for Adyen {}
+
+impl
+    services::ConnectorIntegration<
+        api::AccessTokenAuth,
+        types::AccessTokenRequestData,
+        types::AccessToken,
+    > for Adyen
+{
+}
+
+impl api::ConnectorVerifyWebhookSource for Adyen {}
+
+impl
+    services::ConnectorIntegration<
+        api::VerifyWebhookSource,
+        types::VerifyWebhookSourceRequestData,
+        types::VerifyWebhookSourceResponseData,
+    > for Adyen
+{
+}
+
+impl api::Payout for Adyen {}
+impl api::PayoutCreate for Adyen {}
+impl api::PayoutFulfill for Adyen {}
+impl api::PayoutCancel for Adyen {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Adyen
+{
+    fn get_headers(
+        &self,
+        req: &types::PayoutsRouterData<api::PoCreate>,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::PayoutCreateType::get_content_type(self).to_string(),
+        )];
+        let mut api_header = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_header);
+        Ok(header)
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::PayoutsRouterData<api::PoCreate>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}pal/servlet/Payout/v68/storeDetailAndSubmitThirdParty",
+            self.payout_base_url(connectors)?
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PayoutsRouterData<api::PoCreate>,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let adyen_req = utils::Encode::<adyen::AdyenPayoutCreateRequest>::convert_and_encode(req)
+            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(adyen_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PayoutsRouterData<api::PoCreate>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PayoutCreateType::get_url(self, req, connectors)?)
+                .headers(types::PayoutCreateType::get_headers(self, req, connectors)?)
+                .body(types::PayoutCreateType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    #[instrument(skip_all)]
+    fn handle_response(
+        &self,
+        data: &types::PayoutsRouterData<api::PoCreate>,
+        res: types::Response,
+    ) -> CustomResult<types::PayoutsRouterData<api::PoCreate>, errors::ConnectorError> {
+        let response: adyen::AdyenPayoutResponse = res
+            .response
+            .parse_struct("AdyenPayoutResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        logger::info!(response=?res);
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Bytes,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: adyen::ErrorResponse = res
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        logger::info!(response=?res);
+        Ok(types::ErrorResponse {
+            code: response.error_code,
+            message: response.message,
+            reason: None,
+        })
+    }
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Adyen
+{
+    fn get_headers(
+        &self,
+        req: &types::PayoutsRouterData<api::PoFulfill>,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::PayoutFulfillType::get_content_type(self).to_string(),
+        )];
+        let mut api_header = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_header);
+        Ok(header)
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::PayoutsRouterData<api::PoFulfill>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}pal/servlet/Payout/v68/confirmThirdParty",
+            self.payout_base_url(connectors)?
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PayoutsRouterData<api::PoFulfill>,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let adyen_req = utils::Encode::<adyen::AdyenPayoutModifyRequest>::convert_and_encode(req)
+            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(adyen_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PayoutsRouterData<api::PoFulfill>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PayoutFulfillType::get_url(self, req, connectors)?)
+                .headers(types::PayoutFulfillType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::PayoutFulfillType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    #[instrument(skip_all)]
+    fn handle_response(
+        &self,
+        data: &types::PayoutsRouterData<api::PoFulfill>,
+        res: types::Response,
+    ) -> CustomResult<types::PayoutsRouterData<api::PoFulfill>, errors::ConnectorError> {
+        let response: adyen::AdyenPayoutResponse = res
+            .response
+            .parse_struct("AdyenPayoutResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        logger::info!(response=?res);
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Bytes,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: adyen::ErrorResponse = res
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        logger::info!(response=?res);
+        Ok(types::ErrorResponse {
+            code: response.error_code,
+            message: response.message,
+            reason: None,
+        })
+    }
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Adyen
+{
+    fn get_headers(
+        &self,
+        req: &types::PayoutsRouterData<api::PoCancel>,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut header = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::PayoutCancelType::get_content_type(self).to_string(),
+        )];
+        let mut api_header = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_header);
+        Ok(header)
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::PayoutsRouterData<api::PoCancel>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}pal/servlet/Payout/v68/declineThirdParty",
+            self.payout_base_url(connectors)?
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PayoutsRouterData<api::PoCancel>,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let adyen_req = utils::Encode::<adyen::AdyenPayoutModifyRequest>::convert_and_encode(req)
+            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(adyen_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PayoutsRouterData<api::PoCancel>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PayoutCancelType::get_url(self, req, connectors)?)
+                .headers(types::PayoutCancelType::get_headers(self, req, connectors)?)
+                .body(types::PayoutCancelType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    #[instrument(skip_all)]
+    fn handle_response(
+        &self,
+        data: &types::PayoutsRouterData<api::PoCancel>,
+        res: types::Response,
+    ) -> CustomResult<types::PayoutsRouterData<api::PoCancel>, errors::ConnectorError> {
+        let response: adyen::AdyenPayoutResponse = res
+            .response
+            .parse_struct("AdyenPayoutResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        logger::info!(response=?res);
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Bytes,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: adyen::ErrorResponse = res
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        logger::info!(response=?res);
+        Ok(types::ErrorResponse {
+            code: response.error_code,
+            message: response.message,
+            reason: None,
+        })
+    }
+}
+
+impl api::FraudCheck for Adyen {}
+impl api::FraudCheckPreAuth for Adyen {}
+impl api::FraudCheckPostAuth for Adyen {}
+
+impl
+    services::ConnectorIntegration<
+        api::FraudCheckPre,
+        types::FraudCheckRequestData,
+        types::FraudCheckResponseData,
+    > for Adyen
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::FraudCheckPost,
+        types::FraudCheckRequestData,
+        types::FraudCheckResponseData,
+    > for Adyen
+{
+}
+
+impl api::ExternalAuthentication for Adyen {}
+
+impl
+    services::ConnectorIntegration<
+        api::ThreeDsAuthentication,
+        types::ThreeDsAuthenticationRequestData,
+        types::ThreeDsAuthenticationData,
+    > for Adyen
+{
+}
+
+impl api::Dispute for Adyen {}
+impl api::SubmitEvidence for Adyen {}
+impl api::DefendDispute for Adyen {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Adyen
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Defend,
+        types::DefendDisputeRequestData,
+        types::DefendDisputeResponse,
+    > for Adyen
+{
+}
+
 impl api::Payment for Adyen {}
 impl api::PaymentAuthorize for Adyen {}
 impl api::PaymentSync for Adyen {}
@@ -64,6 +425,17 @@ impl
     // Issue: #173
 }
 
+impl api::PaymentAuthorizeSessionToken for Adyen {}
+
+impl
+    services::ConnectorIntegration<
+        api::AuthorizeSessionToken,
+        types::AuthorizeSessionTokenData,
+        types::AuthorizeSessionTokenResponse,
+    > for Adyen
+{
+}
+
 impl api::PaymentSession for Adyen {}
 
 impl
@@ -677,14 +1049,8 @@ impl api::IncomingWebhook for Adyen {
         &self,
         db: &dyn StorageInterface,
         merchant_id: &str,
-    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
-        let key = format!("whsec_verification_{}_{}", self.id(), merchant_id);
-        let secret = db
-            .get_key(&key)
-            .await
-            .change_context(errors::ConnectorError::WebhookVerificationSecretNotFound)?;
-
-        Ok(secret)
+    ) -> CustomResult<api::WebhookSourceVerificationSecrets, errors::ConnectorError> {
+        api::get_webhook_source_verification_secrets(db, self.id(), merchant_id).await
     }
 
     fn get_webhook_object_reference_id(
@@ -694,6 +1060,14 @@ impl api::IncomingWebhook for Adyen {
         let notif = get_webhook_object_from_body(body)
             .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
 
+        // Dispute notifications reference the disputed payment in `originalReference`
+        if adyen::is_dispute_event(&notif.event_code) {
+            return notif
+                .original_reference
+                .get_required_value("original_reference")
+                .change_context(errors::ConnectorError::WebhookReferenceIdNotFound);
+        }
+
         Ok(notif.psp_reference)
     }
 
@@ -704,12 +1078,40 @@ impl api::IncomingWebhook for Adyen {
         let notif = get_webhook_object_from_body(body)
             .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
 
-        Ok(match notif.event_code.as_str() {
-            "AUTHORISATION" => api::IncomingWebhookEvent::PaymentIntentSuccess,
+        Ok(match (notif.event_code.as_str(), notif.success.as_str()) {
+            ("AUTHORISATION", _) => api::IncomingWebhookEvent::PaymentIntentSuccess,
+            ("REFUND", "true") => api::IncomingWebhookEvent::RefundSuccess,
+            ("REFUND", _) | ("REFUND_FAILED", _) => api::IncomingWebhookEvent::RefundFailure,
+            ("NOTIFICATION_OF_CHARGEBACK", _) => api::IncomingWebhookEvent::DisputeOpened,
+            ("CHARGEBACK", _) => match notif.additional_data.dispute_status.as_deref() {
+                Some("Won") => api::IncomingWebhookEvent::DisputeWon,
+                Some("Undefended") | Some("Pending") => api::IncomingWebhookEvent::DisputeOpened,
+                _ => api::IncomingWebhookEvent::DisputeLost,
+            },
+            ("CHARGEBACK_REVERSED", _) => match notif.additional_data.dispute_status.as_deref() {
+                Some("Pending") => api::IncomingWebhookEvent::DisputeChallenged,
+                _ => api::IncomingWebhookEvent::DisputeWon,
+            },
+            ("SECOND_CHARGEBACK", _) => api::IncomingWebhookEvent::DisputeLost,
             _ => Err(errors::ConnectorError::WebhookEventTypeNotFound).into_report()?,
         })
     }
 
+    fn get_webhook_event_id(
+        &self,
+        body: &[u8],
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let notif = get_webhook_object_from_body(body)
+            .change_context(errors::ConnectorError::WebhookEventIdNotFound)?;
+
+        // Adyen identifies a notification by the PSP reference along with its event code and
+        // success field
+        Ok(Some(format!(
+            "{}_{}_{}",
+            notif.psp_reference, notif.event_code, notif.success
+        )))
+    }
+
     fn get_webhook_resource_object(
         &self,
         body: &[u8],
@@ -726,6 +1128,16 @@ impl api::IncomingWebhook for Adyen {
         Ok(res_json)
     }
 
+    fn get_dispute_details(
+        &self,
+        body: &[u8],
+    ) -> CustomResult<api::disputes::DisputePayload, errors::ConnectorError> {
+        let notif = get_webhook_object_from_body(body)
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+
+        Ok(notif.into())
+    }
+
     fn get_webhook_api_response(
         &self,
     ) -> CustomResult<services::api::ApplicationResponse<serde_json::Value>, errors::ConnectorError>
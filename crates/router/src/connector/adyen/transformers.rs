@@ -5,6 +5,7 @@ use reqwest::Url;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    connector::utils::{self as connector_utils, PaymentsRequestData},
     consts,
     core::errors,
     pii, services,
@@ -58,6 +59,35 @@ pub struct AdyenPaymentRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     recurring_processing_model: Option<AdyenRecurringModel>,
     additional_data: Option<AdditionalData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mpi_data: Option<AdyenMpiData>,
+}
+
+/// The result of a 3DS authentication performed outside of Adyen.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AdyenMpiData {
+    cavv: Option<pii::Secret<String>>,
+    eci: Option<String>,
+    #[serde(rename = "dsTransID")]
+    ds_trans_id: Option<String>,
+    directory_response: types::ThreeDsTransStatus,
+    authentication_response: types::ThreeDsTransStatus,
+    #[serde(rename = "threeDSVersion")]
+    three_ds_version: String,
+}
+
+impl From<types::ThreeDsAuthenticationData> for AdyenMpiData {
+    fn from(authentication_data: types::ThreeDsAuthenticationData) -> Self {
+        Self {
+            cavv: authentication_data.cavv,
+            eci: authentication_data.eci,
+            ds_trans_id: authentication_data.ds_trans_id,
+            directory_response: authentication_data.trans_status,
+            authentication_response: authentication_data.trans_status,
+            three_ds_version: authentication_data.message_version,
+        }
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -79,6 +109,8 @@ pub enum AdyenStatus {
     Refused,
     Cancelled,
     RedirectShopper,
+    /// The payment is pending until the debit settles, as for SEPA Direct Debits
+    Received,
 }
 
 impl From<AdyenStatus> for storage_enums::AttemptStatus {
@@ -88,6 +120,7 @@ impl From<AdyenStatus> for storage_enums::AttemptStatus {
             AdyenStatus::Refused => Self::Failure,
             AdyenStatus::Cancelled => Self::Voided,
             AdyenStatus::RedirectShopper => Self::AuthenticationPending,
+            AdyenStatus::Received => Self::Pending,
         }
     }
 }
@@ -140,6 +173,14 @@ pub struct AdyenResponse {
     merchant_reference: String,
     refusal_reason: Option<String>,
     refusal_reason_code: Option<String>,
+    additional_data: Option<AdyenResponseAdditionalData>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdyenResponseAdditionalData {
+    /// The reference of the stored payment method, with which subsequent payments are made
+    #[serde(rename = "recurring.recurringDetailReference")]
+    recurring_detail_reference: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -175,6 +216,8 @@ pub enum AdyenPaymentMethod {
     AdyenPaypal(AdyenPaypal),
     Gpay(AdyenGPay),
     ApplePay(AdyenApplePay),
+    #[serde(rename = "sepadirectdebit")]
+    SepaDirectDebit(AdyenSepaDirectDebit),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -216,6 +259,14 @@ pub struct AdyenPaypal {
     payment_type: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdyenSepaDirectDebit {
+    #[serde(rename = "sepa.ownerName")]
+    owner_name: pii::Secret<String>,
+    #[serde(rename = "sepa.ibanNumber")]
+    iban_number: pii::Secret<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AdyenGPay {
     #[serde(rename = "type")]
@@ -300,7 +351,9 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for AdyenPaymentRequest {
             api::PaymentMethod::BankTransfer
             | api::PaymentMethod::Wallet(_)
             | api::PaymentMethod::PayLater(_)
-            | api::PaymentMethod::Paypal => None,
+            | api::PaymentMethod::Paypal
+            | api::PaymentMethod::Upi(_)
+            | api::PaymentMethod::BankDebit(_) => None,
         };
 
         let wallet_data = match item.request.payment_method_data {
@@ -313,12 +366,14 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for AdyenPaymentRequest {
             _ => AdyenShopperInteraction::Ecommerce,
         };
 
-        let recurring_processing_model = match item.request.setup_future_usage {
-            Some(storage_enums::FutureUsage::OffSession) => {
-                Some(AdyenRecurringModel::UnscheduledCardOnFile)
-            }
-            _ => None,
-        };
+        // Card details are stored for unscheduled usage both when setting up and when charging
+        // an off session payment
+        let recurring_processing_model = (item.is_merchant_initiated()
+            || matches!(
+                item.request.setup_future_usage,
+                Some(storage_enums::FutureUsage::OffSession)
+            ))
+        .then_some(AdyenRecurringModel::UnscheduledCardOnFile);
 
         let payment_type = match item.payment_method {
             storage_enums::PaymentMethodType::Card => "scheme".to_string(),
@@ -381,6 +436,27 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for AdyenPaymentRequest {
                     let wallet = AdyenPaypal { payment_type };
                     Ok(AdyenPaymentMethod::AdyenPaypal(wallet))
                 }
+                api_enums::WalletIssuer::PaypalRedirect => {
+                    let wallet = AdyenPaypal {
+                        payment_type: api_enums::WalletIssuer::Paypal.to_string(),
+                    };
+                    Ok(AdyenPaymentMethod::AdyenPaypal(wallet))
+                }
+            },
+            storage_enums::PaymentMethodType::BankDebit => match item.request.payment_method_data {
+                api::PaymentMethod::BankDebit(
+                    api_models::payments::BankDebitData::SepaBankDebit {
+                        ref account_holder_name,
+                        ref iban,
+                        ..
+                    },
+                ) => Ok(AdyenPaymentMethod::SepaDirectDebit(AdyenSepaDirectDebit {
+                    owner_name: account_holder_name.clone(),
+                    iban_number: iban.clone(),
+                })),
+                _ => Err(errors::ConnectorError::NotImplemented(
+                    "Bank debit payment method".to_string(),
+                )),
             },
             _ => Err(errors::ConnectorError::MissingRequiredField {
                 field_name: "payment_method".to_string(),
@@ -418,6 +494,7 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for AdyenPaymentRequest {
             recurring_processing_model,
             browser_info,
             additional_data,
+            mpi_data: item.request.authentication_data.clone().map(Into::into),
         })
     }
 }
@@ -503,7 +580,9 @@ pub fn get_adyen_response(
         resource_id: types::ResponseId::ConnectorTransactionId(response.psp_reference),
         redirection_data: None,
         redirect: false,
-        mandate_reference: None,
+        mandate_reference: response
+            .additional_data
+            .and_then(|additional_data| additional_data.recurring_detail_reference),
         connector_metadata: None,
     };
     Ok((status, error, payments_response_data))
@@ -734,6 +813,142 @@ impl<F> TryFrom<types::RefundsResponseRouterData<F, AdyenRefundResponse>>
     }
 }
 
+// Payouts Request and Response
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPayoutCreateRequest {
+    amount: Amount,
+    bank: AdyenBankAccount,
+    merchant_account: String,
+    recurring: AdyenPayoutRecurring,
+    reference: String,
+    shopper_email: pii::Secret<String, pii::Email>,
+    shopper_reference: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenBankAccount {
+    iban: pii::Secret<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bic: Option<pii::Secret<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bank_name: Option<String>,
+    country_code: String,
+    owner_name: pii::Secret<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AdyenPayoutRecurring {
+    contract: String,
+}
+
+/// Request for confirming or declining a payout which was submitted earlier.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPayoutModifyRequest {
+    merchant_account: String,
+    original_reference: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPayoutResponse {
+    psp_reference: String,
+    /// The outcome of submitting a payout
+    result_code: Option<String>,
+    /// The outcome of confirming or declining a payout
+    response: Option<String>,
+}
+
+impl<F> TryFrom<&types::PayoutsRouterData<F>> for AdyenPayoutCreateRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PayoutsRouterData<F>) -> Result<Self, Self::Error> {
+        let auth_type = AdyenAuthType::try_from(&item.connector_auth_type)?;
+        let payout_method_data = item
+            .request
+            .payout_method_data
+            .clone()
+            .ok_or_else(connector_utils::missing_field_err("payout_method_data"))?;
+        let bank = match payout_method_data {
+            api::PayoutMethodData::Bank(bank) => AdyenBankAccount {
+                iban: bank.iban,
+                bic: bank.bic,
+                bank_name: bank.bank_name,
+                country_code: bank.bank_country_code,
+                owner_name: bank.bank_owner_name,
+            },
+        };
+        Ok(Self {
+            amount: Amount {
+                currency: item.request.currency.to_string(),
+                value: item.request.amount,
+            },
+            bank,
+            merchant_account: auth_type.merchant_account,
+            recurring: AdyenPayoutRecurring {
+                contract: "PAYOUT".to_string(),
+            },
+            reference: item.request.payout_id.clone(),
+            shopper_email: item
+                .request
+                .email
+                .clone()
+                .ok_or_else(connector_utils::missing_field_err("email"))?,
+            shopper_reference: item
+                .request
+                .customer_id
+                .clone()
+                .ok_or_else(connector_utils::missing_field_err("customer_id"))?,
+        })
+    }
+}
+
+impl<F> TryFrom<&types::PayoutsRouterData<F>> for AdyenPayoutModifyRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PayoutsRouterData<F>) -> Result<Self, Self::Error> {
+        let auth_type = AdyenAuthType::try_from(&item.connector_auth_type)?;
+        Ok(Self {
+            merchant_account: auth_type.merchant_account,
+            original_reference: item
+                .request
+                .connector_payout_id
+                .clone()
+                .ok_or_else(connector_utils::missing_field_err("connector_payout_id"))?,
+        })
+    }
+}
+
+impl<F> TryFrom<types::PayoutsResponseRouterData<F, AdyenPayoutResponse>>
+    for types::PayoutsRouterData<F>
+{
+    type Error = error_stack::Report<errors::ParsingError>;
+    fn try_from(
+        item: types::PayoutsResponseRouterData<F, AdyenPayoutResponse>,
+    ) -> Result<Self, Self::Error> {
+        // The final outcome of a confirmed payout is available only through the payout
+        // notification webhook
+        let status = match item
+            .response
+            .result_code
+            .as_deref()
+            .or(item.response.response.as_deref())
+        {
+            Some("[payout-submit-received]") => storage_enums::PayoutStatus::RequiresFulfillment,
+            Some("[payout-confirm-received]") => storage_enums::PayoutStatus::Pending,
+            Some("[payout-decline-received]") => storage_enums::PayoutStatus::Cancelled,
+            _ => storage_enums::PayoutStatus::Failed,
+        };
+        Ok(Self {
+            response: Ok(types::PayoutsResponseData {
+                status,
+                connector_payout_id: item.response.psp_reference,
+            }),
+            ..item.data
+        })
+    }
+}
+
 #[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ErrorResponse {
@@ -768,6 +983,10 @@ pub struct ErrorResponse {
 #[serde(rename_all = "camelCase")]
 pub struct AdyenAdditionalDataWH {
     pub hmac_signature: String,
+    pub dispute_status: Option<String>,
+    pub chargeback_reason_code: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub defense_period_ends_at: Option<time::PrimitiveDateTime>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -787,6 +1006,7 @@ pub struct AdyenNotificationRequestItemWH {
     pub merchant_account_code: String,
     pub merchant_reference: String,
     pub success: String,
+    pub reason: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -801,6 +1021,33 @@ pub struct AdyenIncomingWebhook {
     pub notification_items: Vec<AdyenItemObjectWH>,
 }
 
+/// Returns whether the event code is sent by Adyen for a dispute raised against a payment.
+pub fn is_dispute_event(event_code: &str) -> bool {
+    matches!(
+        event_code,
+        "NOTIFICATION_OF_CHARGEBACK" | "CHARGEBACK" | "CHARGEBACK_REVERSED" | "SECOND_CHARGEBACK"
+    )
+}
+
+impl From<AdyenNotificationRequestItemWH> for api::disputes::DisputePayload {
+    fn from(notif: AdyenNotificationRequestItemWH) -> Self {
+        Self {
+            amount: notif.amount.value,
+            currency: notif.amount.currency,
+            connector_status: notif
+                .additional_data
+                .dispute_status
+                .unwrap_or(notif.event_code),
+            connector_dispute_id: notif.psp_reference,
+            connector_reason: notif.reason,
+            connector_reason_code: notif.additional_data.chargeback_reason_code,
+            challenge_required_by: notif.additional_data.defense_period_ends_at,
+            // Adyen reports the fees of chargebacks in its settlement reports only
+            fees: vec![],
+        }
+    }
+}
+
 impl From<AdyenNotificationRequestItemWH> for AdyenResponse {
     fn from(notif: AdyenNotificationRequestItemWH) -> Self {
         Self {
@@ -816,6 +1063,7 @@ impl From<AdyenNotificationRequestItemWH> for AdyenResponse {
             }),
             refusal_reason: None,
             refusal_reason_code: None,
+            additional_data: None,
         }
     }
 }
@@ -31,12 +31,120 @@ impl ConnectorCommon for Applepay {
     }
 }
 
+impl api::ConnectorAccessToken for Applepay {}
+
+impl
+    services::ConnectorIntegration<
+        api::AccessTokenAuth,
+        types::AccessTokenRequestData,
+        types::AccessToken,
+    > for Applepay
+{
+}
+
+impl api::ConnectorVerifyWebhookSource for Applepay {}
+
+impl
+    services::ConnectorIntegration<
+        api::VerifyWebhookSource,
+        types::VerifyWebhookSourceRequestData,
+        types::VerifyWebhookSourceResponseData,
+    > for Applepay
+{
+}
+
+impl api::Payout for Applepay {}
+impl api::PayoutCreate for Applepay {}
+impl api::PayoutFulfill for Applepay {}
+impl api::PayoutCancel for Applepay {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Applepay
+{
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Applepay
+{
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Applepay
+{
+}
+
+impl api::FraudCheck for Applepay {}
+impl api::FraudCheckPreAuth for Applepay {}
+impl api::FraudCheckPostAuth for Applepay {}
+
+impl
+    services::ConnectorIntegration<
+        api::FraudCheckPre,
+        types::FraudCheckRequestData,
+        types::FraudCheckResponseData,
+    > for Applepay
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::FraudCheckPost,
+        types::FraudCheckRequestData,
+        types::FraudCheckResponseData,
+    > for Applepay
+{
+}
+
+impl api::ExternalAuthentication for Applepay {}
+
+impl
+    services::ConnectorIntegration<
+        api::ThreeDsAuthentication,
+        types::ThreeDsAuthenticationRequestData,
+        types::ThreeDsAuthenticationData,
+    > for Applepay
+{
+}
+
+impl api::Dispute for Applepay {}
+impl api::SubmitEvidence for Applepay {}
+impl api::DefendDispute for Applepay {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Applepay
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Defend,
+        types::DefendDisputeRequestData,
+        types::DefendDisputeResponse,
+    > for Applepay
+{
+}
+
 impl api::Payment for Applepay {}
 impl api::PaymentAuthorize for Applepay {}
 impl api::PaymentSync for Applepay {}
 impl api::PaymentVoid for Applepay {}
 impl api::PaymentCapture for Applepay {}
 impl api::PreVerify for Applepay {}
+impl api::PaymentAuthorizeSessionToken for Applepay {}
+
+impl
+    services::ConnectorIntegration<
+        api::AuthorizeSessionToken,
+        types::AuthorizeSessionTokenData,
+        types::AuthorizeSessionTokenResponse,
+    > for Applepay
+{
+}
+
 impl api::PaymentSession for Applepay {}
 
 impl
@@ -11,13 +11,14 @@ use crate::{
     configs::settings,
     consts,
     core::errors::{self, CustomResult},
+    db::StorageInterface,
     headers,
     services::{self, logger},
     types::{
         self,
         api::{self, ConnectorCommon},
     },
-    utils::{self, BytesExt},
+    utils::{self, crypto, ByteSliceExt, BytesExt},
 };
 
 #[derive(Debug, Clone)]
@@ -35,6 +36,112 @@ impl ConnectorCommon for Authorizedotnet {
     fn base_url<'a>(&self, connectors: &'a settings::Connectors) -> &'a str {
         connectors.authorizedotnet.base_url.as_ref()
     }
+
+    fn is_retryable_error(&self, error_code: &str) -> bool {
+        // Response reason codes for errors that occurred while the transaction was being
+        // processed, and which Authorize.Net asks to be retried
+        const RETRYABLE_ERROR_CODES: [&str; 14] = [
+            "19", "20", "21", "22", "23", "25", "26", "57", "58", "59", "60", "61", "62", "63",
+        ];
+        RETRYABLE_ERROR_CODES.contains(&error_code)
+    }
+}
+
+impl api::ConnectorAccessToken for Authorizedotnet {}
+
+impl
+    services::ConnectorIntegration<
+        api::AccessTokenAuth,
+        types::AccessTokenRequestData,
+        types::AccessToken,
+    > for Authorizedotnet
+{
+}
+
+impl api::ConnectorVerifyWebhookSource for Authorizedotnet {}
+
+impl
+    services::ConnectorIntegration<
+        api::VerifyWebhookSource,
+        types::VerifyWebhookSourceRequestData,
+        types::VerifyWebhookSourceResponseData,
+    > for Authorizedotnet
+{
+}
+
+impl api::Payout for Authorizedotnet {}
+impl api::PayoutCreate for Authorizedotnet {}
+impl api::PayoutFulfill for Authorizedotnet {}
+impl api::PayoutCancel for Authorizedotnet {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Authorizedotnet
+{
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Authorizedotnet
+{
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Authorizedotnet
+{
+}
+
+impl api::FraudCheck for Authorizedotnet {}
+impl api::FraudCheckPreAuth for Authorizedotnet {}
+impl api::FraudCheckPostAuth for Authorizedotnet {}
+
+impl
+    services::ConnectorIntegration<
+        api::FraudCheckPre,
+        types::FraudCheckRequestData,
+        types::FraudCheckResponseData,
+    > for Authorizedotnet
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::FraudCheckPost,
+        types::FraudCheckRequestData,
+        types::FraudCheckResponseData,
+    > for Authorizedotnet
+{
+}
+
+impl api::ExternalAuthentication for Authorizedotnet {}
+
+impl
+    services::ConnectorIntegration<
+        api::ThreeDsAuthentication,
+        types::ThreeDsAuthenticationRequestData,
+        types::ThreeDsAuthenticationData,
+    > for Authorizedotnet
+{
+}
+
+impl api::Dispute for Authorizedotnet {}
+impl api::SubmitEvidence for Authorizedotnet {}
+impl api::DefendDispute for Authorizedotnet {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Authorizedotnet
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Defend,
+        types::DefendDisputeRequestData,
+        types::DefendDisputeResponse,
+    > for Authorizedotnet
+{
 }
 
 impl api::Payment for Authorizedotnet {}
@@ -42,6 +149,17 @@ impl api::PaymentAuthorize for Authorizedotnet {}
 impl api::PaymentSync for Authorizedotnet {}
 impl api::PaymentVoid for Authorizedotnet {}
 impl api::PaymentCapture for Authorizedotnet {}
+impl api::PaymentAuthorizeSessionToken for Authorizedotnet {}
+
+impl
+    services::ConnectorIntegration<
+        api::AuthorizeSessionToken,
+        types::AuthorizeSessionTokenData,
+        types::AuthorizeSessionTokenResponse,
+    > for Authorizedotnet
+{
+}
+
 impl api::PaymentSession for Authorizedotnet {}
 
 impl
@@ -73,7 +191,95 @@ impl
         types::PaymentsResponseData,
     > for Authorizedotnet
 {
-    // Not Implemented (R)
+    fn get_headers(
+        &self,
+        _req: &types::PaymentsCaptureRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        // This connector does not require an auth header, the authentication details are sent in the request body
+        Ok(vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                types::PaymentsCaptureType::get_content_type(self).to_string(),
+            ),
+            (headers::X_ROUTER.to_string(), "test".to_string()),
+        ])
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::PaymentsCaptureRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(self.base_url(connectors).to_string())
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PaymentsCaptureRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let authorizedotnet_req =
+            utils::Encode::<authorizedotnet::CaptureTransactionRequest>::convert_and_encode(req)
+                .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(authorizedotnet_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PaymentsCaptureRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PaymentsCaptureType::get_url(self, req, connectors)?)
+                .headers(types::PaymentsCaptureType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::PaymentsCaptureType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsCaptureRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::PaymentsCaptureRouterData, errors::ConnectorError> {
+        use bytes::Buf;
+
+        // Handle the case where response bytes contains U+FEFF (BOM) character sent by connector
+        let encoding = encoding_rs::UTF_8;
+        let intermediate_response = encoding.decode_with_bom_removal(res.response.chunk());
+        let intermediate_response =
+            bytes::Bytes::copy_from_slice(intermediate_response.0.as_bytes());
+
+        let response: authorizedotnet::AuthorizedotnetPaymentsResponse = intermediate_response
+            .parse_struct("AuthorizedotnetPaymentsResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        logger::debug!(authorizedotnetpayments_capture_response=?response);
+
+        types::RouterData::try_from((
+            types::ResponseRouterData {
+                response,
+                data: data.clone(),
+                http_code: res.status_code,
+            },
+            authorizedotnet::TransactionFlow::Capture,
+        ))
+        .change_context(errors::ConnectorError::ResponseDeserializationFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Bytes,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        get_error_response(res)
+    }
 }
 
 impl
@@ -253,11 +459,14 @@ impl
             .parse_struct("AuthorizedotnetPaymentsResponse")
             .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
 
-        types::RouterData::try_from(types::ResponseRouterData {
-            response,
-            data: data.clone(),
-            http_code: res.status_code,
-        })
+        types::RouterData::try_from((
+            types::ResponseRouterData {
+                response,
+                data: data.clone(),
+                http_code: res.status_code,
+            },
+            authorizedotnet::TransactionFlow::from(&data.request),
+        ))
         .change_context(errors::ConnectorError::ResponseDeserializationFailed)
     }
 
@@ -346,11 +555,14 @@ impl
             .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
         logger::debug!(authorizedotnetpayments_create_response=?response);
 
-        types::RouterData::try_from(types::ResponseRouterData {
-            response,
-            data: data.clone(),
-            http_code: res.status_code,
-        })
+        types::RouterData::try_from((
+            types::ResponseRouterData {
+                response,
+                data: data.clone(),
+                http_code: res.status_code,
+            },
+            authorizedotnet::TransactionFlow::Void,
+        ))
         .change_context(errors::ConnectorError::ResponseDeserializationFailed)
     }
 
@@ -549,25 +761,95 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
 
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Authorizedotnet {
-    fn get_webhook_object_reference_id(
+    fn get_webhook_source_verification_algorithm(
+        &self,
+        _headers: &actix_web::http::header::HeaderMap,
+        _body: &[u8],
+    ) -> CustomResult<Box<dyn crypto::VerifySignature + Send>, errors::ConnectorError> {
+        Ok(Box::new(crypto::HmacSha512))
+    }
+
+    /// The signature header is of the form `SHA512=<hex encoded signature>`.
+    fn get_webhook_source_verification_signature(
         &self,
+        headers: &actix_web::http::header::HeaderMap,
         _body: &[u8],
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let signature = headers
+            .get(headers::X_ANET_SIGNATURE)
+            .and_then(|header_value| header_value.to_str().ok())
+            .and_then(|header_value| {
+                header_value
+                    .strip_prefix("sha512=")
+                    .or_else(|| header_value.strip_prefix("SHA512="))
+            })
+            .ok_or(errors::ConnectorError::WebhookSignatureNotFound)
+            .into_report()?;
+
+        hex::decode(signature.to_lowercase())
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookSignatureNotFound)
+    }
+
+    fn get_webhook_source_verification_message(
+        &self,
+        _headers: &actix_web::http::header::HeaderMap,
+        body: &[u8],
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        Ok(body.to_vec())
+    }
+
+    async fn get_webhook_source_verification_merchant_secret(
+        &self,
+        db: &dyn StorageInterface,
+        merchant_id: &str,
+    ) -> CustomResult<api::WebhookSourceVerificationSecrets, errors::ConnectorError> {
+        api::get_webhook_source_verification_secrets(db, self.id(), merchant_id).await
+    }
+
+    fn get_webhook_object_reference_id(
+        &self,
+        body: &[u8],
     ) -> CustomResult<String, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        let event: authorizedotnet::AuthorizedotnetWebhookEvent = body
+            .parse_struct("AuthorizedotnetWebhookEvent")
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+
+        Ok(event.payload.id)
     }
 
     fn get_webhook_event_type(
         &self,
-        _body: &[u8],
+        body: &[u8],
     ) -> CustomResult<api::IncomingWebhookEvent, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        let event: authorizedotnet::AuthorizedotnetWebhookEvent = body
+            .parse_struct("AuthorizedotnetWebhookEvent")
+            .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+
+        match event.event_type {
+            authorizedotnet::AuthorizedotnetWebhookEventType::AuthCaptureCreated
+            | authorizedotnet::AuthorizedotnetWebhookEventType::CaptureCreated
+            | authorizedotnet::AuthorizedotnetWebhookEventType::PriorAuthCaptureCreated => {
+                Ok(api::IncomingWebhookEvent::PaymentIntentSuccess)
+            }
+            authorizedotnet::AuthorizedotnetWebhookEventType::RefundCreated => {
+                Ok(api::IncomingWebhookEvent::RefundSuccess)
+            }
+            _ => Err(errors::ConnectorError::WebhookEventTypeNotFound).into_report(),
+        }
     }
 
     fn get_webhook_resource_object(
         &self,
-        _body: &[u8],
+        body: &[u8],
     ) -> CustomResult<serde_json::Value, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        let event: authorizedotnet::AuthorizedotnetWebhookEvent = body
+            .parse_struct("AuthorizedotnetWebhookEvent")
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+
+        serde_json::to_value(authorizedotnet::AuthorizedotnetSyncResponse::from(event))
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)
     }
 }
 
@@ -1,4 +1,8 @@
-use common_utils::ext_traits::{Encode, ValueExt};
+use api_models::enums as api_enums;
+use common_utils::{
+    ext_traits::{Encode, ValueExt},
+    types::MinorUnit,
+};
 use error_stack::ResultExt;
 use serde::{Deserialize, Serialize};
 
@@ -13,6 +17,10 @@ use crate::{
 pub enum TransactionType {
     #[serde(rename = "authCaptureTransaction")]
     Payment,
+    #[serde(rename = "authOnlyTransaction")]
+    Authorization,
+    #[serde(rename = "priorAuthCaptureTransaction")]
+    Capture,
     #[serde(rename = "refundTransaction")]
     Refund,
     #[serde(rename = "voidTransaction")]
@@ -64,6 +72,8 @@ enum PaymentDetails {
     Wallet,
     Klarna,
     Paypal,
+    Upi,
+    BankDebit,
 }
 
 impl From<api_models::payments::PaymentMethod> for PaymentDetails {
@@ -85,6 +95,8 @@ impl From<api_models::payments::PaymentMethod> for PaymentDetails {
             api::PaymentMethod::PayLater(_) => Self::Klarna,
             api::PaymentMethod::Wallet(_) => Self::Wallet,
             api::PaymentMethod::Paypal => Self::Paypal,
+            api::PaymentMethod::Upi(_) => Self::Upi,
+            api::PaymentMethod::BankDebit(_) => Self::BankDebit,
         }
     }
 }
@@ -93,7 +105,7 @@ impl From<api_models::payments::PaymentMethod> for PaymentDetails {
 #[serde(rename_all = "camelCase")]
 struct TransactionRequest {
     transaction_type: TransactionType,
-    amount: i64,
+    amount: String,
     currency_code: String,
     payment: PaymentDetails,
     authorization_indicator_type: Option<AuthorizationIndicator>,
@@ -105,6 +117,15 @@ struct AuthorizationIndicator {
     authorization_indicator: AuthorizationType,
 }
 
+#[derive(Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct TransactionCaptureRequest {
+    transaction_type: TransactionType,
+    amount: String,
+    #[serde(rename = "refTransId")]
+    reference_transaction_id: String,
+}
+
 #[derive(Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 struct TransactionVoidRequest {
@@ -119,6 +140,13 @@ pub struct AuthorizedotnetPaymentsRequest {
     transaction_request: TransactionRequest,
 }
 
+#[derive(Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorizedotnetPaymentCaptureRequest {
+    merchant_authentication: MerchantAuthentication,
+    transaction_request: TransactionCaptureRequest,
+}
+
 #[derive(Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct AuthorizedotnetPaymentCancelRequest {
@@ -133,6 +161,12 @@ pub struct CreateTransactionRequest {
     create_transaction_request: AuthorizedotnetPaymentsRequest,
 }
 
+#[derive(Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CaptureTransactionRequest {
+    create_transaction_request: AuthorizedotnetPaymentCaptureRequest,
+}
+
 #[derive(Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct CancelTransactionRequest {
@@ -163,9 +197,15 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for CreateTransactionRequest {
             item.request.capture_method.map(|c| AuthorizationIndicator {
                 authorization_indicator: c.into(),
             });
+        let transaction_type = if is_auto_capture(item.request.capture_method) {
+            TransactionType::Payment
+        } else {
+            TransactionType::Authorization
+        };
         let transaction_request = TransactionRequest {
-            transaction_type: TransactionType::Payment,
-            amount: item.request.amount,
+            transaction_type,
+            amount: MinorUnit::new(item.request.amount)
+                .to_major_unit_as_string(&item.request.currency),
             payment: payment_details,
             currency_code: item.request.currency.to_string(),
             authorization_indicator_type,
@@ -182,6 +222,33 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for CreateTransactionRequest {
     }
 }
 
+impl From<&types::PaymentsCaptureData> for TransactionCaptureRequest {
+    fn from(item: &types::PaymentsCaptureData) -> Self {
+        Self {
+            transaction_type: TransactionType::Capture,
+            amount: MinorUnit::new(item.amount_to_capture.unwrap_or(item.amount))
+                .to_major_unit_as_string(&item.currency),
+            reference_transaction_id: item.connector_transaction_id.clone(),
+        }
+    }
+}
+
+impl TryFrom<&types::PaymentsCaptureRouterData> for CaptureTransactionRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
+        let transaction_request = TransactionCaptureRequest::from(&item.request);
+
+        let merchant_authentication = MerchantAuthentication::try_from(&item.connector_auth_type)?;
+
+        Ok(Self {
+            create_transaction_request: AuthorizedotnetPaymentCaptureRequest {
+                merchant_authentication,
+                transaction_request,
+            },
+        })
+    }
+}
+
 impl TryFrom<&types::PaymentsCancelRouterData> for CancelTransactionRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(item: &types::PaymentsCancelRouterData) -> Result<Self, Self::Error> {
@@ -216,18 +283,144 @@ pub enum AuthorizedotnetPaymentStatus {
 
 pub type AuthorizedotnetRefundStatus = AuthorizedotnetPaymentStatus;
 
-impl From<AuthorizedotnetPaymentStatus> for enums::AttemptStatus {
-    fn from(item: AuthorizedotnetPaymentStatus) -> Self {
-        match item {
-            AuthorizedotnetPaymentStatus::Approved => Self::Charged,
-            AuthorizedotnetPaymentStatus::Declined | AuthorizedotnetPaymentStatus::Error => {
-                Self::Failure
+fn is_auto_capture(capture_method: Option<enums::CaptureMethod>) -> bool {
+    matches!(capture_method, Some(enums::CaptureMethod::Automatic) | None)
+}
+
+/// The transaction which was requested, which determines the status of an approved transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionFlow {
+    Authorize { auto_capture: bool },
+    Capture,
+    Void,
+}
+
+impl From<&types::PaymentsAuthorizeData> for TransactionFlow {
+    fn from(item: &types::PaymentsAuthorizeData) -> Self {
+        Self::Authorize {
+            auto_capture: is_auto_capture(item.capture_method),
+        }
+    }
+}
+
+fn get_payment_status(
+    status: AuthorizedotnetPaymentStatus,
+    flow: TransactionFlow,
+) -> enums::AttemptStatus {
+    match status {
+        AuthorizedotnetPaymentStatus::Approved => match flow {
+            TransactionFlow::Authorize {
+                auto_capture: false,
+            } => enums::AttemptStatus::Authorized,
+            TransactionFlow::Authorize { auto_capture: true } | TransactionFlow::Capture => {
+                enums::AttemptStatus::Charged
+            }
+            TransactionFlow::Void => enums::AttemptStatus::Voided,
+        },
+        AuthorizedotnetPaymentStatus::Declined | AuthorizedotnetPaymentStatus::Error => {
+            match flow {
+                TransactionFlow::Authorize { .. } => enums::AttemptStatus::Failure,
+                TransactionFlow::Capture => enums::AttemptStatus::CaptureFailed,
+                TransactionFlow::Void => enums::AttemptStatus::VoidFailed,
             }
-            AuthorizedotnetPaymentStatus::HeldForReview => Self::Pending,
         }
+        AuthorizedotnetPaymentStatus::HeldForReview => enums::AttemptStatus::Pending,
     }
 }
 
+/// Address verification result codes returned by Authorize.Net.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub enum AvsResultCode {
+    /// Street address matched, postal code did not
+    A,
+    /// Address information was not provided for the check
+    B,
+    /// The check returned an error
+    E,
+    /// The card was issued by a non-US bank which does not support the check
+    G,
+    /// Neither the street address nor the postal code matched
+    N,
+    /// The check is not applicable to the transaction
+    P,
+    /// The check was unavailable, the transaction may be retried
+    R,
+    /// The issuer does not support the check
+    S,
+    /// Address information is not available for the card
+    U,
+    /// 9 digit postal code matched, street address did not
+    W,
+    /// Street address and 9 digit postal code matched
+    X,
+    /// Street address and 5 digit postal code matched
+    Y,
+    /// 5 digit postal code matched, street address did not
+    Z,
+}
+
+impl From<AvsResultCode> for api_enums::AvsResult {
+    fn from(item: AvsResultCode) -> Self {
+        match item {
+            AvsResultCode::X | AvsResultCode::Y => Self::Match,
+            AvsResultCode::A => Self::AddressMatch,
+            AvsResultCode::W | AvsResultCode::Z => Self::PostalCodeMatch,
+            AvsResultCode::N => Self::NoMatch,
+            AvsResultCode::B
+            | AvsResultCode::G
+            | AvsResultCode::P
+            | AvsResultCode::S
+            | AvsResultCode::U => Self::Unavailable,
+            AvsResultCode::E | AvsResultCode::R => Self::Error,
+        }
+    }
+}
+
+/// Card security code verification result codes returned by Authorize.Net.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub enum CvvResultCode {
+    /// The security code matched
+    M,
+    /// The security code did not match
+    N,
+    /// The security code was not processed
+    P,
+    /// The security code should be on the card, but was not provided
+    S,
+    /// The issuer is not certified for the check
+    U,
+}
+
+impl From<CvvResultCode> for api_enums::CvvResult {
+    fn from(item: CvvResultCode) -> Self {
+        match item {
+            CvvResultCode::M => Self::Match,
+            CvvResultCode::N => Self::NoMatch,
+            CvvResultCode::P => Self::NotProcessed,
+            CvvResultCode::S => Self::NotProvided,
+            CvvResultCode::U => Self::Unavailable,
+        }
+    }
+}
+
+fn get_payment_checks(
+    transaction_response: &TransactionResponse,
+) -> Option<api_models::payments::PaymentChecks> {
+    let avs_result = transaction_response
+        .avs_result_code
+        .clone()
+        .map(api_enums::AvsResult::from);
+    let cvv_result = transaction_response
+        .cvv_result_code
+        .clone()
+        .map(api_enums::CvvResult::from);
+
+    (avs_result.is_some() || cvv_result.is_some()).then_some(api_models::payments::PaymentChecks {
+        avs_result,
+        cvv_result,
+    })
+}
+
 #[derive(Debug, Clone, Deserialize, PartialEq)]
 struct ResponseMessage {
     code: String,
@@ -263,6 +456,21 @@ pub struct TransactionResponse {
     transaction_id: String,
     pub(super) account_number: Option<String>,
     pub(super) errors: Option<Vec<ErrorMessage>>,
+    #[serde(default, deserialize_with = "deserialize_result_code")]
+    avs_result_code: Option<AvsResultCode>,
+    #[serde(default, deserialize_with = "deserialize_result_code")]
+    cvv_result_code: Option<CvvResultCode>,
+}
+
+/// Result codes the router does not know of, and empty result codes which are sent when no check
+/// was performed, are ignored.
+fn deserialize_result_code<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: serde::de::DeserializeOwned,
+{
+    let value = Option::<serde_json::Value>::deserialize(deserializer)?;
+    Ok(value.and_then(|value| serde_json::from_value(value).ok()))
 }
 
 #[derive(Debug, Clone, Deserialize, PartialEq)]
@@ -273,25 +481,33 @@ pub struct AuthorizedotnetPaymentsResponse {
 }
 
 impl<F, T>
-    TryFrom<
+    TryFrom<(
         types::ResponseRouterData<
             F,
             AuthorizedotnetPaymentsResponse,
             T,
             types::PaymentsResponseData,
         >,
-    > for types::RouterData<F, T, types::PaymentsResponseData>
+        TransactionFlow,
+    )> for types::RouterData<F, T, types::PaymentsResponseData>
 {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(
-        item: types::ResponseRouterData<
-            F,
-            AuthorizedotnetPaymentsResponse,
-            T,
-            types::PaymentsResponseData,
-        >,
+        (item, flow): (
+            types::ResponseRouterData<
+                F,
+                AuthorizedotnetPaymentsResponse,
+                T,
+                types::PaymentsResponseData,
+            >,
+            TransactionFlow,
+        ),
     ) -> Result<Self, Self::Error> {
-        let status = enums::AttemptStatus::from(item.response.transaction_response.response_code);
+        let status = get_payment_status(
+            item.response.transaction_response.response_code.clone(),
+            flow,
+        );
+        let payment_checks = get_payment_checks(&item.response.transaction_response);
         let error = item
             .response
             .transaction_response
@@ -332,6 +548,7 @@ impl<F, T>
                     connector_metadata: metadata,
                 }),
             },
+            payment_checks,
             ..item.data
         })
     }
@@ -341,7 +558,7 @@ impl<F, T>
 #[serde(rename_all = "camelCase")]
 struct RefundTransactionRequest {
     transaction_type: TransactionType,
-    amount: i64,
+    amount: String,
     currency_code: String,
     payment: PaymentDetails,
     #[serde(rename = "refTransId")]
@@ -379,7 +596,8 @@ impl<F> TryFrom<&types::RefundsRouterData<F>> for CreateRefundRequest {
 
         let transaction_request = RefundTransactionRequest {
             transaction_type: TransactionType::Refund,
-            amount: item.request.refund_amount,
+            amount: MinorUnit::new(item.request.refund_amount)
+                .to_major_unit_as_string(&item.request.currency),
             payment: payment_details
                 .parse_value("PaymentDetails")
                 .change_context(errors::ConnectorError::MissingRequiredField {
@@ -514,7 +732,7 @@ impl TryFrom<&types::PaymentsSyncRouterData> for AuthorizedotnetCreateSyncReques
     }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum SyncStatus {
     RefundSettledSuccessfully,
@@ -527,7 +745,7 @@ pub enum SyncStatus {
     CouldNotVoid,
     GeneralError,
 }
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SyncTransactionResponse {
     #[serde(rename = "transId")]
@@ -535,7 +753,7 @@ pub struct SyncTransactionResponse {
     transaction_status: SyncStatus,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct AuthorizedotnetSyncResponse {
     transaction: SyncTransactionResponse,
 }
@@ -556,6 +774,7 @@ impl From<SyncStatus> for enums::AttemptStatus {
             SyncStatus::SettledSuccessfully | SyncStatus::CapturedPendingSettlement => {
                 Self::Charged
             }
+            SyncStatus::AuthorizedPendingCapture => Self::Authorized,
             SyncStatus::Declined => Self::AuthenticationFailed,
             SyncStatus::Voided => Self::Voided,
             SyncStatus::CouldNotVoid => Self::VoidFailed,
@@ -631,6 +850,65 @@ pub struct AuthorizedotnetErrorResponse {
     pub error: ErrorDetails,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorizedotnetWebhookEvent {
+    pub event_type: AuthorizedotnetWebhookEventType,
+    pub payload: AuthorizedotnetWebhookPayload,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+pub enum AuthorizedotnetWebhookEventType {
+    #[serde(rename = "net.authorize.payment.authorization.created")]
+    AuthorizationCreated,
+    #[serde(rename = "net.authorize.payment.authcapture.created")]
+    AuthCaptureCreated,
+    #[serde(rename = "net.authorize.payment.capture.created")]
+    CaptureCreated,
+    #[serde(rename = "net.authorize.payment.priorAuthCapture.created")]
+    PriorAuthCaptureCreated,
+    #[serde(rename = "net.authorize.payment.void.created")]
+    VoidCreated,
+    #[serde(rename = "net.authorize.payment.refund.created")]
+    RefundCreated,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorizedotnetWebhookPayload {
+    /// Identifier of the transaction the event is about
+    pub id: String,
+}
+
+impl From<AuthorizedotnetWebhookEventType> for SyncStatus {
+    fn from(event_type: AuthorizedotnetWebhookEventType) -> Self {
+        match event_type {
+            AuthorizedotnetWebhookEventType::AuthorizationCreated => Self::AuthorizedPendingCapture,
+            AuthorizedotnetWebhookEventType::AuthCaptureCreated
+            | AuthorizedotnetWebhookEventType::CaptureCreated
+            | AuthorizedotnetWebhookEventType::PriorAuthCaptureCreated => {
+                Self::CapturedPendingSettlement
+            }
+            AuthorizedotnetWebhookEventType::VoidCreated => Self::Voided,
+            AuthorizedotnetWebhookEventType::RefundCreated => Self::RefundPendingSettlement,
+            AuthorizedotnetWebhookEventType::Unknown => Self::GeneralError,
+        }
+    }
+}
+
+impl From<AuthorizedotnetWebhookEvent> for AuthorizedotnetSyncResponse {
+    fn from(event: AuthorizedotnetWebhookEvent) -> Self {
+        Self {
+            transaction: SyncTransactionResponse {
+                transaction_id: event.payload.id,
+                transaction_status: SyncStatus::from(event.event_type),
+            },
+        }
+    }
+}
+
 fn construct_refund_payment_details(masked_number: String) -> PaymentDetails {
     PaymentDetails::CreditCard(CreditCardDetails {
         card_number: masked_number.into(),
@@ -638,3 +916,32 @@ fn construct_refund_payment_details(masked_number: String) -> PaymentDetails {
         card_code: None,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_capture_amount_in_major_unit() {
+        let capture_data = |amount_to_capture| types::PaymentsCaptureData {
+            amount_to_capture,
+            currency: enums::Currency::USD,
+            connector_transaction_id: "60123456789".to_string(),
+            amount: 1000,
+            connector_meta: None,
+        };
+        let capture_request = |amount_to_capture| {
+            serde_json::to_value(TransactionCaptureRequest::from(&capture_data(
+                amount_to_capture,
+            )))
+            .unwrap()
+        };
+
+        let request = capture_request(None);
+        assert_eq!(request["transactionType"], "priorAuthCaptureTransaction");
+        assert_eq!(request["amount"], "10.00");
+        assert_eq!(request["refTransId"], "60123456789");
+        assert_eq!(capture_request(Some(505))["amount"], "5.05");
+    }
+}
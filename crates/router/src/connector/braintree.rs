@@ -3,7 +3,8 @@ mod transformers;
 use std::fmt::Debug;
 
 use bytes::Bytes;
-use error_stack::ResultExt;
+use error_stack::{IntoReport, ResultExt};
+use ring::digest;
 
 use self::transformers as braintree;
 use crate::{
@@ -13,12 +14,13 @@ use crate::{
         errors::{self, CustomResult},
         payments,
     },
+    db::StorageInterface,
     headers, logger, services,
     types::{
         self,
         api::{self, ConnectorCommon},
     },
-    utils::{self, BytesExt},
+    utils::{self, crypto, BytesExt, OptionExt},
 };
 
 #[derive(Debug, Clone)]
@@ -44,6 +46,103 @@ impl ConnectorCommon for Braintree {
     }
 }
 
+impl api::ConnectorAccessToken for Braintree {}
+
+impl
+    services::ConnectorIntegration<
+        api::AccessTokenAuth,
+        types::AccessTokenRequestData,
+        types::AccessToken,
+    > for Braintree
+{
+}
+
+impl api::ConnectorVerifyWebhookSource for Braintree {}
+
+impl
+    services::ConnectorIntegration<
+        api::VerifyWebhookSource,
+        types::VerifyWebhookSourceRequestData,
+        types::VerifyWebhookSourceResponseData,
+    > for Braintree
+{
+}
+
+impl api::Payout for Braintree {}
+impl api::PayoutCreate for Braintree {}
+impl api::PayoutFulfill for Braintree {}
+impl api::PayoutCancel for Braintree {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Braintree
+{
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Braintree
+{
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Braintree
+{
+}
+
+impl api::FraudCheck for Braintree {}
+impl api::FraudCheckPreAuth for Braintree {}
+impl api::FraudCheckPostAuth for Braintree {}
+
+impl
+    services::ConnectorIntegration<
+        api::FraudCheckPre,
+        types::FraudCheckRequestData,
+        types::FraudCheckResponseData,
+    > for Braintree
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::FraudCheckPost,
+        types::FraudCheckRequestData,
+        types::FraudCheckResponseData,
+    > for Braintree
+{
+}
+
+impl api::ExternalAuthentication for Braintree {}
+
+impl
+    services::ConnectorIntegration<
+        api::ThreeDsAuthentication,
+        types::ThreeDsAuthenticationRequestData,
+        types::ThreeDsAuthenticationData,
+    > for Braintree
+{
+}
+
+impl api::Dispute for Braintree {}
+impl api::SubmitEvidence for Braintree {}
+impl api::DefendDispute for Braintree {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Braintree
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Defend,
+        types::DefendDisputeRequestData,
+        types::DefendDisputeResponse,
+    > for Braintree
+{
+}
+
 impl api::Payment for Braintree {}
 
 impl api::PaymentAuthorize for Braintree {}
@@ -51,6 +150,17 @@ impl api::PaymentSync for Braintree {}
 impl api::PaymentVoid for Braintree {}
 impl api::PaymentCapture for Braintree {}
 
+impl api::PaymentAuthorizeSessionToken for Braintree {}
+
+impl
+    services::ConnectorIntegration<
+        api::AuthorizeSessionToken,
+        types::AuthorizeSessionTokenData,
+        types::AuthorizeSessionTokenResponse,
+    > for Braintree
+{
+}
+
 impl api::PaymentSession for Braintree {}
 
 impl
@@ -176,7 +286,6 @@ impl
     // Not Implemented (R)
 }
 
-#[allow(dead_code)]
 impl
     services::ConnectorIntegration<
         api::Capture,
@@ -184,7 +293,103 @@ impl
         types::PaymentsResponseData,
     > for Braintree
 {
-    // Not Implemented (R)
+    fn get_headers(
+        &self,
+        req: &types::PaymentsCaptureRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut headers = vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                types::PaymentsCaptureType::get_content_type(self).to_string(),
+            ),
+            (headers::X_ROUTER.to_string(), "test".to_string()),
+            (headers::X_API_VERSION.to_string(), "6".to_string()),
+            (headers::ACCEPT.to_string(), "application/json".to_string()),
+        ];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        headers.append(&mut api_key);
+        Ok(headers)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        "application/json"
+    }
+
+    fn get_url(
+        &self,
+        req: &types::PaymentsCaptureRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let auth_type = braintree::BraintreeAuthType::try_from(&req.connector_auth_type)
+            .change_context(errors::ConnectorError::FailedToObtainAuthType)?;
+        Ok(format!(
+            "{}merchants/{}/transactions/{}/submit_for_settlement",
+            self.base_url(connectors),
+            auth_type.merchant_account,
+            req.request.connector_transaction_id
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PaymentsCaptureRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let braintree_req =
+            utils::Encode::<braintree::BraintreeCaptureRequest>::convert_and_encode(req)
+                .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(braintree_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PaymentsCaptureRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Put)
+                .url(&types::PaymentsCaptureType::get_url(self, req, connectors)?)
+                .headers(types::PaymentsCaptureType::get_headers(
+                    self, req, connectors,
+                )?)
+                .body(types::PaymentsCaptureType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsCaptureRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::PaymentsCaptureRouterData, errors::ConnectorError> {
+        let response: braintree::BraintreePaymentsResponse = res
+            .response
+            .parse_struct("Braintree PaymentsCaptureResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        logger::debug!(braintreepayments_capture_response=?response);
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Bytes,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: braintree::ErrorResponse = res
+            .parse_struct("Braintree ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        Ok(types::ErrorResponse {
+            code: consts::NO_ERROR_CODE.to_string(),
+            message: response.api_error_response.message,
+            reason: None,
+        })
+    }
 }
 
 impl
@@ -536,7 +741,7 @@ impl services::ConnectorIntegration<api::Execute, types::RefundsData, types::Ref
             .change_context(errors::ConnectorError::FailedToObtainAuthType)?;
         let connector_payment_id = req.request.connector_transaction_id.clone();
         Ok(format!(
-            "{}merchants/{}/transactions/{}",
+            "{}merchants/{}/transactions/{}/refund",
             self.base_url(connectors),
             auth_type.merchant_account,
             connector_payment_id
@@ -548,7 +753,7 @@ impl services::ConnectorIntegration<api::Execute, types::RefundsData, types::Ref
         req: &types::RefundsRouterData<api::Execute>,
     ) -> CustomResult<Option<String>, errors::ConnectorError> {
         let braintree_req =
-            utils::Encode::<braintree::BraintreeRefundRequest>::convert_and_url_encode(req)
+            utils::Encode::<braintree::BraintreeRefundRequest>::convert_and_encode(req)
                 .change_context(errors::ConnectorError::RequestEncodingFailed)?;
         Ok(Some(braintree_req))
     }
@@ -578,7 +783,7 @@ impl services::ConnectorIntegration<api::Execute, types::RefundsData, types::Ref
         let response: braintree::RefundResponse = res
             .response
             .parse_struct("Braintree RefundResponse")
-            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
         types::ResponseRouterData {
             response,
             data: data.clone(),
@@ -590,66 +795,94 @@ impl services::ConnectorIntegration<api::Execute, types::RefundsData, types::Ref
 
     fn get_error_response(
         &self,
-        _res: Bytes,
+        res: Bytes,
     ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
-        Err(errors::ConnectorError::NotImplemented("braintree".to_string()).into())
+        let response: braintree::ErrorResponse = res
+            .parse_struct("Braintree ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        Ok(types::ErrorResponse {
+            code: consts::NO_ERROR_CODE.to_string(),
+            message: response.api_error_response.message,
+            reason: None,
+        })
     }
 }
 
-#[allow(dead_code)]
 impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponseData>
     for Braintree
 {
     fn get_headers(
         &self,
-        _req: &types::RouterData<api::RSync, types::RefundsData, types::RefundsResponseData>,
+        req: &types::RefundSyncRouterData,
         _connectors: &settings::Connectors,
     ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
-        Err(errors::ConnectorError::NotImplemented("braintree".to_string()).into())
+        let mut headers = vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                types::RefundSyncType::get_content_type(self).to_string(),
+            ),
+            (headers::X_ROUTER.to_string(), "test".to_string()),
+            (headers::X_API_VERSION.to_string(), "6".to_string()),
+            (headers::ACCEPT.to_string(), "application/json".to_string()),
+        ];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        headers.append(&mut api_key);
+        Ok(headers)
     }
 
     fn get_content_type(&self) -> &'static str {
-        ""
+        "application/json"
     }
 
     fn get_url(
         &self,
-        _req: &types::RouterData<api::RSync, types::RefundsData, types::RefundsResponseData>,
-        _connectors: &settings::Connectors,
+        req: &types::RefundSyncRouterData,
+        connectors: &settings::Connectors,
     ) -> CustomResult<String, errors::ConnectorError> {
-        Err(errors::ConnectorError::NotImplemented("braintree".to_string()).into())
-    }
-
-    fn get_error_response(
-        &self,
-        _res: Bytes,
-    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
-        Err(errors::ConnectorError::NotImplemented("braintree".to_string()).into())
+        let auth_type = braintree::BraintreeAuthType::try_from(&req.connector_auth_type)
+            .change_context(errors::ConnectorError::FailedToObtainAuthType)?;
+        let connector_refund_id = req
+            .response
+            .clone()
+            .ok()
+            .get_required_value("response")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?
+            .connector_refund_id;
+        Ok(format!(
+            "{}merchants/{}/transactions/{}",
+            self.base_url(connectors),
+            auth_type.merchant_account,
+            connector_refund_id
+        ))
     }
 
     fn get_request_body(
         &self,
-        _req: &types::RouterData<api::RSync, types::RefundsData, types::RefundsResponseData>,
+        _req: &types::RefundSyncRouterData,
     ) -> CustomResult<Option<String>, errors::ConnectorError> {
         Ok(None)
     }
 
     fn build_request(
         &self,
-        _req: &types::RouterData<api::RSync, types::RefundsData, types::RefundsResponseData>,
-        _connectors: &settings::Connectors,
+        req: &types::RefundSyncRouterData,
+        connectors: &settings::Connectors,
     ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
-        Ok(None)
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Get)
+                .url(&types::RefundSyncType::get_url(self, req, connectors)?)
+                .headers(types::RefundSyncType::get_headers(self, req, connectors)?)
+                .build(),
+        ))
     }
 
     fn handle_response(
         &self,
-        data: &types::RouterData<api::RSync, types::RefundsData, types::RefundsResponseData>,
+        data: &types::RefundSyncRouterData,
         res: types::Response,
-    ) -> CustomResult<
-        types::RouterData<api::RSync, types::RefundsData, types::RefundsResponseData>,
-        errors::ConnectorError,
-    > {
+    ) -> CustomResult<types::RefundSyncRouterData, errors::ConnectorError> {
         logger::debug!(target: "router::connector::braintree", response=?res);
         let response: braintree::RefundResponse = res
             .response
@@ -663,32 +896,130 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
         .try_into()
         .change_context(errors::ConnectorError::ResponseHandlingFailed)
     }
+
+    fn get_error_response(
+        &self,
+        res: Bytes,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: braintree::ErrorResponse = res
+            .parse_struct("Braintree ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        Ok(types::ErrorResponse {
+            code: consts::NO_ERROR_CODE.to_string(),
+            message: response.api_error_response.message,
+            reason: None,
+        })
+    }
 }
 
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Braintree {
-    fn get_webhook_object_reference_id(
+    fn get_webhook_source_verification_algorithm(
         &self,
+        _headers: &actix_web::http::header::HeaderMap,
         _body: &[u8],
+    ) -> CustomResult<Box<dyn crypto::VerifySignature + Send>, errors::ConnectorError> {
+        Ok(Box::new(crypto::HmacSha1))
+    }
+
+    fn get_webhook_source_verification_signature(
+        &self,
+        _headers: &actix_web::http::header::HeaderMap,
+        body: &[u8],
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        get_webhook_notification(body)?.get_signature()
+    }
+
+    fn get_webhook_source_verification_message(
+        &self,
+        _headers: &actix_web::http::header::HeaderMap,
+        body: &[u8],
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        Ok(get_webhook_notification(body)?.bt_payload.into_bytes())
+    }
+
+    /// Braintree signs its webhooks with the SHA-1 digest of the merchant's private key, which is
+    /// the secret stored for the merchant.
+    async fn get_webhook_source_verification_merchant_secret(
+        &self,
+        db: &dyn StorageInterface,
+        merchant_id: &str,
+    ) -> CustomResult<api::WebhookSourceVerificationSecrets, errors::ConnectorError> {
+        let private_keys =
+            api::get_webhook_source_verification_secrets(db, self.id(), merchant_id).await?;
+        let digest_of = |private_key: Vec<u8>| {
+            digest::digest(&digest::SHA1_FOR_LEGACY_USE_ONLY, &private_key)
+                .as_ref()
+                .to_vec()
+        };
+
+        Ok(api::WebhookSourceVerificationSecrets {
+            current: digest_of(private_keys.current),
+            previous: private_keys.previous.map(digest_of),
+        })
+    }
+
+    fn get_webhook_object_reference_id(
+        &self,
+        body: &[u8],
     ) -> CustomResult<String, errors::ConnectorError> {
-        Err(errors::ConnectorError::NotImplemented("braintree".to_string()).into())
+        let notification = get_webhook_notification(body)?
+            .get_notification()
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+
+        Ok(notification.subject.transaction.id)
     }
 
     fn get_webhook_event_type(
         &self,
-        _body: &[u8],
+        body: &[u8],
     ) -> CustomResult<api::IncomingWebhookEvent, errors::ConnectorError> {
-        Err(errors::ConnectorError::NotImplemented("braintree".to_string()).into())
+        let notification = get_webhook_notification(body)?
+            .get_notification()
+            .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+
+        match (notification.kind, notification.subject.transaction.kind) {
+            (
+                braintree::BraintreeWebhookEventType::TransactionSettled,
+                braintree::BraintreeTransactionType::Sale,
+            ) => Ok(api::IncomingWebhookEvent::PaymentIntentSuccess),
+            (
+                braintree::BraintreeWebhookEventType::TransactionSettled,
+                braintree::BraintreeTransactionType::Credit,
+            ) => Ok(api::IncomingWebhookEvent::RefundSuccess),
+            (
+                braintree::BraintreeWebhookEventType::TransactionSettlementDeclined,
+                braintree::BraintreeTransactionType::Credit,
+            ) => Ok(api::IncomingWebhookEvent::RefundFailure),
+            _ => Err(errors::ConnectorError::WebhookEventTypeNotFound).into_report(),
+        }
     }
 
     fn get_webhook_resource_object(
         &self,
-        _body: &[u8],
+        body: &[u8],
     ) -> CustomResult<serde_json::Value, errors::ConnectorError> {
-        Err(errors::ConnectorError::NotImplemented("braintree".to_string()).into())
+        let notification = get_webhook_notification(body)?
+            .get_notification()
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+
+        serde_json::to_value(braintree::BraintreePaymentsResponse::from(
+            notification.subject.transaction,
+        ))
+        .into_report()
+        .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)
     }
 }
 
+fn get_webhook_notification(
+    body: &[u8],
+) -> CustomResult<braintree::BraintreeWebhookNotification, errors::ConnectorError> {
+    serde_urlencoded::from_bytes::<braintree::BraintreeWebhookNotification>(body)
+        .into_report()
+        .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)
+}
+
 impl services::ConnectorRedirectResponse for Braintree {
     fn get_flow_type(
         &self,
@@ -1,8 +1,11 @@
-use error_stack::ResultExt;
+use base64::Engine;
+use common_utils::types::MinorUnit;
+use error_stack::{IntoReport, ResultExt};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    core::errors,
+    consts,
+    core::errors::{self, CustomResult},
     pii::PeekInterface,
     types::{self, api, storage::enums},
     utils::OptionExt,
@@ -90,7 +93,8 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for BraintreePaymentsRequest {
             Some(enums::CaptureMethod::Automatic) | None
         );
 
-        let amount = item.request.amount.to_string();
+        let amount =
+            MinorUnit::new(item.request.amount).to_major_unit_as_string(&item.request.currency);
         let device_data = DeviceData {};
         let options = PaymentOptions {
             submit_for_settlement,
@@ -153,7 +157,7 @@ impl TryFrom<&types::ConnectorAuthType> for BraintreeAuthType {
     }
 }
 
-#[derive(Debug, Default, Clone, Deserialize, Eq, PartialEq)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub enum BraintreePaymentStatus {
     Succeeded,
@@ -177,7 +181,10 @@ impl From<BraintreePaymentStatus> for enums::AttemptStatus {
         match item {
             BraintreePaymentStatus::Succeeded
             | BraintreePaymentStatus::SubmittedForSettlement
-            | BraintreePaymentStatus::Settling => Self::Charged,
+            | BraintreePaymentStatus::Settling
+            | BraintreePaymentStatus::SettlementPending
+            | BraintreePaymentStatus::Settled
+            | BraintreePaymentStatus::SettlementConfirmed => Self::Charged,
             BraintreePaymentStatus::AuthorizedExpired => Self::AuthorizationFailed,
             BraintreePaymentStatus::Failed
             | BraintreePaymentStatus::GatewayRejected
@@ -203,17 +210,20 @@ impl<F, T>
             types::PaymentsResponseData,
         >,
     ) -> Result<Self, Self::Error> {
-        Ok(Self {
-            status: enums::AttemptStatus::from(item.response.transaction.status),
-            response: Ok(types::PaymentsResponseData::TransactionResponse {
-                resource_id: types::ResponseId::ConnectorTransactionId(
-                    item.response.transaction.id,
-                ),
+        let transaction = item.response.transaction;
+        let response = match get_transaction_error_response(&transaction) {
+            Some(error_response) => Err(error_response),
+            None => Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(transaction.id),
                 redirection_data: None,
                 redirect: false,
                 mandate_reference: None,
                 connector_metadata: None,
             }),
+        };
+        Ok(Self {
+            status: enums::AttemptStatus::from(transaction.status),
+            response,
             ..item.data
         })
     }
@@ -244,7 +254,7 @@ impl<F, T>
     }
 }
 
-#[derive(Default, Debug, Clone, Deserialize)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BraintreePaymentsResponse {
     transaction: TransactionResponse,
@@ -262,13 +272,121 @@ pub struct BraintreeSessionTokenResponse {
     pub client_token: ClientToken,
 }
 
-#[derive(Default, Debug, Clone, Deserialize, Eq, PartialEq)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionResponse {
     id: String,
     currency_iso_code: String,
     amount: String,
     status: BraintreePaymentStatus,
+    gateway_rejection_reason: Option<BraintreeGatewayRejectionReason>,
+    processor_response_code: Option<String>,
+    processor_response_text: Option<String>,
+}
+
+/// The reasons for which Braintree's gateway rejects a transaction before it reaches the
+/// processor.
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BraintreeGatewayRejectionReason {
+    ApplicationIncomplete,
+    Avs,
+    AvsAndCvv,
+    Cvv,
+    Duplicate,
+    ExcessiveRetry,
+    Fraud,
+    RiskThreshold,
+    ThreeDSecure,
+    TokenIssuance,
+    #[serde(other)]
+    Unknown,
+}
+
+impl BraintreeGatewayRejectionReason {
+    /// The error code and message reported by the router for the rejection reason, so that
+    /// rejections are reported the same way regardless of how Braintree words them.
+    fn get_error_code_and_message(&self) -> (&'static str, &'static str) {
+        match self {
+            Self::ApplicationIncomplete => (
+                "merchant_account_incomplete",
+                "The merchant account is not yet approved for processing transactions",
+            ),
+            Self::Avs => (
+                "avs_verification_failed",
+                "The billing address could not be verified",
+            ),
+            Self::AvsAndCvv => (
+                "avs_and_cvv_verification_failed",
+                "The billing address and the card security code could not be verified",
+            ),
+            Self::Cvv => (
+                "cvv_verification_failed",
+                "The card security code could not be verified",
+            ),
+            Self::Duplicate => (
+                "duplicate_transaction",
+                "A transaction with the same details was recently processed",
+            ),
+            Self::ExcessiveRetry => (
+                "excessive_retries",
+                "Too many attempts were made with the same payment method",
+            ),
+            Self::Fraud => (
+                "suspected_fraud",
+                "The transaction was flagged as fraudulent",
+            ),
+            Self::RiskThreshold => (
+                "risk_threshold_exceeded",
+                "The transaction exceeded the risk threshold of the merchant account",
+            ),
+            Self::ThreeDSecure => (
+                "three_ds_authentication_failed",
+                "The 3D Secure authentication of the card holder failed",
+            ),
+            Self::TokenIssuance => (
+                "token_issuance_failed",
+                "The payment method token could not be issued",
+            ),
+            Self::Unknown => (
+                consts::NO_ERROR_CODE,
+                "The transaction was rejected by the gateway",
+            ),
+        }
+    }
+}
+
+/// Builds the error response for transactions which were rejected by the gateway or declined by
+/// the processor, since Braintree reports these with a successful HTTP status.
+fn get_transaction_error_response(
+    transaction: &TransactionResponse,
+) -> Option<types::ErrorResponse> {
+    match transaction.status {
+        BraintreePaymentStatus::GatewayRejected => {
+            let rejection_reason = transaction
+                .gateway_rejection_reason
+                .clone()
+                .unwrap_or(BraintreeGatewayRejectionReason::Unknown);
+            let (code, message) = rejection_reason.get_error_code_and_message();
+            Some(types::ErrorResponse {
+                code: code.to_string(),
+                message: message.to_string(),
+                reason: None,
+            })
+        }
+        BraintreePaymentStatus::ProcessorDeclined => Some(types::ErrorResponse {
+            code: transaction
+                .processor_response_code
+                .clone()
+                .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+            message: transaction
+                .processor_response_text
+                .clone()
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: None,
+        }),
+        _ => None,
+    }
 }
 
 #[derive(Debug, Default, Deserialize)]
@@ -282,6 +400,25 @@ pub struct ApiErrorResponse {
     pub message: String,
 }
 
+#[derive(Default, Debug, Clone, Serialize)]
+pub struct BraintreeCaptureRequest {
+    transaction: Amount,
+}
+
+impl TryFrom<&types::PaymentsCaptureRouterData> for BraintreeCaptureRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            transaction: Amount {
+                amount: item.request.amount_to_capture.map(|amount_to_capture| {
+                    MinorUnit::new(amount_to_capture)
+                        .to_major_unit_as_string(&item.request.currency)
+                }),
+            },
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, Serialize)]
 pub struct BraintreeRefundRequest {
     transaction: Amount,
@@ -294,68 +431,153 @@ pub struct Amount {
 
 impl<F> TryFrom<&types::RefundsRouterData<F>> for BraintreeRefundRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
-    fn try_from(_item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
+    fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
         Ok(Self {
-            transaction: Amount { amount: None },
+            transaction: Amount {
+                amount: Some(
+                    MinorUnit::new(item.request.refund_amount)
+                        .to_major_unit_as_string(&item.request.currency),
+                ),
+            },
         })
     }
 }
 
-#[allow(dead_code)]
-#[derive(Debug, Default, Deserialize, Clone)]
-pub enum RefundStatus {
-    Succeeded,
-    Failed,
-    #[default]
-    Processing,
-}
-
-impl From<RefundStatus> for enums::RefundStatus {
-    fn from(item: RefundStatus) -> Self {
+/// Refunds are credit transactions on Braintree, which are only complete once they are settled.
+impl From<BraintreePaymentStatus> for enums::RefundStatus {
+    fn from(item: BraintreePaymentStatus) -> Self {
         match item {
-            RefundStatus::Succeeded => Self::Success,
-            RefundStatus::Failed => Self::Failure,
-            RefundStatus::Processing => Self::Pending,
+            BraintreePaymentStatus::Succeeded
+            | BraintreePaymentStatus::Settled
+            | BraintreePaymentStatus::SettlementConfirmed => Self::Success,
+            BraintreePaymentStatus::Failed
+            | BraintreePaymentStatus::GatewayRejected
+            | BraintreePaymentStatus::ProcessorDeclined
+            | BraintreePaymentStatus::SettlementDeclined
+            | BraintreePaymentStatus::Voided => Self::Failure,
+            _ => Self::Pending,
         }
     }
 }
 
 #[derive(Default, Debug, Clone, Deserialize)]
 pub struct RefundResponse {
-    pub id: String,
-    pub status: RefundStatus,
+    pub transaction: TransactionResponse,
 }
 
-impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
-    for types::RefundsRouterData<api::Execute>
+impl<F> TryFrom<types::RefundsResponseRouterData<F, RefundResponse>>
+    for types::RefundsRouterData<F>
 {
     type Error = error_stack::Report<errors::ParsingError>;
     fn try_from(
-        item: types::RefundsResponseRouterData<api::Execute, RefundResponse>,
+        item: types::RefundsResponseRouterData<F, RefundResponse>,
     ) -> Result<Self, Self::Error> {
-        Ok(Self {
-            response: Ok(types::RefundsResponseData {
-                connector_refund_id: item.response.id,
-                refund_status: enums::RefundStatus::from(item.response.status),
+        let transaction = item.response.transaction;
+        let response = match get_transaction_error_response(&transaction) {
+            Some(error_response) => Err(error_response),
+            None => Ok(types::RefundsResponseData {
+                connector_refund_id: transaction.id,
+                refund_status: enums::RefundStatus::from(transaction.status),
             }),
+        };
+        Ok(Self {
+            response,
             ..item.data
         })
     }
 }
 
-impl TryFrom<types::RefundsResponseRouterData<api::RSync, RefundResponse>>
-    for types::RefundsRouterData<api::RSync>
-{
-    type Error = error_stack::Report<errors::ParsingError>;
-    fn try_from(
-        item: types::RefundsResponseRouterData<api::RSync, RefundResponse>,
-    ) -> Result<Self, Self::Error> {
-        Ok(Self {
-            response: Ok(types::RefundsResponseData {
-                connector_refund_id: item.response.id,
-                refund_status: enums::RefundStatus::from(item.response.status),
-            }),
-            ..item.data
-        })
+#[derive(Debug, Deserialize)]
+pub struct BraintreeWebhookNotification {
+    pub bt_signature: String,
+    pub bt_payload: String,
+}
+
+impl BraintreeWebhookNotification {
+    /// The signature is sent as `<public_key>|<hex encoded signature>`.
+    pub fn get_signature(&self) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let signature = self
+            .bt_signature
+            .split('|')
+            .nth(1)
+            .ok_or(errors::ConnectorError::WebhookSignatureNotFound)
+            .into_report()?;
+        hex::decode(signature)
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookSignatureNotFound)
+    }
+
+    /// The payload is base64 encoded XML, which may be broken into lines.
+    pub fn get_notification(&self) -> CustomResult<BraintreeNotification, errors::ConnectorError> {
+        let payload: String = self
+            .bt_payload
+            .chars()
+            .filter(|character| !character.is_whitespace())
+            .collect();
+        let decoded_payload = consts::BASE64_ENGINE
+            .decode(payload)
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+        let notification = String::from_utf8(decoded_payload)
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+        quick_xml::de::from_str(&notification)
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct BraintreeNotification {
+    pub kind: BraintreeWebhookEventType,
+    pub subject: BraintreeNotificationSubject,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BraintreeWebhookEventType {
+    TransactionSettled,
+    TransactionSettlementDeclined,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BraintreeNotificationSubject {
+    pub transaction: BraintreeWebhookTransaction,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct BraintreeWebhookTransaction {
+    pub id: String,
+    pub status: BraintreePaymentStatus,
+    pub amount: String,
+    pub currency_iso_code: String,
+    #[serde(rename = "type")]
+    pub kind: BraintreeTransactionType,
+}
+
+#[derive(Debug, Clone, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BraintreeTransactionType {
+    Sale,
+    Credit,
+}
+
+impl From<BraintreeWebhookTransaction> for BraintreePaymentsResponse {
+    fn from(item: BraintreeWebhookTransaction) -> Self {
+        Self {
+            transaction: TransactionResponse {
+                id: item.id,
+                currency_iso_code: item.currency_iso_code,
+                amount: item.amount,
+                status: item.status,
+                gateway_rejection_reason: None,
+                processor_response_code: None,
+                processor_response_text: None,
+            },
+        }
     }
 }
@@ -25,6 +25,14 @@ pub struct CardSource {
 #[serde(untagged)]
 pub enum Source {
     Card(CardSource),
+    Paypal(PaypalSource),
+}
+
+/// A PayPal payment, which the customer approves after being redirected to PayPal
+#[derive(Debug, Serialize)]
+pub struct PaypalSource {
+    #[serde(rename = "type")]
+    pub source_type: String,
 }
 
 pub struct CheckoutAuthType {
@@ -55,6 +63,28 @@ pub struct PaymentsRequest {
 pub struct CheckoutThreeDS {
     enabled: bool,
     force_3ds: bool,
+    /// The result of a 3DS authentication performed outside of Checkout
+    #[serde(flatten)]
+    authentication_data: Option<CheckoutThirdPartyAuthentication>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CheckoutThirdPartyAuthentication {
+    eci: Option<String>,
+    cryptogram: Option<pii::Secret<String>>,
+    xid: Option<String>,
+    version: String,
+}
+
+impl From<types::ThreeDsAuthenticationData> for CheckoutThirdPartyAuthentication {
+    fn from(authentication_data: types::ThreeDsAuthenticationData) -> Self {
+        Self {
+            eci: authentication_data.eci,
+            cryptogram: authentication_data.cavv,
+            xid: authentication_data.ds_trans_id,
+            version: authentication_data.message_version,
+        }
+    }
 }
 
 impl TryFrom<&types::ConnectorAuthType> for CheckoutAuthType {
@@ -78,25 +108,48 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentsRequest {
             api::PaymentMethod::BankTransfer
             | api::PaymentMethod::Wallet(_)
             | api::PaymentMethod::PayLater(_)
-            | api::PaymentMethod::Paypal => None,
+            | api::PaymentMethod::Paypal
+            | api::PaymentMethod::Upi(_)
+            | api::PaymentMethod::BankDebit(_) => None,
         };
 
         let three_ds = match item.auth_type {
             enums::AuthenticationType::ThreeDs => CheckoutThreeDS {
                 enabled: true,
                 force_3ds: true,
+                authentication_data: item.request.authentication_data.clone().map(Into::into),
             },
             enums::AuthenticationType::NoThreeDs => CheckoutThreeDS {
                 enabled: false,
                 force_3ds: false,
+                authentication_data: None,
             },
         };
 
+        let source_var = match item.request.payment_method_data {
+            api::PaymentMethod::Wallet(api::WalletData {
+                issuer_name: api_models::enums::WalletIssuer::PaypalRedirect,
+                ..
+            }) => Source::Paypal(PaypalSource {
+                source_type: "paypal".to_owned(),
+            }),
+            _ => Source::Card(CardSource {
+                source_type: Some("card".to_owned()),
+                number: ccard.map(|x| x.card_number.clone()),
+                expiry_month: ccard.map(|x| x.card_exp_month.clone()),
+                expiry_year: ccard.map(|x| x.card_exp_year.clone()),
+            }),
+        };
+
+        // The status of a PayPal payment is only known once it is synced after the approval
         let return_url = ReturnUrl {
-            success_url: item
-                .router_return_url
-                .as_ref()
-                .map(|return_url| format!("{return_url}?status=success")),
+            success_url: item.router_return_url.as_ref().map(|return_url| {
+                if matches!(source_var, Source::Paypal(_)) {
+                    return_url.to_owned()
+                } else {
+                    format!("{return_url}?status=success")
+                }
+            }),
             failure_url: item
                 .router_return_url
                 .as_ref()
@@ -108,12 +161,6 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentsRequest {
             Some(enums::CaptureMethod::Automatic)
         );
 
-        let source_var = Source::Card(CardSource {
-            source_type: Some("card".to_owned()),
-            number: ccard.map(|x| x.card_number.clone()),
-            expiry_month: ccard.map(|x| x.card_exp_month.clone()),
-            expiry_year: ccard.map(|x| x.card_exp_year.clone()),
-        });
         let connector_auth = &item.connector_auth_type;
         let auth_type: CheckoutAuthType = connector_auth.try_into()?;
         let processing_channel_id = auth_type.processing_channel_id;
@@ -129,7 +176,7 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentsRequest {
     }
 }
 
-#[derive(Default, Clone, Debug, Eq, PartialEq, Deserialize)]
+#[derive(Default, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub enum CheckoutPaymentStatus {
     Authorized,
     #[default]
@@ -163,26 +210,26 @@ impl From<transformers::Foreign<(CheckoutPaymentStatus, Balances)>>
     }
 }
 
-#[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Href {
     href: String,
 }
 
-#[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Links {
     redirect: Option<Href>,
 }
-#[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
 pub struct PaymentsResponse {
     id: String,
-    amount: Option<i32>,
+    amount: Option<i64>,
     status: CheckoutPaymentStatus,
     #[serde(rename = "_links")]
     links: Links,
     balances: Balances,
 }
 
-#[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Balances {
     available_to_capture: i32,
 }
@@ -465,6 +512,51 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, CheckoutRefundResponse
     }
 }
 
+#[derive(Debug, Serialize)]
+pub struct Evidence {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proof_of_delivery_or_service_file: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proof_of_delivery_or_service_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_or_receipt_file: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_or_receipt_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_communication_file: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_communication_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refund_or_cancellation_policy_file: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refund_or_cancellation_policy_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recurring_transaction_agreement_file: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_evidence_file: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_evidence_text: Option<String>,
+}
+
+impl From<&types::SubmitEvidenceRouterData> for Evidence {
+    fn from(item: &types::SubmitEvidenceRouterData) -> Self {
+        let evidence = item.request.evidence.clone();
+        Self {
+            proof_of_delivery_or_service_file: evidence.proof_of_delivery_file,
+            proof_of_delivery_or_service_text: evidence.proof_of_delivery_text,
+            invoice_or_receipt_file: evidence.receipt_file,
+            invoice_or_receipt_text: evidence.receipt_text,
+            customer_communication_file: evidence.customer_communication_file,
+            customer_communication_text: evidence.customer_communication_text,
+            refund_or_cancellation_policy_file: evidence.refund_policy_file,
+            refund_or_cancellation_policy_text: evidence.refund_policy_text,
+            recurring_transaction_agreement_file: evidence.recurring_transaction_agreement_file,
+            additional_evidence_file: evidence.additional_evidence_file,
+            additional_evidence_text: evidence.additional_evidence_text,
+        }
+    }
+}
+
 #[derive(Debug, Default, Eq, PartialEq, Deserialize)]
 pub struct ErrorResponse {
     pub request_id: Option<String>,
@@ -565,3 +657,55 @@ impl From<CheckoutRedirectResponseStatus> for enums::AttemptStatus {
         }
     }
 }
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckoutWebhookEventType {
+    PaymentCaptured,
+    PaymentRefunded,
+    PaymentRefundDeclined,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CheckoutWebhookEventTypeBody {
+    #[serde(rename = "type")]
+    pub transaction_type: CheckoutWebhookEventType,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CheckoutWebhookEventIdBody {
+    pub id: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CheckoutWebhookData {
+    pub id: String,
+    pub action_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CheckoutWebhookBody {
+    #[serde(rename = "type")]
+    pub transaction_type: CheckoutWebhookEventType,
+    pub data: CheckoutWebhookData,
+}
+
+/// The resource object of payment webhooks is consumed as the response of the payment sync flow,
+/// so the payment in the webhook is converted into the shape of a payment retrieved from
+/// Checkout.com.
+impl From<CheckoutWebhookBody> for PaymentsResponse {
+    fn from(webhook: CheckoutWebhookBody) -> Self {
+        let status = match webhook.transaction_type {
+            CheckoutWebhookEventType::PaymentCaptured
+            | CheckoutWebhookEventType::PaymentRefunded
+            | CheckoutWebhookEventType::PaymentRefundDeclined => CheckoutPaymentStatus::Captured,
+        };
+        Self {
+            id: webhook.data.id,
+            amount: None,
+            status,
+            links: Links::default(),
+            balances: Balances::default(),
+        }
+    }
+}
@@ -158,6 +158,103 @@ where
     }
 }
 
+impl api::ConnectorAccessToken for Cybersource {}
+
+impl
+    services::ConnectorIntegration<
+        api::AccessTokenAuth,
+        types::AccessTokenRequestData,
+        types::AccessToken,
+    > for Cybersource
+{
+}
+
+impl api::ConnectorVerifyWebhookSource for Cybersource {}
+
+impl
+    services::ConnectorIntegration<
+        api::VerifyWebhookSource,
+        types::VerifyWebhookSourceRequestData,
+        types::VerifyWebhookSourceResponseData,
+    > for Cybersource
+{
+}
+
+impl api::Payout for Cybersource {}
+impl api::PayoutCreate for Cybersource {}
+impl api::PayoutFulfill for Cybersource {}
+impl api::PayoutCancel for Cybersource {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Cybersource
+{
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Cybersource
+{
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Cybersource
+{
+}
+
+impl api::FraudCheck for Cybersource {}
+impl api::FraudCheckPreAuth for Cybersource {}
+impl api::FraudCheckPostAuth for Cybersource {}
+
+impl
+    services::ConnectorIntegration<
+        api::FraudCheckPre,
+        types::FraudCheckRequestData,
+        types::FraudCheckResponseData,
+    > for Cybersource
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::FraudCheckPost,
+        types::FraudCheckRequestData,
+        types::FraudCheckResponseData,
+    > for Cybersource
+{
+}
+
+impl api::ExternalAuthentication for Cybersource {}
+
+impl
+    services::ConnectorIntegration<
+        api::ThreeDsAuthentication,
+        types::ThreeDsAuthenticationRequestData,
+        types::ThreeDsAuthenticationData,
+    > for Cybersource
+{
+}
+
+impl api::Dispute for Cybersource {}
+impl api::SubmitEvidence for Cybersource {}
+impl api::DefendDispute for Cybersource {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Cybersource
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Defend,
+        types::DefendDisputeRequestData,
+        types::DefendDisputeResponse,
+    > for Cybersource
+{
+}
+
 impl api::Payment for Cybersource {}
 impl api::PaymentAuthorize for Cybersource {}
 impl api::PaymentSync for Cybersource {}
@@ -170,6 +267,17 @@ impl ConnectorIntegration<api::Verify, types::VerifyRequestData, types::Payments
 {
 }
 
+impl api::PaymentAuthorizeSessionToken for Cybersource {}
+
+impl
+    ConnectorIntegration<
+        api::AuthorizeSessionToken,
+        types::AuthorizeSessionTokenData,
+        types::AuthorizeSessionTokenResponse,
+    > for Cybersource
+{
+}
+
 impl api::PaymentSession for Cybersource {}
 
 impl ConnectorIntegration<api::Session, types::PaymentsSessionData, types::PaymentsResponseData>
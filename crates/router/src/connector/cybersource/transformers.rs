@@ -1,5 +1,5 @@
 use api_models::payments;
-use common_utils::pii;
+use common_utils::{pii, types::MinorUnit};
 use masking::Secret;
 use serde::{Deserialize, Serialize};
 
@@ -123,7 +123,8 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for CybersourcePaymentsRequest
 
                 let order_information = OrderInformationWithBill {
                     amount_details: Amount {
-                        total_amount: item.request.amount.to_string(),
+                        total_amount: MinorUnit::new(item.request.amount)
+                            .to_major_unit_as_string(&item.request.currency),
                         currency: item.request.currency.to_string().to_uppercase(),
                     },
                     bill_to,
@@ -173,7 +174,9 @@ impl TryFrom<&types::PaymentsCaptureRouterData> for CybersourcePaymentsRequest {
                     total_amount: value
                         .request
                         .amount_to_capture
-                        .map(|amount| amount.to_string())
+                        .map(|amount| {
+                            MinorUnit::new(amount).to_major_unit_as_string(&value.request.currency)
+                        })
                         .ok_or_else(utils::missing_field_err("amount_to_capture"))?,
                     ..Default::default()
                 },
@@ -190,7 +193,8 @@ impl TryFrom<&types::RefundExecuteRouterData> for CybersourcePaymentsRequest {
         Ok(Self {
             order_information: OrderInformationWithBill {
                 amount_details: Amount {
-                    total_amount: value.request.refund_amount.to_string(),
+                    total_amount: MinorUnit::new(value.request.refund_amount)
+                        .to_major_unit_as_string(&value.request.currency),
                     currency: value.request.currency.to_string(),
                 },
                 ..Default::default()
@@ -387,7 +391,8 @@ impl<F> TryFrom<&types::RefundsRouterData<F>> for CybersourceRefundRequest {
         Ok(Self {
             order_information: OrderInformation {
                 amount_details: Amount {
-                    total_amount: item.request.amount.to_string(),
+                    total_amount: MinorUnit::new(item.request.amount)
+                        .to_major_unit_as_string(&item.request.currency),
                     currency: item.request.currency.to_string(),
                 },
             },
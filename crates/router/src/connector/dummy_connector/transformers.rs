@@ -0,0 +1,232 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::errors,
+    pii::{self, PeekInterface},
+    services,
+    types::{self, api, storage::enums},
+};
+
+#[derive(Debug, Serialize)]
+pub struct DummyConnectorPaymentsRequest {
+    amount: i64,
+    currency: enums::Currency,
+    card: DummyConnectorCard,
+    capture: bool,
+    return_url: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DummyConnectorCard {
+    number: pii::Secret<String>,
+    expiry_month: pii::Secret<String>,
+    expiry_year: pii::Secret<String>,
+    cvc: pii::Secret<String>,
+}
+
+impl TryFrom<&types::PaymentsAuthorizeRouterData> for DummyConnectorPaymentsRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
+        match item.request.payment_method_data {
+            api::PaymentMethod::Card(ref ccard) => Ok(Self {
+                amount: item.request.amount,
+                currency: item.request.currency,
+                card: DummyConnectorCard {
+                    number: pii::Secret::new(ccard.card_number.peek().clone()),
+                    expiry_month: ccard.card_exp_month.clone(),
+                    expiry_year: ccard.card_exp_year.clone(),
+                    cvc: ccard.card_cvc.clone(),
+                },
+                capture: matches!(
+                    item.request.capture_method,
+                    Some(enums::CaptureMethod::Automatic) | None
+                ),
+                return_url: item.router_return_url.clone(),
+            }),
+            _ => Err(
+                errors::ConnectorError::NotImplemented("Current Payment Method".to_string()).into(),
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct DummyConnectorCaptureRequest {
+    amount: Option<i64>,
+}
+
+impl TryFrom<&types::PaymentsCaptureRouterData> for DummyConnectorCaptureRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: item.request.amount_to_capture,
+        })
+    }
+}
+
+// Auth Struct
+pub struct DummyConnectorAuthType {
+    pub(super) api_key: String,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for DummyConnectorAuthType {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        if let types::ConnectorAuthType::HeaderKey { api_key } = auth_type {
+            Ok(Self {
+                api_key: api_key.to_string(),
+            })
+        } else {
+            Err(errors::ConnectorError::FailedToObtainAuthType.into())
+        }
+    }
+}
+
+// PaymentsResponse
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DummyConnectorPaymentStatus {
+    RequiresAuthentication,
+    Authorized,
+    Processing,
+    Succeeded,
+    Failed,
+    Voided,
+}
+
+impl From<DummyConnectorPaymentStatus> for enums::AttemptStatus {
+    fn from(item: DummyConnectorPaymentStatus) -> Self {
+        match item {
+            DummyConnectorPaymentStatus::RequiresAuthentication => Self::AuthenticationPending,
+            DummyConnectorPaymentStatus::Authorized => Self::Authorized,
+            DummyConnectorPaymentStatus::Processing => Self::CaptureInitiated,
+            DummyConnectorPaymentStatus::Succeeded => Self::Charged,
+            DummyConnectorPaymentStatus::Failed => Self::Failure,
+            DummyConnectorPaymentStatus::Voided => Self::Voided,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DummyConnectorPaymentsResponse {
+    id: String,
+    status: DummyConnectorPaymentStatus,
+    amount: i64,
+    redirect_url: Option<String>,
+    error: Option<DummyConnectorErrorDetails>,
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            DummyConnectorPaymentsResponse,
+            T,
+            types::PaymentsResponseData,
+        >,
+    > for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            DummyConnectorPaymentsResponse,
+            T,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let response = match item.response.error {
+            Some(error) => Err(types::ErrorResponse {
+                code: error.code,
+                message: error.message,
+                reason: None,
+            }),
+            None => {
+                let redirection_data = item.response.redirect_url.map(|url| {
+                    services::RedirectForm::new(
+                        url,
+                        services::Method::Get,
+                        std::collections::HashMap::new(),
+                    )
+                });
+                Ok(types::PaymentsResponseData::TransactionResponse {
+                    resource_id: types::ResponseId::ConnectorTransactionId(item.response.id),
+                    redirect: redirection_data.is_some(),
+                    redirection_data,
+                    mandate_reference: None,
+                    connector_metadata: None,
+                })
+            }
+        };
+
+        Ok(Self {
+            status: enums::AttemptStatus::from(item.response.status),
+            response,
+            ..item.data
+        })
+    }
+}
+
+// REFUND :
+// Type definition for RefundRequest
+#[derive(Debug, Serialize)]
+pub struct DummyConnectorRefundRequest {
+    amount: i64,
+}
+
+impl<F> TryFrom<&types::RefundsRouterData<F>> for DummyConnectorRefundRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: item.request.refund_amount,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DummyConnectorRefundStatus {
+    Succeeded,
+}
+
+impl From<DummyConnectorRefundStatus> for enums::RefundStatus {
+    fn from(item: DummyConnectorRefundStatus) -> Self {
+        match item {
+            DummyConnectorRefundStatus::Succeeded => Self::Success,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefundResponse {
+    id: String,
+    status: DummyConnectorRefundStatus,
+}
+
+impl<F> TryFrom<types::RefundsResponseRouterData<F, RefundResponse>>
+    for types::RefundsRouterData<F>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::RefundsResponseRouterData<F, RefundResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::RefundsResponseData {
+                connector_refund_id: item.response.id,
+                refund_status: enums::RefundStatus::from(item.response.status),
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DummyConnectorErrorDetails {
+    pub code: String,
+    pub message: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DummyConnectorErrorResponse {
+    pub error: DummyConnectorErrorDetails,
+}
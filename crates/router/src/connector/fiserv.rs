@@ -64,6 +64,103 @@ impl api::ConnectorCommon for Fiserv {
     }
 }
 
+impl api::ConnectorAccessToken for Fiserv {}
+
+impl
+    services::ConnectorIntegration<
+        api::AccessTokenAuth,
+        types::AccessTokenRequestData,
+        types::AccessToken,
+    > for Fiserv
+{
+}
+
+impl api::ConnectorVerifyWebhookSource for Fiserv {}
+
+impl
+    services::ConnectorIntegration<
+        api::VerifyWebhookSource,
+        types::VerifyWebhookSourceRequestData,
+        types::VerifyWebhookSourceResponseData,
+    > for Fiserv
+{
+}
+
+impl api::Payout for Fiserv {}
+impl api::PayoutCreate for Fiserv {}
+impl api::PayoutFulfill for Fiserv {}
+impl api::PayoutCancel for Fiserv {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Fiserv
+{
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Fiserv
+{
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Fiserv
+{
+}
+
+impl api::FraudCheck for Fiserv {}
+impl api::FraudCheckPreAuth for Fiserv {}
+impl api::FraudCheckPostAuth for Fiserv {}
+
+impl
+    services::ConnectorIntegration<
+        api::FraudCheckPre,
+        types::FraudCheckRequestData,
+        types::FraudCheckResponseData,
+    > for Fiserv
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::FraudCheckPost,
+        types::FraudCheckRequestData,
+        types::FraudCheckResponseData,
+    > for Fiserv
+{
+}
+
+impl api::ExternalAuthentication for Fiserv {}
+
+impl
+    services::ConnectorIntegration<
+        api::ThreeDsAuthentication,
+        types::ThreeDsAuthenticationRequestData,
+        types::ThreeDsAuthenticationData,
+    > for Fiserv
+{
+}
+
+impl api::Dispute for Fiserv {}
+impl api::SubmitEvidence for Fiserv {}
+impl api::DefendDispute for Fiserv {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Fiserv
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Defend,
+        types::DefendDisputeRequestData,
+        types::DefendDisputeResponse,
+    > for Fiserv
+{
+}
+
 impl api::Payment for Fiserv {}
 
 impl api::PreVerify for Fiserv {}
@@ -230,6 +327,17 @@ impl
     }
 }
 
+impl api::PaymentAuthorizeSessionToken for Fiserv {}
+
+impl
+    services::ConnectorIntegration<
+        api::AuthorizeSessionToken,
+        types::AuthorizeSessionTokenData,
+        types::AuthorizeSessionTokenResponse,
+    > for Fiserv
+{
+}
+
 impl api::PaymentSession for Fiserv {}
 
 #[allow(dead_code)]
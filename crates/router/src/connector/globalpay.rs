@@ -91,6 +91,103 @@ impl ConnectorCommon for Globalpay {
     }
 }
 
+impl api::ConnectorAccessToken for Globalpay {}
+
+impl
+    services::ConnectorIntegration<
+        api::AccessTokenAuth,
+        types::AccessTokenRequestData,
+        types::AccessToken,
+    > for Globalpay
+{
+}
+
+impl api::ConnectorVerifyWebhookSource for Globalpay {}
+
+impl
+    services::ConnectorIntegration<
+        api::VerifyWebhookSource,
+        types::VerifyWebhookSourceRequestData,
+        types::VerifyWebhookSourceResponseData,
+    > for Globalpay
+{
+}
+
+impl api::Payout for Globalpay {}
+impl api::PayoutCreate for Globalpay {}
+impl api::PayoutFulfill for Globalpay {}
+impl api::PayoutCancel for Globalpay {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Globalpay
+{
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Globalpay
+{
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Globalpay
+{
+}
+
+impl api::FraudCheck for Globalpay {}
+impl api::FraudCheckPreAuth for Globalpay {}
+impl api::FraudCheckPostAuth for Globalpay {}
+
+impl
+    services::ConnectorIntegration<
+        api::FraudCheckPre,
+        types::FraudCheckRequestData,
+        types::FraudCheckResponseData,
+    > for Globalpay
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::FraudCheckPost,
+        types::FraudCheckRequestData,
+        types::FraudCheckResponseData,
+    > for Globalpay
+{
+}
+
+impl api::ExternalAuthentication for Globalpay {}
+
+impl
+    services::ConnectorIntegration<
+        api::ThreeDsAuthentication,
+        types::ThreeDsAuthenticationRequestData,
+        types::ThreeDsAuthenticationData,
+    > for Globalpay
+{
+}
+
+impl api::Dispute for Globalpay {}
+impl api::SubmitEvidence for Globalpay {}
+impl api::DefendDispute for Globalpay {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Globalpay
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Defend,
+        types::DefendDisputeRequestData,
+        types::DefendDisputeResponse,
+    > for Globalpay
+{
+}
+
 impl api::Payment for Globalpay {}
 
 impl api::PreVerify for Globalpay {}
@@ -335,6 +432,17 @@ impl ConnectorIntegration<api::Capture, types::PaymentsCaptureData, types::Payme
     }
 }
 
+impl api::PaymentAuthorizeSessionToken for Globalpay {}
+
+impl
+    ConnectorIntegration<
+        api::AuthorizeSessionToken,
+        types::AuthorizeSessionTokenData,
+        types::AuthorizeSessionTokenResponse,
+    > for Globalpay
+{
+}
+
 impl api::PaymentSession for Globalpay {}
 
 impl ConnectorIntegration<api::Session, types::PaymentsSessionData, types::PaymentsResponseData>
@@ -23,6 +23,22 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for GlobalpayPaymentsRequest {
             .map(|o| o.to_string())
             .ok_or_else(utils::missing_field_err("connector_meta.account_name"))?;
         let card = item.get_card()?;
+        let (initiator, stored_credential, brand_reference) = if item.is_merchant_initiated() {
+            (
+                Some(requests::Initiator::Merchant),
+                Some(requests::StoredCredential {
+                    model: Some(requests::Model::Unscheduled),
+                    reason: None,
+                    sequence: Some(requests::Sequence::Subsequent),
+                }),
+                item.request
+                    .mandate_id
+                    .as_ref()
+                    .and_then(|mandate_ids| mandate_ids.network_transaction_id.clone()),
+            )
+        } else {
+            (None, None, None)
+        };
         Ok(Self {
             account_name,
             amount: Some(item.request.amount.to_string()),
@@ -39,10 +55,13 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for GlobalpayPaymentsRequest {
                     expiry_month: card.get_card_expiry_month(),
                     expiry_year: card.get_card_expiry_year_2_digit(),
                     cvv: card.get_card_cvc(),
+                    brand_reference,
                     ..Default::default()
                 }),
                 ..Default::default()
             },
+            initiator,
+            stored_credential,
             ..Default::default()
         })
     }
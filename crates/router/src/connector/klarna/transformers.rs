@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     core::errors,
-    types::{self, storage::enums},
+    types::{self, api, storage::enums},
 };
 
 #[derive(Default, Debug, Serialize)]
@@ -12,6 +12,7 @@ pub struct KlarnaPaymentsRequest {
     order_amount: i64,
     purchase_country: String,
     purchase_currency: enums::Currency,
+    auto_capture: bool,
 }
 
 #[derive(Default, Debug, Deserialize)]
@@ -96,6 +97,7 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for KlarnaPaymentsRequest {
                     unit_price: request.amount,
                     total_amount: request.amount,
                 }],
+                auto_capture: is_auto_capture(request.capture_method),
             }),
             None => Err(report!(errors::ConnectorError::MissingRequiredField {
                 field_name: "product_name".to_string()
@@ -119,7 +121,149 @@ impl TryFrom<types::PaymentsResponseRouterData<KlarnaPaymentsResponse>>
                 mandate_reference: None,
                 connector_metadata: None,
             }),
-            status: item.response.fraud_status.into(),
+            status: get_attempt_status(
+                item.response.fraud_status,
+                is_auto_capture(item.data.request.capture_method),
+            ),
+            ..item.data
+        })
+    }
+}
+
+fn is_auto_capture(capture_method: Option<enums::CaptureMethod>) -> bool {
+    matches!(capture_method, Some(enums::CaptureMethod::Automatic) | None)
+}
+
+/// Orders placed with Klarna are captured separately, unless `auto_capture` was requested.
+fn get_attempt_status(fraud_status: KlarnaFraudStatus, auto_capture: bool) -> enums::AttemptStatus {
+    match fraud_status {
+        KlarnaFraudStatus::Accepted if auto_capture => enums::AttemptStatus::Charged,
+        KlarnaFraudStatus::Accepted => enums::AttemptStatus::Authorized,
+        KlarnaFraudStatus::Pending => enums::AttemptStatus::Authorizing,
+        KlarnaFraudStatus::Rejected => enums::AttemptStatus::Failure,
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct KlarnaCaptureRequest {
+    captured_amount: i64,
+}
+
+impl TryFrom<&types::PaymentsCaptureRouterData> for KlarnaCaptureRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            captured_amount: item
+                .request
+                .amount_to_capture
+                .unwrap_or(item.request.amount),
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct KlarnaOrderResponse {
+    pub order_id: String,
+    pub status: KlarnaOrderStatus,
+    pub fraud_status: KlarnaFraudStatus,
+    #[serde(default)]
+    pub refunds: Vec<KlarnaRefund>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum KlarnaOrderStatus {
+    Authorized,
+    PartCaptured,
+    Captured,
+    Cancelled,
+    Expired,
+    Closed,
+}
+
+impl From<&KlarnaOrderResponse> for enums::AttemptStatus {
+    fn from(item: &KlarnaOrderResponse) -> Self {
+        match (&item.status, &item.fraud_status) {
+            (_, KlarnaFraudStatus::Rejected) => Self::Failure,
+            (KlarnaOrderStatus::Authorized, KlarnaFraudStatus::Pending) => Self::Authorizing,
+            (KlarnaOrderStatus::Authorized, KlarnaFraudStatus::Accepted) => Self::Authorized,
+            (KlarnaOrderStatus::PartCaptured, _) => Self::PartialCharged,
+            (KlarnaOrderStatus::Captured, _) | (KlarnaOrderStatus::Closed, _) => Self::Charged,
+            (KlarnaOrderStatus::Cancelled, _) => Self::Voided,
+            (KlarnaOrderStatus::Expired, _) => Self::AuthorizationFailed,
+        }
+    }
+}
+
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, KlarnaOrderResponse, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<F, KlarnaOrderResponse, T, types::PaymentsResponseData>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            status: enums::AttemptStatus::from(&item.response),
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(item.response.order_id),
+                redirect: false,
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+/// Klarna does not return an identifier for the refunds it creates, so refunds are tagged with
+/// the router's refund ID as their reference and looked up by it when syncing.
+#[derive(Debug, Serialize)]
+pub struct KlarnaRefundRequest {
+    refunded_amount: i64,
+    reference: String,
+}
+
+impl<F> TryFrom<&types::RefundsRouterData<F>> for KlarnaRefundRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            refunded_amount: item.request.refund_amount,
+            reference: item.request.refund_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct KlarnaRefund {
+    pub refund_id: String,
+    pub reference: Option<String>,
+}
+
+impl TryFrom<types::RefundsResponseRouterData<api::RSync, KlarnaOrderResponse>>
+    for types::RefundsRouterData<api::RSync>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::RefundsResponseRouterData<api::RSync, KlarnaOrderResponse>,
+    ) -> Result<Self, Self::Error> {
+        let refund_id = item.data.request.refund_id.clone();
+        let refund_status = if item
+            .response
+            .refunds
+            .iter()
+            .any(|refund| refund.reference.as_ref() == Some(&refund_id))
+        {
+            enums::RefundStatus::Success
+        } else {
+            enums::RefundStatus::Pending
+        };
+        Ok(Self {
+            response: Ok(types::RefundsResponseData {
+                connector_refund_id: refund_id,
+                refund_status,
+            }),
             ..item.data
         })
     }
@@ -164,15 +308,7 @@ pub enum KlarnaFraudStatus {
     Accepted,
     #[default]
     Pending,
-}
-
-impl From<KlarnaFraudStatus> for enums::AttemptStatus {
-    fn from(item: KlarnaFraudStatus) -> Self {
-        match item {
-            KlarnaFraudStatus::Accepted => Self::Charged,
-            KlarnaFraudStatus::Pending => Self::Authorizing,
-        }
-    }
+    Rejected,
 }
 
 #[derive(Deserialize)]
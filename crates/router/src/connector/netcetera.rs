@@ -0,0 +1,337 @@
+mod transformers;
+
+use std::fmt::Debug;
+
+use bytes::Bytes;
+use error_stack::{IntoReport, ResultExt};
+
+use self::transformers as netcetera;
+use crate::{
+    configs::settings,
+    core::errors::{self, CustomResult},
+    headers, services,
+    types::{
+        self,
+        api::{self, ConnectorCommon},
+    },
+    utils::{self, BytesExt},
+};
+
+#[derive(Debug, Clone)]
+pub struct Netcetera;
+
+impl ConnectorCommon for Netcetera {
+    fn id(&self) -> &'static str {
+        "netcetera"
+    }
+
+    fn base_url<'a>(&self, connectors: &'a settings::Connectors) -> &'a str {
+        connectors.netcetera.base_url.as_ref()
+    }
+}
+
+impl api::ConnectorAccessToken for Netcetera {}
+
+impl
+    services::ConnectorIntegration<
+        api::AccessTokenAuth,
+        types::AccessTokenRequestData,
+        types::AccessToken,
+    > for Netcetera
+{
+}
+
+impl api::ConnectorVerifyWebhookSource for Netcetera {}
+
+impl
+    services::ConnectorIntegration<
+        api::VerifyWebhookSource,
+        types::VerifyWebhookSourceRequestData,
+        types::VerifyWebhookSourceResponseData,
+    > for Netcetera
+{
+}
+
+impl api::Payout for Netcetera {}
+impl api::PayoutCreate for Netcetera {}
+impl api::PayoutFulfill for Netcetera {}
+impl api::PayoutCancel for Netcetera {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Netcetera
+{
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Netcetera
+{
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Netcetera
+{
+}
+
+impl api::FraudCheck for Netcetera {}
+impl api::FraudCheckPreAuth for Netcetera {}
+impl api::FraudCheckPostAuth for Netcetera {}
+
+impl
+    services::ConnectorIntegration<
+        api::FraudCheckPre,
+        types::FraudCheckRequestData,
+        types::FraudCheckResponseData,
+    > for Netcetera
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::FraudCheckPost,
+        types::FraudCheckRequestData,
+        types::FraudCheckResponseData,
+    > for Netcetera
+{
+}
+
+impl api::ExternalAuthentication for Netcetera {}
+
+impl
+    services::ConnectorIntegration<
+        api::ThreeDsAuthentication,
+        types::ThreeDsAuthenticationRequestData,
+        types::ThreeDsAuthenticationData,
+    > for Netcetera
+{
+    fn get_headers(
+        &self,
+        _req: &types::ThreeDsAuthenticationRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        Ok(vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::ThreeDsAuthenticationType::get_content_type(self).to_string(),
+        )])
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::ThreeDsAuthenticationRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}3ds/authentication", self.base_url(connectors)))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::ThreeDsAuthenticationRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let req =
+            utils::Encode::<netcetera::NetceteraAuthenticationRequest>::convert_and_encode(req)
+                .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::ThreeDsAuthenticationRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Post)
+            .url(&types::ThreeDsAuthenticationType::get_url(
+                self, req, connectors,
+            )?)
+            .headers(types::ThreeDsAuthenticationType::get_headers(
+                self, req, connectors,
+            )?)
+            .body(types::ThreeDsAuthenticationType::get_request_body(
+                self, req,
+            )?)
+            .add_certificate(types::ThreeDsAuthenticationType::get_certificate(
+                self, req,
+            )?)
+            .add_certificate_key(types::ThreeDsAuthenticationType::get_certificate_key(
+                self, req,
+            )?)
+            .build();
+        Ok(Some(request))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::ThreeDsAuthenticationRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::ThreeDsAuthenticationRouterData, errors::ConnectorError> {
+        let response: netcetera::NetceteraAuthenticationResponse = res
+            .response
+            .parse_struct("NetceteraAuthenticationResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: Bytes,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: netcetera::NetceteraErrorResponse = res
+            .parse_struct("NetceteraErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(response.into())
+    }
+
+    fn get_certificate(
+        &self,
+        req: &types::ThreeDsAuthenticationRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let metadata = netcetera::NetceteraMetaData::try_from(&req.connector_meta_data)
+            .change_context(errors::ConnectorError::FailedToObtainCertificate)?;
+        Ok(Some(metadata.certificate))
+    }
+
+    fn get_certificate_key(
+        &self,
+        req: &types::ThreeDsAuthenticationRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let metadata = netcetera::NetceteraMetaData::try_from(&req.connector_meta_data)
+            .change_context(errors::ConnectorError::FailedToObtainCertificateKey)?;
+        Ok(Some(metadata.certificate_key))
+    }
+}
+
+impl api::Dispute for Netcetera {}
+impl api::SubmitEvidence for Netcetera {}
+impl api::DefendDispute for Netcetera {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Netcetera
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Defend,
+        types::DefendDisputeRequestData,
+        types::DefendDisputeResponse,
+    > for Netcetera
+{
+}
+
+impl api::Payment for Netcetera {}
+impl api::PaymentAuthorize for Netcetera {}
+impl api::PaymentSync for Netcetera {}
+impl api::PaymentVoid for Netcetera {}
+impl api::PaymentCapture for Netcetera {}
+impl api::PreVerify for Netcetera {}
+impl api::PaymentAuthorizeSessionToken for Netcetera {}
+
+impl
+    services::ConnectorIntegration<
+        api::AuthorizeSessionToken,
+        types::AuthorizeSessionTokenData,
+        types::AuthorizeSessionTokenResponse,
+    > for Netcetera
+{
+}
+
+impl api::PaymentSession for Netcetera {}
+
+impl
+    services::ConnectorIntegration<
+        api::Verify,
+        types::VerifyRequestData,
+        types::PaymentsResponseData,
+    > for Netcetera
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Capture,
+        types::PaymentsCaptureData,
+        types::PaymentsResponseData,
+    > for Netcetera
+{
+}
+
+impl
+    services::ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsResponseData>
+    for Netcetera
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Authorize,
+        types::PaymentsAuthorizeData,
+        types::PaymentsResponseData,
+    > for Netcetera
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Void,
+        types::PaymentsCancelData,
+        types::PaymentsResponseData,
+    > for Netcetera
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Session,
+        types::PaymentsSessionData,
+        types::PaymentsResponseData,
+    > for Netcetera
+{
+}
+
+impl api::Refund for Netcetera {}
+impl api::RefundExecute for Netcetera {}
+impl api::RefundSync for Netcetera {}
+
+impl services::ConnectorIntegration<api::Execute, types::RefundsData, types::RefundsResponseData>
+    for Netcetera
+{
+}
+
+impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponseData>
+    for Netcetera
+{
+}
+
+impl services::ConnectorRedirectResponse for Netcetera {}
+
+#[async_trait::async_trait]
+impl api::IncomingWebhook for Netcetera {
+    fn get_webhook_object_reference_id(
+        &self,
+        _body: &[u8],
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+    }
+
+    fn get_webhook_event_type(
+        &self,
+        _body: &[u8],
+    ) -> CustomResult<api::IncomingWebhookEvent, errors::ConnectorError> {
+        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+    }
+
+    fn get_webhook_resource_object(
+        &self,
+        _body: &[u8],
+    ) -> CustomResult<serde_json::Value, errors::ConnectorError> {
+        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+    }
+}
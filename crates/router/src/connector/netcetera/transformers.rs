@@ -0,0 +1,287 @@
+use common_utils::ext_traits::ValueExt;
+use error_stack::{IntoReport, ResultExt};
+use masking::{PeekInterface, Secret};
+use serde::{Deserialize, Serialize};
+use time::{format_description, OffsetDateTime};
+
+use crate::{core::errors, pii, types, utils::OptionExt};
+
+/// The configuration of the merchant at the 3DS server, which is stored in the metadata of the
+/// merchant connector account.
+#[derive(Debug, Deserialize)]
+pub struct NetceteraMetaData {
+    /// The client certificate used for authenticating with the 3DS server
+    pub certificate: String,
+    pub certificate_key: String,
+    pub three_ds_requestor_id: String,
+    pub three_ds_requestor_name: String,
+    pub merchant_name: String,
+    /// The merchant category code
+    pub mcc: String,
+    /// The numeric ISO 3166-1 code of the merchant's country
+    pub merchant_country_code: String,
+    pub acquirer_bin: String,
+    pub acquirer_merchant_id: String,
+}
+
+impl TryFrom<&Option<serde_json::Value>> for NetceteraMetaData {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(meta_data: &Option<serde_json::Value>) -> Result<Self, Self::Error> {
+        meta_data
+            .to_owned()
+            .get_required_value("connector_meta_data")
+            .change_context(errors::ConnectorError::NoConnectorMetaData)?
+            .parse_value("NetceteraMetaData")
+            .change_context(errors::ConnectorError::RequestEncodingFailed)
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetceteraAuthenticationRequest {
+    preferred_protocol_version: String,
+    /// The channel through which the cardholder is authenticated, `02` being the browser
+    device_channel: String,
+    /// The kind of the authentication, `01` being a payment
+    message_category: String,
+    three_ds_requestor: ThreeDsRequestor,
+    cardholder_account: CardholderAccount,
+    cardholder: Option<Cardholder>,
+    purchase: Purchase,
+    acquirer: Acquirer,
+    merchant: Merchant,
+    browser_information: Option<BrowserInformation>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ThreeDsRequestor {
+    /// The reason for the authentication, `01` being a payment transaction
+    #[serde(rename = "threeDSRequestorAuthenticationInd")]
+    three_ds_requestor_authentication_ind: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CardholderAccount {
+    acct_number: Secret<String, pii::CardNumber>,
+    /// The expiry date of the card, in the `YYMM` format
+    card_expiry_date: Secret<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Cardholder {
+    email: Secret<String, pii::Email>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Purchase {
+    purchase_amount: i64,
+    /// The numeric ISO 4217 code of the currency
+    purchase_currency: String,
+    /// The number of decimal places of the currency
+    purchase_exponent: u8,
+    /// The time of the purchase in UTC, in the `YYYYMMDDHHMMSS` format
+    purchase_date: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Acquirer {
+    #[serde(rename = "acquirerBIN")]
+    acquirer_bin: String,
+    #[serde(rename = "acquirerMerchantID")]
+    acquirer_merchant_id: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Merchant {
+    mcc: String,
+    #[serde(rename = "merchantCountryCode")]
+    merchant_country_code: String,
+    #[serde(rename = "merchantName")]
+    merchant_name: String,
+    #[serde(rename = "threeDSRequestorID")]
+    three_ds_requestor_id: String,
+    #[serde(rename = "threeDSRequestorName")]
+    three_ds_requestor_name: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BrowserInformation {
+    #[serde(rename = "browserAcceptHeader")]
+    browser_accept_header: String,
+    #[serde(rename = "browserIP")]
+    browser_ip: Option<std::net::IpAddr>,
+    #[serde(rename = "browserJavaEnabled")]
+    browser_java_enabled: bool,
+    #[serde(rename = "browserJavascriptEnabled")]
+    browser_javascript_enabled: bool,
+    #[serde(rename = "browserLanguage")]
+    browser_language: String,
+    #[serde(rename = "browserColorDepth")]
+    browser_color_depth: String,
+    #[serde(rename = "browserScreenHeight")]
+    browser_screen_height: String,
+    #[serde(rename = "browserScreenWidth")]
+    browser_screen_width: String,
+    #[serde(rename = "browserTZ")]
+    browser_tz: String,
+    #[serde(rename = "browserUserAgent")]
+    browser_user_agent: String,
+}
+
+impl From<types::BrowserInformation> for BrowserInformation {
+    fn from(browser_info: types::BrowserInformation) -> Self {
+        Self {
+            browser_accept_header: browser_info.accept_header,
+            browser_ip: browser_info.ip_address,
+            browser_java_enabled: browser_info.java_enabled,
+            browser_javascript_enabled: browser_info.java_script_enabled,
+            browser_language: browser_info.language,
+            browser_color_depth: browser_info.color_depth.to_string(),
+            browser_screen_height: browser_info.screen_height.to_string(),
+            browser_screen_width: browser_info.screen_width.to_string(),
+            browser_tz: browser_info.time_zone.to_string(),
+            browser_user_agent: browser_info.user_agent,
+        }
+    }
+}
+
+fn get_purchase_date() -> Result<String, error_stack::Report<errors::ConnectorError>> {
+    let format = format_description::parse("[year][month][day][hour][minute][second]")
+        .into_report()
+        .change_context(errors::ConnectorError::InvalidDateFormat)?;
+    OffsetDateTime::now_utc()
+        .format(&format)
+        .into_report()
+        .change_context(errors::ConnectorError::InvalidDateFormat)
+}
+
+impl TryFrom<&types::ThreeDsAuthenticationRouterData> for NetceteraAuthenticationRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::ThreeDsAuthenticationRouterData) -> Result<Self, Self::Error> {
+        let metadata = NetceteraMetaData::try_from(&item.connector_meta_data)?;
+        let card = &item.request.card;
+        let card_exp_year = card.card_exp_year.peek();
+        let card_expiry_date = format!(
+            "{}{}",
+            card_exp_year
+                .get(card_exp_year.len().saturating_sub(2)..)
+                .unwrap_or_default(),
+            card.card_exp_month.peek()
+        );
+
+        Ok(Self {
+            preferred_protocol_version: "2.2.0".to_string(),
+            device_channel: "02".to_string(),
+            message_category: "01".to_string(),
+            three_ds_requestor: ThreeDsRequestor {
+                three_ds_requestor_authentication_ind: "01".to_string(),
+            },
+            cardholder_account: CardholderAccount {
+                acct_number: card.card_number.clone(),
+                card_expiry_date: Secret::new(card_expiry_date),
+            },
+            cardholder: item.request.email.clone().map(|email| Cardholder { email }),
+            purchase: Purchase {
+                purchase_amount: item.request.amount,
+                purchase_currency: item.request.currency.iso_4217_numeric_code().to_string(),
+                purchase_exponent: item.request.currency.number_of_digits_after_decimal_point(),
+                purchase_date: get_purchase_date()?,
+            },
+            acquirer: Acquirer {
+                acquirer_bin: metadata.acquirer_bin,
+                acquirer_merchant_id: metadata.acquirer_merchant_id,
+            },
+            merchant: Merchant {
+                mcc: metadata.mcc,
+                merchant_country_code: metadata.merchant_country_code,
+                merchant_name: metadata.merchant_name,
+                three_ds_requestor_id: metadata.three_ds_requestor_id,
+                three_ds_requestor_name: metadata.three_ds_requestor_name,
+            },
+            browser_information: item.request.browser_info.clone().map(Into::into),
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetceteraAuthenticationResponse {
+    #[serde(rename = "threeDSServerTransID")]
+    pub three_ds_server_trans_id: String,
+    pub trans_status: types::ThreeDsTransStatus,
+    /// The cardholder authentication verification value, present for successful authentications
+    pub authentication_value: Option<Secret<String>>,
+    pub eci: Option<String>,
+    pub authentication_response: Option<AuthenticationResponse>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AuthenticationResponse {
+    #[serde(rename = "dsTransID")]
+    pub ds_trans_id: Option<String>,
+    #[serde(rename = "messageVersion")]
+    pub message_version: String,
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            NetceteraAuthenticationResponse,
+            T,
+            types::ThreeDsAuthenticationData,
+        >,
+    > for types::RouterData<F, T, types::ThreeDsAuthenticationData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            NetceteraAuthenticationResponse,
+            T,
+            types::ThreeDsAuthenticationData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let authentication_response = item.response.authentication_response;
+        Ok(Self {
+            response: Ok(types::ThreeDsAuthenticationData {
+                trans_status: item.response.trans_status,
+                cavv: item.response.authentication_value,
+                eci: item.response.eci,
+                ds_trans_id: authentication_response
+                    .as_ref()
+                    .and_then(|response| response.ds_trans_id.clone()),
+                message_version: authentication_response
+                    .map(|response| response.message_version)
+                    .unwrap_or_else(|| "2.2.0".to_string()),
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetceteraErrorResponse {
+    pub error_details: NetceteraErrorDetails,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetceteraErrorDetails {
+    pub error_code: String,
+    pub error_description: String,
+    pub error_detail: Option<String>,
+}
+
+impl From<NetceteraErrorResponse> for types::ErrorResponse {
+    fn from(response: NetceteraErrorResponse) -> Self {
+        Self {
+            code: response.error_details.error_code,
+            message: response.error_details.error_description,
+            reason: response.error_details.error_detail,
+        }
+    }
+}
@@ -0,0 +1,661 @@
+use common_utils::types::MinorUnit;
+use error_stack::{IntoReport, ResultExt};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    connector::utils::{self as connector_utils, CardData},
+    core::errors,
+    headers,
+    pii::PeekInterface,
+    types::{self, api, storage::enums},
+    utils::ValueExt,
+};
+
+fn to_paypal_amount(amount: i64, currency: enums::Currency) -> String {
+    MinorUnit::new(amount).to_major_unit_as_string(&currency)
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct PaypalAmount {
+    currency_code: String,
+    value: String,
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum PaypalPaymentIntent {
+    Capture,
+    Authorize,
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct PurchaseUnitRequest {
+    reference_id: String,
+    amount: PaypalAmount,
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct PaypalCard {
+    name: String,
+    number: String,
+    /// Expiry date of the card in the `YYYY-MM` format
+    expiry: String,
+    security_code: String,
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct PaymentSource {
+    card: PaypalCard,
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct PaypalPaymentsRequest {
+    intent: PaypalPaymentIntent,
+    purchase_units: Vec<PurchaseUnitRequest>,
+    payment_source: PaymentSource,
+}
+
+impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaypalPaymentsRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
+        match item.request.payment_method_data {
+            api::PaymentMethod::Card(ref ccard) => {
+                let intent = match item.request.capture_method {
+                    Some(enums::CaptureMethod::Automatic) | None => PaypalPaymentIntent::Capture,
+                    _ => PaypalPaymentIntent::Authorize,
+                };
+                Ok(Self {
+                    intent,
+                    purchase_units: vec![PurchaseUnitRequest {
+                        reference_id: item.payment_id.clone(),
+                        amount: PaypalAmount {
+                            currency_code: item.request.currency.to_string(),
+                            value: to_paypal_amount(item.request.amount, item.request.currency),
+                        },
+                    }],
+                    payment_source: PaymentSource {
+                        card: PaypalCard {
+                            name: ccard.card_holder_name.peek().clone(),
+                            number: ccard.get_card_number(),
+                            expiry: format!(
+                                "{}-{}",
+                                ccard.get_card_expiry_year(),
+                                ccard.get_card_expiry_month()
+                            ),
+                            security_code: ccard.get_card_cvc(),
+                        },
+                    },
+                })
+            }
+            _ => Err(
+                errors::ConnectorError::NotImplemented("Current Payment Method".to_string()).into(),
+            ),
+        }
+    }
+}
+
+// Auth Struct
+pub struct PaypalAuthType {
+    pub(super) client_id: String,
+    pub(super) client_secret: String,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for PaypalAuthType {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        if let types::ConnectorAuthType::BodyKey { api_key, key1 } = item {
+            Ok(Self {
+                client_id: key1.to_string(),
+                client_secret: api_key.to_string(),
+            })
+        } else {
+            Err(errors::ConnectorError::FailedToObtainAuthType)?
+        }
+    }
+}
+
+// Access token
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct PaypalAuthUpdateRequest {
+    grant_type: String,
+}
+
+impl TryFrom<&types::RefreshTokenRouterData> for PaypalAuthUpdateRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(_item: &types::RefreshTokenRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            grant_type: "client_credentials".to_string(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+pub struct PaypalAuthUpdateResponse {
+    pub access_token: String,
+    pub token_type: String,
+    /// Number of seconds after which the access token expires
+    pub expires_in: i64,
+}
+
+impl<F, T> TryFrom<types::ResponseRouterData<F, PaypalAuthUpdateResponse, T, types::AccessToken>>
+    for types::RouterData<F, T, types::AccessToken>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<F, PaypalAuthUpdateResponse, T, types::AccessToken>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::AccessToken {
+                token: item.response.access_token,
+                expires: item.response.expires_in,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// PaymentsResponse
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaypalOrderStatus {
+    Created,
+    Saved,
+    Approved,
+    Voided,
+    Completed,
+    PayerActionRequired,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaypalAuthorizationStatus {
+    Created,
+    Captured,
+    Denied,
+    Expired,
+    PartiallyCaptured,
+    Voided,
+    Pending,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaypalCaptureStatus {
+    Completed,
+    Declined,
+    PartiallyRefunded,
+    Pending,
+    Refunded,
+    Failed,
+}
+
+impl From<PaypalAuthorizationStatus> for enums::AttemptStatus {
+    fn from(item: PaypalAuthorizationStatus) -> Self {
+        match item {
+            PaypalAuthorizationStatus::Created => Self::Authorized,
+            PaypalAuthorizationStatus::Captured | PaypalAuthorizationStatus::PartiallyCaptured => {
+                Self::Charged
+            }
+            PaypalAuthorizationStatus::Denied | PaypalAuthorizationStatus::Expired => {
+                Self::AuthorizationFailed
+            }
+            PaypalAuthorizationStatus::Voided => Self::Voided,
+            PaypalAuthorizationStatus::Pending => Self::Pending,
+        }
+    }
+}
+
+impl From<PaypalCaptureStatus> for enums::AttemptStatus {
+    fn from(item: PaypalCaptureStatus) -> Self {
+        match item {
+            PaypalCaptureStatus::Completed
+            | PaypalCaptureStatus::PartiallyRefunded
+            | PaypalCaptureStatus::Refunded => Self::Charged,
+            PaypalCaptureStatus::Declined | PaypalCaptureStatus::Failed => Self::Failure,
+            PaypalCaptureStatus::Pending => Self::Pending,
+        }
+    }
+}
+
+impl From<PaypalOrderStatus> for enums::AttemptStatus {
+    fn from(item: PaypalOrderStatus) -> Self {
+        match item {
+            PaypalOrderStatus::Created
+            | PaypalOrderStatus::Saved
+            | PaypalOrderStatus::Approved
+            | PaypalOrderStatus::Completed => Self::Pending,
+            PaypalOrderStatus::PayerActionRequired => Self::AuthenticationPending,
+            PaypalOrderStatus::Voided => Self::Voided,
+        }
+    }
+}
+
+/// Connector metadata stored with the payment attempt, the authorization and capture ids are
+/// required to capture and refund the payment respectively.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PaypalMeta {
+    pub authorize_id: Option<String>,
+    pub capture_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PaypalAuthorization {
+    id: String,
+    status: PaypalAuthorizationStatus,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PaypalCapture {
+    id: String,
+    status: PaypalCaptureStatus,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PaypalPurchaseUnitPayments {
+    #[serde(default)]
+    authorizations: Vec<PaypalAuthorization>,
+    #[serde(default)]
+    captures: Vec<PaypalCapture>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PurchaseUnitResponse {
+    payments: Option<PaypalPurchaseUnitPayments>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PaypalOrdersResponse {
+    id: String,
+    status: PaypalOrderStatus,
+    #[serde(default)]
+    purchase_units: Vec<PurchaseUnitResponse>,
+}
+
+impl PaypalOrdersResponse {
+    fn get_payments(&self) -> Option<&PaypalPurchaseUnitPayments> {
+        self.purchase_units
+            .first()
+            .and_then(|purchase_unit| purchase_unit.payments.as_ref())
+    }
+
+    /// The status of the latest capture takes precedence over the status of the authorization,
+    /// which takes precedence over the status of the order itself.
+    fn get_attempt_status(&self) -> enums::AttemptStatus {
+        let payments = self.get_payments();
+        if let Some(capture) = payments.and_then(|payments| payments.captures.last()) {
+            capture.status.clone().into()
+        } else if let Some(authorization) =
+            payments.and_then(|payments| payments.authorizations.last())
+        {
+            authorization.status.clone().into()
+        } else {
+            self.status.clone().into()
+        }
+    }
+
+    fn get_connector_meta(&self) -> PaypalMeta {
+        let payments = self.get_payments();
+        PaypalMeta {
+            authorize_id: payments
+                .and_then(|payments| payments.authorizations.last())
+                .map(|authorization| authorization.id.clone()),
+            capture_id: payments
+                .and_then(|payments| payments.captures.last())
+                .map(|capture| capture.id.clone()),
+        }
+    }
+}
+
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, PaypalOrdersResponse, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<F, PaypalOrdersResponse, T, types::PaymentsResponseData>,
+    ) -> Result<Self, Self::Error> {
+        let connector_meta = serde_json::to_value(item.response.get_connector_meta())
+            .into_report()
+            .change_context(errors::ConnectorError::ResponseHandlingFailed)?;
+        Ok(Self {
+            status: item.response.get_attempt_status(),
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(item.response.id),
+                redirection_data: None,
+                redirect: false,
+                mandate_reference: None,
+                connector_metadata: Some(connector_meta),
+            }),
+            ..item.data
+        })
+    }
+}
+
+pub fn get_connector_meta(
+    connector_meta: Option<serde_json::Value>,
+) -> Result<PaypalMeta, error_stack::Report<errors::ConnectorError>> {
+    connector_meta
+        .ok_or_else(connector_utils::missing_field_err("connector_meta"))?
+        .parse_value::<PaypalMeta>("PaypalMeta")
+        .change_context(errors::ConnectorError::RequestEncodingFailed)
+}
+
+// Capture
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct PaypalPaymentsCaptureRequest {
+    amount: PaypalAmount,
+    final_capture: bool,
+}
+
+impl TryFrom<&types::PaymentsCaptureRouterData> for PaypalPaymentsCaptureRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: PaypalAmount {
+                currency_code: item.request.currency.to_string(),
+                value: to_paypal_amount(
+                    item.request
+                        .amount_to_capture
+                        .unwrap_or(item.request.amount),
+                    item.request.currency,
+                ),
+            },
+            final_capture: true,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+pub struct PaypalCaptureResponse {
+    id: String,
+    status: PaypalCaptureStatus,
+}
+
+impl TryFrom<types::PaymentsCaptureResponseRouterData<PaypalCaptureResponse>>
+    for types::PaymentsCaptureRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::PaymentsCaptureResponseRouterData<PaypalCaptureResponse>,
+    ) -> Result<Self, Self::Error> {
+        let connector_meta = get_connector_meta(item.data.request.connector_meta.clone())?;
+        let connector_meta = serde_json::to_value(PaypalMeta {
+            capture_id: Some(item.response.id),
+            ..connector_meta
+        })
+        .into_report()
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)?;
+        Ok(Self {
+            status: item.response.status.into(),
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(
+                    item.data.request.connector_transaction_id.clone(),
+                ),
+                redirection_data: None,
+                redirect: false,
+                mandate_reference: None,
+                connector_metadata: Some(connector_meta),
+            }),
+            amount_captured: Some(
+                item.data
+                    .request
+                    .amount_to_capture
+                    .unwrap_or(item.data.request.amount),
+            ),
+            ..item.data
+        })
+    }
+}
+
+// REFUND :
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct PaypalRefundRequest {
+    amount: PaypalAmount,
+}
+
+impl<F> TryFrom<&types::RefundsRouterData<F>> for PaypalRefundRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: PaypalAmount {
+                currency_code: item.request.currency.to_string(),
+                value: to_paypal_amount(item.request.refund_amount, item.request.currency),
+            },
+        })
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RefundStatus {
+    Completed,
+    Failed,
+    Pending,
+    Cancelled,
+}
+
+impl From<RefundStatus> for enums::RefundStatus {
+    fn from(item: RefundStatus) -> Self {
+        match item {
+            RefundStatus::Completed => Self::Success,
+            RefundStatus::Failed | RefundStatus::Cancelled => Self::Failure,
+            RefundStatus::Pending => Self::Pending,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+pub struct RefundResponse {
+    id: String,
+    status: RefundStatus,
+}
+
+impl<F> TryFrom<types::RefundsResponseRouterData<F, RefundResponse>>
+    for types::RefundsRouterData<F>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::RefundsResponseRouterData<F, RefundResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::RefundsResponseData {
+                connector_refund_id: item.response.id,
+                refund_status: enums::RefundStatus::from(item.response.status),
+            }),
+            ..item.data
+        })
+    }
+}
+
+// Webhook source verification
+#[derive(Debug, Serialize)]
+pub struct PaypalVerifyWebhookRequest {
+    auth_algo: String,
+    cert_url: String,
+    transmission_id: String,
+    transmission_sig: String,
+    transmission_time: String,
+    webhook_id: String,
+    webhook_event: serde_json::Value,
+}
+
+fn get_header_value(
+    request: &types::VerifyWebhookSourceRequestData,
+    header_name: &'static str,
+) -> Result<String, error_stack::Report<errors::ConnectorError>> {
+    request
+        .webhook_headers
+        .get(header_name)
+        .and_then(|header_value| header_value.to_str().ok())
+        .map(ToString::to_string)
+        .ok_or_else(connector_utils::missing_field_err(header_name))
+}
+
+impl TryFrom<&types::VerifyWebhookSourceRouterData> for PaypalVerifyWebhookRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::VerifyWebhookSourceRouterData) -> Result<Self, Self::Error> {
+        let request = &item.request;
+        Ok(Self {
+            auth_algo: get_header_value(request, headers::PAYPAL_AUTH_ALGO)?,
+            cert_url: get_header_value(request, headers::PAYPAL_CERT_URL)?,
+            transmission_id: get_header_value(request, headers::PAYPAL_TRANSMISSION_ID)?,
+            transmission_sig: get_header_value(request, headers::PAYPAL_TRANSMISSION_SIG)?,
+            transmission_time: get_header_value(request, headers::PAYPAL_TRANSMISSION_TIME)?,
+            webhook_id: String::from_utf8(request.merchant_secret.clone())
+                .into_report()
+                .change_context(errors::ConnectorError::WebhookVerificationSecretNotFound)?,
+            webhook_event: serde_json::from_slice(&request.webhook_body)
+                .into_report()
+                .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaypalVerificationStatus {
+    Success,
+    Failure,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+pub struct PaypalVerifyWebhookResponse {
+    verification_status: PaypalVerificationStatus,
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            PaypalVerifyWebhookResponse,
+            T,
+            types::VerifyWebhookSourceResponseData,
+        >,
+    > for types::RouterData<F, T, types::VerifyWebhookSourceResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            PaypalVerifyWebhookResponse,
+            T,
+            types::VerifyWebhookSourceResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let verify_webhook_status = match item.response.verification_status {
+            PaypalVerificationStatus::Success => types::VerifyWebhookStatus::SourceVerified,
+            PaypalVerificationStatus::Failure => types::VerifyWebhookStatus::SourceNotVerified,
+        };
+        Ok(Self {
+            response: Ok(types::VerifyWebhookSourceResponseData {
+                verify_webhook_status,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// Webhooks
+#[derive(Debug, Deserialize)]
+pub enum PaypalWebhookEventType {
+    #[serde(rename = "PAYMENT.CAPTURE.COMPLETED")]
+    PaymentCaptureCompleted,
+    #[serde(rename = "PAYMENT.CAPTURE.DECLINED")]
+    PaymentCaptureDeclined,
+    #[serde(rename = "PAYMENT.CAPTURE.REFUNDED")]
+    PaymentCaptureRefunded,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PaypalWebhookObjectEventType {
+    pub event_type: PaypalWebhookEventType,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PaypalRelatedIds {
+    pub order_id: String,
+    pub authorization_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PaypalSupplementaryData {
+    pub related_ids: PaypalRelatedIds,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PaypalWebhookResourceId {
+    pub id: String,
+    pub supplementary_data: Option<PaypalSupplementaryData>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PaypalWebhookObjectEventId {
+    pub id: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PaypalWebhookObjectId {
+    pub event_type: PaypalWebhookEventType,
+    pub resource: PaypalWebhookResourceId,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PaypalWebhookObjectResource {
+    pub event_type: PaypalWebhookEventType,
+    pub resource: serde_json::Value,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PaypalWebhookCaptureResource {
+    pub id: String,
+    pub status: PaypalCaptureStatus,
+    pub supplementary_data: PaypalSupplementaryData,
+}
+
+/// The resource object of payment webhooks is consumed as the response of the payment sync flow,
+/// so the capture in the webhook is wrapped into the order which it belongs to.
+impl From<PaypalWebhookCaptureResource> for PaypalOrdersResponse {
+    fn from(capture: PaypalWebhookCaptureResource) -> Self {
+        let related_ids = capture.supplementary_data.related_ids;
+        let authorizations = related_ids
+            .authorization_id
+            .map(|authorization_id| PaypalAuthorization {
+                id: authorization_id,
+                status: PaypalAuthorizationStatus::Captured,
+            })
+            .into_iter()
+            .collect();
+        Self {
+            id: related_ids.order_id,
+            status: PaypalOrderStatus::Completed,
+            purchase_units: vec![PurchaseUnitResponse {
+                payments: Some(PaypalPurchaseUnitPayments {
+                    authorizations,
+                    captures: vec![PaypalCapture {
+                        id: capture.id,
+                        status: capture.status,
+                    }],
+                }),
+            }],
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PaypalErrorDetails {
+    pub issue: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PaypalErrorResponse {
+    pub name: String,
+    pub message: String,
+    pub details: Option<Vec<PaypalErrorDetails>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PaypalAccessTokenErrorResponse {
+    pub error: String,
+    pub error_description: String,
+}
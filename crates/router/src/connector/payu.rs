@@ -81,6 +81,103 @@ impl ConnectorCommon for Payu {
     }
 }
 
+impl api::ConnectorAccessToken for Payu {}
+
+impl
+    services::ConnectorIntegration<
+        api::AccessTokenAuth,
+        types::AccessTokenRequestData,
+        types::AccessToken,
+    > for Payu
+{
+}
+
+impl api::ConnectorVerifyWebhookSource for Payu {}
+
+impl
+    services::ConnectorIntegration<
+        api::VerifyWebhookSource,
+        types::VerifyWebhookSourceRequestData,
+        types::VerifyWebhookSourceResponseData,
+    > for Payu
+{
+}
+
+impl api::Payout for Payu {}
+impl api::PayoutCreate for Payu {}
+impl api::PayoutFulfill for Payu {}
+impl api::PayoutCancel for Payu {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Payu
+{
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Payu
+{
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Payu
+{
+}
+
+impl api::FraudCheck for Payu {}
+impl api::FraudCheckPreAuth for Payu {}
+impl api::FraudCheckPostAuth for Payu {}
+
+impl
+    services::ConnectorIntegration<
+        api::FraudCheckPre,
+        types::FraudCheckRequestData,
+        types::FraudCheckResponseData,
+    > for Payu
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::FraudCheckPost,
+        types::FraudCheckRequestData,
+        types::FraudCheckResponseData,
+    > for Payu
+{
+}
+
+impl api::ExternalAuthentication for Payu {}
+
+impl
+    services::ConnectorIntegration<
+        api::ThreeDsAuthentication,
+        types::ThreeDsAuthenticationRequestData,
+        types::ThreeDsAuthenticationData,
+    > for Payu
+{
+}
+
+impl api::Dispute for Payu {}
+impl api::SubmitEvidence for Payu {}
+impl api::DefendDispute for Payu {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Payu
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Defend,
+        types::DefendDisputeRequestData,
+        types::DefendDisputeResponse,
+    > for Payu
+{
+}
+
 impl api::Payment for Payu {}
 
 impl api::PreVerify for Payu {}
@@ -326,6 +423,17 @@ impl
     }
 }
 
+impl api::PaymentAuthorizeSessionToken for Payu {}
+
+impl
+    services::ConnectorIntegration<
+        api::AuthorizeSessionToken,
+        types::AuthorizeSessionTokenData,
+        types::AuthorizeSessionTokenResponse,
+    > for Payu
+{
+}
+
 impl api::PaymentSession for Payu {}
 
 impl
@@ -186,6 +186,103 @@ impl
     }
 }
 
+impl api::ConnectorAccessToken for Rapyd {}
+
+impl
+    services::ConnectorIntegration<
+        api::AccessTokenAuth,
+        types::AccessTokenRequestData,
+        types::AccessToken,
+    > for Rapyd
+{
+}
+
+impl api::ConnectorVerifyWebhookSource for Rapyd {}
+
+impl
+    services::ConnectorIntegration<
+        api::VerifyWebhookSource,
+        types::VerifyWebhookSourceRequestData,
+        types::VerifyWebhookSourceResponseData,
+    > for Rapyd
+{
+}
+
+impl api::Payout for Rapyd {}
+impl api::PayoutCreate for Rapyd {}
+impl api::PayoutFulfill for Rapyd {}
+impl api::PayoutCancel for Rapyd {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Rapyd
+{
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Rapyd
+{
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Rapyd
+{
+}
+
+impl api::FraudCheck for Rapyd {}
+impl api::FraudCheckPreAuth for Rapyd {}
+impl api::FraudCheckPostAuth for Rapyd {}
+
+impl
+    services::ConnectorIntegration<
+        api::FraudCheckPre,
+        types::FraudCheckRequestData,
+        types::FraudCheckResponseData,
+    > for Rapyd
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::FraudCheckPost,
+        types::FraudCheckRequestData,
+        types::FraudCheckResponseData,
+    > for Rapyd
+{
+}
+
+impl api::ExternalAuthentication for Rapyd {}
+
+impl
+    services::ConnectorIntegration<
+        api::ThreeDsAuthentication,
+        types::ThreeDsAuthenticationRequestData,
+        types::ThreeDsAuthenticationData,
+    > for Rapyd
+{
+}
+
+impl api::Dispute for Rapyd {}
+impl api::SubmitEvidence for Rapyd {}
+impl api::DefendDispute for Rapyd {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Rapyd
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Defend,
+        types::DefendDisputeRequestData,
+        types::DefendDisputeResponse,
+    > for Rapyd
+{
+}
+
 impl api::Payment for Rapyd {}
 
 impl api::PreVerify for Rapyd {}
@@ -458,6 +555,17 @@ impl
     }
 }
 
+impl api::PaymentAuthorizeSessionToken for Rapyd {}
+
+impl
+    services::ConnectorIntegration<
+        api::AuthorizeSessionToken,
+        types::AuthorizeSessionTokenData,
+        types::AuthorizeSessionTokenResponse,
+    > for Rapyd
+{
+}
+
 impl api::PaymentSession for Rapyd {}
 
 impl
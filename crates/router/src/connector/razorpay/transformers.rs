@@ -0,0 +1,400 @@
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    connector::utils::{self, PaymentsRequestData, PhoneDetailsData},
+    core::errors,
+    pii, services,
+    types::{self, api, storage::enums},
+};
+
+// Auth Struct
+pub struct RazorpayAuthType {
+    pub(super) key_id: String,
+    pub(super) key_secret: String,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for RazorpayAuthType {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        if let types::ConnectorAuthType::BodyKey { api_key, key1 } = auth_type {
+            Ok(Self {
+                key_id: api_key.to_string(),
+                key_secret: key1.to_string(),
+            })
+        } else {
+            Err(errors::ConnectorError::FailedToObtainAuthType)?
+        }
+    }
+}
+
+// Orders
+#[derive(Debug, Serialize)]
+pub struct RazorpayOrderRequest {
+    amount: i64,
+    currency: String,
+    receipt: Option<String>,
+    payment_capture: bool,
+}
+
+impl TryFrom<&types::PaymentsAuthorizeSessionTokenRouterData> for RazorpayOrderRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &types::PaymentsAuthorizeSessionTokenRouterData,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: item.request.amount,
+            currency: item.request.currency.to_string(),
+            receipt: item.request.attempt_id.clone(),
+            payment_capture: matches!(
+                item.request.capture_method,
+                Some(enums::CaptureMethod::Automatic) | None
+            ),
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RazorpayOrderResponse {
+    id: String,
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            RazorpayOrderResponse,
+            T,
+            types::AuthorizeSessionTokenResponse,
+        >,
+    > for types::RouterData<F, T, types::AuthorizeSessionTokenResponse>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            RazorpayOrderResponse,
+            T,
+            types::AuthorizeSessionTokenResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::AuthorizeSessionTokenResponse {
+                session_token: item.response.id,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// Payments
+#[derive(Debug, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RazorpayUpiFlow {
+    /// A collect request is sent to the customer's VPA, to be approved in their UPI app.
+    Collect,
+    /// A UPI link is returned, which the customer opens with a UPI app of their choice.
+    Intent,
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct RazorpayUpiDetails {
+    flow: RazorpayUpiFlow,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vpa: Option<Secret<String>>,
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct RazorpayPaymentsRequest {
+    amount: i64,
+    currency: String,
+    order_id: String,
+    email: Secret<String, pii::Email>,
+    contact: Secret<String>,
+    method: String,
+    upi: RazorpayUpiDetails,
+    description: Option<String>,
+    ip: Option<std::net::IpAddr>,
+    user_agent: Option<String>,
+    callback_url: Option<String>,
+}
+
+impl TryFrom<&types::PaymentsAuthorizeRouterData> for RazorpayPaymentsRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
+        let upi_data = match item.request.payment_method_data {
+            api::PaymentMethod::Upi(ref upi_data) => upi_data,
+            _ => Err(errors::ConnectorError::NotImplemented(
+                "Current Payment Method".to_string(),
+            ))?,
+        };
+        let upi = match upi_data.vpa_id.clone() {
+            Some(vpa) => RazorpayUpiDetails {
+                flow: RazorpayUpiFlow::Collect,
+                vpa: Some(vpa),
+            },
+            None => RazorpayUpiDetails {
+                flow: RazorpayUpiFlow::Intent,
+                vpa: None,
+            },
+        };
+        let order_id = item
+            .session_token
+            .clone()
+            .ok_or_else(utils::missing_field_err("session_token"))?;
+        let email = item
+            .request
+            .email
+            .clone()
+            .ok_or_else(utils::missing_field_err("email"))?;
+        let contact = item.get_billing_phone()?.get_number()?;
+        let browser_info = item.request.browser_info.as_ref();
+
+        Ok(Self {
+            amount: item.request.amount,
+            currency: item.request.currency.to_string(),
+            order_id,
+            email,
+            contact,
+            method: "upi".to_string(),
+            upi,
+            description: item.description.clone(),
+            ip: browser_info.and_then(|browser_info| browser_info.ip_address),
+            user_agent: browser_info.map(|browser_info| browser_info.user_agent.clone()),
+            callback_url: item.router_return_url.clone(),
+        })
+    }
+}
+
+/// Response of the UPI payment creation, the UPI link is only present for the intent flow.
+#[derive(Debug, Deserialize)]
+pub struct RazorpayUpiPaymentsResponse {
+    razorpay_payment_id: String,
+    link: Option<String>,
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<F, RazorpayUpiPaymentsResponse, T, types::PaymentsResponseData>,
+    > for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            RazorpayUpiPaymentsResponse,
+            T,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let redirection_data = item.response.link.map(|link| {
+            services::RedirectForm::new(link, services::Method::Get, Default::default())
+        });
+        Ok(Self {
+            status: enums::AttemptStatus::AuthenticationPending,
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(
+                    item.response.razorpay_payment_id,
+                ),
+                redirect: redirection_data.is_some(),
+                redirection_data,
+                mandate_reference: None,
+                connector_metadata: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RazorpayPaymentStatus {
+    #[default]
+    Created,
+    Authorized,
+    Captured,
+    Refunded,
+    Failed,
+}
+
+impl From<RazorpayPaymentStatus> for enums::AttemptStatus {
+    fn from(item: RazorpayPaymentStatus) -> Self {
+        match item {
+            RazorpayPaymentStatus::Created => Self::AuthenticationPending,
+            RazorpayPaymentStatus::Authorized => Self::Authorized,
+            RazorpayPaymentStatus::Captured | RazorpayPaymentStatus::Refunded => Self::Charged,
+            RazorpayPaymentStatus::Failed => Self::Failure,
+        }
+    }
+}
+
+/// The payment entity, as returned on fetching or capturing a payment and in webhooks.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Eq, PartialEq)]
+pub struct RazorpayPaymentResponse {
+    pub id: String,
+    pub amount: i64,
+    pub currency: String,
+    pub status: RazorpayPaymentStatus,
+    pub order_id: Option<String>,
+    pub error_code: Option<String>,
+    pub error_description: Option<String>,
+}
+
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, RazorpayPaymentResponse, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<F, RazorpayPaymentResponse, T, types::PaymentsResponseData>,
+    ) -> Result<Self, Self::Error> {
+        let status = enums::AttemptStatus::from(item.response.status);
+        let response = match item.response.error_code {
+            Some(code) if status == enums::AttemptStatus::Failure => Err(types::ErrorResponse {
+                code,
+                message: item.response.error_description.unwrap_or_default(),
+                reason: None,
+            }),
+            _ => Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(item.response.id),
+                redirection_data: None,
+                redirect: false,
+                mandate_reference: None,
+                connector_metadata: None,
+            }),
+        };
+        Ok(Self {
+            status,
+            response,
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct RazorpayCaptureRequest {
+    amount: i64,
+    currency: String,
+}
+
+impl TryFrom<&types::PaymentsCaptureRouterData> for RazorpayCaptureRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: item
+                .request
+                .amount_to_capture
+                .unwrap_or(item.request.amount),
+            currency: item.request.currency.to_string(),
+        })
+    }
+}
+
+// REFUND :
+#[derive(Debug, Serialize)]
+pub struct RazorpayRefundRequest {
+    amount: i64,
+    receipt: String,
+}
+
+impl<F> TryFrom<&types::RefundsRouterData<F>> for RazorpayRefundRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: item.request.refund_amount,
+            receipt: item.request.refund_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RazorpayRefundStatus {
+    #[default]
+    Pending,
+    Processed,
+    Failed,
+}
+
+impl From<RazorpayRefundStatus> for enums::RefundStatus {
+    fn from(item: RazorpayRefundStatus) -> Self {
+        match item {
+            RazorpayRefundStatus::Pending => Self::Pending,
+            RazorpayRefundStatus::Processed => Self::Success,
+            RazorpayRefundStatus::Failed => Self::Failure,
+        }
+    }
+}
+
+/// The refund entity, as returned on creating or fetching a refund and in webhooks.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Eq, PartialEq)]
+pub struct RazorpayRefundResponse {
+    pub id: String,
+    pub payment_id: String,
+    pub amount: i64,
+    pub status: RazorpayRefundStatus,
+}
+
+impl<F> TryFrom<types::RefundsResponseRouterData<F, RazorpayRefundResponse>>
+    for types::RefundsRouterData<F>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::RefundsResponseRouterData<F, RazorpayRefundResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::RefundsResponseData {
+                connector_refund_id: item.response.id,
+                refund_status: enums::RefundStatus::from(item.response.status),
+            }),
+            ..item.data
+        })
+    }
+}
+
+// Webhooks
+#[derive(Debug, Deserialize)]
+pub struct RazorpayWebhookEvent {
+    pub event: RazorpayWebhookEventType,
+    pub payload: RazorpayWebhookPayload,
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+pub enum RazorpayWebhookEventType {
+    #[serde(rename = "payment.authorized")]
+    PaymentAuthorized,
+    #[serde(rename = "payment.captured")]
+    PaymentCaptured,
+    #[serde(rename = "payment.failed")]
+    PaymentFailed,
+    #[serde(rename = "refund.processed")]
+    RefundProcessed,
+    #[serde(rename = "refund.failed")]
+    RefundFailed,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RazorpayWebhookPayload {
+    pub payment: Option<RazorpayWebhookEntity<RazorpayPaymentResponse>>,
+    pub refund: Option<RazorpayWebhookEntity<RazorpayRefundResponse>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RazorpayWebhookEntity<T> {
+    pub entity: T,
+}
+
+// Error
+#[derive(Debug, Deserialize)]
+pub struct RazorpayErrorResponse {
+    pub error: RazorpayErrorDetails,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RazorpayErrorDetails {
+    pub code: String,
+    pub description: String,
+    pub reason: Option<String>,
+}
@@ -93,6 +93,103 @@ impl ConnectorCommon for Shift4 {
     }
 }
 
+impl api::ConnectorAccessToken for Shift4 {}
+
+impl
+    services::ConnectorIntegration<
+        api::AccessTokenAuth,
+        types::AccessTokenRequestData,
+        types::AccessToken,
+    > for Shift4
+{
+}
+
+impl api::ConnectorVerifyWebhookSource for Shift4 {}
+
+impl
+    services::ConnectorIntegration<
+        api::VerifyWebhookSource,
+        types::VerifyWebhookSourceRequestData,
+        types::VerifyWebhookSourceResponseData,
+    > for Shift4
+{
+}
+
+impl api::Payout for Shift4 {}
+impl api::PayoutCreate for Shift4 {}
+impl api::PayoutFulfill for Shift4 {}
+impl api::PayoutCancel for Shift4 {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Shift4
+{
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Shift4
+{
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Shift4
+{
+}
+
+impl api::FraudCheck for Shift4 {}
+impl api::FraudCheckPreAuth for Shift4 {}
+impl api::FraudCheckPostAuth for Shift4 {}
+
+impl
+    services::ConnectorIntegration<
+        api::FraudCheckPre,
+        types::FraudCheckRequestData,
+        types::FraudCheckResponseData,
+    > for Shift4
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::FraudCheckPost,
+        types::FraudCheckRequestData,
+        types::FraudCheckResponseData,
+    > for Shift4
+{
+}
+
+impl api::ExternalAuthentication for Shift4 {}
+
+impl
+    services::ConnectorIntegration<
+        api::ThreeDsAuthentication,
+        types::ThreeDsAuthenticationRequestData,
+        types::ThreeDsAuthenticationData,
+    > for Shift4
+{
+}
+
+impl api::Dispute for Shift4 {}
+impl api::SubmitEvidence for Shift4 {}
+impl api::DefendDispute for Shift4 {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Shift4
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Defend,
+        types::DefendDisputeRequestData,
+        types::DefendDisputeResponse,
+    > for Shift4
+{
+}
+
 impl api::Payment for Shift4 {}
 
 impl api::PreVerify for Shift4 {}
@@ -254,6 +351,17 @@ impl ConnectorIntegration<api::Capture, types::PaymentsCaptureData, types::Payme
     }
 }
 
+impl api::PaymentAuthorizeSessionToken for Shift4 {}
+
+impl
+    ConnectorIntegration<
+        api::AuthorizeSessionToken,
+        types::AuthorizeSessionTokenData,
+        types::AuthorizeSessionTokenResponse,
+    > for Shift4
+{
+}
+
 impl api::PaymentSession for Shift4 {}
 
 impl ConnectorIntegration<api::Session, types::PaymentsSessionData, types::PaymentsResponseData>
@@ -519,6 +627,17 @@ impl api::IncomingWebhook for Shift4 {
         })
     }
 
+    fn get_webhook_event_id(
+        &self,
+        body: &[u8],
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let details: shift4::Shift4WebhookObjectEventId = body
+            .parse_struct("Shift4WebhookObjectEventId")
+            .change_context(errors::ConnectorError::WebhookEventIdNotFound)?;
+
+        Ok(Some(details.id))
+    }
+
     fn get_webhook_resource_object(
         &self,
         body: &[u8],
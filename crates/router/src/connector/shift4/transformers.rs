@@ -111,6 +111,11 @@ pub struct Shift4WebhookObjectData {
     pub id: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct Shift4WebhookObjectEventId {
+    pub id: String,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct Shift4WebhookObjectId {
     pub data: Shift4WebhookObjectData,
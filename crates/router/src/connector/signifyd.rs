@@ -0,0 +1,416 @@
+mod transformers;
+
+use std::fmt::Debug;
+
+use base64::Engine;
+use bytes::Bytes;
+use error_stack::{IntoReport, ResultExt};
+
+use self::transformers as signifyd;
+use crate::{
+    configs::settings,
+    consts,
+    core::errors::{self, CustomResult},
+    headers, services,
+    types::{
+        self,
+        api::{self, ConnectorCommon, ConnectorCommonExt},
+    },
+    utils::{self, BytesExt},
+};
+
+#[derive(Debug, Clone)]
+pub struct Signifyd;
+
+impl<Flow, Request, Response> ConnectorCommonExt<Flow, Request, Response> for Signifyd
+where
+    Self: services::ConnectorIntegration<Flow, Request, Response>,
+{
+    fn build_headers(
+        &self,
+        req: &types::RouterData<Flow, Request, Response>,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut headers = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            self.get_content_type().to_string(),
+        )];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        headers.append(&mut api_key);
+        Ok(headers)
+    }
+}
+
+impl ConnectorCommon for Signifyd {
+    fn id(&self) -> &'static str {
+        "signifyd"
+    }
+
+    fn base_url<'a>(&self, connectors: &'a settings::Connectors) -> &'a str {
+        connectors.signifyd.base_url.as_ref()
+    }
+
+    fn get_auth_header(
+        &self,
+        auth_type: &types::ConnectorAuthType,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let auth = signifyd::SignifydAuthType::try_from(auth_type)
+            .change_context(errors::ConnectorError::FailedToObtainAuthType)?;
+        Ok(vec![(
+            headers::AUTHORIZATION.to_string(),
+            format!("Basic {}", consts::BASE64_ENGINE.encode(auth.api_key)),
+        )])
+    }
+
+    fn build_error_response(
+        &self,
+        res: Bytes,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: signifyd::SignifydErrorResponse =
+            res.parse_struct("SignifydErrorResponse")
+                .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(response.into())
+    }
+}
+
+impl api::ConnectorAccessToken for Signifyd {}
+
+impl
+    services::ConnectorIntegration<
+        api::AccessTokenAuth,
+        types::AccessTokenRequestData,
+        types::AccessToken,
+    > for Signifyd
+{
+}
+
+impl api::ConnectorVerifyWebhookSource for Signifyd {}
+
+impl
+    services::ConnectorIntegration<
+        api::VerifyWebhookSource,
+        types::VerifyWebhookSourceRequestData,
+        types::VerifyWebhookSourceResponseData,
+    > for Signifyd
+{
+}
+
+impl api::Payout for Signifyd {}
+impl api::PayoutCreate for Signifyd {}
+impl api::PayoutFulfill for Signifyd {}
+impl api::PayoutCancel for Signifyd {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Signifyd
+{
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Signifyd
+{
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Signifyd
+{
+}
+
+impl api::ExternalAuthentication for Signifyd {}
+
+impl
+    services::ConnectorIntegration<
+        api::ThreeDsAuthentication,
+        types::ThreeDsAuthenticationRequestData,
+        types::ThreeDsAuthenticationData,
+    > for Signifyd
+{
+}
+
+impl api::FraudCheck for Signifyd {}
+impl api::FraudCheckPreAuth for Signifyd {}
+impl api::FraudCheckPostAuth for Signifyd {}
+
+impl
+    services::ConnectorIntegration<
+        api::FraudCheckPre,
+        types::FraudCheckRequestData,
+        types::FraudCheckResponseData,
+    > for Signifyd
+{
+    fn get_headers(
+        &self,
+        req: &types::FraudCheckRouterData<api::FraudCheckPre>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::FraudCheckRouterData<api::FraudCheckPre>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}v3/orders/events/checkouts",
+            self.base_url(connectors)
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::FraudCheckRouterData<api::FraudCheckPre>,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let req = utils::Encode::<signifyd::SignifydCheckoutRequest>::convert_and_encode(req)
+            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::FraudCheckRouterData<api::FraudCheckPre>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Post)
+            .url(&types::FraudCheckPreType::get_url(self, req, connectors)?)
+            .headers(types::FraudCheckPreType::get_headers(
+                self, req, connectors,
+            )?)
+            .body(types::FraudCheckPreType::get_request_body(self, req)?)
+            .build();
+        Ok(Some(request))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::FraudCheckRouterData<api::FraudCheckPre>,
+        res: types::Response,
+    ) -> CustomResult<types::FraudCheckRouterData<api::FraudCheckPre>, errors::ConnectorError> {
+        let response: signifyd::SignifydDecisionResponse = res
+            .response
+            .parse_struct("SignifydDecisionResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: Bytes,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl
+    services::ConnectorIntegration<
+        api::FraudCheckPost,
+        types::FraudCheckRequestData,
+        types::FraudCheckResponseData,
+    > for Signifyd
+{
+    fn get_headers(
+        &self,
+        req: &types::FraudCheckRouterData<api::FraudCheckPost>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::FraudCheckRouterData<api::FraudCheckPost>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}v3/orders/events/transactions",
+            self.base_url(connectors)
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::FraudCheckRouterData<api::FraudCheckPost>,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let req = utils::Encode::<signifyd::SignifydTransactionRequest>::convert_and_encode(req)
+            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::FraudCheckRouterData<api::FraudCheckPost>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Post)
+            .url(&types::FraudCheckPostType::get_url(self, req, connectors)?)
+            .headers(types::FraudCheckPostType::get_headers(
+                self, req, connectors,
+            )?)
+            .body(types::FraudCheckPostType::get_request_body(self, req)?)
+            .build();
+        Ok(Some(request))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::FraudCheckRouterData<api::FraudCheckPost>,
+        res: types::Response,
+    ) -> CustomResult<types::FraudCheckRouterData<api::FraudCheckPost>, errors::ConnectorError>
+    {
+        let response: signifyd::SignifydDecisionResponse = res
+            .response
+            .parse_struct("SignifydDecisionResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: Bytes,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl api::Dispute for Signifyd {}
+impl api::SubmitEvidence for Signifyd {}
+impl api::DefendDispute for Signifyd {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Signifyd
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Defend,
+        types::DefendDisputeRequestData,
+        types::DefendDisputeResponse,
+    > for Signifyd
+{
+}
+
+impl api::Payment for Signifyd {}
+impl api::PaymentAuthorize for Signifyd {}
+impl api::PaymentSync for Signifyd {}
+impl api::PaymentVoid for Signifyd {}
+impl api::PaymentCapture for Signifyd {}
+impl api::PreVerify for Signifyd {}
+impl api::PaymentAuthorizeSessionToken for Signifyd {}
+
+impl
+    services::ConnectorIntegration<
+        api::AuthorizeSessionToken,
+        types::AuthorizeSessionTokenData,
+        types::AuthorizeSessionTokenResponse,
+    > for Signifyd
+{
+}
+
+impl api::PaymentSession for Signifyd {}
+
+impl
+    services::ConnectorIntegration<
+        api::Verify,
+        types::VerifyRequestData,
+        types::PaymentsResponseData,
+    > for Signifyd
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Capture,
+        types::PaymentsCaptureData,
+        types::PaymentsResponseData,
+    > for Signifyd
+{
+}
+
+impl
+    services::ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsResponseData>
+    for Signifyd
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Authorize,
+        types::PaymentsAuthorizeData,
+        types::PaymentsResponseData,
+    > for Signifyd
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Void,
+        types::PaymentsCancelData,
+        types::PaymentsResponseData,
+    > for Signifyd
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Session,
+        types::PaymentsSessionData,
+        types::PaymentsResponseData,
+    > for Signifyd
+{
+}
+
+impl api::Refund for Signifyd {}
+impl api::RefundExecute for Signifyd {}
+impl api::RefundSync for Signifyd {}
+
+impl services::ConnectorIntegration<api::Execute, types::RefundsData, types::RefundsResponseData>
+    for Signifyd
+{
+}
+
+impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponseData>
+    for Signifyd
+{
+}
+
+impl services::ConnectorRedirectResponse for Signifyd {}
+
+#[async_trait::async_trait]
+impl api::IncomingWebhook for Signifyd {
+    fn get_webhook_object_reference_id(
+        &self,
+        _body: &[u8],
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+    }
+
+    fn get_webhook_event_type(
+        &self,
+        _body: &[u8],
+    ) -> CustomResult<api::IncomingWebhookEvent, errors::ConnectorError> {
+        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+    }
+
+    fn get_webhook_resource_object(
+        &self,
+        _body: &[u8],
+    ) -> CustomResult<serde_json::Value, errors::ConnectorError> {
+        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+    }
+}
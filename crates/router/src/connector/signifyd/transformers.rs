@@ -0,0 +1,357 @@
+use std::{collections::HashMap, str::FromStr};
+
+use api_models::enums::FrmDecision;
+use common_utils::types::MinorUnit;
+use error_stack::{IntoReport, ResultExt};
+use masking::PeekInterface;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    consts,
+    core::errors,
+    types::{self, api, storage::enums},
+};
+
+pub struct SignifydAuthType {
+    pub api_key: String,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for SignifydAuthType {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        match auth_type {
+            types::ConnectorAuthType::HeaderKey { api_key } => Ok(Self {
+                api_key: api_key.to_string(),
+            }),
+            _ => Err(errors::ConnectorError::FailedToObtainAuthType.into()),
+        }
+    }
+}
+
+/// Converts an amount in the minor unit of the currency to the decimal amount expected by
+/// Signifyd.
+fn get_decimal_amount(
+    amount: i64,
+    currency: enums::Currency,
+) -> Result<serde_json::Number, error_stack::Report<errors::ConnectorError>> {
+    let amount = MinorUnit::new(amount).to_major_unit_as_string(&currency);
+    serde_json::Number::from_str(&amount)
+        .into_report()
+        .change_context(errors::ConnectorError::RequestEncodingFailed)
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignifydCheckoutRequest {
+    order_id: String,
+    checkout_id: String,
+    purchase: Purchase,
+    user_account: Option<UserAccount>,
+    device: Option<Device>,
+    transactions: Vec<Transaction>,
+    coverage_requests: Vec<CoverageRequest>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignifydTransactionRequest {
+    order_id: String,
+    checkout_id: String,
+    transactions: Vec<Transaction>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Purchase {
+    order_channel: OrderChannel,
+    total_price: serde_json::Number,
+    currency: enums::Currency,
+    products: Vec<Product>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OrderChannel {
+    Web,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Product {
+    item_name: String,
+    item_quantity: u16,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UserAccount {
+    email: masking::Secret<String, common_utils::pii::Email>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Device {
+    client_ip_address: std::net::IpAddr,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CoverageRequest {
+    Fraud,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Transaction {
+    transaction_id: Option<String>,
+    /// The outcome of the authorization, only known for the checks made after it
+    gateway_status_code: Option<GatewayStatusCode>,
+    payment_method: SignifydPaymentMethod,
+    checkout_payment_details: Option<CheckoutPaymentDetails>,
+    amount: serde_json::Number,
+    currency: enums::Currency,
+    gateway: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum GatewayStatusCode {
+    Success,
+    Pending,
+    Failure,
+}
+
+impl From<enums::AttemptStatus> for GatewayStatusCode {
+    fn from(status: enums::AttemptStatus) -> Self {
+        match status {
+            enums::AttemptStatus::Authorized
+            | enums::AttemptStatus::Charged
+            | enums::AttemptStatus::PartialCharged => Self::Success,
+            enums::AttemptStatus::Failure
+            | enums::AttemptStatus::AuthorizationFailed
+            | enums::AttemptStatus::AuthenticationFailed
+            | enums::AttemptStatus::CaptureFailed
+            | enums::AttemptStatus::VoidFailed
+            | enums::AttemptStatus::RouterDeclined => Self::Failure,
+            _ => Self::Pending,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SignifydPaymentMethod {
+    CreditCard,
+    DigitalWallet,
+    Other,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckoutPaymentDetails {
+    card_bin: Option<String>,
+    card_last4: Option<String>,
+    card_expiry_month: masking::Secret<String>,
+    card_expiry_year: masking::Secret<String>,
+}
+
+impl<F> TryFrom<&types::FraudCheckRouterData<F>> for Transaction {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::FraudCheckRouterData<F>) -> Result<Self, Self::Error> {
+        let (payment_method, checkout_payment_details) = match &item.request.payment_method_data {
+            api::PaymentMethod::Card(card) => {
+                let card_number = card.card_number.peek();
+                (
+                    SignifydPaymentMethod::CreditCard,
+                    Some(CheckoutPaymentDetails {
+                        card_bin: card_number.get(..6).map(ToString::to_string),
+                        card_last4: card_number
+                            .get(card_number.len().saturating_sub(4)..)
+                            .map(ToString::to_string),
+                        card_expiry_month: card.card_exp_month.clone(),
+                        card_expiry_year: card.card_exp_year.clone(),
+                    }),
+                )
+            }
+            api::PaymentMethod::Wallet(_) => (SignifydPaymentMethod::DigitalWallet, None),
+            _ => (SignifydPaymentMethod::Other, None),
+        };
+
+        Ok(Self {
+            transaction_id: item.request.connector_transaction_id.clone(),
+            gateway_status_code: item.request.payment_status.map(Into::into),
+            payment_method,
+            checkout_payment_details,
+            amount: get_decimal_amount(item.request.amount, item.request.currency)?,
+            currency: item.request.currency,
+            gateway: item.request.payment_connector.clone(),
+        })
+    }
+}
+
+fn get_checkout_id<F>(item: &types::FraudCheckRouterData<F>) -> String {
+    item.attempt_id
+        .clone()
+        .unwrap_or_else(|| item.payment_id.clone())
+}
+
+impl TryFrom<&types::FraudCheckRouterData<api::FraudCheckPre>> for SignifydCheckoutRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &types::FraudCheckRouterData<api::FraudCheckPre>,
+    ) -> Result<Self, Self::Error> {
+        let products = item
+            .request
+            .order_details
+            .as_ref()
+            .map(|order_details| {
+                vec![Product {
+                    item_name: order_details.product_name.clone(),
+                    item_quantity: order_details.quantity,
+                }]
+            })
+            .unwrap_or_default();
+
+        Ok(Self {
+            order_id: item.payment_id.clone(),
+            checkout_id: get_checkout_id(item),
+            purchase: Purchase {
+                order_channel: OrderChannel::Web,
+                total_price: get_decimal_amount(item.request.amount, item.request.currency)?,
+                currency: item.request.currency,
+                products,
+            },
+            user_account: item
+                .request
+                .email
+                .clone()
+                .map(|email| UserAccount { email }),
+            device: item
+                .request
+                .browser_info
+                .as_ref()
+                .and_then(|browser_info| browser_info.ip_address)
+                .map(|client_ip_address| Device { client_ip_address }),
+            transactions: vec![Transaction::try_from(item)?],
+            coverage_requests: vec![CoverageRequest::Fraud],
+        })
+    }
+}
+
+impl TryFrom<&types::FraudCheckRouterData<api::FraudCheckPost>> for SignifydTransactionRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &types::FraudCheckRouterData<api::FraudCheckPost>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            order_id: item.payment_id.clone(),
+            checkout_id: get_checkout_id(item),
+            transactions: vec![Transaction::try_from(item)?],
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignifydDecisionResponse {
+    pub signifyd_id: i64,
+    pub decision: Decision,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Decision {
+    pub checkpoint_action: CheckpointAction,
+    pub checkpoint_action_reason: Option<String>,
+    pub score: Option<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CheckpointAction {
+    Accept,
+    Reject,
+    Hold,
+    /// Actions which Signifyd may introduce later are treated as requiring a review
+    #[serde(other)]
+    Unknown,
+}
+
+impl From<CheckpointAction> for FrmDecision {
+    fn from(action: CheckpointAction) -> Self {
+        match action {
+            CheckpointAction::Accept => Self::Approve,
+            CheckpointAction::Reject => Self::Reject,
+            CheckpointAction::Hold | CheckpointAction::Unknown => Self::Review,
+        }
+    }
+}
+
+impl<F> TryFrom<types::FraudCheckResponseRouterData<F, SignifydDecisionResponse>>
+    for types::FraudCheckRouterData<F>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::FraudCheckResponseRouterData<F, SignifydDecisionResponse>,
+    ) -> Result<Self, Self::Error> {
+        let decision = item.response.decision;
+        Ok(Self {
+            response: Ok(types::FraudCheckResponseData {
+                decision: decision.checkpoint_action.into(),
+                frm_transaction_id: Some(item.response.signifyd_id.to_string()),
+                score: decision.score,
+                reason: decision.checkpoint_action_reason,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignifydErrorResponse {
+    pub trace_id: Option<String>,
+    /// The messages describing the error, keyed by the invalid field of the request
+    #[serde(default)]
+    pub errors: HashMap<String, Vec<String>>,
+}
+
+impl From<SignifydErrorResponse> for types::ErrorResponse {
+    fn from(response: SignifydErrorResponse) -> Self {
+        let message = response
+            .errors
+            .into_iter()
+            .flat_map(|(field, messages)| {
+                messages
+                    .into_iter()
+                    .map(move |message| format!("{field}: {message}"))
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        Self {
+            code: consts::NO_ERROR_CODE.to_string(),
+            message: if message.is_empty() {
+                consts::NO_ERROR_MESSAGE.to_string()
+            } else {
+                message
+            },
+            reason: response
+                .trace_id
+                .map(|trace_id| format!("trace id: {trace_id}")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_decimal_amount() {
+        let amount = |amount, currency| get_decimal_amount(amount, currency).unwrap().to_string();
+        assert_eq!(amount(1005, enums::Currency::USD), "10.05");
+        assert_eq!(amount(5, enums::Currency::USD), "0.05");
+        assert_eq!(amount(1005, enums::Currency::JPY), "1005");
+        assert_eq!(amount(1005, enums::Currency::KWD), "1.005");
+    }
+}
@@ -1,5 +1,6 @@
 use std::str::FromStr;
 
+use base64::Engine;
 use error_stack::{IntoReport, ResultExt};
 use serde::{Deserialize, Serialize};
 use strum::EnumString;
@@ -7,10 +8,12 @@ use url::Url;
 use uuid::Uuid;
 
 use crate::{
+    consts,
     core::errors,
     pii::{self, ExposeOptionInterface, Secret},
     services,
     types::{self, api, storage::enums},
+    utils::StringExt,
 };
 
 pub struct StripeAuthType {
@@ -85,6 +88,46 @@ pub struct PaymentIntentRequest {
     #[serde(flatten)]
     pub payment_data: Option<StripePaymentMethodData>,
     pub capture_method: StripeCaptureMethod,
+    #[serde(flatten)]
+    pub three_ds_authentication: Option<StripeThreeDsAuthentication>,
+    #[serde(rename = "expand[]")]
+    pub expand: Option<StripeExpandableField>,
+}
+
+/// A field of the response which is returned as an object instead of an ID, when expanded.
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeExpandableField {
+    LatestCharge,
+}
+
+/// The result of a 3DS authentication performed outside of Stripe.
+#[derive(Debug, Eq, PartialEq, Serialize)]
+pub struct StripeThreeDsAuthentication {
+    #[serde(rename = "payment_method_options[card][three_d_secure][cryptogram]")]
+    pub cryptogram: Option<Secret<String>>,
+    #[serde(
+        rename = "payment_method_options[card][three_d_secure][electronic_commerce_indicator]"
+    )]
+    pub electronic_commerce_indicator: Option<String>,
+    #[serde(rename = "payment_method_options[card][three_d_secure][transaction_id]")]
+    pub transaction_id: Option<String>,
+    #[serde(rename = "payment_method_options[card][three_d_secure][version]")]
+    pub version: String,
+    #[serde(rename = "payment_method_options[card][three_d_secure][ares_trans_status]")]
+    pub ares_trans_status: types::ThreeDsTransStatus,
+}
+
+impl From<types::ThreeDsAuthenticationData> for StripeThreeDsAuthentication {
+    fn from(authentication_data: types::ThreeDsAuthenticationData) -> Self {
+        Self {
+            cryptogram: authentication_data.cavv,
+            electronic_commerce_indicator: authentication_data.eci,
+            transaction_id: authentication_data.ds_trans_id,
+            version: authentication_data.message_version,
+            ares_trans_status: authentication_data.trans_status,
+        }
+    }
 }
 
 #[derive(Debug, Eq, PartialEq, Serialize)]
@@ -138,8 +181,23 @@ pub enum StripePaymentMethodData {
     Klarna(StripePayLaterData),
     Affirm(StripePayLaterData),
     Bank,
+    ApplePay(StripeWalletTokenData),
+    GooglePay(StripeWalletTokenData),
     Wallet,
     Paypal,
+    AchBankDebit(StripeAchBankDebitData),
+    SepaBankDebit(StripeSepaBankDebitData),
+}
+
+/// A wallet payment, made with the Stripe token which the wallet token was exchanged for.
+#[derive(Debug, Eq, PartialEq, Serialize)]
+pub struct StripeWalletTokenData {
+    #[serde(rename = "payment_method_types[]")]
+    pub payment_method_types: StripePaymentMethodType,
+    #[serde(rename = "payment_method_data[type]")]
+    pub payment_method_data_type: StripePaymentMethodType,
+    #[serde(rename = "payment_method_data[card][token]")]
+    pub token: String,
 }
 
 #[derive(Debug, Eq, PartialEq, Serialize)]
@@ -148,6 +206,114 @@ pub enum StripePaymentMethodType {
     Card,
     Klarna,
     Affirm,
+    UsBankAccount,
+    SepaDebit,
+}
+
+/// An ACH debit from a bank account in the US, which takes a few business days to settle.
+#[derive(Debug, Eq, PartialEq, Serialize)]
+pub struct StripeAchBankDebitData {
+    #[serde(rename = "payment_method_types[]")]
+    pub payment_method_types: StripePaymentMethodType,
+    #[serde(rename = "payment_method_data[type]")]
+    pub payment_method_data_type: StripePaymentMethodType,
+    #[serde(rename = "payment_method_data[billing_details][name]")]
+    pub account_holder_name: Secret<String>,
+    #[serde(rename = "payment_method_data[us_bank_account][account_number]")]
+    pub account_number: Secret<String>,
+    #[serde(rename = "payment_method_data[us_bank_account][routing_number]")]
+    pub routing_number: Secret<String>,
+    #[serde(rename = "payment_method_data[us_bank_account][account_holder_type]")]
+    pub account_holder_type: String,
+    #[serde(flatten)]
+    pub mandate_acceptance: StripeMandateAcceptance,
+}
+
+/// A SEPA Direct Debit, which takes a few business days to settle.
+#[derive(Debug, Eq, PartialEq, Serialize)]
+pub struct StripeSepaBankDebitData {
+    #[serde(rename = "payment_method_types[]")]
+    pub payment_method_types: StripePaymentMethodType,
+    #[serde(rename = "payment_method_data[type]")]
+    pub payment_method_data_type: StripePaymentMethodType,
+    #[serde(rename = "payment_method_data[billing_details][name]")]
+    pub account_holder_name: Secret<String>,
+    #[serde(rename = "payment_method_data[billing_details][email]")]
+    pub billing_email: Option<Secret<String, pii::Email>>,
+    #[serde(rename = "payment_method_data[sepa_debit][iban]")]
+    pub iban: Secret<String>,
+    #[serde(flatten)]
+    pub mandate_acceptance: StripeMandateAcceptance,
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+pub struct StripeMandateAcceptance {
+    #[serde(rename = "mandate_data[customer_acceptance][type]")]
+    pub acceptance_type: api_models::payments::AcceptanceType,
+    /// The time at which the mandate was accepted, as a unix timestamp
+    #[serde(rename = "mandate_data[customer_acceptance][accepted_at]")]
+    pub accepted_at: Option<i64>,
+    #[serde(rename = "mandate_data[customer_acceptance][online][ip_address]")]
+    pub ip_address: Option<Secret<String, pii::IpAddress>>,
+    #[serde(rename = "mandate_data[customer_acceptance][online][user_agent]")]
+    pub user_agent: Option<String>,
+}
+
+impl From<api_models::payments::CustomerAcceptance> for StripeMandateAcceptance {
+    fn from(customer_acceptance: api_models::payments::CustomerAcceptance) -> Self {
+        let online = customer_acceptance.online;
+        Self {
+            acceptance_type: customer_acceptance.acceptance_type,
+            accepted_at: customer_acceptance
+                .accepted_at
+                .map(|accepted_at| accepted_at.assume_utc().unix_timestamp()),
+            ip_address: online.as_ref().map(|online| online.ip_address.clone()),
+            user_agent: online.map(|online| online.user_agent),
+        }
+    }
+}
+
+impl
+    From<(
+        api_models::payments::BankDebitData,
+        Option<Secret<String, pii::Email>>,
+    )> for StripePaymentMethodData
+{
+    fn from(
+        (bank_debit_data, billing_email): (
+            api_models::payments::BankDebitData,
+            Option<Secret<String, pii::Email>>,
+        ),
+    ) -> Self {
+        match bank_debit_data {
+            api_models::payments::BankDebitData::AchBankDebit {
+                account_holder_name,
+                account_number,
+                routing_number,
+                mandate_acceptance,
+            } => Self::AchBankDebit(StripeAchBankDebitData {
+                payment_method_types: StripePaymentMethodType::UsBankAccount,
+                payment_method_data_type: StripePaymentMethodType::UsBankAccount,
+                account_holder_name,
+                account_number,
+                routing_number,
+                account_holder_type: "individual".to_string(),
+                mandate_acceptance: mandate_acceptance.into(),
+            }),
+            api_models::payments::BankDebitData::SepaBankDebit {
+                account_holder_name,
+                iban,
+                mandate_acceptance,
+            } => Self::SepaBankDebit(StripeSepaBankDebitData {
+                payment_method_types: StripePaymentMethodType::SepaDebit,
+                payment_method_data_type: StripePaymentMethodType::SepaDebit,
+                account_holder_name,
+                billing_email,
+                iban,
+                mandate_acceptance: mandate_acceptance.into(),
+            }),
+        }
+    }
 }
 
 impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentIntentRequest {
@@ -169,10 +335,12 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentIntentRequest {
                 None => (
                     Some(match item.request.payment_method_data {
                         api::PaymentMethod::Card(ref ccard) => StripePaymentMethodData::Card({
-                            let payment_method_auth_type = match item.auth_type {
-                                enums::AuthenticationType::ThreeDs => Auth3ds::Any,
-                                enums::AuthenticationType::NoThreeDs => Auth3ds::Automatic,
-                            };
+                            // Payments which were already authenticated are not authenticated again
+                            let payment_method_auth_type =
+                                match (item.auth_type, &item.request.authentication_data) {
+                                    (enums::AuthenticationType::ThreeDs, None) => Auth3ds::Any,
+                                    _ => Auth3ds::Automatic,
+                                };
                             StripeCardData {
                                 payment_method_types: StripePaymentMethodType::Card,
                                 payment_method_data_type: StripePaymentMethodType::Card,
@@ -212,8 +380,43 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentIntentRequest {
                             )
                             .change_context(errors::ParsingError))?,
                         },
+                        api::PaymentMethod::Wallet(api_models::payments::WalletData {
+                            issuer_name: api_models::enums::WalletIssuer::ApplePay,
+                            ..
+                        }) => StripePaymentMethodData::ApplePay(StripeWalletTokenData {
+                            payment_method_types: StripePaymentMethodType::Card,
+                            payment_method_data_type: StripePaymentMethodType::Card,
+                            token: item
+                                .session_token
+                                .clone()
+                                .ok_or(errors::ParsingError)
+                                .into_report()
+                                .attach_printable("Missing the token for the Apple Pay payment")?,
+                        }),
+                        api::PaymentMethod::Wallet(
+                            ref wallet_data @ api_models::payments::WalletData {
+                                issuer_name: api_models::enums::WalletIssuer::GooglePay,
+                                ..
+                            },
+                        ) => StripePaymentMethodData::GooglePay(StripeWalletTokenData::try_from(
+                            wallet_data,
+                        )?),
                         api::PaymentMethod::Wallet(_) => StripePaymentMethodData::Wallet,
                         api::PaymentMethod::Paypal => StripePaymentMethodData::Paypal,
+                        api::PaymentMethod::Upi(_) => Err(error_stack::report!(
+                            errors::ApiErrorResponse::NotImplemented
+                        )
+                        .attach_printable(
+                            "Stripe does not support payment through provided payment method"
+                                .to_string(),
+                        )
+                        .change_context(errors::ParsingError))?,
+                        api::PaymentMethod::BankDebit(ref bank_debit_data) => {
+                            StripePaymentMethodData::from((
+                                bank_debit_data.clone(),
+                                item.request.email.clone(),
+                            ))
+                        }
                     }),
                     None,
                 ),
@@ -249,6 +452,14 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentIntentRequest {
             None => Address::default(),
         };
 
+        // The mandate of a bank debit is only present in the charge of the payment intent
+        let expand = matches!(
+            payment_data,
+            Some(StripePaymentMethodData::AchBankDebit(_))
+                | Some(StripePaymentMethodData::SepaBankDebit(_))
+        )
+        .then_some(StripeExpandableField::LatestCharge);
+
         Ok(Self {
             amount: item.request.amount, //hopefully we don't loose some cents here
             currency: item.request.currency.to_string(), //we need to copy the value and not transfer ownership
@@ -268,6 +479,8 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentIntentRequest {
             capture_method: StripeCaptureMethod::from(item.request.capture_method),
             payment_data,
             mandate,
+            three_ds_authentication: item.request.authentication_data.clone().map(Into::into),
+            expand,
         })
     }
 }
@@ -352,6 +565,50 @@ pub struct PaymentIntentResponse {
     pub metadata: StripeMetadata,
     pub next_action: Option<StripeNextActionResponse>,
     pub payment_method_options: Option<StripePaymentMethodOptions>,
+    pub latest_charge: Option<StripeChargeEnum>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum StripeChargeEnum {
+    ChargeId(String),
+    ChargeObject(StripeCharge),
+}
+
+impl StripeChargeEnum {
+    /// The mandate with which the bank account was debited, for bank debit payments
+    fn get_bank_debit_mandate(self) -> Option<String> {
+        match self {
+            Self::ChargeId(_) => None,
+            Self::ChargeObject(charge) => charge.payment_method_details.and_then(|details| {
+                details
+                    .sepa_debit
+                    .and_then(|sepa_debit| sepa_debit.mandate)
+                    .or_else(|| {
+                        details
+                            .us_bank_account
+                            .and_then(|us_bank_account| us_bank_account.mandate)
+                    })
+            }),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize)]
+pub struct StripeCharge {
+    pub id: String,
+    pub payment_method_details: Option<StripePaymentMethodDetails>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize)]
+pub struct StripePaymentMethodDetails {
+    pub sepa_debit: Option<StripeBankDebitDetails>,
+    pub us_bank_account: Option<StripeBankDebitDetails>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize)]
+pub struct StripeBankDebitDetails {
+    pub mandate: Option<String>,
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize)]
@@ -366,6 +623,7 @@ pub struct SetupIntentResponse {
     pub metadata: StripeMetadata,
     pub next_action: Option<StripeNextActionResponse>,
     pub payment_method_options: Option<StripePaymentMethodOptions>,
+    pub mandate: Option<String>,
 }
 
 impl<F, T>
@@ -376,33 +634,37 @@ impl<F, T>
     fn try_from(
         item: types::ResponseRouterData<F, PaymentIntentResponse, T, types::PaymentsResponseData>,
     ) -> Result<Self, Self::Error> {
-        let redirection_data = item.response.next_action.as_ref().map(
-            |StripeNextActionResponse::RedirectToUrl(response)| {
-                let mut base_url = response.url.clone();
-                base_url.set_query(None);
-                services::RedirectForm {
-                    url: base_url.to_string(),
-                    method: services::Method::Get,
-                    form_fields: std::collections::HashMap::from_iter(
-                        response
-                            .url
-                            .query_pairs()
-                            .map(|(k, v)| (k.to_string(), v.to_string())),
-                    ),
-                }
-            },
-        );
-
-        let mandate_reference =
-            item.response
-                .payment_method_options
-                .and_then(|payment_method_options| match payment_method_options {
-                    StripePaymentMethodOptions::Card {
-                        mandate_options, ..
-                    } => mandate_options.map(|mandate_options| mandate_options.reference),
-                    StripePaymentMethodOptions::Klarna {} => None,
-                    StripePaymentMethodOptions::Affirm {} => None,
-                });
+        let redirection_data = item.response.next_action.as_ref().map(|next_action| {
+            let url = next_action.get_url();
+            let mut base_url = url.clone();
+            base_url.set_query(None);
+            services::RedirectForm {
+                url: base_url.to_string(),
+                method: services::Method::Get,
+                form_fields: std::collections::HashMap::from_iter(
+                    url.query_pairs()
+                        .map(|(k, v)| (k.to_string(), v.to_string())),
+                ),
+            }
+        });
+
+        let mandate_reference = item
+            .response
+            .payment_method_options
+            .and_then(|payment_method_options| match payment_method_options {
+                StripePaymentMethodOptions::Card {
+                    mandate_options, ..
+                } => mandate_options.map(|mandate_options| mandate_options.reference),
+                StripePaymentMethodOptions::Klarna {} => None,
+                StripePaymentMethodOptions::Affirm {} => None,
+                StripePaymentMethodOptions::UsBankAccount {} => None,
+                StripePaymentMethodOptions::SepaDebit {} => None,
+            })
+            .or_else(|| {
+                item.response
+                    .latest_charge
+                    .and_then(StripeChargeEnum::get_bank_debit_mandate)
+            });
 
         Ok(Self {
             status: enums::AttemptStatus::from(item.response.status),
@@ -431,33 +693,33 @@ impl<F, T>
     fn try_from(
         item: types::ResponseRouterData<F, SetupIntentResponse, T, types::PaymentsResponseData>,
     ) -> Result<Self, Self::Error> {
-        let redirection_data = item.response.next_action.as_ref().map(
-            |StripeNextActionResponse::RedirectToUrl(response)| {
-                let mut base_url = response.url.clone();
-                base_url.set_query(None);
-                services::RedirectForm {
-                    url: base_url.to_string(),
-                    method: services::Method::Get,
-                    form_fields: std::collections::HashMap::from_iter(
-                        response
-                            .url
-                            .query_pairs()
-                            .map(|(k, v)| (k.to_string(), v.to_string())),
-                    ),
-                }
-            },
-        );
-
-        let mandate_reference =
-            item.response
-                .payment_method_options
-                .and_then(|payment_method_options| match payment_method_options {
-                    StripePaymentMethodOptions::Card {
-                        mandate_options, ..
-                    } => mandate_options.map(|mandate_option| mandate_option.reference),
-                    StripePaymentMethodOptions::Klarna {} => None,
-                    StripePaymentMethodOptions::Affirm {} => None,
-                });
+        let redirection_data = item.response.next_action.as_ref().map(|next_action| {
+            let url = next_action.get_url();
+            let mut base_url = url.clone();
+            base_url.set_query(None);
+            services::RedirectForm {
+                url: base_url.to_string(),
+                method: services::Method::Get,
+                form_fields: std::collections::HashMap::from_iter(
+                    url.query_pairs()
+                        .map(|(k, v)| (k.to_string(), v.to_string())),
+                ),
+            }
+        });
+
+        let mandate_reference = item
+            .response
+            .payment_method_options
+            .and_then(|payment_method_options| match payment_method_options {
+                StripePaymentMethodOptions::Card {
+                    mandate_options, ..
+                } => mandate_options.map(|mandate_option| mandate_option.reference),
+                StripePaymentMethodOptions::Klarna {} => None,
+                StripePaymentMethodOptions::Affirm {} => None,
+                StripePaymentMethodOptions::UsBankAccount {} => None,
+                StripePaymentMethodOptions::SepaDebit {} => None,
+            })
+            .or(item.response.mandate);
 
         Ok(Self {
             status: enums::AttemptStatus::from(item.response.status),
@@ -477,6 +739,19 @@ impl<F, T>
 #[serde(rename_all = "snake_case", remote = "Self")]
 pub enum StripeNextActionResponse {
     RedirectToUrl(StripeRedirectToUrlResponse),
+    VerifyWithMicrodeposits(StripeVerifyWithMicroDepositsResponse),
+}
+
+impl StripeNextActionResponse {
+    /// The URL to which the customer is redirected to complete the action
+    fn get_url(&self) -> &Url {
+        match self {
+            Self::RedirectToUrl(redirect_to_url) => &redirect_to_url.url,
+            Self::VerifyWithMicrodeposits(verify_with_microdeposits) => {
+                &verify_with_microdeposits.hosted_verification_url
+            }
+        }
+    }
 }
 
 // This impl is required because Stripe's response is of the below format, which is externally
@@ -505,6 +780,13 @@ pub struct StripeRedirectToUrlResponse {
     url: Url,
 }
 
+/// The bank account of an ACH debit is verified by the customer entering the amounts of the
+/// micro-deposits made by Stripe, on a page hosted by Stripe.
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct StripeVerifyWithMicroDepositsResponse {
+    hosted_verification_url: Url,
+}
+
 // REFUND :
 // Type definition for Stripe RefundRequest
 
@@ -614,6 +896,101 @@ pub struct ErrorDetails {
     pub param: Option<String>,
 }
 
+/// The token of a Google Pay payment, when the merchant has configured Stripe as the gateway
+/// for tokenization. The token already contains the Stripe token which the payment is made with.
+#[derive(Debug, Deserialize)]
+pub struct StripeGooglePayToken {
+    pub id: String,
+}
+
+impl TryFrom<&api_models::payments::WalletData> for StripeWalletTokenData {
+    type Error = error_stack::Report<errors::ParsingError>;
+    fn try_from(wallet_data: &api_models::payments::WalletData) -> Result<Self, Self::Error> {
+        let google_pay_token: StripeGooglePayToken = wallet_data
+            .token
+            .clone()
+            .ok_or(errors::ParsingError)
+            .into_report()
+            .attach_printable("Missing the token for the Google Pay payment")?
+            .parse_struct("StripeGooglePayToken")
+            .attach_printable(
+                "The Google Pay token was not tokenized with Stripe as the gateway",
+            )?;
+
+        Ok(Self {
+            payment_method_types: StripePaymentMethodType::Card,
+            payment_method_data_type: StripePaymentMethodType::Card,
+            token: google_pay_token.id,
+        })
+    }
+}
+
+/// Exchanges an Apple Pay token for a Stripe token, with which the payment is made.
+#[derive(Debug, Eq, PartialEq, Serialize)]
+pub struct StripeApplePayTokenRequest {
+    /// The payment data of the Apple Pay token, which is decrypted by Stripe
+    pub pk_token: Secret<String>,
+}
+
+impl TryFrom<&types::PaymentsAuthorizeSessionTokenRouterData> for StripeApplePayTokenRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &types::PaymentsAuthorizeSessionTokenRouterData,
+    ) -> Result<Self, Self::Error> {
+        let token = match &item.request.payment_method_data {
+            api::PaymentMethod::Wallet(wallet_data) => wallet_data.token.clone(),
+            _ => None,
+        }
+        .ok_or_else(|| errors::ConnectorError::MissingRequiredField {
+            field_name: "wallet_data.token".to_string(),
+        })
+        .into_report()?;
+
+        // The token holds the base64 encoded payment data, as it is sent to the other connectors
+        let payment_data = consts::BASE64_ENGINE
+            .decode(token)
+            .into_report()
+            .change_context(errors::ConnectorError::RequestEncodingFailed)
+            .attach_printable("Failed to decode the Apple Pay token")?;
+        let pk_token = String::from_utf8(payment_data)
+            .into_report()
+            .change_context(errors::ConnectorError::RequestEncodingFailed)
+            .attach_printable("The Apple Pay token is not valid UTF-8")?;
+
+        Ok(Self {
+            pk_token: Secret::new(pk_token),
+        })
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Deserialize)]
+pub struct StripeTokenResponse {
+    pub id: String,
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<F, StripeTokenResponse, T, types::AuthorizeSessionTokenResponse>,
+    > for types::RouterData<F, T, types::AuthorizeSessionTokenResponse>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            StripeTokenResponse,
+            T,
+            types::AuthorizeSessionTokenResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::AuthorizeSessionTokenResponse {
+                session_token: item.response.id,
+            }),
+            ..item.data
+        })
+    }
+}
+
 #[derive(Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
 pub struct ErrorResponse {
     pub error: ErrorDetails,
@@ -693,6 +1070,8 @@ pub enum StripePaymentMethodOptions {
     },
     Klarna {},
     Affirm {},
+    UsBankAccount {},
+    SepaDebit {},
 }
 // #[derive(Deserialize, Debug, Clone, Eq, PartialEq)]
 // pub struct Card
@@ -779,11 +1158,117 @@ pub struct StripeWebhookObjectEventType {
     pub event_type: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct StripeWebhookObjectEventId {
+    pub id: String,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct StripeWebhookObjectId {
     pub data: StripeWebhookDataId,
 }
 
+/// Returns whether the event is sent by Stripe for a dispute raised against a payment.
+pub fn is_dispute_event(event_type: &str) -> bool {
+    event_type.starts_with("charge.dispute.")
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeDisputeWebhookBody {
+    pub data: StripeDisputeWebhookData,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeDisputeWebhookData {
+    pub object: StripeDispute,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeDispute {
+    pub id: String,
+    pub amount: i64,
+    pub currency: String,
+    pub status: StripeDisputeStatus,
+    pub reason: Option<String>,
+    pub payment_intent: Option<String>,
+    pub evidence_details: Option<StripeDisputeEvidenceDetails>,
+    /// The balance transactions of the funds withdrawn and reinstated for the dispute, along with
+    /// the dispute fee and its reversal
+    #[serde(default)]
+    pub balance_transactions: Vec<StripeBalanceTransaction>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum StripeDisputeStatus {
+    WarningNeedsResponse,
+    WarningUnderReview,
+    WarningClosed,
+    NeedsResponse,
+    UnderReview,
+    Won,
+    Lost,
+}
+
+impl From<StripeDisputeStatus> for api::IncomingWebhookEvent {
+    fn from(status: StripeDisputeStatus) -> Self {
+        match status {
+            StripeDisputeStatus::WarningNeedsResponse | StripeDisputeStatus::NeedsResponse => {
+                Self::DisputeOpened
+            }
+            StripeDisputeStatus::WarningUnderReview | StripeDisputeStatus::UnderReview => {
+                Self::DisputeChallenged
+            }
+            // An inquiry which is closed without turning into a chargeback costs the merchant
+            // nothing, as a won dispute does
+            StripeDisputeStatus::WarningClosed | StripeDisputeStatus::Won => Self::DisputeWon,
+            StripeDisputeStatus::Lost => Self::DisputeLost,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeDisputeEvidenceDetails {
+    /// The time by which evidence must be submitted, as a Unix timestamp
+    pub due_by: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StripeBalanceTransaction {
+    pub id: String,
+    /// The fee charged for the transaction, negative if a fee is refunded by it
+    pub fee: i64,
+    pub currency: String,
+}
+
+impl From<StripeDispute> for api::disputes::DisputePayload {
+    fn from(dispute: StripeDispute) -> Self {
+        Self {
+            amount: dispute.amount,
+            currency: dispute.currency.to_uppercase(),
+            connector_status: dispute.status.to_string(),
+            connector_dispute_id: dispute.id,
+            connector_reason: dispute.reason,
+            connector_reason_code: None,
+            challenge_required_by: dispute
+                .evidence_details
+                .and_then(|details| details.due_by)
+                .and_then(|due_by| time::OffsetDateTime::from_unix_timestamp(due_by).ok())
+                .map(|due_by| time::PrimitiveDateTime::new(due_by.date(), due_by.time())),
+            fees: dispute
+                .balance_transactions
+                .into_iter()
+                .map(|transaction| api::disputes::DisputeFee {
+                    connector_reference: transaction.id,
+                    amount: transaction.fee,
+                    currency: transaction.currency.to_uppercase(),
+                })
+                .collect(),
+        }
+    }
+}
+
 impl TryFrom<(api::PaymentMethod, enums::AuthenticationType)> for StripePaymentMethodData {
     type Error = error_stack::Report<errors::ParsingError>;
     fn try_from(
@@ -826,8 +1311,26 @@ impl TryFrom<(api::PaymentMethod, enums::AuthenticationType)> for StripePaymentM
                         .change_context(errors::ParsingError),
                 )?,
             },
+            api::PaymentMethod::Wallet(
+                ref wallet_data @ api_models::payments::WalletData {
+                    issuer_name: api_models::enums::WalletIssuer::GooglePay,
+                    ..
+                },
+            ) => Ok(Self::GooglePay(StripeWalletTokenData::try_from(
+                wallet_data,
+            )?)),
             api::PaymentMethod::Wallet(_) => Ok(Self::Wallet),
             api::PaymentMethod::Paypal => Ok(Self::Paypal),
+            api::PaymentMethod::Upi(_) => Err(error_stack::report!(
+                errors::ApiErrorResponse::NotImplemented
+            )
+            .attach_printable(
+                "Stripe does not support payment through provided payment method".to_string(),
+            )
+            .change_context(errors::ParsingError)),
+            api::PaymentMethod::BankDebit(bank_debit_data) => {
+                Ok(Self::from((bank_debit_data, None)))
+            }
         }
     }
 }
@@ -24,6 +24,7 @@ pub trait PaymentsRequestData {
     fn get_billing_country(&self) -> Result<String, Error>;
     fn get_billing_phone(&self) -> Result<&api::PhoneDetails, Error>;
     fn get_card(&self) -> Result<api::CCard, Error>;
+    fn is_merchant_initiated(&self) -> bool;
 }
 
 impl PaymentsRequestData for types::PaymentsAuthorizeRouterData {
@@ -62,6 +63,12 @@ impl PaymentsRequestData for types::PaymentsAuthorizeRouterData {
             .as_ref()
             .ok_or_else(missing_field_err("billing"))
     }
+
+    /// Whether the payment is charged against a stored mandate, without the customer being
+    /// present.
+    fn is_merchant_initiated(&self) -> bool {
+        self.request.off_session == Some(true) && self.request.mandate_id.is_some()
+    }
 }
 
 pub trait CardData {
@@ -16,6 +16,7 @@ use crate::{
         errors::{self, CustomResult},
         payments,
     },
+    db::StorageInterface,
     headers, logger,
     services::{self, ConnectorIntegration},
     types::{
@@ -23,7 +24,7 @@ use crate::{
         api::{self, ConnectorCommon, ConnectorCommonExt},
         ErrorResponse, Response,
     },
-    utils::{self, BytesExt},
+    utils::{self, crypto, ByteSliceExt, BytesExt},
 };
 
 #[derive(Debug, Clone)]
@@ -86,6 +87,103 @@ impl ConnectorCommon for Worldpay {
     }
 }
 
+impl api::ConnectorAccessToken for Worldpay {}
+
+impl
+    services::ConnectorIntegration<
+        api::AccessTokenAuth,
+        types::AccessTokenRequestData,
+        types::AccessToken,
+    > for Worldpay
+{
+}
+
+impl api::ConnectorVerifyWebhookSource for Worldpay {}
+
+impl
+    services::ConnectorIntegration<
+        api::VerifyWebhookSource,
+        types::VerifyWebhookSourceRequestData,
+        types::VerifyWebhookSourceResponseData,
+    > for Worldpay
+{
+}
+
+impl api::Payout for Worldpay {}
+impl api::PayoutCreate for Worldpay {}
+impl api::PayoutFulfill for Worldpay {}
+impl api::PayoutCancel for Worldpay {}
+
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Worldpay
+{
+}
+
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Worldpay
+{
+}
+
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Worldpay
+{
+}
+
+impl api::FraudCheck for Worldpay {}
+impl api::FraudCheckPreAuth for Worldpay {}
+impl api::FraudCheckPostAuth for Worldpay {}
+
+impl
+    services::ConnectorIntegration<
+        api::FraudCheckPre,
+        types::FraudCheckRequestData,
+        types::FraudCheckResponseData,
+    > for Worldpay
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::FraudCheckPost,
+        types::FraudCheckRequestData,
+        types::FraudCheckResponseData,
+    > for Worldpay
+{
+}
+
+impl api::ExternalAuthentication for Worldpay {}
+
+impl
+    services::ConnectorIntegration<
+        api::ThreeDsAuthentication,
+        types::ThreeDsAuthenticationRequestData,
+        types::ThreeDsAuthenticationData,
+    > for Worldpay
+{
+}
+
+impl api::Dispute for Worldpay {}
+impl api::SubmitEvidence for Worldpay {}
+impl api::DefendDispute for Worldpay {}
+
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Worldpay
+{
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Defend,
+        types::DefendDisputeRequestData,
+        types::DefendDisputeResponse,
+    > for Worldpay
+{
+}
+
 impl api::Payment for Worldpay {}
 
 impl api::PreVerify for Worldpay {}
@@ -338,6 +436,17 @@ impl ConnectorIntegration<api::Capture, types::PaymentsCaptureData, types::Payme
     }
 }
 
+impl api::PaymentAuthorizeSessionToken for Worldpay {}
+
+impl
+    ConnectorIntegration<
+        api::AuthorizeSessionToken,
+        types::AuthorizeSessionTokenData,
+        types::AuthorizeSessionTokenResponse,
+    > for Worldpay
+{
+}
+
 impl api::PaymentSession for Worldpay {}
 
 impl ConnectorIntegration<api::Session, types::PaymentsSessionData, types::PaymentsResponseData>
@@ -584,25 +693,91 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
 
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Worldpay {
-    fn get_webhook_object_reference_id(
+    fn get_webhook_source_verification_algorithm(
+        &self,
+        _headers: &actix_web::http::header::HeaderMap,
+        _body: &[u8],
+    ) -> CustomResult<Box<dyn crypto::VerifySignature + Send>, errors::ConnectorError> {
+        Ok(Box::new(crypto::HmacSha256))
+    }
+
+    /// The signature header is of the form `<key_id>/SHA256/<hex encoded signature>`.
+    fn get_webhook_source_verification_signature(
         &self,
+        headers: &actix_web::http::header::HeaderMap,
         _body: &[u8],
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let signature = headers
+            .get(headers::WORLDPAY_EVENT_SIGNATURE)
+            .and_then(|header_value| header_value.to_str().ok())
+            .and_then(|header_value| header_value.rsplit('/').next())
+            .ok_or(errors::ConnectorError::WebhookSignatureNotFound)
+            .into_report()?;
+
+        hex::decode(signature)
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookSignatureNotFound)
+    }
+
+    fn get_webhook_source_verification_message(
+        &self,
+        _headers: &actix_web::http::header::HeaderMap,
+        body: &[u8],
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        Ok(body.to_vec())
+    }
+
+    async fn get_webhook_source_verification_merchant_secret(
+        &self,
+        db: &dyn StorageInterface,
+        merchant_id: &str,
+    ) -> CustomResult<api::WebhookSourceVerificationSecrets, errors::ConnectorError> {
+        api::get_webhook_source_verification_secrets(db, self.id(), merchant_id).await
+    }
+
+    fn get_webhook_object_reference_id(
+        &self,
+        body: &[u8],
     ) -> CustomResult<String, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        let body: WorldpayWebhookEventBody = body
+            .parse_struct("WorldpayWebhookEventBody")
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+
+        Ok(body.event_details.transaction_reference)
+    }
+
+    /// Worldpay refers to payments by the transaction reference sent while authorizing them.
+    fn get_webhook_payment_id_type(&self, object_reference_id: String) -> api::PaymentIdType {
+        api::PaymentIdType::PaymentAttemptId(object_reference_id)
     }
 
     fn get_webhook_event_type(
         &self,
-        _body: &[u8],
+        body: &[u8],
     ) -> CustomResult<api::IncomingWebhookEvent, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        let body: WorldpayWebhookEventBody = body
+            .parse_struct("WorldpayWebhookEventBody")
+            .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+
+        match body.event_details.event_type {
+            EventType::SentForSettlement | EventType::Settled => {
+                Ok(api::IncomingWebhookEvent::PaymentIntentSuccess)
+            }
+            _ => Err(errors::ConnectorError::WebhookEventTypeNotFound).into_report(),
+        }
     }
 
     fn get_webhook_resource_object(
         &self,
-        _body: &[u8],
+        body: &[u8],
     ) -> CustomResult<serde_json::Value, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        let body: WorldpayWebhookEventBody = body
+            .parse_struct("WorldpayWebhookEventBody")
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+
+        serde_json::to_value(WorldpayEventResponse::from(body.event_details))
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)
     }
 }
 
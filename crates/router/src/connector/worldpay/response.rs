@@ -43,12 +43,43 @@ pub enum EventType {
     Authorized,
     Cancelled,
     Charged,
+    SentForSettlement,
+    Settled,
+    SettlementFailed,
     SentForRefund,
     RefundFailed,
     Refused,
     Refunded,
+    Expired,
     Error,
     CaptureFailed,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorldpayWebhookEventBody {
+    pub event_id: String,
+    pub event_details: WorldpayWebhookEventDetails,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorldpayWebhookEventDetails {
+    #[serde(rename = "type")]
+    pub event_type: EventType,
+    /// The transaction reference sent when authorizing the payment, which is the attempt ID.
+    pub transaction_reference: String,
+}
+
+impl From<WorldpayWebhookEventDetails> for WorldpayEventResponse {
+    fn from(item: WorldpayWebhookEventDetails) -> Self {
+        Self {
+            last_event: item.event_type,
+            links: None,
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
@@ -131,9 +131,10 @@ impl From<EventType> for enums::AttemptStatus {
     fn from(value: EventType) -> Self {
         match value {
             EventType::Authorized => Self::Authorized,
-            EventType::CaptureFailed => Self::CaptureFailed,
-            EventType::Refused => Self::Failure,
-            EventType::Charged => Self::Charged,
+            EventType::CaptureFailed | EventType::SettlementFailed => Self::CaptureFailed,
+            EventType::Refused | EventType::Expired => Self::Failure,
+            EventType::Charged | EventType::SentForSettlement | EventType::Settled => Self::Charged,
+            EventType::Cancelled => Self::Voided,
             _ => Self::Pending,
         }
     }
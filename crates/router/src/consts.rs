@@ -1,6 +1,7 @@
 // ID generation
 pub(crate) const ID_LENGTH: usize = 20;
 pub(crate) const MAX_ID_LENGTH: usize = 24;
+pub(crate) const WEBHOOK_SIGNING_SECRET_LENGTH: usize = 32;
 #[rustfmt::skip]
 pub(crate) const ALPHABETS: [char; 62] = [
     '0', '1', '2', '3', '4', '5', '6', '7', '8', '9',
@@ -15,6 +16,13 @@ pub const REQUEST_TIME_OUT: u64 = 30;
 // String literals
 pub(crate) const NO_ERROR_MESSAGE: &str = "No error message";
 pub(crate) const NO_ERROR_CODE: &str = "No error code";
+/// Error code of payments declined because their card, email or IP address is blocklisted
+pub(crate) const BLOCKLISTED_PAYMENT_ERROR_CODE: &str = "blocklisted";
+/// Error code of payments declined because too many payments were attempted with the same card,
+/// by the same customer or from the same IP address
+pub(crate) const VELOCITY_LIMIT_EXCEEDED_ERROR_CODE: &str = "velocity_limit_exceeded";
+pub(crate) const IRRELEVANT_PAYMENT_ID_IN_SOURCE_VERIFICATION_FLOW: &str =
+    "irrelevant_payment_id_in_source_verification_flow";
 
 // General purpose base64 engine
 pub(crate) const BASE64_ENGINE: base64::engine::GeneralPurpose =
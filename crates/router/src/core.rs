@@ -1,9 +1,30 @@
 pub mod admin;
+#[cfg(feature = "olap")]
+pub mod analytics;
+pub mod api_keys;
+pub mod authentication;
+pub mod blocklist;
 pub mod customers;
+#[cfg(feature = "olap")]
+pub mod disputes;
+#[cfg(feature = "dummy_connector")]
+pub mod dummy_connector;
 pub mod errors;
+#[cfg(feature = "olap")]
+pub mod events;
+pub mod exports;
+pub mod forex;
+pub mod fraud_check;
+pub mod health_check;
 pub mod mandate;
+pub mod ops;
+pub mod payment_links;
 pub mod payment_methods;
 pub mod payments;
+pub mod payouts;
+pub mod recon;
 pub mod refunds;
+pub mod surcharge;
 pub mod utils;
+pub mod velocity;
 pub mod webhooks;
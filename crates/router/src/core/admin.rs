@@ -1,13 +1,22 @@
 use common_utils::ext_traits::ValueExt;
-use error_stack::{report, FutureExt, ResultExt};
+use error_stack::{report, FutureExt, IntoReport, ResultExt};
+use masking::{PeekInterface, StrongSecret};
 use uuid::Uuid;
 
 use crate::{
-    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    consts,
+    core::{
+        api_keys,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        utils as core_utils,
+        webhooks::utils as webhooks_utils,
+    },
     db::StorageInterface,
     env::{self, Env},
+    logger,
     pii::Secret,
-    services::api as service_api,
+    routes::AppState,
+    services::{api as service_api, connector_credentials::ConnectorCredentials},
     types::{
         self, api,
         storage::{self, MerchantAccount},
@@ -26,14 +35,82 @@ pub fn create_merchant_api_key() -> String {
     }
 }
 
+#[inline]
+fn create_webhook_signing_secret() -> StrongSecret<String> {
+    utils::generate_id(consts::WEBHOOK_SIGNING_SECRET_LENGTH, "whsec").into()
+}
+
+fn validate_routing_algorithm(routing_algorithm: &serde_json::Value) -> RouterResult<()> {
+    let routing_algorithm: api::RoutingAlgorithm = routing_algorithm
+        .clone()
+        .parse_value("RoutingAlgorithm")
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "routing_algorithm",
+        })
+        .attach_printable("Invalid routing algorithm given")?;
+
+    match routing_algorithm {
+        api::RoutingAlgorithm::Priority(connectors) if connectors.is_empty() => {
+            Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "routing_algorithm",
+            })
+            .attach_printable("Priority routing requires at least one connector"))
+        }
+        api::RoutingAlgorithm::VolumeSplit(splits)
+            if splits
+                .iter()
+                .map(|split| u16::from(split.split))
+                .sum::<u16>()
+                != 100 =>
+        {
+            Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "routing_algorithm",
+            })
+            .attach_printable("Volume split percentages must add up to 100"))
+        }
+        _ => Ok(()),
+    }
+}
+
+fn encode_surcharge_config(
+    surcharge_config: &api::SurchargeConfig,
+) -> RouterResult<serde_json::Value> {
+    let is_valid_percentage =
+        |percentage: f64| percentage.is_finite() && (0.0..=100.0).contains(&percentage);
+    let is_valid_rule = |rule: &api::SurchargeRule| match rule.surcharge {
+        api::Surcharge::Fixed(amount) => amount >= 0,
+        api::Surcharge::Percentage(percentage) => is_valid_percentage(percentage),
+    };
+
+    utils::when(
+        !surcharge_config.rules.iter().all(is_valid_rule)
+            || !surcharge_config
+                .tax_on_surcharge_percentage
+                .map_or(true, is_valid_percentage),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "surcharge_config",
+            })
+            .attach_printable(
+                "Surcharges must not be negative and percentages must be at most 100",
+            ))
+        },
+    )?;
+
+    utils::Encode::<api::SurchargeConfig>::encode_to_value(surcharge_config).change_context(
+        errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "surcharge_config",
+        },
+    )
+}
+
 pub async fn create_merchant_account(
-    db: &dyn StorageInterface,
+    state: &AppState,
     req: api::CreateMerchantAccount,
 ) -> RouterResponse<api::MerchantAccountResponse> {
+    let db = &*state.store;
     let publishable_key = Some(format!("pk_{}", create_merchant_api_key()));
 
-    let api_key = Some(create_merchant_api_key().into());
-
     let merchant_details = Some(
         utils::Encode::<api::MerchantDetails>::encode_to_value(&req.merchant_details)
             .change_context(errors::ApiErrorResponse::InvalidDataValue {
@@ -49,19 +126,30 @@ pub async fn create_merchant_account(
     );
 
     if let Some(ref routing_algorithm) = req.routing_algorithm {
-        let _: api::RoutingAlgorithm = routing_algorithm
-            .clone()
-            .parse_value("RoutingAlgorithm")
-            .change_context(errors::ApiErrorResponse::InvalidDataValue {
-                field_name: "routing_algorithm",
-            })
-            .attach_printable("Invalid routing algorithm given")?;
+        validate_routing_algorithm(routing_algorithm)?;
     }
 
+    let surcharge_config = req
+        .surcharge_config
+        .as_ref()
+        .map(encode_surcharge_config)
+        .transpose()?;
+
+    let outgoing_webhook_custom_headers = req
+        .outgoing_webhook_custom_headers
+        .as_ref()
+        .map(|custom_headers| {
+            webhooks_utils::encrypt_outgoing_webhook_custom_headers(
+                &state.conf.secrets.master_enc_key,
+                custom_headers,
+            )
+        })
+        .transpose()?;
+
     let merchant_account = storage::MerchantAccountNew {
         merchant_id: req.merchant_id,
         merchant_name: req.merchant_name,
-        api_key,
+        api_key: None,
         merchant_details,
         return_url: req.return_url,
         webhook_details,
@@ -79,6 +167,9 @@ pub async fn create_merchant_account(
         publishable_key,
         locker_id: req.locker_id,
         metadata: req.metadata,
+        webhook_signing_secret: Some(create_webhook_signing_secret()),
+        outgoing_webhook_custom_headers,
+        surcharge_config,
     };
 
     let merchant_account = db
@@ -88,9 +179,38 @@ pub async fn create_merchant_account(
             error.to_duplicate_response(errors::ApiErrorResponse::DuplicateMerchantAccount)
         })?;
 
-    Ok(service_api::ApplicationResponse::Json(
-        merchant_account.foreign_into(),
-    ))
+    // The merchant account is removed if its API key can't be created, as the merchant would
+    // otherwise be left without a way to authenticate and unable to create the account again
+    let api_key = match api_keys::insert_api_key(
+        db,
+        merchant_account.merchant_id.clone(),
+        "Default API key".to_string(),
+        None,
+        None,
+    )
+    .await
+    {
+        Ok((_, api_key)) => api_key,
+        Err(error) => {
+            if let Err(delete_error) = db
+                .delete_merchant_account_by_merchant_id(&merchant_account.merchant_id)
+                .await
+            {
+                logger::error!(
+                    ?delete_error,
+                    merchant_id = %merchant_account.merchant_id,
+                    "Failed to delete the merchant account whose API key could not be created"
+                );
+            }
+            return Err(error);
+        }
+    };
+
+    // The plaintext API key is returned only once, as only its hash is stored
+    let mut response: api::MerchantAccountResponse = merchant_account.foreign_into();
+    response.api_key = Some(api_key);
+
+    Ok(service_api::ApplicationResponse::Json(response))
 }
 
 pub async fn get_merchant_account(
@@ -110,10 +230,11 @@ pub async fn get_merchant_account(
 }
 
 pub async fn merchant_account_update(
-    db: &dyn StorageInterface,
+    state: &AppState,
     merchant_id: &String,
     req: api::CreateMerchantAccount,
 ) -> RouterResponse<api::MerchantAccountResponse> {
+    let db = &*state.store;
     let merchant_account = db
         .find_merchant_account_by_merchant_id(merchant_id)
         .await
@@ -134,13 +255,7 @@ pub async fn merchant_account_update(
     }
 
     if let Some(ref routing_algorithm) = req.routing_algorithm {
-        let _: api::RoutingAlgorithm = routing_algorithm
-            .clone()
-            .parse_value("RoutingAlgorithm")
-            .change_context(errors::ApiErrorResponse::InvalidDataValue {
-                field_name: "routing_algorithm",
-            })
-            .attach_printable("Invalid routing algorithm given")?;
+        validate_routing_algorithm(routing_algorithm)?;
     }
 
     let updated_merchant_account = storage::MerchantAccountUpdate::Update {
@@ -181,6 +296,21 @@ pub async fn merchant_account_update(
         merchant_id: merchant_account.merchant_id.to_owned(),
         api_key: None,
         publishable_key: None,
+        outgoing_webhook_custom_headers: req
+            .outgoing_webhook_custom_headers
+            .as_ref()
+            .map(|custom_headers| {
+                webhooks_utils::encrypt_outgoing_webhook_custom_headers(
+                    &state.conf.secrets.master_enc_key,
+                    custom_headers,
+                )
+            })
+            .transpose()?,
+        surcharge_config: req
+            .surcharge_config
+            .as_ref()
+            .map(encode_surcharge_config)
+            .transpose()?,
     };
 
     let response = db
@@ -212,6 +342,156 @@ pub async fn merchant_account_delete(
     Ok(service_api::ApplicationResponse::Json(response))
 }
 
+pub async fn rotate_webhook_signing_secret(
+    db: &dyn StorageInterface,
+    merchant_id: String,
+) -> RouterResponse<api::WebhookSigningSecretResponse> {
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&merchant_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        })?;
+
+    let webhook_signing_secret = create_webhook_signing_secret();
+    db.update_merchant(
+        merchant_account,
+        storage::MerchantAccountUpdate::WebhookSigningSecretUpdate {
+            webhook_signing_secret: webhook_signing_secret.clone(),
+        },
+    )
+    .await
+    .map_err(|error| {
+        error.to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+    })?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        api::WebhookSigningSecretResponse {
+            merchant_id,
+            webhook_signing_secret,
+        },
+    ))
+}
+
+pub async fn retrieve_merchant_webhook_config(
+    db: &dyn StorageInterface,
+    merchant_id: String,
+) -> RouterResponse<api::MerchantWebhookConfigResponse> {
+    validate_merchant_id(db, &merchant_id).await?;
+
+    let response = match db
+        .find_merchant_webhook_config_by_merchant_id(&merchant_id)
+        .await
+    {
+        Ok(webhook_config) => webhook_config.foreign_into(),
+        Err(error) if error.current_context().is_db_not_found() => {
+            api::MerchantWebhookConfigResponse {
+                merchant_id,
+                enabled: true,
+                webhook_url: None,
+                enabled_events: None,
+            }
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the webhook config of the merchant")?,
+    };
+
+    Ok(service_api::ApplicationResponse::Json(response))
+}
+
+pub async fn update_merchant_webhook_config(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    req: api::MerchantWebhookConfigRequest,
+) -> RouterResponse<api::MerchantWebhookConfigResponse> {
+    validate_merchant_id(db, merchant_id).await?;
+
+    if let Some(webhook_url) = &req.webhook_url {
+        url::Url::parse(webhook_url).into_report().change_context(
+            errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "webhook_url",
+            },
+        )?;
+    }
+
+    let enabled = req.enabled.unwrap_or(true);
+    let enabled_events = req.enabled_events.map(|enabled_events| {
+        enabled_events
+            .into_iter()
+            .map(ForeignInto::foreign_into)
+            .collect::<Vec<storage::enums::EventType>>()
+    });
+
+    let webhook_config = match db
+        .find_merchant_webhook_config_by_merchant_id(merchant_id)
+        .await
+    {
+        Ok(webhook_config) => db
+            .update_merchant_webhook_config(
+                webhook_config,
+                storage::MerchantWebhookConfigUpdate::Update {
+                    enabled,
+                    webhook_url: req.webhook_url,
+                    enabled_events,
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the webhook config of the merchant")?,
+        Err(error) if error.current_context().is_db_not_found() => db
+            .insert_merchant_webhook_config(storage::MerchantWebhookConfigNew {
+                merchant_id: merchant_id.to_string(),
+                enabled,
+                webhook_url: req.webhook_url,
+                enabled_events,
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert the webhook config of the merchant")?,
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the webhook config of the merchant")?,
+    };
+
+    Ok(service_api::ApplicationResponse::Json(
+        webhook_config.foreign_into(),
+    ))
+}
+
+pub async fn retrieve_payment_audit_log(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    payment_id: String,
+) -> RouterResponse<api::PaymentAuditLogResponse> {
+    let merchant_account = validate_merchant_id(db, merchant_id).await?;
+    db.find_payment_intent_by_payment_id_merchant_id(
+        &payment_id,
+        merchant_id,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    let mut audit_logs = db
+        .find_payment_audit_logs_by_merchant_id_payment_id(merchant_id, &payment_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the audit log of the payment")?;
+    audit_logs.sort_by_key(|audit_log| audit_log.id);
+
+    Ok(service_api::ApplicationResponse::Json(
+        api::PaymentAuditLogResponse {
+            payment_id,
+            merchant_id: merchant_account.merchant_id,
+            entries: audit_logs
+                .into_iter()
+                .map(ForeignInto::foreign_into)
+                .collect(),
+        },
+    ))
+}
+
 async fn get_parent_merchant(
     db: &dyn StorageInterface,
     sub_merchants_enabled: Option<bool>,
@@ -252,6 +532,7 @@ async fn validate_merchant_id<S: Into<String>>(
 
 pub async fn create_payment_connector(
     store: &dyn StorageInterface,
+    connector_credentials: &ConnectorCredentials,
     req: api::PaymentConnectorCreate,
     merchant_id: &String,
 ) -> RouterResponse<api::PaymentConnectorCreate> {
@@ -289,16 +570,28 @@ pub async fn create_payment_connector(
             expected_format: "auth_type and api_key".to_string(),
         })?;
 
+    let connector_label = core_utils::get_connector_label(
+        &req.connector_name,
+        req.business_country.as_ref(),
+        req.business_label.as_ref(),
+    );
     let merchant_connector_account = storage::MerchantConnectorAccountNew {
         merchant_id: Some(merchant_id.to_string()),
         connector_type: Some(req.connector_type.foreign_into()),
         connector_name: Some(req.connector_name),
         merchant_connector_id: None,
-        connector_account_details: req.connector_account_details,
+        connector_account_details: encrypt_connector_account_details(
+            connector_credentials,
+            req.connector_account_details,
+        )
+        .await?,
         payment_methods_enabled,
         test_mode: req.test_mode,
         disabled: req.disabled,
         metadata: req.metadata,
+        business_country: req.business_country,
+        business_label: req.business_label,
+        connector_label: Some(connector_label),
     };
 
     let mca = store
@@ -314,6 +607,7 @@ pub async fn create_payment_connector(
 
 pub async fn retrieve_payment_connector(
     store: &dyn StorageInterface,
+    connector_credentials: &ConnectorCredentials,
     merchant_id: String,
     merchant_connector_id: i32,
 ) -> RouterResponse<api::PaymentConnectorCreate> {
@@ -334,6 +628,7 @@ pub async fn retrieve_payment_connector(
             error.to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)
         })?;
 
+    let mca = decrypt_connector_account_details(connector_credentials, mca).await?;
     Ok(service_api::ApplicationResponse::Json(
         mca.foreign_try_into()?,
     ))
@@ -341,6 +636,7 @@ pub async fn retrieve_payment_connector(
 
 pub async fn list_payment_connectors(
     store: &dyn StorageInterface,
+    connector_credentials: &ConnectorCredentials,
     merchant_id: String,
 ) -> RouterResponse<Vec<api::PaymentConnectorCreate>> {
     // Validate merchant account
@@ -361,6 +657,7 @@ pub async fn list_payment_connectors(
 
     // The can be eliminated once [#79711](https://github.com/rust-lang/rust/issues/79711) is stabilized
     for mca in merchant_connector_accounts.into_iter() {
+        let mca = decrypt_connector_account_details(connector_credentials, mca).await?;
         response.push(mca.foreign_try_into()?);
     }
 
@@ -369,6 +666,7 @@ pub async fn list_payment_connectors(
 
 pub async fn update_payment_connector(
     db: &dyn StorageInterface,
+    connector_credentials: &ConnectorCredentials,
     merchant_id: &str,
     merchant_connector_id: i32,
     req: api::PaymentConnectorCreate,
@@ -404,16 +702,30 @@ pub async fn update_payment_connector(
         }
         None => Some(vec),
     };
+    let business_country = req.business_country.or(mca.business_country.clone());
+    let business_label = req.business_label.or(mca.business_label.clone());
+    let connector_label = core_utils::get_connector_label(
+        &req.connector_name,
+        business_country.as_ref(),
+        business_label.as_ref(),
+    );
     let payment_connector = storage::MerchantConnectorAccountUpdate::Update {
         merchant_id: Some(merchant_id.to_string()),
         connector_type: Some(req.connector_type.foreign_into()),
         connector_name: Some(req.connector_name),
         merchant_connector_id: Some(merchant_connector_id),
-        connector_account_details: req.connector_account_details,
+        connector_account_details: encrypt_connector_account_details(
+            connector_credentials,
+            req.connector_account_details,
+        )
+        .await?,
         payment_methods_enabled,
         test_mode: mca.test_mode,
         disabled: req.disabled.or(mca.disabled),
         metadata: req.metadata,
+        business_country,
+        business_label,
+        connector_label: Some(connector_label),
     };
 
     let updated_mca = db
@@ -426,6 +738,7 @@ pub async fn update_payment_connector(
                 merchant_connector_id
             )
         })?;
+    let updated_mca = decrypt_connector_account_details(connector_credentials, updated_mca).await?;
     let response = api::PaymentConnectorCreate {
         connector_type: updated_mca.connector_type.foreign_into(),
         connector_name: updated_mca.connector_name,
@@ -435,10 +748,40 @@ pub async fn update_payment_connector(
         disabled: updated_mca.disabled,
         payment_methods_enabled: req.payment_methods_enabled,
         metadata: updated_mca.metadata,
+        business_country: updated_mca.business_country,
+        business_label: updated_mca.business_label,
     };
     Ok(service_api::ApplicationResponse::Json(response))
 }
 
+async fn encrypt_connector_account_details(
+    connector_credentials: &ConnectorCredentials,
+    connector_account_details: Option<Secret<serde_json::Value>>,
+) -> RouterResult<Option<Secret<serde_json::Value>>> {
+    match connector_account_details {
+        Some(details) => connector_credentials
+            .encrypt(details.peek())
+            .await
+            .map(|encrypted| Some(Secret::new(encrypted)))
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encrypt the connector account details"),
+        None => Ok(None),
+    }
+}
+
+/// Decrypts the credentials of the merchant connector account, for returning them to the merchant.
+async fn decrypt_connector_account_details(
+    connector_credentials: &ConnectorCredentials,
+    mut mca: storage::MerchantConnectorAccount,
+) -> RouterResult<storage::MerchantConnectorAccount> {
+    mca.connector_account_details = connector_credentials
+        .decrypt(&mca.connector_account_details)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to decrypt the connector account details")?;
+    Ok(mca)
+}
+
 pub async fn delete_payment_connector(
     db: &dyn StorageInterface,
     merchant_id: String,
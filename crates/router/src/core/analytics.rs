@@ -0,0 +1,329 @@
+use std::{collections::HashMap, hash::Hash, str::FromStr};
+
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::errors::{self, RouterResponse},
+    db::StorageInterface,
+    services,
+    types::{
+        api::analytics,
+        storage::{self, enums},
+        transformers::ForeignInto,
+    },
+};
+
+/// The longest time range the metrics can be computed over, to bound the number of payment
+/// attempts scanned by a single request.
+const MAX_TIME_RANGE: time::Duration = time::Duration::days(90);
+
+/// The number of most frequent decline reasons returned.
+const DECLINE_REASONS_LIMIT: usize = 10;
+
+/// Attempt statuses of the payments which were authorized, including the ones which were captured
+/// or voided afterwards.
+const AUTHORIZED_ATTEMPT_STATUSES: [enums::AttemptStatus; 8] = [
+    enums::AttemptStatus::Authorized,
+    enums::AttemptStatus::CaptureInitiated,
+    enums::AttemptStatus::CaptureFailed,
+    enums::AttemptStatus::Charged,
+    enums::AttemptStatus::PartialCharged,
+    enums::AttemptStatus::VoidInitiated,
+    enums::AttemptStatus::Voided,
+    enums::AttemptStatus::VoidFailed,
+];
+
+/// Attempt statuses of the payments which were charged.
+const SUCCESSFUL_ATTEMPT_STATUSES: [enums::AttemptStatus; 2] = [
+    enums::AttemptStatus::Charged,
+    enums::AttemptStatus::PartialCharged,
+];
+
+#[instrument(skip(db))]
+pub async fn retrieve_payment_analytics(
+    db: &dyn StorageInterface,
+    merchant_account: storage::MerchantAccount,
+    req: analytics::PaymentAnalyticsRequest,
+) -> RouterResponse<analytics::PaymentAnalyticsResponse> {
+    let start_time = req.start_time;
+    let end_time = req.end_time.unwrap_or_else(common_utils::date_time::now);
+    if start_time >= end_time {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "start_time should be before end_time".to_string(),
+        })?
+    }
+    if end_time - start_time > MAX_TIME_RANGE {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "time range should not be longer than {} days",
+                MAX_TIME_RANGE.whole_days()
+            ),
+        })?
+    }
+
+    let merchant_id = &merchant_account.merchant_id;
+    let aggregates = db
+        .aggregate_payment_attempts_by_merchant_id_created_between(
+            merchant_id,
+            start_time,
+            end_time,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to aggregate the payment attempts")?;
+    let decline_reasons = db
+        .count_payment_attempt_declines_by_merchant_id_created_between(
+            merchant_id,
+            start_time,
+            end_time,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to count the declined payment attempts")?;
+    let costs = db
+        .aggregate_payment_costs_by_merchant_id_created_between(merchant_id, start_time, end_time)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to aggregate the payment costs")?;
+
+    let mut overall = AttemptCounts::default();
+    for aggregate in &aggregates {
+        overall.add(aggregate);
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        analytics::PaymentAnalyticsResponse {
+            start_time,
+            end_time,
+            overall: overall.metrics(),
+            decline_reasons: top_decline_reasons(decline_reasons),
+            by_connector: group_by(&aggregates, |aggregate| aggregate.connector.clone())
+                .into_iter()
+                .map(|(connector, counts)| analytics::ConnectorMetrics {
+                    connector,
+                    metrics: counts.metrics(),
+                })
+                .collect(),
+            by_currency: currency_metrics(&aggregates, &costs),
+            by_payment_method: group_by(&aggregates, |aggregate| aggregate.payment_method)
+                .into_iter()
+                .map(|(payment_method, counts)| analytics::PaymentMethodMetrics {
+                    payment_method: payment_method.map(ForeignInto::foreign_into),
+                    metrics: counts.metrics(),
+                })
+                .collect(),
+        },
+    ))
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct AttemptCounts {
+    total: i64,
+    authorized: i64,
+    successful: i64,
+    successful_amount: i64,
+}
+
+impl AttemptCounts {
+    fn add(&mut self, aggregate: &storage::PaymentAttemptAggregate) {
+        self.total += aggregate.count;
+        if AUTHORIZED_ATTEMPT_STATUSES.contains(&aggregate.status) {
+            self.authorized += aggregate.count;
+        }
+        if SUCCESSFUL_ATTEMPT_STATUSES.contains(&aggregate.status) {
+            self.successful += aggregate.count;
+            self.successful_amount += aggregate.amount.unwrap_or_default();
+        }
+    }
+
+    fn metrics(&self) -> analytics::PaymentMetrics {
+        analytics::PaymentMetrics {
+            total_attempts: self.total,
+            authorized_attempts: self.authorized,
+            successful_attempts: self.successful,
+            authorization_rate: percentage(self.authorized, self.total),
+            success_rate: percentage(self.successful, self.total),
+        }
+    }
+}
+
+/// Computes the metrics of each currency, including the currencies which dispute fees were charged
+/// in while no payments were made in them.
+fn currency_metrics(
+    aggregates: &[storage::PaymentAttemptAggregate],
+    costs: &[storage::PaymentCostAggregate],
+) -> Vec<analytics::CurrencyMetrics> {
+    let mut dispute_fees: HashMap<Option<enums::Currency>, i64> = HashMap::new();
+    for cost in costs {
+        if matches!(
+            cost.cost_type,
+            enums::PaymentCostType::DisputeFee | enums::PaymentCostType::DisputeFeeReversal
+        ) {
+            *dispute_fees
+                .entry(enums::Currency::from_str(&cost.currency).ok())
+                .or_default() += cost.amount.unwrap_or_default();
+        }
+    }
+
+    let mut groups = group_by(aggregates, |aggregate| aggregate.currency);
+    for currency in dispute_fees.keys() {
+        if !groups.iter().any(|(key, _)| key == currency) {
+            groups.push((*currency, AttemptCounts::default()));
+        }
+    }
+
+    groups
+        .into_iter()
+        .map(|(currency, counts)| {
+            let dispute_fee_amount = dispute_fees.get(&currency).copied().unwrap_or_default();
+            analytics::CurrencyMetrics {
+                currency: currency.map(ForeignInto::foreign_into),
+                metrics: counts.metrics(),
+                successful_amount: counts.successful_amount,
+                dispute_fee_amount,
+                net_amount: counts.successful_amount - dispute_fee_amount,
+            }
+        })
+        .collect()
+}
+
+fn percentage(count: i64, total: i64) -> Option<f64> {
+    if total == 0 {
+        return None;
+    }
+    // Safety: The counts are far below the range in which `f64` loses precision
+    #[allow(clippy::as_conversions)]
+    let percentage = count as f64 * 100.0 / total as f64;
+    Some(percentage)
+}
+
+/// Combines the aggregates which share the same key, with the most frequent keys first.
+fn group_by<K, F>(
+    aggregates: &[storage::PaymentAttemptAggregate],
+    key: F,
+) -> Vec<(K, AttemptCounts)>
+where
+    K: Eq + Hash,
+    F: Fn(&storage::PaymentAttemptAggregate) -> K,
+{
+    let mut groups: HashMap<K, AttemptCounts> = HashMap::new();
+    for aggregate in aggregates {
+        groups.entry(key(aggregate)).or_default().add(aggregate);
+    }
+
+    let mut groups: Vec<_> = groups.into_iter().collect();
+    groups.sort_by(|(_, a), (_, b)| b.total.cmp(&a.total));
+    groups
+}
+
+fn top_decline_reasons(
+    mut decline_reasons: Vec<storage::PaymentAttemptDeclineReason>,
+) -> Vec<analytics::DeclineReasonMetric> {
+    decline_reasons.sort_by(|a, b| b.count.cmp(&a.count));
+    decline_reasons
+        .into_iter()
+        .take(DECLINE_REASONS_LIMIT)
+        .map(|reason| analytics::DeclineReasonMetric {
+            error_code: reason.error_code,
+            error_message: reason.error_message,
+            count: reason.count,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn aggregate(
+        connector: &str,
+        status: enums::AttemptStatus,
+        count: i64,
+        amount: i64,
+    ) -> storage::PaymentAttemptAggregate {
+        storage::PaymentAttemptAggregate {
+            connector: Some(connector.to_string()),
+            currency: Some(enums::Currency::USD),
+            payment_method: Some(enums::PaymentMethodType::Card),
+            status,
+            count,
+            amount: Some(amount),
+        }
+    }
+
+    #[test]
+    fn test_group_by_connector() {
+        let aggregates = vec![
+            aggregate("stripe", enums::AttemptStatus::Charged, 3, 3000),
+            aggregate("stripe", enums::AttemptStatus::Failure, 1, 500),
+            aggregate("adyen", enums::AttemptStatus::Authorized, 1, 200),
+            aggregate("stripe", enums::AttemptStatus::PartialCharged, 1, 100),
+            aggregate("adyen", enums::AttemptStatus::AuthorizationFailed, 1, 200),
+        ];
+
+        let groups = group_by(&aggregates, |aggregate| aggregate.connector.clone());
+
+        assert_eq!(
+            groups,
+            vec![
+                (
+                    Some("stripe".to_string()),
+                    AttemptCounts {
+                        total: 5,
+                        authorized: 4,
+                        successful: 4,
+                        successful_amount: 3100,
+                    }
+                ),
+                (
+                    Some("adyen".to_string()),
+                    AttemptCounts {
+                        total: 2,
+                        authorized: 1,
+                        successful: 0,
+                        successful_amount: 0,
+                    }
+                ),
+            ]
+        );
+        assert_eq!(groups[0].1.metrics().success_rate, Some(80.0));
+        assert_eq!(AttemptCounts::default().metrics().success_rate, None);
+    }
+
+    #[test]
+    fn test_currency_metrics_net_of_dispute_fees() {
+        let cost = |cost_type, currency: &str, amount| storage::PaymentCostAggregate {
+            cost_type,
+            currency: currency.to_string(),
+            amount: Some(amount),
+        };
+        let aggregates = vec![aggregate("stripe", enums::AttemptStatus::Charged, 2, 2000)];
+        let costs = vec![
+            cost(enums::PaymentCostType::DisputeFee, "USD", 1500),
+            cost(enums::PaymentCostType::DisputeFeeReversal, "USD", -1000),
+            cost(enums::PaymentCostType::DisputeFee, "EUR", 1500),
+        ];
+
+        let metrics = currency_metrics(&aggregates, &costs);
+
+        let amounts: Vec<_> = metrics
+            .iter()
+            .map(|metrics| {
+                (
+                    metrics.currency,
+                    metrics.successful_amount,
+                    metrics.dispute_fee_amount,
+                    metrics.net_amount,
+                )
+            })
+            .collect();
+        assert_eq!(
+            amounts,
+            vec![
+                (Some(api_models::enums::Currency::USD), 2000, 500, 1500),
+                (Some(api_models::enums::Currency::EUR), 0, 1500, -1500),
+            ]
+        );
+    }
+}
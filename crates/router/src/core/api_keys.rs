@@ -0,0 +1,230 @@
+use common_utils::date_time;
+use error_stack::{report, IntoReport, ResultExt};
+use masking::StrongSecret;
+use ring::digest;
+use router_env::{instrument, tracing};
+
+use crate::{
+    consts,
+    core::{
+        admin,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    },
+    db::StorageInterface,
+    logger, services,
+    types::{api::api_keys, storage},
+    utils,
+};
+
+/// The number of characters of the plaintext API key which are stored along with its hash, to
+/// help the merchant identify the key.
+const API_KEY_PREFIX_LENGTH: usize = 12;
+
+/// The last usage of an API key is recorded at most once in this interval, so that authenticating
+/// a request does not write to the database every time.
+const API_KEY_LAST_USED_UPDATE_INTERVAL: time::Duration = time::Duration::minutes(1);
+
+/// Hashes an API key, so that the plaintext key is never stored. The keys are random and long
+/// enough that a fast unsalted hash is sufficient, and it allows looking up a key by its hash.
+pub fn hash_api_key(api_key: &str) -> String {
+    hex::encode(digest::digest(&digest::SHA256, api_key.as_bytes()))
+}
+
+/// Generates a new API key for the merchant and stores its hash, returning the stored API key
+/// along with the plaintext key, which cannot be obtained again afterwards.
+pub async fn insert_api_key(
+    store: &dyn StorageInterface,
+    merchant_id: String,
+    name: String,
+    description: Option<String>,
+    expires_at: Option<time::PrimitiveDateTime>,
+) -> RouterResult<(storage::ApiKey, StrongSecret<String>)> {
+    let plaintext_api_key = admin::create_merchant_api_key();
+    let api_key_new = storage::ApiKeyNew {
+        key_id: utils::generate_id(consts::ID_LENGTH, "key"),
+        merchant_id,
+        name,
+        description,
+        hashed_api_key: hash_api_key(&plaintext_api_key),
+        prefix: plaintext_api_key
+            .chars()
+            .take(API_KEY_PREFIX_LENGTH)
+            .collect(),
+        expires_at,
+    };
+    let api_key = store
+        .insert_api_key(api_key_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the API key")?;
+
+    Ok((api_key, plaintext_api_key.into()))
+}
+
+#[instrument(skip(store))]
+pub async fn create_api_key(
+    store: &dyn StorageInterface,
+    merchant_account: storage::MerchantAccount,
+    req: api_keys::CreateApiKeyRequest,
+) -> RouterResponse<api_keys::CreateApiKeyResponse> {
+    if let Some(expires_at) = req.expires_at {
+        utils::when(expires_at <= date_time::now(), || {
+            Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "expires_at",
+            })
+            .attach_printable("The expiry of an API key must be in the future"))
+        })?;
+    }
+
+    let (api_key, plaintext_api_key) = insert_api_key(
+        store,
+        merchant_account.merchant_id,
+        req.name,
+        req.description,
+        req.expires_at,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        api_keys::CreateApiKeyResponse {
+            key_id: api_key.key_id,
+            merchant_id: api_key.merchant_id,
+            name: api_key.name,
+            description: api_key.description,
+            api_key: plaintext_api_key,
+            created_at: api_key.created_at,
+            expires_at: api_key.expires_at,
+        },
+    ))
+}
+
+#[instrument(skip(store))]
+pub async fn retrieve_api_key(
+    store: &dyn StorageInterface,
+    merchant_account: storage::MerchantAccount,
+    key_id: String,
+) -> RouterResponse<api_keys::RetrieveApiKeyResponse> {
+    let api_key = store
+        .find_api_key_by_merchant_id_key_id(&merchant_account.merchant_id, &key_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::ApiKeyNotFound))?;
+
+    Ok(services::ApplicationResponse::Json(get_api_key_response(
+        api_key,
+    )))
+}
+
+#[instrument(skip(store))]
+pub async fn list_api_keys(
+    store: &dyn StorageInterface,
+    merchant_account: storage::MerchantAccount,
+) -> RouterResponse<Vec<api_keys::RetrieveApiKeyResponse>> {
+    let api_keys = store
+        .list_api_keys_by_merchant_id(&merchant_account.merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the API keys of the merchant")?;
+
+    Ok(services::ApplicationResponse::Json(
+        api_keys.into_iter().map(get_api_key_response).collect(),
+    ))
+}
+
+#[instrument(skip(store))]
+pub async fn revoke_api_key(
+    store: &dyn StorageInterface,
+    merchant_account: storage::MerchantAccount,
+    key_id: String,
+) -> RouterResponse<api_keys::RevokeApiKeyResponse> {
+    let revoked = store
+        .revoke_api_key(&merchant_account.merchant_id, &key_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::ApiKeyNotFound))?;
+
+    Ok(services::ApplicationResponse::Json(
+        api_keys::RevokeApiKeyResponse {
+            merchant_id: merchant_account.merchant_id,
+            key_id,
+            revoked,
+        },
+    ))
+}
+
+/// Finds the merchant account to which an API key belongs, given the plaintext key passed in a
+/// request. Expired keys are rejected, and the time at which the key was last used is recorded.
+#[instrument(skip_all)]
+pub async fn find_merchant_account_by_api_key(
+    store: &dyn StorageInterface,
+    api_key: &str,
+) -> RouterResult<storage::MerchantAccount> {
+    let stored_api_key = store
+        .find_api_key_by_hashed_api_key(&hash_api_key(api_key))
+        .await
+        .change_context(errors::ApiErrorResponse::Unauthorized)
+        .attach_printable("API key not found")?;
+
+    let now = date_time::now();
+    utils::when(
+        stored_api_key
+            .expires_at
+            .map_or(false, |expires_at| expires_at <= now),
+        || {
+            Err(errors::ApiErrorResponse::Unauthorized)
+                .into_report()
+                .attach_printable("API key has expired")
+        },
+    )?;
+
+    let merchant_account = store
+        .find_merchant_account_by_merchant_id(&stored_api_key.merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::Unauthorized)
+        .attach_printable("Merchant account of the API key not found")?;
+
+    let is_last_used_outdated = stored_api_key.last_used.map_or(true, |last_used| {
+        now - last_used >= API_KEY_LAST_USED_UPDATE_INTERVAL
+    });
+
+    // Failing to record the usage of the key must not fail the request being authenticated
+    if is_last_used_outdated {
+        if let Err(error) = store
+            .update_api_key(
+                stored_api_key,
+                storage::ApiKeyUpdate::LastUsedUpdate { last_used: now },
+            )
+            .await
+        {
+            logger::error!(?error, "Failed to update the last usage of the API key");
+        }
+    }
+
+    Ok(merchant_account)
+}
+
+fn get_api_key_response(api_key: storage::ApiKey) -> api_keys::RetrieveApiKeyResponse {
+    api_keys::RetrieveApiKeyResponse {
+        key_id: api_key.key_id,
+        merchant_id: api_key.merchant_id,
+        name: api_key.name,
+        description: api_key.description,
+        prefix: api_key.prefix,
+        created_at: api_key.created_at,
+        expires_at: api_key.expires_at,
+        last_used: api_key.last_used,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_api_key() {
+        // Matches the hashes computed by the migration which moved the existing API keys of
+        // merchant accounts to the `api_keys` table
+        assert_eq!(
+            hash_api_key("MySecretApiKey"),
+            "ef85ddb271333dcf01abe1e565a913e948ef8b0c87ed28e7046c0cf2656b622b"
+        );
+    }
+}
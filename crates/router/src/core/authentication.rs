@@ -0,0 +1,100 @@
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        errors::{self, ConnectorErrorExt, RouterResult},
+        payments, utils as core_utils,
+    },
+    routes::AppState,
+    services,
+    types::{
+        self, api,
+        storage::{self, enums},
+    },
+};
+
+/// Connectors which accept the result of an authentication performed with a standalone 3DS
+/// server along with the authorization, instead of authenticating the cardholder themselves.
+pub fn connector_accepts_authentication_data(connector: &api::ConnectorData) -> bool {
+    matches!(
+        connector.connector_name,
+        types::Connector::Adyen | types::Connector::Checkout | types::Connector::Stripe
+    )
+}
+
+/// Authenticates the cardholder with the 3DS server which the merchant has configured as their
+/// authentication processor.
+///
+/// Returns `None` if the merchant has not configured an authentication processor, or if the
+/// payment is not made with a card.
+#[instrument(skip_all)]
+pub async fn perform_external_authentication(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    router_data: &types::PaymentsAuthorizeRouterData,
+) -> RouterResult<Option<Result<types::ThreeDsAuthenticationData, types::ErrorResponse>>> {
+    let card = match &router_data.request.payment_method_data {
+        api::PaymentMethod::Card(card) => card.clone(),
+        _ => return Ok(None),
+    };
+
+    let merchant_connector_account = state
+        .store
+        .find_merchant_connector_account_by_merchant_id_list(&merchant_account.merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the merchant connector accounts")?
+        .into_iter()
+        .find(|merchant_connector_account| {
+            merchant_connector_account.connector_type
+                == enums::ConnectorType::AuthenticationProcessor
+        });
+    let merchant_connector_account = match merchant_connector_account {
+        Some(merchant_connector_account) => merchant_connector_account,
+        None => return Ok(None),
+    };
+
+    let connector = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &merchant_connector_account.connector_name,
+        api::GetToken::Connector,
+    )?;
+    let connector_auth_type =
+        core_utils::get_connector_auth_type(state, &merchant_connector_account).await?;
+
+    let authentication_router_data: types::ThreeDsAuthenticationRouterData = types::RouterData {
+        connector: merchant_connector_account.connector_name,
+        connector_auth_type,
+        connector_meta_data: merchant_connector_account.metadata,
+        test_mode: merchant_connector_account.test_mode,
+        ..payments::helpers::router_data_type_conversion(
+            router_data,
+            types::ThreeDsAuthenticationRequestData {
+                card,
+                amount: router_data.request.amount,
+                currency: router_data.request.currency,
+                email: router_data.request.email.clone(),
+                browser_info: router_data.request.browser_info.clone(),
+            },
+            Err(types::ErrorResponse::default()),
+        )
+    };
+
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        api::ThreeDsAuthentication,
+        types::ThreeDsAuthenticationRequestData,
+        types::ThreeDsAuthenticationData,
+    > = connector.connector.get_connector_integration();
+    let response = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &authentication_router_data,
+        payments::CallConnectorAction::Trigger,
+    )
+    .await
+    .map_err(|error| error.to_payment_failed_response())?;
+
+    Ok(Some(response.response))
+}
@@ -0,0 +1,231 @@
+use std::net::IpAddr;
+
+use common_utils::{crypto, crypto::SignMessage, date_time};
+use error_stack::{report, ResultExt};
+use masking::{ExposeInterface, PeekInterface};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    db::StorageInterface,
+    routes::AppState,
+    services,
+    types::{
+        self,
+        api::{self, blocklist},
+        storage::{self, enums},
+        transformers::ForeignInto,
+    },
+    utils,
+};
+
+#[instrument(skip_all)]
+pub async fn add_blocklist_entry(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: blocklist::BlocklistAddRequest,
+) -> RouterResponse<blocklist::BlocklistResponse> {
+    let db = &*state.store;
+    let kind: enums::BlocklistKind = req.kind.foreign_into();
+    let data = normalize_blocklist_data(state, kind, req.data.expose())?;
+
+    utils::when(
+        req.expires_at
+            .map_or(false, |expires_at| expires_at <= date_time::now()),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "expires_at must be in the future".to_string(),
+            }))
+        },
+    )?;
+
+    // An expired entry for the same data is replaced, instead of preventing the data from being
+    // blocked again
+    match db
+        .find_blocklist_entry_by_merchant_id_kind_data(&merchant_account.merchant_id, kind, &data)
+        .await
+    {
+        Ok(existing_entry) if existing_entry.is_expired_at(date_time::now()) => {
+            db.delete_blocklist_entry_by_merchant_id_entry_id(
+                &merchant_account.merchant_id,
+                &existing_entry.entry_id,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to delete the expired blocklist entry")?;
+        }
+        Ok(_) => Err(report!(errors::ApiErrorResponse::DuplicateBlocklistEntry))?,
+        Err(error) if error.current_context().is_db_not_found() => {}
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the blocklist entry"))?,
+    }
+
+    let blocklist_new = storage::BlocklistNew {
+        entry_id: utils::generate_id(consts::ID_LENGTH, "bl"),
+        merchant_id: merchant_account.merchant_id,
+        kind,
+        data,
+        expires_at: req.expires_at,
+    };
+    let blocklist_entry = db
+        .insert_blocklist_entry(blocklist_new)
+        .await
+        .map_err(|error| {
+            error.to_duplicate_response(errors::ApiErrorResponse::DuplicateBlocklistEntry)
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        blocklist_entry.foreign_into(),
+    ))
+}
+
+#[instrument(skip(store))]
+pub async fn list_blocklist_entries(
+    store: &dyn StorageInterface,
+    merchant_account: storage::MerchantAccount,
+    constraints: blocklist::BlocklistListConstraints,
+) -> RouterResponse<Vec<blocklist::BlocklistResponse>> {
+    let kind: Option<enums::BlocklistKind> = constraints.kind.map(ForeignInto::foreign_into);
+    let blocklist_entries = store
+        .list_blocklist_entries_by_merchant_id(&merchant_account.merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the blocklist entries")?
+        .into_iter()
+        .filter(|entry| kind.map_or(true, |kind| entry.kind == kind))
+        .map(ForeignInto::foreign_into)
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(blocklist_entries))
+}
+
+#[instrument(skip(store))]
+pub async fn delete_blocklist_entry(
+    store: &dyn StorageInterface,
+    merchant_account: storage::MerchantAccount,
+    entry_id: String,
+) -> RouterResponse<blocklist::BlocklistDeleteResponse> {
+    let deleted = store
+        .delete_blocklist_entry_by_merchant_id_entry_id(&merchant_account.merchant_id, &entry_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::BlocklistEntryNotFound)
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        blocklist::BlocklistDeleteResponse { entry_id, deleted },
+    ))
+}
+
+/// Finds the unexpired blocklist entry of the merchant which blocks the card, the email or the
+/// IP address of the payment, if any.
+#[instrument(skip_all)]
+pub async fn find_blocking_entry(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    router_data: &types::PaymentsAuthorizeRouterData,
+) -> RouterResult<Option<storage::Blocklist>> {
+    let mut candidates = Vec::new();
+    if let api::PaymentMethod::Card(card) = &router_data.request.payment_method_data {
+        candidates.push((
+            enums::BlocklistKind::CardFingerprint,
+            get_card_fingerprint(state, card.card_number.peek())?,
+        ));
+    }
+    if let Some(email) = &router_data.request.email {
+        candidates.push((enums::BlocklistKind::Email, normalize_email(email.peek())));
+    }
+    if let Some(ip_address) = router_data
+        .request
+        .browser_info
+        .as_ref()
+        .and_then(|browser_info| browser_info.ip_address)
+    {
+        candidates.push((enums::BlocklistKind::IpAddress, ip_address.to_string()));
+    }
+
+    let now = date_time::now();
+    for (kind, data) in candidates {
+        match state
+            .store
+            .find_blocklist_entry_by_merchant_id_kind_data(
+                &merchant_account.merchant_id,
+                kind,
+                &data,
+            )
+            .await
+        {
+            Ok(entry) if !entry.is_expired_at(now) => {
+                logger::info!(entry_id = %entry.entry_id, %kind, "Payment blocked by the blocklist");
+                return Ok(Some(entry));
+            }
+            Ok(_) => {}
+            Err(error) if error.current_context().is_db_not_found() => {}
+            Err(error) => Err(error
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the blocklist entry"))?,
+        }
+    }
+    Ok(None)
+}
+
+/// Converts the data of a blocklist entry to the form in which it is stored and matched against
+/// payments. Card numbers are replaced by their fingerprint.
+fn normalize_blocklist_data(
+    state: &AppState,
+    kind: enums::BlocklistKind,
+    data: String,
+) -> RouterResult<String> {
+    let data = data.trim();
+    utils::when(data.is_empty(), || {
+        Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "data"
+        }))
+    })?;
+
+    match kind {
+        enums::BlocklistKind::CardFingerprint => {
+            let card_number: String = data
+                .chars()
+                .filter(|character| !matches!(character, ' ' | '-'))
+                .collect();
+            utils::when(
+                card_number.is_empty()
+                    || !card_number
+                        .chars()
+                        .all(|character| character.is_ascii_digit()),
+                || {
+                    Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+                        field_name: "data"
+                    }))
+                },
+            )?;
+            get_card_fingerprint(state, &card_number)
+        }
+        enums::BlocklistKind::Email => Ok(normalize_email(data)),
+        enums::BlocklistKind::IpAddress => data
+            .parse::<IpAddr>()
+            .map(|ip_address| ip_address.to_string())
+            .map_err(|_| {
+                report!(errors::ApiErrorResponse::InvalidDataValue { field_name: "data" })
+            }),
+    }
+}
+
+fn normalize_email(email: &str) -> String {
+    email.trim().to_lowercase()
+}
+
+/// Computes the fingerprint of a card number, which is stored in place of the card number.
+pub(crate) fn get_card_fingerprint(state: &AppState, card_number: &str) -> RouterResult<String> {
+    crypto::HmacSha256
+        .sign_message(
+            state.conf.secrets.card_fingerprint_key.as_bytes(),
+            card_number.as_bytes(),
+        )
+        .map(hex::encode)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to compute the card fingerprint")
+}
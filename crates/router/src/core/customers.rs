@@ -115,7 +115,7 @@ pub async fn delete_customer(
     merchant_account: storage::MerchantAccount,
     req: customers::CustomerId,
 ) -> RouterResponse<customers::CustomerDeleteResponse> {
-    let db = &state.store;
+    let db = &*state.store;
 
     db.find_customer_by_customer_id_merchant_id(&req.customer_id, &merchant_account.merchant_id)
         .await
@@ -132,21 +132,161 @@ pub async fn delete_customer(
         }
     }
 
+    delete_customer_payment_methods(state, &merchant_account.merchant_id, &req.customer_id).await?;
+
     match db
-        .find_payment_method_by_customer_id_merchant_id_list(
+        .update_address_by_merchant_id_customer_id(
             &req.customer_id,
             &merchant_account.merchant_id,
+            redacted_address(),
+        )
+        .await
+    {
+        Ok(_) => Ok(()),
+        Err(error) => match error.current_context() {
+            errors::StorageError::DatabaseError(err) => match err.current_context() {
+                storage_errors::DatabaseError::NotFound => Ok(()),
+                _ => Err(errors::ApiErrorResponse::InternalServerError),
+            },
+            _ => Err(errors::ApiErrorResponse::InternalServerError),
+        },
+    }?;
+
+    redact_customer_details(db, req.customer_id.clone(), merchant_account.merchant_id).await?;
+
+    let response = customers::CustomerDeleteResponse {
+        customer_id: req.customer_id,
+        customer_deleted: true,
+        address_deleted: true,
+        payment_methods_deleted: true,
+    };
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+/// Irreversibly removes the personal data of the customer, along with the references to the
+/// customer from their payments, and records the redaction in the customer redaction log.
+#[instrument(skip_all)]
+pub async fn redact_customer(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: customers::CustomerId,
+) -> RouterResponse<customers::CustomerRedactResponse> {
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+
+    db.find_customer_by_customer_id_merchant_id(&req.customer_id, merchant_id)
+        .await
+        .map_err(|err| err.to_not_found_response(errors::ApiErrorResponse::CustomerNotFound))?;
+
+    let customer_mandates = db
+        .find_mandate_by_merchant_id_customer_id(merchant_id, &req.customer_id)
+        .await
+        .map_err(|err| err.to_not_found_response(errors::ApiErrorResponse::MandateNotFound))?;
+
+    if customer_mandates
+        .iter()
+        .any(|mandate| mandate.mandate_status == enums::MandateStatus::Active)
+    {
+        Err(errors::ApiErrorResponse::MandateActive)?
+    }
+
+    let payment_methods_deleted =
+        delete_customer_payment_methods(state, merchant_id, &req.customer_id).await?;
+
+    match db
+        .update_address_by_merchant_id_customer_id(
+            &req.customer_id,
+            merchant_id,
+            redacted_address(),
+        )
+        .await
+    {
+        Ok(_) => Ok(()),
+        Err(error) if error.current_context().is_db_not_found() => Ok(()),
+        Err(error) => Err(error.change_context(errors::ApiErrorResponse::InternalServerError)),
+    }?;
+
+    let payment_intents = db
+        .anonymize_payment_intents_by_merchant_id_customer_id(
+            merchant_id,
+            &req.customer_id,
+            merchant_account.storage_scheme,
         )
         .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to anonymize the payment intents of the customer")?;
+
+    // The addresses of the payments are not linked to the customer once the payment intents have
+    // been anonymized, so they are redacted by their IDs
+    let mut payment_address_ids: Vec<_> = payment_intents
+        .iter()
+        .flat_map(|payment_intent| {
+            [
+                payment_intent.billing_address_id.clone(),
+                payment_intent.shipping_address_id.clone(),
+            ]
+        })
+        .flatten()
+        .collect();
+    payment_address_ids.sort();
+    payment_address_ids.dedup();
+    for address_id in payment_address_ids {
+        match db.update_address(address_id, redacted_address()).await {
+            Ok(_) => Ok(()),
+            Err(error) if error.current_context().is_db_not_found() => Ok(()),
+            Err(error) => Err(error.change_context(errors::ApiErrorResponse::InternalServerError)),
+        }?;
+    }
+
+    // Safety: A customer is not expected to have more than `i32::MAX` payment methods or payments
+    #[allow(clippy::as_conversions)]
+    let redaction_log = storage::CustomerRedactionLogNew {
+        customer_id: req.customer_id.clone(),
+        merchant_id: merchant_id.clone(),
+        payment_methods_deleted: payment_methods_deleted as i32,
+        payment_intents_anonymized: payment_intents.len() as i32,
+    };
+    // The redaction is recorded before the customer is redacted, as the customer can no longer be
+    // found once redacted, which would prevent retrying a redaction whose recording failed
+    let redaction_log = db
+        .insert_customer_redaction_log(redaction_log)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to record the redaction of the customer")?;
+
+    redact_customer_details(db, req.customer_id.clone(), merchant_id.clone()).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        customers::CustomerRedactResponse {
+            customer_id: req.customer_id,
+            customer_redacted: true,
+            payment_methods_deleted: redaction_log.payment_methods_deleted,
+            payment_intents_anonymized: redaction_log.payment_intents_anonymized,
+        },
+    ))
+}
+
+/// Deletes the saved payment methods of the customer, along with the cards stored in the locker,
+/// returning the number of payment methods deleted.
+async fn delete_customer_payment_methods(
+    state: &AppState,
+    merchant_id: &str,
+    customer_id: &str,
+) -> errors::RouterResult<usize> {
+    let db = &*state.store;
+
+    match db
+        .find_payment_method_by_customer_id_merchant_id_list(customer_id, merchant_id)
+        .await
     {
         Ok(customer_payment_methods) => {
+            let count = customer_payment_methods.len();
             for pm in customer_payment_methods.into_iter() {
                 if pm.payment_method == enums::PaymentMethodType::Card {
-                    cards::delete_card(state, &merchant_account.merchant_id, &pm.payment_method_id)
-                        .await?;
+                    cards::delete_card(state, merchant_id, &pm.payment_method_id).await?;
                 }
                 db.delete_payment_method_by_merchant_id_payment_method_id(
-                    &merchant_account.merchant_id,
+                    merchant_id,
                     &pm.payment_method_id,
                 )
                 .await
@@ -154,17 +294,15 @@ pub async fn delete_customer(
                     error.to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)
                 })?;
             }
+            Ok(count)
         }
-        Err(error) => match error.current_context() {
-            errors::StorageError::DatabaseError(err) => match err.current_context() {
-                storage_errors::DatabaseError::NotFound => Ok(()),
-                _ => Err(errors::ApiErrorResponse::InternalServerError),
-            },
-            _ => Err(errors::ApiErrorResponse::InternalServerError),
-        }?,
-    };
+        Err(error) if error.current_context().is_db_not_found() => Ok(0),
+        Err(error) => Err(error.change_context(errors::ApiErrorResponse::InternalServerError)),
+    }
+}
 
-    let update_address = storage::AddressUpdate::Update {
+fn redacted_address() -> storage::AddressUpdate {
+    storage::AddressUpdate::Update {
         city: Some(REDACTED.to_string()),
         country: Some(REDACTED.to_string()),
         line1: Some(REDACTED.to_string().into()),
@@ -176,26 +314,15 @@ pub async fn delete_customer(
         last_name: Some(REDACTED.to_string().into()),
         phone_number: Some(REDACTED.to_string().into()),
         country_code: Some(REDACTED.to_string()),
-    };
-
-    match db
-        .update_address_by_merchant_id_customer_id(
-            &req.customer_id,
-            &merchant_account.merchant_id,
-            update_address,
-        )
-        .await
-    {
-        Ok(_) => Ok(()),
-        Err(error) => match error.current_context() {
-            errors::StorageError::DatabaseError(err) => match err.current_context() {
-                storage_errors::DatabaseError::NotFound => Ok(()),
-                _ => Err(errors::ApiErrorResponse::InternalServerError),
-            },
-            _ => Err(errors::ApiErrorResponse::InternalServerError),
-        },
-    }?;
+    }
+}
 
+/// Replaces the personal data of the customer, after which the customer can no longer be found.
+async fn redact_customer_details(
+    db: &dyn StorageInterface,
+    customer_id: String,
+    merchant_id: String,
+) -> errors::RouterResult<()> {
     let updated_customer = storage::CustomerUpdate::Update {
         name: Some(REDACTED.to_string()),
         email: Some(REDACTED.to_string().into()),
@@ -204,21 +331,11 @@ pub async fn delete_customer(
         phone_country_code: Some(REDACTED.to_string()),
         metadata: None,
     };
-    db.update_customer_by_customer_id_merchant_id(
-        req.customer_id.clone(),
-        merchant_account.merchant_id,
-        updated_customer,
-    )
-    .await
-    .change_context(errors::ApiErrorResponse::CustomerNotFound)?;
+    db.update_customer_by_customer_id_merchant_id(customer_id, merchant_id, updated_customer)
+        .await
+        .change_context(errors::ApiErrorResponse::CustomerNotFound)?;
 
-    let response = customers::CustomerDeleteResponse {
-        customer_id: req.customer_id,
-        customer_deleted: true,
-        address_deleted: true,
-        payment_methods_deleted: true,
-    };
-    Ok(services::ApplicationResponse::Json(response))
+    Ok(())
 }
 
 #[instrument(skip(db))]
@@ -0,0 +1,213 @@
+use error_stack::{IntoReport, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, StorageErrorExt},
+        payments, utils as core_utils,
+    },
+    db::StorageInterface,
+    routes::AppState,
+    services,
+    types::{
+        self,
+        api::{self, disputes},
+        storage::{self, enums},
+        transformers::ForeignInto,
+    },
+    utils,
+};
+
+const DISPUTE_LIST_DEFAULT_LIMIT: i64 = 10;
+const DISPUTE_LIST_MAX_LIMIT: i64 = 100;
+
+#[instrument(skip(db))]
+pub async fn retrieve_dispute(
+    db: &dyn StorageInterface,
+    merchant_account: storage::MerchantAccount,
+    dispute_id: String,
+) -> RouterResponse<disputes::DisputeResponse> {
+    let dispute = db
+        .find_dispute_by_merchant_id_dispute_id(&merchant_account.merchant_id, &dispute_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::DisputeNotFound))?;
+
+    Ok(services::ApplicationResponse::Json(dispute.foreign_into()))
+}
+
+#[instrument(skip(db))]
+pub async fn retrieve_disputes_list(
+    db: &dyn StorageInterface,
+    merchant_account: storage::MerchantAccount,
+    constraints: disputes::DisputeListConstraints,
+) -> RouterResponse<disputes::DisputeListResponse> {
+    let limit = match constraints.limit {
+        Some(limit) if !(1..=DISPUTE_LIST_MAX_LIMIT).contains(&limit) => {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("limit should be in between 1 and {DISPUTE_LIST_MAX_LIMIT}"),
+            })?
+        }
+        Some(limit) => limit,
+        None => DISPUTE_LIST_DEFAULT_LIMIT,
+    };
+
+    let data = db
+        .filter_dispute_by_constraints(&merchant_account.merchant_id, &constraints, limit)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to filter disputes by the given constraints")?
+        .into_iter()
+        .map(ForeignInto::foreign_into)
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(
+        disputes::DisputeListResponse { data },
+    ))
+}
+
+/// Submits the evidence for challenging a dispute to the connector, and submits the challenge for
+/// review for connectors which accept the evidence and the challenge in separate calls.
+#[instrument(skip(state))]
+pub async fn submit_evidence(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    dispute_id: String,
+    req: disputes::SubmitEvidenceRequest,
+) -> RouterResponse<disputes::DisputeResponse> {
+    let db = &*state.store;
+    let dispute = db
+        .find_dispute_by_merchant_id_dispute_id(&merchant_account.merchant_id, &dispute_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::DisputeNotFound))?;
+
+    utils::when(
+        dispute.dispute_status != enums::DisputeStatus::DisputeOpened,
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "Evidence can be submitted only for open disputes, the dispute is {}",
+                    dispute.dispute_status
+                ),
+            })
+        },
+    )?;
+
+    let payment_attempt = db
+        .find_payment_attempt_by_merchant_id_attempt_id(
+            &merchant_account.merchant_id,
+            &dispute.attempt_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    let connector = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &dispute.connector,
+        api::GetToken::Connector,
+    )?;
+
+    // The evidence is considered not submitted for connectors which do not support it
+    let evidence_router_data: types::SubmitEvidenceRouterData =
+        core_utils::construct_dispute_router_data(
+            state,
+            &merchant_account,
+            &dispute,
+            &payment_attempt,
+            types::SubmitEvidenceRequestData {
+                dispute_id: dispute.dispute_id.clone(),
+                connector_dispute_id: dispute.connector_dispute_id.clone(),
+                evidence: req.clone(),
+            },
+            Err(types::ErrorResponse::get_not_implemented()),
+        )
+        .await?;
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > = connector.connector.get_connector_integration();
+    let evidence_response = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &evidence_router_data,
+        payments::CallConnectorAction::Trigger,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed while submitting the dispute evidence to the connector")?
+    .response
+    .map_err(get_evidence_submission_error)?;
+
+    // Connectors which do not need a separate call for submitting the challenge respond with the
+    // outcome of the evidence submission
+    let defend_router_data: types::DefendDisputeRouterData =
+        core_utils::construct_dispute_router_data(
+            state,
+            &merchant_account,
+            &dispute,
+            &payment_attempt,
+            types::DefendDisputeRequestData {
+                dispute_id: dispute.dispute_id.clone(),
+                connector_dispute_id: dispute.connector_dispute_id.clone(),
+            },
+            Ok(types::DefendDisputeResponse {
+                challenge_status: evidence_response.challenge_status,
+                connector_status: evidence_response.connector_status,
+            }),
+        )
+        .await?;
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        api::Defend,
+        types::DefendDisputeRequestData,
+        types::DefendDisputeResponse,
+    > = connector.connector.get_connector_integration();
+    let defend_response = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &defend_router_data,
+        payments::CallConnectorAction::Trigger,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed while submitting the dispute challenge to the connector")?
+    .response
+    .map_err(get_evidence_submission_error)?;
+
+    let evidence = serde_json::to_value(&req)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the dispute evidence")?;
+    let updated_dispute = db
+        .update_dispute(
+            dispute,
+            storage::DisputeUpdate::EvidenceUpdate {
+                dispute_status: enums::DisputeStatus::DisputeChallenged,
+                connector_status: defend_response.connector_status,
+                evidence,
+                challenge_status: defend_response.challenge_status,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| format!("Failed to update the dispute with id {dispute_id}"))?;
+
+    Ok(services::ApplicationResponse::Json(
+        updated_dispute.foreign_into(),
+    ))
+}
+
+fn get_evidence_submission_error(
+    error: types::ErrorResponse,
+) -> error_stack::Report<errors::ApiErrorResponse> {
+    errors::ApiErrorResponse::DisputeEvidenceSubmissionFailed {
+        data: Some(serde_json::json!({
+            "code": error.code,
+            "message": error.message,
+            "reason": error.reason,
+        })),
+    }
+    .into()
+}
@@ -0,0 +1,402 @@
+//! The dummy connector, which the router serves itself so that the payment flows can be tested
+//! without the credentials of a real connector.
+//!
+//! The outcome of a payment is decided by the card number it is made with:
+//!
+//! | Card number        | Outcome                                                          |
+//! |--------------------|------------------------------------------------------------------|
+//! | `4242424242424242` | Succeeds                                                         |
+//! | `4111111111111111` | Succeeds                                                         |
+//! | `4000000000000002` | Is declined with `card_declined`                                 |
+//! | `4000000000009995` | Is declined with `insufficient_funds`                            |
+//! | `4000000000000069` | Is declined with `expired_card`                                  |
+//! | `4000000000003220` | Requires authentication, then succeeds                           |
+//! | `4000008400001629` | Requires authentication, then fails with `authentication_failed` |
+//!
+//! Payments made with any other card number are declined with `card_not_supported`.
+//!
+//! Captures of payments whose amount ends in `77` in the minor unit of the currency, such as
+//! `10.77`, are processed with a delay. The payment is `processing` until it is next retrieved,
+//! after which it has succeeded.
+
+use common_utils::date_time;
+use error_stack::{report, IntoReport, ResultExt};
+use masking::PeekInterface;
+use router_env::{instrument, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, StorageErrorExt},
+    routes::AppState,
+    services,
+    types::{
+        api::dummy_connector as dummy_connector_types,
+        storage::dummy_connector::{
+            DummyConnectorError, DummyConnectorPayment, DummyConnectorRefund,
+            DummyConnectorRefundStatus, DummyConnectorStatus,
+        },
+    },
+    utils::{self, OptionExt},
+};
+
+/// Outcome of a payment made with a test card.
+enum CardOutcome {
+    Success,
+    Decline(DummyConnectorError),
+    /// The payment requires authentication, after which it fails with the error if there is one
+    Authentication(Option<DummyConnectorError>),
+}
+
+fn get_card_outcome(card_number: &str) -> CardOutcome {
+    let error = |code: &str, message: &str| DummyConnectorError {
+        code: code.to_string(),
+        message: message.to_string(),
+    };
+
+    match card_number {
+        "4242424242424242" | "4111111111111111" => CardOutcome::Success,
+        "4000000000000002" => {
+            CardOutcome::Decline(error("card_declined", "Your card was declined."))
+        }
+        "4000000000009995" => CardOutcome::Decline(error(
+            "insufficient_funds",
+            "Your card has insufficient funds.",
+        )),
+        "4000000000000069" => CardOutcome::Decline(error("expired_card", "Your card has expired.")),
+        "4000000000003220" => CardOutcome::Authentication(None),
+        "4000008400001629" => CardOutcome::Authentication(Some(error(
+            "authentication_failed",
+            "The cardholder could not be authenticated.",
+        ))),
+        _ => CardOutcome::Decline(error(
+            "card_not_supported",
+            "Only the test cards of the dummy connector are supported.",
+        )),
+    }
+}
+
+fn is_delayed_capture_amount(amount: i64) -> bool {
+    amount % 100 == 77
+}
+
+/// Authorizes the payment, capturing it as well if it is to be captured automatically.
+fn authorize(payment: &mut DummyConnectorPayment) {
+    if !payment.capture {
+        payment.status = DummyConnectorStatus::Authorized;
+    } else {
+        capture(payment, payment.amount);
+    }
+}
+
+fn capture(payment: &mut DummyConnectorPayment, amount: i64) {
+    payment.status = if payment.delayed_capture {
+        DummyConnectorStatus::Processing
+    } else {
+        DummyConnectorStatus::Succeeded
+    };
+    payment.refundable_amount = amount;
+}
+
+fn get_redirect_url(state: &AppState, payment: &DummyConnectorPayment) -> Option<String> {
+    (payment.status == DummyConnectorStatus::RequiresAuthentication).then(|| {
+        format!(
+            "{}authorize/{}",
+            state.conf.connectors.dummy_connector.base_url, payment.payment_id
+        )
+    })
+}
+
+fn payment_response(
+    state: &AppState,
+    payment: DummyConnectorPayment,
+) -> services::ApplicationResponse<dummy_connector_types::DummyConnectorPaymentResponse> {
+    let redirect_url = get_redirect_url(state, &payment);
+    services::ApplicationResponse::Json(dummy_connector_types::DummyConnectorPaymentResponse::new(
+        payment,
+        redirect_url,
+    ))
+}
+
+async fn find_payment(
+    state: &AppState,
+    payment_id: &str,
+) -> errors::RouterResult<DummyConnectorPayment> {
+    state
+        .store
+        .find_dummy_connector_payment(payment_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))
+}
+
+async fn store_payment(
+    state: &AppState,
+    payment: &DummyConnectorPayment,
+) -> errors::RouterResult<()> {
+    state
+        .store
+        .store_dummy_connector_payment(payment)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to store the dummy connector payment")
+}
+
+#[instrument(skip_all)]
+pub async fn payment(
+    state: &AppState,
+    req: dummy_connector_types::DummyConnectorPaymentRequest,
+) -> RouterResponse<dummy_connector_types::DummyConnectorPaymentResponse> {
+    utils::when(req.amount <= 0, || {
+        Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "amount"
+        }))
+    })?;
+
+    let mut payment = DummyConnectorPayment {
+        payment_id: utils::generate_id(consts::ID_LENGTH, "dummy_pay"),
+        status: DummyConnectorStatus::RequiresAuthentication,
+        amount: req.amount,
+        currency: req.currency,
+        capture: req.capture,
+        delayed_capture: is_delayed_capture_amount(req.amount),
+        refundable_amount: 0,
+        return_url: None,
+        error: None,
+        created_at: date_time::now().assume_utc().unix_timestamp(),
+    };
+
+    match get_card_outcome(req.card.number.peek()) {
+        CardOutcome::Success => authorize(&mut payment),
+        CardOutcome::Decline(error) => {
+            payment.status = DummyConnectorStatus::Failed;
+            payment.error = Some(error);
+        }
+        CardOutcome::Authentication(error) => {
+            payment.return_url = Some(req.return_url.get_required_value("return_url")?);
+            payment.error = error;
+        }
+    }
+
+    store_payment(state, &payment).await?;
+    Ok(payment_response(state, payment))
+}
+
+#[instrument(skip_all)]
+pub async fn payment_retrieve(
+    state: &AppState,
+    payment_id: String,
+) -> RouterResponse<dummy_connector_types::DummyConnectorPaymentResponse> {
+    let mut payment = find_payment(state, &payment_id).await?;
+
+    // Delayed captures complete when the payment is retrieved
+    if payment.status == DummyConnectorStatus::Processing {
+        payment.status = DummyConnectorStatus::Succeeded;
+        store_payment(state, &payment).await?;
+    }
+
+    Ok(payment_response(state, payment))
+}
+
+#[instrument(skip_all)]
+pub async fn payment_capture(
+    state: &AppState,
+    payment_id: String,
+    req: dummy_connector_types::DummyConnectorCaptureRequest,
+) -> RouterResponse<dummy_connector_types::DummyConnectorPaymentResponse> {
+    let mut payment = find_payment(state, &payment_id).await?;
+
+    utils::when(payment.status != DummyConnectorStatus::Authorized, || {
+        Err(report!(errors::ApiErrorResponse::PaymentUnexpectedState {
+            current_flow: "captured".to_string(),
+            field_name: "status".to_string(),
+            current_value: payment.status.to_string(),
+            states: DummyConnectorStatus::Authorized.to_string(),
+        }))
+    })?;
+
+    let amount = req.amount.unwrap_or(payment.amount);
+    utils::when(amount <= 0 || amount > payment.amount, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "amount must be greater than zero and at most the amount of the payment"
+                .to_string(),
+        }))
+    })?;
+
+    capture(&mut payment, amount);
+    store_payment(state, &payment).await?;
+    Ok(payment_response(state, payment))
+}
+
+#[instrument(skip_all)]
+pub async fn payment_void(
+    state: &AppState,
+    payment_id: String,
+) -> RouterResponse<dummy_connector_types::DummyConnectorPaymentResponse> {
+    let mut payment = find_payment(state, &payment_id).await?;
+
+    utils::when(
+        !matches!(
+            payment.status,
+            DummyConnectorStatus::Authorized | DummyConnectorStatus::RequiresAuthentication
+        ),
+        || {
+            Err(report!(errors::ApiErrorResponse::PaymentUnexpectedState {
+                current_flow: "voided".to_string(),
+                field_name: "status".to_string(),
+                current_value: payment.status.to_string(),
+                states: "authorized, requires_authentication".to_string(),
+            }))
+        },
+    )?;
+
+    payment.status = DummyConnectorStatus::Voided;
+    store_payment(state, &payment).await?;
+    Ok(payment_response(state, payment))
+}
+
+/// Completes the authentication of the payment, and sends the customer back to the return URL
+/// of the payment. Payments which were already authenticated are left as they are.
+#[instrument(skip_all)]
+pub async fn payment_authorize(state: &AppState, payment_id: String) -> RouterResponse<()> {
+    let mut payment = find_payment(state, &payment_id).await?;
+
+    if payment.status == DummyConnectorStatus::RequiresAuthentication {
+        if payment.error.is_some() {
+            payment.status = DummyConnectorStatus::Failed;
+        } else {
+            authorize(&mut payment);
+        }
+        store_payment(state, &payment).await?;
+    }
+
+    let return_url = url::Url::parse(&payment.return_url.get_required_value("return_url")?)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the return URL of the dummy connector payment")?;
+
+    // The query parameters are sent as form fields, as they are dropped from the URL of forms
+    // submitted with `GET`
+    Ok(services::ApplicationResponse::Form(
+        services::RedirectForm {
+            url: return_url.to_string(),
+            method: services::Method::Get,
+            form_fields: return_url
+                .query_pairs()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn refund(
+    state: &AppState,
+    payment_id: String,
+    req: dummy_connector_types::DummyConnectorRefundRequest,
+) -> RouterResponse<dummy_connector_types::DummyConnectorRefundResponse> {
+    let mut payment = find_payment(state, &payment_id).await?;
+
+    utils::when(payment.status != DummyConnectorStatus::Succeeded, || {
+        Err(report!(errors::ApiErrorResponse::PaymentUnexpectedState {
+            current_flow: "refunded".to_string(),
+            field_name: "status".to_string(),
+            current_value: payment.status.to_string(),
+            states: DummyConnectorStatus::Succeeded.to_string(),
+        }))
+    })?;
+    utils::when(req.amount <= 0, || {
+        Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "amount"
+        }))
+    })?;
+    utils::when(req.amount > payment.refundable_amount, || {
+        Err(report!(
+            errors::ApiErrorResponse::RefundAmountExceedsPaymentAmount
+        ))
+    })?;
+
+    let refund = DummyConnectorRefund {
+        refund_id: utils::generate_id(consts::ID_LENGTH, "dummy_ref"),
+        payment_id: payment.payment_id.clone(),
+        status: DummyConnectorRefundStatus::Succeeded,
+        amount: req.amount,
+        currency: payment.currency,
+        created_at: date_time::now().assume_utc().unix_timestamp(),
+    };
+    payment.refundable_amount -= req.amount;
+
+    state
+        .store
+        .store_dummy_connector_refund(&refund)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to store the dummy connector refund")?;
+    store_payment(state, &payment).await?;
+
+    Ok(services::ApplicationResponse::Json(refund.into()))
+}
+
+#[instrument(skip_all)]
+pub async fn refund_retrieve(
+    state: &AppState,
+    refund_id: String,
+) -> RouterResponse<dummy_connector_types::DummyConnectorRefundResponse> {
+    let refund = state
+        .store
+        .find_dummy_connector_refund(&refund_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::RefundNotFound))?;
+
+    Ok(services::ApplicationResponse::Json(refund.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_card_outcomes() {
+        assert!(matches!(
+            get_card_outcome("4242424242424242"),
+            CardOutcome::Success
+        ));
+        assert!(matches!(
+            get_card_outcome("4000000000000002"),
+            CardOutcome::Decline(DummyConnectorError { code, .. }) if code == "card_declined"
+        ));
+        assert!(matches!(
+            get_card_outcome("4000000000003220"),
+            CardOutcome::Authentication(None)
+        ));
+        assert!(matches!(
+            get_card_outcome("4000008400001629"),
+            CardOutcome::Authentication(Some(_))
+        ));
+        assert!(matches!(
+            get_card_outcome("4200000000000000"),
+            CardOutcome::Decline(DummyConnectorError { code, .. }) if code == "card_not_supported"
+        ));
+    }
+
+    #[test]
+    fn test_automatic_capture() {
+        let mut payment = DummyConnectorPayment {
+            payment_id: "dummy_pay_1".to_string(),
+            status: DummyConnectorStatus::RequiresAuthentication,
+            amount: 1077,
+            currency: crate::types::storage::enums::Currency::USD,
+            capture: true,
+            delayed_capture: is_delayed_capture_amount(1077),
+            refundable_amount: 0,
+            return_url: None,
+            error: None,
+            created_at: 0,
+        };
+        authorize(&mut payment);
+        assert_eq!(payment.status, DummyConnectorStatus::Processing);
+        assert_eq!(payment.refundable_amount, 1077);
+
+        payment.capture = false;
+        authorize(&mut payment);
+        assert_eq!(payment.status, DummyConnectorStatus::Authorized);
+    }
+}
@@ -64,6 +64,8 @@ pub enum StorageError {
     MockDbError,
     #[error("Customer with this id is Redacted")]
     CustomerRedacted,
+    #[error("Failed to encrypt or decrypt the data")]
+    EncryptionError,
 }
 
 impl From<error_stack::Report<storage_errors::DatabaseError>> for StorageError {
@@ -96,6 +98,9 @@ impl StorageError {
 
 impl_error_type!(EncryptionError, "Encryption error");
 
+#[cfg(feature = "kafka_events")]
+impl_error_type!(KafkaError, "Kafka error");
+
 #[derive(Debug, thiserror::Error)]
 pub enum ApplicationError {
     // Display's impl can be overridden by the attribute error marco.
@@ -111,6 +116,10 @@ pub enum ApplicationError {
 
     #[error("I/O: {0}")]
     IoError(std::io::Error),
+
+    #[cfg(feature = "grpc")]
+    #[error("gRPC server error: {0}")]
+    GrpcServerError(tonic::transport::Error),
 }
 
 impl From<MetricsError> for ApplicationError {
@@ -176,6 +185,8 @@ pub enum ApiClientError {
 
     #[error("URL encoding of request payload failed")]
     UrlEncodingFailed,
+    #[error("Failed to connect to connector {0}")]
+    ConnectionFailed(String),
     #[error("Failed to send request to connector {0}")]
     RequestNotSent(String),
     #[error("Failed to decode response")]
@@ -246,10 +257,22 @@ pub enum ConnectorError {
     WebhookReferenceIdNotFound,
     #[error("Incoming webhook event type not found")]
     WebhookEventTypeNotFound,
+    #[error("Incoming webhook event ID not found")]
+    WebhookEventIdNotFound,
     #[error("Incoming webhook event resource object not found")]
     WebhookResourceObjectNotFound,
     #[error("Invalid Date/time format")]
     InvalidDateFormat,
+    #[error("Failed to obtain the base URL for the requested flow")]
+    FailedToObtainIntegrationUrl,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SecretsManagerError {
+    #[error("Secret `{0}` was not found in the secrets manager")]
+    SecretNotFound(String),
+    #[error("Failed to fetch the secret from the secrets manager")]
+    FetchSecretFailed,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -371,8 +394,24 @@ pub enum WebhooksFlowError {
     MerchantWebhookURLNotConfigured,
     #[error("Payments core flow failed")]
     PaymentsCoreFailed,
+    #[error("Refunds core flow failed")]
+    RefundsCoreFailed,
+    #[error("Disputes core flow failed")]
+    DisputesCoreFailed,
+    #[error("Mandates core flow failed")]
+    MandatesCoreFailed,
+    #[error("Webhook source could not be verified")]
+    WebhookSourceNotVerified,
+    #[error("Resource referenced by the webhook not found")]
+    ResourceNotFound,
     #[error("Webhook event creation failed")]
     WebhookEventCreationFailed,
+    #[error("Outgoing webhook encoding failed")]
+    OutgoingWebhookEncodingFailed,
+    #[error("Outgoing webhook signing failed")]
+    OutgoingWebhookSigningFailed,
+    #[error("Custom headers of outgoing webhooks could not be decrypted")]
+    CustomHeadersDecryptionFailed,
     #[error("Unable to fork webhooks flow for outgoing webhooks")]
     ForkFlowFailed,
     #[error("Webhook api call to merchant failed")]
@@ -46,6 +46,8 @@ pub enum ApiErrorResponse {
     ClientSecretNotGiven,
     #[error(error_type = ErrorType::InvalidRequestError, code = "IR_07", message = "The client_secret provided does not match the client_secret associated with the Payment.")]
     ClientSecretInvalid,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_12", message = "The client_secret provided has expired, a new client_secret has to be generated for the Payment.")]
+    ClientSecretExpired,
     #[error(error_type = ErrorType::InvalidRequestError, code = "IR_07", message = "Customer has existing mandate/subsciption.")]
     MandateActive,
     #[error(error_type = ErrorType::InvalidRequestError, code = "IR_07", message = "Customer has already redacted.")]
@@ -72,6 +74,8 @@ pub enum ApiErrorResponse {
         message = "Access forbidden, invalid JWT token was used."
     )]
     InvalidJwtToken,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_13", message = "The source of the webhook could not be verified.")]
+    WebhookAuthenticationFailed,
 
     #[error(error_type = ErrorType::ProcessingError, code = "CE_01", message = "Payment failed while processing with connector. Retry payment.")]
     PaymentAuthorizationFailed { data: Option<serde_json::Value> },
@@ -87,9 +91,13 @@ pub enum ApiErrorResponse {
     RefundFailed { data: Option<serde_json::Value> },
     #[error(error_type = ErrorType::ProcessingError, code = "CE_01", message = "Verification failed while processing with connector. Retry operation.")]
     VerificationFailed { data: Option<serde_json::Value> },
+    #[error(error_type = ErrorType::ProcessingError, code = "CE_07", message = "Evidence submission failed while processing with connector.")]
+    DisputeEvidenceSubmissionFailed { data: Option<serde_json::Value> },
 
     #[error(error_type = ErrorType::ServerNotAvailable, code = "RE_00", message = "Something went wrong.")]
     InternalServerError,
+    #[error(error_type = ErrorType::ServerNotAvailable, code = "RE_06", message = "The server is overloaded. Please retry the request after some time.")]
+    ServiceUnavailable,
     #[error(error_type = ErrorType::DuplicateRequest, code = "RE_01", message = "Duplicate refund request. Refund already attempted with the refund ID.")]
     DuplicateRefundRequest,
     #[error(error_type = ErrorType::ObjectNotFound, code = "RE_02", message = "Refund does not exist in our records.")]
@@ -110,6 +118,22 @@ pub enum ApiErrorResponse {
     DuplicateMandate,
     #[error(error_type = ErrorType::ObjectNotFound, code = "RE_02", message = "Mandate does not exist in our records.")]
     MandateNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "RE_02", message = "Dispute does not exist in our records.")]
+    DisputeNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "RE_02", message = "Event does not exist in our records.")]
+    EventNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "RE_02", message = "Payout does not exist in our records.")]
+    PayoutNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "RE_02", message = "Payment link does not exist in our records.")]
+    PaymentLinkNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "RE_02", message = "Export does not exist in our records.")]
+    ExportNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "RE_02", message = "API key does not exist in our records.")]
+    ApiKeyNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "RE_02", message = "Blocklist entry does not exist in our records.")]
+    BlocklistEntryNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "RE_02", message = "Recon report does not exist in our records.")]
+    ReconReportNotFound,
     #[error(error_type = ErrorType::ValidationError, code = "RE_03", message = "Return URL is not configured and not passed in payments request.")]
     ReturnUrlUnavailable,
     #[error(error_type = ErrorType::ValidationError, code = "RE_03", message = "Refunds not possible through hyperswitch. Please raise Refunds through {connector} dashboard")]
@@ -122,6 +146,10 @@ pub enum ApiErrorResponse {
     DuplicatePaymentMethod,
     #[error(error_type = ErrorType::DuplicateRequest, code = "RE_04", message = "The payment with the specified payment_id '{payment_id}' already exists in our records.")]
     DuplicatePayment { payment_id: String },
+    #[error(error_type = ErrorType::DuplicateRequest, code = "RE_04", message = "The payout with the specified payout_id '{payout_id}' already exists in our records.")]
+    DuplicatePayout { payout_id: String },
+    #[error(error_type = ErrorType::DuplicateRequest, code = "RE_04", message = "The blocklist entry with the specified details already exists in our records.")]
+    DuplicateBlocklistEntry,
     #[error(error_type= ErrorType::InvalidRequestError, code = "RE_05", message = "The payment has not succeeded yet")]
     PaymentNotSucceeded,
     #[error(error_type= ErrorType::ObjectNotFound, code = "RE_05", message = "Successful payment not found for the given payment id")]
@@ -151,9 +179,10 @@ impl actix_web::ResponseError for ApiErrorResponse {
         use reqwest::StatusCode;
 
         match self {
-            Self::Unauthorized | Self::InvalidEphermeralKey | Self::InvalidJwtToken => {
-                StatusCode::UNAUTHORIZED
-            } // 401
+            Self::Unauthorized
+            | Self::InvalidEphermeralKey
+            | Self::InvalidJwtToken
+            | Self::WebhookAuthenticationFailed => StatusCode::UNAUTHORIZED, // 401
             Self::InvalidRequestUrl => StatusCode::NOT_FOUND, // 404
             Self::InvalidHttpMethod => StatusCode::METHOD_NOT_ALLOWED, // 405
             Self::MissingRequiredField { .. } | Self::InvalidDataValue { .. } => {
@@ -174,10 +203,12 @@ impl actix_web::ResponseError for ApiErrorResponse {
             | Self::RefundFailed { .. }
             | Self::RefundNotPossible { .. }
             | Self::VerificationFailed { .. }
+            | Self::DisputeEvidenceSubmissionFailed { .. }
             | Self::PaymentUnexpectedState { .. }
             | Self::MandateValidationFailed { .. } => StatusCode::BAD_REQUEST, // 400
 
             Self::InternalServerError => StatusCode::INTERNAL_SERVER_ERROR, // 500
+            Self::ServiceUnavailable => StatusCode::SERVICE_UNAVAILABLE,    // 503
             Self::DuplicateRefundRequest | Self::DuplicatePayment { .. } => StatusCode::BAD_REQUEST, // 400
             Self::RefundNotFound
             | Self::CustomerNotFound
@@ -188,8 +219,17 @@ impl actix_web::ResponseError for ApiErrorResponse {
             | Self::MerchantAccountNotFound
             | Self::MerchantConnectorAccountNotFound
             | Self::MandateNotFound
+            | Self::DisputeNotFound
+            | Self::EventNotFound
+            | Self::PayoutNotFound
+            | Self::PaymentLinkNotFound
+            | Self::ExportNotFound
+            | Self::ApiKeyNotFound
+            | Self::BlocklistEntryNotFound
+            | Self::ReconReportNotFound
             | Self::ClientSecretNotGiven
             | Self::ClientSecretInvalid
+            | Self::ClientSecretExpired
             | Self::SuccessfulPaymentNotFound
             | Self::IncorrectConnectorNameGiven
             | Self::ResourceIdNotFound
@@ -197,6 +237,8 @@ impl actix_web::ResponseError for ApiErrorResponse {
             Self::DuplicateMerchantAccount
             | Self::DuplicateMerchantConnectorAccount
             | Self::DuplicatePaymentMethod
+            | Self::DuplicatePayout { .. }
+            | Self::DuplicateBlocklistEntry
             | Self::DuplicateMandate => StatusCode::BAD_REQUEST, // 400
             Self::ReturnUrlUnavailable => StatusCode::SERVICE_UNAVAILABLE, // 503
             Self::PaymentNotSucceeded => StatusCode::BAD_REQUEST,          // 400
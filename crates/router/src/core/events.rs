@@ -0,0 +1,157 @@
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, StorageErrorExt},
+        webhooks,
+    },
+    db::StorageInterface,
+    logger,
+    routes::AppState,
+    services,
+    types::{api::events, storage, transformers::ForeignInto},
+};
+
+const EVENT_LIST_DEFAULT_LIMIT: i64 = 10;
+const EVENT_LIST_MAX_LIMIT: i64 = 100;
+
+#[instrument(skip(db))]
+pub async fn retrieve_event(
+    db: &dyn StorageInterface,
+    merchant_account: storage::MerchantAccount,
+    event_id: String,
+) -> RouterResponse<events::EventResponse> {
+    let event = db
+        .find_event_by_merchant_id_event_id(&merchant_account.merchant_id, &event_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::EventNotFound))?;
+
+    Ok(services::ApplicationResponse::Json(event.foreign_into()))
+}
+
+#[instrument(skip(db))]
+pub async fn list_events(
+    db: &dyn StorageInterface,
+    merchant_account: storage::MerchantAccount,
+    constraints: events::EventListConstraints,
+) -> RouterResponse<events::EventListResponse> {
+    let limit = match constraints.limit {
+        Some(limit) if !(1..=EVENT_LIST_MAX_LIMIT).contains(&limit) => {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("limit should be in between 1 and {EVENT_LIST_MAX_LIMIT}"),
+            })?
+        }
+        Some(limit) => limit,
+        None => EVENT_LIST_DEFAULT_LIMIT,
+    };
+
+    let data = db
+        .filter_events_by_constraints(&merchant_account.merchant_id, &constraints, limit)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to filter events by the given constraints")?
+        .into_iter()
+        .map(ForeignInto::foreign_into)
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(
+        events::EventListResponse { data },
+    ))
+}
+
+#[instrument(skip(db))]
+pub async fn list_event_delivery_attempts(
+    db: &dyn StorageInterface,
+    merchant_account: storage::MerchantAccount,
+    event_id: String,
+) -> RouterResponse<events::WebhookDeliveryAttemptListResponse> {
+    let event = db
+        .find_event_by_merchant_id_event_id(&merchant_account.merchant_id, &event_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::EventNotFound))?;
+
+    let mut attempts = db
+        .find_webhook_delivery_attempts_by_merchant_id_event_id(
+            &merchant_account.merchant_id,
+            &event.event_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve the delivery attempts of the event")?;
+    attempts.sort_by_key(|attempt| attempt.attempt_number);
+
+    Ok(services::ApplicationResponse::Json(
+        events::WebhookDeliveryAttemptListResponse {
+            event_id: event.event_id,
+            data: attempts
+                .into_iter()
+                .map(ForeignInto::foreign_into)
+                .collect(),
+        },
+    ))
+}
+
+/// Resends the webhook of an event to the merchant, regardless of whether earlier deliveries of the
+/// webhook succeeded.
+#[instrument(skip(state))]
+pub async fn retry_event(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    event_id: String,
+) -> RouterResponse<events::EventResponse> {
+    let db = &*state.store;
+    let event = db
+        .find_event_by_merchant_id_event_id(&merchant_account.merchant_id, &event_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::EventNotFound))?;
+
+    let webhook = event.content.clone().ok_or_else(|| {
+        report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The webhook content of the event was not stored, it cannot be resent"
+                .to_string(),
+        })
+    })?;
+
+    let attempt_number = db
+        .find_webhook_delivery_attempts_by_merchant_id_event_id(
+            &merchant_account.merchant_id,
+            &event.event_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve the delivery attempts of the event")?
+        .iter()
+        .map(|attempt| attempt.attempt_number)
+        .max()
+        .unwrap_or_default()
+        + 1;
+
+    let result = webhooks::trigger_webhook_to_merchant(
+        state,
+        &merchant_account,
+        &event.event_id,
+        attempt_number,
+        &webhook,
+    )
+    .await;
+
+    let event = match result {
+        Ok(()) => db
+            .update_event(
+                event.event_id,
+                storage::EventUpdate::UpdateWebhookNotified {
+                    is_webhook_notified: true,
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to mark event as notified")?,
+        Err(error) => {
+            logger::error!(?error, event_id = %event.event_id, attempt_number, "Manual webhook retry failed");
+            event
+        }
+    };
+
+    Ok(services::ApplicationResponse::Json(event.foreign_into()))
+}
@@ -0,0 +1,620 @@
+use std::collections::HashMap;
+
+use common_utils::{
+    crypto::{self, SignMessage, VerifySignature},
+    custom_serde, date_time,
+    types::MinorUnit,
+};
+use error_stack::{report, IntoReport, ResultExt};
+use router_env::{instrument, logger, tracing};
+use serde::Serialize;
+use time::PrimitiveDateTime;
+
+use crate::{
+    configs::settings::ExportSettings,
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        refunds,
+    },
+    db::StorageInterface,
+    routes::AppState,
+    scheduler::utils as pt_utils,
+    services,
+    types::{
+        api::exports,
+        storage::{self, enums},
+        transformers::ForeignInto,
+    },
+    utils::{
+        self,
+        anonymization::{Anonymize, Anonymizer},
+    },
+};
+
+pub(crate) const EXPORT_RUNNER: &str = "EXPORT_WORKFLOW";
+const EXPORT_TASK: &str = "EXPORT";
+/// The number of payments whose attempts are fetched in one query
+const ATTEMPTS_LOOKUP_BATCH_SIZE: usize = 1000;
+
+#[instrument(skip(state))]
+pub async fn create_export(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: exports::ExportCreateRequest,
+) -> RouterResponse<exports::ExportResponse> {
+    let max_time_range_days = state.conf.exports.max_time_range_days;
+
+    utils::when(
+        req.object == api_models::enums::ExportObject::RefundBatch,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Refund batches are exported through their report".to_string(),
+            }))
+        },
+    )?;
+    utils::when(req.start_time >= req.end_time, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "start_time must be earlier than end_time".to_string(),
+        }))
+    })?;
+    utils::when(
+        req.end_time - req.start_time > time::Duration::days(max_time_range_days),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "The time range of an export cannot exceed {} days",
+                    max_time_range_days
+                ),
+            }))
+        },
+    )?;
+
+    let export_new = storage::ExportNew {
+        export_id: utils::generate_id(consts::ID_LENGTH, "exp"),
+        merchant_id: merchant_account.merchant_id,
+        object: req.object.foreign_into(),
+        format: req.format.foreign_into(),
+        start_time: req.start_time,
+        end_time: req.end_time,
+        status: enums::ExportStatus::Pending,
+        batch_id: None,
+        anonymized: req.anonymize,
+    };
+
+    insert_export(state, export_new).await
+}
+
+/// Inserts a pending export and schedules the generation of its file.
+pub(crate) async fn insert_export(
+    state: &AppState,
+    export_new: storage::ExportNew,
+) -> RouterResponse<exports::ExportResponse> {
+    let db = &*state.store;
+    let export = db
+        .insert_export(export_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the export")?;
+
+    add_export_task(db, &export).await?;
+
+    Ok(services::ApplicationResponse::Json(get_export_response(
+        state, export,
+    )?))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_export(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    export_id: String,
+) -> RouterResponse<exports::ExportResponse> {
+    let export = state
+        .store
+        .find_export_by_merchant_id_export_id(&merchant_account.merchant_id, &export_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::ExportNotFound))?;
+
+    Ok(services::ApplicationResponse::Json(get_export_response(
+        state, export,
+    )?))
+}
+
+#[instrument(skip(state))]
+pub async fn download_export(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    export_id: String,
+    params: exports::ExportDownloadParams,
+) -> RouterResponse<()> {
+    let invalid_link_error = || {
+        report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The download link is invalid or has expired".to_string(),
+        })
+    };
+
+    let signature = hex::decode(&params.signature).map_err(|_| invalid_link_error())?;
+    let message =
+        get_download_message(&merchant_account.merchant_id, &export_id, params.expires_at);
+    let is_signature_valid = crypto::HmacSha256
+        .verify_signature(
+            state.conf.secrets.export_signing_key.as_bytes(),
+            &signature,
+            message.as_bytes(),
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to verify the export download signature")?;
+    let is_expired = params.expires_at < date_time::now_unix_timestamp();
+    utils::when(!is_signature_valid || is_expired, || {
+        Err(invalid_link_error())
+    })?;
+
+    let export = state
+        .store
+        .find_export_by_merchant_id_export_id(&merchant_account.merchant_id, &export_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::ExportNotFound))?;
+    let content = export
+        .file_content
+        .ok_or(errors::ApiErrorResponse::ExportNotFound)
+        .into_report()
+        .attach_printable("The export does not have a generated file")?;
+
+    let (extension, content_type) = match export.format {
+        enums::ExportFormat::Csv => ("csv", "text/csv"),
+        enums::ExportFormat::Json => ("json", "application/json"),
+    };
+    Ok(services::ApplicationResponse::FileData(
+        services::FileData {
+            file_name: format!("{}.{}", export.export_id, extension),
+            content_type,
+            content,
+        },
+    ))
+}
+
+/// Generates the file of a pending export. Failures to generate the file are recorded on the
+/// export instead of being retried, so that the merchant can request a new export.
+#[instrument(skip_all, fields(export_id = %export.export_id))]
+pub async fn generate_export(
+    state: &AppState,
+    export: storage::Export,
+) -> RouterResult<storage::Export> {
+    let db = &*state.store;
+    let anonymizer = export
+        .anonymized
+        .then(|| Anonymizer::new(&state.conf.secrets.anonymization_key));
+    let export_file =
+        build_export_file(db, &state.conf.exports, &export, anonymizer.as_ref()).await;
+    let update = match export_file {
+        Ok((row_count, file_content)) => storage::ExportUpdate::SucceededUpdate {
+            row_count,
+            file_content,
+        },
+        Err(error) => {
+            logger::error!(?error, "Failed to generate the export");
+            let error_message = match error.current_context() {
+                errors::ApiErrorResponse::PreconditionFailed { message } => message.clone(),
+                _ => "The export could not be generated".to_string(),
+            };
+            storage::ExportUpdate::FailedUpdate { error_message }
+        }
+    };
+
+    db.update_export(export, update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the export")
+}
+
+async fn add_export_task(db: &dyn StorageInterface, export: &storage::Export) -> RouterResult<()> {
+    let tracking_data = storage::ExportTrackingData {
+        merchant_id: export.merchant_id.clone(),
+        export_id: export.export_id.clone(),
+    };
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        EXPORT_RUNNER,
+        EXPORT_TASK,
+        &export.export_id,
+        &export.merchant_id,
+    );
+    let process_tracker_entry =
+        <storage::ProcessTracker as storage::ProcessTrackerExt>::make_process_tracker_new(
+            process_tracker_id,
+            EXPORT_TASK,
+            EXPORT_RUNNER,
+            tracking_data,
+            date_time::now(),
+        )
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to construct the export task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting task in process_tracker: export_id: {}",
+                export.export_id
+            )
+        })?;
+    Ok(())
+}
+
+/// Fetches the records of the export and serializes them in the requested format, returning the
+/// number of records and the file content. The records are anonymized when an anonymizer is given.
+async fn build_export_file(
+    db: &dyn StorageInterface,
+    exports_config: &ExportSettings,
+    export: &storage::Export,
+    anonymizer: Option<&Anonymizer>,
+) -> RouterResult<(i32, String)> {
+    // One record more than the maximum is fetched to detect exports exceeding the maximum
+    let limit = exports_config.max_rows.saturating_add(1);
+    let (merchant_id, start, end) = (&export.merchant_id, export.start_time, export.end_time);
+
+    let (row_count, file_content) = match export.object {
+        enums::ExportObject::Payments => {
+            let mut records = db
+                .find_payment_intents_by_merchant_id_created_between(merchant_id, start, end, limit)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+            records.sort_by_key(|record| record.created_at);
+            let mut card_details = find_card_details(db, merchant_id, &records, anonymizer).await?;
+            let rows: Vec<_> = records
+                .into_iter()
+                .map(|record| {
+                    let card_details = card_details.remove(&record.payment_id);
+                    PaymentExportRow::from((anonymize(record, anonymizer), card_details))
+                })
+                .collect();
+            (rows.len(), serialize_rows(&rows, export.format)?)
+        }
+        enums::ExportObject::Refunds => {
+            let mut records = db
+                .find_refunds_by_merchant_id_created_between(merchant_id, start, end, limit)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+            records.sort_by_key(|record| record.created_at);
+            let rows: Vec<_> = records
+                .into_iter()
+                .map(|record| RefundExportRow::from(anonymize(record, anonymizer)))
+                .collect();
+            (rows.len(), serialize_rows(&rows, export.format)?)
+        }
+        enums::ExportObject::Disputes => {
+            let mut records = db
+                .find_disputes_by_merchant_id_created_between(merchant_id, start, end, limit)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+            records.sort_by_key(|record| record.created_at);
+            let rows: Vec<_> = records.into_iter().map(DisputeExportRow::from).collect();
+            (rows.len(), serialize_rows(&rows, export.format)?)
+        }
+        enums::ExportObject::RefundBatch => {
+            let batch_id = export
+                .batch_id
+                .as_deref()
+                .ok_or(errors::ApiErrorResponse::InternalServerError)
+                .into_report()
+                .attach_printable("The refund batch export does not have a batch_id")?;
+            let (_, refund_batch) = refunds::find_refund_batch(db, merchant_id, batch_id).await?;
+            let rows: Vec<_> = refund_batch
+                .rows
+                .into_iter()
+                .enumerate()
+                .map(RefundBatchExportRow::from)
+                .collect();
+            (rows.len(), serialize_rows(&rows, export.format)?)
+        }
+    };
+
+    let row_count = i32::try_from(row_count)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    utils::when(i64::from(row_count) > exports_config.max_rows, || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "The export exceeds {} records, request a shorter time range",
+                exports_config.max_rows
+            ),
+        }))
+    })?;
+
+    Ok((row_count, file_content))
+}
+
+fn anonymize<T: Anonymize>(record: T, anonymizer: Option<&Anonymizer>) -> T {
+    match anonymizer {
+        Some(anonymizer) => record.anonymize(anonymizer),
+        None => record,
+    }
+}
+
+/// Finds the BIN and the last four digits of the card of the payments, from the latest attempt of
+/// each payment which was made with a card.
+async fn find_card_details(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    payment_intents: &[storage::PaymentIntent],
+    anonymizer: Option<&Anonymizer>,
+) -> RouterResult<HashMap<String, CardDetails>> {
+    let mut card_details = HashMap::new();
+    for payment_intents in payment_intents.chunks(ATTEMPTS_LOOKUP_BATCH_SIZE) {
+        let payment_ids = payment_intents
+            .iter()
+            .map(|payment_intent| payment_intent.payment_id.clone())
+            .collect();
+        let mut attempts = db
+            .find_payment_attempts_by_merchant_id_payment_ids(merchant_id, payment_ids)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find the attempts of the exported payments")?;
+        // Later attempts replace the card details of earlier attempts of the same payment
+        attempts.sort_by_key(|attempt| attempt.created_at);
+        for attempt in attempts
+            .into_iter()
+            .filter(|attempt| attempt.card_last4.is_some())
+        {
+            let attempt = anonymize(attempt, anonymizer);
+            card_details.insert(
+                attempt.payment_id,
+                CardDetails {
+                    card_bin: attempt.card_bin,
+                    card_last4: attempt.card_last4,
+                },
+            );
+        }
+    }
+    Ok(card_details)
+}
+
+fn serialize_rows<T: Serialize>(rows: &[T], format: enums::ExportFormat) -> RouterResult<String> {
+    match format {
+        enums::ExportFormat::Csv => {
+            let mut writer = csv::Writer::from_writer(vec![]);
+            for row in rows {
+                writer
+                    .serialize(row)
+                    .into_report()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to serialize an export row to CSV")?;
+            }
+            let content = writer
+                .into_inner()
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+            String::from_utf8(content)
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+        }
+        enums::ExportFormat::Json => serde_json::to_string(rows)
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize the export rows to JSON"),
+    }
+}
+
+fn get_export_response(
+    state: &AppState,
+    export: storage::Export,
+) -> RouterResult<exports::ExportResponse> {
+    let (download_url, download_url_expires_at) = match export.status {
+        enums::ExportStatus::Succeeded => {
+            let (url, expires_at) = get_download_url(state, &export)?;
+            (Some(url), Some(expires_at))
+        }
+        enums::ExportStatus::Pending | enums::ExportStatus::Failed => (None, None),
+    };
+
+    Ok(exports::ExportResponse {
+        export_id: export.export_id,
+        object: export.object.foreign_into(),
+        format: export.format.foreign_into(),
+        start_time: export.start_time,
+        end_time: export.end_time,
+        status: export.status.foreign_into(),
+        row_count: export.row_count,
+        download_url,
+        download_url_expires_at,
+        error_message: export.error_message,
+        batch_id: export.batch_id,
+        anonymized: export.anonymized,
+        created_at: export.created_at,
+    })
+}
+
+/// Issues a download URL for the export, signed with the export signing key so that it can be
+/// used without authentication until it expires.
+fn get_download_url(
+    state: &AppState,
+    export: &storage::Export,
+) -> RouterResult<(String, PrimitiveDateTime)> {
+    let expires_at = date_time::now().saturating_add(time::Duration::seconds(
+        state.conf.exports.download_url_validity,
+    ));
+    let expires_at_unix = expires_at.assume_utc().unix_timestamp();
+    let message = get_download_message(&export.merchant_id, &export.export_id, expires_at_unix);
+    let signature = crypto::HmacSha256
+        .sign_message(
+            state.conf.secrets.export_signing_key.as_bytes(),
+            message.as_bytes(),
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to sign the export download URL")?;
+
+    let url = format!(
+        "{}/exports/{}/{}/download?expires_at={}&signature={}",
+        state.conf.server.base_url,
+        export.merchant_id,
+        export.export_id,
+        expires_at_unix,
+        hex::encode(signature)
+    );
+    Ok((url, expires_at))
+}
+
+fn get_download_message(merchant_id: &str, export_id: &str, expires_at: i64) -> String {
+    format!("{merchant_id}.{export_id}.{expires_at}")
+}
+
+#[derive(Serialize)]
+struct PaymentExportRow {
+    payment_id: String,
+    status: enums::IntentStatus,
+    amount: MinorUnit,
+    currency: Option<enums::Currency>,
+    amount_captured: Option<MinorUnit>,
+    customer_id: Option<String>,
+    description: Option<String>,
+    connector: Option<String>,
+    business_country: Option<String>,
+    business_label: Option<String>,
+    /// The BIN of the card, or its pseudonym in anonymized exports
+    card_bin: Option<String>,
+    /// The last four digits of the card, or their pseudonym in anonymized exports
+    card_last4: Option<String>,
+    #[serde(with = "custom_serde::iso8601")]
+    created_at: PrimitiveDateTime,
+    #[serde(with = "custom_serde::iso8601")]
+    modified_at: PrimitiveDateTime,
+}
+
+struct CardDetails {
+    card_bin: Option<String>,
+    card_last4: Option<String>,
+}
+
+impl From<(storage::PaymentIntent, Option<CardDetails>)> for PaymentExportRow {
+    fn from((payment_intent, card_details): (storage::PaymentIntent, Option<CardDetails>)) -> Self {
+        let (card_bin, card_last4) = card_details
+            .map(|card_details| (card_details.card_bin, card_details.card_last4))
+            .unwrap_or_default();
+        Self {
+            payment_id: payment_intent.payment_id,
+            status: payment_intent.status,
+            amount: payment_intent.amount,
+            currency: payment_intent.currency,
+            amount_captured: payment_intent.amount_captured,
+            customer_id: payment_intent.customer_id,
+            description: payment_intent.description,
+            connector: payment_intent.connector_id,
+            business_country: payment_intent.business_country,
+            business_label: payment_intent.business_label,
+            card_bin,
+            card_last4,
+            created_at: payment_intent.created_at,
+            modified_at: payment_intent.modified_at,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct RefundExportRow {
+    refund_id: String,
+    payment_id: String,
+    connector: String,
+    connector_refund_id: Option<String>,
+    refund_type: enums::RefundType,
+    total_amount: i64,
+    refund_amount: i64,
+    currency: enums::Currency,
+    refund_status: enums::RefundStatus,
+    refund_reason: Option<String>,
+    refund_error_message: Option<String>,
+    #[serde(with = "custom_serde::iso8601")]
+    created_at: PrimitiveDateTime,
+    #[serde(with = "custom_serde::iso8601")]
+    updated_at: PrimitiveDateTime,
+}
+
+impl From<storage::Refund> for RefundExportRow {
+    fn from(refund: storage::Refund) -> Self {
+        Self {
+            refund_id: refund.refund_id,
+            payment_id: refund.payment_id,
+            connector: refund.connector,
+            connector_refund_id: refund.connector_refund_id,
+            refund_type: refund.refund_type,
+            total_amount: refund.total_amount,
+            refund_amount: refund.refund_amount,
+            currency: refund.currency,
+            refund_status: refund.refund_status,
+            refund_reason: refund.refund_reason,
+            refund_error_message: refund.refund_error_message,
+            created_at: refund.created_at,
+            updated_at: refund.updated_at,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct DisputeExportRow {
+    dispute_id: String,
+    payment_id: String,
+    attempt_id: String,
+    connector: String,
+    connector_dispute_id: String,
+    amount: i64,
+    currency: String,
+    dispute_status: enums::DisputeStatus,
+    connector_status: String,
+    connector_reason: Option<String>,
+    connector_reason_code: Option<String>,
+    challenge_status: Option<enums::DisputeChallengeStatus>,
+    #[serde(with = "custom_serde::iso8601::option")]
+    challenge_required_by: Option<PrimitiveDateTime>,
+    #[serde(with = "custom_serde::iso8601")]
+    created_at: PrimitiveDateTime,
+    #[serde(with = "custom_serde::iso8601")]
+    modified_at: PrimitiveDateTime,
+}
+
+impl From<storage::Dispute> for DisputeExportRow {
+    fn from(dispute: storage::Dispute) -> Self {
+        Self {
+            dispute_id: dispute.dispute_id,
+            payment_id: dispute.payment_id,
+            attempt_id: dispute.attempt_id,
+            connector: dispute.connector,
+            connector_dispute_id: dispute.connector_dispute_id,
+            amount: dispute.amount,
+            currency: dispute.currency,
+            dispute_status: dispute.dispute_status,
+            connector_status: dispute.connector_status,
+            connector_reason: dispute.connector_reason,
+            connector_reason_code: dispute.connector_reason_code,
+            challenge_status: dispute.challenge_status,
+            challenge_required_by: dispute.challenge_required_by,
+            created_at: dispute.created_at,
+            modified_at: dispute.modified_at,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct RefundBatchExportRow {
+    row: usize,
+    payment_id: String,
+    refund_id: Option<String>,
+    /// Whether the row has been processed, rows which are not processed yet have no outcome
+    processed: bool,
+    status: Option<api_models::refunds::RefundStatus>,
+    error_message: Option<String>,
+}
+
+impl From<(usize, storage::RefundBatchRow)> for RefundBatchExportRow {
+    fn from((row, refund_batch_row): (usize, storage::RefundBatchRow)) -> Self {
+        let result = refund_batch_row.result;
+        Self {
+            row,
+            payment_id: refund_batch_row.request.payment_id,
+            processed: result.is_some(),
+            refund_id: result.as_ref().and_then(|result| result.refund_id.clone()),
+            status: result.as_ref().and_then(|result| result.status.clone()),
+            error_message: result.and_then(|result| result.error_message),
+        }
+    }
+}
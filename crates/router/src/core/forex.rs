@@ -0,0 +1,230 @@
+use std::collections::HashMap;
+
+use error_stack::{report, IntoReport, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResult},
+        payments::PaymentData,
+    },
+    headers,
+    routes::AppState,
+    services,
+    types::storage::enums,
+    utils::{self, BytesExt},
+};
+
+/// The amount of a payment converted to the currency in which the merchant settles it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SettlementDetails {
+    pub currency: enums::Currency,
+    pub amount: i64,
+    pub fx_rate: f64,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ExchangeRatesResponse {
+    rates: HashMap<String, f64>,
+}
+
+/// Converts the amount of a payment to its settlement currency, with the current exchange rate of
+/// the currency pair.
+///
+/// Returns `None` if the payment is settled in its own currency.
+#[instrument(skip(state))]
+pub async fn get_settlement_details(
+    state: &AppState,
+    amount: i64,
+    currency: enums::Currency,
+    settlement_currency: Option<enums::Currency>,
+) -> RouterResult<Option<SettlementDetails>> {
+    let settlement_currency = match settlement_currency {
+        Some(settlement_currency) if settlement_currency != currency => settlement_currency,
+        _ => return Ok(None),
+    };
+
+    let fx_rate = get_exchange_rate(state, currency, settlement_currency).await?;
+    let amount = convert_amount(amount, currency, settlement_currency, fx_rate)?;
+
+    Ok(Some(SettlementDetails {
+        currency: settlement_currency,
+        amount,
+        fx_rate,
+    }))
+}
+
+async fn get_exchange_rate(
+    state: &AppState,
+    from: enums::Currency,
+    to: enums::Currency,
+) -> RouterResult<f64> {
+    utils::when(state.conf.forex.base_url.is_empty(), || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Settling payments in another currency is not enabled".to_string(),
+        }))
+    })?;
+
+    state
+        .forex_rates
+        .get_or_populate(&format!("{from}_{to}"), || {
+            fetch_exchange_rate(state, from, to)
+        })
+        .await
+}
+
+async fn fetch_exchange_rate(
+    state: &AppState,
+    from: enums::Currency,
+    to: enums::Currency,
+) -> RouterResult<f64> {
+    let forex = &state.conf.forex;
+    let url = format!("{}latest?base={from}&symbols={to}", forex.base_url);
+    let mut request = services::Request::new(services::Method::Get, &url);
+    if !forex.api_key.is_empty() {
+        request.add_header(headers::AUTHORIZATION, &format!("Bearer {}", forex.api_key));
+    }
+
+    let response = services::call_connector_api(state, request)
+        .await
+        .change_context(errors::ApiErrorResponse::ServiceUnavailable)
+        .attach_printable("Failed to call the exchange rate provider")?
+        .map_err(|error_response| {
+            logger::error!(?error_response, "Exchange rate provider returned an error");
+            report!(errors::ApiErrorResponse::ServiceUnavailable)
+        })?;
+    let response: ExchangeRatesResponse = response
+        .response
+        .parse_struct("ExchangeRatesResponse")
+        .change_context(errors::ApiErrorResponse::ServiceUnavailable)
+        .attach_printable("Failed to parse the response of the exchange rate provider")?;
+
+    let fx_rate = response.rates.get(&to.to_string()).copied();
+    match fx_rate {
+        Some(fx_rate) if fx_rate.is_finite() && fx_rate > 0.0 => Ok(fx_rate),
+        _ => {
+            Err(report!(errors::ApiErrorResponse::ServiceUnavailable)).attach_printable_lazy(|| {
+                format!("Exchange rate provider returned no valid rate for {from} to {to}")
+            })
+        }
+    }
+}
+
+/// Converts an amount in the lowest denomination of a currency to the lowest denomination of
+/// another currency, accounting for the currencies having a different number of minor units.
+#[allow(clippy::as_conversions)]
+fn convert_amount(
+    amount: i64,
+    from: enums::Currency,
+    to: enums::Currency,
+    fx_rate: f64,
+) -> RouterResult<i64> {
+    let exponent = i32::from(to.number_of_digits_after_decimal_point())
+        - i32::from(from.number_of_digits_after_decimal_point());
+    let converted = (amount as f64 * fx_rate * 10_f64.powi(exponent)).round();
+
+    if converted.is_finite() && converted >= i64::MIN as f64 && converted <= i64::MAX as f64 {
+        Ok(converted as i64)
+    } else {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("The amount cannot be converted from {from} to {to}"),
+        }))
+    }
+}
+
+/// Converts a part of the amount of a payment, such as a capture or a refund, to the settlement
+/// currency with the rate recorded when the payment was converted, so that all amounts of the
+/// payment are converted consistently even when the exchange rate has since changed.
+pub fn convert_with_recorded_rate(
+    amount: i64,
+    payment_amount: i64,
+    settlement_amount: i64,
+) -> RouterResult<i64> {
+    utils::when(payment_amount <= 0, || {
+        Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Cannot convert the amount of a payment with no amount")
+    })?;
+
+    // Rounded to the nearest minor unit
+    let (amount, payment_amount, settlement_amount) = (
+        i128::from(amount),
+        i128::from(payment_amount),
+        i128::from(settlement_amount),
+    );
+    let converted = (2 * amount * settlement_amount + payment_amount) / (2 * payment_amount);
+
+    i64::try_from(converted)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Converted amount does not fit in 64 bits")
+}
+
+/// Returns the payment data with its amounts converted to the settlement currency of the payment,
+/// if any, which are the amounts that the connector is requested to process.
+pub fn with_settlement_amounts<F: Clone>(
+    mut payment_data: PaymentData<F>,
+) -> RouterResult<PaymentData<F>> {
+    let payment_attempt = &payment_data.payment_attempt;
+    let (settlement_currency, settlement_amount) = match (
+        payment_attempt.settlement_currency,
+        payment_attempt.settlement_amount,
+    ) {
+        (Some(settlement_currency), Some(settlement_amount)) => {
+            (settlement_currency, settlement_amount)
+        }
+        _ => return Ok(payment_data),
+    };
+    let payment_amount = payment_attempt.amount;
+
+    let amount = convert_with_recorded_rate(
+        payment_data.amount.into(),
+        payment_amount,
+        settlement_amount,
+    )?;
+    let amount_to_capture = payment_attempt
+        .amount_to_capture
+        .map(|amount_to_capture| {
+            convert_with_recorded_rate(amount_to_capture, payment_amount, settlement_amount)
+        })
+        .transpose()?;
+
+    payment_data.amount = amount.into();
+    payment_data.currency = settlement_currency;
+    payment_data.payment_attempt.amount_to_capture = amount_to_capture;
+    Ok(payment_data)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_convert_amount_between_currencies_with_same_minor_units() {
+        let amount = convert_amount(6000, enums::Currency::USD, enums::Currency::EUR, 0.92);
+        assert_eq!(amount.unwrap(), 5520);
+    }
+
+    #[test]
+    fn test_convert_amount_between_currencies_with_different_minor_units() {
+        let amount = convert_amount(1000, enums::Currency::USD, enums::Currency::JPY, 149.5);
+        assert_eq!(amount.unwrap(), 1495);
+
+        let amount = convert_amount(1495, enums::Currency::JPY, enums::Currency::KWD, 0.00206);
+        assert_eq!(amount.unwrap(), 3080);
+    }
+
+    #[test]
+    fn test_convert_amount_out_of_range() {
+        let amount = convert_amount(i64::MAX, enums::Currency::USD, enums::Currency::JPY, 150.0);
+        assert!(amount.is_err());
+    }
+
+    #[test]
+    fn test_convert_with_recorded_rate() {
+        assert_eq!(convert_with_recorded_rate(6000, 6000, 5520).unwrap(), 5520);
+        assert_eq!(convert_with_recorded_rate(1000, 6000, 5520).unwrap(), 920);
+        assert_eq!(convert_with_recorded_rate(1, 3, 2).unwrap(), 1);
+        assert!(convert_with_recorded_rate(1000, 0, 5520).is_err());
+    }
+}
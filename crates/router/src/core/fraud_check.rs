@@ -0,0 +1,220 @@
+use std::fmt::Debug;
+
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::{
+        errors::{self, ConnectorErrorExt, RouterResult},
+        payments, utils as core_utils,
+    },
+    routes::AppState,
+    services,
+    types::{
+        self, api,
+        storage::{self, enums},
+    },
+};
+
+/// The configuration of the fraud and risk management service of the merchant, which is stored in
+/// the metadata of its merchant connector account.
+#[derive(Debug, Default, serde::Deserialize)]
+#[serde(default)]
+struct FrmConfig {
+    /// Whether payments are screened before or after they are authorized by the connector
+    frm_flow: types::FrmFlow,
+}
+
+/// The fraud and risk management service which the merchant has configured.
+pub struct FrmConfiguration {
+    merchant_connector_account: storage::MerchantConnectorAccount,
+    pub flow: types::FrmFlow,
+}
+
+/// Fetches the fraud and risk management service which the merchant has configured as their FRM
+/// processor, if any.
+#[instrument(skip_all)]
+pub async fn get_frm_configuration(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+) -> RouterResult<Option<FrmConfiguration>> {
+    let merchant_connector_account = state
+        .store
+        .find_merchant_connector_account_by_merchant_id_list(&merchant_account.merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the merchant connector accounts")?
+        .into_iter()
+        .find(|merchant_connector_account| {
+            merchant_connector_account.connector_type == enums::ConnectorType::FrmProcessor
+                && !merchant_connector_account.disabled.unwrap_or(false)
+        });
+    let merchant_connector_account = match merchant_connector_account {
+        Some(merchant_connector_account) => merchant_connector_account,
+        None => return Ok(None),
+    };
+
+    let config: FrmConfig = merchant_connector_account
+        .metadata
+        .clone()
+        .map(|metadata| metadata.parse_value("FrmConfig"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "metadata",
+        })
+        .attach_printable("Failed to parse the configuration of the FRM processor")?
+        .unwrap_or_default();
+
+    Ok(Some(FrmConfiguration {
+        merchant_connector_account,
+        flow: config.frm_flow,
+    }))
+}
+
+/// Screens the payment with the fraud and risk management service of the merchant, before or
+/// after it has been authorized depending on the configured flow.
+///
+/// Returns `None` if the service could not be reached or failed to screen the payment, in which
+/// case the payment proceeds as if it had not been screened.
+#[instrument(skip_all)]
+pub async fn perform_fraud_check(
+    state: &AppState,
+    frm_configuration: &FrmConfiguration,
+    router_data: &types::PaymentsAuthorizeRouterData,
+) -> RouterResult<Option<types::FrmData>> {
+    let merchant_connector_account = &frm_configuration.merchant_connector_account;
+    let connector = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &merchant_connector_account.connector_name,
+        api::GetToken::Connector,
+    )?;
+    let connector_auth_type =
+        core_utils::get_connector_auth_type(state, merchant_connector_account).await?;
+
+    let (connector_transaction_id, payment_status) = match frm_configuration.flow {
+        types::FrmFlow::Pre => (None, None),
+        types::FrmFlow::Post => (
+            router_data
+                .response
+                .as_ref()
+                .ok()
+                .and_then(|response| match response {
+                    types::PaymentsResponseData::TransactionResponse { resource_id, .. } => {
+                        resource_id.get_connector_transaction_id().ok()
+                    }
+                    _ => None,
+                }),
+            Some(router_data.status),
+        ),
+    };
+    let request = types::FraudCheckRequestData {
+        amount: router_data.request.amount,
+        currency: router_data.request.currency,
+        payment_method_data: router_data.request.payment_method_data.clone(),
+        email: router_data.request.email.clone(),
+        browser_info: router_data.request.browser_info.clone(),
+        order_details: router_data.request.order_details.clone(),
+        payment_connector: router_data.connector.clone(),
+        connector_transaction_id,
+        payment_status,
+    };
+
+    let response = match frm_configuration.flow {
+        types::FrmFlow::Pre => {
+            let connector_integration: services::BoxedConnectorIntegration<
+                '_,
+                api::FraudCheckPre,
+                types::FraudCheckRequestData,
+                types::FraudCheckResponseData,
+            > = connector.connector.get_connector_integration();
+            call_frm_connector(
+                state,
+                connector_integration,
+                merchant_connector_account,
+                connector_auth_type,
+                router_data,
+                request,
+            )
+            .await?
+        }
+        types::FrmFlow::Post => {
+            let connector_integration: services::BoxedConnectorIntegration<
+                '_,
+                api::FraudCheckPost,
+                types::FraudCheckRequestData,
+                types::FraudCheckResponseData,
+            > = connector.connector.get_connector_integration();
+            call_frm_connector(
+                state,
+                connector_integration,
+                merchant_connector_account,
+                connector_auth_type,
+                router_data,
+                request,
+            )
+            .await?
+        }
+    };
+
+    // Payments are not blocked when the service is unavailable, so that an outage of the service
+    // does not prevent the merchant from accepting payments
+    Ok(match response {
+        Ok(response) => Some(types::FrmData {
+            frm_connector: merchant_connector_account.connector_name.clone(),
+            flow: frm_configuration.flow,
+            decision: response.decision,
+            frm_transaction_id: response.frm_transaction_id,
+            score: response.score,
+            reason: response.reason,
+        }),
+        Err(error_response) => {
+            logger::warn!(
+                frm_connector = %merchant_connector_account.connector_name,
+                ?error_response,
+                "Failed to screen the payment with the FRM processor"
+            );
+            None
+        }
+    })
+}
+
+async fn call_frm_connector<F>(
+    state: &AppState,
+    connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        F,
+        types::FraudCheckRequestData,
+        types::FraudCheckResponseData,
+    >,
+    merchant_connector_account: &storage::MerchantConnectorAccount,
+    connector_auth_type: types::ConnectorAuthType,
+    router_data: &types::PaymentsAuthorizeRouterData,
+    request: types::FraudCheckRequestData,
+) -> RouterResult<Result<types::FraudCheckResponseData, types::ErrorResponse>>
+where
+    F: Clone + Debug + 'static,
+{
+    let frm_router_data: types::FraudCheckRouterData<F> = types::RouterData {
+        connector: merchant_connector_account.connector_name.clone(),
+        connector_auth_type,
+        connector_meta_data: merchant_connector_account.metadata.clone(),
+        test_mode: merchant_connector_account.test_mode,
+        ..payments::helpers::router_data_type_conversion(
+            router_data,
+            request,
+            Err(types::ErrorResponse::default()),
+        )
+    };
+
+    let response = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &frm_router_data,
+        payments::CallConnectorAction::Trigger,
+    )
+    .await
+    .map_err(|error| error.to_payment_failed_response())?;
+
+    Ok(response.response)
+}
@@ -0,0 +1,124 @@
+use std::{
+    future::Future,
+    time::{Duration, Instant},
+};
+
+use api_models::health_check::{ComponentHealth, ConnectionPoolHealth, RouterHealthCheckResponse};
+use error_stack::{IntoReport, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::errors::{self, CustomResult},
+    db::{health_check::ConnectionPoolState, StorageInterface},
+    routes::AppState,
+    services,
+};
+
+/// Checks that the databases and Redis can serve queries, along with the state of the connection
+/// pools used to reach them. Returns whether all of them are healthy, along with the report.
+#[instrument(skip_all)]
+pub async fn deep_health_check(db: &dyn StorageInterface) -> (bool, RouterHealthCheckResponse) {
+    let connection_pool_states = db.connection_pool_states();
+
+    let (master_database, replica_database, redis) = futures::join!(
+        check_component(
+            db.health_check_master_db(),
+            connection_pool_states.master_database
+        ),
+        check_component(
+            db.health_check_replica_db(),
+            connection_pool_states.replica_database
+        ),
+        check_component(db.health_check_redis(), Some(connection_pool_states.redis)),
+    );
+
+    let healthy = master_database.healthy && replica_database.healthy && redis.healthy;
+    (
+        healthy,
+        RouterHealthCheckResponse {
+            master_database,
+            replica_database,
+            redis,
+            canary_connector: None,
+        },
+    )
+}
+
+/// Checks whether the router is ready to serve requests: the checks of [`deep_health_check`],
+/// along with a call to the canary connector, if one is configured.
+#[instrument(skip_all)]
+pub async fn readiness_check(state: &AppState) -> (bool, RouterHealthCheckResponse) {
+    let settings = &state.conf.health_check;
+    let canary_connector = async {
+        match &settings.canary_connector_url {
+            Some(url) => Some(
+                check_component(
+                    call_canary_connector(state, url, settings.canary_timeout),
+                    None,
+                )
+                .await,
+            ),
+            None => None,
+        }
+    };
+
+    let ((healthy, mut response), canary_connector) =
+        futures::join!(deep_health_check(&*state.store), canary_connector);
+
+    let healthy = healthy
+        && canary_connector
+            .as_ref()
+            .map_or(true, |canary_connector| canary_connector.healthy);
+    response.canary_connector = canary_connector;
+    (healthy, response)
+}
+
+async fn call_canary_connector(
+    state: &AppState,
+    url: &str,
+    timeout: u64,
+) -> CustomResult<(), errors::ApiClientError> {
+    let request = services::RequestBuilder::new()
+        .method(services::Method::Get)
+        .url(url)
+        .build();
+
+    tokio::time::timeout(
+        Duration::from_millis(timeout),
+        services::call_connector_api(state, request),
+    )
+    .await
+    .into_report()
+    .change_context(errors::ApiClientError::RequestTimeoutReceived)?
+    .map(|_| ())
+}
+
+async fn check_component<F, E>(
+    health_check: F,
+    pool_state: Option<ConnectionPoolState>,
+) -> ComponentHealth
+where
+    F: Future<Output = CustomResult<(), E>>,
+    E: error_stack::Context,
+{
+    let start = Instant::now();
+    let result = health_check.await;
+    let latency_ms = u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX);
+
+    let error = result.err().map(|error| {
+        logger::error!(?error, "Health check failed");
+        error.current_context().to_string()
+    });
+
+    ComponentHealth {
+        healthy: error.is_none(),
+        error,
+        latency_ms,
+        pool: pool_state.map(|state| ConnectionPoolHealth {
+            max_connections: state.max_connections,
+            open_connections: state.open_connections,
+            idle_connections: state.idle_connections,
+            exhausted: state.is_exhausted(),
+        }),
+    }
+}
@@ -4,8 +4,10 @@ use storage_models::enums as storage_enums;
 
 use super::payments::helpers;
 use crate::{
-    core::errors::{self, RouterResponse, StorageErrorExt},
-    db::StorageInterface,
+    core::{
+        errors::{self, RouterResponse, StorageErrorExt},
+        webhooks,
+    },
     routes::AppState,
     services,
     types::{
@@ -35,13 +37,14 @@ pub async fn get_mandate(
     ))
 }
 
-#[instrument(skip(db))]
+#[instrument(skip(state))]
 pub async fn revoke_mandate(
-    db: &dyn StorageInterface,
+    state: &AppState,
     merchant_account: storage::MerchantAccount,
     req: mandates::MandateId,
 ) -> RouterResponse<mandates::MandateRevokedResponse> {
-    let mandate = db
+    let mandate = state
+        .store
         .update_mandate_by_merchant_id_mandate_id(
             &merchant_account.merchant_id,
             &req.mandate_id,
@@ -51,13 +54,13 @@ pub async fn revoke_mandate(
         )
         .await
         .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::MandateNotFound))?;
+    let response = mandates::MandateRevokedResponse {
+        mandate_id: mandate.mandate_id.clone(),
+        status: mandate.mandate_status.foreign_into(),
+    };
+    trigger_mandate_outgoing_webhook(state, &merchant_account, mandate).await;
 
-    Ok(services::ApplicationResponse::Json(
-        mandates::MandateRevokedResponse {
-            mandate_id: mandate.mandate_id,
-            status: mandate.mandate_status.foreign_into(),
-        },
-    ))
+    Ok(services::ApplicationResponse::Json(response))
 }
 
 #[instrument(skip(state))]
@@ -136,6 +139,11 @@ where
                     .change_context(errors::ApiErrorResponse::MandateNotFound),
             }?;
 
+            // A single use mandate is revoked once it has been used
+            if mandate.mandate_status == storage_enums::MandateStatus::Revoked {
+                trigger_mandate_outgoing_webhook(state, merchant_account, mandate.clone()).await;
+            }
+
             resp.payment_method_id = Some(mandate.payment_method_id);
         }
         None => {
@@ -151,13 +159,7 @@ where
                 .payment_method_id;
 
                 resp.payment_method_id = Some(payment_method_id.clone());
-                let mandate_reference = match resp.response.as_ref().ok() {
-                    Some(types::PaymentsResponseData::TransactionResponse {
-                        mandate_reference,
-                        ..
-                    }) => mandate_reference.clone(),
-                    _ => None,
-                };
+                let mandate_reference = get_mandate_reference(&resp);
 
                 if let Some(new_mandate_data) = helpers::generate_mandate(
                     resp.merchant_id.clone(),
@@ -168,30 +170,36 @@ where
                     mandate_reference,
                 ) {
                     logger::error!("{:?}", new_mandate_data);
-                    resp.request
-                        .set_mandate_id(api_models::payments::MandateIds {
-                            mandate_id: new_mandate_data.mandate_id.clone(),
-                            connector_mandate_id: new_mandate_data.connector_mandate_id.clone(),
-                        });
-                    state
-                        .store
-                        .insert_mandate(new_mandate_data)
-                        .await
-                        .map_err(|err| {
-                            err.to_duplicate_response(
-                                errors::ApiErrorResponse::DuplicateRefundRequest,
-                            )
-                        })?;
+                    insert_mandate(state, merchant_account, &mut resp, new_mandate_data).await?;
                 };
-            } else if resp.request.get_setup_future_usage().is_some() {
-                helpers::call_payment_method(
+            } else if let Some(setup_future_usage) = resp.request.get_setup_future_usage() {
+                let payment_method_id = helpers::call_payment_method(
                     state,
                     merchant_account,
                     Some(&resp.request.get_payment_method_data()),
                     Some(resp.payment_method),
                     maybe_customer,
                 )
-                .await?;
+                .await?
+                .payment_method_id;
+
+                // The connector may return a mandate reference for payments set up for future
+                // off-session usage, which can be used for charging the customer later on
+                if setup_future_usage == storage_enums::FutureUsage::OffSession {
+                    let mandate_reference = get_mandate_reference(&resp);
+
+                    if let Some(new_mandate_data) = helpers::generate_off_session_mandate(
+                        resp.merchant_id.clone(),
+                        resp.connector.clone(),
+                        maybe_customer,
+                        payment_method_id.clone(),
+                        mandate_reference,
+                    ) {
+                        resp.payment_method_id = Some(payment_method_id);
+                        insert_mandate(state, merchant_account, &mut resp, new_mandate_data)
+                            .await?;
+                    }
+                }
             }
         }
     }
@@ -199,6 +207,60 @@ where
     Ok(resp)
 }
 
+fn get_mandate_reference<F, FData>(
+    resp: &types::RouterData<F, FData, types::PaymentsResponseData>,
+) -> Option<String> {
+    match resp.response.as_ref().ok() {
+        Some(types::PaymentsResponseData::TransactionResponse {
+            mandate_reference, ..
+        }) => mandate_reference.clone(),
+        _ => None,
+    }
+}
+
+async fn insert_mandate<F, FData>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    resp: &mut types::RouterData<F, FData, types::PaymentsResponseData>,
+    new_mandate_data: storage::MandateNew,
+) -> errors::RouterResult<()>
+where
+    FData: MandateBehaviour,
+{
+    resp.request
+        .set_mandate_id(api_models::payments::MandateIds {
+            mandate_id: new_mandate_data.mandate_id.clone(),
+            connector_mandate_id: new_mandate_data.connector_mandate_id.clone(),
+            network_transaction_id: new_mandate_data.network_transaction_id.clone(),
+        });
+    let mandate = state
+        .store
+        .insert_mandate(new_mandate_data)
+        .await
+        .map_err(|err| err.to_duplicate_response(errors::ApiErrorResponse::DuplicateMandate))?;
+    trigger_mandate_outgoing_webhook(state, merchant_account, mandate).await;
+
+    Ok(())
+}
+
+/// Notifies the merchant of the status of the mandate. A failure in notifying the merchant does
+/// not fail the operation which changed the status of the mandate.
+async fn trigger_mandate_outgoing_webhook(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    mandate: storage::Mandate,
+) {
+    let result = webhooks::trigger_mandate_outgoing_webhook(
+        state.clone(),
+        merchant_account.clone(),
+        mandate,
+    )
+    .await;
+    if let Err(error) = result {
+        logger::error!(?error, "Failed to send the mandate status webhook");
+    }
+}
+
 pub trait MandateBehaviour {
     fn get_amount(&self) -> i64;
     fn get_setup_future_usage(&self) -> Option<storage_models::enums::FutureUsage>;
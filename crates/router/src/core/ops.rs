@@ -0,0 +1,563 @@
+use api_models::ops;
+use error_stack::{report, IntoReport, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use super::{payments, webhooks};
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    db::StorageInterface,
+    routes::AppState,
+    scheduler::utils as pt_utils,
+    services,
+    types::storage::{self, enums, ProcessTrackerExt},
+    utils::{self, ValueExt},
+};
+
+const RETRIES_EXCEEDED: &str = "RETRIES_EXCEEDED";
+const OPS_RUNNER: &str = "OPS_WORKFLOW";
+const OPS_BATCH_TASK: &str = "OPS_BATCH";
+
+/// Number of events replayed when the request does not set a limit.
+#[cfg(feature = "olap")]
+const EVENTS_REPLAY_DEFAULT_LIMIT: i64 = 1000;
+
+/// Payment attempt statuses which are expected to move to a terminal status on syncing with the
+/// connector.
+const RESYNCABLE_ATTEMPT_STATUSES: [enums::AttemptStatus; 5] = [
+    enums::AttemptStatus::Pending,
+    enums::AttemptStatus::Authorizing,
+    enums::AttemptStatus::AuthenticationPending,
+    enums::AttemptStatus::CaptureInitiated,
+    enums::AttemptStatus::VoidInitiated,
+];
+
+fn requeue_process_update() -> storage::ProcessTrackerUpdate {
+    storage::ProcessTrackerUpdate::Update {
+        name: None,
+        retry_count: Some(0),
+        schedule_time: Some(common_utils::date_time::now()),
+        tracking_data: None,
+        business_status: Some(String::from("Pending")),
+        status: Some(enums::ProcessTrackerStatus::New),
+        updated_at: Some(common_utils::date_time::now()),
+    }
+}
+
+fn pending_item(id: String, merchant_id: Option<String>) -> storage::OpsBatchItem {
+    storage::OpsBatchItem {
+        id,
+        merchant_id,
+        result: None,
+    }
+}
+
+fn item_result(
+    status: ops::OpsActionStatus,
+    message: Option<String>,
+) -> storage::OpsBatchItemResult {
+    storage::OpsBatchItemResult { status, message }
+}
+
+/// Requeues the finished processes of the scheduler, which by default are the ones which
+/// exhausted their retries.
+#[instrument(skip(state))]
+pub async fn requeue_processes(
+    state: &AppState,
+    req: ops::ProcessRequeueRequest,
+) -> RouterResponse<ops::OpsActionResponse> {
+    let business_status = req
+        .business_status
+        .unwrap_or_else(|| RETRIES_EXCEEDED.to_string());
+
+    let items = state
+        .store
+        .find_processes_by_status_business_status(
+            enums::ProcessTrackerStatus::Finish,
+            &business_status,
+            req.limit,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?
+        .into_iter()
+        .filter(|process| {
+            req.runner
+                .as_ref()
+                .map_or(true, |runner| process.runner.as_ref() == Some(runner))
+        })
+        .map(|process| pending_item(process.id, None))
+        .collect();
+
+    run_ops_action(
+        state,
+        storage::OpsAction::ProcessRequeue,
+        items,
+        req.dry_run,
+    )
+    .await
+}
+
+/// Schedules a sync with the connector for the payment attempts which have been in a non
+/// terminal status for longer than the requested duration.
+#[instrument(skip(state))]
+pub async fn resync_stuck_payments(
+    state: &AppState,
+    req: ops::PaymentsResyncRequest,
+) -> RouterResponse<ops::OpsActionResponse> {
+    utils::when(req.stuck_for_hours <= 0, || {
+        Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "stuck_for_hours".to_string(),
+            expected_format: "positive integer".to_string(),
+        }))
+    })?;
+
+    let modified_before =
+        common_utils::date_time::now() - time::Duration::hours(req.stuck_for_hours);
+    let items = state
+        .store
+        .find_payment_attempts_by_statuses_modified_before(
+            RESYNCABLE_ATTEMPT_STATUSES.to_vec(),
+            modified_before,
+            req.limit,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?
+        .into_iter()
+        .map(|payment_attempt| {
+            pending_item(
+                payment_attempt.attempt_id,
+                Some(payment_attempt.merchant_id),
+            )
+        })
+        .collect();
+
+    run_ops_action(
+        state,
+        storage::OpsAction::PaymentsResync,
+        items,
+        req.dry_run,
+    )
+    .await
+}
+
+/// Resends the webhooks of the events of a merchant created in the requested time range, which by
+/// default are only the ones the merchant did not receive.
+#[cfg(feature = "olap")]
+#[instrument(skip(state))]
+pub async fn replay_events(
+    state: &AppState,
+    req: ops::EventsReplayRequest,
+) -> RouterResponse<ops::OpsActionResponse> {
+    utils::when(req.start_time >= req.end_time, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "start_time must be before end_time".to_string(),
+        }))
+    })?;
+
+    let constraints = api_models::events::EventListConstraints {
+        event_type: req.event_type,
+        is_webhook_notified: (!req.include_delivered).then_some(false),
+        created_gte: Some(req.start_time),
+        created_lt: Some(req.end_time),
+        ..Default::default()
+    };
+    let items = state
+        .store
+        .filter_events_by_constraints(
+            &req.merchant_id,
+            &constraints,
+            req.limit.unwrap_or(EVENTS_REPLAY_DEFAULT_LIMIT),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?
+        .into_iter()
+        .map(|event| {
+            let mut item = pending_item(event.event_id, Some(req.merchant_id.clone()));
+            if event.content.is_none() {
+                item.result = Some(item_result(
+                    ops::OpsActionStatus::Skipped,
+                    Some("The webhook content of the event was not stored".to_string()),
+                ));
+            }
+            item
+        })
+        .collect();
+
+    run_ops_action(state, storage::OpsAction::EventsReplay, items, req.dry_run).await
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_ops_batch(
+    state: &AppState,
+    batch_id: String,
+) -> RouterResponse<ops::OpsActionResponse> {
+    let (process, ops_batch) = find_ops_batch(&*state.store, &batch_id).await?;
+
+    Ok(services::ApplicationResponse::Json(ops_batch_to_response(
+        &process, ops_batch,
+    )))
+}
+
+/// Reports what the action would do on the matched records for dry runs. Otherwise the action is
+/// scheduled as a batch for the scheduler, whose progress can be followed with the batch id.
+async fn run_ops_action(
+    state: &AppState,
+    action: storage::OpsAction,
+    items: Vec<storage::OpsBatchItem>,
+    dry_run: bool,
+) -> RouterResponse<ops::OpsActionResponse> {
+    if dry_run {
+        let mut response = ops::OpsActionResponse {
+            dry_run,
+            matched_count: items.len(),
+            ..Default::default()
+        };
+        for item in items {
+            let result = match item.result {
+                Some(result) => result,
+                None => process_ops_item(state, action, &item, dry_run).await,
+            };
+            response.push(item.id, result.status, result.message);
+        }
+        return Ok(services::ApplicationResponse::Json(response));
+    }
+
+    let batch_id = utils::generate_id(consts::ID_LENGTH, "opsbatch");
+    let ops_batch = storage::OpsBatchWorkflow {
+        batch_id: batch_id.clone(),
+        action,
+        items,
+    };
+
+    let process_tracker_entry =
+        <storage::ProcessTracker as storage::ProcessTrackerExt>::make_process_tracker_new(
+            get_ops_batch_process_tracker_id(&batch_id),
+            OPS_BATCH_TASK,
+            OPS_RUNNER,
+            &ops_batch,
+            common_utils::date_time::now(),
+        )
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to construct the ops batch process")?;
+
+    let process = state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting task in process_tracker: batch_id: {}",
+                batch_id
+            )
+        })?;
+    logger::info!(%batch_id, ?action, matched = ops_batch.items.len(), "Scheduled ops batch");
+
+    Ok(services::ApplicationResponse::Json(ops_batch_to_response(
+        &process, ops_batch,
+    )))
+}
+
+/// Performs the action on a single record of a batch, or only checks whether it would be
+/// performed for dry runs.
+async fn process_ops_item(
+    state: &AppState,
+    action: storage::OpsAction,
+    item: &storage::OpsBatchItem,
+    dry_run: bool,
+) -> storage::OpsBatchItemResult {
+    let result = match action {
+        storage::OpsAction::ProcessRequeue => requeue_process(&*state.store, item, dry_run).await,
+        storage::OpsAction::PaymentsResync => resync_payment(&*state.store, item, dry_run).await,
+        storage::OpsAction::EventsReplay => replay_event(state, item, dry_run).await,
+    };
+
+    match result {
+        Ok(result) => result,
+        Err(error) => {
+            logger::error!(id = %item.id, ?action, ?error, "Ops action failed");
+            item_result(
+                ops::OpsActionStatus::Failed,
+                Some(error.current_context().error_message()),
+            )
+        }
+    }
+}
+
+fn performed(dry_run: bool) -> storage::OpsBatchItemResult {
+    let status = if dry_run {
+        ops::OpsActionStatus::WouldProcess
+    } else {
+        ops::OpsActionStatus::Processed
+    };
+    item_result(status, None)
+}
+
+async fn requeue_process(
+    db: &dyn StorageInterface,
+    item: &storage::OpsBatchItem,
+    dry_run: bool,
+) -> RouterResult<storage::OpsBatchItemResult> {
+    if dry_run {
+        return Ok(performed(dry_run));
+    }
+
+    let process = db
+        .find_process_by_id(&item.id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find the process")?
+        .ok_or(errors::ApiErrorResponse::ResourceIdNotFound)
+        .into_report()?;
+
+    // The process may have been requeued or retried since the batch was scheduled
+    if process.status != enums::ProcessTrackerStatus::Finish {
+        return Ok(item_result(
+            ops::OpsActionStatus::Skipped,
+            Some("The process is no longer finished".to_string()),
+        ));
+    }
+
+    db.update_process(process, requeue_process_update())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to requeue the process")?;
+    Ok(performed(dry_run))
+}
+
+async fn resync_payment(
+    db: &dyn StorageInterface,
+    item: &storage::OpsBatchItem,
+    dry_run: bool,
+) -> RouterResult<storage::OpsBatchItemResult> {
+    let merchant_id = item.merchant_id.as_deref().unwrap_or_default();
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        "PAYMENTS_SYNC_WORKFLOW",
+        "PAYMENTS_SYNC",
+        &item.id,
+        merchant_id,
+    );
+
+    let existing_process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find the sync process of the payment attempt")?;
+
+    let is_sync_pending = existing_process.as_ref().map_or(false, |process| {
+        process.status != enums::ProcessTrackerStatus::Finish
+    });
+    if is_sync_pending {
+        return Ok(item_result(
+            ops::OpsActionStatus::Skipped,
+            Some("A sync is already scheduled for the payment attempt".to_string()),
+        ));
+    }
+
+    if dry_run {
+        return Ok(performed(dry_run));
+    }
+
+    match existing_process {
+        Some(process) => db
+            .update_process(process, requeue_process_update())
+            .await
+            .map(|_| ())
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to requeue the sync process of the payment attempt")?,
+        None => payments::add_attempt_sync_task(
+            db,
+            merchant_id,
+            &item.id,
+            common_utils::date_time::now(),
+        )
+        .await
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to schedule a sync for the payment attempt")?,
+    }
+    Ok(performed(dry_run))
+}
+
+async fn replay_event(
+    state: &AppState,
+    item: &storage::OpsBatchItem,
+    dry_run: bool,
+) -> RouterResult<storage::OpsBatchItemResult> {
+    if dry_run {
+        return Ok(performed(dry_run));
+    }
+
+    let db = &*state.store;
+    let merchant_id = item.merchant_id.as_deref().unwrap_or_default();
+    let event = db
+        .find_event_by_merchant_id_event_id(merchant_id, &item.id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::EventNotFound))?;
+    let webhook = match event.content {
+        Some(webhook) => webhook,
+        None => {
+            return Ok(item_result(
+                ops::OpsActionStatus::Skipped,
+                Some("The webhook content of the event was not stored".to_string()),
+            ))
+        }
+    };
+
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(merchant_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        })?;
+    let attempt_number = db
+        .find_webhook_delivery_attempts_by_merchant_id_event_id(merchant_id, &event.event_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find the delivery attempts of the event")?
+        .iter()
+        .map(|attempt| attempt.attempt_number)
+        .max()
+        .unwrap_or_default()
+        + 1;
+
+    webhooks::trigger_webhook_to_merchant(
+        state,
+        &merchant_account,
+        &event.event_id,
+        attempt_number,
+        &webhook,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to deliver the webhook of the event")?;
+    webhooks::mark_event_as_notified(db, event.event_id).await;
+
+    Ok(performed(dry_run))
+}
+
+/// Performs the action on the records of a batch which were not processed yet.
+#[instrument(skip_all)]
+pub async fn trigger_ops_batch_workflow(
+    state: &AppState,
+    ops_tracker: &storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let mut ops_batch: storage::OpsBatchWorkflow = ops_tracker
+        .tracking_data
+        .clone()
+        .parse_value("OpsBatchWorkflow")?;
+
+    let mut ops_tracker = ops_tracker.clone();
+    let pending_items: Vec<usize> = ops_batch
+        .items
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| item.result.is_none())
+        .map(|(index, _)| index)
+        .collect();
+
+    for index in pending_items {
+        let result =
+            process_ops_item(state, ops_batch.action, &ops_batch.items[index], false).await;
+        ops_batch.items[index].result = Some(result);
+
+        // The progress is saved after every record, so that it can be followed while the batch
+        // runs, and an interrupted run does not perform the action twice on a record
+        ops_tracker = update_ops_batch_tracking_data(db, ops_tracker, &ops_batch).await?;
+    }
+
+    let response = ops_batch_to_response(&ops_tracker, ops_batch);
+    logger::info!(
+        batch_id = ?response.batch_id,
+        matched = response.matched_count,
+        processed = response.processed_count,
+        skipped = response.skipped_count,
+        failed = response.failed_count,
+        "Ops batch completed"
+    );
+
+    let id = ops_tracker.id.clone();
+    ops_tracker
+        .finish_with_status(db, format!("COMPLETED_BY_PT_{}", id))
+        .await?;
+    Ok(())
+}
+
+fn get_ops_batch_process_tracker_id(batch_id: &str) -> String {
+    format!("{}_{}_{}", OPS_RUNNER, OPS_BATCH_TASK, batch_id)
+}
+
+async fn find_ops_batch(
+    db: &dyn StorageInterface,
+    batch_id: &str,
+) -> RouterResult<(storage::ProcessTracker, storage::OpsBatchWorkflow)> {
+    let process = db
+        .find_process_by_id(&get_ops_batch_process_tracker_id(batch_id))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find the ops batch process")?
+        .ok_or(errors::ApiErrorResponse::ResourceIdNotFound)
+        .into_report()?;
+
+    let ops_batch =
+        serde_json::from_value::<storage::OpsBatchWorkflow>(process.tracking_data.clone())
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unable to deserialize ops batch tracking data")?;
+
+    Ok((process, ops_batch))
+}
+
+async fn update_ops_batch_tracking_data(
+    db: &dyn StorageInterface,
+    ops_tracker: storage::ProcessTracker,
+    ops_batch: &storage::OpsBatchWorkflow,
+) -> Result<storage::ProcessTracker, errors::ProcessTrackerError> {
+    let tracking_data = serde_json::to_value(ops_batch)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("unable to serialize ops batch tracking data")?;
+
+    Ok(db
+        .update_process(
+            ops_tracker,
+            storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: None,
+                schedule_time: None,
+                tracking_data: Some(tracking_data),
+                business_status: None,
+                status: None,
+                updated_at: Some(common_utils::date_time::now()),
+            },
+        )
+        .await?)
+}
+
+fn ops_batch_to_response(
+    process: &storage::ProcessTracker,
+    ops_batch: storage::OpsBatchWorkflow,
+) -> ops::OpsActionResponse {
+    let is_started = ops_batch.items.iter().any(|item| item.result.is_some());
+    let status = match process.status {
+        enums::ProcessTrackerStatus::Finish => ops::OpsBatchStatus::Completed,
+        _ if is_started => ops::OpsBatchStatus::Processing,
+        _ => ops::OpsBatchStatus::Pending,
+    };
+
+    let mut response = ops::OpsActionResponse {
+        batch_id: Some(ops_batch.batch_id),
+        status: Some(status),
+        matched_count: ops_batch.items.len(),
+        ..Default::default()
+    };
+    for item in ops_batch.items {
+        match item.result {
+            Some(result) => response.push(item.id, result.status, result.message),
+            None => response.push(item.id, ops::OpsActionStatus::Pending, None),
+        }
+    }
+    response
+}
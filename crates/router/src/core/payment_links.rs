@@ -0,0 +1,233 @@
+use common_utils::{date_time, ext_traits::AsyncExt};
+use error_stack::{IntoReport, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    configs::settings::Server,
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::AppState,
+    services,
+    types::{
+        api::payment_links,
+        storage::{self, enums},
+        transformers::ForeignInto,
+    },
+    utils,
+};
+
+/// The number of seconds after which a payment link expires, if not specified while creating it.
+const PAYMENT_LINK_DEFAULT_EXPIRY: u32 = 900;
+
+#[instrument(skip(state))]
+pub async fn create_payment_link(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: payment_links::PaymentLinkCreateRequest,
+) -> RouterResponse<payment_links::PaymentLinkResponse> {
+    let db = &*state.store;
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &req.payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    utils::when(!is_payable(payment_intent.status), || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "A payment link cannot be created for a payment with status {}",
+                payment_intent.status
+            ),
+        })
+        .into_report()
+    })?;
+    utils::when(merchant_account.publishable_key.is_none(), || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The merchant account does not have a publishable key".to_string(),
+        })
+        .into_report()
+    })?;
+
+    let expires_in = req.expires_in.unwrap_or(PAYMENT_LINK_DEFAULT_EXPIRY);
+    let payment_link_new = storage::PaymentLinkNew {
+        payment_link_id: utils::generate_id(consts::ID_LENGTH, "plink"),
+        payment_id: payment_intent.payment_id,
+        merchant_id: merchant_account.merchant_id,
+        status: enums::PaymentLinkStatus::Active,
+        expires_at: date_time::now().saturating_add(time::Duration::seconds(expires_in.into())),
+    };
+    let payment_link = db
+        .insert_payment_link(payment_link_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the payment link")?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_payment_link_response(&state.conf.server, payment_link),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_payment_link(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    payment_link_id: String,
+) -> RouterResponse<payment_links::PaymentLinkResponse> {
+    let (payment_link, _) =
+        find_and_refresh_payment_link(state, &merchant_account, &payment_link_id).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_payment_link_response(&state.conf.server, payment_link),
+    ))
+}
+
+/// Renders the hosted checkout page of a payment link, through which the customer pays for the
+/// payment using the publishable key of the merchant.
+#[instrument(skip(state))]
+pub async fn initiate_payment_link(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    payment_link_id: String,
+) -> RouterResponse<services::PaymentLinkFormData> {
+    let (payment_link, payment_intent) =
+        find_and_refresh_payment_link(state, &merchant_account, &payment_link_id).await?;
+
+    let publishable_key = merchant_account
+        .publishable_key
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .into_report()
+        .attach_printable("Missing publishable key for the merchant account")?;
+    let client_secret = payment_intent
+        .client_secret
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .into_report()
+        .attach_printable("Missing client secret for the payment")?;
+    let currency = payment_intent
+        .currency
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .into_report()
+        .attach_printable("Missing currency for the payment")?;
+
+    Ok(services::ApplicationResponse::PaymentLinkForm(
+        services::PaymentLinkFormData {
+            link: get_payment_link_url(&state.conf.server, &payment_link),
+            base_url: state.conf.server.base_url.clone(),
+            merchant_name: merchant_account
+                .merchant_name
+                .unwrap_or(merchant_account.merchant_id),
+            payment_id: payment_intent.payment_id,
+            amount: payment_intent.amount.to_major_unit_as_string(&currency),
+            currency: currency.to_string(),
+            status: payment_link.status,
+            publishable_key,
+            client_secret,
+        },
+    ))
+}
+
+/// Fetches a payment link along with its payment, and brings the status of the link up to date
+/// with the status of the payment.
+///
+/// A link whose payment has been made is marked as completed. A link which is past its expiry is
+/// marked as expired, and the payment is cancelled if it is still awaiting payment, so that it
+/// cannot be paid after the link has expired.
+async fn find_and_refresh_payment_link(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_link_id: &str,
+) -> RouterResult<(storage::PaymentLink, storage::PaymentIntent)> {
+    let db = &*state.store;
+    let payment_link = db
+        .find_payment_link_by_merchant_id_payment_link_id(
+            &merchant_account.merchant_id,
+            payment_link_id,
+        )
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)
+        })?;
+    let mut payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &payment_link.payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    if payment_link.status != enums::PaymentLinkStatus::Active {
+        return Ok((payment_link, payment_intent));
+    }
+
+    let status = match payment_intent.status {
+        enums::IntentStatus::Succeeded
+        | enums::IntentStatus::Processing
+        | enums::IntentStatus::RequiresCapture
+        | enums::IntentStatus::PartiallyCaptured => Some(enums::PaymentLinkStatus::Completed),
+        enums::IntentStatus::Cancelled | enums::IntentStatus::Failed => {
+            Some(enums::PaymentLinkStatus::Expired)
+        }
+        _ if payment_link.expires_at < date_time::now() => {
+            if is_payable(payment_intent.status) {
+                payment_intent = db
+                    .update_payment_intent(
+                        payment_intent,
+                        storage::PaymentIntentUpdate::CancelUpdate,
+                        merchant_account.storage_scheme,
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to cancel the payment of an expired payment link")?;
+            }
+            Some(enums::PaymentLinkStatus::Expired)
+        }
+        _ => None,
+    };
+
+    let payment_link = status
+        .async_map(|status| {
+            db.update_payment_link(
+                payment_link.clone(),
+                storage::PaymentLinkUpdate::StatusUpdate { status },
+            )
+        })
+        .await
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the status of the payment link")?
+        .unwrap_or(payment_link);
+
+    Ok((payment_link, payment_intent))
+}
+
+/// Whether the payment is still awaiting the customer to pay for it.
+fn is_payable(status: enums::IntentStatus) -> bool {
+    matches!(
+        status,
+        enums::IntentStatus::RequiresPaymentMethod | enums::IntentStatus::RequiresConfirmation
+    )
+}
+
+fn get_payment_link_url(server: &Server, payment_link: &storage::PaymentLink) -> String {
+    format!(
+        "{}/payment_links/{}/{}",
+        server.base_url, payment_link.merchant_id, payment_link.payment_link_id
+    )
+}
+
+fn get_payment_link_response(
+    server: &Server,
+    payment_link: storage::PaymentLink,
+) -> payment_links::PaymentLinkResponse {
+    payment_links::PaymentLinkResponse {
+        link: get_payment_link_url(server, &payment_link),
+        payment_link_id: payment_link.payment_link_id,
+        payment_id: payment_link.payment_id,
+        status: payment_link.status.foreign_into(),
+        expires_at: payment_link.expires_at,
+        created_at: payment_link.created_at,
+    }
+}
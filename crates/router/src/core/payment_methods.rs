@@ -1,3 +1,4 @@
+pub mod card_vault;
 pub mod cards;
 pub mod transformers;
 pub mod vault;
@@ -0,0 +1,180 @@
+//! Clients for the card vault, the service that stores card details and hands out card
+//! references in exchange.
+
+use async_trait::async_trait;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+#[cfg(feature = "basilisk_mock")]
+use super::cards;
+use super::transformers as payment_methods;
+use crate::{
+    configs::settings::Locker,
+    core::errors::{self, CustomResult},
+    routes, services,
+    types::api,
+    utils::{BytesExt, ConnectorResponseExt},
+};
+
+#[async_trait]
+pub trait CardVault: Send + Sync {
+    /// Stores the card and returns the card reference issued by the vault.
+    async fn add_card(
+        &self,
+        state: &routes::AppState,
+        req: &api::CreatePaymentMethod,
+        card: &api::CardDetail,
+        customer_id: &str,
+        locker_id: &str,
+        merchant_id: &str,
+    ) -> CustomResult<payment_methods::AddCardResponse, errors::VaultError>;
+
+    async fn get_card(
+        &self,
+        state: &routes::AppState,
+        locker_id: &str,
+        card_id: &str,
+    ) -> errors::RouterResult<payment_methods::GetCardResponse>;
+
+    async fn delete_card(
+        &self,
+        state: &routes::AppState,
+        merchant_id: &str,
+        card_id: &str,
+    ) -> errors::RouterResult<payment_methods::DeleteCardResponse>;
+}
+
+/// Returns the card vault configured for this router.
+///
+/// The mock locker is only available when the router is built with the `basilisk_mock` feature.
+#[cfg_attr(not(feature = "basilisk_mock"), allow(unused_variables))]
+pub fn get_card_vault(locker: &Locker) -> &'static dyn CardVault {
+    #[cfg(feature = "basilisk_mock")]
+    if locker.mock_locker {
+        return &MockCardVault;
+    }
+
+    &LockerCardVault
+}
+
+/// The external card locker at `locker.host`. Requests to it are JWE encrypted.
+pub struct LockerCardVault;
+
+#[async_trait]
+impl CardVault for LockerCardVault {
+    #[instrument(skip_all)]
+    async fn add_card(
+        &self,
+        state: &routes::AppState,
+        req: &api::CreatePaymentMethod,
+        card: &api::CardDetail,
+        customer_id: &str,
+        locker_id: &str,
+        merchant_id: &str,
+    ) -> CustomResult<payment_methods::AddCardResponse, errors::VaultError> {
+        let request = payment_methods::mk_add_card_request(
+            &state.conf.locker,
+            card,
+            customer_id,
+            req,
+            locker_id,
+            merchant_id,
+        )?;
+        let response = services::call_connector_api(state, request)
+            .await
+            .change_context(errors::VaultError::SaveCardFailed)?;
+
+        match response {
+            Ok(card) => card
+                .response
+                .parse_struct("AddCardResponse")
+                .change_context(errors::VaultError::ResponseDeserializationFailed),
+            Err(err) => Err(report!(errors::VaultError::UnexpectedResponseError(
+                err.response
+            ))),
+        }
+    }
+
+    #[instrument(skip_all)]
+    async fn get_card(
+        &self,
+        state: &routes::AppState,
+        locker_id: &str,
+        card_id: &str,
+    ) -> errors::RouterResult<payment_methods::GetCardResponse> {
+        let request = payment_methods::mk_get_card_request(&state.conf.locker, locker_id, card_id)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Making get card request failed")?;
+        services::call_connector_api(state, request)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while executing call_connector_api for get_card")
+            .get_response_inner("AddCardResponse")
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_card(
+        &self,
+        state: &routes::AppState,
+        merchant_id: &str,
+        card_id: &str,
+    ) -> errors::RouterResult<payment_methods::DeleteCardResponse> {
+        let request =
+            payment_methods::mk_delete_card_request(&state.conf.locker, merchant_id, card_id)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Making Delete card request Failed")?;
+        services::call_connector_api(state, request)
+            .await
+            .get_response_inner("DeleteCardResponse")
+    }
+}
+
+/// Emulates the card locker using the `locker_mock_up` table. Card details are stored
+/// unencrypted, so this is meant for tests and local setups only.
+#[cfg(feature = "basilisk_mock")]
+pub struct MockCardVault;
+
+#[cfg(feature = "basilisk_mock")]
+#[async_trait]
+impl CardVault for MockCardVault {
+    #[instrument(skip_all)]
+    async fn add_card(
+        &self,
+        state: &routes::AppState,
+        _req: &api::CreatePaymentMethod,
+        card: &api::CardDetail,
+        customer_id: &str,
+        _locker_id: &str,
+        _merchant_id: &str,
+    ) -> CustomResult<payment_methods::AddCardResponse, errors::VaultError> {
+        let card_id = common_utils::generate_id(common_utils::consts::ID_LENGTH, "card");
+        cards::mock_add_card(&*state.store, &card_id, card, None, None, Some(customer_id)).await
+    }
+
+    #[instrument(skip_all)]
+    async fn get_card(
+        &self,
+        state: &routes::AppState,
+        _locker_id: &str,
+        card_id: &str,
+    ) -> errors::RouterResult<payment_methods::GetCardResponse> {
+        let (get_card_response, _) = cards::mock_get_card(&*state.store, card_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while fetching card from mock_locker")?;
+        Ok(get_card_response)
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_card(
+        &self,
+        state: &routes::AppState,
+        _merchant_id: &str,
+        card_id: &str,
+    ) -> errors::RouterResult<payment_methods::DeleteCardResponse> {
+        cards::mock_delete_card(&*state.store, card_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while deleting card from card_locker")
+    }
+}
@@ -7,10 +7,11 @@ use router_env::{instrument, tracing};
 use crate::{
     core::{
         errors::{self, StorageErrorExt},
-        payment_methods::{transformers as payment_methods, vault},
+        payment_methods::{card_vault, transformers as payment_methods, vault},
         payments::helpers,
+        surcharge, utils as core_utils,
     },
-    db,
+    db, logger,
     pii::prelude::*,
     routes, services,
     types::{
@@ -18,7 +19,7 @@ use crate::{
         storage::{self, enums},
         transformers::ForeignInto,
     },
-    utils::{BytesExt, ConnectorResponseExt, OptionExt},
+    utils::OptionExt,
 };
 
 #[instrument(skip_all)]
@@ -54,6 +55,11 @@ pub async fn add_payment_method(
     req.validate()?;
     let merchant_id = &merchant_account.merchant_id;
     let customer_id = req.customer_id.clone().get_required_value("customer_id")?;
+    state
+        .store
+        .find_customer_by_customer_id_merchant_id(&customer_id, merchant_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::CustomerNotFound))?;
     match req.card.clone() {
         Some(card) => add_card(state, req, card, customer_id, merchant_account)
             .await
@@ -134,7 +140,6 @@ pub async fn add_card(
     customer_id: String,
     merchant_account: &storage::MerchantAccount,
 ) -> errors::CustomResult<api::PaymentMethodResponse, errors::VaultError> {
-    let locker = &state.conf.locker;
     let db = &*state.store;
     let merchant_id = &merchant_account.merchant_id;
     let locker_id = merchant_account
@@ -143,33 +148,9 @@ pub async fn add_card(
         .get_required_value("locker_id")
         .change_context(errors::VaultError::SaveCardFailed)?;
 
-    let request = payment_methods::mk_add_card_request(
-        locker,
-        &card,
-        &customer_id,
-        &req,
-        &locker_id,
-        merchant_id,
-    )?;
-    let response = if !locker.mock_locker {
-        let response = services::call_connector_api(state, request)
-            .await
-            .change_context(errors::VaultError::SaveCardFailed)?;
-
-        let response: payment_methods::AddCardResponse = match response {
-            Ok(card) => card
-                .response
-                .parse_struct("AddCardResponse")
-                .change_context(errors::VaultError::ResponseDeserializationFailed),
-            Err(err) => Err(report!(errors::VaultError::UnexpectedResponseError(
-                err.response
-            ))),
-        }?;
-        response
-    } else {
-        let card_id = generate_id(consts::ID_LENGTH, "card");
-        mock_add_card(db, &card_id, &card, None, None, Some(&customer_id)).await?
-    };
+    let response = card_vault::get_card_vault(&state.conf.locker)
+        .add_card(state, &req, &card, &customer_id, &locker_id, merchant_id)
+        .await?;
 
     if let Some(false) = response.duplicate {
         create_payment_method(db, &req, &customer_id, &response.card_id, merchant_id)
@@ -237,6 +218,7 @@ pub async fn mock_add_card(
     })
 }
 
+#[cfg(feature = "basilisk_mock")]
 #[instrument(skip_all)]
 pub async fn mock_get_card<'a>(
     db: &dyn db::StorageInterface,
@@ -270,6 +252,7 @@ pub async fn mock_get_card<'a>(
     ))
 }
 
+#[cfg(feature = "basilisk_mock")]
 #[instrument(skip_all)]
 pub async fn mock_delete_card<'a>(
     db: &dyn db::StorageInterface,
@@ -293,26 +276,9 @@ pub async fn get_card_from_legacy_locker<'a>(
     locker_id: &'a str,
     card_id: &'a str,
 ) -> errors::RouterResult<payment_methods::GetCardResponse> {
-    let locker = &state.conf.locker;
-    let request = payment_methods::mk_get_card_request(locker, locker_id, card_id)
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Making get card request failed")?;
-    let get_card_result = if !locker.mock_locker {
-        let response = services::call_connector_api(state, request)
-            .await
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Failed while executing call_connector_api for get_card");
-
-        response.get_response_inner("AddCardResponse")?
-    } else {
-        let (get_card_response, _) = mock_get_card(&*state.store, card_id)
-            .await
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Failed while fetching card from mock_locker")?;
-        get_card_response
-    };
-
-    Ok(get_card_result)
+    card_vault::get_card_vault(&state.conf.locker)
+        .get_card(state, locker_id, card_id)
+        .await
 }
 
 #[instrument(skip_all)]
@@ -321,24 +287,9 @@ pub async fn delete_card<'a>(
     merchant_id: &'a str,
     card_id: &'a str,
 ) -> errors::RouterResult<payment_methods::DeleteCardResponse> {
-    let locker = &state.conf.locker;
-    let request = payment_methods::mk_delete_card_request(&state.conf.locker, merchant_id, card_id)
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Making Delete card request Failed")?;
-
-    let card_delete_failure_message = "Failed while deleting card from card_locker";
-    let delete_card_resp = if !locker.mock_locker {
-        services::call_connector_api(state, request)
-            .await
-            .get_response_inner("DeleteCardResponse")?
-    } else {
-        mock_delete_card(&*state.store, card_id)
-            .await
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable(card_delete_failure_message)?
-    };
-
-    Ok(delete_card_resp)
+    card_vault::get_card_vault(&state.conf.locker)
+        .delete_card(state, merchant_id, card_id)
+        .await
 }
 
 pub async fn list_payment_methods(
@@ -385,6 +336,19 @@ pub async fn list_payment_methods(
 
     let mut response: HashSet<api::ListPaymentMethod> = HashSet::new();
     for mca in all_mcas {
+        // Only the connector accounts of the business of the payment can process the payment
+        let is_business_mca = payment_intent.as_ref().map_or(true, |pi| {
+            mca.connector_label
+                == core_utils::get_connector_label(
+                    &mca.connector_name,
+                    pi.business_country.as_ref(),
+                    pi.business_label.as_ref(),
+                )
+        });
+        if !is_business_mca {
+            continue;
+        }
+
         let payment_methods = match mca.payment_methods_enabled {
             Some(pm) => pm,
             None => continue,
@@ -401,6 +365,22 @@ pub async fn list_payment_methods(
         .await?;
     }
 
+    // Surcharges can only be calculated for the amount of a payment
+    let response = match payment_intent.as_ref() {
+        Some(payment_intent) => response
+            .into_iter()
+            .map(|mut payment_method| {
+                payment_method.surcharge_details = surcharge::get_payment_method_surcharges(
+                    &merchant_account,
+                    &payment_method,
+                    payment_intent.amount.get_amount_as_i64(),
+                )?;
+                Ok(payment_method)
+            })
+            .collect::<errors::RouterResult<HashSet<_>>>()?,
+        None => response,
+    };
+
     response
         .is_empty()
         .then(|| Err(report!(errors::ApiErrorResponse::PaymentMethodNotFound)))
@@ -548,7 +528,7 @@ fn filter_payment_amount_based(
     payment_intent: &storage::PaymentIntent,
     pm: &api::ListPaymentMethod,
 ) -> bool {
-    let amount = payment_intent.amount;
+    let amount = payment_intent.amount.get_amount_as_i64();
     pm.maximum_amount.map_or(true, |amt| amount < amt)
         && pm.minimum_amount.map_or(true, |amt| amount > amt)
 }
@@ -831,13 +811,49 @@ pub async fn retrieve_payment_method(
 pub async fn delete_payment_method(
     state: &routes::AppState,
     merchant_account: storage::MerchantAccount,
-    pm: api::PaymentMethodId,
+    token: api::PaymentMethodId,
+) -> errors::RouterResponse<api::DeletePaymentMethodResponse> {
+    delete_payment_method_by_token(state, merchant_account, token, None).await
+}
+
+/// Deletes a saved payment method of the customer, for requests authenticated with an ephemeral
+/// key, which can only manage the payment methods saved for the customer it was issued for.
+pub async fn delete_customer_payment_method(
+    state: &routes::AppState,
+    merchant_account: storage::MerchantAccount,
+    customer_id: &str,
+    token: api::PaymentMethodId,
+) -> errors::RouterResponse<api::DeletePaymentMethodResponse> {
+    delete_payment_method_by_token(state, merchant_account, token, Some(customer_id)).await
+}
+
+async fn delete_payment_method_by_token(
+    state: &routes::AppState,
+    merchant_account: storage::MerchantAccount,
+    token: api::PaymentMethodId,
+    customer_id: Option<&str>,
 ) -> errors::RouterResponse<api::DeletePaymentMethodResponse> {
     let (_, supplementary_data) =
-        vault::Vault::get_payment_method_data_from_locker(state, &pm.payment_method_id).await?;
+        vault::Vault::get_payment_method_data_from_locker(state, &token.payment_method_id).await?;
     let payment_method_id = supplementary_data
         .payment_method_id
         .map_or(Err(errors::ApiErrorResponse::PaymentMethodNotFound), Ok)?;
+
+    if let Some(customer_id) = customer_id {
+        let payment_method = state
+            .store
+            .find_payment_method(&payment_method_id)
+            .await
+            .map_err(|error| {
+                error.to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)
+            })?;
+        crate::utils::when(
+            payment_method.customer_id != customer_id
+                || payment_method.merchant_id != merchant_account.merchant_id,
+            || Err(report!(errors::ApiErrorResponse::PaymentMethodNotFound)),
+        )?;
+    }
+
     let pm = state
         .store
         .delete_payment_method_by_merchant_id_payment_method_id(
@@ -852,12 +868,17 @@ pub async fn delete_payment_method(
     if pm.payment_method == enums::PaymentMethodType::Card {
         let response = delete_card(state, &pm.merchant_id, &payment_method_id).await?;
         if response.status == "success" {
-            print!("Card From locker deleted Successfully")
+            logger::info!("Card From locker deleted Successfully")
         } else {
-            print!("Error: Deleting Card From Locker")
+            logger::error!("Error: Deleting Card From Locker")
         }
     };
 
+    vault::Vault::delete_locker_payment_method_by_lookup_key(state, &Some(token.payment_method_id))
+        .await;
+
+    revoke_payment_method_mandates(state, &pm).await?;
+
     Ok(services::ApplicationResponse::Json(
         api::DeletePaymentMethodResponse {
             payment_method_id: pm.payment_method_id,
@@ -865,3 +886,38 @@ pub async fn delete_payment_method(
         },
     ))
 }
+
+/// Revokes the active mandates of the customer which charge the deleted payment method, as the
+/// payment method can no longer be used for them.
+async fn revoke_payment_method_mandates(
+    state: &routes::AppState,
+    payment_method: &storage::PaymentMethod,
+) -> errors::RouterResult<()> {
+    let db = &*state.store;
+    let mandates = db
+        .find_mandate_by_merchant_id_customer_id(
+            &payment_method.merchant_id,
+            &payment_method.customer_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the mandates of the customer")?;
+
+    for mandate in mandates.into_iter().filter(|mandate| {
+        mandate.payment_method_id == payment_method.payment_method_id
+            && mandate.mandate_status == enums::MandateStatus::Active
+    }) {
+        db.update_mandate_by_merchant_id_mandate_id(
+            &mandate.merchant_id,
+            &mandate.mandate_id,
+            storage::MandateUpdate::StatusUpdate {
+                mandate_status: enums::MandateStatus::Revoked,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to revoke the mandate of the deleted payment method")?;
+    }
+
+    Ok(())
+}
@@ -1,3 +1,4 @@
+pub mod access_token;
 pub mod flows;
 pub mod helpers;
 pub mod operations;
@@ -21,7 +22,7 @@ use self::{
 };
 use crate::{
     core::{
-        errors::{self, RouterResponse, RouterResult},
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
         payment_methods::vault,
     },
     db::StorageInterface,
@@ -32,11 +33,12 @@ use crate::{
     types::{
         self, api,
         storage::{self, enums as storage_enums},
+        transformers::ForeignInto,
     },
     utils::OptionExt,
 };
 
-#[instrument(skip_all)]
+#[instrument(skip_all, fields(payment_id, merchant_id = %merchant_account.merchant_id))]
 pub async fn payments_operation_core<F, Req, Op, FData>(
     state: &AppState,
     merchant_account: storage::MerchantAccount,
@@ -79,6 +81,14 @@ where
         )
         .await?;
 
+    helpers::validate_customer_exists(
+        &*state.store,
+        &state.conf.customers,
+        customer_details.as_ref(),
+        validate_result.merchant_id,
+    )
+    .await?;
+
     let (operation, customer) = operation
         .to_domain()?
         .get_or_create_customer_details(
@@ -88,7 +98,7 @@ where
             validate_result.merchant_id,
         )
         .await
-        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::CustomerNotFound))
         .attach_printable("Failed while fetching/creating customer")?;
 
     let (operation, payment_method_data) = operation
@@ -134,7 +144,20 @@ where
                     state,
                     &merchant_account,
                     &validate_result.payment_id,
-                    connector,
+                    &connector,
+                    &operation,
+                    payment_data,
+                    &customer,
+                    call_connector_action,
+                )
+                .await?
+            }
+            api::ConnectorCallType::Failover(connectors) => {
+                call_connector_service_with_failover(
+                    state,
+                    &merchant_account,
+                    &validate_result.payment_id,
+                    connectors,
                     &operation,
                     payment_data,
                     &customer,
@@ -173,7 +196,7 @@ where
                     state,
                     &merchant_account,
                     &validate_result.payment_id,
-                    connector_data,
+                    &connector_data,
                     &operation,
                     payment_data,
                     &customer,
@@ -311,12 +334,12 @@ pub async fn payments_response_for_redirection_flows<'a>(
 }
 
 #[allow(clippy::too_many_arguments)]
-#[instrument(skip_all)]
+#[instrument(skip_all, fields(connector = %connector.connector_name))]
 pub async fn call_connector_service<F, Op, Req>(
     state: &AppState,
     merchant_account: &storage::MerchantAccount,
     payment_id: &api::PaymentIdType,
-    connector: api::ConnectorData,
+    connector: &api::ConnectorData,
     _operation: &Op,
     payment_data: PaymentData<F>,
     customer: &Option<storage::Customer>,
@@ -340,19 +363,32 @@ where
 
     let stime_connector = Instant::now();
 
-    let router_data = payment_data
+    let mut router_data = payment_data
         .construct_router_data(state, connector.connector.id(), merchant_account)
         .await?;
 
-    let res = router_data
-        .decide_flows(
-            state,
-            &connector,
-            customer,
-            call_connector_action,
-            merchant_account,
-        )
-        .await;
+    let add_access_token_result =
+        access_token::add_access_token(state, connector, merchant_account, &router_data).await?;
+
+    let should_continue_payment = access_token::update_router_data_with_access_token_result(
+        &add_access_token_result,
+        &mut router_data,
+        &call_connector_action,
+    );
+
+    let res = if should_continue_payment {
+        router_data
+            .decide_flows(
+                state,
+                connector,
+                customer,
+                call_connector_action,
+                merchant_account,
+            )
+            .await
+    } else {
+        Ok(router_data)
+    };
 
     let response = res
         .async_and_then(|response| async {
@@ -378,6 +414,144 @@ where
     Ok(response)
 }
 
+/// Calls the connectors in order, until one of them does not fail with a retryable error. Each
+/// retry is made with a new payment attempt, so that every attempt is recorded separately.
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+pub async fn call_connector_service_with_failover<F, Op, Req>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_id: &api::PaymentIdType,
+    connectors: Vec<api::ConnectorData>,
+    operation: &Op,
+    mut payment_data: PaymentData<F>,
+    customer: &Option<storage::Customer>,
+    call_connector_action: CallConnectorAction,
+) -> RouterResult<PaymentData<F>>
+where
+    Op: Debug,
+    F: Send + Clone,
+
+    // To create connector flow specific interface data
+    PaymentData<F>: ConstructFlowSpecificData<F, Req, types::PaymentsResponseData>,
+    types::RouterData<F, Req, types::PaymentsResponseData>: Feature<F, Req> + Send,
+
+    // To construct connector flow specific api
+    dyn api::Connector: services::api::ConnectorIntegration<F, Req, types::PaymentsResponseData>,
+
+    // To perform router related operation for PaymentResponse
+    PaymentResponse: Operation<F, Req>,
+{
+    let db = &*state.store;
+    let mut connectors = connectors.into_iter();
+
+    let mut connector = connectors
+        .next()
+        .get_required_value("connector")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("No connector selected for routing")?;
+
+    // The payment can only be retried if the connector is actually called
+    if !matches!(call_connector_action, CallConnectorAction::Trigger) {
+        return call_connector_service(
+            state,
+            merchant_account,
+            payment_id,
+            &connector,
+            operation,
+            payment_data,
+            customer,
+            call_connector_action,
+        )
+        .await;
+    }
+
+    loop {
+        let next_connector = match connectors.next() {
+            Some(next_connector) => next_connector,
+            None => {
+                return call_connector_service(
+                    state,
+                    merchant_account,
+                    payment_id,
+                    &connector,
+                    operation,
+                    payment_data,
+                    customer,
+                    CallConnectorAction::Trigger,
+                )
+                .await
+            }
+        };
+
+        let connector_name = connector.connector_name.to_string();
+        let result = call_connector_service(
+            state,
+            merchant_account,
+            payment_id,
+            &connector,
+            operation,
+            payment_data.clone(),
+            customer,
+            CallConnectorAction::Trigger,
+        )
+        .await;
+
+        match result {
+            Ok(updated_payment_data)
+                if helpers::is_retryable_attempt_failure(
+                    &connector,
+                    &updated_payment_data.payment_attempt,
+                ) =>
+            {
+                payment_data = updated_payment_data;
+            }
+            Ok(updated_payment_data) => return Ok(updated_payment_data),
+            Err(error) => {
+                let client_error = match helpers::get_retryable_api_client_error(&error) {
+                    Some(client_error) => client_error.to_string(),
+                    None => return Err(error),
+                };
+                logger::error!(?error);
+
+                payment_data.payment_attempt = db
+                    .update_payment_attempt(
+                        payment_data.payment_attempt,
+                        storage::PaymentAttemptUpdate::ErrorUpdate {
+                            connector: Some(connector_name.clone()),
+                            status: storage_enums::AttemptStatus::Failure,
+                            error_code: None,
+                            error_message: Some(client_error),
+                            connector_api_log: None,
+                            test_mode: None,
+                        },
+                        merchant_account.storage_scheme,
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to mark the payment attempt as failed")?;
+            }
+        }
+
+        logger::info!(
+            "Retrying payment with connector {} after retryable failure with connector {}",
+            next_connector.connector_name,
+            connector_name
+        );
+
+        helpers::insert_failover_payment_attempt(
+            db,
+            &mut payment_data,
+            &next_connector,
+            merchant_account.storage_scheme,
+        )
+        .await?;
+
+        connector = next_connector;
+    }
+}
+
+#[instrument(skip_all)]
 pub async fn call_multiple_connectors_service<F, Op, Req>(
     state: &AppState,
     merchant_account: &storage::MerchantAccount,
@@ -482,6 +656,7 @@ where
     pub force_sync: Option<bool>,
     pub payment_method_data: Option<api::PaymentMethod>,
     pub refunds: Vec<storage::Refund>,
+    pub captures: Vec<storage::Capture>,
     pub sessions_token: Vec<api::SessionToken>,
     pub card_cvc: Option<pii::Secret<String>>,
     pub email: Option<masking::Secret<String, pii::Email>>,
@@ -544,7 +719,9 @@ pub fn should_call_connector<Op: Debug, F: Clone>(
         "PaymentStart" => {
             !matches!(
                 payment_data.payment_intent.status,
-                storage_enums::IntentStatus::Failed | storage_enums::IntentStatus::Succeeded
+                storage_enums::IntentStatus::Failed
+                    | storage_enums::IntentStatus::Succeeded
+                    | storage_enums::IntentStatus::PartiallyCaptured
             ) && payment_data
                 .connector_response
                 .authentication_data
@@ -567,6 +744,7 @@ pub fn should_call_connector<Op: Debug, F: Clone>(
             matches!(
                 payment_data.payment_intent.status,
                 storage_enums::IntentStatus::RequiresCapture
+                    | storage_enums::IntentStatus::PartiallyCaptured
             )
         }
         "PaymentSession" => true,
@@ -582,17 +760,23 @@ pub async fn list_payments(
 ) -> RouterResponse<api::PaymentListResponse> {
     helpers::validate_payment_list_request(&constraints)?;
     let merchant_id = &merchant.merchant_id;
-    let payment_intent =
-        helpers::filter_by_constraints(db, &constraints, merchant_id, merchant.storage_scheme)
-            .await
-            .map_err(|err| {
-                errors::StorageErrorExt::to_not_found_response(
-                    err,
-                    errors::ApiErrorResponse::PaymentNotFound,
-                )
-            })?;
+    let payment_intents = helpers::filter_by_constraints(
+        db,
+        &helpers::get_payment_list_lookahead_constraints(&constraints),
+        merchant_id,
+        merchant.storage_scheme,
+    )
+    .await
+    .map_err(|err| {
+        errors::StorageErrorExt::to_not_found_response(
+            err,
+            errors::ApiErrorResponse::PaymentNotFound,
+        )
+    })?;
+    let (payment_intents, has_more) =
+        helpers::paginate_payment_intents(payment_intents, &constraints);
 
-    let data: Vec<api::PaymentsResponse> = payment_intent
+    let data: Vec<api::PaymentsResponse> = payment_intents
         .into_iter()
         .map(types::transformers::ForeignInto::foreign_into)
         .collect();
@@ -600,25 +784,387 @@ pub async fn list_payments(
         api::PaymentListResponse {
             size: data.len(),
             data,
+            has_more,
         },
     ))
 }
 
+/// Lists the payments of the merchant accounts under an organization, which is the merchant
+/// account set as the parent of the sub-merchants. Only the organization itself is allowed to
+/// list these payments.
+#[cfg(feature = "olap")]
+pub async fn list_organization_payments(
+    db: &dyn StorageInterface,
+    merchant: storage::MerchantAccount,
+    organization_id: String,
+    constraints: api::OrganizationPaymentListConstraints,
+) -> RouterResponse<api::PaymentListResponse> {
+    crate::utils::when(merchant.merchant_id != organization_id, || {
+        Err(errors::ApiErrorResponse::Unauthorized)
+            .into_report()
+            .attach_printable("Merchant account is not allowed to access the organization")
+    })?;
+
+    let merchant_id_filter = constraints.merchant_id.clone();
+    let constraints = api::PaymentListConstraints::from(constraints);
+    helpers::validate_payment_list_request(&constraints)?;
+    crate::utils::when(
+        merchant_id_filter.is_none()
+            && (constraints.starting_after.is_some() || constraints.ending_before.is_some()),
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "`merchant_id` is mandatory when paginating with `starting_after` or \
+                          `ending_before`, use the `created` filters otherwise"
+                    .to_string(),
+            })
+            .into_report()
+        },
+    )?;
+
+    let merchant_accounts: Vec<_> = db
+        .list_merchant_accounts_by_parent_merchant_id(&organization_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?
+        .into_iter()
+        .filter(|account| {
+            merchant_id_filter
+                .as_ref()
+                .map_or(true, |merchant_id| &account.merchant_id == merchant_id)
+        })
+        .collect();
+
+    // A merchant account outside of the organization is reported the same way as one which
+    // doesn't exist, so that the listing can't be used to discover other merchant accounts
+    crate::utils::when(
+        merchant_id_filter.is_some() && merchant_accounts.is_empty(),
+        || Err(errors::ApiErrorResponse::MerchantAccountNotFound).into_report(),
+    )?;
+
+    let lookahead_constraints = helpers::get_payment_list_lookahead_constraints(&constraints);
+    let mut payment_intents = Vec::new();
+    for merchant_account in merchant_accounts {
+        let result = helpers::filter_by_constraints(
+            db,
+            &lookahead_constraints,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await;
+        match result {
+            Ok(intents) => payment_intents.extend(intents),
+            Err(error) if error.current_context().is_db_not_found() => {}
+            Err(error) => Err(error.change_context(errors::ApiErrorResponse::InternalServerError))?,
+        }
+    }
+
+    payment_intents
+        .sort_by(|a, b| (&b.created_at, &b.payment_id).cmp(&(&a.created_at, &a.payment_id)));
+    let (payment_intents, has_more) =
+        helpers::paginate_payment_intents(payment_intents, &constraints);
+
+    let data: Vec<api::PaymentsResponse> = payment_intents
+        .into_iter()
+        .map(types::transformers::ForeignInto::foreign_into)
+        .collect();
+    Ok(services::ApplicationResponse::Json(
+        api::PaymentListResponse {
+            size: data.len(),
+            data,
+            has_more,
+        },
+    ))
+}
+
+/// Creates a new payment with the details of a payment which could not be completed, so that the
+/// customer can be asked to pay again. The new payment is created without being confirmed.
+#[instrument(skip_all)]
+pub async fn payments_clone_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: api::PaymentsCloneRequest,
+) -> RouterResponse<api::PaymentsResponse> {
+    let db = &*state.store;
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &req.payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    crate::utils::when(
+        matches!(
+            payment_intent.status,
+            storage_enums::IntentStatus::Succeeded
+                | storage_enums::IntentStatus::PartiallyCaptured
+                | storage_enums::IntentStatus::Processing
+                | storage_enums::IntentStatus::RequiresCapture
+        ),
+        || {
+            Err(errors::ApiErrorResponse::PaymentUnexpectedState {
+                current_flow: "cloned".to_string(),
+                field_name: "status".to_string(),
+                current_value: payment_intent.status.to_string(),
+                states: "failed, cancelled, requires_customer_action, requires_payment_method, \
+                         requires_confirmation"
+                    .to_string(),
+            })
+        },
+    )?;
+
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id(
+            &payment_intent.payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    let (payment_token, payment_method) = if req.reuse_payment_method {
+        let payment_method_id = payment_attempt
+            .payment_method_id
+            .clone()
+            .get_required_value("payment_method_id")
+            .attach_printable("No saved payment method was used for the payment")?;
+        let payment_method = db
+            .find_payment_method(&payment_method_id)
+            .await
+            .map_err(|error| {
+                error.to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)
+            })?;
+        let locker_id = merchant_account
+            .locker_id
+            .to_owned()
+            .get_required_value("locker_id")?;
+
+        let token = uuid::Uuid::new_v4().to_string();
+        crate::core::payment_methods::cards::get_lookup_key_from_locker(
+            state,
+            &token,
+            &payment_method,
+            &locker_id,
+        )
+        .await?;
+
+        (
+            Some(token),
+            Some(payment_method.payment_method.foreign_into()),
+        )
+    } else {
+        (None, None)
+    };
+
+    let metadata = payment_intent
+        .metadata
+        .map(serde_json::from_value::<api_models::payments::Metadata>)
+        .transpose()
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse metadata of the payment")?;
+    let shipping = helpers::get_address_by_id(db, payment_intent.shipping_address_id)
+        .await?
+        .as_ref()
+        .map(ForeignInto::foreign_into);
+    let billing = helpers::get_address_by_id(db, payment_intent.billing_address_id)
+        .await?
+        .as_ref()
+        .map(ForeignInto::foreign_into);
+
+    let payments_request = api::PaymentsRequest {
+        amount: Some(payment_intent.amount.into()),
+        currency: payment_intent.currency.map(ForeignInto::foreign_into),
+        capture_method: payment_attempt
+            .capture_method
+            .map(ForeignInto::foreign_into),
+        confirm: Some(false),
+        customer_id: payment_intent.customer_id,
+        off_session: payment_intent.off_session,
+        description: payment_intent.description,
+        return_url: payment_intent.return_url,
+        setup_future_usage: payment_intent
+            .setup_future_usage
+            .map(ForeignInto::foreign_into),
+        authentication_type: payment_attempt
+            .authentication_type
+            .map(ForeignInto::foreign_into),
+        payment_method,
+        payment_token,
+        shipping,
+        billing,
+        statement_descriptor_name: payment_intent.statement_descriptor_name,
+        statement_descriptor_suffix: payment_intent.statement_descriptor_suffix,
+        metadata,
+        ..Default::default()
+    };
+
+    payments_core::<api::Authorize, api::PaymentsResponse, _, _, _>(
+        state,
+        merchant_account,
+        PaymentCreate,
+        payments_request,
+        services::AuthFlow::Merchant,
+        CallConnectorAction::Trigger,
+    )
+    .await
+}
+
+/// Replaces the client secret of a payment which is awaiting the customer, so that the payment can
+/// be completed from the client after its previous client secret has expired.
+#[instrument(skip_all)]
+pub async fn payments_client_secret_regenerate_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    payment_id: String,
+) -> RouterResponse<api::PaymentsClientSecretResponse> {
+    let db = &*state.store;
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    crate::utils::when(
+        !matches!(
+            payment_intent.status,
+            storage_enums::IntentStatus::RequiresPaymentMethod
+                | storage_enums::IntentStatus::RequiresConfirmation
+                | storage_enums::IntentStatus::RequiresCustomerAction
+        ),
+        || {
+            Err(errors::ApiErrorResponse::PaymentUnexpectedState {
+                current_flow: "given a new client secret".to_string(),
+                field_name: "status".to_string(),
+                current_value: payment_intent.status.to_string(),
+                states: "requires_payment_method, requires_confirmation, requires_customer_action"
+                    .to_string(),
+            })
+        },
+    )?;
+
+    let (client_secret, client_secret_expiry) =
+        helpers::create_client_secret(&state.conf.client_secret, &payment_intent.payment_id);
+    let payment_intent = db
+        .update_payment_intent(
+            payment_intent,
+            storage::PaymentIntentUpdate::ClientSecretUpdate {
+                client_secret: client_secret.clone(),
+                client_secret_expiry,
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the client secret of the payment")?;
+
+    Ok(services::ApplicationResponse::Json(
+        api::PaymentsClientSecretResponse {
+            payment_id: payment_intent.payment_id,
+            client_secret: masking::Secret::new(client_secret),
+            client_secret_expiry,
+        },
+    ))
+}
+
+/// Creates everything the SDK needs on the client side to present the payment methods of the
+/// merchant, the session tokens of the wallets along with the client keys of the connectors.
+#[instrument(skip_all)]
+pub async fn payments_session_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: api::PaymentsSessionRequest,
+) -> RouterResponse<api::PaymentsSessionResponse> {
+    let merchant_id = merchant_account.merchant_id.clone();
+    let response = payments_core::<api::Session, api::PaymentsSessionResponse, _, _, _>(
+        state,
+        merchant_account,
+        PaymentSession,
+        req,
+        services::AuthFlow::Client,
+        CallConnectorAction::Trigger,
+    )
+    .await?;
+
+    match response {
+        services::ApplicationResponse::Json(mut session_response) => {
+            let connector_accounts = state
+                .store
+                .find_merchant_connector_account_by_merchant_id_list(&merchant_id)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Database error when querying for merchant connector accounts")?;
+            session_response.connector_client_keys =
+                helpers::get_connector_client_keys(&connector_accounts);
+
+            Ok(services::ApplicationResponse::Json(session_response))
+        }
+        response => Ok(response),
+    }
+}
+
 pub async fn add_process_sync_task(
     db: &dyn StorageInterface,
     payment_attempt: &storage::PaymentAttempt,
     schedule_time: time::PrimitiveDateTime,
+) -> Result<(), errors::ProcessTrackerError> {
+    add_attempt_sync_task(
+        db,
+        &payment_attempt.merchant_id,
+        &payment_attempt.attempt_id,
+        schedule_time,
+    )
+    .await
+}
+
+pub async fn add_attempt_sync_task(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    attempt_id: &str,
+    schedule_time: time::PrimitiveDateTime,
 ) -> Result<(), errors::ProcessTrackerError> {
     let tracking_data = api::PaymentsRetrieveRequest {
         force_sync: true,
-        merchant_id: Some(payment_attempt.merchant_id.clone()),
+        merchant_id: Some(merchant_id.to_string()),
 
-        resource_id: api::PaymentIdType::PaymentAttemptId(payment_attempt.attempt_id.clone()),
+        resource_id: api::PaymentIdType::PaymentAttemptId(attempt_id.to_string()),
         param: None,
         connector: None,
+        client_secret: None,
     };
     let runner = "PAYMENTS_SYNC_WORKFLOW";
     let task = "PAYMENTS_SYNC";
+    let process_tracker_id =
+        pt_utils::get_process_tracker_id(runner, task, attempt_id, merchant_id);
+    let process_tracker_entry =
+        <storage::ProcessTracker as storage::ProcessTrackerExt>::make_process_tracker_new(
+            process_tracker_id,
+            task,
+            runner,
+            tracking_data,
+            schedule_time,
+        )?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+pub async fn add_process_capture_task(
+    db: &dyn StorageInterface,
+    payment_attempt: &storage::PaymentAttempt,
+    schedule_time: time::PrimitiveDateTime,
+) -> Result<(), errors::ProcessTrackerError> {
+    let tracking_data = api::PaymentsCaptureRequest {
+        payment_id: Some(payment_attempt.payment_id.clone()),
+        merchant_id: Some(payment_attempt.merchant_id.clone()),
+        amount_to_capture: payment_attempt.amount_to_capture,
+        ..Default::default()
+    };
+    let runner = "PAYMENTS_CAPTURE_WORKFLOW";
+    let task = "PAYMENTS_CAPTURE";
     let process_tracker_id = pt_utils::get_process_tracker_id(
         runner,
         task,
@@ -662,23 +1208,51 @@ where
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Could not decode merchant routing rules")?;
 
-            let connector_name = match routing_algorithm {
-                api::RoutingAlgorithm::Single(conn) => conn.to_string(),
+            let connector_names = match routing_algorithm {
+                api::RoutingAlgorithm::Single(conn) => vec![conn],
+                api::RoutingAlgorithm::Priority(conns) => conns,
+                api::RoutingAlgorithm::VolumeSplit(splits) => {
+                    vec![helpers::get_connector_by_volume_split(
+                        &payment_data.payment_intent.payment_id,
+                        &splits,
+                    )
+                    .get_required_value("connector")
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Volume split gave no connector")?]
+                }
             };
 
-            let connector_data = api::ConnectorData::get_connector_by_name(
-                &state.conf.connectors,
-                &connector_name,
-                api::GetToken::Connector,
-            )
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Routing algorithm gave invalid connector")?;
+            let mut connectors = connector_names
+                .iter()
+                .map(|connector_name| {
+                    api::ConnectorData::get_connector_by_name(
+                        &state.conf.connectors,
+                        &connector_name.to_string(),
+                        api::GetToken::Connector,
+                    )
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Routing algorithm gave invalid connector")
+                })
+                .collect::<RouterResult<Vec<_>>>()?;
+
+            let connector_name = connector_names
+                .first()
+                .get_required_value("connector")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Routing algorithm gave no connector")?
+                .to_string();
 
             payment_data.payment_attempt.connector = Some(connector_name);
 
-            Ok(api::ConnectorCallType::Single(connector_data))
+            if connectors.len() > 1 {
+                Ok(api::ConnectorCallType::Failover(connectors))
+            } else {
+                Ok(api::ConnectorCallType::Single(connectors.remove(0)))
+            }
         }
 
-        call_type @ api::ConnectorCallType::Multiple(_) => Ok(call_type),
+        call_type @ (api::ConnectorCallType::Multiple(_) | api::ConnectorCallType::Failover(_)) => {
+            Ok(call_type)
+        }
     }
 }
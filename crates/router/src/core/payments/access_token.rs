@@ -0,0 +1,235 @@
+use std::time::Duration;
+
+use error_stack::{IntoReport, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResult},
+        payments,
+    },
+    logger,
+    routes::AppState,
+    services,
+    types::{self, api as api_types, storage},
+};
+
+/// Connectors which authenticate their API calls with an access token obtained using the
+/// merchant's credentials, instead of using the credentials directly.
+pub fn connector_supports_access_token(connector: &api_types::ConnectorData) -> bool {
+    matches!(connector.connector_name, types::Connector::Paypal)
+}
+
+/// Fetches the cached access token for the connector, obtaining a new one from the connector
+/// if none is cached or the cached one is about to expire.
+#[instrument(skip_all)]
+pub async fn add_access_token<F, Req, Res>(
+    state: &AppState,
+    connector: &api_types::ConnectorData,
+    merchant_account: &storage::MerchantAccount,
+    router_data: &types::RouterData<F, Req, Res>,
+) -> RouterResult<types::AddAccessTokenResult> {
+    if !connector_supports_access_token(connector) {
+        return Ok(types::AddAccessTokenResult {
+            access_token_result: Ok(None),
+            connector_supports_access_token: false,
+        });
+    }
+
+    let merchant_id = &merchant_account.merchant_id;
+    let store = &*state.store;
+    let old_access_token = store
+        .get_access_token(merchant_id, connector.connector.id())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the cached access token")?;
+
+    let refresh_threshold = state.conf.access_token.refresh_threshold;
+    let access_token_result = match old_access_token {
+        Some(access_token) if access_token.expires > refresh_threshold => Ok(Some(access_token)),
+        _ => get_refreshed_access_token(state, connector, merchant_id, router_data)
+            .await?
+            .map(Some),
+    };
+
+    Ok(types::AddAccessTokenResult {
+        access_token_result,
+        connector_supports_access_token: true,
+    })
+}
+
+/// Refreshes the access token, making sure that only one refresh is in progress at a time for a
+/// merchant and connector. If another refresh is already in progress, waits for the token
+/// obtained by it to be cached instead.
+#[instrument(skip_all)]
+async fn get_refreshed_access_token<F, Req, Res>(
+    state: &AppState,
+    connector: &api_types::ConnectorData,
+    merchant_id: &str,
+    router_data: &types::RouterData<F, Req, Res>,
+) -> RouterResult<Result<types::AccessToken, types::ErrorResponse>> {
+    let store = &*state.store;
+    let connector_name = connector.connector.id();
+    let access_token_config = &state.conf.access_token;
+
+    let is_lock_acquired = store
+        .acquire_access_token_refresh_lock(
+            merchant_id,
+            connector_name,
+            access_token_config.refresh_lock_ttl,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to acquire the access token refresh lock")?;
+
+    if is_lock_acquired {
+        // The token may have been refreshed by another request since it was last fetched
+        let access_token_result = match store
+            .get_access_token(merchant_id, connector_name)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the cached access token")
+        {
+            Ok(Some(access_token))
+                if access_token.expires > access_token_config.refresh_threshold =>
+            {
+                Ok(Ok(access_token))
+            }
+            Ok(_) => {
+                refresh_and_cache_access_token(state, connector, merchant_id, router_data).await
+            }
+            Err(error) => Err(error),
+        };
+
+        // The lock expires on its own if it could not be released
+        if let Err(error) = store
+            .release_access_token_refresh_lock(merchant_id, connector_name)
+            .await
+        {
+            logger::error!(?error, "Failed to release the access token refresh lock");
+        }
+
+        return access_token_result;
+    }
+
+    for _ in 0..access_token_config.refresh_lock_wait_attempts {
+        tokio::time::sleep(Duration::from_millis(
+            access_token_config.refresh_lock_wait_interval,
+        ))
+        .await;
+
+        let access_token = store
+            .get_access_token(merchant_id, connector_name)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the cached access token")?;
+
+        if let Some(access_token) = access_token
+            .filter(|access_token| access_token.expires > access_token_config.refresh_threshold)
+        {
+            return Ok(Ok(access_token));
+        }
+    }
+
+    logger::warn!("Access token was not refreshed in time by another request, refreshing it");
+    refresh_and_cache_access_token(state, connector, merchant_id, router_data).await
+}
+
+/// Obtains a new access token from the connector and caches it.
+async fn refresh_and_cache_access_token<F, Req, Res>(
+    state: &AppState,
+    connector: &api_types::ConnectorData,
+    merchant_id: &str,
+    router_data: &types::RouterData<F, Req, Res>,
+) -> RouterResult<Result<types::AccessToken, types::ErrorResponse>> {
+    let refresh_token_request_data =
+        types::AccessTokenRequestData::try_from(router_data.connector_auth_type.clone())
+            .into_report()
+            .attach_printable(
+                "Could not create access token request, invalid connector account credentials",
+            )?;
+
+    let refresh_token_router_data = payments::helpers::router_data_type_conversion::<
+        _,
+        api_types::AccessTokenAuth,
+        _,
+        _,
+        _,
+        _,
+    >(
+        router_data,
+        refresh_token_request_data,
+        Err(types::ErrorResponse::default()),
+    );
+
+    let access_token_result =
+        refresh_connector_auth(state, connector, &refresh_token_router_data).await?;
+
+    if let Ok(access_token) = &access_token_result {
+        state
+            .store
+            .set_access_token(merchant_id, connector.connector.id(), access_token.clone())
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to cache the access token")?;
+    }
+
+    Ok(access_token_result)
+}
+
+/// Obtains a new access token from the connector.
+#[instrument(skip_all)]
+pub async fn refresh_connector_auth(
+    state: &AppState,
+    connector: &api_types::ConnectorData,
+    router_data: &types::RefreshTokenRouterData,
+) -> RouterResult<Result<types::AccessToken, types::ErrorResponse>> {
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        api_types::AccessTokenAuth,
+        types::AccessTokenRequestData,
+        types::AccessToken,
+    > = connector.connector.get_connector_integration();
+
+    let access_token_router_data = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        router_data,
+        payments::CallConnectorAction::Trigger,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to obtain an access token from the connector")?;
+
+    Ok(access_token_router_data.response)
+}
+
+/// Sets the access token on the router data. If no access token could be obtained, the error
+/// from the connector is set as the response instead.
+///
+/// Returns whether the connector should still be called with the router data.
+pub fn update_router_data_with_access_token_result<F, Req, Res>(
+    add_access_token_result: &types::AddAccessTokenResult,
+    router_data: &mut types::RouterData<F, Req, Res>,
+    call_connector_action: &payments::CallConnectorAction,
+) -> bool {
+    let should_update_router_data = matches!(
+        call_connector_action,
+        payments::CallConnectorAction::Trigger
+    );
+
+    if add_access_token_result.connector_supports_access_token && should_update_router_data {
+        match add_access_token_result.access_token_result.as_ref() {
+            Ok(access_token) => {
+                router_data.access_token = access_token.clone();
+                true
+            }
+            Err(connector_error) => {
+                router_data.response = Err(connector_error.clone());
+                false
+            }
+        }
+    } else {
+        true
+    }
+}
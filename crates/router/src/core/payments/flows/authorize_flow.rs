@@ -1,11 +1,15 @@
 use async_trait::async_trait;
+use router_env::logger;
 
 use super::{ConstructFlowSpecificData, Feature};
 use crate::{
+    consts,
     core::{
+        authentication, blocklist,
         errors::{ConnectorErrorExt, RouterResult},
-        mandate,
+        fraud_check, mandate,
         payments::{self, transformers, PaymentData},
+        velocity,
     },
     routes::AppState,
     scheduler::metrics,
@@ -53,7 +57,160 @@ impl Feature<api::Authorize, types::PaymentsAuthorizeData> for types::PaymentsAu
         call_connector_action: payments::CallConnectorAction,
         merchant_account: &storage::MerchantAccount,
     ) -> RouterResult<Self> {
-        let resp = self
+        let mut router_data = self;
+
+        if matches!(
+            call_connector_action,
+            payments::CallConnectorAction::Trigger
+        ) {
+            if let Some(blocklist_entry) =
+                blocklist::find_blocking_entry(state, merchant_account, &router_data).await?
+            {
+                router_data.response = Err(types::ErrorResponse {
+                    code: consts::BLOCKLISTED_PAYMENT_ERROR_CODE.to_string(),
+                    message: "The payment was blocked by the blocklist of the merchant".to_string(),
+                    reason: Some(format!(
+                        "The {} of the payment is blocklisted",
+                        blocklist_entry.kind
+                    )),
+                });
+                return Ok(router_data);
+            }
+
+            if let Some(rule) = velocity::find_exceeded_velocity_rule(
+                state,
+                merchant_account,
+                customer,
+                &router_data,
+            )
+            .await?
+            {
+                router_data.response = Err(types::ErrorResponse {
+                    code: consts::VELOCITY_LIMIT_EXCEEDED_ERROR_CODE.to_string(),
+                    message: "Too many payments were attempted, try again later".to_string(),
+                    reason: Some(format!(
+                        "More than {} payments were attempted with the same {} within {} seconds",
+                        rule.max_attempts, rule.key, rule.window
+                    )),
+                });
+                return Ok(router_data);
+            }
+        }
+
+        if matches!(
+            call_connector_action,
+            payments::CallConnectorAction::Trigger
+        ) && connector_requires_session_token(connector, &router_data.request)
+        {
+            match router_data
+                .authorize_session_token(state, connector)
+                .await?
+            {
+                Ok(session_token) => router_data.session_token = Some(session_token),
+                Err(error_response) => {
+                    router_data.response = Err(error_response);
+                    return Ok(router_data);
+                }
+            }
+        }
+
+        if matches!(
+            call_connector_action,
+            payments::CallConnectorAction::Trigger
+        ) && router_data.auth_type == storage::enums::AuthenticationType::ThreeDs
+            && router_data.request.authentication_data.is_none()
+            && authentication::connector_accepts_authentication_data(connector)
+        {
+            match authentication::perform_external_authentication(
+                state,
+                merchant_account,
+                &router_data,
+            )
+            .await?
+            {
+                Some(Ok(authentication_data))
+                    if authentication_data.trans_status.is_authenticated() =>
+                {
+                    router_data.request.authentication_data = Some(authentication_data)
+                }
+                // Challenges are not supported yet, so the payment fails unless the cardholder
+                // was authenticated without one
+                Some(Ok(authentication_data)) => {
+                    router_data.response = Err(types::ErrorResponse {
+                        code: consts::NO_ERROR_CODE.to_string(),
+                        message: "The cardholder could not be authenticated".to_string(),
+                        reason: Some(format!(
+                            "The 3DS authentication ended with the status {:?}",
+                            authentication_data.trans_status
+                        )),
+                    });
+                    return Ok(router_data);
+                }
+                Some(Err(error_response)) => {
+                    router_data.response = Err(error_response);
+                    return Ok(router_data);
+                }
+                None => {}
+            }
+        }
+
+        let frm_configuration = match call_connector_action {
+            payments::CallConnectorAction::Trigger => {
+                fraud_check::get_frm_configuration(state, merchant_account).await?
+            }
+            _ => None,
+        };
+
+        if let Some(frm_configuration) = frm_configuration
+            .as_ref()
+            .filter(|frm_configuration| frm_configuration.flow == types::FrmFlow::Pre)
+        {
+            if let Some(frm_data) =
+                fraud_check::perform_fraud_check(state, frm_configuration, &router_data).await?
+            {
+                match frm_data.decision {
+                    api_models::enums::FrmDecision::Approve => {}
+                    // Payments under review are only authorized, and are captured by the
+                    // merchant once they have been reviewed
+                    api_models::enums::FrmDecision::Review => {
+                        router_data.request.capture_method =
+                            Some(storage::enums::CaptureMethod::Manual)
+                    }
+                    api_models::enums::FrmDecision::Reject => {
+                        router_data.response = Err(types::ErrorResponse {
+                            code: consts::NO_ERROR_CODE.to_string(),
+                            message: "The payment was rejected by the fraud check".to_string(),
+                            reason: frm_data.reason.clone(),
+                        });
+                        router_data.request.frm_data = Some(frm_data);
+                        return Ok(router_data);
+                    }
+                }
+                router_data.request.frm_data = Some(frm_data);
+            }
+        }
+
+        // Payments screened after the authorization are captured only once they have been
+        // approved. Payments which may require the customer to be redirected are not screened,
+        // since they are not authorized within this request.
+        let frm_configuration = frm_configuration.filter(|frm_configuration| {
+            frm_configuration.flow == types::FrmFlow::Post
+                && router_data.auth_type == storage::enums::AuthenticationType::NoThreeDs
+                && matches!(
+                    router_data.request.payment_method_data,
+                    api::PaymentMethod::Card(_)
+                )
+        });
+        let capture_after_fraud_check = frm_configuration.is_some()
+            && matches!(
+                router_data.request.capture_method,
+                None | Some(storage::enums::CaptureMethod::Automatic)
+            );
+        if capture_after_fraud_check {
+            router_data.request.capture_method = Some(storage::enums::CaptureMethod::Manual);
+        }
+
+        let mut resp = router_data
             .decide_flow(
                 state,
                 connector,
@@ -62,15 +219,189 @@ impl Feature<api::Authorize, types::PaymentsAuthorizeData> for types::PaymentsAu
                 call_connector_action,
                 merchant_account,
             )
-            .await;
+            .await?;
 
         metrics::PAYMENT_COUNT.add(&metrics::CONTEXT, 1, &[]); // Metrics
 
-        resp
+        if let Some(frm_configuration) = frm_configuration {
+            if resp.response.is_ok() && resp.status == storage::enums::AttemptStatus::Authorized {
+                let frm_data =
+                    fraud_check::perform_fraud_check(state, &frm_configuration, &resp).await?;
+                let decision = frm_data.as_ref().map(|frm_data| frm_data.decision);
+                resp.request.frm_data = frm_data;
+                match decision {
+                    // The payment is captured as requested by the merchant when the service is
+                    // unavailable
+                    Some(api_models::enums::FrmDecision::Approve) | None
+                        if capture_after_fraud_check =>
+                    {
+                        resp.capture_after_fraud_check(state, connector).await?
+                    }
+                    Some(api_models::enums::FrmDecision::Reject) => {
+                        resp.void_after_fraud_check(state, connector).await?
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        Ok(resp)
+    }
+}
+
+/// Connectors which require an order or session to be created with them before a payment can be
+/// authorized. Stripe requires the Apple Pay token to be exchanged for a Stripe token first.
+fn connector_requires_session_token(
+    connector: &api::ConnectorData,
+    request: &types::PaymentsAuthorizeData,
+) -> bool {
+    match connector.connector_name {
+        types::Connector::Razorpay => true,
+        types::Connector::Stripe => matches!(
+            request.payment_method_data,
+            api::PaymentMethod::Wallet(api_models::payments::WalletData {
+                issuer_name: api_models::enums::WalletIssuer::ApplePay,
+                ..
+            })
+        ),
+        _ => false,
     }
 }
 
 impl types::PaymentsAuthorizeRouterData {
+    /// Captures a payment which was only authorized so that it could be screened first. The
+    /// payment stays authorized if the capture fails, so that the merchant can capture it later.
+    async fn capture_after_fraud_check(
+        &mut self,
+        state: &AppState,
+        connector: &api::ConnectorData,
+    ) -> RouterResult<()> {
+        let (connector_transaction_id, connector_meta) = match &self.response {
+            Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(connector_transaction_id),
+                connector_metadata,
+                ..
+            }) => (connector_transaction_id.clone(), connector_metadata.clone()),
+            _ => return Ok(()),
+        };
+        let capture_router_data: types::PaymentsCaptureRouterData =
+            payments::helpers::router_data_type_conversion(
+                self,
+                types::PaymentsCaptureData {
+                    amount_to_capture: None,
+                    currency: self.request.currency,
+                    connector_transaction_id,
+                    amount: self.request.amount,
+                    connector_meta,
+                },
+                Err(types::ErrorResponse::default()),
+            );
+        let capture_router_data = capture_router_data
+            .decide_flow(
+                state,
+                connector,
+                &None,
+                Some(true),
+                payments::CallConnectorAction::Trigger,
+            )
+            .await?;
+
+        match capture_router_data.response {
+            Ok(_) => {
+                self.status = capture_router_data.status;
+                self.amount_captured = capture_router_data.amount_captured;
+            }
+            Err(error_response) => logger::error!(
+                ?error_response,
+                "Failed to capture the payment after it was approved by the fraud check"
+            ),
+        }
+        Ok(())
+    }
+
+    /// Voids a payment which was rejected by the fraud check after it had been authorized. The
+    /// payment stays authorized if it could not be voided, so that the merchant can void it later.
+    async fn void_after_fraud_check(
+        &mut self,
+        state: &AppState,
+        connector: &api::ConnectorData,
+    ) -> RouterResult<()> {
+        let connector_transaction_id = match &self.response {
+            Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(connector_transaction_id),
+                ..
+            }) => connector_transaction_id.clone(),
+            _ => return Ok(()),
+        };
+        let cancel_router_data: types::PaymentsCancelRouterData =
+            payments::helpers::router_data_type_conversion(
+                self,
+                types::PaymentsCancelData {
+                    connector_transaction_id,
+                    cancellation_reason: Some("Rejected by the fraud check".to_string()),
+                },
+                Err(types::ErrorResponse::default()),
+            );
+        let cancel_router_data = cancel_router_data
+            .decide_flow(
+                state,
+                connector,
+                &None,
+                Some(true),
+                payments::CallConnectorAction::Trigger,
+            )
+            .await?;
+
+        match cancel_router_data.response {
+            Ok(_) => self.status = cancel_router_data.status,
+            Err(error_response) => logger::error!(
+                ?error_response,
+                "Failed to void the payment after it was rejected by the fraud check"
+            ),
+        }
+        Ok(())
+    }
+
+    /// Creates an order or session with the connector, returning the token identifying it.
+    pub async fn authorize_session_token<'a>(
+        &self,
+        state: &'a AppState,
+        connector: &api::ConnectorData,
+    ) -> RouterResult<Result<String, types::ErrorResponse>> {
+        let connector_integration: services::BoxedConnectorIntegration<
+            '_,
+            api::AuthorizeSessionToken,
+            types::AuthorizeSessionTokenData,
+            types::AuthorizeSessionTokenResponse,
+        > = connector.connector.get_connector_integration();
+
+        let session_token_router_data = payments::helpers::router_data_type_conversion::<
+            _,
+            api::AuthorizeSessionToken,
+            _,
+            _,
+            _,
+            _,
+        >(
+            self,
+            types::AuthorizeSessionTokenData::from(self),
+            Err(types::ErrorResponse::default()),
+        );
+
+        let resp = services::execute_connector_processing_step(
+            state,
+            connector_integration,
+            &session_token_router_data,
+            payments::CallConnectorAction::Trigger,
+        )
+        .await
+        .map_err(|error| error.to_payment_failed_response())?;
+
+        Ok(resp
+            .response
+            .map(|session_token_response| session_token_response.session_token))
+    }
+
     pub async fn decide_flow<'a, 'b>(
         &'b self,
         state: &'a AppState,
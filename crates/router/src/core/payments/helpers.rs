@@ -1,9 +1,9 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, marker::PhantomData};
 
 use common_utils::ext_traits::AsyncExt;
 // TODO : Evaluate all the helper functions ()
 use error_stack::{report, IntoReport, ResultExt};
-use masking::ExposeOptionInterface;
+use masking::{ExposeOptionInterface, PeekInterface};
 use router_env::{instrument, tracing};
 use uuid::Uuid;
 
@@ -12,7 +12,7 @@ use super::{
     CustomerDetails, PaymentData,
 };
 use crate::{
-    configs::settings::Server,
+    configs::settings::{ClientSecretConfig, CustomerSettings, Server},
     consts,
     core::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
@@ -23,6 +23,7 @@ use crate::{
     scheduler::{metrics, workflows::payment_sync},
     services,
     types::{
+        self,
         api::{self, enums as api_enums, CustomerAcceptanceExt, MandateValidationFieldsExt},
         storage::{self, enums as storage_enums, ephemeral_key},
         transformers::ForeignInto,
@@ -193,6 +194,25 @@ pub async fn get_token_for_recurring_mandate(
     Ok((Some(token), Some(payment_method.payment_method)))
 }
 
+/// Fetches the identifiers stored against the mandate, which the connector needs to charge the
+/// customer's payment method without the customer being present.
+pub async fn get_mandate_ids(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    mandate_id: &str,
+) -> RouterResult<api_models::payments::MandateIds> {
+    let mandate = db
+        .find_mandate_by_merchant_id_mandate_id(merchant_id, mandate_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::MandateNotFound))?;
+
+    Ok(api_models::payments::MandateIds {
+        mandate_id: mandate.mandate_id,
+        connector_mandate_id: mandate.connector_mandate_id,
+        network_transaction_id: mandate.network_transaction_id,
+    })
+}
+
 #[instrument(skip_all)]
 /// Check weather the merchant id in the request
 /// and merchant id in the merchant account are same.
@@ -328,17 +348,11 @@ pub fn validate_customer_id_mandatory_cases(
     }
 }
 
-pub fn create_startpay_url(
-    server: &Server,
-    payment_attempt: &storage::PaymentAttempt,
-    payment_intent: &storage::PaymentIntent,
-) -> String {
+/// URL to which the shopper is sent for authenticating the payment with the connector.
+pub fn create_startpay_url(server: &Server, payment_intent: &storage::PaymentIntent) -> String {
     format!(
-        "{}/payments/start/{}/{}/{}",
-        server.base_url,
-        payment_intent.payment_id,
-        payment_intent.merchant_id,
-        payment_attempt.attempt_id
+        "{}/payments/redirect/{}/{}",
+        server.base_url, payment_intent.payment_id, payment_intent.merchant_id
     )
 }
 
@@ -418,6 +432,27 @@ pub fn verify_mandate_details(
     )
 }
 
+/// Truncates the card number of a card payment to its BIN and last four digits, which are stored
+/// on the payment attempt in place of the card number.
+pub fn get_card_bin_and_last4(
+    payment_method_data: &Option<api::PaymentMethod>,
+) -> (Option<String>, Option<String>) {
+    match payment_method_data {
+        Some(api::PaymentMethod::Card(card)) => {
+            let card_number = card.card_number.peek();
+            // Shorter numbers are not valid card numbers, and would be kept almost entirely
+            if card_number.len() < 13 || !card_number.bytes().all(|byte| byte.is_ascii_digit()) {
+                return (None, None);
+            }
+            (
+                Some(card_number[..6].to_string()),
+                Some(card_number[card_number.len() - 4..].to_string()),
+            )
+        }
+        _ => (None, None),
+    }
+}
+
 #[instrument(skip_all)]
 pub fn payment_attempt_status_fsm(
     payment_method_data: &Option<api::PaymentMethod>,
@@ -667,6 +702,30 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R>(
     ))
 }
 
+/// Fails with `CustomerNotFound` when existing customers are required and the request references
+/// a customer that does not exist, so that the customer is not created implicitly.
+#[instrument(skip_all)]
+pub async fn validate_customer_exists(
+    db: &dyn StorageInterface,
+    customer_settings: &CustomerSettings,
+    customer_details: Option<&CustomerDetails>,
+    merchant_id: &str,
+) -> RouterResult<()> {
+    let customer_id = match customer_details.and_then(|details| details.customer_id.as_ref()) {
+        Some(customer_id) if customer_settings.require_existing_customer => customer_id,
+        _ => return Ok(()),
+    };
+
+    db.find_customer_optional_by_customer_id_merchant_id(customer_id, merchant_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::CustomerNotFound))?
+        .ok_or(errors::ApiErrorResponse::CustomerNotFound)
+        .into_report()
+        .attach_printable_lazy(|| format!("customer {customer_id} does not exist"))?;
+
+    Ok(())
+}
+
 #[allow(clippy::too_many_arguments)]
 pub async fn make_pm_data<'a, F: Clone, R>(
     operation: BoxedOperation<'a, F, R>,
@@ -762,6 +821,8 @@ pub async fn make_pm_data<'a, F: Clone, R>(
             Ok(pm_opt.to_owned())
         }
         (pm @ Some(api::PaymentMethod::PayLater(_)), _) => Ok(pm.to_owned()),
+        (pm @ Some(api::PaymentMethod::Upi(_)), _) => Ok(pm.to_owned()),
+        (pm @ Some(api::PaymentMethod::BankDebit(_)), _) => Ok(pm.to_owned()),
         (pm_opt @ Some(pm @ api::PaymentMethod::Wallet(_)), _) => {
             let token = vault::Vault::store_payment_method_data_in_locker(
                 state,
@@ -797,18 +858,84 @@ pub(crate) fn validate_capture_method(
 }
 
 #[instrument(skip_all)]
-pub(crate) fn validate_status(status: storage_enums::IntentStatus) -> RouterResult<()> {
-    utils::when(
-        status != storage_enums::IntentStatus::RequiresCapture,
-        || {
-            Err(report!(errors::ApiErrorResponse::PaymentUnexpectedState {
-                field_name: "payment.status".to_string(),
-                current_flow: "captured".to_string(),
-                current_value: status.to_string(),
-                states: "requires_capture".to_string()
+pub(crate) fn validate_capture_on(
+    capture_method: Option<api_enums::CaptureMethod>,
+    capture_on: Option<time::PrimitiveDateTime>,
+) -> RouterResult<()> {
+    match (capture_method, capture_on) {
+        (Some(api_enums::CaptureMethod::Scheduled), None) => {
+            Err(report!(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "capture_on".to_string()
             }))
-        },
-    )
+        }
+        (Some(api_enums::CaptureMethod::Scheduled), Some(capture_on))
+            if capture_on <= common_utils::date_time::now() =>
+        {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "capture_on should be a time in the future".to_string()
+            }))
+        }
+        _ => Ok(()),
+    }
+}
+
+#[instrument(skip_all)]
+pub(crate) fn validate_status(
+    status: storage_enums::IntentStatus,
+    capture_method: storage_enums::CaptureMethod,
+) -> RouterResult<()> {
+    // Only payments authorized for multiple captures can be captured again after a partial capture
+    let (is_capturable, states) = match capture_method {
+        storage_enums::CaptureMethod::ManualMultiple => (
+            matches!(
+                status,
+                storage_enums::IntentStatus::RequiresCapture
+                    | storage_enums::IntentStatus::PartiallyCaptured
+            ),
+            "requires_capture, partially_captured",
+        ),
+        _ => (
+            status == storage_enums::IntentStatus::RequiresCapture,
+            "requires_capture",
+        ),
+    };
+
+    utils::when(!is_capturable, || {
+        Err(report!(errors::ApiErrorResponse::PaymentUnexpectedState {
+            field_name: "payment.status".to_string(),
+            current_flow: "captured".to_string(),
+            current_value: status.to_string(),
+            states: states.to_string()
+        }))
+    })
+}
+
+/// Total amount of the captures made against an authorization, including the ones still in
+/// flight. Failed captures are not counted.
+pub(crate) fn get_total_capture_amount(captures: &[storage::Capture]) -> i64 {
+    captures
+        .iter()
+        .filter(|capture| capture.status != storage_enums::CaptureStatus::Failed)
+        .map(|capture| capture.amount)
+        .sum()
+}
+
+#[instrument(skip_all)]
+pub(crate) fn validate_total_amount_to_capture(
+    authorized_amount: i64,
+    captures: &[storage::Capture],
+    amount_to_capture: i64,
+) -> RouterResult<()> {
+    let remaining_amount = authorized_amount - get_total_capture_amount(captures);
+
+    utils::when(amount_to_capture > remaining_amount, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "amount_to_capture is greater than the amount remaining on the authorization: {}",
+                remaining_amount
+            )
+        }))
+    })
 }
 
 #[instrument(skip_all)]
@@ -848,6 +975,22 @@ pub(crate) fn validate_payment_method_fields_present(
         },
     )?;
 
+    // The token generated by the wallet on the client is passed through to the connector
+    utils::when(
+        matches!(
+            req.payment_method_data,
+            Some(api::PaymentMethod::Wallet(api::WalletData {
+                issuer_name: api_enums::WalletIssuer::GooglePay | api_enums::WalletIssuer::ApplePay,
+                token: None,
+            }))
+        ),
+        || {
+            Err(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "payment_method_data.wallet.token".to_string(),
+            })
+        },
+    )?;
+
     Ok(())
 }
 
@@ -864,6 +1007,137 @@ pub fn can_call_connector(status: &storage_enums::AttemptStatus) -> bool {
     )
 }
 
+/// Selects a connector according to the percentage of payments to be routed to each connector.
+/// The payment ID is hashed into a bucket, so that a payment is always routed to the same
+/// connector.
+pub fn get_connector_by_volume_split(
+    payment_id: &str,
+    splits: &[api::ConnectorVolumeSplit],
+) -> Option<api_enums::RoutableConnectors> {
+    let bucket = crc32fast::hash(payment_id.as_bytes()) % 100;
+    let mut split_end = 0;
+
+    splits
+        .iter()
+        .find(|split| {
+            split_end += u32::from(split.split);
+            bucket < split_end
+        })
+        .map(|split| split.connector)
+}
+
+/// Returns the error with which the request to the connector failed, if the payment may be
+/// retried with another connector: the connection to the connector could not be established, or
+/// the connector responded with a server error other than a gateway timeout. Timeouts, and
+/// failures after the request was sent, are not retried, since the connector may have processed
+/// the payment.
+pub fn get_retryable_api_client_error(
+    error: &error_stack::Report<errors::ApiErrorResponse>,
+) -> Option<&errors::ApiClientError> {
+    error
+        .downcast_ref::<errors::ApiClientError>()
+        .filter(|error| {
+            matches!(
+                error,
+                errors::ApiClientError::ConnectionFailed(_)
+                    | errors::ApiClientError::InternalServerErrorReceived
+                    | errors::ApiClientError::BadGatewayReceived
+                    | errors::ApiClientError::ServiceUnavailableReceived
+            )
+        })
+}
+
+/// Whether the payment attempt was declined by the connector with an error that the connector
+/// considers retryable.
+pub fn is_retryable_attempt_failure(
+    connector: &api::ConnectorData,
+    payment_attempt: &storage::PaymentAttempt,
+) -> bool {
+    payment_attempt.status == storage_enums::AttemptStatus::Failure
+        && payment_attempt
+            .error_code
+            .as_deref()
+            .map_or(false, |error_code| {
+                connector.connector.is_retryable_error(error_code)
+            })
+}
+
+/// Creates a new payment attempt, along with its connector response, for retrying the payment
+/// with another connector. The new attempt is set as the current attempt of the payment, and as
+/// the active attempt of its payment intent.
+#[instrument(skip_all)]
+pub async fn insert_failover_payment_attempt<F: Clone>(
+    db: &dyn StorageInterface,
+    payment_data: &mut PaymentData<F>,
+    connector: &api::ConnectorData,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) -> RouterResult<()> {
+    let previous_attempt = &payment_data.payment_attempt;
+    let created_at @ modified_at @ last_synced = Some(common_utils::date_time::now());
+
+    let payment_attempt = storage::PaymentAttemptNew {
+        payment_id: previous_attempt.payment_id.clone(),
+        merchant_id: previous_attempt.merchant_id.clone(),
+        attempt_id: Uuid::new_v4().to_string(),
+        status: storage_enums::AttemptStatus::Pending,
+        amount: previous_attempt.amount,
+        currency: previous_attempt.currency,
+        save_to_locker: previous_attempt.save_to_locker,
+        connector: Some(connector.connector_name.to_string()),
+        offer_amount: previous_attempt.offer_amount,
+        surcharge_amount: previous_attempt.surcharge_amount,
+        tax_amount: previous_attempt.tax_amount,
+        payment_method_id: previous_attempt.payment_method_id.clone(),
+        payment_method: previous_attempt.payment_method,
+        payment_flow: previous_attempt.payment_flow,
+        capture_method: previous_attempt.capture_method,
+        capture_on: previous_attempt.capture_on,
+        confirm: previous_attempt.confirm,
+        authentication_type: previous_attempt.authentication_type,
+        created_at,
+        modified_at,
+        last_synced,
+        amount_to_capture: previous_attempt.amount_to_capture,
+        mandate_id: previous_attempt.mandate_id.clone(),
+        browser_info: previous_attempt.browser_info.clone(),
+        payment_token: previous_attempt.payment_token.clone(),
+        card_bin: previous_attempt.card_bin.clone(),
+        card_last4: previous_attempt.card_last4.clone(),
+        ..storage::PaymentAttemptNew::default()
+    };
+
+    let payment_attempt = db
+        .insert_payment_attempt(payment_attempt, storage_scheme)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the failover payment attempt")?;
+
+    payment_data.connector_response = db
+        .insert_connector_response(
+            super::PaymentCreate::make_connector_response(&payment_attempt),
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the connector response for the failover attempt")?;
+
+    payment_data.payment_intent = db
+        .update_payment_intent(
+            payment_data.payment_intent.clone(),
+            storage::PaymentIntentUpdate::ActiveAttemptUpdate {
+                active_attempt_id: payment_attempt.attempt_id.clone(),
+            },
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to set the failover attempt as the active attempt")?;
+
+    payment_data.payment_attempt = payment_attempt;
+
+    Ok(())
+}
+
 pub fn append_option<T, U, F, V>(func: F, option1: Option<T>, option2: Option<U>) -> Option<V>
 where
     F: FnOnce(T, U) -> V,
@@ -884,6 +1158,42 @@ pub(super) async fn filter_by_constraints(
     Ok(result)
 }
 
+/// Returns the constraints to fetch one payment intent more than the limit, which tells whether
+/// there are more payment intents to be listed after the page.
+#[cfg(feature = "olap")]
+pub(super) fn get_payment_list_lookahead_constraints(
+    constraints: &api::PaymentListConstraints,
+) -> api::PaymentListConstraints {
+    api::PaymentListConstraints {
+        limit: constraints.limit + 1,
+        ..constraints.clone()
+    }
+}
+
+/// Limits the payment intents fetched with [`get_payment_list_lookahead_constraints`] to a page,
+/// returning whether there are more payment intents to be listed. The payment intents are
+/// expected to be ordered from the newest to the oldest.
+#[cfg(feature = "olap")]
+pub(super) fn paginate_payment_intents(
+    mut payment_intents: Vec<storage::PaymentIntent>,
+    constraints: &api::PaymentListConstraints,
+) -> (Vec<storage::PaymentIntent>, bool) {
+    let limit = usize::try_from(constraints.limit).unwrap_or_default();
+    let has_more = payment_intents.len() > limit;
+
+    // The page before `ending_before` extends towards the newer payment intents, so the extra
+    // payment intent is the first one
+    if has_more {
+        if constraints.ending_before.is_some() && constraints.starting_after.is_none() {
+            payment_intents.drain(..payment_intents.len() - limit);
+        } else {
+            payment_intents.truncate(limit);
+        }
+    }
+
+    (payment_intents, has_more)
+}
+
 #[cfg(feature = "olap")]
 pub(super) fn validate_payment_list_request(
     req: &api::PaymentListConstraints,
@@ -958,12 +1268,19 @@ pub fn make_merchant_url_with_response(
     Ok(merchant_url_with_response.to_string())
 }
 
+/// Issues an ephemeral key for the customer, which can be used from the client instead of the
+/// API key of the merchant for managing the saved payment methods of that customer alone.
 pub async fn make_ephemeral_key(
     state: &AppState,
     customer_id: String,
     merchant_id: String,
 ) -> errors::RouterResponse<ephemeral_key::EphemeralKey> {
     let store = &state.store;
+    store
+        .find_customer_by_customer_id_merchant_id(&customer_id, &merchant_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::CustomerNotFound))?;
+
     let id = utils::generate_id(consts::ID_LENGTH, "eki");
     let secret = format!("epk_{}", &Uuid::new_v4().simple().to_string());
     let ek = ephemeral_key::EphemeralKeyNew {
@@ -983,11 +1300,21 @@ pub async fn make_ephemeral_key(
 pub async fn delete_ephemeral_key(
     store: &dyn StorageInterface,
     ek_id: String,
+    merchant_id: &str,
 ) -> errors::RouterResponse<ephemeral_key::EphemeralKey> {
+    let ek = store
+        .get_ephemeral_key(&ek_id)
+        .await
+        .map_err(|err| err.to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound))
+        .attach_printable("Unable to fetch ephemeral key")?;
+    utils::when(ek.merchant_id != merchant_id, || {
+        Err(errors::ApiErrorResponse::ResourceIdNotFound).into_report()
+    })?;
+
     let ek = store
         .delete_ephemeral_key(&ek_id)
         .await
-        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .map_err(|err| err.to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound))
         .attach_printable("Unable to delete ephemeral key")?;
     Ok(services::ApplicationResponse::Json(ek))
 }
@@ -1002,7 +1329,7 @@ pub fn make_pg_redirect_response(
         status: response.status,
         gateway_id: connector,
         customer_id: response.customer_id.to_owned(),
-        amount: Some(response.amount),
+        amount: Some(response.amount.get_amount_as_i64()),
     }
 }
 
@@ -1134,19 +1461,94 @@ pub fn generate_mandate(
     }
 }
 
+/// Returns the amount of the payment, from the request if it is provided, or from the existing
+/// payment intent otherwise. If the payment intent could not be found, the error is left to be
+/// reported by the payment operation.
+pub async fn get_payment_amount(
+    db: &dyn StorageInterface,
+    merchant_account: &storage::MerchantAccount,
+    req: &api::PaymentsRequest,
+) -> Option<api::Amount> {
+    match (req.amount, req.payment_id.as_ref()) {
+        (Some(amount), _) => Some(amount),
+        (None, Some(api::PaymentIdType::PaymentIntentId(payment_id))) => db
+            .find_payment_intent_by_payment_id_merchant_id(
+                payment_id,
+                &merchant_account.merchant_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .ok()
+            .map(|payment_intent| payment_intent.amount.into()),
+        (None, _) => None,
+    }
+}
+
+/// Generates a multi-use mandate for a payment set up for future off-session usage without any
+/// explicit mandate details, if the connector returned a mandate reference for it.
+pub fn generate_off_session_mandate(
+    merchant_id: String,
+    connector: String,
+    customer: &Option<storage::Customer>,
+    payment_method_id: String,
+    connector_mandate_id: Option<String>,
+) -> Option<storage::MandateNew> {
+    match (customer, connector_mandate_id) {
+        (Some(cus), Some(connector_mandate_id)) => {
+            let mandate_id = utils::generate_id(consts::ID_LENGTH, "man");
+
+            let mut new_mandate = storage::MandateNew::default();
+
+            Some(
+                new_mandate
+                    .set_mandate_id(mandate_id)
+                    .set_customer_id(cus.customer_id.clone())
+                    .set_merchant_id(merchant_id)
+                    .set_payment_method_id(payment_method_id)
+                    .set_connector(connector)
+                    .set_mandate_status(storage_enums::MandateStatus::Active)
+                    .set_connector_mandate_id(Some(connector_mandate_id))
+                    .set_mandate_type(storage_enums::MandateType::MultiUse)
+                    .to_owned(),
+            )
+        }
+        (_, _) => None,
+    }
+}
+
 // A function to manually authenticate the client secret
 pub(crate) fn authenticate_client_secret(
     request_client_secret: Option<&String>,
     payment_intent_client_secret: Option<&String>,
+    client_secret_expiry: Option<time::PrimitiveDateTime>,
 ) -> Result<(), errors::ApiErrorResponse> {
     match (request_client_secret, payment_intent_client_secret) {
-        (Some(req_cs), Some(pi_cs)) => utils::when(req_cs.ne(pi_cs), || {
-            Err(errors::ApiErrorResponse::ClientSecretInvalid)
-        }),
+        (Some(req_cs), Some(pi_cs)) => {
+            utils::when(req_cs.ne(pi_cs), || {
+                Err(errors::ApiErrorResponse::ClientSecretInvalid)
+            })?;
+            utils::when(
+                client_secret_expiry
+                    .map_or(false, |expiry| expiry < common_utils::date_time::now()),
+                || Err(errors::ApiErrorResponse::ClientSecretExpired),
+            )
+        }
         _ => Ok(()),
     }
 }
 
+/// Generates a client secret for the payment, along with the time after which it expires.
+pub fn create_client_secret(
+    client_secret_config: &ClientSecretConfig,
+    payment_id: &str,
+) -> (String, time::PrimitiveDateTime) {
+    let client_secret =
+        utils::generate_id(consts::ID_LENGTH, format!("{payment_id}_secret").as_str());
+    let client_secret_expiry = common_utils::date_time::now()
+        .saturating_add(time::Duration::seconds(client_secret_config.validity));
+    (client_secret, client_secret_expiry)
+}
+
 pub(crate) fn validate_pm_or_token_given(
     payment_method: &Option<api_enums::PaymentMethodType>,
     payment_method_data: &Option<api::PaymentMethod>,
@@ -1186,8 +1588,12 @@ pub(crate) async fn verify_client_secret(
                 .await
                 .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
 
-            authenticate_client_secret(Some(&cs), payment_intent.client_secret.as_ref())
-                .map_err(errors::ApiErrorResponse::from)?;
+            authenticate_client_secret(
+                Some(&cs),
+                payment_intent.client_secret.as_ref(),
+                payment_intent.client_secret_expiry,
+            )
+            .map_err(errors::ApiErrorResponse::from)?;
             Ok(payment_intent)
         })
         .await
@@ -1199,6 +1605,28 @@ pub(crate) fn get_payment_id_from_client_secret(cs: &str) -> String {
     cs.split('_').take(2).collect::<Vec<&str>>().join("_")
 }
 
+/// Collects the client keys configured in the metadata of the enabled connector accounts, which
+/// are public keys that the SDK needs to load the client side library of the connector.
+pub fn get_connector_client_keys(
+    connector_accounts: &[storage::MerchantConnectorAccount],
+) -> Vec<api::ConnectorClientKey> {
+    connector_accounts
+        .iter()
+        .filter(|connector_account| connector_account.disabled != Some(true))
+        .filter_map(|connector_account| {
+            let client_key = connector_account
+                .metadata
+                .as_ref()?
+                .get("client_key")?
+                .as_str()?;
+            Some(api::ConnectorClientKey {
+                connector: connector_account.connector_name.clone(),
+                client_key: client_key.to_string(),
+            })
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1207,6 +1635,204 @@ mod tests {
     fn test_authenticate_client_secret() {
         let req_cs = Some("1".to_string());
         let pi_cs = Some("2".to_string());
-        assert!(authenticate_client_secret(req_cs.as_ref(), pi_cs.as_ref()).is_err())
+        assert!(authenticate_client_secret(req_cs.as_ref(), pi_cs.as_ref(), None).is_err())
+    }
+
+    #[test]
+    fn test_authenticate_expired_client_secret() {
+        let cs = Some("1".to_string());
+        let expiry =
+            Some(common_utils::date_time::now().saturating_sub(time::Duration::seconds(1)));
+        assert!(matches!(
+            authenticate_client_secret(cs.as_ref(), cs.as_ref(), expiry),
+            Err(errors::ApiErrorResponse::ClientSecretExpired)
+        ));
+        assert!(authenticate_client_secret(cs.as_ref(), cs.as_ref(), None).is_ok())
+    }
+
+    #[test]
+    fn test_get_connector_by_volume_split() {
+        let splits = vec![
+            api::ConnectorVolumeSplit {
+                connector: api_enums::RoutableConnectors::Stripe,
+                split: 70,
+            },
+            api::ConnectorVolumeSplit {
+                connector: api_enums::RoutableConnectors::Adyen,
+                split: 30,
+            },
+        ];
+
+        let connectors = (0..1000)
+            .filter_map(|i| get_connector_by_volume_split(&format!("pay_{i}"), &splits))
+            .collect::<Vec<_>>();
+        assert_eq!(connectors.len(), 1000);
+
+        let stripe_count = connectors
+            .iter()
+            .filter(|connector| **connector == api_enums::RoutableConnectors::Stripe)
+            .count();
+        assert!((600..800).contains(&stripe_count));
+
+        // The same payment is always routed to the same connector
+        assert_eq!(
+            get_connector_by_volume_split("pay_1", &splits),
+            connectors.get(1).copied()
+        );
+    }
+
+    #[test]
+    fn test_get_retryable_api_client_error() {
+        let is_retryable = |error: errors::ApiClientError| {
+            let error =
+                report!(error).change_context(errors::ApiErrorResponse::InternalServerError);
+            get_retryable_api_client_error(&error).is_some()
+        };
+
+        assert!(is_retryable(errors::ApiClientError::ConnectionFailed(
+            "connection refused".to_string()
+        )));
+        assert!(is_retryable(
+            errors::ApiClientError::ServiceUnavailableReceived
+        ));
+        assert!(is_retryable(errors::ApiClientError::BadGatewayReceived));
+
+        // The connector may have processed the payment
+        assert!(!is_retryable(
+            errors::ApiClientError::GatewayTimeoutReceived
+        ));
+        assert!(!is_retryable(
+            errors::ApiClientError::RequestTimeoutReceived
+        ));
+        assert!(!is_retryable(errors::ApiClientError::RequestNotSent(
+            "connection closed before message completed".to_string()
+        )));
+    }
+
+    #[test]
+    fn test_get_connector_client_keys() {
+        let connector_account =
+            |connector_name: &str, metadata, disabled| storage::MerchantConnectorAccount {
+                id: 1,
+                merchant_id: "merchant".to_string(),
+                connector_name: connector_name.to_string(),
+                connector_account_details: serde_json::json!({}),
+                test_mode: None,
+                disabled,
+                merchant_connector_id: 1,
+                payment_methods_enabled: None,
+                connector_type: storage_enums::ConnectorType::PaymentProcessor,
+                metadata,
+                business_country: None,
+                business_label: None,
+                connector_label: connector_name.to_string(),
+            };
+        let connector_accounts = vec![
+            connector_account(
+                "adyen",
+                Some(serde_json::json!({ "client_key": "test_adyen_key" })),
+                None,
+            ),
+            connector_account(
+                "checkout",
+                Some(serde_json::json!({ "client_key": "pk_test_checkout" })),
+                Some(true),
+            ),
+            connector_account("stripe", None, Some(false)),
+        ];
+
+        let client_keys = get_connector_client_keys(&connector_accounts);
+        assert_eq!(client_keys.len(), 1);
+        assert_eq!(client_keys[0].connector, "adyen");
+        assert_eq!(client_keys[0].client_key, "test_adyen_key");
+    }
+
+    #[actix_rt::test]
+    #[allow(clippy::unwrap_used)]
+    async fn test_validate_customer_exists() {
+        let conf = crate::configs::settings::Settings::new().unwrap();
+        let state = AppState::with_storage(conf, crate::db::StorageImpl::Mock)
+            .await
+            .unwrap();
+        let db = &*state.store;
+        let customer_details = CustomerDetails {
+            customer_id: Some("cus_unknown".to_string()),
+            ..CustomerDetails::default()
+        };
+        let require_existing_customer = CustomerSettings {
+            require_existing_customer: true,
+        };
+
+        let result = validate_customer_exists(
+            db,
+            &require_existing_customer,
+            Some(&customer_details),
+            "merchant",
+        )
+        .await;
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::ApiErrorResponse::CustomerNotFound
+        ));
+
+        // Unknown customers are created by the payment unless existing customers are required
+        assert!(validate_customer_exists(
+            db,
+            &CustomerSettings::default(),
+            Some(&customer_details),
+            "merchant",
+        )
+        .await
+        .is_ok());
+
+        db.insert_customer(storage::CustomerNew {
+            customer_id: "cus_unknown".to_string(),
+            merchant_id: "merchant".to_string(),
+            ..storage::CustomerNew::default()
+        })
+        .await
+        .unwrap();
+        assert!(validate_customer_exists(
+            db,
+            &require_existing_customer,
+            Some(&customer_details),
+            "merchant",
+        )
+        .await
+        .is_ok());
+    }
+}
+
+/// Creates router data for a different connector flow from the given router data, carrying over
+/// the fields which are common to all flows.
+pub fn router_data_type_conversion<F1, F2, Req1, Req2, Res1, Res2>(
+    router_data: &types::RouterData<F1, Req1, Res1>,
+    request: Req2,
+    response: Result<Res2, types::ErrorResponse>,
+) -> types::RouterData<F2, Req2, Res2> {
+    types::RouterData {
+        flow: PhantomData,
+        request,
+        response,
+        merchant_id: router_data.merchant_id.clone(),
+        connector: router_data.connector.clone(),
+        payment_id: router_data.payment_id.clone(),
+        attempt_id: router_data.attempt_id.clone(),
+        status: router_data.status,
+        payment_method: router_data.payment_method,
+        connector_auth_type: router_data.connector_auth_type.clone(),
+        description: router_data.description.clone(),
+        return_url: router_data.return_url.clone(),
+        router_return_url: router_data.router_return_url.clone(),
+        address: router_data.address.clone(),
+        auth_type: router_data.auth_type,
+        connector_meta_data: router_data.connector_meta_data.clone(),
+        amount_captured: router_data.amount_captured,
+        access_token: router_data.access_token.clone(),
+        session_token: router_data.session_token.clone(),
+        payment_checks: router_data.payment_checks.clone(),
+        connector_api_log: None,
+        test_mode: router_data.test_mode,
+        payment_method_id: router_data.payment_method_id.clone(),
     }
 }
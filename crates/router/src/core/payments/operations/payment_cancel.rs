@@ -99,14 +99,23 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsCancelRequest>
         payment_attempt.cancellation_reason = request.cancellation_reason.clone();
 
         match payment_intent.status {
-            status if status != enums::IntentStatus::RequiresCapture => {
-                Err(errors::ApiErrorResponse::InvalidRequestData {
-                    message: "You cannot cancel the payment that has not been authorized"
-                        .to_string(),
+            enums::IntentStatus::Succeeded
+            | enums::IntentStatus::PartiallyCaptured
+            | enums::IntentStatus::Failed
+            | enums::IntentStatus::Cancelled
+            | enums::IntentStatus::Processing
+            | enums::IntentStatus::RequiresCustomerAction => {
+                Err(errors::ApiErrorResponse::PreconditionFailed {
+                    message: format!(
+                        "You cannot cancel this payment because it has status {}",
+                        payment_intent.status
+                    ),
                 }
                 .into())
             }
-            _ => Ok((
+            enums::IntentStatus::RequiresPaymentMethod
+            | enums::IntentStatus::RequiresConfirmation
+            | enums::IntentStatus::RequiresCapture => Ok((
                 Box::new(self),
                 PaymentData {
                     flow: PhantomData,
@@ -126,6 +135,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsCancelRequest>
                     payment_method_data: None,
                     force_sync: None,
                     refunds: vec![],
+                    captures: vec![],
                     connector_response,
                     sessions_token: vec![],
                     card_cvc: None,
@@ -154,11 +164,22 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsCancelRequest> for
         F: 'b + Send,
     {
         let cancellation_reason = payment_data.payment_attempt.cancellation_reason.clone();
+
+        // Only an authorized payment has to be voided at the connector, a payment which has not
+        // been confirmed yet is cancelled right away
+        let is_authorized =
+            payment_data.payment_intent.status == enums::IntentStatus::RequiresCapture;
+        let attempt_status = if is_authorized {
+            enums::AttemptStatus::VoidInitiated
+        } else {
+            enums::AttemptStatus::Voided
+        };
+
         payment_data.payment_attempt = db
             .update_payment_attempt(
                 payment_data.payment_attempt,
                 storage::PaymentAttemptUpdate::VoidUpdate {
-                    status: enums::AttemptStatus::VoidInitiated,
+                    status: attempt_status,
                     cancellation_reason,
                 },
                 storage_scheme,
@@ -166,6 +187,19 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsCancelRequest> for
             .await
             .map_err(|err| err.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
 
+        if !is_authorized {
+            payment_data.payment_intent = db
+                .update_payment_intent(
+                    payment_data.payment_intent,
+                    storage::PaymentIntentUpdate::CancelUpdate,
+                    storage_scheme,
+                )
+                .await
+                .map_err(|err| {
+                    err.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+                })?;
+        }
+
         Ok((Box::new(self), payment_data))
     }
 }
@@ -6,6 +6,7 @@ use router_env::{instrument, tracing};
 
 use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, ValidateRequest};
 use crate::{
+    consts,
     core::{
         errors::{self, RouterResult, StorageErrorExt},
         payments::{self, helpers, operations},
@@ -17,7 +18,7 @@ use crate::{
         storage::{self, enums},
         transformers::ForeignInto,
     },
-    utils::OptionExt,
+    utils::{self, OptionExt},
 };
 
 #[derive(Debug, Clone, Copy, router_derive::PaymentOperation)]
@@ -57,9 +58,10 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
                 error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
             })?;
 
-        helpers::validate_status(payment_intent.status)?;
-
-        helpers::validate_amount_to_capture(payment_intent.amount, request.amount_to_capture)?;
+        helpers::validate_amount_to_capture(
+            payment_intent.amount.get_amount_as_i64(),
+            request.amount_to_capture,
+        )?;
 
         payment_attempt = db
             .find_payment_attempt_by_payment_id_merchant_id(
@@ -72,16 +74,38 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
                 error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
             })?;
 
-        payment_attempt
-            .amount_to_capture
-            .update_value(request.amount_to_capture);
-
         let capture_method = payment_attempt
             .capture_method
             .get_required_value("capture_method")?;
 
         helpers::validate_capture_method(capture_method)?;
 
+        helpers::validate_status(payment_intent.status, capture_method)?;
+
+        let captures = db
+            .find_captures_by_merchant_id_payment_id(merchant_id, &payment_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!(
+                    "Failed while getting capture list for, payment_id: {}, merchant_id: {}",
+                    &payment_id, merchant_id
+                )
+            })?;
+
+        // Without an explicit amount, whatever remains on the authorization is captured
+        let amount_to_capture = request
+            .amount_to_capture
+            .unwrap_or(payment_attempt.amount - helpers::get_total_capture_amount(&captures));
+
+        helpers::validate_total_amount_to_capture(
+            payment_attempt.amount,
+            &captures,
+            amount_to_capture,
+        )?;
+
+        payment_attempt.amount_to_capture = Some(amount_to_capture);
+
         currency = payment_attempt.currency.get_required_value("currency")?;
 
         amount = payment_attempt.amount.into();
@@ -136,6 +160,7 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
                 confirm: None,
                 payment_method_data: None,
                 refunds: vec![],
+                captures,
                 connector_response,
                 sessions_token: vec![],
                 card_cvc: None,
@@ -152,9 +177,9 @@ impl<F: Clone> UpdateTracker<F, payments::PaymentData<F>, api::PaymentsCaptureRe
     #[instrument(skip_all)]
     async fn update_trackers<'b>(
         &'b self,
-        _db: &dyn StorageInterface,
+        db: &dyn StorageInterface,
         _payment_id: &api::PaymentIdType,
-        payment_data: payments::PaymentData<F>,
+        mut payment_data: payments::PaymentData<F>,
         _customer: Option<storage::Customer>,
         _storage_scheme: enums::MerchantStorageScheme,
     ) -> RouterResult<(
@@ -164,6 +189,33 @@ impl<F: Clone> UpdateTracker<F, payments::PaymentData<F>, api::PaymentsCaptureRe
     where
         F: 'b + Send,
     {
+        let payment_attempt = &payment_data.payment_attempt;
+        let capture_new = storage::CaptureNew {
+            capture_id: utils::generate_id(consts::ID_LENGTH, "cap"),
+            payment_id: payment_attempt.payment_id.clone(),
+            merchant_id: payment_attempt.merchant_id.clone(),
+            attempt_id: payment_attempt.attempt_id.clone(),
+            status: enums::CaptureStatus::Started,
+            amount: payment_attempt
+                .amount_to_capture
+                .unwrap_or(payment_attempt.amount),
+            currency: payment_data.currency,
+            connector: payment_attempt
+                .connector
+                .clone()
+                .get_required_value("connector")?,
+            connector_capture_id: None,
+            error_message: None,
+            error_code: None,
+        };
+
+        let capture = db
+            .insert_capture(capture_new)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while inserting capture")?;
+        payment_data.captures.push(capture);
+
         Ok((Box::new(self), payment_data))
     }
 }
@@ -1,6 +1,7 @@
 use std::marker::PhantomData;
 
 use async_trait::async_trait;
+use common_utils::ext_traits::AsyncExt;
 use error_stack::{report, ResultExt};
 use router_derive::PaymentOperation;
 use router_env::{instrument, tracing};
@@ -9,8 +10,9 @@ use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, Valida
 use crate::{
     core::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
+        forex,
         payments::{helpers, operations, CustomerDetails, PaymentAddress, PaymentData},
-        utils as core_utils,
+        surcharge, utils as core_utils,
     },
     db::StorageInterface,
     routes::AppState,
@@ -70,6 +72,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
         helpers::authenticate_client_secret(
             request.client_secret.as_ref(),
             payment_intent.client_secret.as_ref(),
+            payment_intent.client_secret_expiry,
         )?;
 
         let browser_info = request
@@ -106,6 +109,44 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
         currency = payment_attempt.currency.get_required_value("currency")?;
         amount = payment_attempt.amount.into();
 
+        // The amount is converted with the exchange rate at the time of the confirmation
+        let settlement_details = forex::get_settlement_details(
+            state,
+            payment_attempt.amount,
+            currency,
+            request
+                .settlement_currency
+                .map(ForeignInto::foreign_into)
+                .or(payment_attempt.settlement_currency),
+        )
+        .await?;
+        if let Some(settlement_details) = settlement_details {
+            payment_attempt.settlement_currency = Some(settlement_details.currency);
+            payment_attempt.settlement_amount = Some(settlement_details.amount);
+            payment_attempt.fx_rate = Some(settlement_details.fx_rate.to_string());
+        }
+
+        let card_network = match &request.payment_method_data {
+            Some(api::PaymentMethod::Card(card)) => surcharge::get_card_network(card),
+            _ => None,
+        };
+        let surcharge_details = payment_attempt
+            .payment_method
+            .map(|payment_method| {
+                surcharge::get_surcharge_details(
+                    merchant_account,
+                    payment_method,
+                    card_network,
+                    payment_attempt.amount,
+                )
+            })
+            .transpose()?
+            .flatten();
+        payment_attempt.surcharge_amount =
+            surcharge_details.map(|details| details.surcharge_amount);
+        payment_attempt.tax_amount =
+            surcharge_details.map(|details| details.tax_on_surcharge_amount);
+
         helpers::validate_customer_id_mandatory_cases(
             request.shipping.is_some(),
             request.billing.is_some(),
@@ -149,8 +190,17 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
         payment_intent.billing_address_id = billing_address.clone().map(|i| i.address_id);
         payment_intent.return_url = request.return_url.clone();
 
+        let mandate_id = request
+            .mandate_id
+            .as_ref()
+            .async_map(|mandate_id| helpers::get_mandate_ids(db, merchant_id, mandate_id))
+            .await
+            .transpose()?;
+
         match payment_intent.status {
-            enums::IntentStatus::Succeeded | enums::IntentStatus::Failed => {
+            enums::IntentStatus::Succeeded
+            | enums::IntentStatus::PartiallyCaptured
+            | enums::IntentStatus::Failed => {
                 Err(report!(errors::ApiErrorResponse::PreconditionFailed {
                     message: "You cannot confirm this Payment because it has already succeeded \
                               after being previously confirmed."
@@ -167,7 +217,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                     connector_response,
                     amount,
                     email: request.email.clone(),
-                    mandate_id: None,
+                    mandate_id,
                     setup_mandate,
                     token,
                     address: PaymentAddress {
@@ -178,6 +228,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                     payment_method_data: request.payment_method_data.clone(),
                     force_sync: None,
                     refunds: vec![],
+                    captures: vec![],
                     sessions_token: vec![],
                     card_cvc: request.card_cvc.clone(),
                 },
@@ -289,6 +340,13 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
 
         let connector = payment_data.payment_attempt.connector.clone();
         let payment_token = payment_data.token.clone();
+        let settlement_currency = payment_data.payment_attempt.settlement_currency;
+        let settlement_amount = payment_data.payment_attempt.settlement_amount;
+        let fx_rate = payment_data.payment_attempt.fx_rate.clone();
+        let surcharge_amount = payment_data.payment_attempt.surcharge_amount;
+        let tax_amount = payment_data.payment_attempt.tax_amount;
+        let (card_bin, card_last4) =
+            helpers::get_card_bin_and_last4(&payment_data.payment_method_data);
 
         payment_data.payment_attempt = db
             .update_payment_attempt(
@@ -302,6 +360,13 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
                     browser_info,
                     connector,
                     payment_token,
+                    settlement_currency,
+                    settlement_amount,
+                    fx_rate,
+                    surcharge_amount,
+                    tax_amount,
+                    card_bin,
+                    card_last4,
                 },
                 storage_scheme,
             )
@@ -9,11 +9,12 @@ use uuid::Uuid;
 
 use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, ValidateRequest};
 use crate::{
-    consts,
+    configs::settings::ClientSecretConfig,
     core::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
+        forex,
         payments::{self, helpers, operations, CustomerDetails, PaymentAddress, PaymentData},
-        utils as core_utils,
+        surcharge, utils as core_utils,
     },
     db::StorageInterface,
     routes::AppState,
@@ -98,6 +99,30 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 field_name: "browser_info",
             })?;
 
+        let settlement_details = forex::get_settlement_details(
+            state,
+            amount.into(),
+            currency,
+            request.settlement_currency.map(ForeignInto::foreign_into),
+        )
+        .await?;
+
+        let card_network = match &request.payment_method_data {
+            Some(api::PaymentMethod::Card(card)) => surcharge::get_card_network(card),
+            _ => None,
+        };
+        let surcharge_details = payment_method_type
+            .map(|payment_method| {
+                surcharge::get_surcharge_details(
+                    merchant_account,
+                    payment_method,
+                    card_network,
+                    amount.into(),
+                )
+            })
+            .transpose()?
+            .flatten();
+
         payment_attempt = db
             .insert_payment_attempt(
                 Self::make_payment_attempt(
@@ -107,6 +132,8 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                     payment_method_type,
                     request,
                     browser_info,
+                    settlement_details,
+                    surcharge_details,
                 ),
                 storage_scheme,
             )
@@ -122,10 +149,12 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 Self::make_payment_intent(
                     &payment_id,
                     merchant_id,
+                    &payment_attempt.attempt_id,
                     money,
                     request,
                     shipping_address.clone().map(|x| x.address_id),
                     billing_address.clone().map(|x| x.address_id),
+                    &state.conf.client_secret,
                 )?,
                 storage_scheme,
             )
@@ -150,16 +179,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
         let mandate_id = request
             .mandate_id
             .as_ref()
-            .async_and_then(|mandate_id| async {
-                let mandate = db
-                    .find_mandate_by_merchant_id_mandate_id(merchant_id, mandate_id)
-                    .await
-                    .change_context(errors::ApiErrorResponse::MandateNotFound);
-                Some(mandate.map(|mandate_obj| api_models::payments::MandateIds {
-                    mandate_id: mandate_obj.mandate_id,
-                    connector_mandate_id: mandate_obj.connector_mandate_id,
-                }))
-            })
+            .async_map(|mandate_id| helpers::get_mandate_ids(db, merchant_id, mandate_id))
             .await
             .transpose()?;
 
@@ -188,6 +208,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 confirm: request.confirm,
                 payment_method_data: request.payment_method_data.clone(),
                 refunds: vec![],
+                captures: vec![],
                 force_sync: None,
                 connector_response,
                 sessions_token: vec![],
@@ -369,6 +390,8 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest> for PaymentCreate
 
         helpers::validate_payment_method_fields_present(request)?;
 
+        helpers::validate_capture_on(request.capture_method, request.capture_on)?;
+
         let payment_id = core_utils::get_or_generate_id("payment_id", &given_payment_id, "pay")?;
 
         let mandate_type = helpers::validate_mandate(request)?;
@@ -410,11 +433,14 @@ impl PaymentCreate {
         payment_method: Option<enums::PaymentMethodType>,
         request: &api::PaymentsRequest,
         browser_info: Option<serde_json::Value>,
+        settlement_details: Option<forex::SettlementDetails>,
+        surcharge_details: Option<surcharge::SurchargeDetails>,
     ) -> storage::PaymentAttemptNew {
         let created_at @ modified_at @ last_synced = Some(common_utils::date_time::now());
         let status =
             helpers::payment_attempt_status_fsm(&request.payment_method_data, request.confirm);
         let (amount, currency) = (money.0, Some(money.1));
+        let (card_bin, card_last4) = helpers::get_card_bin_and_last4(&request.payment_method_data);
         storage::PaymentAttemptNew {
             payment_id: payment_id.to_string(),
             merchant_id: merchant_id.to_string(),
@@ -431,24 +457,34 @@ impl PaymentCreate {
             last_synced,
             authentication_type: request.authentication_type.map(ForeignInto::foreign_into),
             browser_info,
+            settlement_currency: settlement_details.map(|details| details.currency),
+            settlement_amount: settlement_details.map(|details| details.amount),
+            fx_rate: settlement_details.map(|details| details.fx_rate.to_string()),
+            surcharge_amount: surcharge_details.map(|details| details.surcharge_amount),
+            tax_amount: surcharge_details.map(|details| details.tax_on_surcharge_amount),
+            card_bin,
+            card_last4,
             ..storage::PaymentAttemptNew::default()
         }
     }
 
     #[instrument(skip_all)]
+    #[allow(clippy::too_many_arguments)]
     fn make_payment_intent(
         payment_id: &str,
         merchant_id: &str,
+        active_attempt_id: &str,
         money: (api::Amount, enums::Currency),
         request: &api::PaymentsRequest,
         shipping_address_id: Option<String>,
         billing_address_id: Option<String>,
+        client_secret_config: &ClientSecretConfig,
     ) -> RouterResult<storage::PaymentIntentNew> {
         let created_at @ modified_at @ last_synced = Some(common_utils::date_time::now());
         let status =
             helpers::payment_intent_status_fsm(&request.payment_method_data, request.confirm);
-        let client_secret =
-            crate::utils::generate_id(consts::ID_LENGTH, format!("{payment_id}_secret").as_str());
+        let (client_secret, client_secret_expiry) =
+            helpers::create_client_secret(client_secret_config, payment_id);
         let (amount, currency) = (money.0, Some(money.1));
         let metadata = request
             .metadata
@@ -468,6 +504,7 @@ impl PaymentCreate {
             modified_at,
             last_synced,
             client_secret: Some(client_secret),
+            client_secret_expiry: Some(client_secret_expiry),
             setup_future_usage: request.setup_future_usage.map(ForeignInto::foreign_into),
             off_session: request.off_session,
             return_url: request.return_url.clone(),
@@ -476,6 +513,9 @@ impl PaymentCreate {
             statement_descriptor_name: request.statement_descriptor_name.clone(),
             statement_descriptor_suffix: request.statement_descriptor_suffix.clone(),
             metadata,
+            business_country: request.business_country.clone(),
+            business_label: request.business_label.clone(),
+            active_attempt_id: Some(active_attempt_id.to_string()),
             ..storage::PaymentIntentNew::default()
         })
     }
@@ -1,7 +1,7 @@
 use std::marker::PhantomData;
 
 use async_trait::async_trait;
-use common_utils::{date_time, errors::CustomResult};
+use common_utils::{date_time, errors::CustomResult, types::MinorUnit};
 use error_stack::ResultExt;
 use router_derive::PaymentOperation;
 use router_env::{instrument, tracing};
@@ -9,7 +9,7 @@ use uuid::Uuid;
 
 use super::{BoxedOperation, Domain, GetTracker, PaymentCreate, UpdateTracker, ValidateRequest};
 use crate::{
-    consts,
+    configs::settings::ClientSecretConfig,
     core::{
         errors::{self, RouterResult, StorageErrorExt},
         payments::{self, helpers, operations, Operation, PaymentData},
@@ -23,7 +23,6 @@ use crate::{
         storage::{self, enums as storage_enums},
         transformers::ForeignInto,
     },
-    utils,
 };
 
 #[derive(Debug, Clone, Copy, PaymentOperation)]
@@ -106,7 +105,13 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::VerifyRequest> for Paym
 
         payment_intent = match db
             .insert_payment_intent(
-                Self::make_payment_intent(&payment_id, merchant_id, request),
+                Self::make_payment_intent(
+                    &payment_id,
+                    merchant_id,
+                    &payment_attempt.attempt_id,
+                    request,
+                    &state.conf.client_secret,
+                ),
                 storage_scheme,
             )
             .await
@@ -149,6 +154,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::VerifyRequest> for Paym
                 address: types::PaymentAddress::default(),
                 force_sync: None,
                 refunds: vec![],
+                captures: vec![],
                 sessions_token: vec![],
                 card_cvc: None,
             },
@@ -291,26 +297,30 @@ impl PaymentMethodValidate {
     fn make_payment_intent(
         payment_id: &str,
         merchant_id: &str,
+        active_attempt_id: &str,
         request: &api::VerifyRequest,
+        client_secret_config: &ClientSecretConfig,
     ) -> storage::PaymentIntentNew {
         let created_at @ modified_at @ last_synced = Some(date_time::now());
         let status = helpers::payment_intent_status_fsm(&request.payment_method_data, Some(true));
 
-        let client_secret =
-            utils::generate_id(consts::ID_LENGTH, format!("{}_secret", payment_id).as_str());
+        let (client_secret, client_secret_expiry) =
+            helpers::create_client_secret(client_secret_config, payment_id);
         storage::PaymentIntentNew {
             payment_id: payment_id.to_string(),
             merchant_id: merchant_id.to_string(),
             status,
-            amount: 0,
+            amount: MinorUnit::zero(),
             currency: Default::default(),
             connector_id: None,
             created_at,
             modified_at,
             last_synced,
             client_secret: Some(client_secret),
+            client_secret_expiry: Some(client_secret_expiry),
             setup_future_usage: request.setup_future_usage.map(ForeignInto::foreign_into),
             off_session: request.off_session,
+            active_attempt_id: Some(active_attempt_id.to_string()),
             ..Default::default()
         }
     }
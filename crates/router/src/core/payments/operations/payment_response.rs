@@ -1,12 +1,13 @@
 use async_trait::async_trait;
-use error_stack::ResultExt;
+use common_utils::types::MinorUnit;
+use error_stack::{IntoReport, ResultExt};
 use router_derive;
 
 use super::{Operation, PostUpdateTracker};
 use crate::{
     core::{
         errors::{self, RouterResult, StorageErrorExt},
-        payments::PaymentData,
+        payments::{self, PaymentData},
     },
     db::StorageInterface,
     services::RedirectForm,
@@ -47,9 +48,90 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsAuthorizeData
         payment_data.mandate_id = payment_data
             .mandate_id
             .or_else(|| router_data.request.mandate_id.clone());
+        // The authentication data is stored only when it was obtained during this request
+        let authentication_data = router_data.request.authentication_data.clone().filter(|_| {
+            payment_data
+                .payment_attempt
+                .three_ds_authentication_data
+                .is_none()
+        });
+        let frm_data = router_data.request.frm_data.clone();
 
-        payment_response_update_tracker(db, payment_id, payment_data, router_data, storage_scheme)
-            .await
+        let mut payment_data = payment_response_update_tracker(
+            db,
+            payment_id,
+            payment_data,
+            router_data,
+            storage_scheme,
+        )
+        .await?;
+
+        if let Some(authentication_data) = authentication_data {
+            let three_ds_authentication_data =
+                utils::Encode::<types::ThreeDsAuthenticationData>::encode_to_value(
+                    &authentication_data,
+                )
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Could not encode the 3DS authentication data")?;
+            payment_data.payment_attempt = db
+                .update_payment_attempt(
+                    payment_data.payment_attempt,
+                    storage::PaymentAttemptUpdate::ThreeDsAuthenticationDataUpdate {
+                        three_ds_authentication_data,
+                    },
+                    storage_scheme,
+                )
+                .await
+                .map_err(|error| {
+                    error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+                })?;
+        }
+
+        if let Some(frm_data) = frm_data {
+            let frm_data = utils::Encode::<types::FrmData>::encode_to_value(&frm_data)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Could not encode the FRM data")?;
+            // Payments which the fraud check left authorized are captured by the merchant
+            let payment_attempt = &payment_data.payment_attempt;
+            let capture_method = (payment_attempt.status == enums::AttemptStatus::Authorized
+                && matches!(
+                    payment_attempt.capture_method,
+                    None | Some(enums::CaptureMethod::Automatic)
+                ))
+            .then_some(enums::CaptureMethod::Manual);
+            payment_data.payment_attempt = db
+                .update_payment_attempt(
+                    payment_data.payment_attempt,
+                    storage::PaymentAttemptUpdate::FrmDataUpdate {
+                        frm_data,
+                        capture_method,
+                    },
+                    storage_scheme,
+                )
+                .await
+                .map_err(|error| {
+                    error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+                })?;
+        }
+
+        let payment_attempt = &payment_data.payment_attempt;
+        if let (
+            enums::AttemptStatus::Authorized,
+            Some(enums::CaptureMethod::Scheduled),
+            Some(capture_on),
+        ) = (
+            payment_attempt.status,
+            payment_attempt.capture_method,
+            payment_attempt.capture_on,
+        ) {
+            payments::add_process_capture_task(db, payment_attempt, capture_on)
+                .await
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while adding capture task to process tracker")?;
+        }
+
+        Ok(payment_data)
     }
 }
 
@@ -99,13 +181,67 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsCaptureData>
         &'b self,
         db: &dyn StorageInterface,
         payment_id: &api::PaymentIdType,
-        payment_data: PaymentData<F>,
-        response: types::RouterData<F, types::PaymentsCaptureData, types::PaymentsResponseData>,
+        mut payment_data: PaymentData<F>,
+        mut response: types::RouterData<F, types::PaymentsCaptureData, types::PaymentsResponseData>,
         storage_scheme: enums::MerchantStorageScheme,
     ) -> RouterResult<PaymentData<F>>
     where
         F: 'b + Send,
     {
+        // The capture being made is the last one, inserted while updating the trackers
+        let current_capture = payment_data.captures.pop();
+        let previously_captured: i64 = payment_data
+            .captures
+            .iter()
+            .filter(|capture| capture.status == enums::CaptureStatus::Charged)
+            .map(|capture| capture.amount)
+            .sum();
+
+        // Connectors report a successful capture as `Charged` irrespective of the amount, so a
+        // capture for less than the authorized amount is recorded as a partial charge here.
+        if response.response.is_ok() && response.status == enums::AttemptStatus::Charged {
+            let amount_captured = previously_captured
+                + response
+                    .request
+                    .amount_to_capture
+                    .unwrap_or(response.request.amount);
+            if amount_captured < response.request.amount {
+                response.status = enums::AttemptStatus::PartialCharged;
+            }
+            response.amount_captured = Some(amount_captured);
+        }
+
+        if let Some(capture) = current_capture {
+            let capture_update = match &response.response {
+                Err(err) => storage::CaptureUpdate::ErrorUpdate {
+                    status: enums::CaptureStatus::Failed,
+                    error_message: Some(err.message.clone()),
+                    error_code: Some(err.code.clone()),
+                },
+                Ok(types::PaymentsResponseData::TransactionResponse { resource_id, .. }) => {
+                    storage::CaptureUpdate::ResponseUpdate {
+                        status: response.status.foreign_into(),
+                        connector_capture_id: match resource_id {
+                            types::ResponseId::NoResponseId => None,
+                            types::ResponseId::ConnectorTransactionId(id)
+                            | types::ResponseId::EncodedData(id) => Some(id.clone()),
+                        },
+                    }
+                }
+                Ok(_) => storage::CaptureUpdate::ResponseUpdate {
+                    status: response.status.foreign_into(),
+                    connector_capture_id: None,
+                },
+            };
+
+            let capture = db
+                .update_capture(capture, capture_update)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while updating capture")?;
+            payment_data.captures.push(capture);
+        }
+
         payment_response_update_tracker(db, payment_id, payment_data, response, storage_scheme)
             .await
     }
@@ -168,6 +304,8 @@ async fn payment_response_update_tracker<F: Clone, T>(
                 status: storage::enums::AttemptStatus::Failure,
                 error_message: Some(err.message),
                 error_code: Some(err.code),
+                connector_api_log: router_data.connector_api_log.clone(),
+                test_mode: router_data.test_mode,
             }),
             Some(storage::ConnectorResponseUpdate::ErrorUpdate {
                 connector_name: Some(router_data.connector.clone()),
@@ -196,6 +334,17 @@ async fn payment_response_update_tracker<F: Clone, T>(
                     .change_context(errors::ApiErrorResponse::InternalServerError)
                     .attach_printable("Could not parse the connector response")?;
 
+                let payment_checks = router_data
+                    .payment_checks
+                    .map(|payment_checks| {
+                        utils::Encode::<api_models::payments::PaymentChecks>::encode_to_value(
+                            &payment_checks,
+                        )
+                    })
+                    .transpose()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Could not encode the payment checks")?;
+
                 let payment_attempt_update = storage::PaymentAttemptUpdate::ResponseUpdate {
                     status: router_data.status,
                     connector: Some(router_data.connector),
@@ -208,6 +357,9 @@ async fn payment_response_update_tracker<F: Clone, T>(
                         .clone()
                         .map(|mandate| mandate.mandate_id),
                     connector_metadata,
+                    payment_checks,
+                    connector_api_log: router_data.connector_api_log,
+                    test_mode: router_data.test_mode,
                 };
 
                 let connector_response_update = storage::ConnectorResponseUpdate::ResponseUpdate {
@@ -262,7 +414,7 @@ async fn payment_response_update_tracker<F: Clone, T>(
         Ok(_) => storage::PaymentIntentUpdate::ResponseUpdate {
             status: router_data.status.foreign_into(),
             return_url: router_data.return_url,
-            amount_captured: router_data.amount_captured,
+            amount_captured: router_data.amount_captured.map(MinorUnit::new),
         },
     };
 
@@ -80,6 +80,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsSessionRequest>
         helpers::authenticate_client_secret(
             Some(&request.client_secret),
             payment_intent.client_secret.as_ref(),
+            payment_intent.client_secret_expiry,
         )?;
 
         let shipping_address = helpers::get_address_for_payment_request(
@@ -145,6 +146,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsSessionRequest>
                 payment_method_data: None,
                 force_sync: None,
                 refunds: vec![],
+                captures: vec![],
                 sessions_token: vec![],
                 connector_response,
                 card_cvc: None,
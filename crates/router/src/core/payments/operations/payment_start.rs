@@ -112,7 +112,9 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsStartRequest> f
             })?;
 
         match payment_intent.status {
-            enums::IntentStatus::Succeeded | enums::IntentStatus::Failed => {
+            enums::IntentStatus::Succeeded
+            | enums::IntentStatus::PartiallyCaptured
+            | enums::IntentStatus::Failed => {
                 Err(report!(errors::ApiErrorResponse::PreconditionFailed {
                     message: "You cannot confirm this Payment because it has already succeeded \
                               after being previously confirmed."
@@ -140,6 +142,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsStartRequest> f
                     payment_method_data: None,
                     force_sync: None,
                     refunds: vec![],
+                    captures: vec![],
                     sessions_token: vec![],
                     card_cvc: None,
                 },
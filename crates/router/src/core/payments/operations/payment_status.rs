@@ -242,6 +242,17 @@ async fn get_tracker_for_sync<
             )
         })?;
 
+    let captures = db
+        .find_captures_by_merchant_id_payment_id(merchant_id, &payment_id_str)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while getting capture list for, payment_id: {}, merchant_id: {}",
+                &payment_id_str, merchant_id
+            )
+        })?;
+
     Ok((
         Box::new(operation),
         PaymentData {
@@ -265,6 +276,7 @@ async fn get_tracker_for_sync<
             ),
             payment_attempt,
             refunds,
+            captures,
             sessions_token: vec![],
             card_cvc: None,
         },
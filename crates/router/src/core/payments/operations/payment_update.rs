@@ -91,6 +91,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
         helpers::authenticate_client_secret(
             request.client_secret.as_ref(),
             payment_intent.client_secret.as_ref(),
+            payment_intent.client_secret_expiry,
         )?;
 
         if request.confirm.unwrap_or(false) {
@@ -154,16 +155,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
         let mandate_id = request
             .mandate_id
             .as_ref()
-            .async_and_then(|mandate_id| async {
-                let mandate = db
-                    .find_mandate_by_merchant_id_mandate_id(merchant_id, mandate_id)
-                    .await
-                    .change_context(errors::ApiErrorResponse::MandateNotFound);
-                Some(mandate.map(|mandate_obj| api_models::payments::MandateIds {
-                    mandate_id: mandate_obj.mandate_id,
-                    connector_mandate_id: mandate_obj.connector_mandate_id,
-                }))
-            })
+            .async_map(|mandate_id| helpers::get_mandate_ids(db, merchant_id, mandate_id))
             .await
             .transpose()?;
         let next_operation: BoxedOperation<'a, F, api::PaymentsRequest> =
@@ -175,6 +167,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
 
         match payment_intent.status {
             enums::IntentStatus::Succeeded
+            | enums::IntentStatus::PartiallyCaptured
             | enums::IntentStatus::Failed
             | enums::IntentStatus::RequiresCapture => {
                 Err(report!(errors::ApiErrorResponse::PreconditionFailed {
@@ -204,6 +197,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                     payment_method_data: request.payment_method_data.clone(),
                     force_sync: None,
                     refunds: vec![],
+                    captures: vec![],
                     connector_response,
                     sessions_token: vec![],
                     card_cvc: request.card_cvc.clone(),
@@ -1,5 +1,6 @@
 use std::{fmt::Debug, marker::PhantomData};
 
+use common_utils::types::MinorUnit;
 use error_stack::ResultExt;
 use router_env::{instrument, tracing};
 
@@ -8,7 +9,9 @@ use crate::{
     configs::settings::Server,
     core::{
         errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        forex,
         payments::{self, helpers},
+        surcharge, utils as core_utils,
     },
     routes::AppState,
     services::{self, RedirectForm},
@@ -35,21 +38,22 @@ where
 {
     let (merchant_connector_account, payment_method, router_data);
     let db = &*state.store;
+    let connector_label = core_utils::get_connector_label(
+        connector_id,
+        payment_data.payment_intent.business_country.as_ref(),
+        payment_data.payment_intent.business_label.as_ref(),
+    );
     merchant_connector_account = db
-        .find_merchant_connector_account_by_merchant_id_connector(
+        .find_merchant_connector_account_by_merchant_id_connector_label(
             &merchant_account.merchant_id,
-            connector_id,
+            &connector_label,
         )
         .await
         .map_err(|error| {
             error.to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)
         })?;
 
-    let auth_type: types::ConnectorAuthType = merchant_connector_account
-        .connector_account_details
-        .parse_value("ConnectorAuthType")
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Failed while parsing value for ConnectorAuthType")?;
+    let auth_type = core_utils::get_connector_auth_type(state, &merchant_connector_account).await?;
 
     payment_method = payment_data
         .payment_attempt
@@ -95,9 +99,19 @@ where
             .authentication_type
             .unwrap_or_default(),
         connector_meta_data: merchant_connector_account.metadata,
-        request: T::try_from(payment_data.clone())?,
+        request: T::try_from(forex::with_settlement_amounts(
+            surcharge::with_surcharge_amounts(payment_data.clone())?,
+        )?)?,
         response: response.map_or_else(|| Err(types::ErrorResponse::default()), Ok),
-        amount_captured: payment_data.payment_intent.amount_captured,
+        amount_captured: payment_data
+            .payment_intent
+            .amount_captured
+            .map(MinorUnit::get_amount_as_i64),
+        access_token: None,
+        session_token: None,
+        payment_checks: None,
+        connector_api_log: None,
+        test_mode: merchant_connector_account.test_mode,
     };
 
     Ok(router_data)
@@ -137,6 +151,7 @@ where
             payment_data.payment_attempt,
             payment_data.payment_intent,
             payment_data.refunds,
+            payment_data.captures,
             payment_data.payment_method_data,
             customer,
             auth_flow,
@@ -170,6 +185,7 @@ where
                 .client_secret
                 .get_required_value("client_secret")?
                 .into(),
+            connector_client_keys: vec![],
         }))
     }
 }
@@ -226,6 +242,7 @@ pub fn payments_to_payments_response<R, Op>(
     payment_attempt: storage::PaymentAttempt,
     payment_intent: storage::PaymentIntent,
     refunds: Vec<storage::Refund>,
+    captures: Vec<storage::Capture>,
     payment_method_data: Option<api::PaymentMethod>,
     customer: Option<storage::Customer>,
     auth_flow: services::AuthFlow,
@@ -244,11 +261,36 @@ where
         .get_required_value("currency")?
         .to_string();
     let mandate_id = payment_attempt.mandate_id.clone();
+    let payment_checks: Option<api_models::payments::PaymentChecks> = payment_attempt
+        .payment_checks
+        .clone()
+        .map(|payment_checks| payment_checks.parse_value("PaymentChecks"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the payment checks of the payment attempt")?;
+    let frm_data: Option<types::FrmData> = payment_attempt
+        .frm_data
+        .clone()
+        .map(|frm_data| frm_data.parse_value("FrmData"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the FRM data of the payment attempt")?;
+    let frm_decision = frm_data.map(|frm_data| frm_data.decision);
     let refunds_response = if refunds.is_empty() {
         None
     } else {
         Some(refunds.into_iter().map(ForeignInto::foreign_into).collect())
     };
+    let captures_response = if captures.is_empty() {
+        None
+    } else {
+        Some(
+            captures
+                .into_iter()
+                .map(ForeignInto::foreign_into)
+                .collect(),
+        )
+    };
 
     Ok(match payment_request {
         Some(request) => {
@@ -267,7 +309,6 @@ where
                         next_action_type: api::NextActionType::RedirectToUrl,
                         redirect_to_url: Some(helpers::create_startpay_url(
                             server,
-                            &payment_attempt,
                             &payment_intent,
                         )),
                     })
@@ -278,7 +319,7 @@ where
                         .set_payment_id(Some(payment_attempt.payment_id))
                         .set_merchant_id(Some(payment_attempt.merchant_id))
                         .set_status(payment_intent.status.foreign_into())
-                        .set_amount(payment_attempt.amount)
+                        .set_amount(MinorUnit::new(payment_attempt.amount))
                         .set_amount_capturable(None)
                         .set_amount_received(payment_intent.amount_captured)
                         .set_connector(payment_attempt.connector)
@@ -304,6 +345,7 @@ where
                         .set_mandate_id(mandate_id)
                         .set_description(payment_intent.description)
                         .set_refunds(refunds_response) // refunds.iter().map(refund_to_refund_response),
+                        .set_captures(captures_response)
                         .set_payment_method(
                             payment_attempt
                                 .payment_method
@@ -315,8 +357,21 @@ where
                             auth_flow == services::AuthFlow::Merchant,
                         )
                         .set_payment_token(payment_attempt.payment_token)
+                        .set_payment_method_id(payment_attempt.payment_method_id)
                         .set_error_message(payment_attempt.error_message)
                         .set_error_code(payment_attempt.error_code)
+                        .set_payment_checks(payment_checks)
+                        .set_frm_decision(frm_decision)
+                        .set_settlement_currency(
+                            payment_attempt
+                                .settlement_currency
+                                .map(ForeignInto::foreign_into),
+                        )
+                        .set_settlement_amount(payment_attempt.settlement_amount)
+                        .set_fx_rate(payment_attempt.fx_rate)
+                        .set_surcharge_amount(payment_attempt.surcharge_amount)
+                        .set_tax_on_surcharge_amount(payment_attempt.tax_amount)
+                        .set_test_mode(payment_attempt.test_mode)
                         .set_shipping(address.shipping)
                         .set_billing(address.billing)
                         .to_owned()
@@ -348,7 +403,7 @@ where
             payment_id: Some(payment_attempt.payment_id),
             merchant_id: Some(payment_attempt.merchant_id),
             status: payment_intent.status.foreign_into(),
-            amount: payment_attempt.amount,
+            amount: MinorUnit::new(payment_attempt.amount),
             amount_capturable: None,
             amount_received: payment_intent.amount_captured,
             client_secret: payment_intent.client_secret.map(masking::Secret::new),
@@ -357,6 +412,7 @@ where
             customer_id: payment_intent.customer_id,
             description: payment_intent.description,
             refunds: refunds_response,
+            captures: captures_response,
             payment_method: payment_attempt
                 .payment_method
                 .map(ForeignInto::foreign_into),
@@ -380,6 +436,17 @@ where
             billing: address.billing,
             cancellation_reason: payment_attempt.cancellation_reason,
             payment_token: payment_attempt.payment_token,
+            payment_method_id: payment_attempt.payment_method_id,
+            payment_checks,
+            frm_decision,
+            settlement_currency: payment_attempt
+                .settlement_currency
+                .map(ForeignInto::foreign_into),
+            settlement_amount: payment_attempt.settlement_amount,
+            fx_rate: payment_attempt.fx_rate,
+            surcharge_amount: payment_attempt.surcharge_amount,
+            tax_on_surcharge_amount: payment_attempt.tax_amount,
+            test_mode: payment_attempt.test_mode,
             ..Default::default()
         }),
     })
@@ -414,6 +481,16 @@ impl<F: Clone> TryFrom<PaymentData<F>> for types::PaymentsAuthorizeData {
 
         let order_details = parsed_metadata.and_then(|data| data.order_details);
 
+        // Payments which were authenticated earlier, for example before being retried with
+        // another connector, are not authenticated again
+        let authentication_data: Option<types::ThreeDsAuthenticationData> = payment_data
+            .payment_attempt
+            .three_ds_authentication_data
+            .map(|data| data.parse_value("ThreeDsAuthenticationData"))
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the 3DS authentication data")?;
+
         Ok(Self {
             payment_method_data: payment_data
                 .payment_method_data
@@ -424,12 +501,21 @@ impl<F: Clone> TryFrom<PaymentData<F>> for types::PaymentsAuthorizeData {
             setup_mandate_details: payment_data.setup_mandate.clone(),
             confirm: payment_data.payment_attempt.confirm,
             statement_descriptor_suffix: payment_data.payment_intent.statement_descriptor_suffix,
-            capture_method: payment_data.payment_attempt.capture_method,
+            // Scheduled captures are made by the scheduler, so they are only authorized here
+            capture_method: payment_data
+                .payment_attempt
+                .capture_method
+                .map(|capture_method| match capture_method {
+                    enums::CaptureMethod::Scheduled => enums::CaptureMethod::Manual,
+                    capture_method => capture_method,
+                }),
             amount: payment_data.amount.into(),
             currency: payment_data.currency,
             browser_info,
             email: payment_data.email,
             order_details,
+            authentication_data,
+            frm_data: None,
         })
     }
 }
@@ -463,6 +549,7 @@ impl<F: Clone> TryFrom<PaymentData<F>> for types::PaymentsCaptureData {
                 .connector_transaction_id
                 .ok_or(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)?,
             amount: payment_data.amount.into(),
+            connector_meta: payment_data.payment_attempt.connector_metadata,
         })
     }
 }
@@ -526,7 +613,15 @@ impl<F: Clone> TryFrom<PaymentData<F>> for types::VerifyRequestData {
                 .payment_method_data
                 .get_required_value("payment_method_data")?,
             statement_descriptor_suffix: payment_data.payment_intent.statement_descriptor_suffix,
-            setup_future_usage: payment_data.payment_intent.setup_future_usage,
+            // The payment method is set up for off-session usage by default, like Stripe's
+            // setup intents, so that it is saved for the customer
+            setup_future_usage: payment_data.payment_intent.setup_future_usage.or_else(|| {
+                payment_data
+                    .payment_intent
+                    .customer_id
+                    .as_ref()
+                    .map(|_| enums::FutureUsage::OffSession)
+            }),
             off_session: payment_data.mandate_id.as_ref().map(|_| true),
             mandate_id: payment_data.mandate_id.clone(),
             setup_mandate_details: payment_data.setup_mandate,
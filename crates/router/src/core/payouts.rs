@@ -0,0 +1,411 @@
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments, utils as core_utils, webhooks,
+    },
+    routes::AppState,
+    services,
+    types::{
+        self,
+        api::{self, payouts},
+        storage::{self, enums},
+        transformers::ForeignInto,
+    },
+    utils::{self, OptionExt},
+};
+
+#[instrument(skip(state))]
+pub async fn payouts_create_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: payouts::PayoutCreateRequest,
+) -> RouterResponse<payouts::PayoutResponse> {
+    let db = &*state.store;
+    let payout_id = core_utils::get_or_generate_id("payout_id", &req.payout_id, "payout")?;
+
+    utils::when(req.amount <= 0, || {
+        Err(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "amount",
+        })
+    })?;
+
+    let connector_name = req.connector.to_string();
+    api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &connector_name,
+        api::GetToken::Connector,
+    )?;
+
+    // The recipient's account details are not stored, they are needed only when the payout is
+    // submitted to the connector
+    let payout_method_data = req
+        .confirm
+        .unwrap_or(false)
+        .then(|| {
+            req.payout_method_data
+                .clone()
+                .get_required_value("payout_method_data")
+        })
+        .transpose()?;
+
+    let payout = db
+        .insert_payout(storage::PayoutNew {
+            payout_id: payout_id.clone(),
+            merchant_id: merchant_account.merchant_id.clone(),
+            customer_id: req.customer_id,
+            amount: req.amount,
+            currency: req.currency.foreign_into(),
+            payout_type: req.payout_type.foreign_into(),
+            status: enums::PayoutStatus::RequiresConfirmation,
+            connector: connector_name.clone(),
+            description: req.description,
+            metadata: req.metadata,
+        })
+        .await
+        .map_err(|error| {
+            error.to_duplicate_response(errors::ApiErrorResponse::DuplicatePayout {
+                payout_id: payout_id.clone(),
+            })
+        })?;
+
+    let payout_attempt = db
+        .insert_payout_attempt(storage::PayoutAttemptNew {
+            payout_attempt_id: get_payout_attempt_id(&payout_id),
+            payout_id,
+            merchant_id: merchant_account.merchant_id.clone(),
+            connector: connector_name,
+            connector_payout_id: None,
+            status: enums::PayoutStatus::RequiresConfirmation,
+            error_code: None,
+            error_message: None,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the payout attempt")?;
+
+    let (payout, payout_attempt) = match payout_method_data {
+        Some(payout_method_data) => {
+            let router_data: types::PayoutsRouterData<api::PoCreate> =
+                core_utils::construct_payout_router_data(
+                    state,
+                    &merchant_account,
+                    &payout,
+                    &payout_attempt,
+                    Some(payout_method_data),
+                    req.email,
+                )
+                .await?;
+            call_connector_payout(
+                state,
+                &merchant_account,
+                payout,
+                payout_attempt,
+                router_data,
+            )
+            .await?
+        }
+        None => (payout, payout_attempt),
+    };
+
+    Ok(services::ApplicationResponse::Json(
+        (payout, payout_attempt).foreign_into(),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn payouts_retrieve_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    payout_id: String,
+) -> RouterResponse<payouts::PayoutResponse> {
+    let (payout, payout_attempt) =
+        find_payout_and_attempt(state, &merchant_account, &payout_id).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        (payout, payout_attempt).foreign_into(),
+    ))
+}
+
+/// Submits the payout along with the recipient's account details to the connector.
+#[instrument(skip(state))]
+pub async fn payouts_confirm_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    payout_id: String,
+    req: payouts::PayoutConfirmRequest,
+) -> RouterResponse<payouts::PayoutResponse> {
+    let (payout, payout_attempt) =
+        find_payout_and_attempt(state, &merchant_account, &payout_id).await?;
+    validate_payout_status(
+        &payout,
+        "confirmed",
+        &[enums::PayoutStatus::RequiresConfirmation],
+    )?;
+
+    let router_data: types::PayoutsRouterData<api::PoCreate> =
+        core_utils::construct_payout_router_data(
+            state,
+            &merchant_account,
+            &payout,
+            &payout_attempt,
+            Some(req.payout_method_data),
+            req.email,
+        )
+        .await?;
+    let (payout, payout_attempt) = call_connector_payout(
+        state,
+        &merchant_account,
+        payout,
+        payout_attempt,
+        router_data,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        (payout, payout_attempt).foreign_into(),
+    ))
+}
+
+/// Instructs the connector to execute a payout which was submitted earlier.
+#[instrument(skip(state))]
+pub async fn payouts_fulfill_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    payout_id: String,
+) -> RouterResponse<payouts::PayoutResponse> {
+    let (payout, payout_attempt) =
+        find_payout_and_attempt(state, &merchant_account, &payout_id).await?;
+    validate_payout_status(
+        &payout,
+        "fulfilled",
+        &[enums::PayoutStatus::RequiresFulfillment],
+    )?;
+
+    let router_data: types::PayoutsRouterData<api::PoFulfill> =
+        core_utils::construct_payout_router_data(
+            state,
+            &merchant_account,
+            &payout,
+            &payout_attempt,
+            None,
+            None,
+        )
+        .await?;
+    let (payout, payout_attempt) = call_connector_payout(
+        state,
+        &merchant_account,
+        payout,
+        payout_attempt,
+        router_data,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        (payout, payout_attempt).foreign_into(),
+    ))
+}
+
+/// Cancels a payout which has not been fulfilled yet. Payouts which were not submitted to the
+/// connector are cancelled without calling the connector.
+#[instrument(skip(state))]
+pub async fn payouts_cancel_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    payout_id: String,
+) -> RouterResponse<payouts::PayoutResponse> {
+    let db = &*state.store;
+    let (payout, payout_attempt) =
+        find_payout_and_attempt(state, &merchant_account, &payout_id).await?;
+    validate_payout_status(
+        &payout,
+        "cancelled",
+        &[
+            enums::PayoutStatus::RequiresConfirmation,
+            enums::PayoutStatus::RequiresFulfillment,
+        ],
+    )?;
+
+    let (payout, payout_attempt) = if payout.status == enums::PayoutStatus::RequiresConfirmation {
+        let payout_attempt = db
+            .update_payout_attempt(
+                payout_attempt,
+                storage::PayoutAttemptUpdate::ResponseUpdate {
+                    status: enums::PayoutStatus::Cancelled,
+                    connector_payout_id: None,
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the payout attempt")?;
+        let payout = db
+            .update_payout(
+                payout,
+                storage::PayoutUpdate::StatusUpdate {
+                    status: enums::PayoutStatus::Cancelled,
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the payout")?;
+        trigger_payout_outgoing_webhook(state, &merchant_account, &payout, &payout_attempt).await;
+        (payout, payout_attempt)
+    } else {
+        let router_data: types::PayoutsRouterData<api::PoCancel> =
+            core_utils::construct_payout_router_data(
+                state,
+                &merchant_account,
+                &payout,
+                &payout_attempt,
+                None,
+                None,
+            )
+            .await?;
+        call_connector_payout(
+            state,
+            &merchant_account,
+            payout,
+            payout_attempt,
+            router_data,
+        )
+        .await?
+    };
+
+    Ok(services::ApplicationResponse::Json(
+        (payout, payout_attempt).foreign_into(),
+    ))
+}
+
+/// A single attempt is made for each payout, so the attempt identifier is derived from the payout
+/// identifier.
+fn get_payout_attempt_id(payout_id: &str) -> String {
+    format!("{payout_id}_1")
+}
+
+async fn find_payout_and_attempt(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payout_id: &str,
+) -> RouterResult<(storage::Payout, storage::PayoutAttempt)> {
+    let db = &*state.store;
+    let payout = db
+        .find_payout_by_merchant_id_payout_id(&merchant_account.merchant_id, payout_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PayoutNotFound))?;
+    let payout_attempt = db
+        .find_payout_attempt_by_merchant_id_payout_attempt_id(
+            &merchant_account.merchant_id,
+            &get_payout_attempt_id(payout_id),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Failed to find the payout attempt for the payout with id {payout_id}")
+        })?;
+
+    Ok((payout, payout_attempt))
+}
+
+fn validate_payout_status(
+    payout: &storage::Payout,
+    action: &str,
+    allowed_statuses: &[enums::PayoutStatus],
+) -> RouterResult<()> {
+    utils::when(!allowed_statuses.contains(&payout.status), || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "The payout cannot be {action} because it has a status of {}",
+                payout.status
+            ),
+        })
+    })?;
+    Ok(())
+}
+
+async fn call_connector_payout<F>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payout: storage::Payout,
+    payout_attempt: storage::PayoutAttempt,
+    router_data: types::PayoutsRouterData<F>,
+) -> RouterResult<(storage::Payout, storage::PayoutAttempt)>
+where
+    F: Clone + std::fmt::Debug + Send + Sync + 'static,
+    dyn api::Connector:
+        services::ConnectorIntegration<F, types::PayoutsData, types::PayoutsResponseData>,
+{
+    let db = &*state.store;
+    let connector = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &payout_attempt.connector,
+        api::GetToken::Connector,
+    )?;
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        F,
+        types::PayoutsData,
+        types::PayoutsResponseData,
+    > = connector.connector.get_connector_integration();
+    let router_data = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        payments::CallConnectorAction::Trigger,
+    )
+    .await
+    .map_err(|error| error.to_payment_failed_response())?;
+
+    let (status, payout_attempt_update) = match router_data.response {
+        Ok(response) => (
+            response.status,
+            storage::PayoutAttemptUpdate::ResponseUpdate {
+                status: response.status,
+                connector_payout_id: Some(response.connector_payout_id),
+            },
+        ),
+        Err(error) => (
+            enums::PayoutStatus::Failed,
+            storage::PayoutAttemptUpdate::ErrorUpdate {
+                status: enums::PayoutStatus::Failed,
+                error_code: Some(error.code),
+                error_message: Some(error.message),
+            },
+        ),
+    };
+
+    let payout_attempt = db
+        .update_payout_attempt(payout_attempt, payout_attempt_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the payout attempt")?;
+    let payout = db
+        .update_payout(payout, storage::PayoutUpdate::StatusUpdate { status })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the payout")?;
+    trigger_payout_outgoing_webhook(state, merchant_account, &payout, &payout_attempt).await;
+
+    Ok((payout, payout_attempt))
+}
+
+/// Notifies the merchant of the status of the payout. A failure in notifying the merchant does not
+/// fail the operation which changed the status of the payout.
+async fn trigger_payout_outgoing_webhook(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payout: &storage::Payout,
+    payout_attempt: &storage::PayoutAttempt,
+) {
+    let result = webhooks::trigger_payout_outgoing_webhook(
+        state.clone(),
+        merchant_account.clone(),
+        payout.clone(),
+        payout_attempt.clone(),
+    )
+    .await;
+    if let Err(error) = result {
+        logger::error!(?error, "Failed to send the payout status webhook");
+    }
+}
@@ -0,0 +1,561 @@
+use common_utils::date_time;
+use error_stack::{report, IntoReport, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        forex,
+    },
+    db::StorageInterface,
+    routes::AppState,
+    scheduler::utils as pt_utils,
+    services,
+    types::{
+        api::recon,
+        storage::{self, enums},
+        transformers::ForeignInto,
+    },
+    utils,
+};
+
+pub(crate) const RECON_RUNNER: &str = "RECON_WORKFLOW";
+const RECON_TASK: &str = "RECON";
+
+const RECON_REPORT_MAX_ENTRIES: usize = 10_000;
+const RECON_REPORT_LIST_DEFAULT_LIMIT: i64 = 10;
+const RECON_REPORT_LIST_MAX_LIMIT: i64 = 100;
+
+/// An entry of a settlement report, which settles a single payment or refund.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+struct SettlementEntry {
+    connector_transaction_id: String,
+    #[serde(rename = "type")]
+    entry_type: enums::ReconEntryType,
+    amount: i64,
+    currency: enums::Currency,
+}
+
+/// The payment or refund settled by an entry of a settlement report, as recorded by us.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ExpectedSettlement {
+    amount: i64,
+    currency: Option<enums::Currency>,
+    /// Whether the payment or refund has succeeded, and is therefore expected to be settled
+    is_successful: bool,
+    payment_id: String,
+    refund_id: Option<String>,
+}
+
+#[instrument(skip_all)]
+pub async fn create_report(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: recon::ReconReportCreateRequest,
+) -> RouterResponse<recon::ReconReportResponse> {
+    let db = &*state.store;
+
+    // The report is reconciled in the background, so it is validated upfront for the merchant
+    // to be told about malformed reports right away
+    let entries = parse_settlement_report(&req.file_content)?;
+    utils::when(entries.is_empty(), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The settlement report does not have any entries".to_string(),
+        }))
+    })?;
+    utils::when(entries.len() > RECON_REPORT_MAX_ENTRIES, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "A settlement report cannot have more than {RECON_REPORT_MAX_ENTRIES} entries"
+            ),
+        }))
+    })?;
+
+    let recon_report_new = storage::ReconReportNew {
+        report_id: utils::generate_id(consts::ID_LENGTH, "recon"),
+        merchant_id: merchant_account.merchant_id,
+        connector: req.connector,
+        status: enums::ReconReportStatus::Pending,
+        file_content: req.file_content,
+    };
+    let recon_report = db
+        .insert_recon_report(recon_report_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the recon report")?;
+
+    add_recon_task(db, &recon_report).await?;
+
+    Ok(services::ApplicationResponse::Json(get_report_response(
+        recon_report,
+        None,
+    )))
+}
+
+#[instrument(skip(db))]
+pub async fn list_reports(
+    db: &dyn StorageInterface,
+    merchant_account: storage::MerchantAccount,
+    constraints: recon::ReconReportListConstraints,
+) -> RouterResponse<recon::ReconReportListResponse> {
+    let limit = match constraints.limit {
+        Some(limit) if !(1..=RECON_REPORT_LIST_MAX_LIMIT).contains(&limit) => {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("limit should be in between 1 and {RECON_REPORT_LIST_MAX_LIMIT}"),
+            })?
+        }
+        Some(limit) => limit,
+        None => RECON_REPORT_LIST_DEFAULT_LIMIT,
+    };
+
+    let data = db
+        .filter_recon_reports_by_constraints(&merchant_account.merchant_id, &constraints, limit)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to filter recon reports by the given constraints")?
+        .into_iter()
+        .map(|recon_report| get_report_response(recon_report, None))
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(
+        recon::ReconReportListResponse { data },
+    ))
+}
+
+#[instrument(skip(db))]
+pub async fn retrieve_report(
+    db: &dyn StorageInterface,
+    merchant_account: storage::MerchantAccount,
+    report_id: String,
+) -> RouterResponse<recon::ReconReportResponse> {
+    let merchant_id = &merchant_account.merchant_id;
+    let recon_report = db
+        .find_recon_report_by_merchant_id_report_id(merchant_id, &report_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::ReconReportNotFound)
+        })?;
+    let mismatches = db
+        .find_recon_mismatches_by_merchant_id_report_id(merchant_id, &report_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the mismatches of the recon report")?
+        .into_iter()
+        .map(ForeignInto::foreign_into)
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(get_report_response(
+        recon_report,
+        Some(mismatches),
+    )))
+}
+
+/// Matches the entries of a pending report against the payments and refunds of the merchant,
+/// recording the entries which do not match. Failures to reconcile the report are recorded on the
+/// report instead of being retried, so that the merchant can upload the report again.
+#[instrument(skip_all, fields(report_id = %recon_report.report_id))]
+pub async fn process_report(
+    state: &AppState,
+    recon_report: storage::ReconReport,
+) -> RouterResult<storage::ReconReport> {
+    let db = &*state.store;
+    let update = match reconcile_report(db, &recon_report).await {
+        Ok((total_entries, mismatched_entries)) => storage::ReconReportUpdate::CompletedUpdate {
+            total_entries,
+            matched_entries: total_entries - mismatched_entries,
+            mismatched_entries,
+        },
+        Err(error) => {
+            logger::error!(?error, "Failed to reconcile the recon report");
+            let error_message = match error.current_context() {
+                errors::ApiErrorResponse::InvalidRequestData { message } => message.clone(),
+                _ => "The report could not be reconciled".to_string(),
+            };
+            storage::ReconReportUpdate::FailedUpdate { error_message }
+        }
+    };
+
+    db.update_recon_report(recon_report, update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the recon report")
+}
+
+async fn add_recon_task(
+    db: &dyn StorageInterface,
+    recon_report: &storage::ReconReport,
+) -> RouterResult<()> {
+    let tracking_data = storage::ReconTrackingData {
+        merchant_id: recon_report.merchant_id.clone(),
+        report_id: recon_report.report_id.clone(),
+    };
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        RECON_RUNNER,
+        RECON_TASK,
+        &recon_report.report_id,
+        &recon_report.merchant_id,
+    );
+    let process_tracker_entry =
+        <storage::ProcessTracker as storage::ProcessTrackerExt>::make_process_tracker_new(
+            process_tracker_id,
+            RECON_TASK,
+            RECON_RUNNER,
+            tracking_data,
+            date_time::now(),
+        )
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to construct the recon task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting task in process_tracker: report_id: {}",
+                recon_report.report_id
+            )
+        })?;
+    Ok(())
+}
+
+/// Reconciles the entries of the report, returning the number of entries and the number of
+/// entries which do not match.
+async fn reconcile_report(
+    db: &dyn StorageInterface,
+    recon_report: &storage::ReconReport,
+) -> RouterResult<(i32, i32)> {
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&recon_report.merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the merchant account of the recon report")?;
+    let entries = parse_settlement_report(&recon_report.file_content)?;
+
+    let mut mismatches = Vec::new();
+    for entry in &entries {
+        let expected =
+            find_expected_settlement(db, &merchant_account, &recon_report.connector, entry).await?;
+        if let Some(reason) = get_mismatch_reason(entry, expected.as_ref()) {
+            mismatches.push(storage::ReconMismatchNew {
+                report_id: recon_report.report_id.clone(),
+                merchant_id: recon_report.merchant_id.clone(),
+                entry_type: entry.entry_type,
+                connector_transaction_id: entry.connector_transaction_id.clone(),
+                amount: entry.amount,
+                currency: entry.currency,
+                reason,
+                expected_amount: expected.as_ref().map(|expected| expected.amount),
+                expected_currency: expected.as_ref().and_then(|expected| expected.currency),
+                payment_id: expected
+                    .as_ref()
+                    .map(|expected| expected.payment_id.clone()),
+                refund_id: expected.and_then(|expected| expected.refund_id),
+            });
+        }
+    }
+
+    let total_entries = i32::try_from(entries.len())
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let mismatched_entries = i32::try_from(mismatches.len())
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    if !mismatches.is_empty() {
+        db.insert_recon_mismatches(mismatches)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert the mismatches of the recon report")?;
+    }
+
+    Ok((total_entries, mismatched_entries))
+}
+
+fn parse_settlement_report(file_content: &str) -> RouterResult<Vec<SettlementEntry>> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(file_content.as_bytes());
+
+    reader
+        .deserialize()
+        .map(|entry| {
+            entry.into_report().change_context_lazy(|| {
+                errors::ApiErrorResponse::InvalidRequestData {
+                    message: "The settlement report is not a valid CSV file with the columns \
+                        connector_transaction_id, type, amount and currency"
+                        .to_string(),
+                }
+            })
+        })
+        .collect()
+}
+
+/// Finds the payment or refund settled by the entry, if it was processed by the connector of the
+/// report.
+async fn find_expected_settlement(
+    db: &dyn StorageInterface,
+    merchant_account: &storage::MerchantAccount,
+    connector: &str,
+    entry: &SettlementEntry,
+) -> RouterResult<Option<ExpectedSettlement>> {
+    let (merchant_id, storage_scheme) = (
+        &merchant_account.merchant_id,
+        merchant_account.storage_scheme,
+    );
+
+    match entry.entry_type {
+        enums::ReconEntryType::Payment => {
+            let payment_attempt = db
+                .find_payment_attempt_by_merchant_id_connector_txn_id(
+                    merchant_id,
+                    &entry.connector_transaction_id,
+                    storage_scheme,
+                )
+                .await;
+            let payment_attempt = match payment_attempt {
+                Ok(payment_attempt) if payment_attempt.connector.as_deref() == Some(connector) => {
+                    payment_attempt
+                }
+                Ok(_) => return Ok(None),
+                Err(error) if error.current_context().is_db_not_found() => return Ok(None),
+                Err(error) => {
+                    return Err(error)
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to fetch the payment attempt of an entry")
+                }
+            };
+
+            let (amount, currency) = get_payment_settlement_amount(&payment_attempt)?;
+            Ok(Some(ExpectedSettlement {
+                amount,
+                currency,
+                is_successful: matches!(
+                    payment_attempt.status,
+                    enums::AttemptStatus::Charged | enums::AttemptStatus::PartialCharged
+                ),
+                payment_id: payment_attempt.payment_id,
+                refund_id: None,
+            }))
+        }
+        enums::ReconEntryType::Refund => {
+            let refund = db
+                .find_refund_by_merchant_id_connector_refund_id_connector(
+                    merchant_id,
+                    &entry.connector_transaction_id,
+                    connector,
+                    storage_scheme,
+                )
+                .await;
+            let refund = match refund {
+                Ok(refund) => refund,
+                Err(error) if error.current_context().is_db_not_found() => return Ok(None),
+                Err(error) => {
+                    return Err(error)
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to fetch the refund of an entry")
+                }
+            };
+            let payment_attempt = db
+                .find_payment_attempt_by_merchant_id_attempt_id(
+                    merchant_id,
+                    &refund.attempt_id,
+                    storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the payment attempt of a refund")?;
+
+            // Refunds of payments settled in another currency are settled in the settlement
+            // currency, with the exchange rate at which the payment was converted
+            let (amount, currency) = match (
+                payment_attempt.settlement_currency,
+                payment_attempt.settlement_amount,
+            ) {
+                (Some(settlement_currency), Some(settlement_amount)) => (
+                    forex::convert_with_recorded_rate(
+                        refund.refund_amount,
+                        payment_attempt.amount,
+                        settlement_amount,
+                    )?,
+                    settlement_currency,
+                ),
+                _ => (refund.refund_amount, refund.currency),
+            };
+            Ok(Some(ExpectedSettlement {
+                amount,
+                currency: Some(currency),
+                is_successful: refund.refund_status == enums::RefundStatus::Success,
+                payment_id: refund.payment_id,
+                refund_id: Some(refund.refund_id),
+            }))
+        }
+    }
+}
+
+/// The amount which the connector was requested to capture for the payment, including the
+/// surcharge of the payment and converted to its settlement currency, if any.
+fn get_payment_settlement_amount(
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<(i64, Option<enums::Currency>)> {
+    let total_surcharge =
+        payment_attempt.surcharge_amount.unwrap_or(0) + payment_attempt.tax_amount.unwrap_or(0);
+    let amount = match payment_attempt.amount_to_capture {
+        Some(amount_to_capture) if amount_to_capture != payment_attempt.amount => amount_to_capture,
+        _ => payment_attempt
+            .amount
+            .checked_add(total_surcharge)
+            .ok_or_else(|| report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Amount including the surcharge is out of range")?,
+    };
+
+    match (
+        payment_attempt.settlement_currency,
+        payment_attempt.settlement_amount,
+    ) {
+        (Some(settlement_currency), Some(settlement_amount)) => Ok((
+            forex::convert_with_recorded_rate(amount, payment_attempt.amount, settlement_amount)?,
+            Some(settlement_currency),
+        )),
+        _ => Ok((amount, payment_attempt.currency)),
+    }
+}
+
+fn get_mismatch_reason(
+    entry: &SettlementEntry,
+    expected: Option<&ExpectedSettlement>,
+) -> Option<enums::ReconMismatchReason> {
+    let expected = match expected {
+        Some(expected) => expected,
+        None => return Some(enums::ReconMismatchReason::NotFound),
+    };
+
+    if !expected.is_successful {
+        Some(enums::ReconMismatchReason::StatusMismatch)
+    } else if expected.currency != Some(entry.currency) {
+        Some(enums::ReconMismatchReason::CurrencyMismatch)
+    } else if expected.amount != entry.amount {
+        Some(enums::ReconMismatchReason::AmountMismatch)
+    } else {
+        None
+    }
+}
+
+fn get_report_response(
+    recon_report: storage::ReconReport,
+    mismatches: Option<Vec<recon::ReconMismatchResponse>>,
+) -> recon::ReconReportResponse {
+    recon::ReconReportResponse {
+        report_id: recon_report.report_id,
+        connector: recon_report.connector,
+        status: recon_report.status.foreign_into(),
+        total_entries: recon_report.total_entries,
+        matched_entries: recon_report.matched_entries,
+        mismatched_entries: recon_report.mismatched_entries,
+        error_message: recon_report.error_message,
+        created_at: recon_report.created_at,
+        mismatches,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn expected_settlement() -> ExpectedSettlement {
+        ExpectedSettlement {
+            amount: 6540,
+            currency: Some(enums::Currency::USD),
+            is_successful: true,
+            payment_id: "pay_123".to_string(),
+            refund_id: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_settlement_report() {
+        let entries = parse_settlement_report(
+            "connector_transaction_id, type, amount, currency\n\
+             pi_123, payment, 6540, USD\n\
+             re_456, refund, 1000, EUR\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            entries,
+            vec![
+                SettlementEntry {
+                    connector_transaction_id: "pi_123".to_string(),
+                    entry_type: enums::ReconEntryType::Payment,
+                    amount: 6540,
+                    currency: enums::Currency::USD,
+                },
+                SettlementEntry {
+                    connector_transaction_id: "re_456".to_string(),
+                    entry_type: enums::ReconEntryType::Refund,
+                    amount: 1000,
+                    currency: enums::Currency::EUR,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_malformed_settlement_report() {
+        assert!(parse_settlement_report("id,amount\npi_123,6540\n").is_err());
+        assert!(parse_settlement_report(
+            "connector_transaction_id,type,amount,currency\npi_123,payment,65.40,USD\n"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_get_mismatch_reason() {
+        let entry = SettlementEntry {
+            connector_transaction_id: "pi_123".to_string(),
+            entry_type: enums::ReconEntryType::Payment,
+            amount: 6540,
+            currency: enums::Currency::USD,
+        };
+
+        assert_eq!(
+            get_mismatch_reason(&entry, Some(&expected_settlement())),
+            None
+        );
+        assert_eq!(
+            get_mismatch_reason(&entry, None),
+            Some(enums::ReconMismatchReason::NotFound)
+        );
+        assert_eq!(
+            get_mismatch_reason(
+                &entry,
+                Some(&ExpectedSettlement {
+                    is_successful: false,
+                    ..expected_settlement()
+                })
+            ),
+            Some(enums::ReconMismatchReason::StatusMismatch)
+        );
+        assert_eq!(
+            get_mismatch_reason(
+                &entry,
+                Some(&ExpectedSettlement {
+                    currency: Some(enums::Currency::EUR),
+                    ..expected_settlement()
+                })
+            ),
+            Some(enums::ReconMismatchReason::CurrencyMismatch)
+        );
+        assert_eq!(
+            get_mismatch_reason(
+                &entry,
+                Some(&ExpectedSettlement {
+                    amount: 6500,
+                    ..expected_settlement()
+                })
+            ),
+            Some(enums::ReconMismatchReason::AmountMismatch)
+        );
+    }
+}
@@ -1,5 +1,8 @@
 pub mod validator;
 
+use std::collections::HashMap;
+
+use common_utils::types::MinorUnit;
 use error_stack::{report, IntoReport, ResultExt};
 use router_env::{instrument, tracing};
 
@@ -7,11 +10,13 @@ use crate::{
     consts,
     core::{
         errors::{self, ConnectorErrorExt, RouterResponse, RouterResult, StorageErrorExt},
-        payments, utils as core_utils,
+        exports,
+        payments::{self, access_token},
+        utils as core_utils, webhooks,
     },
     db, logger,
     routes::AppState,
-    scheduler::{process_data, utils as process_tracker_utils, workflows::payment_sync},
+    scheduler::{process_data, utils as process_tracker_utils},
     services,
     types::{
         self,
@@ -29,6 +34,41 @@ pub async fn refund_create_core(
     state: &AppState,
     merchant_account: storage::merchant_account::MerchantAccount,
     req: refunds::RefundRequest,
+) -> RouterResponse<refunds::RefundResponse> {
+    create_refund(state, merchant_account, req, None).await
+}
+
+/// Issues the refund of a payment whose connector account has been disabled through another
+/// account of the same connector. This is only available to operators, the refund is flagged for
+/// manual reconciliation. Refunds are not issued as payouts, as a payout is neither linked to the
+/// payment it would refund at the connector nor reported by the connector as a refund.
+#[instrument(skip_all)]
+pub async fn refund_alternate_account_create_core(
+    state: &AppState,
+    req: refunds::RefundAlternateAccountRequest,
+) -> RouterResponse<refunds::RefundResponse> {
+    let merchant_account = state
+        .store
+        .find_merchant_account_by_merchant_id(&req.merchant_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        })?;
+
+    create_refund(
+        state,
+        merchant_account,
+        req.refund,
+        Some(req.connector_label),
+    )
+    .await
+}
+
+async fn create_refund(
+    state: &AppState,
+    merchant_account: storage::merchant_account::MerchantAccount,
+    req: refunds::RefundRequest,
+    alternate_connector_label: Option<String>,
 ) -> RouterResponse<refunds::RefundResponse> {
     let db = &*state.store;
     let (merchant_id, payment_intent, payment_attempt, amount);
@@ -44,17 +84,6 @@ pub async fn refund_create_core(
         .await
         .change_context(errors::ApiErrorResponse::SuccessfulPaymentNotFound)?;
 
-    // Amount is not passed in request refer from payment attempt.
-    amount = req.amount.unwrap_or(payment_attempt.amount); // [#298]: Need to that capture amount
-                                                           //[#299]: Can we change the flow based on some workflow idea
-    utils::when(amount <= 0, || {
-        Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
-            field_name: "amount".to_string(),
-            expected_format: "positive integer".to_string()
-        })
-        .attach_printable("amount less than zero"))
-    })?;
-
     payment_intent = db
         .find_payment_intent_by_payment_id_merchant_id(
             &req.payment_id,
@@ -64,8 +93,27 @@ pub async fn refund_create_core(
         .await
         .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
 
+    // Amount is not passed in request refer from the captured amount of the payment.
+    amount = req.amount.unwrap_or(
+        payment_intent
+            .amount_captured
+            .map(MinorUnit::get_amount_as_i64)
+            .unwrap_or(payment_attempt.amount),
+    ); //[#299]: Can we change the flow based on some workflow idea
+
+    utils::when(amount <= 0, || {
+        Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "amount".to_string(),
+            expected_format: "positive integer".to_string()
+        })
+        .attach_printable("amount less than zero"))
+    })?;
+
     utils::when(
-        payment_intent.status != enums::IntentStatus::Succeeded,
+        !matches!(
+            payment_intent.status,
+            enums::IntentStatus::Succeeded | enums::IntentStatus::PartiallyCaptured
+        ),
         || {
             Err(report!(errors::ApiErrorResponse::PaymentNotSucceeded)
                 .attach_printable("unable to refund for a unsuccessful payment intent"))
@@ -79,6 +127,7 @@ pub async fn refund_create_core(
         &payment_intent,
         amount,
         req,
+        alternate_connector_label,
     )
     .await
     .map(services::ApplicationResponse::Json)
@@ -92,11 +141,7 @@ pub async fn trigger_refund_to_gateway(
     payment_attempt: &storage::PaymentAttempt,
     payment_intent: &storage::PaymentIntent,
 ) -> RouterResult<storage::Refund> {
-    let connector = payment_attempt
-        .connector
-        .clone()
-        .ok_or(errors::ApiErrorResponse::InternalServerError)?;
-    let connector_id = connector.to_string();
+    let connector_id = refund.connector.to_string();
     let connector: api::ConnectorData = api::ConnectorData::get_connector_by_name(
         &state.conf.connectors,
         &connector_id,
@@ -114,7 +159,7 @@ pub async fn trigger_refund_to_gateway(
 
     validator::validate_for_valid_refunds(payment_attempt)?;
 
-    let router_data = core_utils::construct_refund_router_data(
+    let mut router_data = core_utils::construct_refund_router_data(
         state,
         &connector_id,
         merchant_account,
@@ -132,14 +177,27 @@ pub async fn trigger_refund_to_gateway(
         types::RefundsData,
         types::RefundsResponseData,
     > = connector.connector.get_connector_integration();
-    let router_data = services::execute_connector_processing_step(
-        state,
-        connector_integration,
-        &router_data,
-        payments::CallConnectorAction::Trigger,
-    )
-    .await
-    .map_err(|error| error.to_refund_failed_response())?;
+
+    let add_access_token_result =
+        access_token::add_access_token(state, &connector, merchant_account, &router_data).await?;
+    let should_continue = access_token::update_router_data_with_access_token_result(
+        &add_access_token_result,
+        &mut router_data,
+        &payments::CallConnectorAction::Trigger,
+    );
+
+    let router_data = if should_continue {
+        services::execute_connector_processing_step(
+            state,
+            connector_integration,
+            &router_data,
+            payments::CallConnectorAction::Trigger,
+        )
+        .await
+        .map_err(|error| error.to_refund_failed_response())?
+    } else {
+        router_data
+    };
 
     let refund_update = match router_data.response {
         Err(err) => storage::RefundUpdate::ErrorUpdate {
@@ -170,6 +228,7 @@ pub async fn trigger_refund_to_gateway(
                 refund.refund_id
             )
         })?;
+    trigger_refund_outgoing_webhook(state, merchant_account, refund.refund_status, &response).await;
     Ok(response)
 }
 
@@ -231,18 +290,32 @@ pub async fn refund_retrieve_core(
         .await
         .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
 
-    response = sync_refund_with_gateway(
-        state,
-        &merchant_account,
-        &payment_attempt,
-        &payment_intent,
-        &refund,
-    )
-    .await?;
+    response = if should_call_refund(&refund) {
+        sync_refund_with_gateway(
+            state,
+            &merchant_account,
+            &payment_attempt,
+            &payment_intent,
+            &refund,
+        )
+        .await?
+    } else {
+        refund
+    };
 
     Ok(response)
 }
 
+/// Refunds in a final status are not synced with the connector again.
+fn should_call_refund(refund: &storage::Refund) -> bool {
+    !matches!(
+        refund.refund_status,
+        enums::RefundStatus::Success
+            | enums::RefundStatus::Failure
+            | enums::RefundStatus::TransactionFailure
+    )
+}
+
 #[instrument(skip_all)]
 pub async fn sync_refund_with_gateway(
     state: &AppState,
@@ -262,7 +335,7 @@ pub async fn sync_refund_with_gateway(
 
     let currency = payment_attempt.currency.get_required_value("currency")?;
 
-    let router_data = core_utils::construct_refund_router_data::<api::RSync>(
+    let mut router_data = core_utils::construct_refund_router_data::<api::RSync>(
         state,
         &connector_id,
         merchant_account,
@@ -279,14 +352,27 @@ pub async fn sync_refund_with_gateway(
         types::RefundsData,
         types::RefundsResponseData,
     > = connector.connector.get_connector_integration();
-    let router_data = services::execute_connector_processing_step(
-        state,
-        connector_integration,
-        &router_data,
-        payments::CallConnectorAction::Trigger,
-    )
-    .await
-    .map_err(|error| error.to_refund_failed_response())?;
+
+    let add_access_token_result =
+        access_token::add_access_token(state, &connector, merchant_account, &router_data).await?;
+    let should_continue = access_token::update_router_data_with_access_token_result(
+        &add_access_token_result,
+        &mut router_data,
+        &payments::CallConnectorAction::Trigger,
+    );
+
+    let router_data = if should_continue {
+        services::execute_connector_processing_step(
+            state,
+            connector_integration,
+            &router_data,
+            payments::CallConnectorAction::Trigger,
+        )
+        .await
+        .map_err(|error| error.to_refund_failed_response())?
+    } else {
+        router_data
+    };
 
     let refund_update = match router_data.response {
         Err(error_message) => storage::RefundUpdate::ErrorUpdate {
@@ -317,9 +403,34 @@ pub async fn sync_refund_with_gateway(
                 refund.refund_id
             )
         })?;
+    trigger_refund_outgoing_webhook(state, merchant_account, refund.refund_status, &response).await;
     Ok(response)
 }
 
+/// Notifies the merchant if the refund has reached a final status with the latest update, so that
+/// the merchant is notified only once for each refund. A failure in notifying the merchant does not
+/// fail the refund.
+async fn trigger_refund_outgoing_webhook(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    previous_status: enums::RefundStatus,
+    refund: &storage::Refund,
+) {
+    if refund.refund_status == previous_status || should_call_refund(refund) {
+        return;
+    }
+
+    let result = webhooks::trigger_refund_outgoing_webhook(
+        state.clone(),
+        merchant_account.clone(),
+        refund.clone(),
+    )
+    .await;
+    if let Err(error) = result {
+        logger::error!(?error, "Failed to send the refund status webhook");
+    }
+}
+
 // ********************************************** REFUND UPDATE **********************************************
 
 pub async fn refund_update_core(
@@ -365,6 +476,7 @@ pub async fn validate_and_create_refund(
     payment_intent: &storage::PaymentIntent,
     refund_amount: i64,
     req: refunds::RefundRequest,
+    alternate_connector_label: Option<String>,
 ) -> RouterResult<refunds::RefundResponse> {
     let db = &*state.store;
     let (refund_id, all_refunds, currency, refund_create_req, refund);
@@ -435,8 +547,15 @@ pub async fn validate_and_create_refund(
                 ),
             })?;
 
-            validator::validate_refund_amount(payment_attempt.amount, &all_refunds, refund_amount)
-                .change_context(errors::ApiErrorResponse::RefundAmountExceedsPaymentAmount)?;
+            validator::validate_refund_amount(
+                payment_intent
+                    .amount_captured
+                    .map(MinorUnit::get_amount_as_i64)
+                    .unwrap_or(payment_attempt.amount),
+                &all_refunds,
+                refund_amount,
+            )
+            .change_context(errors::ApiErrorResponse::RefundAmountExceedsPaymentAmount)?;
 
             validator::validate_maximum_refund_against_payment_attempt(
                 &all_refunds,
@@ -449,6 +568,15 @@ pub async fn validate_and_create_refund(
                     .attach_printable("connector not populated in payment attempt.")
             })?;
 
+            let alternate_connector_label = validator::validate_refund_connector_account(
+                db,
+                &merchant_account.merchant_id,
+                payment_intent,
+                &connector,
+                alternate_connector_label,
+            )
+            .await?;
+
             refund_create_req = storage::RefundNew::default()
                 .set_refund_id(refund_id.to_string())
                 .set_internal_reference_id(utils::generate_id(consts::ID_LENGTH, "refid"))
@@ -468,6 +596,8 @@ pub async fn validate_and_create_refund(
                 .set_description(req.reason.clone())
                 .set_attempt_id(payment_attempt.attempt_id.clone())
                 .set_refund_reason(req.reason)
+                .set_reconciliation_required(alternate_connector_label.is_some())
+                .set_alternate_connector_label(alternate_connector_label)
                 .to_owned();
 
             refund = db
@@ -493,8 +623,8 @@ pub async fn validate_and_create_refund(
 
 // ********************************************** Refund list **********************************************
 
-///   If payment-id is provided, lists all the refunds associated with that particular payment-id
-///   If payment-id is not provided, lists the refunds associated with that particular merchant - to the limit specified,if no limits given, it is 10 by default
+///   Lists the refunds of the merchant matching the payment-id, status, connector and creation time filters given
+///   The refunds are paginated using the offset and the limit specified, if no limits given, it is 10 by default
 
 #[instrument(skip_all)]
 #[cfg(feature = "olap")]
@@ -504,6 +634,11 @@ pub async fn refund_list(
     req: api_models::refunds::RefundListRequest,
 ) -> RouterResponse<api_models::refunds::RefundListResponse> {
     let limit = validator::validate_refund_list(req.limit)?;
+    utils::when(req.offset.map_or(false, |offset| offset < 0), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "offset should not be negative".to_string(),
+        })
+    })?;
     let refund_list = db
         .filter_refund_by_constraints(
             &merchant_account.merchant_id,
@@ -526,6 +661,186 @@ pub async fn refund_list(
     ))
 }
 
+// ********************************************** REFUND BATCH **********************************************
+
+#[instrument(skip_all)]
+pub async fn refund_batch_create_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: api_models::refunds::RefundBatchRequest,
+) -> RouterResponse<api_models::refunds::RefundBatchResponse> {
+    let db = &*state.store;
+    let max_batch_size = state.conf.refund.max_batch_size;
+
+    utils::when(req.refunds.is_empty(), || {
+        Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "refunds".to_string(),
+            expected_format: "a non empty list of refunds".to_string(),
+        }))
+    })?;
+    utils::when(req.refunds.len() > max_batch_size, || {
+        Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "refunds".to_string(),
+            expected_format: format!("a list of at most {} refunds", max_batch_size),
+        }))
+    })?;
+
+    // The refund id of each row is fixed up front, so that a row whose progress was not saved
+    // before the workflow was interrupted refers to the same refund when it is processed again.
+    let rows = req
+        .refunds
+        .into_iter()
+        .map(|mut request| {
+            let refund_id = core_utils::get_or_generate_id("refund_id", &request.refund_id, "ref")?;
+            request.refund_id = Some(refund_id);
+            Ok(storage::RefundBatchRow {
+                request,
+                result: None,
+            })
+        })
+        .collect::<RouterResult<Vec<_>>>()?;
+
+    let batch_id = utils::generate_id(consts::ID_LENGTH, "refbatch");
+    let refund_batch = storage::RefundBatchWorkflow {
+        batch_id: batch_id.clone(),
+        merchant_id: merchant_account.merchant_id,
+        rows,
+    };
+
+    let current_time = common_utils::date_time::now();
+    let tracking_data = serde_json::to_value(&refund_batch)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("unable to serialize refund batch tracking data")?;
+    let task = "REFUND_BATCH";
+    let process_tracker_entry = storage::ProcessTrackerNew {
+        id: get_refund_batch_process_tracker_id(&batch_id),
+        name: Some(String::from(task)),
+        tag: vec![String::from("REFUND")],
+        runner: Some(String::from("REFUND_WORKFLOW_ROUTER")),
+        retry_count: 0,
+        schedule_time: Some(current_time),
+        rule: String::new(),
+        tracking_data,
+        business_status: String::from("Pending"),
+        status: enums::ProcessTrackerStatus::New,
+        event: vec![],
+        created_at: current_time,
+        updated_at: current_time,
+    };
+
+    let process = db
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting task in process_tracker: batch_id: {}",
+                batch_id
+            )
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        refund_batch_to_response(&process, refund_batch),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn refund_batch_retrieve_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    batch_id: String,
+) -> RouterResponse<api_models::refunds::RefundBatchResponse> {
+    let (process, refund_batch) =
+        find_refund_batch(&*state.store, &merchant_account.merchant_id, &batch_id).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        refund_batch_to_response(&process, refund_batch),
+    ))
+}
+
+/// Requests the report of a refund batch through the exports, which lists the outcome of every
+/// row of the batch at the time the report is generated.
+#[instrument(skip_all)]
+pub async fn refund_batch_report_core(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    batch_id: String,
+) -> RouterResponse<api_models::exports::ExportResponse> {
+    let (process, _) =
+        find_refund_batch(&*state.store, &merchant_account.merchant_id, &batch_id).await?;
+
+    let export_new = storage::ExportNew {
+        export_id: utils::generate_id(consts::ID_LENGTH, "exp"),
+        merchant_id: merchant_account.merchant_id,
+        object: enums::ExportObject::RefundBatch,
+        format: enums::ExportFormat::Csv,
+        start_time: process.created_at,
+        end_time: common_utils::date_time::now(),
+        status: enums::ExportStatus::Pending,
+        batch_id: Some(batch_id),
+        anonymized: false,
+    };
+
+    exports::insert_export(state, export_new).await
+}
+
+fn get_refund_batch_process_tracker_id(batch_id: &str) -> String {
+    format!("REFUND_WORKFLOW_ROUTER_REFUND_BATCH_{}", batch_id)
+}
+
+pub(crate) async fn find_refund_batch(
+    db: &dyn db::StorageInterface,
+    merchant_id: &str,
+    batch_id: &str,
+) -> RouterResult<(storage::ProcessTracker, storage::RefundBatchWorkflow)> {
+    let process = db
+        .find_process_by_id(&get_refund_batch_process_tracker_id(batch_id))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find the refund batch process")?
+        .ok_or(errors::ApiErrorResponse::ResourceIdNotFound)
+        .into_report()?;
+
+    let refund_batch =
+        serde_json::from_value::<storage::RefundBatchWorkflow>(process.tracking_data.clone())
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unable to deserialize refund batch tracking data")?;
+
+    utils::when(refund_batch.merchant_id != merchant_id, || {
+        Err(report!(errors::ApiErrorResponse::ResourceIdNotFound))
+    })?;
+
+    Ok((process, refund_batch))
+}
+
+fn refund_batch_to_response(
+    process: &storage::ProcessTracker,
+    refund_batch: storage::RefundBatchWorkflow,
+) -> api_models::refunds::RefundBatchResponse {
+    let total_count = refund_batch.rows.len();
+    let results: Vec<_> = refund_batch
+        .rows
+        .into_iter()
+        .filter_map(|row| row.result)
+        .collect();
+    let processed_count = results.len();
+    let status = match process.status {
+        enums::ProcessTrackerStatus::Finish => api_models::refunds::RefundBatchStatus::Completed,
+        _ if processed_count > 0 => api_models::refunds::RefundBatchStatus::Processing,
+        _ => api_models::refunds::RefundBatchStatus::Pending,
+    };
+
+    api_models::refunds::RefundBatchResponse {
+        batch_id: refund_batch.batch_id,
+        status,
+        total_count,
+        processed_count,
+        results,
+    }
+}
+
 impl From<Foreign<storage::Refund>> for Foreign<api::RefundResponse> {
     fn from(refund: Foreign<storage::Refund>) -> Self {
         let refund = refund.0;
@@ -538,6 +853,9 @@ impl From<Foreign<storage::Refund>> for Foreign<api::RefundResponse> {
             status: refund.refund_status.foreign_into(),
             metadata: refund.metadata,
             error_message: refund.refund_error_message,
+            connector: refund.connector,
+            alternate_connector_label: refund.alternate_connector_label,
+            reconciliation_required: refund.reconciliation_required,
             created_at: Some(refund.created_at),
             updated_at: Some(refund.updated_at),
         }
@@ -582,14 +900,24 @@ pub async fn schedule_refund_execution(
                             Ok(refund)
                         }
                         api_models::refunds::RefundType::Instant => {
-                            trigger_refund_to_gateway(
+                            let updated_refund = trigger_refund_to_gateway(
                                 state,
                                 &refund,
                                 merchant_account,
                                 payment_attempt,
                                 payment_intent,
                             )
-                            .await
+                            .await?;
+                            // Refunds still pending at the connector are polled by the scheduler
+                            if updated_refund.sent_to_gateway
+                                && updated_refund.refund_status == enums::RefundStatus::Pending
+                            {
+                                add_refund_sync_task(db, &updated_refund, runner)
+                                    .await
+                                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                                    .attach_printable_lazy(|| format!("Failed while pushing refund sync task in scheduler: refund_id: {}", updated_refund.refund_id))?;
+                            }
+                            Ok(updated_refund)
                         }
                     }
                 }
@@ -640,12 +968,17 @@ pub async fn sync_refund_with_gateway_workflow(
         .find_merchant_account_by_merchant_id(&refund_core.merchant_id)
         .await?;
 
-    let response = refund_retrieve_core(
-        state,
-        merchant_account,
-        refund_core.refund_internal_reference_id,
-    )
-    .await?;
+    let refund = state
+        .store
+        .find_refund_by_internal_reference_id_merchant_id(
+            &refund_core.refund_internal_reference_id,
+            &refund_core.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await?;
+
+    // The merchant is notified by the refund sync once the refund reaches a final status
+    let response = refund_retrieve_core(state, merchant_account, refund.refund_id).await?;
     let terminal_status = vec![
         enums::RefundStatus::Success,
         enums::RefundStatus::Failure,
@@ -660,7 +993,7 @@ pub async fn sync_refund_with_gateway_workflow(
                 .await?
         }
         _ => {
-            payment_sync::retry_sync_task(
+            retry_refund_sync_task(
                 &*state.store,
                 response.connector,
                 response.merchant_id,
@@ -681,6 +1014,7 @@ pub async fn start_refund_workflow(
     match refund_tracker.name.as_deref() {
         Some("EXECUTE_REFUND") => trigger_refund_execute_workflow(state, refund_tracker).await,
         Some("SYNC_REFUND") => sync_refund_with_gateway_workflow(state, refund_tracker).await,
+        Some("REFUND_BATCH") => trigger_refund_batch_workflow(state, refund_tracker).await,
         _ => Err(errors::ProcessTrackerError::JobNotFound),
     }
 }
@@ -776,6 +1110,152 @@ pub async fn trigger_refund_execute_workflow(
     Ok(())
 }
 
+/// Processes the pending refunds of a batch, restricting the number of refunds sent to each
+/// connector in a single run. Pending refunds are picked up again after the configured interval.
+#[instrument(skip_all)]
+pub async fn trigger_refund_batch_workflow(
+    state: &AppState,
+    refund_tracker: &storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let mut refund_batch = serde_json::from_value::<storage::RefundBatchWorkflow>(
+        refund_tracker.tracking_data.clone(),
+    )
+    .into_report()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable_lazy(|| {
+        format!(
+            "unable to convert into refund_batch {:?}",
+            refund_tracker.tracking_data
+        )
+    })?;
+
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&refund_batch.merchant_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        })?;
+
+    let batch_id = refund_batch.batch_id.clone();
+    let rate_limit = state.conf.refund.batch_connector_rate_limit;
+    let mut refunds_per_connector: HashMap<String, usize> = HashMap::new();
+    let mut refund_tracker = refund_tracker.clone();
+    let pending_rows: Vec<usize> = refund_batch
+        .rows
+        .iter()
+        .enumerate()
+        .filter(|(_, refund_batch_row)| refund_batch_row.result.is_none())
+        .map(|(row, _)| row)
+        .collect();
+
+    for row in pending_rows {
+        let mut request = refund_batch.rows[row].request.clone();
+        let payment_id = request.payment_id.clone();
+        let failed_result = |error_message: String| api_models::refunds::RefundBatchRowResult {
+            row,
+            payment_id: payment_id.clone(),
+            refund_id: None,
+            status: None,
+            error_message: Some(error_message),
+        };
+
+        let result = match db
+            .find_payment_attempt_last_successful_attempt_by_payment_id_merchant_id(
+                &request.payment_id,
+                &merchant_account.merchant_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+        {
+            Ok(payment_attempt) => {
+                let refunds_sent = refunds_per_connector
+                    .entry(payment_attempt.connector.unwrap_or_default())
+                    .or_default();
+                if *refunds_sent >= rate_limit {
+                    continue;
+                }
+                *refunds_sent += 1;
+
+                // Refunds of a batch are already processed asynchronously, hence they are sent to
+                // the connector right away unless requested otherwise.
+                request
+                    .refund_type
+                    .get_or_insert(api_models::refunds::RefundType::Instant);
+
+                match refund_create_core(state, merchant_account.clone(), request).await {
+                    Ok(services::ApplicationResponse::Json(response)) => {
+                        api_models::refunds::RefundBatchRowResult {
+                            row,
+                            payment_id: response.payment_id,
+                            refund_id: Some(response.refund_id),
+                            status: Some(response.status),
+                            error_message: response.error_message,
+                        }
+                    }
+                    Ok(_) => {
+                        failed_result(errors::ApiErrorResponse::InternalServerError.error_message())
+                    }
+                    Err(error) => {
+                        logger::error!(?error, %batch_id, row);
+                        failed_result(error.current_context().error_message())
+                    }
+                }
+            }
+            Err(_) => {
+                failed_result(errors::ApiErrorResponse::SuccessfulPaymentNotFound.error_message())
+            }
+        };
+        refund_batch.rows[row].result = Some(result);
+
+        // The progress is saved after every row, so that an interrupted run does not lose the
+        // outcome of the refunds which were already sent
+        refund_tracker =
+            update_refund_batch_tracking_data(db, refund_tracker, &refund_batch).await?;
+    }
+
+    let is_batch_completed = refund_batch.rows.iter().all(|row| row.result.is_some());
+
+    if is_batch_completed {
+        let id = refund_tracker.id.clone();
+        refund_tracker
+            .finish_with_status(db, format!("COMPLETED_BY_PT_{}", id))
+            .await?;
+    } else {
+        let schedule_time = common_utils::date_time::now()
+            + time::Duration::seconds(state.conf.refund.batch_interval);
+        refund_tracker.retry(db, schedule_time).await?;
+    }
+
+    Ok(())
+}
+
+async fn update_refund_batch_tracking_data(
+    db: &dyn db::StorageInterface,
+    refund_tracker: storage::ProcessTracker,
+    refund_batch: &storage::RefundBatchWorkflow,
+) -> Result<storage::ProcessTracker, errors::ProcessTrackerError> {
+    let tracking_data = serde_json::to_value(refund_batch)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("unable to serialize refund batch tracking data")?;
+
+    Ok(db
+        .update_process(
+            refund_tracker,
+            storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: None,
+                schedule_time: None,
+                tracking_data: Some(tracking_data),
+                business_status: None,
+                status: None,
+                updated_at: Some(common_utils::date_time::now()),
+            },
+        )
+        .await?)
+}
+
 #[instrument]
 pub fn refund_to_refund_core_workflow_model(
     refund: &storage::Refund,
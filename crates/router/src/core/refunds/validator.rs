@@ -4,7 +4,10 @@ use router_env::{instrument, tracing};
 use time::PrimitiveDateTime;
 
 use crate::{
-    core::errors::{self, CustomResult, RouterResult},
+    core::{
+        errors::{self, CustomResult, RouterResult, StorageErrorExt},
+        utils as core_utils,
+    },
     db::StorageInterface,
     logger,
     types::storage::{self, enums},
@@ -170,3 +173,86 @@ pub fn validate_for_valid_refunds(
     )?;
     Ok(())
 }
+
+/// Decides the connector account through which the refund has to be issued. When the connector
+/// account of the original payment is disabled or no longer exists, the refund can only be issued
+/// through the `alternate_connector_label` approved by an operator, which has to be another
+/// account of the same connector, as the payment is only known to that connector. The label of
+/// the alternate account is returned if the refund is to be routed through it.
+#[instrument(skip(db))]
+pub async fn validate_refund_connector_account(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    payment_intent: &storage::PaymentIntent,
+    payment_connector: &str,
+    alternate_connector_label: Option<String>,
+) -> RouterResult<Option<String>> {
+    let payment_connector_label = core_utils::get_connector_label(
+        payment_connector,
+        payment_intent.business_country.as_ref(),
+        payment_intent.business_label.as_ref(),
+    );
+    let is_payment_connector_available = match db
+        .find_merchant_connector_account_by_merchant_id_connector_label(
+            merchant_id,
+            &payment_connector_label,
+        )
+        .await
+    {
+        Ok(merchant_connector_account) => !merchant_connector_account.disabled.unwrap_or(false),
+        Err(err) if err.current_context().is_db_not_found() => false,
+        Err(err) => {
+            return Err(err
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while finding merchant connector account"))
+        }
+    };
+
+    match (is_payment_connector_available, alternate_connector_label) {
+        (true, None) => Ok(None),
+        (true, Some(_)) => Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "An alternate connector account can only be used when the connector account {} is disabled",
+                payment_connector_label
+            ),
+        })),
+        (false, None) => Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "The connector account {} is disabled, the refund has to be issued by an operator through another account of {}",
+                payment_connector_label, payment_connector
+            ),
+        })),
+        (false, Some(alternate_connector_label)) => {
+            let merchant_connector_account = db
+                .find_merchant_connector_account_by_merchant_id_connector_label(
+                    merchant_id,
+                    &alternate_connector_label,
+                )
+                .await
+                .map_err(|error| {
+                    error.to_not_found_response(
+                        errors::ApiErrorResponse::MerchantConnectorAccountNotFound,
+                    )
+                })?;
+            utils::when(
+                merchant_connector_account.connector_name != payment_connector,
+                || {
+                    Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+                        field_name: "connector_label".to_string(),
+                        expected_format: format!("the label of an account of {payment_connector}"),
+                    }))
+                },
+            )?;
+            utils::when(merchant_connector_account.disabled.unwrap_or(false), || {
+                Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                    message: format!(
+                        "The connector account {} is disabled",
+                        alternate_connector_label
+                    ),
+                }))
+            })?;
+
+            Ok(Some(alternate_connector_label))
+        }
+    }
+}
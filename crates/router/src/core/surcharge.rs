@@ -0,0 +1,314 @@
+use common_utils::ext_traits::ValueExt;
+use error_stack::{report, ResultExt};
+use masking::PeekInterface;
+
+use crate::{
+    core::{
+        errors::{self, RouterResult},
+        payments::PaymentData,
+    },
+    types::{
+        api::{self, enums as api_enums},
+        storage::{self, enums as storage_enums},
+        transformers::ForeignInto,
+    },
+};
+
+/// The surcharge levied on a payment along with the tax charged on it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SurchargeDetails {
+    pub surcharge_amount: i64,
+    pub tax_on_surcharge_amount: i64,
+}
+
+fn get_surcharge_config(
+    merchant_account: &storage::MerchantAccount,
+) -> RouterResult<Option<api::SurchargeConfig>> {
+    merchant_account
+        .surcharge_config
+        .clone()
+        .map(|surcharge_config| surcharge_config.parse_value("SurchargeConfig"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the surcharge configuration of the merchant")
+}
+
+/// Finds the rule deciding the surcharge of a payment, preferring a rule specific to the card
+/// network of the payment over a rule for its payment method as a whole.
+fn find_surcharge_rule(
+    surcharge_config: &api::SurchargeConfig,
+    payment_method: api_enums::PaymentMethodType,
+    card_network: Option<api_enums::CardNetwork>,
+) -> Option<&api::SurchargeRule> {
+    let rules = || {
+        surcharge_config
+            .rules
+            .iter()
+            .filter(move |rule| rule.payment_method == payment_method)
+    };
+
+    card_network
+        .and_then(|card_network| rules().find(|rule| rule.card_network == Some(card_network)))
+        .or_else(|| rules().find(|rule| rule.card_network.is_none()))
+}
+
+#[allow(clippy::as_conversions)]
+fn percentage_of(amount: i64, percentage: f64) -> RouterResult<i64> {
+    let result = (amount as f64 * percentage / 100.0).round();
+
+    if result.is_finite() && result >= 0.0 && result <= i64::MAX as f64 {
+        Ok(result as i64)
+    } else {
+        Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Surcharge amount is out of range")
+    }
+}
+
+fn calculate_surcharge(
+    surcharge_config: &api::SurchargeConfig,
+    rule: &api::SurchargeRule,
+    amount: i64,
+) -> RouterResult<SurchargeDetails> {
+    let surcharge_amount = match rule.surcharge {
+        api::Surcharge::Fixed(surcharge_amount) => surcharge_amount,
+        api::Surcharge::Percentage(percentage) => percentage_of(amount, percentage)?,
+    };
+    let tax_on_surcharge_amount = surcharge_config
+        .tax_on_surcharge_percentage
+        .map(|percentage| percentage_of(surcharge_amount, percentage))
+        .transpose()?
+        .unwrap_or(0);
+
+    Ok(SurchargeDetails {
+        surcharge_amount,
+        tax_on_surcharge_amount,
+    })
+}
+
+/// Calculates the surcharge which the merchant levies on a payment of the amount with the payment
+/// method, if any.
+pub fn get_surcharge_details(
+    merchant_account: &storage::MerchantAccount,
+    payment_method: storage_enums::PaymentMethodType,
+    card_network: Option<api_enums::CardNetwork>,
+    amount: i64,
+) -> RouterResult<Option<SurchargeDetails>> {
+    let surcharge_config = match get_surcharge_config(merchant_account)? {
+        Some(surcharge_config) => surcharge_config,
+        None => return Ok(None),
+    };
+
+    find_surcharge_rule(
+        &surcharge_config,
+        payment_method.foreign_into(),
+        card_network,
+    )
+    .map(|rule| calculate_surcharge(&surcharge_config, rule, amount))
+    .transpose()
+}
+
+/// Calculates the surcharges which would be levied on a payment of the amount with the listed
+/// payment method, for the payment method as a whole and for each of its card networks which have
+/// a surcharge of their own.
+pub fn get_payment_method_surcharges(
+    merchant_account: &storage::MerchantAccount,
+    payment_method: &api::ListPaymentMethod,
+    amount: i64,
+) -> RouterResult<Option<Vec<api::SurchargeDetailsResponse>>> {
+    let surcharge_config = match get_surcharge_config(merchant_account)? {
+        Some(surcharge_config) => surcharge_config,
+        None => return Ok(None),
+    };
+
+    let card_networks = payment_method
+        .payment_schemes
+        .iter()
+        .flatten()
+        .filter_map(|payment_scheme| payment_scheme.parse::<api_enums::CardNetwork>().ok())
+        .map(Some);
+    let mut surcharges = Vec::new();
+    for card_network in std::iter::once(None).chain(card_networks) {
+        let rule = surcharge_config.rules.iter().find(|rule| {
+            rule.payment_method == payment_method.payment_method
+                && rule.card_network == card_network
+        });
+        if let Some(rule) = rule {
+            let surcharge_details = calculate_surcharge(&surcharge_config, rule, amount)?;
+            surcharges.push(api::SurchargeDetailsResponse {
+                card_network,
+                surcharge_amount: surcharge_details.surcharge_amount,
+                tax_on_surcharge_amount: surcharge_details.tax_on_surcharge_amount,
+            });
+        }
+    }
+
+    Ok((!surcharges.is_empty()).then_some(surcharges))
+}
+
+/// Identifies the network of a card from the leading digits of its number.
+pub fn get_card_network(card: &api::CCard) -> Option<api_enums::CardNetwork> {
+    let card_number = card.card_number.peek();
+    let prefix = |length: usize| {
+        card_number
+            .get(..length)
+            .and_then(|prefix| prefix.parse::<u16>().ok())
+            .unwrap_or_default()
+    };
+
+    if prefix(1) == 4 {
+        Some(api_enums::CardNetwork::Visa)
+    } else if (51..=55).contains(&prefix(2)) || (2221..=2720).contains(&prefix(4)) {
+        Some(api_enums::CardNetwork::Mastercard)
+    } else if matches!(prefix(2), 34 | 37) {
+        Some(api_enums::CardNetwork::AmericanExpress)
+    } else if prefix(4) == 6011 || prefix(2) == 65 || (644..=649).contains(&prefix(3)) {
+        Some(api_enums::CardNetwork::Discover)
+    } else if (3528..=3589).contains(&prefix(4)) {
+        Some(api_enums::CardNetwork::Jcb)
+    } else if matches!(prefix(2), 36 | 38 | 39) || (300..=305).contains(&prefix(3)) {
+        Some(api_enums::CardNetwork::DinersClub)
+    } else if prefix(2) == 62 {
+        Some(api_enums::CardNetwork::UnionPay)
+    } else {
+        None
+    }
+}
+
+/// Returns the payment data with the surcharge of the payment and the tax on it added to its
+/// amount, which is the amount that the connector is requested to process. The surcharge is also
+/// added to the amount to capture when the full amount of the payment is captured.
+pub fn with_surcharge_amounts<F: Clone>(
+    mut payment_data: PaymentData<F>,
+) -> RouterResult<PaymentData<F>> {
+    let payment_attempt = &payment_data.payment_attempt;
+    let total_surcharge =
+        payment_attempt.surcharge_amount.unwrap_or(0) + payment_attempt.tax_amount.unwrap_or(0);
+    if total_surcharge == 0 {
+        return Ok(payment_data);
+    }
+
+    let add_surcharge = |amount: i64| {
+        amount
+            .checked_add(total_surcharge)
+            .ok_or_else(|| report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Amount including the surcharge is out of range")
+    };
+    let amount = add_surcharge(payment_data.amount.into())?;
+    let amount_to_capture = match payment_attempt.amount_to_capture {
+        Some(amount_to_capture) if amount_to_capture == payment_attempt.amount => {
+            Some(add_surcharge(amount_to_capture)?)
+        }
+        amount_to_capture => amount_to_capture,
+    };
+
+    payment_data.amount = amount.into();
+    payment_data.payment_attempt.amount_to_capture = amount_to_capture;
+    Ok(payment_data)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn surcharge_config() -> api::SurchargeConfig {
+        serde_json::from_value(serde_json::json!({
+            "rules": [
+                {
+                    "payment_method": "card",
+                    "surcharge": {"type": "percentage", "value": 1.5}
+                },
+                {
+                    "payment_method": "card",
+                    "card_network": "american_express",
+                    "surcharge": {"type": "percentage", "value": 3.0}
+                },
+                {
+                    "payment_method": "wallet",
+                    "surcharge": {"type": "fixed", "value": 50}
+                }
+            ],
+            "tax_on_surcharge_percentage": 18.0
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_surcharge_of_card_network_takes_precedence() {
+        let surcharge_config = surcharge_config();
+        let calculate = |payment_method, card_network| {
+            let rule = find_surcharge_rule(&surcharge_config, payment_method, card_network);
+            calculate_surcharge(&surcharge_config, rule.unwrap(), 10000).unwrap()
+        };
+
+        assert_eq!(
+            calculate(
+                api_enums::PaymentMethodType::Card,
+                Some(api_enums::CardNetwork::AmericanExpress)
+            ),
+            SurchargeDetails {
+                surcharge_amount: 300,
+                tax_on_surcharge_amount: 54,
+            }
+        );
+        assert_eq!(
+            calculate(
+                api_enums::PaymentMethodType::Card,
+                Some(api_enums::CardNetwork::Visa)
+            ),
+            SurchargeDetails {
+                surcharge_amount: 150,
+                tax_on_surcharge_amount: 27,
+            }
+        );
+        assert_eq!(
+            calculate(api_enums::PaymentMethodType::Wallet, None),
+            SurchargeDetails {
+                surcharge_amount: 50,
+                tax_on_surcharge_amount: 9,
+            }
+        );
+    }
+
+    #[test]
+    fn test_no_surcharge_without_matching_rule() {
+        let surcharge_config = surcharge_config();
+        let rule = find_surcharge_rule(
+            &surcharge_config,
+            api_enums::PaymentMethodType::BankTransfer,
+            None,
+        );
+        assert!(rule.is_none());
+    }
+
+    #[test]
+    fn test_get_card_network() {
+        let card_network = |card_number: &str| {
+            get_card_network(&api::CCard {
+                card_number: card_number.to_string().into(),
+                card_exp_month: "10".to_string().into(),
+                card_exp_year: "35".to_string().into(),
+                card_holder_name: "John Doe".to_string().into(),
+                card_cvc: "123".to_string().into(),
+            })
+        };
+
+        assert_eq!(
+            card_network("4242424242424242"),
+            Some(api_enums::CardNetwork::Visa)
+        );
+        assert_eq!(
+            card_network("5555555555554444"),
+            Some(api_enums::CardNetwork::Mastercard)
+        );
+        assert_eq!(
+            card_network("378282246310005"),
+            Some(api_enums::CardNetwork::AmericanExpress)
+        );
+        assert_eq!(
+            card_network("6011111111111117"),
+            Some(api_enums::CardNetwork::Discover)
+        );
+        assert_eq!(card_network("9999999999999999"), None);
+    }
+}
@@ -1,15 +1,20 @@
 use std::marker::PhantomData;
 
+use common_utils::types::MinorUnit;
 use error_stack::ResultExt;
 use router_env::{instrument, tracing};
 
 use super::payments::PaymentAddress;
 use crate::{
     consts,
-    core::errors::{self, RouterResult},
+    core::{
+        errors::{self, RouterResult},
+        forex,
+    },
+    pii,
     routes::AppState,
     types::{
-        self,
+        self, api,
         storage::{self, enums},
     },
     utils::{generate_id, OptionExt, ValueExt},
@@ -27,22 +32,43 @@ pub async fn construct_refund_router_data<'a, F>(
     refund: &'a storage::Refund,
 ) -> RouterResult<types::RefundsRouterData<F>> {
     let db = &*state.store;
+    // Refunds issued by an operator through another account of the connector, when the account
+    // of the payment was disabled, are made with the account recorded on the refund
+    let connector_label = refund.alternate_connector_label.clone().unwrap_or_else(|| {
+        get_connector_label(
+            connector_id,
+            payment_intent.business_country.as_ref(),
+            payment_intent.business_label.as_ref(),
+        )
+    });
     let merchant_connector_account = db
-        .find_merchant_connector_account_by_merchant_id_connector(
+        .find_merchant_connector_account_by_merchant_id_connector_label(
             &merchant_account.merchant_id,
-            connector_id,
+            &connector_label,
         )
         .await
         .change_context(errors::ApiErrorResponse::MerchantAccountNotFound)?;
 
-    let auth_type: types::ConnectorAuthType = merchant_connector_account
-        .connector_account_details
-        .parse_value("ConnectorAuthType")
-        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let auth_type = get_connector_auth_type(state, &merchant_connector_account).await?;
 
     let status = payment_attempt.status;
 
-    let (amount, currency) = money;
+    // Payments settled in another currency are refunded in the settlement currency, with the
+    // exchange rate at which the payment was converted
+    let ((amount, currency), refund_amount) = match (
+        payment_attempt.settlement_currency,
+        payment_attempt.settlement_amount,
+    ) {
+        (Some(settlement_currency), Some(settlement_amount)) => (
+            (settlement_amount, settlement_currency),
+            forex::convert_with_recorded_rate(
+                refund.refund_amount,
+                payment_attempt.amount,
+                settlement_amount,
+            )?,
+        ),
+        _ => (money, refund.refund_amount),
+    };
 
     let payment_method_type = payment_attempt
         .payment_method
@@ -65,11 +91,18 @@ pub async fn construct_refund_router_data<'a, F>(
         address: PaymentAddress::default(),
         auth_type: payment_attempt.authentication_type.unwrap_or_default(),
         connector_meta_data: None,
-        amount_captured: payment_intent.amount_captured,
+        amount_captured: payment_intent
+            .amount_captured
+            .map(MinorUnit::get_amount_as_i64),
+        access_token: None,
+        session_token: None,
+        payment_checks: None,
+        connector_api_log: None,
+        test_mode: merchant_connector_account.test_mode,
         request: types::RefundsData {
             refund_id: refund.refund_id.clone(),
             connector_transaction_id: refund.connector_transaction_id.clone(),
-            refund_amount: refund.refund_amount,
+            refund_amount,
             currency,
             amount,
             connector_metadata: payment_attempt.connector_metadata.clone(),
@@ -85,6 +118,170 @@ pub async fn construct_refund_router_data<'a, F>(
     Ok(router_data)
 }
 
+/// Constructs the router data for the connector flows of a dispute, which are made against the
+/// payment attempt which was disputed.
+#[instrument(skip_all)]
+pub async fn construct_dispute_router_data<F, Req, Res>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    dispute: &storage::Dispute,
+    payment_attempt: &storage::PaymentAttempt,
+    request: Req,
+    response: Result<Res, types::ErrorResponse>,
+) -> RouterResult<types::RouterData<F, Req, Res>> {
+    let db = &*state.store;
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &payment_attempt.payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+    let connector_label = get_connector_label(
+        &dispute.connector,
+        payment_intent.business_country.as_ref(),
+        payment_intent.business_label.as_ref(),
+    );
+    let merchant_connector_account = db
+        .find_merchant_connector_account_by_merchant_id_connector_label(
+            &merchant_account.merchant_id,
+            &connector_label,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)?;
+
+    let auth_type = get_connector_auth_type(state, &merchant_connector_account).await?;
+
+    let payment_method_type = payment_attempt
+        .payment_method
+        .get_required_value("payment_method_type")?;
+
+    let router_data = types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_account.merchant_id.clone(),
+        connector: merchant_connector_account.connector_name,
+        payment_id: payment_attempt.payment_id.clone(),
+        attempt_id: Some(payment_attempt.attempt_id.clone()),
+        status: payment_attempt.status,
+        payment_method: payment_method_type,
+        connector_auth_type: auth_type,
+        description: None,
+        return_url: None,
+        router_return_url: None,
+        payment_method_id: payment_attempt.payment_method_id.clone(),
+        address: PaymentAddress::default(),
+        auth_type: payment_attempt.authentication_type.unwrap_or_default(),
+        connector_meta_data: None,
+        amount_captured: None,
+        access_token: None,
+        session_token: None,
+        payment_checks: None,
+        connector_api_log: None,
+        test_mode: merchant_connector_account.test_mode,
+        request,
+        response,
+    };
+
+    Ok(router_data)
+}
+
+/// Constructs the router data for a payout flow. The response is initialized as not implemented,
+/// so that connectors which do not support payouts fail the payout instead of leaving it pending.
+#[instrument(skip_all)]
+pub async fn construct_payout_router_data<F>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payout: &storage::Payout,
+    payout_attempt: &storage::PayoutAttempt,
+    payout_method_data: Option<api::PayoutMethodData>,
+    email: Option<pii::Secret<String, pii::Email>>,
+) -> RouterResult<types::PayoutsRouterData<F>> {
+    let db = &*state.store;
+    // Payouts do not carry business details, so they are made with the default account of the
+    // connector
+    let merchant_connector_account = db
+        .find_merchant_connector_account_by_merchant_id_connector_label(
+            &merchant_account.merchant_id,
+            &get_connector_label(&payout_attempt.connector, None, None),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)?;
+
+    let auth_type = get_connector_auth_type(state, &merchant_connector_account).await?;
+
+    let router_data = types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_account.merchant_id.clone(),
+        connector: merchant_connector_account.connector_name,
+        payment_id: payout.payout_id.clone(),
+        attempt_id: Some(payout_attempt.payout_attempt_id.clone()),
+        status: enums::AttemptStatus::Pending,
+        payment_method: enums::PaymentMethodType::BankTransfer,
+        connector_auth_type: auth_type,
+        description: payout.description.clone(),
+        return_url: None,
+        router_return_url: None,
+        payment_method_id: None,
+        address: PaymentAddress::default(),
+        auth_type: enums::AuthenticationType::default(),
+        connector_meta_data: None,
+        amount_captured: None,
+        access_token: None,
+        session_token: None,
+        payment_checks: None,
+        connector_api_log: None,
+        test_mode: merchant_connector_account.test_mode,
+        request: types::PayoutsData {
+            payout_id: payout.payout_id.clone(),
+            amount: payout.amount,
+            currency: payout.currency,
+            customer_id: payout.customer_id.clone(),
+            email,
+            payout_method_data,
+            connector_payout_id: payout_attempt.connector_payout_id.clone(),
+        },
+        response: Err(types::ErrorResponse::get_not_implemented()),
+    };
+
+    Ok(router_data)
+}
+
+/// Derives the label which distinguishes the connector accounts of a merchant for the same
+/// connector. An account without business details is labelled with just the connector name, and
+/// is used for payments which do not specify business details either.
+pub fn get_connector_label(
+    connector: &str,
+    business_country: Option<&String>,
+    business_label: Option<&String>,
+) -> String {
+    match (business_country, business_label) {
+        (None, None) => connector.to_string(),
+        (business_country, business_label) => format!(
+            "{connector}_{}_{}",
+            business_country.map(String::as_str).unwrap_or_default(),
+            business_label.map(String::as_str).unwrap_or_default()
+        ),
+    }
+}
+
+/// Decrypts the credentials of the merchant connector account, with which the connector is
+/// authenticated.
+pub async fn get_connector_auth_type(
+    state: &AppState,
+    merchant_connector_account: &storage::MerchantConnectorAccount,
+) -> RouterResult<types::ConnectorAuthType> {
+    state
+        .connector_credentials
+        .decrypt(&merchant_connector_account.connector_account_details)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to decrypt the connector account details")?
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while parsing value for ConnectorAuthType")
+}
+
 pub fn get_or_generate_id(
     key: &str,
     provided_id: &Option<String>,
@@ -136,6 +333,22 @@ mod tests {
         assert_eq!(result, payment_id);
     }
 
+    #[test]
+    fn test_get_connector_label() {
+        let country = "US".to_string();
+        let label = "default".to_string();
+
+        assert_eq!(get_connector_label("stripe", None, None), "stripe");
+        assert_eq!(
+            get_connector_label("stripe", Some(&country), Some(&label)),
+            "stripe_US_default"
+        );
+        assert_eq!(
+            get_connector_label("stripe", Some(&country), None),
+            "stripe_US_"
+        );
+    }
+
     #[test]
     fn test_generate_id() {
         let generated_id = generate_id(consts::ID_LENGTH, "ref");
@@ -0,0 +1,91 @@
+use masking::PeekInterface;
+use router_env::{instrument, logger, opentelemetry, tracing};
+
+use crate::{
+    configs::settings::{VelocityKey, VelocityRule},
+    core::{blocklist, errors::RouterResult},
+    routes::{metrics, AppState},
+    types::{self, api, storage},
+};
+
+/// Counts the payment against the velocity rules configured in the settings, and returns the
+/// first rule whose limit the payment exceeds, if any.
+///
+/// Counters which could not be updated are skipped, so that an outage of Redis does not prevent
+/// the merchant from accepting payments.
+#[instrument(skip_all)]
+pub async fn find_exceeded_velocity_rule(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    customer: &Option<storage::Customer>,
+    router_data: &types::PaymentsAuthorizeRouterData,
+) -> RouterResult<Option<VelocityRule>> {
+    let mut exceeded_rule = None;
+
+    for rule in &state.conf.velocity.rules {
+        let value = match rule.key {
+            VelocityKey::Card => match &router_data.request.payment_method_data {
+                // The fingerprint is used, so that card numbers are not stored in Redis
+                api::PaymentMethod::Card(card) => Some(blocklist::get_card_fingerprint(
+                    state,
+                    card.card_number.peek(),
+                )?),
+                _ => None,
+            },
+            VelocityKey::Customer => customer
+                .as_ref()
+                .map(|customer| customer.customer_id.clone()),
+            VelocityKey::IpAddress => router_data
+                .request
+                .browser_info
+                .as_ref()
+                .and_then(|browser_info| browser_info.ip_address)
+                .map(|ip_address| ip_address.to_string()),
+        };
+        let value = match value {
+            Some(value) => value,
+            None => continue,
+        };
+
+        let key = get_velocity_counter_key(&merchant_account.merchant_id, rule, &value);
+        match state
+            .store
+            .increment_velocity_counter(&key, rule.window)
+            .await
+        {
+            Ok(attempts) if attempts > i64::from(rule.max_attempts) => {
+                exceeded_rule.get_or_insert_with(|| rule.clone());
+            }
+            Ok(_) => {}
+            Err(error) => {
+                logger::warn!(?error, velocity_key = %rule.key, "Failed to count the payment");
+            }
+        }
+    }
+
+    if let Some(rule) = &exceeded_rule {
+        metrics::VELOCITY_LIMIT_EXCEEDED_COUNT.add(
+            &metrics::CONTEXT,
+            1,
+            &[
+                opentelemetry::KeyValue::new("merchant_id", merchant_account.merchant_id.clone()),
+                opentelemetry::KeyValue::new("velocity_key", rule.key.to_string()),
+            ],
+        );
+        logger::info!(
+            velocity_key = %rule.key,
+            max_attempts = rule.max_attempts,
+            window = rule.window,
+            "Payment exceeded a velocity rule"
+        );
+    }
+
+    Ok(exceeded_rule)
+}
+
+fn get_velocity_counter_key(merchant_id: &str, rule: &VelocityRule, value: &str) -> String {
+    format!(
+        "velocity_{merchant_id}_{}_{}_{value}",
+        rule.key, rule.window
+    )
+}
@@ -1,34 +1,47 @@
 pub mod transformers;
 pub mod utils;
 
+use std::marker::PhantomData;
+
 use error_stack::{IntoReport, ResultExt};
-use masking::ExposeInterface;
-use router_env::{instrument, tracing};
+use masking::{ExposeInterface, PeekInterface};
+use rand::Rng;
+use router_env::{instrument, opentelemetry, tracing, tracing::Instrument, Tag};
 
 use crate::{
+    configs::settings,
     consts,
     core::{
-        errors::{self, CustomResult, RouterResponse},
-        payments,
+        errors::{self, CustomResult, RouterResponse, RouterResult, StorageErrorExt},
+        payments::{self, access_token},
+        refunds, utils as core_utils,
     },
     db::StorageInterface,
-    logger,
-    routes::AppState,
+    headers, logger,
+    routes::{metrics, AppState},
+    scheduler::utils as pt_utils,
     services,
     types::{
-        api,
+        self,
+        api::{self, mandates::MandateResponseExt},
         storage::{self, enums},
         transformers::{ForeignInto, ForeignTryInto},
     },
-    utils::{generate_id, Encode, OptionExt, ValueExt},
+    utils::{
+        crypto::{self, SignMessage},
+        generate_id, Encode, OptionExt, ValueExt,
+    },
 };
 
 const OUTGOING_WEBHOOK_TIMEOUT_MS: u64 = 5000;
+pub(crate) const OUTGOING_WEBHOOK_RETRY_RUNNER: &str = "OUTGOING_WEBHOOK_RETRY_WORKFLOW";
+const OUTGOING_WEBHOOK_RETRY_TASK: &str = "OUTGOING_WEBHOOK_RETRY";
 
 #[instrument(skip_all)]
 async fn payments_incoming_webhook_flow(
     state: AppState,
     merchant_account: storage::MerchantAccount,
+    payment_id_type: api::PaymentIdType,
     webhook_details: api::IncomingWebhookDetails,
     source_verified: bool,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
@@ -43,13 +56,12 @@ async fn payments_incoming_webhook_flow(
         merchant_account.clone(),
         payments::operations::PaymentStatus,
         api::PaymentsRetrieveRequest {
-            resource_id: api::PaymentIdType::ConnectorTransactionId(
-                webhook_details.object_reference_id,
-            ),
+            resource_id: payment_id_type,
             merchant_id: Some(merchant_account.merchant_id.clone()),
             force_sync: true,
             connector: None,
             param: None,
+            client_secret: None,
         },
         services::AuthFlow::Merchant,
         consume_or_trigger_flow,
@@ -59,37 +71,359 @@ async fn payments_incoming_webhook_flow(
 
     match payments_response {
         services::ApplicationResponse::Json(payments_response) => {
-            let payment_id = payments_response
-                .payment_id
-                .clone()
-                .get_required_value("payment_id")
-                .change_context(errors::WebhooksFlowError::PaymentsCoreFailed)?;
+            trigger_payment_outgoing_webhook(state, merchant_account, payments_response).await?;
+        }
 
-            let event_type: enums::EventType = payments_response
-                .status
-                .foreign_try_into()
-                .into_report()
-                .change_context(errors::WebhooksFlowError::PaymentsCoreFailed)?;
+        _ => Err(errors::WebhooksFlowError::PaymentsCoreFailed).into_report()?,
+    }
 
-            create_event_and_trigger_outgoing_webhook(
-                merchant_account,
-                event_type,
-                enums::EventClass::Payments,
-                None,
-                payment_id,
-                enums::EventObjectType::PaymentDetails,
-                api::OutgoingWebhookContent::PaymentDetails(payments_response),
-                state.store,
+    Ok(())
+}
+
+/// Notifies the merchant of the current status of a payment. Fails for statuses which have no
+/// corresponding event type.
+#[instrument(skip_all)]
+pub(crate) async fn trigger_payment_outgoing_webhook(
+    state: AppState,
+    merchant_account: storage::MerchantAccount,
+    payments_response: api::PaymentsResponse,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let payment_id = payments_response
+        .payment_id
+        .clone()
+        .get_required_value("payment_id")
+        .change_context(errors::WebhooksFlowError::PaymentsCoreFailed)?;
+
+    let event_type: enums::EventType = payments_response
+        .status
+        .foreign_try_into()
+        .into_report()
+        .change_context(errors::WebhooksFlowError::PaymentsCoreFailed)?;
+
+    create_event_and_trigger_outgoing_webhook(
+        merchant_account,
+        event_type,
+        enums::EventClass::Payments,
+        None,
+        payment_id,
+        enums::EventObjectType::PaymentDetails,
+        api::OutgoingWebhookContent::PaymentDetails(payments_response),
+        state,
+    )
+    .await
+}
+
+#[instrument(skip_all)]
+async fn refunds_incoming_webhook_flow(
+    state: AppState,
+    merchant_account: storage::MerchantAccount,
+    webhook_details: api::IncomingWebhookDetails,
+    connector_name: &str,
+    event_type: api::IncomingWebhookEvent,
+    source_verified: bool,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let db = &*state.store;
+    let refund = db
+        .find_refund_by_merchant_id_connector_refund_id_connector(
+            &merchant_account.merchant_id,
+            &webhook_details.object_reference_id,
+            connector_name,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::ResourceNotFound)
+        .attach_printable("Failed to find the refund referenced by the webhook")?;
+
+    // The status in the webhook is trusted only if the source of the webhook is verified,
+    // otherwise the refund is synced with the connector, which notifies the merchant if the
+    // status of the refund changed
+    if source_verified {
+        let refund_status = match event_type {
+            api::IncomingWebhookEvent::RefundSuccess => enums::RefundStatus::Success,
+            api::IncomingWebhookEvent::RefundFailure => enums::RefundStatus::Failure,
+            _ => Err(errors::WebhooksFlowError::RefundsCoreFailed)
+                .into_report()
+                .attach_printable("Unexpected event type in refunds webhook flow")?,
+        };
+        let refund_id = refund.refund_id.clone();
+        let previous_status = refund.refund_status;
+        let updated_refund = db
+            .update_refund(
+                refund,
+                storage::RefundUpdate::StatusUpdate {
+                    connector_refund_id: None,
+                    sent_to_gateway: true,
+                    refund_status,
+                },
+                merchant_account.storage_scheme,
             )
-            .await?;
+            .await
+            .change_context(errors::WebhooksFlowError::RefundsCoreFailed)
+            .attach_printable_lazy(|| {
+                format!("Failed to update refund with refund_id: {refund_id}")
+            })?;
+
+        if updated_refund.refund_status != previous_status {
+            trigger_refund_outgoing_webhook(state, merchant_account, updated_refund).await?;
         }
+    } else {
+        refunds::refund_retrieve_core(&state, merchant_account, refund.refund_id)
+            .await
+            .change_context(errors::WebhooksFlowError::RefundsCoreFailed)?;
+    }
 
-        _ => Err(errors::WebhooksFlowError::PaymentsCoreFailed).into_report()?,
+    Ok(())
+}
+
+#[instrument(skip_all)]
+pub(crate) async fn trigger_refund_outgoing_webhook(
+    state: AppState,
+    merchant_account: storage::MerchantAccount,
+    refund: storage::Refund,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let event_type = match refund.refund_status {
+        enums::RefundStatus::Success => enums::EventType::RefundSucceeded,
+        enums::RefundStatus::Failure => enums::EventType::RefundFailed,
+        // The merchant is notified only once the refund reaches a terminal status
+        _ => return Ok(()),
+    };
+    let refund_response: api_models::refunds::RefundResponse = refund.foreign_into();
+
+    create_event_and_trigger_outgoing_webhook(
+        merchant_account,
+        event_type,
+        enums::EventClass::Refunds,
+        Some(refund_response.payment_id.clone()),
+        refund_response.refund_id.clone(),
+        enums::EventObjectType::RefundDetails,
+        api::OutgoingWebhookContent::RefundDetails(refund_response),
+        state,
+    )
+    .await
+}
+
+#[instrument(skip_all)]
+pub(crate) async fn trigger_mandate_outgoing_webhook(
+    state: AppState,
+    merchant_account: storage::MerchantAccount,
+    mandate: storage::Mandate,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let event_type = match mandate.mandate_status {
+        enums::MandateStatus::Active => enums::EventType::MandateActive,
+        enums::MandateStatus::Revoked => enums::EventType::MandateRevoked,
+        // The merchant is notified only when the mandate becomes usable or is revoked
+        _ => return Ok(()),
+    };
+    let mandate_response =
+        api::mandates::MandateResponse::from_db_mandate(&state, mandate, &merchant_account)
+            .await
+            .change_context(errors::WebhooksFlowError::MandatesCoreFailed)?;
+
+    create_event_and_trigger_outgoing_webhook(
+        merchant_account,
+        event_type,
+        enums::EventClass::Mandates,
+        None,
+        mandate_response.mandate_id.clone(),
+        enums::EventObjectType::MandateDetails,
+        api::OutgoingWebhookContent::MandateDetails(mandate_response),
+        state,
+    )
+    .await
+}
+
+#[instrument(skip_all)]
+pub(crate) async fn trigger_payout_outgoing_webhook(
+    state: AppState,
+    merchant_account: storage::MerchantAccount,
+    payout: storage::Payout,
+    payout_attempt: storage::PayoutAttempt,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let event_type = match payout.status {
+        enums::PayoutStatus::Success => enums::EventType::PayoutSucceeded,
+        enums::PayoutStatus::Failed => enums::EventType::PayoutFailed,
+        enums::PayoutStatus::Cancelled => enums::EventType::PayoutCancelled,
+        // The merchant is notified only once the payout reaches a terminal status
+        _ => return Ok(()),
+    };
+    let payout_response: api::payouts::PayoutResponse = (payout, payout_attempt).foreign_into();
+
+    create_event_and_trigger_outgoing_webhook(
+        merchant_account,
+        event_type,
+        enums::EventClass::Payouts,
+        None,
+        payout_response.payout_id.clone(),
+        enums::EventObjectType::PayoutDetails,
+        api::OutgoingWebhookContent::PayoutDetails(payout_response),
+        state,
+    )
+    .await
+}
+
+/// Records the fees of a dispute in the ledger of the costs of its payment. Fees recorded already,
+/// from an earlier notification of the dispute, are skipped.
+#[instrument(skip_all)]
+async fn record_dispute_fees(
+    db: &dyn StorageInterface,
+    dispute: &storage::Dispute,
+    fees: Vec<api::DisputeFee>,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    for fee in fees.into_iter().filter(|fee| fee.amount != 0) {
+        let cost_type = if fee.amount > 0 {
+            enums::PaymentCostType::DisputeFee
+        } else {
+            enums::PaymentCostType::DisputeFeeReversal
+        };
+        let payment_cost = storage::PaymentCostNew {
+            cost_id: generate_id(consts::ID_LENGTH, "cost"),
+            merchant_id: dispute.merchant_id.clone(),
+            payment_id: dispute.payment_id.clone(),
+            attempt_id: dispute.attempt_id.clone(),
+            dispute_id: Some(dispute.dispute_id.clone()),
+            connector: dispute.connector.clone(),
+            cost_type,
+            amount: fee.amount,
+            currency: fee.currency.to_uppercase(),
+            connector_reference: fee.connector_reference,
+        };
+
+        match db.insert_payment_cost(payment_cost).await {
+            Ok(_) => {}
+            Err(error) if error.current_context().is_db_unique_violation() => {
+                logger::debug!(
+                    dispute_id = %dispute.dispute_id,
+                    "Dispute fee has already been recorded"
+                );
+            }
+            Err(error) => Err(error)
+                .change_context(errors::WebhooksFlowError::DisputesCoreFailed)
+                .attach_printable("Failed to record the dispute fee")?,
+        }
     }
 
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+async fn disputes_incoming_webhook_flow(
+    state: AppState,
+    merchant_account: storage::MerchantAccount,
+    webhook_details: api::IncomingWebhookDetails,
+    connector_name: &str,
+    event_type: api::IncomingWebhookEvent,
+    dispute_details: api::DisputePayload,
+    source_verified: bool,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    // Disputes cannot be synced with the connector, hence the webhook is processed only if its
+    // source is verified
+    if !source_verified {
+        Err(errors::WebhooksFlowError::WebhookSourceNotVerified).into_report()?;
+    }
+
+    let dispute_status = match event_type {
+        api::IncomingWebhookEvent::DisputeOpened => enums::DisputeStatus::DisputeOpened,
+        api::IncomingWebhookEvent::DisputeChallenged => enums::DisputeStatus::DisputeChallenged,
+        api::IncomingWebhookEvent::DisputeWon => enums::DisputeStatus::DisputeWon,
+        api::IncomingWebhookEvent::DisputeLost => enums::DisputeStatus::DisputeLost,
+        _ => Err(errors::WebhooksFlowError::DisputesCoreFailed)
+            .into_report()
+            .attach_printable("Unexpected event type in disputes webhook flow")?,
+    };
+
+    let db = &*state.store;
+    let payment_attempt = db
+        .find_payment_attempt_by_merchant_id_connector_txn_id(
+            &merchant_account.merchant_id,
+            &webhook_details.object_reference_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::ResourceNotFound)
+        .attach_printable("Failed to find the payment attempt referenced by the webhook")?;
+
+    let dispute_fees = dispute_details.fees;
+    let existing_dispute = db
+        .find_by_merchant_id_connector_connector_dispute_id(
+            &merchant_account.merchant_id,
+            connector_name,
+            &dispute_details.connector_dispute_id,
+        )
+        .await;
+
+    let dispute = match existing_dispute {
+        Ok(dispute) => {
+            // The outcome of a dispute which was challenged is the outcome of its challenge
+            let challenge_status = match (dispute.challenge_status, dispute_status) {
+                (Some(_), enums::DisputeStatus::DisputeChallenged) => {
+                    Some(enums::DisputeChallengeStatus::UnderReview)
+                }
+                (Some(_), enums::DisputeStatus::DisputeWon) => {
+                    Some(enums::DisputeChallengeStatus::Won)
+                }
+                (Some(_), enums::DisputeStatus::DisputeLost) => {
+                    Some(enums::DisputeChallengeStatus::Lost)
+                }
+                _ => None,
+            };
+            db.update_dispute(
+                dispute,
+                storage::DisputeUpdate::Update {
+                    dispute_status,
+                    connector_status: dispute_details.connector_status,
+                    connector_reason: dispute_details.connector_reason,
+                    connector_reason_code: dispute_details.connector_reason_code,
+                    challenge_required_by: dispute_details.challenge_required_by,
+                    challenge_status,
+                },
+            )
+            .await
+            .change_context(errors::WebhooksFlowError::DisputesCoreFailed)
+            .attach_printable("Failed to update the dispute")?
+        }
+        Err(error) if error.current_context().is_db_not_found() => db
+            .insert_dispute(storage::DisputeNew {
+                dispute_id: generate_id(consts::ID_LENGTH, "dp"),
+                amount: dispute_details.amount,
+                currency: dispute_details.currency,
+                dispute_status,
+                payment_id: payment_attempt.payment_id,
+                attempt_id: payment_attempt.attempt_id,
+                merchant_id: merchant_account.merchant_id.clone(),
+                connector: connector_name.to_string(),
+                connector_status: dispute_details.connector_status,
+                connector_dispute_id: dispute_details.connector_dispute_id,
+                connector_reason: dispute_details.connector_reason,
+                connector_reason_code: dispute_details.connector_reason_code,
+                challenge_required_by: dispute_details.challenge_required_by,
+            })
+            .await
+            .change_context(errors::WebhooksFlowError::DisputesCoreFailed)
+            .attach_printable("Failed to insert the dispute")?,
+        Err(error) => Err(error)
+            .change_context(errors::WebhooksFlowError::DisputesCoreFailed)
+            .attach_printable("Failed to find the dispute referenced by the webhook")?,
+    };
+
+    record_dispute_fees(db, &dispute, dispute_fees).await?;
+
+    let event_type: enums::EventType = dispute.dispute_status.foreign_into();
+    let dispute_response: api::DisputeResponse = dispute.foreign_into();
+
+    create_event_and_trigger_outgoing_webhook(
+        merchant_account,
+        event_type,
+        enums::EventClass::Disputes,
+        Some(dispute_response.payment_id.clone()),
+        dispute_response.dispute_id.clone(),
+        enums::EventObjectType::DisputeDetails,
+        api::OutgoingWebhookContent::DisputeDetails(dispute_response),
+        state,
+    )
+    .await
+}
+
 #[allow(clippy::too_many_arguments)]
 #[instrument(skip_all)]
 async fn create_event_and_trigger_outgoing_webhook(
@@ -100,95 +434,586 @@ async fn create_event_and_trigger_outgoing_webhook(
     primary_object_id: String,
     primary_object_type: enums::EventObjectType,
     content: api::OutgoingWebhookContent,
-    db: Box<dyn StorageInterface>,
+    state: AppState,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
-    let arbiter = actix::Arbiter::try_current()
-        .ok_or(errors::WebhooksFlowError::ForkFlowFailed)
-        .into_report()?;
+    let webhook_config =
+        utils::find_merchant_webhook_config(&*state.store, &merchant_account.merchant_id)
+            .await
+            .change_context(errors::WebhooksFlowError::MerchantConfigNotFound)?;
+    if !utils::is_outgoing_webhook_enabled(webhook_config.as_ref(), event_type) {
+        logger::info!(
+            merchant_id = %merchant_account.merchant_id,
+            ?event_type,
+            "Outgoing webhook not sent as it is disabled in the webhook config of the merchant"
+        );
+        return Ok(());
+    }
 
-    let new_event = storage::EventNew {
+    let outgoing_webhook = api::OutgoingWebhook {
+        merchant_id: merchant_account.merchant_id.clone(),
         event_id: generate_id(consts::ID_LENGTH, "evt"),
+        event_type: event_type.foreign_into(),
+        content,
+        timestamp: common_utils::date_time::now(),
+    };
+
+    let webhook_content = Encode::<api::OutgoingWebhook>::encode_to_value(&outgoing_webhook)
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)?;
+
+    let new_event = storage::EventNew {
+        event_id: outgoing_webhook.event_id.clone(),
         event_type,
         event_class,
         is_webhook_notified: false,
         intent_reference_id,
         primary_object_id,
         primary_object_type,
+        merchant_id: Some(merchant_account.merchant_id.clone()),
+        content: Some(webhook_content),
     };
 
-    let event = db
+    state
+        .store
         .insert_event(new_event)
         .await
         .change_context(errors::WebhooksFlowError::WebhookEventCreationFailed)?;
 
-    let outgoing_webhook = api::OutgoingWebhook {
-        merchant_id: merchant_account.merchant_id.clone(),
-        event_id: event.event_id,
-        event_type: event.event_type.foreign_into(),
-        content,
-        timestamp: event.created_at,
-    };
+    let delivery = async move {
+        let db = &*state.store;
+        let event_id = outgoing_webhook.event_id.clone();
+        let result =
+            trigger_webhook_to_merchant(&state, &merchant_account, &event_id, 1, &outgoing_webhook)
+                .await;
+
+        match result {
+            Ok(()) => mark_event_as_notified(db, event_id).await,
+            Err(error) => {
+                logger::error!(?error, %event_id, attempt = 1, "Outgoing webhook delivery failed");
 
-    arbiter.spawn(async move {
-        let result = trigger_webhook_to_merchant(merchant_account, outgoing_webhook, db).await;
+                if is_retryable_delivery_error(error.current_context())
+                    && state.conf.webhooks.outgoing_max_attempts > 1
+                {
+                    let result = add_outgoing_webhook_retry_task(
+                        db,
+                        &state.conf.webhooks,
+                        &merchant_account.merchant_id,
+                        &outgoing_webhook,
+                    )
+                    .await;
 
-        if let Err(e) = result {
-            logger::error!(?e);
+                    if let Err(error) = result {
+                        logger::error!(?error, %event_id, "Failed to schedule outgoing webhook retry");
+                    }
+                }
+            }
         }
-    });
+    };
+
+    match actix::Arbiter::try_current() {
+        Some(arbiter) => {
+            arbiter.spawn(delivery.in_current_span());
+        }
+        // Outside of the server, e.g. in the scheduler, the webhook is delivered inline
+        None => delivery.await,
+    }
 
     Ok(())
 }
 
-async fn trigger_webhook_to_merchant(
-    merchant_account: storage::MerchantAccount,
-    webhook: api::OutgoingWebhook,
-    _db: Box<dyn StorageInterface>,
+/// Schedules the redelivery of an outgoing webhook whose first delivery attempt failed.
+async fn add_outgoing_webhook_retry_task(
+    db: &dyn StorageInterface,
+    webhooks_config: &settings::WebhooksSettings,
+    merchant_id: &str,
+    webhook: &api::OutgoingWebhook,
+) -> Result<(), errors::ProcessTrackerError> {
+    let tracking_data = storage::OutgoingWebhookTrackingData {
+        merchant_id: merchant_id.to_string(),
+        event_id: webhook.event_id.clone(),
+        webhook: serde_json::to_value(webhook)
+            .map_err(|_| errors::ProcessTrackerError::SerializationFailed)?,
+    };
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        OUTGOING_WEBHOOK_RETRY_RUNNER,
+        OUTGOING_WEBHOOK_RETRY_TASK,
+        &webhook.event_id,
+        merchant_id,
+    );
+    let process_tracker_entry =
+        <storage::ProcessTracker as storage::ProcessTrackerExt>::make_process_tracker_new(
+            process_tracker_id,
+            OUTGOING_WEBHOOK_RETRY_TASK,
+            OUTGOING_WEBHOOK_RETRY_RUNNER,
+            tracking_data,
+            get_outgoing_webhook_retry_schedule_time(webhooks_config, 0),
+        )?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
+/// Delay before the retry of an outgoing webhook, which is doubled on every retry and capped at
+/// the configured maximum interval.
+pub(crate) fn get_outgoing_webhook_retry_delay(
+    webhooks_config: &settings::WebhooksSettings,
+    retry_count: i32,
+) -> time::Duration {
+    let multiplier = 2_i64.saturating_pow(u32::try_from(retry_count).unwrap_or_default());
+    let delay = webhooks_config
+        .outgoing_retry_interval
+        .saturating_mul(multiplier)
+        .min(webhooks_config.outgoing_max_retry_interval);
+
+    time::Duration::seconds(delay)
+}
+
+/// Time of the next retry of an outgoing webhook, with up to 20% of jitter added to the delay so
+/// that deliveries which failed together are not retried together.
+pub(crate) fn get_outgoing_webhook_retry_schedule_time(
+    webhooks_config: &settings::WebhooksSettings,
+    retry_count: i32,
+) -> time::PrimitiveDateTime {
+    let delay = get_outgoing_webhook_retry_delay(webhooks_config, retry_count);
+    let jitter = rand::thread_rng().gen_range(0..=delay.whole_seconds() / 5);
+
+    common_utils::date_time::now() + delay + time::Duration::seconds(jitter)
+}
+
+/// Only failures in reaching the merchant are retried, configuration errors would fail again.
+pub(crate) fn is_retryable_delivery_error(error: &errors::WebhooksFlowError) -> bool {
+    matches!(
+        error,
+        errors::WebhooksFlowError::CallToMerchantFailed
+            | errors::WebhooksFlowError::NotReceivedByMerchant
+    )
+}
+
+pub(crate) async fn mark_event_as_notified(db: &dyn StorageInterface, event_id: String) {
+    let result = db
+        .update_event(
+            event_id.clone(),
+            storage::EventUpdate::UpdateWebhookNotified {
+                is_webhook_notified: true,
+            },
+        )
+        .await;
+
+    if let Err(error) = result {
+        logger::error!(?error, %event_id, "Failed to mark event as notified");
+    }
+}
+
+/// Signs the webhook payload for the merchant to verify that the webhook was sent by the router.
+/// The signature is the hex encoded HMAC-SHA256 of `{timestamp}.{payload}`, where the timestamp is
+/// sent in a separate header so that the merchant can reject replayed webhooks.
+fn sign_outgoing_webhook(
+    secret: &str,
+    timestamp: i64,
+    payload: &str,
+) -> CustomResult<String, errors::WebhooksFlowError> {
+    let message = format!("{timestamp}.{payload}");
+    let signature = crypto::HmacSha256
+        .sign_message(secret.as_bytes(), message.as_bytes())
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)?;
+
+    Ok(hex::encode(signature))
+}
+
+/// Delivers the webhook to the merchant and records the delivery attempt, so that merchants can
+/// look up why a webhook was not received.
+#[instrument(skip(state, merchant_account, webhook), fields(merchant_id = %merchant_account.merchant_id))]
+pub(crate) async fn trigger_webhook_to_merchant<T: serde::Serialize>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    event_id: &str,
+    attempt_number: i32,
+    webhook: &T,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
-    let webhook_details_json = merchant_account
-        .webhook_details
-        .get_required_value("webhook_details")
-        .change_context(errors::WebhooksFlowError::MerchantWebhookDetailsNotFound)?;
-
-    let webhook_details: api::WebhookDetails =
-        webhook_details_json
-            .parse_value("WebhookDetails")
-            .change_context(errors::WebhooksFlowError::MerchantWebhookDetailsNotFound)?;
-
-    let webhook_url = webhook_details
-        .webhook_url
-        .get_required_value("webhook_url")
-        .change_context(errors::WebhooksFlowError::MerchantWebhookURLNotConfigured)
-        .map(ExposeInterface::expose)?;
-
-    let response = reqwest::Client::new()
-        .post(&webhook_url)
-        .header(reqwest::header::CONTENT_TYPE, "application/json")
-        .json(&webhook)
+    let db = &*state.store;
+    let webhook_url_override =
+        utils::find_merchant_webhook_config(db, &merchant_account.merchant_id)
+            .await
+            .change_context(errors::WebhooksFlowError::MerchantConfigNotFound)?
+            .and_then(|webhook_config| webhook_config.webhook_url);
+
+    let started_at = std::time::Instant::now();
+    let response = send_webhook_to_merchant(
+        &state.conf.secrets,
+        merchant_account,
+        webhook_url_override,
+        webhook,
+    )
+    .await;
+    let latency_ms = i64::try_from(started_at.elapsed().as_millis()).unwrap_or(i64::MAX);
+
+    let (response_code, result) = match response {
+        Ok(res) => {
+            let status = res.status();
+            let result = if status.is_success() {
+                Ok(())
+            } else {
+                Err(errors::WebhooksFlowError::NotReceivedByMerchant)
+                    .into_report()
+                    .attach_printable_lazy(|| {
+                        format!("Merchant responded with status code {status}")
+                    })
+            };
+            (Some(i32::from(status.as_u16())), result)
+        }
+        Err(error) => (None, Err(error)),
+    };
+
+    let delivery_attempt = storage::WebhookDeliveryAttemptNew {
+        event_id: event_id.to_string(),
+        merchant_id: merchant_account.merchant_id.clone(),
+        attempt_number,
+        response_code,
+        latency_ms,
+        error_message: result
+            .as_ref()
+            .err()
+            .map(|error| error.current_context().to_string()),
+    };
+    if let Err(error) = db.insert_webhook_delivery_attempt(delivery_attempt).await {
+        logger::error!(?error, %event_id, attempt_number, "Failed to record webhook delivery attempt");
+    }
+
+    result
+}
+
+async fn send_webhook_to_merchant<T: serde::Serialize>(
+    secrets: &settings::Secrets,
+    merchant_account: &storage::MerchantAccount,
+    webhook_url_override: Option<String>,
+    webhook: &T,
+) -> CustomResult<reqwest::Response, errors::WebhooksFlowError> {
+    let webhook_url = match webhook_url_override {
+        Some(webhook_url) => webhook_url,
+        None => {
+            let webhook_details_json = merchant_account
+                .webhook_details
+                .clone()
+                .get_required_value("webhook_details")
+                .change_context(errors::WebhooksFlowError::MerchantWebhookDetailsNotFound)?;
+
+            let webhook_details: api::WebhookDetails = webhook_details_json
+                .parse_value("WebhookDetails")
+                .change_context(errors::WebhooksFlowError::MerchantWebhookDetailsNotFound)?;
+
+            webhook_details
+                .webhook_url
+                .get_required_value("webhook_url")
+                .change_context(errors::WebhooksFlowError::MerchantWebhookURLNotConfigured)
+                .map(ExposeInterface::expose)?
+        }
+    };
+
+    let payload = serde_json::to_string(webhook)
+        .into_report()
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)?;
+
+    let mut request = reqwest::Client::new().post(&webhook_url);
+
+    // The custom headers are added first, so that they cannot override the headers set below
+    if let Some(custom_headers) = merchant_account.outgoing_webhook_custom_headers.clone() {
+        let custom_headers =
+            utils::decrypt_outgoing_webhook_custom_headers(&secrets.master_enc_key, custom_headers)
+                .change_context(errors::WebhooksFlowError::CustomHeadersDecryptionFailed)?;
+        for (name, value) in custom_headers {
+            request = request.header(name, value.expose());
+        }
+    }
+
+    request = request.header(reqwest::header::CONTENT_TYPE, "application/json");
+
+    // The trace context is propagated to the merchant, so that the webhook can be correlated
+    // with the flow which triggered it
+    for (name, value) in router_env::trace_context_headers() {
+        request = request.header(name, value);
+    }
+    if let Some(request_id) = router_env::current_request_id() {
+        request = request.header(headers::X_REQUEST_ID, request_id);
+    }
+
+    match merchant_account.webhook_signing_secret.as_ref() {
+        Some(secret) => {
+            let timestamp = common_utils::date_time::now_unix_timestamp();
+            let signature = sign_outgoing_webhook(secret.peek(), timestamp, &payload)?;
+            request = request
+                .header(headers::X_WEBHOOK_SIGNATURE, signature)
+                .header(headers::X_WEBHOOK_TIMESTAMP, timestamp.to_string());
+        }
+        None => logger::warn!(
+            merchant_id = %merchant_account.merchant_id,
+            "Sending unsigned webhook, merchant has no webhook signing secret"
+        ),
+    }
+
+    request
+        .body(payload)
         .timeout(core::time::Duration::from_millis(
             OUTGOING_WEBHOOK_TIMEOUT_MS,
         ))
         .send()
-        .await;
+        .await
+        .into_report()
+        .change_context(errors::WebhooksFlowError::CallToMerchantFailed)
+}
 
-    match response {
-        Err(e) => {
-            // [#217]: Schedule webhook for retry.
-            Err(e)
-                .into_report()
-                .change_context(errors::WebhooksFlowError::CallToMerchantFailed)?;
+/// Connectors which verify the source of their webhooks through an API call to the connector,
+/// instead of a signature which can be verified locally.
+fn connector_verifies_webhook_source(connector: &api::ConnectorData) -> bool {
+    matches!(connector.connector_name, types::Connector::Paypal)
+}
+
+#[instrument(skip_all)]
+async fn verify_webhook_source_with_connector(
+    state: &AppState,
+    connector: &api::ConnectorData,
+    merchant_account: &storage::MerchantAccount,
+    headers: &actix_web::http::header::HeaderMap,
+    body: &[u8],
+) -> RouterResult<bool> {
+    let db = &*state.store;
+    // The webhook source is verified before the object of the webhook is known, so the secret of
+    // the default account of the connector is used
+    let merchant_connector_account = db
+        .find_merchant_connector_account_by_merchant_id_connector_label(
+            &merchant_account.merchant_id,
+            &core_utils::get_connector_label(connector.connector.id(), None, None),
+        )
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)
+        })?;
+
+    let connector_auth_type =
+        core_utils::get_connector_auth_type(state, &merchant_connector_account).await?;
+
+    let merchant_secret = connector
+        .connector
+        .get_webhook_source_verification_merchant_secret(db, &merchant_account.merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Could not fetch the merchant secret for webhook source verification")?
+        .current;
+
+    let mut router_data: types::VerifyWebhookSourceRouterData = types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_account.merchant_id.clone(),
+        connector: connector.connector.id().to_string(),
+        payment_id: consts::IRRELEVANT_PAYMENT_ID_IN_SOURCE_VERIFICATION_FLOW.to_string(),
+        attempt_id: None,
+        status: enums::AttemptStatus::default(),
+        payment_method: enums::PaymentMethodType::default(),
+        connector_auth_type,
+        description: None,
+        return_url: None,
+        router_return_url: None,
+        address: payments::PaymentAddress::default(),
+        auth_type: enums::AuthenticationType::default(),
+        connector_meta_data: None,
+        amount_captured: None,
+        access_token: None,
+        session_token: None,
+        payment_checks: None,
+        connector_api_log: None,
+        test_mode: merchant_connector_account.test_mode,
+        request: types::VerifyWebhookSourceRequestData {
+            webhook_headers: headers.clone(),
+            webhook_body: body.to_vec(),
+            merchant_secret,
+        },
+        response: Err(types::ErrorResponse::default()),
+        payment_method_id: None,
+    };
+
+    let add_access_token_result =
+        access_token::add_access_token(state, connector, merchant_account, &router_data).await?;
+    let should_continue = access_token::update_router_data_with_access_token_result(
+        &add_access_token_result,
+        &mut router_data,
+        &payments::CallConnectorAction::Trigger,
+    );
+
+    if !should_continue {
+        return Ok(false);
+    }
+
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        api::VerifyWebhookSource,
+        types::VerifyWebhookSourceRequestData,
+        types::VerifyWebhookSourceResponseData,
+    > = connector.connector.get_connector_integration();
+
+    let router_data = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        payments::CallConnectorAction::Trigger,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("There was an issue in incoming webhook source verification")?;
+
+    Ok(matches!(
+        router_data.response,
+        Ok(types::VerifyWebhookSourceResponseData {
+            verify_webhook_status: types::VerifyWebhookStatus::SourceVerified,
+        })
+    ))
+}
+
+/// Checks whether an incoming webhook event was already received, so that redeliveries of the
+/// event by the connector are not processed again. The event is locked in redis while it is being
+/// processed, and recorded in the database once it has been processed.
+#[instrument(skip_all)]
+async fn is_duplicate_incoming_webhook_event(
+    state: &AppState,
+    merchant_id: &str,
+    connector_name: &str,
+    connector_event_id: &str,
+) -> RouterResult<bool> {
+    let db = &*state.store;
+    let is_lock_acquired = db
+        .acquire_incoming_webhook_event_lock(
+            merchant_id,
+            connector_name,
+            connector_event_id,
+            state.conf.webhooks.incoming_event_lock_ttl,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to acquire the incoming webhook event lock")?;
+    if !is_lock_acquired {
+        return Ok(true);
+    }
+
+    match db
+        .find_incoming_webhook_event_by_merchant_id_connector_event_id(
+            merchant_id,
+            connector_name,
+            connector_event_id,
+        )
+        .await
+    {
+        Ok(_) => Ok(true),
+        Err(error) if error.current_context().is_db_not_found() => Ok(false),
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find the incoming webhook event")),
+    }
+}
+
+/// Records an incoming webhook event once it has been processed. If processing the event failed,
+/// the event is unlocked instead, so that the connector can redeliver it.
+async fn record_incoming_webhook_event(
+    state: &AppState,
+    merchant_id: &str,
+    connector_name: &str,
+    connector_event_id: String,
+    is_processed: bool,
+) {
+    let db = &*state.store;
+    if is_processed {
+        let event = storage::IncomingWebhookEventNew {
+            merchant_id: merchant_id.to_string(),
+            connector_name: connector_name.to_string(),
+            connector_event_id: connector_event_id.clone(),
+        };
+        if let Err(error) = db.insert_incoming_webhook_event(event).await {
+            logger::error!(?error, %connector_event_id, "Failed to record incoming webhook event");
         }
-        Ok(res) => {
-            if !res.status().is_success() {
-                // [#217]: Schedule webhook for retry.
-                Err(errors::WebhooksFlowError::NotReceivedByMerchant).into_report()?;
-            }
+    } else if let Err(error) = db
+        .release_incoming_webhook_event_lock(merchant_id, connector_name, &connector_event_id)
+        .await
+    {
+        logger::error!(?error, %connector_event_id, "Failed to release incoming webhook event lock");
+    }
+}
+
+#[instrument(skip_all)]
+async fn process_incoming_webhook_event(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    connector: &(dyn api::Connector + Sync),
+    connector_name: &str,
+    decoded_body: &[u8],
+    event_type: api::IncomingWebhookEvent,
+    source_verified: bool,
+) -> RouterResult<()> {
+    let object_ref_id = connector
+        .get_webhook_object_reference_id(decoded_body)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Could not find object reference id in incoming webhook body")?;
+
+    let event_object = connector
+        .get_webhook_resource_object(decoded_body)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Could not find resource object in incoming webhook body")?;
+
+    let webhook_details = api::IncomingWebhookDetails {
+        object_reference_id: object_ref_id,
+        resource_object: Encode::<serde_json::Value>::encode_to_vec(&event_object)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable(
+                "There was an issue when encoding the incoming webhook body to bytes",
+            )?,
+    };
+
+    let flow_type: api::WebhookFlow = event_type.clone().into();
+    match flow_type {
+        api::WebhookFlow::Payment => payments_incoming_webhook_flow(
+            state.clone(),
+            merchant_account,
+            connector.get_webhook_payment_id_type(webhook_details.object_reference_id.clone()),
+            webhook_details,
+            source_verified,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Incoming webhook flow for payments failed")?,
+        api::WebhookFlow::Refund => refunds_incoming_webhook_flow(
+            state.clone(),
+            merchant_account,
+            webhook_details,
+            connector_name,
+            event_type,
+            source_verified,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Incoming webhook flow for refunds failed")?,
+        api::WebhookFlow::Dispute => {
+            let dispute_details = connector
+                .get_dispute_details(decoded_body)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Could not find dispute details in incoming webhook body")?;
+
+            disputes_incoming_webhook_flow(
+                state.clone(),
+                merchant_account,
+                webhook_details,
+                connector_name,
+                event_type,
+                dispute_details,
+                source_verified,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Incoming webhook flow for disputes failed")?
         }
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .into_report()
+            .attach_printable("Unsupported Flow Type received in incoming webhooks")?,
     }
 
     Ok(())
 }
 
+/// Processes an incoming connector webhook. When `deduplicate_events` is set, events which were
+/// already received are acknowledged without being processed again.
 #[instrument(skip_all)]
 pub async fn webhooks_core(
     state: &AppState,
@@ -196,6 +1021,7 @@ pub async fn webhooks_core(
     merchant_account: storage::MerchantAccount,
     connector_name: &str,
     body: actix_web::web::Bytes,
+    deduplicate_events: bool,
 ) -> RouterResponse<serde_json::Value> {
     let connector = api::ConnectorData::get_connector_by_name(
         &state.conf.connectors,
@@ -205,18 +1031,55 @@ pub async fn webhooks_core(
     .change_context(errors::ApiErrorResponse::InternalServerError)
     .attach_printable("Failed construction of ConnectorData")?;
 
-    let connector = connector.connector;
-
-    let source_verified = connector
-        .verify_webhook_source(
-            &*state.store,
+    let source_verified = if connector_verifies_webhook_source(&connector) {
+        verify_webhook_source_with_connector(
+            state,
+            &connector,
+            &merchant_account,
             req.headers(),
             &body,
+        )
+        .await?
+    } else {
+        connector
+            .connector
+            .verify_webhook_source(
+                &*state.store,
+                req.headers(),
+                &body,
+                &merchant_account.merchant_id,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("There was an issue in incoming webhook source verification")?
+    };
+
+    if !source_verified
+        && utils::is_webhook_source_verification_mandatory(
+            &*state.store,
+            connector_name,
             &merchant_account.merchant_id,
         )
-        .await
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("There was an issue in incoming webhook source verification")?;
+        .await?
+    {
+        metrics::WEBHOOK_SOURCE_VERIFICATION_FAILURE_COUNT.add(
+            &metrics::CONTEXT,
+            1,
+            &[
+                opentelemetry::KeyValue::new("merchant_id", merchant_account.merchant_id.clone()),
+                opentelemetry::KeyValue::new("connector", connector_name.to_string()),
+            ],
+        );
+        logger::warn!(
+            tag = ?Tag::Audit,
+            merchant_id = %merchant_account.merchant_id,
+            connector = %connector_name,
+            "Rejected an incoming webhook whose source could not be verified"
+        );
+        return Err(errors::ApiErrorResponse::WebhookAuthenticationFailed).into_report();
+    }
+
+    let connector = connector.connector;
 
     let decoded_body = connector
         .decode_webhook_body(
@@ -243,39 +1106,57 @@ pub async fn webhooks_core(
     .await;
 
     if process_webhook_further {
-        let object_ref_id = connector
-            .get_webhook_object_reference_id(&decoded_body)
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Could not find object reference id in incoming webhook body")?;
-
-        let event_object = connector
-            .get_webhook_resource_object(&decoded_body)
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Could not find resource object in incoming webhook body")?;
-
-        let webhook_details = api::IncomingWebhookDetails {
-            object_reference_id: object_ref_id,
-            resource_object: Encode::<serde_json::Value>::encode_to_vec(&event_object)
+        let connector_event_id = if deduplicate_events {
+            connector
+                .get_webhook_event_id(&decoded_body)
                 .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable(
-                    "There was an issue when encoding the incoming webhook body to bytes",
-                )?,
+                .attach_printable("Could not find event id in incoming webhook body")?
+        } else {
+            None
         };
+        let merchant_id = merchant_account.merchant_id.clone();
 
-        let flow_type: api::WebhookFlow = event_type.into();
-        match flow_type {
-            api::WebhookFlow::Payment => payments_incoming_webhook_flow(
-                state.clone(),
+        let is_duplicate_event = match &connector_event_id {
+            Some(connector_event_id) => {
+                is_duplicate_incoming_webhook_event(
+                    state,
+                    &merchant_id,
+                    connector_name,
+                    connector_event_id,
+                )
+                .await?
+            }
+            None => false,
+        };
+
+        if is_duplicate_event {
+            logger::info!(
+                ?connector_event_id,
+                "Skipping the processing of an incoming webhook event which was already received"
+            );
+        } else {
+            let result = process_incoming_webhook_event(
+                state,
                 merchant_account,
-                webhook_details,
+                *connector,
+                connector_name,
+                &decoded_body,
+                event_type,
                 source_verified,
             )
-            .await
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Incoming webhook flow for payments failed")?,
-            _ => Err(errors::ApiErrorResponse::InternalServerError)
-                .into_report()
-                .attach_printable("Unsupported Flow Type received in incoming webhooks")?,
+            .await;
+
+            if let Some(connector_event_id) = connector_event_id {
+                record_incoming_webhook_event(
+                    state,
+                    &merchant_id,
+                    connector_name,
+                    connector_event_id,
+                    result.is_ok(),
+                )
+                .await;
+            }
+            result?;
         }
     }
 
@@ -286,3 +1167,103 @@ pub async fn webhooks_core(
 
     Ok(response)
 }
+
+/// Runs an incoming connector webhook through the webhook processing flow again, on behalf of the
+/// merchant. The webhook source is verified like for received webhooks, so the headers of the
+/// original webhook have to be sent along with its body. Replayed webhooks are processed even if
+/// the event was already received.
+#[instrument(skip_all)]
+pub async fn replay_incoming_webhook(
+    state: &AppState,
+    req: &actix_web::HttpRequest,
+    merchant_id: &str,
+    connector_name: &str,
+    body: actix_web::web::Bytes,
+) -> RouterResponse<serde_json::Value> {
+    let merchant_account = state
+        .store
+        .find_merchant_account_by_merchant_id(merchant_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        })?;
+
+    webhooks_core(state, req, merchant_account, connector_name, body, false).await
+}
+
+/// Sends a sample webhook of the given event type to the webhook endpoint of the merchant, so that
+/// merchants can check that their endpoint receives and verifies webhooks before going live. Test
+/// webhooks are neither recorded as events nor retried, and are sent even if the event type is
+/// disabled in the webhook config of the merchant.
+#[instrument(skip_all)]
+pub async fn trigger_test_webhook(
+    state: &AppState,
+    merchant_id: &str,
+    req: api::TestWebhookRequest,
+) -> RouterResponse<api::TestWebhookResponse> {
+    let db = &*state.store;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(merchant_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        })?;
+    let webhook_url_override = utils::find_merchant_webhook_config(db, merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?
+        .and_then(|webhook_config| webhook_config.webhook_url);
+
+    let outgoing_webhook = api::OutgoingWebhook {
+        merchant_id: merchant_id.to_string(),
+        event_id: generate_id(consts::ID_LENGTH, "evt_test"),
+        event_type: req.event_type,
+        content: transformers::get_sample_outgoing_webhook_content(merchant_id, req.event_type),
+        timestamp: common_utils::date_time::now(),
+    };
+
+    let started_at = std::time::Instant::now();
+    let response = send_webhook_to_merchant(
+        &state.conf.secrets,
+        &merchant_account,
+        webhook_url_override,
+        &outgoing_webhook,
+    )
+    .await;
+    let latency_ms = i64::try_from(started_at.elapsed().as_millis()).unwrap_or(i64::MAX);
+
+    let (response_code, error_message) = match response {
+        Ok(res) => {
+            let status = res.status();
+            (
+                Some(i32::from(status.as_u16())),
+                (!status.is_success())
+                    .then(|| errors::WebhooksFlowError::NotReceivedByMerchant.to_string()),
+            )
+        }
+        // Failures in reaching the merchant are the result of the test, any other failure is not
+        Err(error) if is_retryable_delivery_error(error.current_context()) => {
+            (None, Some(error.current_context().to_string()))
+        }
+        Err(error) => {
+            return Err(match error.current_context() {
+                errors::WebhooksFlowError::MerchantWebhookDetailsNotFound
+                | errors::WebhooksFlowError::MerchantWebhookURLNotConfigured => error
+                    .change_context(errors::ApiErrorResponse::PreconditionFailed {
+                        message: "Merchant does not have a webhook URL configured".to_string(),
+                    }),
+                _ => error.change_context(errors::ApiErrorResponse::InternalServerError),
+            });
+        }
+    };
+
+    Ok(services::ApplicationResponse::Json(
+        api::TestWebhookResponse {
+            event_id: outgoing_webhook.event_id,
+            event_type: outgoing_webhook.event_type,
+            delivered: response_code.is_some() && error_message.is_none(),
+            response_code,
+            latency_ms,
+            error_message,
+        },
+    ))
+}
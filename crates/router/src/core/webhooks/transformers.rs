@@ -1 +1,155 @@
+use common_utils::types::MinorUnit;
 
+use crate::types::api::{self, enums as api_enums, mandates::MandateResponse};
+
+const SAMPLE_PAYMENT_ID: &str = "pay_test_webhook";
+const SAMPLE_CONNECTOR: &str = "stripe";
+const SAMPLE_AMOUNT: i64 = 6540;
+const SAMPLE_CURRENCY: &str = "USD";
+
+/// Fabricates the content of a webhook of the given event type, as sent for test webhooks. The
+/// objects in it do not exist, and are marked as test objects where the object has a test mode.
+pub fn get_sample_outgoing_webhook_content(
+    merchant_id: &str,
+    event_type: api_enums::EventType,
+) -> api::OutgoingWebhookContent {
+    let now = common_utils::date_time::now();
+
+    match event_type {
+        api_enums::EventType::PaymentSucceeded | api_enums::EventType::PaymentFailed => {
+            let succeeded = event_type == api_enums::EventType::PaymentSucceeded;
+            api::OutgoingWebhookContent::PaymentDetails(api::PaymentsResponse {
+                payment_id: Some(SAMPLE_PAYMENT_ID.to_string()),
+                merchant_id: Some(merchant_id.to_string()),
+                status: if succeeded {
+                    api_enums::IntentStatus::Succeeded
+                } else {
+                    api_enums::IntentStatus::Failed
+                },
+                amount: MinorUnit::new(SAMPLE_AMOUNT),
+                amount_received: succeeded.then_some(MinorUnit::new(SAMPLE_AMOUNT)),
+                connector: Some(SAMPLE_CONNECTOR.to_string()),
+                created: Some(now),
+                currency: SAMPLE_CURRENCY.to_string(),
+                error_code: (!succeeded).then(|| "card_declined".to_string()),
+                error_message: (!succeeded).then(|| "Your card was declined.".to_string()),
+                test_mode: Some(true),
+                ..Default::default()
+            })
+        }
+        api_enums::EventType::RefundSucceeded | api_enums::EventType::RefundFailed => {
+            let succeeded = event_type == api_enums::EventType::RefundSucceeded;
+            api::OutgoingWebhookContent::RefundDetails(api::RefundResponse {
+                refund_id: "ref_test_webhook".to_string(),
+                payment_id: SAMPLE_PAYMENT_ID.to_string(),
+                amount: SAMPLE_AMOUNT,
+                currency: SAMPLE_CURRENCY.to_string(),
+                reason: None,
+                status: if succeeded {
+                    api::RefundStatus::Succeeded
+                } else {
+                    api::RefundStatus::Failed
+                },
+                metadata: None,
+                error_message: (!succeeded).then(|| "Refund failed at the connector".to_string()),
+                connector: SAMPLE_CONNECTOR.to_string(),
+                alternate_connector_label: None,
+                reconciliation_required: false,
+                created_at: Some(now),
+                updated_at: Some(now),
+            })
+        }
+        api_enums::EventType::DisputeOpened
+        | api_enums::EventType::DisputeChallenged
+        | api_enums::EventType::DisputeWon
+        | api_enums::EventType::DisputeLost => {
+            let dispute_status = match event_type {
+                api_enums::EventType::DisputeChallenged => {
+                    api_enums::DisputeStatus::DisputeChallenged
+                }
+                api_enums::EventType::DisputeWon => api_enums::DisputeStatus::DisputeWon,
+                api_enums::EventType::DisputeLost => api_enums::DisputeStatus::DisputeLost,
+                _ => api_enums::DisputeStatus::DisputeOpened,
+            };
+            api::OutgoingWebhookContent::DisputeDetails(api::DisputeResponse {
+                dispute_id: "dp_test_webhook".to_string(),
+                payment_id: SAMPLE_PAYMENT_ID.to_string(),
+                attempt_id: format!("{SAMPLE_PAYMENT_ID}_1"),
+                amount: SAMPLE_AMOUNT,
+                currency: SAMPLE_CURRENCY.to_string(),
+                dispute_status,
+                connector: SAMPLE_CONNECTOR.to_string(),
+                connector_status: dispute_status.to_string(),
+                connector_dispute_id: "dp_test_webhook".to_string(),
+                connector_reason: Some("fraudulent".to_string()),
+                connector_reason_code: None,
+                challenge_required_by: Some(now + time::Duration::days(7)),
+                created_at: now,
+                modified_at: now,
+                challenge_status: None,
+            })
+        }
+        api_enums::EventType::MandateActive | api_enums::EventType::MandateRevoked => {
+            api::OutgoingWebhookContent::MandateDetails(MandateResponse {
+                mandate_id: "man_test_webhook".to_string(),
+                status: if event_type == api_enums::EventType::MandateActive {
+                    api_enums::MandateStatus::Active
+                } else {
+                    api_enums::MandateStatus::Revoked
+                },
+                payment_method_id: "pm_test_webhook".to_string(),
+                payment_method: "card".to_string(),
+                ..Default::default()
+            })
+        }
+        api_enums::EventType::PayoutSucceeded
+        | api_enums::EventType::PayoutFailed
+        | api_enums::EventType::PayoutCancelled => {
+            let status = match event_type {
+                api_enums::EventType::PayoutSucceeded => api_enums::PayoutStatus::Success,
+                api_enums::EventType::PayoutFailed => api_enums::PayoutStatus::Failed,
+                _ => api_enums::PayoutStatus::Cancelled,
+            };
+            api::OutgoingWebhookContent::PayoutDetails(api::PayoutResponse {
+                payout_id: "payout_test_webhook".to_string(),
+                merchant_id: merchant_id.to_string(),
+                customer_id: None,
+                amount: SAMPLE_AMOUNT,
+                currency: SAMPLE_CURRENCY.to_string(),
+                payout_type: api_enums::PayoutType::Bank,
+                status,
+                connector: SAMPLE_CONNECTOR.to_string(),
+                connector_payout_id: Some("po_test_webhook".to_string()),
+                error_code: (status == api_enums::PayoutStatus::Failed)
+                    .then(|| "account_closed".to_string()),
+                error_message: (status == api_enums::PayoutStatus::Failed)
+                    .then(|| "The bank account has been closed.".to_string()),
+                description: None,
+                metadata: None,
+                created_at: now,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_sample_outgoing_webhook_content_matches_event_type() {
+        let content =
+            get_sample_outgoing_webhook_content("merchant", api_enums::EventType::PaymentFailed);
+        let content = serde_json::to_value(content).unwrap();
+        assert_eq!(content["type"], "payment_details");
+        assert_eq!(content["object"]["status"], "failed");
+        assert_eq!(content["object"]["test_mode"], true);
+
+        let content =
+            get_sample_outgoing_webhook_content("merchant", api_enums::EventType::DisputeWon);
+        let content = serde_json::to_value(content).unwrap();
+        assert_eq!(content["type"], "dispute_details");
+        assert_eq!(content["object"]["dispute_status"], "dispute_won");
+    }
+}
@@ -1,10 +1,66 @@
+use std::collections::HashMap;
+
+use error_stack::{IntoReport, ResultExt};
+use masking::{PeekInterface, Secret};
+
 use crate::{
+    core::errors::{self, CustomResult, RouterResult},
     db::{get_and_deserialize_key, StorageInterface},
-    types::api,
+    headers,
+    services::encryption,
+    types::{api, storage},
+    utils,
 };
 
+/// Headers which are set by the router on every outgoing webhook, and cannot be overridden by the
+/// custom headers of the merchant.
+const RESERVED_OUTGOING_WEBHOOK_HEADERS: [&str; 3] = [
+    headers::CONTENT_TYPE,
+    headers::X_WEBHOOK_SIGNATURE,
+    headers::X_WEBHOOK_TIMESTAMP,
+];
+
 fn default_webhook_config() -> api::MerchantWebhookConfig {
-    std::collections::HashSet::from([api::IncomingWebhookEvent::PaymentIntentSuccess])
+    std::collections::HashSet::from([
+        api::IncomingWebhookEvent::PaymentIntentSuccess,
+        api::IncomingWebhookEvent::PaymentIntentFailure,
+        api::IncomingWebhookEvent::RefundSuccess,
+        api::IncomingWebhookEvent::RefundFailure,
+        api::IncomingWebhookEvent::DisputeOpened,
+        api::IncomingWebhookEvent::DisputeChallenged,
+        api::IncomingWebhookEvent::DisputeWon,
+        api::IncomingWebhookEvent::DisputeLost,
+    ])
+}
+
+/// Fetches the outgoing webhook preferences of the merchant, which are absent if the merchant has
+/// never configured them.
+pub async fn find_merchant_webhook_config(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> CustomResult<Option<storage::MerchantWebhookConfig>, errors::StorageError> {
+    match db
+        .find_merchant_webhook_config_by_merchant_id(merchant_id)
+        .await
+    {
+        Ok(webhook_config) => Ok(Some(webhook_config)),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+/// Whether a webhook for the event type is to be sent to the merchant, as per their webhook config.
+pub fn is_outgoing_webhook_enabled(
+    webhook_config: Option<&storage::MerchantWebhookConfig>,
+    event_type: storage::enums::EventType,
+) -> bool {
+    webhook_config.map_or(true, |webhook_config| {
+        webhook_config.enabled
+            && webhook_config
+                .enabled_events
+                .as_ref()
+                .map_or(true, |enabled_events| enabled_events.contains(&event_type))
+    })
 }
 
 pub async fn lookup_webhook_event(
@@ -22,3 +78,167 @@ pub async fn lookup_webhook_event(
 
     webhook_config.contains(event)
 }
+
+/// Whether incoming webhooks which fail source verification have to be rejected instead of being
+/// processed as unverified. This is configured for a connector of a merchant, falling back to the
+/// configuration for all connectors of the merchant, and is disabled by default.
+pub async fn is_webhook_source_verification_mandatory(
+    db: &dyn StorageInterface,
+    connector_id: &str,
+    merchant_id: &str,
+) -> RouterResult<bool> {
+    let config_keys = [
+        format!("whsrc_verification_mandatory_{merchant_id}_{connector_id}"),
+        format!("whsrc_verification_mandatory_{merchant_id}"),
+    ];
+
+    for config_key in config_keys {
+        match db.find_config_by_key(&config_key).await {
+            Ok(config) => return Ok(config.config == "true"),
+            Err(error) if error.current_context().is_db_not_found() => continue,
+            Err(error) => {
+                return Err(error
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to fetch the webhook source verification config"))
+            }
+        }
+    }
+
+    Ok(false)
+}
+
+/// Validates the custom headers which the merchant wants to be sent with their outgoing webhooks,
+/// and encrypts them for storing them in the merchant account.
+pub fn encrypt_outgoing_webhook_custom_headers(
+    master_enc_key: &str,
+    custom_headers: &HashMap<String, Secret<String>>,
+) -> RouterResult<Vec<u8>> {
+    for (name, value) in custom_headers {
+        let is_valid_header = reqwest::header::HeaderName::from_bytes(name.as_bytes()).is_ok()
+            && reqwest::header::HeaderValue::from_str(value.peek()).is_ok()
+            && !RESERVED_OUTGOING_WEBHOOK_HEADERS
+                .iter()
+                .any(|reserved| reserved.eq_ignore_ascii_case(name));
+        utils::when(!is_valid_header, || {
+            Err(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "outgoing_webhook_custom_headers",
+            })
+            .into_report()
+            .attach_printable_lazy(|| format!("Invalid or reserved custom header {name}"))
+        })?;
+    }
+
+    let custom_headers = custom_headers
+        .iter()
+        .map(|(name, value)| (name, value.peek()))
+        .collect::<HashMap<_, _>>();
+    let custom_headers = serde_json::to_string(&custom_headers)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the custom headers of outgoing webhooks")?;
+
+    get_master_enc_key(master_enc_key)
+        .and_then(|key| encryption::encrypt(&custom_headers, &key))
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encrypt the custom headers of outgoing webhooks")
+}
+
+pub fn decrypt_outgoing_webhook_custom_headers(
+    master_enc_key: &str,
+    encrypted_custom_headers: Vec<u8>,
+) -> CustomResult<HashMap<String, Secret<String>>, errors::EncryptionError> {
+    let custom_headers = encryption::decrypt(
+        encrypted_custom_headers,
+        &get_master_enc_key(master_enc_key)?,
+    )?;
+
+    serde_json::from_str::<HashMap<String, String>>(&custom_headers)
+        .into_report()
+        .change_context(errors::EncryptionError)
+        .attach_printable("Failed to deserialize the custom headers of outgoing webhooks")
+        .map(|custom_headers| {
+            custom_headers
+                .into_iter()
+                .map(|(name, value)| (name, Secret::new(value)))
+                .collect()
+        })
+}
+
+fn get_master_enc_key(master_enc_key: &str) -> CustomResult<Vec<u8>, errors::EncryptionError> {
+    hex::decode(master_enc_key)
+        .into_report()
+        .change_context(errors::EncryptionError)
+        .attach_printable("Failed to decode the master encryption key")
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    const MASTER_ENC_KEY: &str = "73ad7bbbbc640c845a150f67d058b279849370cd2c1f3c67c4dd6c869213e13a";
+
+    #[test]
+    fn test_outgoing_webhook_custom_headers_roundtrip() {
+        let custom_headers = HashMap::from([(
+            "Authorization".to_string(),
+            Secret::new("Bearer abc123".to_string()),
+        )]);
+
+        let encrypted =
+            encrypt_outgoing_webhook_custom_headers(MASTER_ENC_KEY, &custom_headers).unwrap();
+        let decrypted = decrypt_outgoing_webhook_custom_headers(MASTER_ENC_KEY, encrypted).unwrap();
+
+        assert_eq!(
+            decrypted
+                .get("Authorization")
+                .map(|value| value.peek().as_str()),
+            Some("Bearer abc123")
+        );
+    }
+
+    #[test]
+    fn test_reserved_outgoing_webhook_custom_headers_are_rejected() {
+        let custom_headers = HashMap::from([(
+            "x-webhook-signature".to_string(),
+            Secret::new("forged".to_string()),
+        )]);
+
+        assert!(encrypt_outgoing_webhook_custom_headers(MASTER_ENC_KEY, &custom_headers).is_err());
+    }
+
+    #[test]
+    fn test_outgoing_webhook_enabled_as_per_webhook_config() {
+        let webhook_config = storage::MerchantWebhookConfig {
+            id: 1,
+            merchant_id: "merchant".to_string(),
+            enabled: true,
+            webhook_url: None,
+            enabled_events: Some(vec![storage::enums::EventType::PaymentSucceeded]),
+            created_at: common_utils::date_time::now(),
+            modified_at: common_utils::date_time::now(),
+        };
+
+        assert!(is_outgoing_webhook_enabled(
+            None,
+            storage::enums::EventType::RefundSucceeded
+        ));
+        assert!(is_outgoing_webhook_enabled(
+            Some(&webhook_config),
+            storage::enums::EventType::PaymentSucceeded
+        ));
+        assert!(!is_outgoing_webhook_enabled(
+            Some(&webhook_config),
+            storage::enums::EventType::RefundSucceeded
+        ));
+
+        let webhook_config = storage::MerchantWebhookConfig {
+            enabled: false,
+            ..webhook_config
+        };
+        assert!(!is_outgoing_webhook_enabled(
+            Some(&webhook_config),
+            storage::enums::EventType::PaymentSucceeded
+        ));
+    }
+}
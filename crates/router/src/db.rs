@@ -1,20 +1,41 @@
 pub mod address;
+pub mod api_keys;
+pub mod blocklist;
+pub mod capture;
 pub mod configs;
+pub mod connector_access_token;
 pub mod connector_response;
+pub mod customer_redaction_log;
 pub mod customers;
+pub mod dispute;
+pub mod dummy_connector;
 pub mod ephemeral_key;
 pub mod events;
+pub mod export;
+pub mod health_check;
+pub mod incoming_webhook_event;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
 pub mod merchant_connector_account;
+pub mod merchant_key_store;
+pub mod merchant_webhook_config;
+pub mod outbox_event;
 pub mod payment_attempt;
+pub mod payment_audit_log;
+pub mod payment_cost;
 pub mod payment_intent;
+pub mod payment_link;
 pub mod payment_method;
+pub mod payout;
+pub mod payout_attempt;
 pub mod process_tracker;
 pub mod queue;
+pub mod recon;
 pub mod refund;
 pub mod reverse_lookup;
+pub mod velocity;
+pub mod webhook_delivery_attempt;
 
 use std::sync::Arc;
 
@@ -37,20 +58,41 @@ pub trait StorageInterface:
     + payment_attempt::PaymentAttemptInterface
     + mandate::MandateInterface
     + address::AddressInterface
+    + api_keys::ApiKeyInterface
+    + blocklist::BlocklistInterface
+    + capture::CaptureInterface
     + configs::ConfigInterface
+    + connector_access_token::ConnectorAccessTokenInterface
+    + customer_redaction_log::CustomerRedactionLogInterface
     + customers::CustomerInterface
+    + dispute::DisputeInterface
+    + dummy_connector::DummyConnectorInterface
     + events::EventInterface
+    + export::ExportInterface
+    + health_check::HealthCheckInterface
+    + incoming_webhook_event::IncomingWebhookEventInterface
     + merchant_account::MerchantAccountInterface
     + merchant_connector_account::MerchantConnectorAccountInterface
+    + merchant_key_store::MerchantKeyStoreInterface
+    + merchant_webhook_config::MerchantWebhookConfigInterface
+    + outbox_event::OutboxEventInterface
     + locker_mock_up::LockerMockUpInterface
+    + payment_audit_log::PaymentAuditLogInterface
+    + payment_cost::PaymentCostInterface
     + payment_intent::PaymentIntentInterface
+    + payment_link::PaymentLinkInterface
     + payment_method::PaymentMethodInterface
+    + payout::PayoutInterface
+    + payout_attempt::PayoutAttemptInterface
     + process_tracker::ProcessTrackerInterface
+    + recon::ReconInterface
     + refund::RefundInterface
     + queue::QueueInterface
     + ephemeral_key::EphemeralKeyInterface
     + connector_response::ConnectorResponseInterface
     + reverse_lookup::ReverseLookupInterface
+    + velocity::VelocityInterface
+    + webhook_delivery_attempt::WebhookDeliveryAttemptInterface
     + 'static
 {
     async fn close(&mut self) {}
@@ -64,18 +106,34 @@ impl StorageInterface for Store {
             .expect("Redis connection pool cannot be closed")
             .close_connections()
             .await;
+        #[cfg(feature = "kafka_events")]
+        self.kafka_producer.flush();
     }
 }
 
 #[derive(Clone)]
 pub struct MockDb {
     merchant_accounts: Arc<Mutex<Vec<storage::MerchantAccount>>>,
+    api_keys: Arc<Mutex<Vec<storage::ApiKey>>>,
+    blocklist: Arc<Mutex<Vec<storage::Blocklist>>>,
+    exports: Arc<Mutex<Vec<storage::Export>>>,
     merchant_connector_accounts: Arc<Mutex<Vec<storage::MerchantConnectorAccount>>>,
+    merchant_webhook_configs: Arc<Mutex<Vec<storage::MerchantWebhookConfig>>>,
+    outbox_events: Arc<Mutex<Vec<storage::OutboxEvent>>>,
     payment_attempts: Arc<Mutex<Vec<storage::PaymentAttempt>>>,
+    payment_audit_logs: Arc<Mutex<Vec<storage::PaymentAuditLog>>>,
+    payment_costs: Arc<Mutex<Vec<storage::PaymentCost>>>,
     payment_intents: Arc<Mutex<Vec<storage::PaymentIntent>>>,
+    payment_links: Arc<Mutex<Vec<storage::PaymentLink>>>,
     customers: Arc<Mutex<Vec<storage::Customer>>>,
+    customer_redaction_logs: Arc<Mutex<Vec<storage::CustomerRedactionLog>>>,
     refunds: Arc<Mutex<Vec<storage::Refund>>>,
+    captures: Arc<Mutex<Vec<storage::Capture>>>,
+    payouts: Arc<Mutex<Vec<storage::Payout>>>,
+    payout_attempts: Arc<Mutex<Vec<storage::PayoutAttempt>>>,
     processes: Arc<Mutex<Vec<storage::ProcessTracker>>>,
+    recon_reports: Arc<Mutex<Vec<storage::ReconReport>>>,
+    recon_mismatches: Arc<Mutex<Vec<storage::ReconMismatch>>>,
     connector_response: Arc<Mutex<Vec<storage::ConnectorResponse>>>,
     redis: Arc<redis_interface::RedisConnectionPool>,
 }
@@ -84,12 +142,26 @@ impl MockDb {
     pub async fn new(redis: &crate::configs::settings::Settings) -> Self {
         Self {
             merchant_accounts: Default::default(),
+            api_keys: Default::default(),
+            blocklist: Default::default(),
+            exports: Default::default(),
             merchant_connector_accounts: Default::default(),
+            merchant_webhook_configs: Default::default(),
+            outbox_events: Default::default(),
             payment_attempts: Default::default(),
+            payment_audit_logs: Default::default(),
+            payment_costs: Default::default(),
             payment_intents: Default::default(),
+            payment_links: Default::default(),
             customers: Default::default(),
+            customer_redaction_logs: Default::default(),
             refunds: Default::default(),
+            captures: Default::default(),
+            payouts: Default::default(),
+            payout_attempts: Default::default(),
             processes: Default::default(),
+            recon_reports: Default::default(),
+            recon_mismatches: Default::default(),
             connector_response: Default::default(),
             redis: Arc::new(crate::connection::redis_connection(redis).await),
         }
@@ -1,4 +1,5 @@
-use error_stack::IntoReport;
+use error_stack::{IntoReport, ResultExt};
+use storage_models::address as address_rows;
 
 use super::{MockDb, Store};
 use crate::{
@@ -39,11 +40,16 @@ impl AddressInterface for Store {
         &self,
         address_id: &str,
     ) -> CustomResult<storage::Address, errors::StorageError> {
-        let conn = pg_connection(&self.master_pool).await;
-        storage::Address::find_by_address_id(&conn, address_id)
-            .await
-            .map_err(Into::into)
-            .into_report()
+        let address = {
+            let conn = pg_connection(&self.master_pool).await;
+            address_rows::Address::find_by_address_id(&conn, address_id)
+                .await
+                .map_err(Into::into)
+                .into_report()?
+        };
+        let key = self.get_merchant_data_key(&address.merchant_id).await?;
+        storage::Address::decrypt(address, &key)
+            .change_context(errors::StorageError::EncryptionError)
     }
 
     async fn update_address(
@@ -51,23 +57,44 @@ impl AddressInterface for Store {
         address_id: String,
         address: storage::AddressUpdate,
     ) -> CustomResult<storage::Address, errors::StorageError> {
+        // The merchant owning the address is needed for encrypting the update
+        let merchant_id = {
+            let conn = pg_connection(&self.master_pool).await;
+            address_rows::Address::find_by_address_id(&conn, &address_id)
+                .await
+                .map_err(Into::into)
+                .into_report()?
+                .merchant_id
+        };
+        let key = self.get_merchant_data_key(&merchant_id).await?;
+        let address = address
+            .encrypt(&key)
+            .change_context(errors::StorageError::EncryptionError)?;
         let conn = pg_connection(&self.master_pool).await;
-        storage::Address::update_by_address_id(&conn, address_id, address)
+        let address = address_rows::Address::update_by_address_id(&conn, address_id, address)
             .await
             .map_err(Into::into)
-            .into_report()
+            .into_report()?;
+        storage::Address::decrypt(address, &key)
+            .change_context(errors::StorageError::EncryptionError)
     }
 
     async fn insert_address(
         &self,
         address: storage::AddressNew,
     ) -> CustomResult<storage::Address, errors::StorageError> {
+        let key = self.get_merchant_data_key(&address.merchant_id).await?;
+        let address = address
+            .encrypt(&key)
+            .change_context(errors::StorageError::EncryptionError)?;
         let conn = pg_connection(&self.master_pool).await;
-        address
+        let address = address
             .insert(&conn)
             .await
             .map_err(Into::into)
-            .into_report()
+            .into_report()?;
+        storage::Address::decrypt(address, &key)
+            .change_context(errors::StorageError::EncryptionError)
     }
 
     async fn update_address_by_merchant_id_customer_id(
@@ -76,8 +103,12 @@ impl AddressInterface for Store {
         merchant_id: &str,
         address: storage::AddressUpdate,
     ) -> CustomResult<Vec<storage::Address>, errors::StorageError> {
+        let key = self.get_merchant_data_key(merchant_id).await?;
+        let address = address
+            .encrypt(&key)
+            .change_context(errors::StorageError::EncryptionError)?;
         let conn = pg_connection(&self.master_pool).await;
-        storage::Address::update_by_merchant_id_customer_id(
+        address_rows::Address::update_by_merchant_id_customer_id(
             &conn,
             customer_id,
             merchant_id,
@@ -85,7 +116,13 @@ impl AddressInterface for Store {
         )
         .await
         .map_err(Into::into)
-        .into_report()
+        .into_report()?
+        .into_iter()
+        .map(|address| {
+            storage::Address::decrypt(address, &key)
+                .change_context(errors::StorageError::EncryptionError)
+        })
+        .collect()
     }
 }
 
@@ -0,0 +1,226 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait ApiKeyInterface {
+    async fn insert_api_key(
+        &self,
+        api_key: storage::ApiKeyNew,
+    ) -> CustomResult<storage::ApiKey, errors::StorageError>;
+
+    async fn find_api_key_by_merchant_id_key_id(
+        &self,
+        merchant_id: &str,
+        key_id: &str,
+    ) -> CustomResult<storage::ApiKey, errors::StorageError>;
+
+    async fn find_api_key_by_hashed_api_key(
+        &self,
+        hashed_api_key: &str,
+    ) -> CustomResult<storage::ApiKey, errors::StorageError>;
+
+    async fn list_api_keys_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::ApiKey>, errors::StorageError>;
+
+    async fn update_api_key(
+        &self,
+        this: storage::ApiKey,
+        api_key: storage::ApiKeyUpdate,
+    ) -> CustomResult<storage::ApiKey, errors::StorageError>;
+
+    async fn revoke_api_key(
+        &self,
+        merchant_id: &str,
+        key_id: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ApiKeyInterface for Store {
+    async fn insert_api_key(
+        &self,
+        api_key: storage::ApiKeyNew,
+    ) -> CustomResult<storage::ApiKey, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        api_key
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_api_key_by_merchant_id_key_id(
+        &self,
+        merchant_id: &str,
+        key_id: &str,
+    ) -> CustomResult<storage::ApiKey, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::ApiKey::find_by_merchant_id_key_id(&conn, merchant_id, key_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_api_key_by_hashed_api_key(
+        &self,
+        hashed_api_key: &str,
+    ) -> CustomResult<storage::ApiKey, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::ApiKey::find_by_hashed_api_key(&conn, hashed_api_key)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn list_api_keys_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::ApiKey>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::ApiKey::find_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_api_key(
+        &self,
+        this: storage::ApiKey,
+        api_key: storage::ApiKeyUpdate,
+    ) -> CustomResult<storage::ApiKey, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        this.update(&conn, api_key)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn revoke_api_key(
+        &self,
+        merchant_id: &str,
+        key_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::ApiKey::delete_by_merchant_id_key_id(&conn, merchant_id, key_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl ApiKeyInterface for MockDb {
+    async fn insert_api_key(
+        &self,
+        api_key: storage::ApiKeyNew,
+    ) -> CustomResult<storage::ApiKey, errors::StorageError> {
+        let mut api_keys = self.api_keys.lock().await;
+
+        let api_key = storage::ApiKey {
+            key_id: api_key.key_id,
+            merchant_id: api_key.merchant_id,
+            name: api_key.name,
+            description: api_key.description,
+            hashed_api_key: api_key.hashed_api_key,
+            prefix: api_key.prefix,
+            created_at: common_utils::date_time::now(),
+            expires_at: api_key.expires_at,
+            last_used: None,
+        };
+        api_keys.push(api_key.clone());
+        Ok(api_key)
+    }
+
+    async fn find_api_key_by_merchant_id_key_id(
+        &self,
+        merchant_id: &str,
+        key_id: &str,
+    ) -> CustomResult<storage::ApiKey, errors::StorageError> {
+        let api_keys = self.api_keys.lock().await;
+
+        api_keys
+            .iter()
+            .find(|api_key| api_key.merchant_id == merchant_id && api_key.key_id == key_id)
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "No API key found with key_id = {key_id}"
+                ))
+            })
+            .into_report()
+    }
+
+    async fn find_api_key_by_hashed_api_key(
+        &self,
+        hashed_api_key: &str,
+    ) -> CustomResult<storage::ApiKey, errors::StorageError> {
+        let api_keys = self.api_keys.lock().await;
+
+        api_keys
+            .iter()
+            .find(|api_key| api_key.hashed_api_key == hashed_api_key)
+            .cloned()
+            .ok_or_else(|| errors::StorageError::ValueNotFound("No API key found".to_string()))
+            .into_report()
+    }
+
+    async fn list_api_keys_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::ApiKey>, errors::StorageError> {
+        let api_keys = self.api_keys.lock().await;
+
+        Ok(api_keys
+            .iter()
+            .filter(|api_key| api_key.merchant_id == merchant_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn update_api_key(
+        &self,
+        this: storage::ApiKey,
+        api_key: storage::ApiKeyUpdate,
+    ) -> CustomResult<storage::ApiKey, errors::StorageError> {
+        let mut api_keys = self.api_keys.lock().await;
+        let item = api_keys
+            .iter_mut()
+            .find(|item| item.key_id == this.key_id)
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "No API key found with key_id = {}",
+                    this.key_id
+                ))
+            })
+            .into_report()?;
+        *item = api_key.apply_changeset(item.clone());
+        Ok(item.clone())
+    }
+
+    async fn revoke_api_key(
+        &self,
+        merchant_id: &str,
+        key_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut api_keys = self.api_keys.lock().await;
+        let count = api_keys.len();
+        api_keys
+            .retain(|api_key| !(api_key.merchant_id == merchant_id && api_key.key_id == key_id));
+        if api_keys.len() == count {
+            Err(errors::StorageError::ValueNotFound(format!(
+                "No API key found with key_id = {key_id}"
+            )))
+            .into_report()
+        } else {
+            Ok(true)
+        }
+    }
+}
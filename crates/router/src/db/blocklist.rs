@@ -0,0 +1,159 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait BlocklistInterface {
+    async fn insert_blocklist_entry(
+        &self,
+        blocklist_entry: storage::BlocklistNew,
+    ) -> CustomResult<storage::Blocklist, errors::StorageError>;
+
+    async fn find_blocklist_entry_by_merchant_id_kind_data(
+        &self,
+        merchant_id: &str,
+        kind: enums::BlocklistKind,
+        data: &str,
+    ) -> CustomResult<storage::Blocklist, errors::StorageError>;
+
+    async fn list_blocklist_entries_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError>;
+
+    async fn delete_blocklist_entry_by_merchant_id_entry_id(
+        &self,
+        merchant_id: &str,
+        entry_id: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl BlocklistInterface for Store {
+    async fn insert_blocklist_entry(
+        &self,
+        blocklist_entry: storage::BlocklistNew,
+    ) -> CustomResult<storage::Blocklist, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        blocklist_entry
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_blocklist_entry_by_merchant_id_kind_data(
+        &self,
+        merchant_id: &str,
+        kind: enums::BlocklistKind,
+        data: &str,
+    ) -> CustomResult<storage::Blocklist, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::Blocklist::find_by_merchant_id_kind_data(&conn, merchant_id, kind, data)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn list_blocklist_entries_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
+        let conn = pg_connection(self.read_pool()).await;
+        storage::Blocklist::find_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn delete_blocklist_entry_by_merchant_id_entry_id(
+        &self,
+        merchant_id: &str,
+        entry_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::Blocklist::delete_by_merchant_id_entry_id(&conn, merchant_id, entry_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl BlocklistInterface for MockDb {
+    async fn insert_blocklist_entry(
+        &self,
+        blocklist_entry: storage::BlocklistNew,
+    ) -> CustomResult<storage::Blocklist, errors::StorageError> {
+        let mut blocklist = self.blocklist.lock().await;
+
+        let blocklist_entry = storage::Blocklist {
+            #[allow(clippy::as_conversions)]
+            id: blocklist.len() as i32,
+            entry_id: blocklist_entry.entry_id,
+            merchant_id: blocklist_entry.merchant_id,
+            kind: blocklist_entry.kind,
+            data: blocklist_entry.data,
+            expires_at: blocklist_entry.expires_at,
+            created_at: common_utils::date_time::now(),
+        };
+        blocklist.push(blocklist_entry.clone());
+        Ok(blocklist_entry)
+    }
+
+    async fn find_blocklist_entry_by_merchant_id_kind_data(
+        &self,
+        merchant_id: &str,
+        kind: enums::BlocklistKind,
+        data: &str,
+    ) -> CustomResult<storage::Blocklist, errors::StorageError> {
+        let blocklist = self.blocklist.lock().await;
+
+        blocklist
+            .iter()
+            .find(|entry| {
+                entry.merchant_id == merchant_id && entry.kind == kind && entry.data == data
+            })
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound("No blocklist entry found".to_string())
+            })
+            .into_report()
+    }
+
+    async fn list_blocklist_entries_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
+        let blocklist = self.blocklist.lock().await;
+
+        Ok(blocklist
+            .iter()
+            .filter(|entry| entry.merchant_id == merchant_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn delete_blocklist_entry_by_merchant_id_entry_id(
+        &self,
+        merchant_id: &str,
+        entry_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut blocklist = self.blocklist.lock().await;
+        let count = blocklist.len();
+        blocklist.retain(|entry| !(entry.merchant_id == merchant_id && entry.entry_id == entry_id));
+        if blocklist.len() == count {
+            Err(errors::StorageError::ValueNotFound(format!(
+                "No blocklist entry found with entry_id = {entry_id}"
+            )))
+            .into_report()
+        } else {
+            Ok(true)
+        }
+    }
+}
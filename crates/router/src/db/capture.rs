@@ -0,0 +1,134 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait CaptureInterface {
+    async fn insert_capture(
+        &self,
+        capture: storage::CaptureNew,
+    ) -> CustomResult<storage::Capture, errors::StorageError>;
+
+    async fn find_captures_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::Capture>, errors::StorageError>;
+
+    async fn update_capture(
+        &self,
+        this: storage::Capture,
+        capture: storage::CaptureUpdate,
+    ) -> CustomResult<storage::Capture, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl CaptureInterface for Store {
+    async fn insert_capture(
+        &self,
+        capture: storage::CaptureNew,
+    ) -> CustomResult<storage::Capture, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        capture
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_captures_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::Capture>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::Capture::find_by_merchant_id_payment_id(&conn, merchant_id, payment_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_capture(
+        &self,
+        this: storage::Capture,
+        capture: storage::CaptureUpdate,
+    ) -> CustomResult<storage::Capture, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        this.update(&conn, capture)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl CaptureInterface for MockDb {
+    async fn insert_capture(
+        &self,
+        capture: storage::CaptureNew,
+    ) -> CustomResult<storage::Capture, errors::StorageError> {
+        let mut captures = self.captures.lock().await;
+        let current_time = common_utils::date_time::now();
+
+        let capture = storage::Capture {
+            #[allow(clippy::as_conversions)]
+            id: captures.len() as i32,
+            capture_id: capture.capture_id,
+            payment_id: capture.payment_id,
+            merchant_id: capture.merchant_id,
+            attempt_id: capture.attempt_id,
+            status: capture.status,
+            amount: capture.amount,
+            currency: capture.currency,
+            connector: capture.connector,
+            connector_capture_id: capture.connector_capture_id,
+            error_message: capture.error_message,
+            error_code: capture.error_code,
+            created_at: current_time,
+            modified_at: current_time,
+        };
+        captures.push(capture.clone());
+        Ok(capture)
+    }
+
+    async fn find_captures_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::Capture>, errors::StorageError> {
+        let captures = self.captures.lock().await;
+
+        Ok(captures
+            .iter()
+            .filter(|capture| {
+                capture.merchant_id == merchant_id && capture.payment_id == payment_id
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn update_capture(
+        &self,
+        this: storage::Capture,
+        capture: storage::CaptureUpdate,
+    ) -> CustomResult<storage::Capture, errors::StorageError> {
+        let mut captures = self.captures.lock().await;
+        let item = captures
+            .iter_mut()
+            .find(|item| item.id == this.id)
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "No capture found with capture_id = {}",
+                    this.capture_id
+                ))
+            })
+            .into_report()?;
+        *item = capture.apply_changeset(item.clone());
+        Ok(item.clone())
+    }
+}
@@ -0,0 +1,209 @@
+use error_stack::{IntoReport, ResultExt};
+use redis_interface::{RedisConnectionPool, SetnxReply};
+
+use super::{MockDb, Store};
+use crate::{
+    core::errors::{self, CustomResult},
+    types,
+    utils::ByteSliceExt,
+};
+
+#[async_trait::async_trait]
+pub trait ConnectorAccessTokenInterface {
+    /// Returns the cached access token, with its `expires` set to the number of seconds
+    /// remaining until it expires.
+    async fn get_access_token(
+        &self,
+        merchant_id: &str,
+        connector_name: &str,
+    ) -> CustomResult<Option<types::AccessToken>, errors::StorageError>;
+
+    async fn set_access_token(
+        &self,
+        merchant_id: &str,
+        connector_name: &str,
+        access_token: types::AccessToken,
+    ) -> CustomResult<(), errors::StorageError>;
+
+    /// Acquires the lock held while refreshing the access token, returns whether the lock could
+    /// be acquired.
+    async fn acquire_access_token_refresh_lock(
+        &self,
+        merchant_id: &str,
+        connector_name: &str,
+        ttl: i64,
+    ) -> CustomResult<bool, errors::StorageError>;
+
+    async fn release_access_token_refresh_lock(
+        &self,
+        merchant_id: &str,
+        connector_name: &str,
+    ) -> CustomResult<(), errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ConnectorAccessTokenInterface for Store {
+    async fn get_access_token(
+        &self,
+        merchant_id: &str,
+        connector_name: &str,
+    ) -> CustomResult<Option<types::AccessToken>, errors::StorageError> {
+        get_access_token(&self.redis_conn, merchant_id, connector_name).await
+    }
+
+    async fn set_access_token(
+        &self,
+        merchant_id: &str,
+        connector_name: &str,
+        access_token: types::AccessToken,
+    ) -> CustomResult<(), errors::StorageError> {
+        set_access_token(&self.redis_conn, merchant_id, connector_name, access_token).await
+    }
+
+    async fn acquire_access_token_refresh_lock(
+        &self,
+        merchant_id: &str,
+        connector_name: &str,
+        ttl: i64,
+    ) -> CustomResult<bool, errors::StorageError> {
+        acquire_access_token_refresh_lock(&self.redis_conn, merchant_id, connector_name, ttl).await
+    }
+
+    async fn release_access_token_refresh_lock(
+        &self,
+        merchant_id: &str,
+        connector_name: &str,
+    ) -> CustomResult<(), errors::StorageError> {
+        release_access_token_refresh_lock(&self.redis_conn, merchant_id, connector_name).await
+    }
+}
+
+#[async_trait::async_trait]
+impl ConnectorAccessTokenInterface for MockDb {
+    async fn get_access_token(
+        &self,
+        merchant_id: &str,
+        connector_name: &str,
+    ) -> CustomResult<Option<types::AccessToken>, errors::StorageError> {
+        get_access_token(&self.redis, merchant_id, connector_name).await
+    }
+
+    async fn set_access_token(
+        &self,
+        merchant_id: &str,
+        connector_name: &str,
+        access_token: types::AccessToken,
+    ) -> CustomResult<(), errors::StorageError> {
+        set_access_token(&self.redis, merchant_id, connector_name, access_token).await
+    }
+
+    async fn acquire_access_token_refresh_lock(
+        &self,
+        merchant_id: &str,
+        connector_name: &str,
+        ttl: i64,
+    ) -> CustomResult<bool, errors::StorageError> {
+        acquire_access_token_refresh_lock(&self.redis, merchant_id, connector_name, ttl).await
+    }
+
+    async fn release_access_token_refresh_lock(
+        &self,
+        merchant_id: &str,
+        connector_name: &str,
+    ) -> CustomResult<(), errors::StorageError> {
+        release_access_token_refresh_lock(&self.redis, merchant_id, connector_name).await
+    }
+}
+
+fn get_access_token_key(merchant_id: &str, connector_name: &str) -> String {
+    format!("access_token_{merchant_id}_{connector_name}")
+}
+
+async fn get_access_token(
+    redis_conn: &RedisConnectionPool,
+    merchant_id: &str,
+    connector_name: &str,
+) -> CustomResult<Option<types::AccessToken>, errors::StorageError> {
+    let key = get_access_token_key(merchant_id, connector_name);
+    let maybe_token = redis_conn
+        .get_key::<Option<Vec<u8>>>(&key)
+        .await
+        .change_context(errors::StorageError::KVError)
+        .attach_printable("DB error when getting access token")?;
+
+    let maybe_token: Option<types::AccessToken> = maybe_token
+        .map(|token| {
+            token
+                .parse_struct("AccessToken")
+                .change_context(errors::StorageError::SerializationFailed)
+        })
+        .transpose()?;
+
+    match maybe_token {
+        Some(mut access_token) => {
+            // The stored token expires along with the key, so the time to live of the key is
+            // the time remaining until the token expires
+            access_token.expires = redis_conn
+                .get_ttl(&key)
+                .await
+                .change_context(errors::StorageError::KVError)
+                .attach_printable("DB error when getting access token expiry")?;
+            Ok(Some(access_token))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Stores the access token until it expires, after which it has to be refreshed with the
+/// connector.
+async fn set_access_token(
+    redis_conn: &RedisConnectionPool,
+    merchant_id: &str,
+    connector_name: &str,
+    access_token: types::AccessToken,
+) -> CustomResult<(), errors::StorageError> {
+    let key = get_access_token_key(merchant_id, connector_name);
+    let serialized_access_token = serde_json::to_string(&access_token)
+        .into_report()
+        .change_context(errors::StorageError::SerializationFailed)?;
+
+    redis_conn
+        .set_key_with_expiry(&key, serialized_access_token, access_token.expires)
+        .await
+        .change_context(errors::StorageError::KVError)
+        .attach_printable("DB error when setting access token")
+}
+
+fn get_access_token_refresh_lock_key(merchant_id: &str, connector_name: &str) -> String {
+    format!("access_token_refresh_lock_{merchant_id}_{connector_name}")
+}
+
+async fn acquire_access_token_refresh_lock(
+    redis_conn: &RedisConnectionPool,
+    merchant_id: &str,
+    connector_name: &str,
+    ttl: i64,
+) -> CustomResult<bool, errors::StorageError> {
+    let key = get_access_token_refresh_lock_key(merchant_id, connector_name);
+
+    redis_conn
+        .set_key_if_not_exists_with_expiry(&key, "true", ttl)
+        .await
+        .map(|reply| matches!(reply, SetnxReply::KeySet))
+        .change_context(errors::StorageError::KVError)
+        .attach_printable("DB error when acquiring access token refresh lock")
+}
+
+async fn release_access_token_refresh_lock(
+    redis_conn: &RedisConnectionPool,
+    merchant_id: &str,
+    connector_name: &str,
+) -> CustomResult<(), errors::StorageError> {
+    let key = get_access_token_refresh_lock_key(merchant_id, connector_name);
+
+    redis_conn
+        .delete_key(&key)
+        .await
+        .change_context(errors::StorageError::KVError)
+        .attach_printable("DB error when releasing access token refresh lock")
+}
@@ -0,0 +1,91 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait CustomerRedactionLogInterface {
+    async fn insert_customer_redaction_log(
+        &self,
+        redaction_log: storage::CustomerRedactionLogNew,
+    ) -> CustomResult<storage::CustomerRedactionLog, errors::StorageError>;
+
+    async fn find_customer_redaction_logs_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+    ) -> CustomResult<Vec<storage::CustomerRedactionLog>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl CustomerRedactionLogInterface for Store {
+    async fn insert_customer_redaction_log(
+        &self,
+        redaction_log: storage::CustomerRedactionLogNew,
+    ) -> CustomResult<storage::CustomerRedactionLog, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        redaction_log
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_customer_redaction_logs_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+    ) -> CustomResult<Vec<storage::CustomerRedactionLog>, errors::StorageError> {
+        let conn = pg_connection(self.read_pool()).await;
+        storage::CustomerRedactionLog::find_by_merchant_id_customer_id(
+            &conn,
+            merchant_id,
+            customer_id,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl CustomerRedactionLogInterface for MockDb {
+    async fn insert_customer_redaction_log(
+        &self,
+        redaction_log: storage::CustomerRedactionLogNew,
+    ) -> CustomResult<storage::CustomerRedactionLog, errors::StorageError> {
+        let mut customer_redaction_logs = self.customer_redaction_logs.lock().await;
+
+        let redaction_log = storage::CustomerRedactionLog {
+            #[allow(clippy::as_conversions)]
+            id: customer_redaction_logs.len() as i32,
+            customer_id: redaction_log.customer_id,
+            merchant_id: redaction_log.merchant_id,
+            payment_methods_deleted: redaction_log.payment_methods_deleted,
+            payment_intents_anonymized: redaction_log.payment_intents_anonymized,
+            created_at: common_utils::date_time::now(),
+        };
+        customer_redaction_logs.push(redaction_log.clone());
+        Ok(redaction_log)
+    }
+
+    async fn find_customer_redaction_logs_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+    ) -> CustomResult<Vec<storage::CustomerRedactionLog>, errors::StorageError> {
+        let customer_redaction_logs = self.customer_redaction_logs.lock().await;
+
+        Ok(customer_redaction_logs
+            .iter()
+            .filter(|redaction_log| {
+                redaction_log.merchant_id == merchant_id && redaction_log.customer_id == customer_id
+            })
+            .cloned()
+            .collect())
+    }
+}
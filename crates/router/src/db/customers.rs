@@ -1,4 +1,5 @@
-use error_stack::IntoReport;
+use error_stack::{IntoReport, ResultExt};
+use storage_models::customers as customer_rows;
 
 use super::{MockDb, Store};
 use crate::{
@@ -50,15 +51,19 @@ impl CustomerInterface for Store {
         customer_id: &str,
         merchant_id: &str,
     ) -> CustomResult<Option<storage::Customer>, errors::StorageError> {
+        let key = self.get_merchant_data_key(merchant_id).await?;
         let conn = pg_connection(&self.master_pool).await;
-        let maybe_customer = storage::Customer::find_optional_by_customer_id_merchant_id(
+        let maybe_customer = customer_rows::Customer::find_optional_by_customer_id_merchant_id(
             &conn,
             customer_id,
             merchant_id,
         )
         .await
         .map_err(Into::into)
-        .into_report()?;
+        .into_report()?
+        .map(|customer| storage::Customer::decrypt(customer, &key))
+        .transpose()
+        .change_context(errors::StorageError::EncryptionError)?;
         maybe_customer.map_or(Ok(None), |customer| {
             // in the future, once #![feature(is_some_and)] is stable, we can make this more concise:
             // `if customer.name.is_some_and(|ref name| name == REDACTED) ...`
@@ -75,8 +80,12 @@ impl CustomerInterface for Store {
         merchant_id: String,
         customer: storage::CustomerUpdate,
     ) -> CustomResult<storage::Customer, errors::StorageError> {
+        let key = self.get_merchant_data_key(&merchant_id).await?;
+        let customer = customer
+            .encrypt(&key)
+            .change_context(errors::StorageError::EncryptionError)?;
         let conn = pg_connection(&self.master_pool).await;
-        storage::Customer::update_by_customer_id_merchant_id(
+        let customer = customer_rows::Customer::update_by_customer_id_merchant_id(
             &conn,
             customer_id,
             merchant_id,
@@ -84,7 +93,9 @@ impl CustomerInterface for Store {
         )
         .await
         .map_err(Into::into)
-        .into_report()
+        .into_report()?;
+        storage::Customer::decrypt(customer, &key)
+            .change_context(errors::StorageError::EncryptionError)
     }
 
     async fn find_customer_by_customer_id_merchant_id(
@@ -92,12 +103,18 @@ impl CustomerInterface for Store {
         customer_id: &str,
         merchant_id: &str,
     ) -> CustomResult<storage::Customer, errors::StorageError> {
+        let key = self.get_merchant_data_key(merchant_id).await?;
         let conn = pg_connection(&self.master_pool).await;
-        let customer =
-            storage::Customer::find_by_customer_id_merchant_id(&conn, customer_id, merchant_id)
-                .await
-                .map_err(Into::into)
-                .into_report()?;
+        let customer = customer_rows::Customer::find_by_customer_id_merchant_id(
+            &conn,
+            customer_id,
+            merchant_id,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()?;
+        let customer = storage::Customer::decrypt(customer, &key)
+            .change_context(errors::StorageError::EncryptionError)?;
         match customer.name {
             Some(ref name) if name == REDACTED => Err(errors::StorageError::CustomerRedacted)?,
             _ => Ok(customer),
@@ -108,12 +125,20 @@ impl CustomerInterface for Store {
         &self,
         customer_data: storage::CustomerNew,
     ) -> CustomResult<storage::Customer, errors::StorageError> {
+        let key = self
+            .get_merchant_data_key(&customer_data.merchant_id)
+            .await?;
+        let customer_data = customer_data
+            .encrypt(&key)
+            .change_context(errors::StorageError::EncryptionError)?;
         let conn = pg_connection(&self.master_pool).await;
-        customer_data
+        let customer = customer_data
             .insert(&conn)
             .await
             .map_err(Into::into)
-            .into_report()
+            .into_report()?;
+        storage::Customer::decrypt(customer, &key)
+            .change_context(errors::StorageError::EncryptionError)
     }
 
     async fn delete_customer_by_customer_id_merchant_id(
@@ -122,7 +147,7 @@ impl CustomerInterface for Store {
         merchant_id: &str,
     ) -> CustomResult<bool, errors::StorageError> {
         let conn = pg_connection(&self.master_pool).await;
-        storage::Customer::delete_by_customer_id_merchant_id(&conn, customer_id, merchant_id)
+        customer_rows::Customer::delete_by_customer_id_merchant_id(&conn, customer_id, merchant_id)
             .await
             .map_err(Into::into)
             .into_report()
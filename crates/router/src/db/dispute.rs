@@ -0,0 +1,203 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait DisputeInterface {
+    async fn insert_dispute(
+        &self,
+        dispute: storage::DisputeNew,
+    ) -> CustomResult<storage::Dispute, errors::StorageError>;
+
+    async fn find_by_merchant_id_connector_connector_dispute_id(
+        &self,
+        merchant_id: &str,
+        connector: &str,
+        connector_dispute_id: &str,
+    ) -> CustomResult<storage::Dispute, errors::StorageError>;
+
+    async fn find_dispute_by_merchant_id_dispute_id(
+        &self,
+        merchant_id: &str,
+        dispute_id: &str,
+    ) -> CustomResult<storage::Dispute, errors::StorageError>;
+
+    #[cfg(feature = "olap")]
+    async fn filter_dispute_by_constraints(
+        &self,
+        merchant_id: &str,
+        dispute_constraints: &api_models::disputes::DisputeListConstraints,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError>;
+
+    async fn update_dispute(
+        &self,
+        this: storage::Dispute,
+        dispute: storage::DisputeUpdate,
+    ) -> CustomResult<storage::Dispute, errors::StorageError>;
+
+    /// Finds at most `limit` disputes created by the merchant in the time range `[start, end)`.
+    async fn find_disputes_by_merchant_id_created_between(
+        &self,
+        merchant_id: &str,
+        start: time::PrimitiveDateTime,
+        end: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl DisputeInterface for Store {
+    async fn insert_dispute(
+        &self,
+        dispute: storage::DisputeNew,
+    ) -> CustomResult<storage::Dispute, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        dispute
+            .insert_with_dispute_opened_event(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_by_merchant_id_connector_connector_dispute_id(
+        &self,
+        merchant_id: &str,
+        connector: &str,
+        connector_dispute_id: &str,
+    ) -> CustomResult<storage::Dispute, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::Dispute::find_by_merchant_id_connector_connector_dispute_id(
+            &conn,
+            merchant_id,
+            connector,
+            connector_dispute_id,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn find_dispute_by_merchant_id_dispute_id(
+        &self,
+        merchant_id: &str,
+        dispute_id: &str,
+    ) -> CustomResult<storage::Dispute, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::Dispute::find_by_merchant_id_dispute_id(&conn, merchant_id, dispute_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    #[cfg(feature = "olap")]
+    async fn filter_dispute_by_constraints(
+        &self,
+        merchant_id: &str,
+        dispute_constraints: &api_models::disputes::DisputeListConstraints,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
+        let conn = pg_connection(self.read_pool()).await;
+        <storage::Dispute as storage::DisputeDbExt>::filter_by_constraints(
+            &conn,
+            merchant_id,
+            dispute_constraints,
+            limit,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn update_dispute(
+        &self,
+        this: storage::Dispute,
+        dispute: storage::DisputeUpdate,
+    ) -> CustomResult<storage::Dispute, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        this.update(&conn, dispute)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_disputes_by_merchant_id_created_between(
+        &self,
+        merchant_id: &str,
+        start: time::PrimitiveDateTime,
+        end: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
+        let conn = pg_connection(self.read_pool()).await;
+        storage::Dispute::find_by_merchant_id_created_between(&conn, merchant_id, start, end, limit)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl DisputeInterface for MockDb {
+    async fn insert_dispute(
+        &self,
+        _dispute: storage::DisputeNew,
+    ) -> CustomResult<storage::Dispute, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_by_merchant_id_connector_connector_dispute_id(
+        &self,
+        _merchant_id: &str,
+        _connector: &str,
+        _connector_dispute_id: &str,
+    ) -> CustomResult<storage::Dispute, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_dispute_by_merchant_id_dispute_id(
+        &self,
+        _merchant_id: &str,
+        _dispute_id: &str,
+    ) -> CustomResult<storage::Dispute, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[cfg(feature = "olap")]
+    async fn filter_dispute_by_constraints(
+        &self,
+        _merchant_id: &str,
+        _dispute_constraints: &api_models::disputes::DisputeListConstraints,
+        _limit: i64,
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_dispute(
+        &self,
+        _this: storage::Dispute,
+        _dispute: storage::DisputeUpdate,
+    ) -> CustomResult<storage::Dispute, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_disputes_by_merchant_id_created_between(
+        &self,
+        _merchant_id: &str,
+        _start: time::PrimitiveDateTime,
+        _end: time::PrimitiveDateTime,
+        _limit: i64,
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -0,0 +1,165 @@
+use error_stack::{IntoReport, ResultExt};
+use redis_interface::RedisConnectionPool;
+use storage_models::errors::DatabaseError;
+
+use super::{MockDb, Store};
+use crate::{
+    core::errors::{self, CustomResult},
+    types::storage::dummy_connector::{DummyConnectorPayment, DummyConnectorRefund},
+    utils::ByteSliceExt,
+};
+
+#[async_trait::async_trait]
+pub trait DummyConnectorInterface {
+    /// Stores the payment, replacing the payment with the same ID if there is one.
+    async fn store_dummy_connector_payment(
+        &self,
+        payment: &DummyConnectorPayment,
+    ) -> CustomResult<(), errors::StorageError>;
+
+    async fn find_dummy_connector_payment(
+        &self,
+        payment_id: &str,
+    ) -> CustomResult<DummyConnectorPayment, errors::StorageError>;
+
+    async fn store_dummy_connector_refund(
+        &self,
+        refund: &DummyConnectorRefund,
+    ) -> CustomResult<(), errors::StorageError>;
+
+    async fn find_dummy_connector_refund(
+        &self,
+        refund_id: &str,
+    ) -> CustomResult<DummyConnectorRefund, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl DummyConnectorInterface for Store {
+    async fn store_dummy_connector_payment(
+        &self,
+        payment: &DummyConnectorPayment,
+    ) -> CustomResult<(), errors::StorageError> {
+        store_value(
+            &self.redis_conn,
+            &get_payment_key(&payment.payment_id),
+            payment,
+        )
+        .await
+    }
+
+    async fn find_dummy_connector_payment(
+        &self,
+        payment_id: &str,
+    ) -> CustomResult<DummyConnectorPayment, errors::StorageError> {
+        find_value(
+            &self.redis_conn,
+            &get_payment_key(payment_id),
+            "DummyConnectorPayment",
+        )
+        .await
+    }
+
+    async fn store_dummy_connector_refund(
+        &self,
+        refund: &DummyConnectorRefund,
+    ) -> CustomResult<(), errors::StorageError> {
+        store_value(&self.redis_conn, &get_refund_key(&refund.refund_id), refund).await
+    }
+
+    async fn find_dummy_connector_refund(
+        &self,
+        refund_id: &str,
+    ) -> CustomResult<DummyConnectorRefund, errors::StorageError> {
+        find_value(
+            &self.redis_conn,
+            &get_refund_key(refund_id),
+            "DummyConnectorRefund",
+        )
+        .await
+    }
+}
+
+#[async_trait::async_trait]
+impl DummyConnectorInterface for MockDb {
+    async fn store_dummy_connector_payment(
+        &self,
+        payment: &DummyConnectorPayment,
+    ) -> CustomResult<(), errors::StorageError> {
+        store_value(&self.redis, &get_payment_key(&payment.payment_id), payment).await
+    }
+
+    async fn find_dummy_connector_payment(
+        &self,
+        payment_id: &str,
+    ) -> CustomResult<DummyConnectorPayment, errors::StorageError> {
+        find_value(
+            &self.redis,
+            &get_payment_key(payment_id),
+            "DummyConnectorPayment",
+        )
+        .await
+    }
+
+    async fn store_dummy_connector_refund(
+        &self,
+        refund: &DummyConnectorRefund,
+    ) -> CustomResult<(), errors::StorageError> {
+        store_value(&self.redis, &get_refund_key(&refund.refund_id), refund).await
+    }
+
+    async fn find_dummy_connector_refund(
+        &self,
+        refund_id: &str,
+    ) -> CustomResult<DummyConnectorRefund, errors::StorageError> {
+        find_value(
+            &self.redis,
+            &get_refund_key(refund_id),
+            "DummyConnectorRefund",
+        )
+        .await
+    }
+}
+
+fn get_payment_key(payment_id: &str) -> String {
+    format!("dummy_connector_payment_{payment_id}")
+}
+
+fn get_refund_key(refund_id: &str) -> String {
+    format!("dummy_connector_refund_{refund_id}")
+}
+
+async fn store_value<T>(
+    redis_conn: &RedisConnectionPool,
+    key: &str,
+    value: &T,
+) -> CustomResult<(), errors::StorageError>
+where
+    T: serde::Serialize + std::fmt::Debug + Sync,
+{
+    redis_conn
+        .serialize_and_set_key(key, value)
+        .await
+        .change_context(errors::StorageError::KVError)
+        .attach_printable("DB error when storing dummy connector data")
+}
+
+async fn find_value<T>(
+    redis_conn: &RedisConnectionPool,
+    key: &str,
+    type_name: &str,
+) -> CustomResult<T, errors::StorageError>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let value = redis_conn
+        .get_key::<Option<Vec<u8>>>(key)
+        .await
+        .change_context(errors::StorageError::KVError)
+        .attach_printable("DB error when finding dummy connector data")?
+        .ok_or_else(|| errors::StorageError::DatabaseError(DatabaseError::NotFound.into()))
+        .into_report()?;
+
+    value
+        .parse_struct(type_name)
+        .change_context(errors::StorageError::SerializationFailed)
+}
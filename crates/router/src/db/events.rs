@@ -13,6 +13,26 @@ pub trait EventInterface {
         &self,
         event: storage::EventNew,
     ) -> CustomResult<storage::Event, errors::StorageError>;
+
+    async fn update_event(
+        &self,
+        event_id: String,
+        event: storage::EventUpdate,
+    ) -> CustomResult<storage::Event, errors::StorageError>;
+
+    async fn find_event_by_merchant_id_event_id(
+        &self,
+        merchant_id: &str,
+        event_id: &str,
+    ) -> CustomResult<storage::Event, errors::StorageError>;
+
+    #[cfg(feature = "olap")]
+    async fn filter_events_by_constraints(
+        &self,
+        merchant_id: &str,
+        event_constraints: &api_models::events::EventListConstraints,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Event>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -24,6 +44,48 @@ impl EventInterface for Store {
         let conn = pg_connection(&self.master_pool).await;
         event.insert(&conn).await.map_err(Into::into).into_report()
     }
+
+    async fn update_event(
+        &self,
+        event_id: String,
+        event: storage::EventUpdate,
+    ) -> CustomResult<storage::Event, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::Event::update_by_event_id(&conn, &event_id, event)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+    async fn find_event_by_merchant_id_event_id(
+        &self,
+        merchant_id: &str,
+        event_id: &str,
+    ) -> CustomResult<storage::Event, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::Event::find_by_merchant_id_event_id(&conn, merchant_id, event_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    #[cfg(feature = "olap")]
+    async fn filter_events_by_constraints(
+        &self,
+        merchant_id: &str,
+        event_constraints: &api_models::events::EventListConstraints,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Event>, errors::StorageError> {
+        let conn = pg_connection(self.read_pool()).await;
+        <storage::Event as storage::EventDbExt>::filter_by_constraints(
+            &conn,
+            merchant_id,
+            event_constraints,
+            limit,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
 }
 
 #[async_trait::async_trait]
@@ -35,4 +97,33 @@ impl EventInterface for MockDb {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn update_event(
+        &self,
+        _event_id: String,
+        _event: storage::EventUpdate,
+    ) -> CustomResult<storage::Event, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_event_by_merchant_id_event_id(
+        &self,
+        _merchant_id: &str,
+        _event_id: &str,
+    ) -> CustomResult<storage::Event, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[cfg(feature = "olap")]
+    async fn filter_events_by_constraints(
+        &self,
+        _merchant_id: &str,
+        _event_constraints: &api_models::events::EventListConstraints,
+        _limit: i64,
+    ) -> CustomResult<Vec<storage::Event>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
 }
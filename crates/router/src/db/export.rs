@@ -0,0 +1,134 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait ExportInterface {
+    async fn insert_export(
+        &self,
+        export: storage::ExportNew,
+    ) -> CustomResult<storage::Export, errors::StorageError>;
+
+    async fn find_export_by_merchant_id_export_id(
+        &self,
+        merchant_id: &str,
+        export_id: &str,
+    ) -> CustomResult<storage::Export, errors::StorageError>;
+
+    async fn update_export(
+        &self,
+        this: storage::Export,
+        export: storage::ExportUpdate,
+    ) -> CustomResult<storage::Export, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ExportInterface for Store {
+    async fn insert_export(
+        &self,
+        export: storage::ExportNew,
+    ) -> CustomResult<storage::Export, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        export.insert(&conn).await.map_err(Into::into).into_report()
+    }
+
+    async fn find_export_by_merchant_id_export_id(
+        &self,
+        merchant_id: &str,
+        export_id: &str,
+    ) -> CustomResult<storage::Export, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::Export::find_by_merchant_id_export_id(&conn, merchant_id, export_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_export(
+        &self,
+        this: storage::Export,
+        export: storage::ExportUpdate,
+    ) -> CustomResult<storage::Export, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        this.update(&conn, export)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl ExportInterface for MockDb {
+    async fn insert_export(
+        &self,
+        export: storage::ExportNew,
+    ) -> CustomResult<storage::Export, errors::StorageError> {
+        let mut exports = self.exports.lock().await;
+        let current_time = common_utils::date_time::now();
+
+        let export = storage::Export {
+            #[allow(clippy::as_conversions)]
+            id: exports.len() as i32,
+            export_id: export.export_id,
+            merchant_id: export.merchant_id,
+            object: export.object,
+            format: export.format,
+            start_time: export.start_time,
+            end_time: export.end_time,
+            status: export.status,
+            row_count: None,
+            file_content: None,
+            error_message: None,
+            created_at: current_time,
+            modified_at: current_time,
+            batch_id: export.batch_id,
+            anonymized: export.anonymized,
+        };
+        exports.push(export.clone());
+        Ok(export)
+    }
+
+    async fn find_export_by_merchant_id_export_id(
+        &self,
+        merchant_id: &str,
+        export_id: &str,
+    ) -> CustomResult<storage::Export, errors::StorageError> {
+        let exports = self.exports.lock().await;
+
+        exports
+            .iter()
+            .find(|export| export.merchant_id == merchant_id && export.export_id == export_id)
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "No export found with export_id = {export_id}"
+                ))
+            })
+            .into_report()
+    }
+
+    async fn update_export(
+        &self,
+        this: storage::Export,
+        export: storage::ExportUpdate,
+    ) -> CustomResult<storage::Export, errors::StorageError> {
+        let mut exports = self.exports.lock().await;
+        let item = exports
+            .iter_mut()
+            .find(|item| item.id == this.id)
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "No export found with export_id = {}",
+                    this.export_id
+                ))
+            })
+            .into_report()?;
+        *item = export.apply_changeset(item.clone());
+        Ok(item.clone())
+    }
+}
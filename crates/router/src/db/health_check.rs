@@ -0,0 +1,146 @@
+use error_stack::IntoReport;
+use redis_interface::errors::RedisError;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::{self, PgPool},
+    core::errors::{self, CustomResult},
+};
+
+/// The state of a connection pool.
+#[derive(Clone, Copy, Debug)]
+pub struct ConnectionPoolState {
+    pub max_connections: u32,
+    pub open_connections: u32,
+    /// The number of open connections which are not in use, absent for Redis, whose connections
+    /// are shared by concurrent commands
+    pub idle_connections: Option<u32>,
+}
+
+impl ConnectionPoolState {
+    fn from_pg_pool(pool: &PgPool, max_connections: u32) -> Self {
+        let state = pool.state();
+        Self {
+            max_connections,
+            open_connections: state.connections,
+            idle_connections: Some(state.idle_connections),
+        }
+    }
+
+    fn from_redis_pool(redis_conn: &redis_interface::RedisConnectionPool) -> Self {
+        let (pool_size, connected) = redis_conn.connection_state();
+        Self {
+            max_connections: u32::try_from(pool_size).unwrap_or(u32::MAX),
+            open_connections: u32::try_from(connected).unwrap_or(u32::MAX),
+            idle_connections: None,
+        }
+    }
+
+    /// Whether a request needing a connection from the pool would have to wait for one.
+    ///
+    /// A Postgres pool is exhausted while all of its connections are in use. A Redis pool is
+    /// exhausted while none of its connections is connected, as commands are queued until one
+    /// reconnects.
+    pub fn is_exhausted(&self) -> bool {
+        match self.idle_connections {
+            Some(idle_connections) => {
+                idle_connections == 0 && self.open_connections >= self.max_connections
+            }
+            None => self.open_connections == 0,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ConnectionPoolStates {
+    /// Absent for stores which are not backed by Postgres
+    pub master_database: Option<ConnectionPoolState>,
+    /// Absent for stores which are not backed by Postgres
+    pub replica_database: Option<ConnectionPoolState>,
+    pub redis: ConnectionPoolState,
+}
+
+impl ConnectionPoolStates {
+    pub fn is_any_exhausted(&self) -> bool {
+        [
+            self.master_database,
+            self.replica_database,
+            Some(self.redis),
+        ]
+        .into_iter()
+        .flatten()
+        .any(|state| state.is_exhausted())
+    }
+}
+
+#[async_trait::async_trait]
+pub trait HealthCheckInterface {
+    /// Runs `SELECT 1` on the primary database.
+    async fn health_check_master_db(&self) -> CustomResult<(), errors::StorageError>;
+
+    /// Runs `SELECT 1` on the replica database.
+    async fn health_check_replica_db(&self) -> CustomResult<(), errors::StorageError>;
+
+    /// Sends a `PING` to Redis.
+    async fn health_check_redis(&self) -> CustomResult<(), RedisError>;
+
+    fn connection_pool_states(&self) -> ConnectionPoolStates;
+}
+
+#[async_trait::async_trait]
+impl HealthCheckInterface for Store {
+    async fn health_check_master_db(&self) -> CustomResult<(), errors::StorageError> {
+        connection::select_one(&self.master_pool)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn health_check_replica_db(&self) -> CustomResult<(), errors::StorageError> {
+        connection::select_one(&self.replica_pool)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn health_check_redis(&self) -> CustomResult<(), RedisError> {
+        self.redis_conn.ping().await
+    }
+
+    fn connection_pool_states(&self) -> ConnectionPoolStates {
+        ConnectionPoolStates {
+            master_database: Some(ConnectionPoolState::from_pg_pool(
+                &self.master_pool,
+                self.config.master_pool_size,
+            )),
+            replica_database: Some(ConnectionPoolState::from_pg_pool(
+                &self.replica_pool,
+                self.config.replica_pool_size,
+            )),
+            redis: ConnectionPoolState::from_redis_pool(&self.redis_conn),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl HealthCheckInterface for MockDb {
+    async fn health_check_master_db(&self) -> CustomResult<(), errors::StorageError> {
+        Ok(())
+    }
+
+    async fn health_check_replica_db(&self) -> CustomResult<(), errors::StorageError> {
+        Ok(())
+    }
+
+    async fn health_check_redis(&self) -> CustomResult<(), RedisError> {
+        self.redis.ping().await
+    }
+
+    fn connection_pool_states(&self) -> ConnectionPoolStates {
+        ConnectionPoolStates {
+            master_database: None,
+            replica_database: None,
+            redis: ConnectionPoolState::from_redis_pool(&self.redis),
+        }
+    }
+}
@@ -0,0 +1,196 @@
+use error_stack::{IntoReport, ResultExt};
+use redis_interface::{RedisConnectionPool, SetnxReply};
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait IncomingWebhookEventInterface {
+    async fn insert_incoming_webhook_event(
+        &self,
+        event: storage::IncomingWebhookEventNew,
+    ) -> CustomResult<storage::IncomingWebhookEvent, errors::StorageError>;
+
+    async fn find_incoming_webhook_event_by_merchant_id_connector_event_id(
+        &self,
+        merchant_id: &str,
+        connector_name: &str,
+        connector_event_id: &str,
+    ) -> CustomResult<storage::IncomingWebhookEvent, errors::StorageError>;
+
+    /// Acquires the lock held while processing an incoming webhook event, returns whether the
+    /// lock could be acquired. The lock is not acquired if the event is being processed or was
+    /// processed recently.
+    async fn acquire_incoming_webhook_event_lock(
+        &self,
+        merchant_id: &str,
+        connector_name: &str,
+        connector_event_id: &str,
+        ttl: i64,
+    ) -> CustomResult<bool, errors::StorageError>;
+
+    async fn release_incoming_webhook_event_lock(
+        &self,
+        merchant_id: &str,
+        connector_name: &str,
+        connector_event_id: &str,
+    ) -> CustomResult<(), errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl IncomingWebhookEventInterface for Store {
+    async fn insert_incoming_webhook_event(
+        &self,
+        event: storage::IncomingWebhookEventNew,
+    ) -> CustomResult<storage::IncomingWebhookEvent, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        event.insert(&conn).await.map_err(Into::into).into_report()
+    }
+
+    async fn find_incoming_webhook_event_by_merchant_id_connector_event_id(
+        &self,
+        merchant_id: &str,
+        connector_name: &str,
+        connector_event_id: &str,
+    ) -> CustomResult<storage::IncomingWebhookEvent, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::IncomingWebhookEvent::find_by_merchant_id_connector_name_connector_event_id(
+            &conn,
+            merchant_id,
+            connector_name,
+            connector_event_id,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn acquire_incoming_webhook_event_lock(
+        &self,
+        merchant_id: &str,
+        connector_name: &str,
+        connector_event_id: &str,
+        ttl: i64,
+    ) -> CustomResult<bool, errors::StorageError> {
+        acquire_incoming_webhook_event_lock(
+            &self.redis_conn,
+            merchant_id,
+            connector_name,
+            connector_event_id,
+            ttl,
+        )
+        .await
+    }
+
+    async fn release_incoming_webhook_event_lock(
+        &self,
+        merchant_id: &str,
+        connector_name: &str,
+        connector_event_id: &str,
+    ) -> CustomResult<(), errors::StorageError> {
+        release_incoming_webhook_event_lock(
+            &self.redis_conn,
+            merchant_id,
+            connector_name,
+            connector_event_id,
+        )
+        .await
+    }
+}
+
+#[async_trait::async_trait]
+impl IncomingWebhookEventInterface for MockDb {
+    async fn insert_incoming_webhook_event(
+        &self,
+        _event: storage::IncomingWebhookEventNew,
+    ) -> CustomResult<storage::IncomingWebhookEvent, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_incoming_webhook_event_by_merchant_id_connector_event_id(
+        &self,
+        _merchant_id: &str,
+        _connector_name: &str,
+        _connector_event_id: &str,
+    ) -> CustomResult<storage::IncomingWebhookEvent, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn acquire_incoming_webhook_event_lock(
+        &self,
+        merchant_id: &str,
+        connector_name: &str,
+        connector_event_id: &str,
+        ttl: i64,
+    ) -> CustomResult<bool, errors::StorageError> {
+        acquire_incoming_webhook_event_lock(
+            &self.redis,
+            merchant_id,
+            connector_name,
+            connector_event_id,
+            ttl,
+        )
+        .await
+    }
+
+    async fn release_incoming_webhook_event_lock(
+        &self,
+        merchant_id: &str,
+        connector_name: &str,
+        connector_event_id: &str,
+    ) -> CustomResult<(), errors::StorageError> {
+        release_incoming_webhook_event_lock(
+            &self.redis,
+            merchant_id,
+            connector_name,
+            connector_event_id,
+        )
+        .await
+    }
+}
+
+fn get_incoming_webhook_event_lock_key(
+    merchant_id: &str,
+    connector_name: &str,
+    connector_event_id: &str,
+) -> String {
+    format!("whevent_{merchant_id}_{connector_name}_{connector_event_id}")
+}
+
+async fn acquire_incoming_webhook_event_lock(
+    redis_conn: &RedisConnectionPool,
+    merchant_id: &str,
+    connector_name: &str,
+    connector_event_id: &str,
+    ttl: i64,
+) -> CustomResult<bool, errors::StorageError> {
+    let key = get_incoming_webhook_event_lock_key(merchant_id, connector_name, connector_event_id);
+
+    redis_conn
+        .set_key_if_not_exists_with_expiry(&key, "true", ttl)
+        .await
+        .map(|reply| matches!(reply, SetnxReply::KeySet))
+        .change_context(errors::StorageError::KVError)
+        .attach_printable("DB error when acquiring incoming webhook event lock")
+}
+
+async fn release_incoming_webhook_event_lock(
+    redis_conn: &RedisConnectionPool,
+    merchant_id: &str,
+    connector_name: &str,
+    connector_event_id: &str,
+) -> CustomResult<(), errors::StorageError> {
+    let key = get_incoming_webhook_event_lock_key(merchant_id, connector_name, connector_event_id);
+
+    redis_conn
+        .delete_key(&key)
+        .await
+        .change_context(errors::StorageError::KVError)
+        .attach_printable("DB error when releasing incoming webhook event lock")
+}
@@ -1,8 +1,8 @@
-use error_stack::{IntoReport, Report};
-use masking::PeekInterface;
+use error_stack::IntoReport;
 
 use super::{MockDb, Store};
 use crate::{
+    cache::CacheKind,
     connection::pg_connection,
     core::errors::{self, CustomResult},
     types::storage::{self, enums},
@@ -26,11 +26,6 @@ pub trait MerchantAccountInterface {
         merchant_account: storage::MerchantAccountUpdate,
     ) -> CustomResult<storage::MerchantAccount, errors::StorageError>;
 
-    async fn find_merchant_account_by_api_key(
-        &self,
-        api_key: &str,
-    ) -> CustomResult<storage::MerchantAccount, errors::StorageError>;
-
     async fn find_merchant_account_by_publishable_key(
         &self,
         publishable_key: &str,
@@ -40,6 +35,11 @@ pub trait MerchantAccountInterface {
         &self,
         merchant_id: &str,
     ) -> CustomResult<bool, errors::StorageError>;
+
+    async fn list_merchant_accounts_by_parent_merchant_id(
+        &self,
+        parent_merchant_id: &str,
+    ) -> CustomResult<Vec<storage::MerchantAccount>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -60,11 +60,16 @@ impl MerchantAccountInterface for Store {
         &self,
         merchant_id: &str,
     ) -> CustomResult<storage::MerchantAccount, errors::StorageError> {
-        let conn = pg_connection(&self.master_pool).await;
-        storage::MerchantAccount::find_by_merchant_id(&conn, merchant_id)
+        self.cache
+            .merchant_accounts
+            .get_or_populate(merchant_id, || async {
+                let conn = pg_connection(&self.master_pool).await;
+                storage::MerchantAccount::find_by_merchant_id(&conn, merchant_id)
+                    .await
+                    .map_err(Into::into)
+                    .into_report()
+            })
             .await
-            .map_err(Into::into)
-            .into_report()
     }
 
     async fn update_merchant(
@@ -73,21 +78,15 @@ impl MerchantAccountInterface for Store {
         merchant_account: storage::MerchantAccountUpdate,
     ) -> CustomResult<storage::MerchantAccount, errors::StorageError> {
         let conn = pg_connection(&self.master_pool).await;
-        this.update(&conn, merchant_account)
-            .await
-            .map_err(Into::into)
-            .into_report()
-    }
-
-    async fn find_merchant_account_by_api_key(
-        &self,
-        api_key: &str,
-    ) -> CustomResult<storage::MerchantAccount, errors::StorageError> {
-        let conn = pg_connection(&self.master_pool).await;
-        storage::MerchantAccount::find_by_api_key(&conn, api_key)
+        let merchant_id = this.merchant_id.clone();
+        let updated = this
+            .update(&conn, merchant_account)
             .await
             .map_err(Into::into)
-            .into_report()
+            .into_report();
+        self.invalidate_cache(CacheKind::MerchantAccount, &merchant_id)
+            .await;
+        updated
     }
 
     async fn find_merchant_account_by_publishable_key(
@@ -106,7 +105,21 @@ impl MerchantAccountInterface for Store {
         merchant_id: &str,
     ) -> CustomResult<bool, errors::StorageError> {
         let conn = pg_connection(&self.master_pool).await;
-        storage::MerchantAccount::delete_by_merchant_id(&conn, merchant_id)
+        let deleted = storage::MerchantAccount::delete_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(Into::into)
+            .into_report();
+        self.invalidate_cache(CacheKind::MerchantAccount, merchant_id)
+            .await;
+        deleted
+    }
+
+    async fn list_merchant_accounts_by_parent_merchant_id(
+        &self,
+        parent_merchant_id: &str,
+    ) -> CustomResult<Vec<storage::MerchantAccount>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::MerchantAccount::find_by_parent_merchant_id(&conn, parent_merchant_id)
             .await
             .map_err(Into::into)
             .into_report()
@@ -144,6 +157,9 @@ impl MerchantAccountInterface for MockDb {
             storage_scheme: enums::MerchantStorageScheme::PostgresOnly,
             locker_id: merchant_account.locker_id,
             metadata: merchant_account.metadata,
+            webhook_signing_secret: merchant_account.webhook_signing_secret,
+            outgoing_webhook_custom_headers: merchant_account.outgoing_webhook_custom_headers,
+            surcharge_config: merchant_account.surcharge_config,
         };
         accounts.push(account.clone());
         Ok(account)
@@ -175,21 +191,6 @@ impl MerchantAccountInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
-    #[allow(clippy::panic)]
-    async fn find_merchant_account_by_api_key(
-        &self,
-        api_key: &str,
-    ) -> CustomResult<storage::MerchantAccount, errors::StorageError> {
-        let accounts = self.merchant_accounts.lock().await;
-
-        accounts
-            .iter()
-            .find(|account| account.api_key.as_ref().map(|s| s.peek()) == Some(&api_key.into()))
-            .cloned()
-            .ok_or_else(|| Report::from(storage_models::errors::DatabaseError::NotFound).into())
-            .into_report()
-    }
-
     async fn find_merchant_account_by_publishable_key(
         &self,
         _publishable_key: &str,
@@ -205,4 +206,17 @@ impl MerchantAccountInterface for MockDb {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn list_merchant_accounts_by_parent_merchant_id(
+        &self,
+        parent_merchant_id: &str,
+    ) -> CustomResult<Vec<storage::MerchantAccount>, errors::StorageError> {
+        let accounts = self.merchant_accounts.lock().await;
+
+        Ok(accounts
+            .iter()
+            .filter(|account| account.parent_merchant_id.as_deref() == Some(parent_merchant_id))
+            .cloned()
+            .collect())
+    }
 }
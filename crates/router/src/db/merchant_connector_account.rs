@@ -1,8 +1,10 @@
-use error_stack::IntoReport;
+use error_stack::{report, IntoReport};
 use masking::ExposeInterface;
+use storage_models::errors::DatabaseError;
 
 use super::{MockDb, Store};
 use crate::{
+    cache::CacheKind,
     connection::pg_connection,
     core::errors::{self, CustomResult},
     types::storage,
@@ -10,10 +12,10 @@ use crate::{
 
 #[async_trait::async_trait]
 pub trait MerchantConnectorAccountInterface {
-    async fn find_merchant_connector_account_by_merchant_id_connector(
+    async fn find_merchant_connector_account_by_merchant_id_connector_label(
         &self,
         merchant_id: &str,
-        connector: &str,
+        connector_label: &str,
     ) -> CustomResult<storage::MerchantConnectorAccount, errors::StorageError>;
 
     async fn insert_merchant_connector_account(
@@ -47,20 +49,17 @@ pub trait MerchantConnectorAccountInterface {
 
 #[async_trait::async_trait]
 impl MerchantConnectorAccountInterface for Store {
-    async fn find_merchant_connector_account_by_merchant_id_connector(
+    async fn find_merchant_connector_account_by_merchant_id_connector_label(
         &self,
         merchant_id: &str,
-        connector: &str,
+        connector_label: &str,
     ) -> CustomResult<storage::MerchantConnectorAccount, errors::StorageError> {
-        let conn = pg_connection(&self.master_pool).await;
-        storage::MerchantConnectorAccount::find_by_merchant_id_connector(
-            &conn,
-            merchant_id,
-            connector,
-        )
-        .await
-        .map_err(Into::into)
-        .into_report()
+        self.find_merchant_connector_account_by_merchant_id_list(merchant_id)
+            .await?
+            .into_iter()
+            .find(|mca| mca.connector_label == connector_label)
+            .ok_or_else(|| errors::StorageError::from(report!(DatabaseError::NotFound)))
+            .into_report()
     }
 
     async fn find_by_merchant_connector_account_merchant_id_merchant_connector_id(
@@ -68,15 +67,12 @@ impl MerchantConnectorAccountInterface for Store {
         merchant_id: &str,
         merchant_connector_id: &i32,
     ) -> CustomResult<storage::MerchantConnectorAccount, errors::StorageError> {
-        let conn = pg_connection(&self.master_pool).await;
-        storage::MerchantConnectorAccount::find_by_merchant_id_merchant_connector_id(
-            &conn,
-            merchant_id,
-            merchant_connector_id,
-        )
-        .await
-        .map_err(Into::into)
-        .into_report()
+        self.find_merchant_connector_account_by_merchant_id_list(merchant_id)
+            .await?
+            .into_iter()
+            .find(|mca| mca.merchant_connector_id == *merchant_connector_id)
+            .ok_or_else(|| errors::StorageError::from(report!(DatabaseError::NotFound)))
+            .into_report()
     }
 
     async fn insert_merchant_connector_account(
@@ -84,18 +80,26 @@ impl MerchantConnectorAccountInterface for Store {
         t: storage::MerchantConnectorAccountNew,
     ) -> CustomResult<storage::MerchantConnectorAccount, errors::StorageError> {
         let conn = pg_connection(&self.master_pool).await;
-        t.insert(&conn).await.map_err(Into::into).into_report()
+        let inserted = t.insert(&conn).await.map_err(Into::into).into_report()?;
+        self.invalidate_cache(CacheKind::MerchantConnectorAccount, &inserted.merchant_id)
+            .await;
+        Ok(inserted)
     }
 
     async fn find_merchant_connector_account_by_merchant_id_list(
         &self,
         merchant_id: &str,
     ) -> CustomResult<Vec<storage::MerchantConnectorAccount>, errors::StorageError> {
-        let conn = pg_connection(&self.master_pool).await;
-        storage::MerchantConnectorAccount::find_by_merchant_id(&conn, merchant_id)
+        self.cache
+            .merchant_connector_accounts
+            .get_or_populate(merchant_id, || async {
+                let conn = pg_connection(&self.master_pool).await;
+                storage::MerchantConnectorAccount::find_by_merchant_id(&conn, merchant_id)
+                    .await
+                    .map_err(Into::into)
+                    .into_report()
+            })
             .await
-            .map_err(Into::into)
-            .into_report()
     }
 
     async fn update_merchant_connector_account(
@@ -104,10 +108,15 @@ impl MerchantConnectorAccountInterface for Store {
         merchant_connector_account: storage::MerchantConnectorAccountUpdate,
     ) -> CustomResult<storage::MerchantConnectorAccount, errors::StorageError> {
         let conn = pg_connection(&self.master_pool).await;
-        this.update(&conn, merchant_connector_account)
+        let merchant_id = this.merchant_id.clone();
+        let updated = this
+            .update(&conn, merchant_connector_account)
             .await
             .map_err(Into::into)
-            .into_report()
+            .into_report();
+        self.invalidate_cache(CacheKind::MerchantConnectorAccount, &merchant_id)
+            .await;
+        updated
     }
 
     async fn delete_merchant_connector_account_by_merchant_id_merchant_connector_id(
@@ -116,14 +125,18 @@ impl MerchantConnectorAccountInterface for Store {
         merchant_connector_id: &i32,
     ) -> CustomResult<bool, errors::StorageError> {
         let conn = pg_connection(&self.master_pool).await;
-        storage::MerchantConnectorAccount::delete_by_merchant_id_merchant_connector_id(
-            &conn,
-            merchant_id,
-            merchant_connector_id,
-        )
-        .await
-        .map_err(Into::into)
-        .into_report()
+        let deleted =
+            storage::MerchantConnectorAccount::delete_by_merchant_id_merchant_connector_id(
+                &conn,
+                merchant_id,
+                merchant_connector_id,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report();
+        self.invalidate_cache(CacheKind::MerchantConnectorAccount, merchant_id)
+            .await;
+        deleted
     }
 }
 
@@ -131,16 +144,16 @@ impl MerchantConnectorAccountInterface for Store {
 impl MerchantConnectorAccountInterface for MockDb {
     // safety: only used for testing
     #[allow(clippy::unwrap_used)]
-    async fn find_merchant_connector_account_by_merchant_id_connector(
+    async fn find_merchant_connector_account_by_merchant_id_connector_label(
         &self,
         merchant_id: &str,
-        connector: &str,
+        connector_label: &str,
     ) -> CustomResult<storage::MerchantConnectorAccount, errors::StorageError> {
         let accounts = self.merchant_connector_accounts.lock().await;
         let account = accounts
             .iter()
             .find(|account| {
-                account.merchant_id == merchant_id && account.connector_name == connector
+                account.merchant_id == merchant_id && account.connector_label == connector_label
             })
             .cloned()
             .unwrap();
@@ -173,6 +186,9 @@ impl MerchantConnectorAccountInterface for MockDb {
             merchant_connector_id: t.merchant_connector_id.unwrap_or_default(),
             payment_methods_enabled: t.payment_methods_enabled,
             metadata: t.metadata,
+            business_country: t.business_country,
+            business_label: t.business_label,
+            connector_label: t.connector_label.unwrap_or_default(),
             connector_type: t
                 .connector_type
                 .unwrap_or(crate::types::storage::enums::ConnectorType::FinOperations),
@@ -0,0 +1,118 @@
+use error_stack::{IntoReport, ResultExt};
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    services::encryption,
+    types::storage,
+};
+
+/// Length of the data keys of the merchants, in bytes.
+const MERCHANT_DATA_KEY_LENGTH: usize = 32;
+
+#[async_trait::async_trait]
+pub trait MerchantKeyStoreInterface {
+    async fn insert_merchant_key_store(
+        &self,
+        merchant_key_store: storage::MerchantKeyStoreNew,
+    ) -> CustomResult<storage::MerchantKeyStore, errors::StorageError>;
+
+    async fn find_merchant_key_store_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<storage::MerchantKeyStore, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl MerchantKeyStoreInterface for Store {
+    async fn insert_merchant_key_store(
+        &self,
+        merchant_key_store: storage::MerchantKeyStoreNew,
+    ) -> CustomResult<storage::MerchantKeyStore, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        merchant_key_store
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_merchant_key_store_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<storage::MerchantKeyStore, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::MerchantKeyStore::find_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+impl Store {
+    /// The key with which the personal data of the merchant is encrypted, which is generated the
+    /// first time it is needed.
+    pub(crate) async fn get_merchant_data_key(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<u8>, errors::StorageError> {
+        let merchant_key_store = match self
+            .find_merchant_key_store_by_merchant_id(merchant_id)
+            .await
+        {
+            Ok(merchant_key_store) => merchant_key_store,
+            Err(error) if error.current_context().is_db_not_found() => {
+                let data_key: [u8; MERCHANT_DATA_KEY_LENGTH] = rand::random();
+                let merchant_key_store_new = storage::MerchantKeyStoreNew {
+                    merchant_id: merchant_id.to_string(),
+                    key: encryption::encrypt(&hex::encode(data_key), &self.config.master_enc_key)
+                        .change_context(errors::StorageError::EncryptionError)
+                        .attach_printable("Failed to encrypt the merchant data key")?,
+                };
+                match self.insert_merchant_key_store(merchant_key_store_new).await {
+                    Ok(merchant_key_store) => merchant_key_store,
+                    // The key has been created concurrently by another request, it is read from
+                    // the primary as it may not have been replicated yet
+                    Err(error) if error.current_context().is_db_unique_violation() => {
+                        let conn = pg_connection(&self.master_pool).await;
+                        storage::MerchantKeyStore::find_by_merchant_id(&conn, merchant_id)
+                            .await
+                            .map_err(Into::into)
+                            .into_report()?
+                    }
+                    Err(error) => return Err(error),
+                }
+            }
+            Err(error) => return Err(error),
+        };
+
+        encryption::decrypt(merchant_key_store.key, &self.config.master_enc_key)
+            .and_then(|data_key| {
+                hex::decode(data_key)
+                    .into_report()
+                    .change_context(errors::EncryptionError)
+            })
+            .change_context(errors::StorageError::EncryptionError)
+            .attach_printable("Failed to decrypt the merchant data key")
+    }
+}
+
+#[async_trait::async_trait]
+impl MerchantKeyStoreInterface for MockDb {
+    async fn insert_merchant_key_store(
+        &self,
+        _merchant_key_store: storage::MerchantKeyStoreNew,
+    ) -> CustomResult<storage::MerchantKeyStore, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_merchant_key_store_by_merchant_id(
+        &self,
+        _merchant_id: &str,
+    ) -> CustomResult<storage::MerchantKeyStore, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
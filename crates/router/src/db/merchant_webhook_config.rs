@@ -0,0 +1,119 @@
+use error_stack::IntoReport;
+use storage_models::errors::DatabaseError;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait MerchantWebhookConfigInterface {
+    async fn insert_merchant_webhook_config(
+        &self,
+        merchant_webhook_config: storage::MerchantWebhookConfigNew,
+    ) -> CustomResult<storage::MerchantWebhookConfig, errors::StorageError>;
+
+    async fn find_merchant_webhook_config_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<storage::MerchantWebhookConfig, errors::StorageError>;
+
+    async fn update_merchant_webhook_config(
+        &self,
+        this: storage::MerchantWebhookConfig,
+        merchant_webhook_config: storage::MerchantWebhookConfigUpdate,
+    ) -> CustomResult<storage::MerchantWebhookConfig, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl MerchantWebhookConfigInterface for Store {
+    async fn insert_merchant_webhook_config(
+        &self,
+        merchant_webhook_config: storage::MerchantWebhookConfigNew,
+    ) -> CustomResult<storage::MerchantWebhookConfig, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        merchant_webhook_config
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_merchant_webhook_config_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<storage::MerchantWebhookConfig, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::MerchantWebhookConfig::find_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_merchant_webhook_config(
+        &self,
+        this: storage::MerchantWebhookConfig,
+        merchant_webhook_config: storage::MerchantWebhookConfigUpdate,
+    ) -> CustomResult<storage::MerchantWebhookConfig, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        this.update(&conn, merchant_webhook_config)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl MerchantWebhookConfigInterface for MockDb {
+    async fn insert_merchant_webhook_config(
+        &self,
+        merchant_webhook_config: storage::MerchantWebhookConfigNew,
+    ) -> CustomResult<storage::MerchantWebhookConfig, errors::StorageError> {
+        let mut merchant_webhook_configs = self.merchant_webhook_configs.lock().await;
+        let current_time = common_utils::date_time::now();
+
+        let merchant_webhook_config = storage::MerchantWebhookConfig {
+            #[allow(clippy::as_conversions)]
+            id: merchant_webhook_configs.len() as i32,
+            merchant_id: merchant_webhook_config.merchant_id,
+            enabled: merchant_webhook_config.enabled,
+            webhook_url: merchant_webhook_config.webhook_url,
+            enabled_events: merchant_webhook_config.enabled_events,
+            created_at: current_time,
+            modified_at: current_time,
+        };
+        merchant_webhook_configs.push(merchant_webhook_config.clone());
+        Ok(merchant_webhook_config)
+    }
+
+    async fn find_merchant_webhook_config_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<storage::MerchantWebhookConfig, errors::StorageError> {
+        let merchant_webhook_configs = self.merchant_webhook_configs.lock().await;
+
+        merchant_webhook_configs
+            .iter()
+            .find(|merchant_webhook_config| merchant_webhook_config.merchant_id == merchant_id)
+            .cloned()
+            .ok_or_else(|| errors::StorageError::DatabaseError(DatabaseError::NotFound.into()))
+            .into_report()
+    }
+
+    async fn update_merchant_webhook_config(
+        &self,
+        this: storage::MerchantWebhookConfig,
+        merchant_webhook_config: storage::MerchantWebhookConfigUpdate,
+    ) -> CustomResult<storage::MerchantWebhookConfig, errors::StorageError> {
+        let mut merchant_webhook_configs = self.merchant_webhook_configs.lock().await;
+        let item = merchant_webhook_configs
+            .iter_mut()
+            .find(|item| item.id == this.id)
+            .ok_or_else(|| errors::StorageError::DatabaseError(DatabaseError::NotFound.into()))
+            .into_report()?;
+        *item = merchant_webhook_config.apply_changeset(item.clone());
+        Ok(item.clone())
+    }
+}
@@ -0,0 +1,90 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait OutboxEventInterface {
+    async fn find_unpublished_outbox_events(
+        &self,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::OutboxEvent>, errors::StorageError>;
+
+    async fn mark_outbox_events_published(
+        &self,
+        ids: Vec<i32>,
+    ) -> CustomResult<usize, errors::StorageError>;
+}
+
+/// Whether the update moves the payment intent into the succeeded state, in which case the update
+/// has to be recorded along with the `payment.succeeded` event.
+pub(super) fn is_payment_succeeded(
+    this: &storage::PaymentIntent,
+    payment_intent: &storage::PaymentIntentUpdate,
+) -> bool {
+    this.status != enums::IntentStatus::Succeeded
+        && payment_intent.clone().apply_changeset(this.clone()).status
+            == enums::IntentStatus::Succeeded
+}
+
+#[async_trait::async_trait]
+impl OutboxEventInterface for Store {
+    async fn find_unpublished_outbox_events(
+        &self,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::OutboxEvent>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::OutboxEvent::find_unpublished(&conn, limit)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn mark_outbox_events_published(
+        &self,
+        ids: Vec<i32>,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::OutboxEvent::mark_published(&conn, ids)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl OutboxEventInterface for MockDb {
+    async fn find_unpublished_outbox_events(
+        &self,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::OutboxEvent>, errors::StorageError> {
+        let outbox_events = self.outbox_events.lock().await;
+        Ok(outbox_events
+            .iter()
+            .filter(|event| event.published_at.is_none())
+            .take(usize::try_from(limit).unwrap_or_default())
+            .cloned()
+            .collect())
+    }
+
+    async fn mark_outbox_events_published(
+        &self,
+        ids: Vec<i32>,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let mut outbox_events = self.outbox_events.lock().await;
+        let published_at = common_utils::date_time::now();
+        let mut count = 0;
+        for event in outbox_events
+            .iter_mut()
+            .filter(|event| ids.contains(&event.id))
+        {
+            event.published_at = Some(published_at);
+            count += 1;
+        }
+        Ok(count)
+    }
+}
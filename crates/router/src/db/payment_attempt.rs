@@ -54,6 +54,37 @@ pub trait PaymentAttemptInterface {
         attempt_id: &str,
         storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<types::PaymentAttempt, errors::StorageError>;
+
+    async fn find_payment_attempts_by_statuses_modified_before(
+        &self,
+        statuses: Vec<enums::AttemptStatus>,
+        modified_before: time::PrimitiveDateTime,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<types::PaymentAttempt>, errors::StorageError>;
+
+    /// Finds the attempts of the payments of the merchant, for exporting them along with the
+    /// payments.
+    async fn find_payment_attempts_by_merchant_id_payment_ids(
+        &self,
+        merchant_id: &str,
+        payment_ids: Vec<String>,
+    ) -> CustomResult<Vec<types::PaymentAttempt>, errors::StorageError>;
+
+    #[cfg(feature = "olap")]
+    async fn aggregate_payment_attempts_by_merchant_id_created_between(
+        &self,
+        merchant_id: &str,
+        start: time::PrimitiveDateTime,
+        end: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<types::PaymentAttemptAggregate>, errors::StorageError>;
+
+    #[cfg(feature = "olap")]
+    async fn count_payment_attempt_declines_by_merchant_id_created_between(
+        &self,
+        merchant_id: &str,
+        start: time::PrimitiveDateTime,
+        end: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<types::PaymentAttemptDeclineReason>, errors::StorageError>;
 }
 
 #[cfg(not(feature = "kv_store"))]
@@ -76,11 +107,15 @@ mod storage {
             _storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<PaymentAttempt, errors::StorageError> {
             let conn = pg_connection(&self.master_pool).await;
-            payment_attempt
+            let created_attempt = payment_attempt
                 .insert(&conn)
                 .await
                 .map_err(Into::into)
-                .into_report()
+                .into_report()?;
+
+            #[cfg(feature = "kafka_events")]
+            self.kafka_producer.log_event(&created_attempt);
+            Ok(created_attempt)
         }
 
         async fn update_payment_attempt(
@@ -90,10 +125,15 @@ mod storage {
             _storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<PaymentAttempt, errors::StorageError> {
             let conn = pg_connection(&self.master_pool).await;
-            this.update(&conn, payment_attempt)
+            let updated_attempt = this
+                .update(&conn, payment_attempt)
                 .await
                 .map_err(Into::into)
-                .into_report()
+                .into_report()?;
+
+            #[cfg(feature = "kafka_events")]
+            self.kafka_producer.log_event(&updated_attempt);
+            Ok(updated_attempt)
         }
 
         async fn find_payment_attempt_by_payment_id_merchant_id(
@@ -175,11 +215,134 @@ mod storage {
                 .map_err(Into::into)
                 .into_report()
         }
+
+        async fn find_payment_attempts_by_statuses_modified_before(
+            &self,
+            statuses: Vec<enums::AttemptStatus>,
+            modified_before: time::PrimitiveDateTime,
+            limit: Option<i64>,
+        ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError> {
+            let conn = pg_connection(self.read_pool()).await;
+            PaymentAttempt::find_by_statuses_modified_before(
+                &conn,
+                statuses,
+                modified_before,
+                limit,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        }
+
+        async fn find_payment_attempts_by_merchant_id_payment_ids(
+            &self,
+            merchant_id: &str,
+            payment_ids: Vec<String>,
+        ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError> {
+            let conn = pg_connection(self.read_pool()).await;
+            PaymentAttempt::find_by_merchant_id_payment_ids(&conn, merchant_id, payment_ids)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        #[cfg(feature = "olap")]
+        async fn aggregate_payment_attempts_by_merchant_id_created_between(
+            &self,
+            merchant_id: &str,
+            start: time::PrimitiveDateTime,
+            end: time::PrimitiveDateTime,
+        ) -> CustomResult<Vec<PaymentAttemptAggregate>, errors::StorageError> {
+            let conn = pg_connection(self.read_pool()).await;
+            PaymentAttempt::aggregate_by_merchant_id_created_between(&conn, merchant_id, start, end)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        #[cfg(feature = "olap")]
+        async fn count_payment_attempt_declines_by_merchant_id_created_between(
+            &self,
+            merchant_id: &str,
+            start: time::PrimitiveDateTime,
+            end: time::PrimitiveDateTime,
+        ) -> CustomResult<Vec<PaymentAttemptDeclineReason>, errors::StorageError> {
+            let conn = pg_connection(self.read_pool()).await;
+            PaymentAttempt::count_declines_by_merchant_id_created_between(
+                &conn,
+                merchant_id,
+                start,
+                end,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        }
     }
 }
 
 #[async_trait::async_trait]
 impl PaymentAttemptInterface for MockDb {
+    #[cfg(feature = "olap")]
+    async fn aggregate_payment_attempts_by_merchant_id_created_between(
+        &self,
+        _merchant_id: &str,
+        _start: time::PrimitiveDateTime,
+        _end: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<types::PaymentAttemptAggregate>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[cfg(feature = "olap")]
+    async fn count_payment_attempt_declines_by_merchant_id_created_between(
+        &self,
+        _merchant_id: &str,
+        _start: time::PrimitiveDateTime,
+        _end: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<types::PaymentAttemptDeclineReason>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payment_attempts_by_statuses_modified_before(
+        &self,
+        statuses: Vec<enums::AttemptStatus>,
+        modified_before: time::PrimitiveDateTime,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<types::PaymentAttempt>, errors::StorageError> {
+        let payment_attempts = self.payment_attempts.lock().await;
+        let matching_attempts = payment_attempts
+            .iter()
+            .filter(|payment_attempt| {
+                statuses.contains(&payment_attempt.status)
+                    && payment_attempt.modified_at < modified_before
+            })
+            .cloned();
+
+        Ok(match limit.and_then(|limit| usize::try_from(limit).ok()) {
+            Some(limit) => matching_attempts.take(limit).collect(),
+            None => matching_attempts.collect(),
+        })
+    }
+
+    async fn find_payment_attempts_by_merchant_id_payment_ids(
+        &self,
+        merchant_id: &str,
+        payment_ids: Vec<String>,
+    ) -> CustomResult<Vec<types::PaymentAttempt>, errors::StorageError> {
+        let payment_attempts = self.payment_attempts.lock().await;
+
+        Ok(payment_attempts
+            .iter()
+            .filter(|payment_attempt| {
+                payment_attempt.merchant_id == merchant_id
+                    && payment_ids.contains(&payment_attempt.payment_id)
+            })
+            .cloned()
+            .collect())
+    }
+
     async fn find_payment_attempt_by_merchant_id_attempt_id(
         &self,
         _merchant_id: &str,
@@ -244,6 +407,16 @@ impl PaymentAttemptInterface for MockDb {
             payment_token: None,
             error_code: payment_attempt.error_code,
             connector_metadata: None,
+            payment_checks: None,
+            connector_api_log: None,
+            three_ds_authentication_data: None,
+            frm_data: None,
+            settlement_currency: payment_attempt.settlement_currency,
+            settlement_amount: payment_attempt.settlement_amount,
+            fx_rate: payment_attempt.fx_rate,
+            test_mode: None,
+            card_bin: payment_attempt.card_bin,
+            card_last4: payment_attempt.card_last4,
         };
         payment_attempts.push(payment_attempt.clone());
         Ok(payment_attempt)
@@ -323,8 +496,8 @@ mod storage {
         core::errors::{self, utils::RedisErrorExt, CustomResult},
         db::reverse_lookup::ReverseLookupInterface,
         services::Store,
-        types::storage::{enums, kv, payment_attempt::*, ReverseLookupNew},
-        utils::storage_partitioning::KvStorePartition,
+        types::storage::{enums, kv, payment_attempt::*, PaymentIntent, ReverseLookupNew},
+        utils::storage_partitioning::{self, KvStorePartition},
     };
 
     #[async_trait::async_trait]
@@ -334,7 +507,7 @@ mod storage {
             payment_attempt: PaymentAttemptNew,
             storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<PaymentAttempt, errors::StorageError> {
-            match storage_scheme {
+            let created_attempt = match storage_scheme {
                 enums::MerchantStorageScheme::PostgresOnly => {
                     let conn = pg_connection(&self.master_pool).await;
                     payment_attempt
@@ -345,9 +518,10 @@ mod storage {
                 }
 
                 enums::MerchantStorageScheme::RedisKv => {
-                    let key = format!(
-                        "{}_{}",
-                        payment_attempt.merchant_id, payment_attempt.payment_id
+                    let key = storage_partitioning::payment_hash_key(
+                        &payment_attempt.merchant_id,
+                        &payment_attempt.payment_id,
+                        self.config.hash_tagged_keys,
                     );
 
                     let created_attempt = PaymentAttempt {
@@ -383,6 +557,18 @@ mod storage {
                         payment_token: payment_attempt.payment_token.clone(),
                         error_code: payment_attempt.error_code.clone(),
                         connector_metadata: payment_attempt.connector_metadata.clone(),
+                        payment_checks: payment_attempt.payment_checks.clone(),
+                        three_ds_authentication_data: payment_attempt
+                            .three_ds_authentication_data
+                            .clone(),
+                        frm_data: payment_attempt.frm_data.clone(),
+                        connector_api_log: payment_attempt.connector_api_log.clone(),
+                        settlement_currency: payment_attempt.settlement_currency,
+                        settlement_amount: payment_attempt.settlement_amount,
+                        fx_rate: payment_attempt.fx_rate.clone(),
+                        test_mode: None,
+                        card_bin: payment_attempt.card_bin.clone(),
+                        card_last4: payment_attempt.card_last4.clone(),
                     };
 
                     let field = format!("pa_{}", created_attempt.attempt_id);
@@ -417,14 +603,16 @@ mod storage {
                                 op: kv::DBOperation::Insert {
                                     insertable: kv::Insertable::PaymentAttempt(payment_attempt),
                                 },
+                                outbox_event: None,
                             };
-                            let stream_name = self.get_drainer_stream_name(&PaymentAttempt::shard_key(
-                                crate::utils::storage_partitioning::PartitionKey::MerchantIdPaymentId {
-                                    merchant_id: &created_attempt.merchant_id,
-                                    payment_id: &created_attempt.payment_id,
-                                },
-                                self.config.drainer_num_partitions,
-                            ));
+                            let stream_name =
+                                self.get_drainer_stream_name(&PaymentAttempt::shard_key(
+                                    storage_partitioning::PartitionKey::MerchantIdPaymentId {
+                                        merchant_id: &created_attempt.merchant_id,
+                                        payment_id: &created_attempt.payment_id,
+                                    },
+                                    self.config.drainer_num_partitions,
+                                ));
                             self.redis_conn
                                 .stream_append_entry(
                                     &stream_name,
@@ -440,7 +628,11 @@ mod storage {
                         Err(error) => Err(error.change_context(errors::StorageError::KVError)),
                     }
                 }
-            }
+            }?;
+
+            #[cfg(feature = "kafka_events")]
+            self.kafka_producer.log_event(&created_attempt);
+            Ok(created_attempt)
         }
 
         async fn update_payment_attempt(
@@ -449,7 +641,7 @@ mod storage {
             payment_attempt: PaymentAttemptUpdate,
             storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<PaymentAttempt, errors::StorageError> {
-            match storage_scheme {
+            let updated_attempt = match storage_scheme {
                 enums::MerchantStorageScheme::PostgresOnly => {
                     let conn = pg_connection(&self.master_pool).await;
                     this.update(&conn, payment_attempt)
@@ -459,7 +651,11 @@ mod storage {
                 }
 
                 enums::MerchantStorageScheme::RedisKv => {
-                    let key = format!("{}_{}", this.merchant_id, this.payment_id);
+                    let key = storage_partitioning::payment_hash_key(
+                        &this.merchant_id,
+                        &this.payment_id,
+                        self.config.hash_tagged_keys,
+                    );
 
                     let updated_attempt = payment_attempt.clone().apply_changeset(this.clone());
                     // Check for database presence as well Maybe use a read replica here ?
@@ -504,10 +700,11 @@ mod storage {
                                 },
                             ),
                         },
+                        outbox_event: None,
                     };
 
                     let stream_name = self.get_drainer_stream_name(&PaymentAttempt::shard_key(
-                        crate::utils::storage_partitioning::PartitionKey::MerchantIdPaymentId {
+                        storage_partitioning::PartitionKey::MerchantIdPaymentId {
                             merchant_id: &updated_attempt.merchant_id,
                             payment_id: &updated_attempt.payment_id,
                         },
@@ -525,7 +722,11 @@ mod storage {
                         .change_context(errors::StorageError::KVError)?;
                     Ok(updated_attempt)
                 }
-            }
+            }?;
+
+            #[cfg(feature = "kafka_events")]
+            self.kafka_producer.log_event(&updated_attempt);
+            Ok(updated_attempt)
         }
 
         async fn find_payment_attempt_by_payment_id_merchant_id(
@@ -544,21 +745,49 @@ mod storage {
                 }
 
                 enums::MerchantStorageScheme::RedisKv => {
-                    let key = format!("{}_{}", merchant_id, payment_id);
-                    let lookup = self
-                        .get_lookup_by_lookup_id(&key)
-                        .await
-                        .map_err(Into::<errors::StorageError>::into)
-                        .into_report()?;
-                    self.redis_conn
-                        .get_hash_field_and_deserialize::<PaymentAttempt>(
+                    let key = storage_partitioning::payment_hash_key(
+                        merchant_id,
+                        payment_id,
+                        self.config.hash_tagged_keys,
+                    );
+                    // The attempts are stored in the hash of their payment intent, which records
+                    // the attempt the payment is currently processed with
+                    let payment_intent = self
+                        .redis_conn
+                        .get_hash_field_and_deserialize::<PaymentIntent>(
                             &key,
-                            &lookup.sk_id,
-                            "PaymentAttempt",
+                            "pi",
+                            "PaymentIntent",
                         )
                         .await
-                        .map_err(|error| error.to_redis_failed_response(&key))
-                    // Check for database presence as well Maybe use a read replica here ?
+                        .map_err(|error| error.to_redis_failed_response(&key))?;
+
+                    match payment_intent.active_attempt_id {
+                        Some(attempt_id) => self
+                            .redis_conn
+                            .get_hash_field_and_deserialize::<PaymentAttempt>(
+                                &key,
+                                &format!("pa_{attempt_id}"),
+                                "PaymentAttempt",
+                            )
+                            .await
+                            .map_err(|error| error.to_redis_failed_response(&key)),
+                        // Payment intents created before the active attempt was recorded on them
+                        // were never retried, so their only attempt is the latest one
+                        None => self
+                            .redis_conn
+                            .hscan_and_deserialize::<PaymentAttempt>(&key, "pa_*", None)
+                            .await
+                            .map_err(|error| error.to_redis_failed_response(&key))?
+                            .into_iter()
+                            .max_by_key(|payment_attempt| payment_attempt.created_at)
+                            .ok_or_else(|| {
+                                errors::StorageError::ValueNotFound(format!(
+                                    "Payment Attempt does not exist for {key}"
+                                ))
+                            })
+                            .into_report(),
+                    }
                 }
             }
         }
@@ -617,7 +846,7 @@ mod storage {
             )
             .await
             .and_then(|attempt| match attempt.status {
-                enums::AttemptStatus::Charged => Ok(attempt),
+                enums::AttemptStatus::Charged | enums::AttemptStatus::PartialCharged => Ok(attempt),
                 _ => Err(errors::StorageError::ValueNotFound(format!(
                     "Successful payment attempt does not exist for {}_{}",
                     payment_id, merchant_id
@@ -700,5 +929,74 @@ mod storage {
                 }
             }
         }
+
+        async fn find_payment_attempts_by_statuses_modified_before(
+            &self,
+            statuses: Vec<enums::AttemptStatus>,
+            modified_before: time::PrimitiveDateTime,
+            limit: Option<i64>,
+        ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError> {
+            // Attempts across merchants are looked up in the database, which the drainer keeps
+            // up to date for merchants using the KV store.
+            let conn = pg_connection(self.read_pool()).await;
+            PaymentAttempt::find_by_statuses_modified_before(
+                &conn,
+                statuses,
+                modified_before,
+                limit,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        }
+
+        async fn find_payment_attempts_by_merchant_id_payment_ids(
+            &self,
+            merchant_id: &str,
+            payment_ids: Vec<String>,
+        ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError> {
+            // Exports are generated from the database, which the drainer keeps up to date for
+            // merchants using the KV store.
+            let conn = pg_connection(self.read_pool()).await;
+            PaymentAttempt::find_by_merchant_id_payment_ids(&conn, merchant_id, payment_ids)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        #[cfg(feature = "olap")]
+        async fn aggregate_payment_attempts_by_merchant_id_created_between(
+            &self,
+            merchant_id: &str,
+            start: time::PrimitiveDateTime,
+            end: time::PrimitiveDateTime,
+        ) -> CustomResult<Vec<PaymentAttemptAggregate>, errors::StorageError> {
+            // Attempts of merchants using the KV store are aggregated once the drainer has
+            // written them to the database.
+            let conn = pg_connection(self.read_pool()).await;
+            PaymentAttempt::aggregate_by_merchant_id_created_between(&conn, merchant_id, start, end)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        #[cfg(feature = "olap")]
+        async fn count_payment_attempt_declines_by_merchant_id_created_between(
+            &self,
+            merchant_id: &str,
+            start: time::PrimitiveDateTime,
+            end: time::PrimitiveDateTime,
+        ) -> CustomResult<Vec<PaymentAttemptDeclineReason>, errors::StorageError> {
+            let conn = pg_connection(self.read_pool()).await;
+            PaymentAttempt::count_declines_by_merchant_id_created_between(
+                &conn,
+                merchant_id,
+                start,
+                end,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        }
     }
 }
@@ -0,0 +1,133 @@
+use std::future::Future;
+
+use error_stack::IntoReport;
+use router_env::logger;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage::{self, enums},
+};
+
+tokio::task_local! {
+    /// The actor on whose behalf the current task updates payments.
+    static AUDIT_ACTOR: enums::AuditActor;
+}
+
+/// Runs the future with the actor which is recorded in the audit log of the payments it updates.
+pub async fn with_audit_actor<F: Future>(actor: enums::AuditActor, future: F) -> F::Output {
+    AUDIT_ACTOR.scope(actor, future).await
+}
+
+/// The actor on whose behalf the current task updates payments. Tasks which have not been run
+/// with [`with_audit_actor`] serve API requests.
+pub fn current_audit_actor() -> enums::AuditActor {
+    AUDIT_ACTOR
+        .try_with(|actor| *actor)
+        .unwrap_or(enums::AuditActor::Api)
+}
+
+/// Records an update of a payment intent in its audit log. A failure to do so is logged and not
+/// propagated, as the payment intent has already been updated by then.
+pub(super) async fn record_payment_intent_update(
+    db: &dyn PaymentAuditLogInterface,
+    previous_status: enums::IntentStatus,
+    payment_intent: &storage::PaymentIntent,
+) {
+    let audit_log = storage::PaymentAuditLogNew {
+        payment_id: payment_intent.payment_id.clone(),
+        merchant_id: payment_intent.merchant_id.clone(),
+        previous_status,
+        status: payment_intent.status,
+        actor: current_audit_actor(),
+    };
+
+    if let Err(error) = db.insert_payment_audit_log(audit_log).await {
+        logger::error!(
+            ?error,
+            payment_id = %payment_intent.payment_id,
+            "Failed to record the payment intent update in the audit log"
+        );
+    }
+}
+
+#[async_trait::async_trait]
+pub trait PaymentAuditLogInterface {
+    async fn insert_payment_audit_log(
+        &self,
+        audit_log: storage::PaymentAuditLogNew,
+    ) -> CustomResult<storage::PaymentAuditLog, errors::StorageError>;
+
+    async fn find_payment_audit_logs_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::PaymentAuditLog>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PaymentAuditLogInterface for Store {
+    async fn insert_payment_audit_log(
+        &self,
+        audit_log: storage::PaymentAuditLogNew,
+    ) -> CustomResult<storage::PaymentAuditLog, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        audit_log
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_payment_audit_logs_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::PaymentAuditLog>, errors::StorageError> {
+        let conn = pg_connection(self.read_pool()).await;
+        storage::PaymentAuditLog::find_by_merchant_id_payment_id(&conn, merchant_id, payment_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentAuditLogInterface for MockDb {
+    async fn insert_payment_audit_log(
+        &self,
+        audit_log: storage::PaymentAuditLogNew,
+    ) -> CustomResult<storage::PaymentAuditLog, errors::StorageError> {
+        let mut payment_audit_logs = self.payment_audit_logs.lock().await;
+
+        let audit_log = storage::PaymentAuditLog {
+            #[allow(clippy::as_conversions)]
+            id: payment_audit_logs.len() as i32,
+            payment_id: audit_log.payment_id,
+            merchant_id: audit_log.merchant_id,
+            previous_status: audit_log.previous_status,
+            status: audit_log.status,
+            actor: audit_log.actor,
+            created_at: common_utils::date_time::now(),
+        };
+        payment_audit_logs.push(audit_log.clone());
+        Ok(audit_log)
+    }
+
+    async fn find_payment_audit_logs_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::PaymentAuditLog>, errors::StorageError> {
+        let payment_audit_logs = self.payment_audit_logs.lock().await;
+
+        Ok(payment_audit_logs
+            .iter()
+            .filter(|audit_log| {
+                audit_log.merchant_id == merchant_id && audit_log.payment_id == payment_id
+            })
+            .cloned()
+            .collect())
+    }
+}
@@ -0,0 +1,159 @@
+use error_stack::{report, IntoReport};
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait PaymentCostInterface {
+    /// Records a cost in the ledger. Fails with a unique violation if a cost with the same
+    /// connector reference has already been recorded for the merchant.
+    async fn insert_payment_cost(
+        &self,
+        payment_cost: storage::PaymentCostNew,
+    ) -> CustomResult<storage::PaymentCost, errors::StorageError>;
+
+    async fn find_payment_costs_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::PaymentCost>, errors::StorageError>;
+
+    #[cfg(feature = "olap")]
+    async fn aggregate_payment_costs_by_merchant_id_created_between(
+        &self,
+        merchant_id: &str,
+        start: time::PrimitiveDateTime,
+        end: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage::PaymentCostAggregate>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PaymentCostInterface for Store {
+    async fn insert_payment_cost(
+        &self,
+        payment_cost: storage::PaymentCostNew,
+    ) -> CustomResult<storage::PaymentCost, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        payment_cost
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_payment_costs_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::PaymentCost>, errors::StorageError> {
+        let conn = pg_connection(self.read_pool()).await;
+        storage::PaymentCost::find_by_merchant_id_payment_id(&conn, merchant_id, payment_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    #[cfg(feature = "olap")]
+    async fn aggregate_payment_costs_by_merchant_id_created_between(
+        &self,
+        merchant_id: &str,
+        start: time::PrimitiveDateTime,
+        end: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage::PaymentCostAggregate>, errors::StorageError> {
+        let conn = pg_connection(self.read_pool()).await;
+        <storage::PaymentCost as storage::PaymentCostDbExt>::aggregate_by_merchant_id_created_between(
+            &conn,
+            merchant_id,
+            start,
+            end,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentCostInterface for MockDb {
+    async fn insert_payment_cost(
+        &self,
+        payment_cost: storage::PaymentCostNew,
+    ) -> CustomResult<storage::PaymentCost, errors::StorageError> {
+        let mut payment_costs = self.payment_costs.lock().await;
+
+        if payment_costs.iter().any(|cost| {
+            cost.merchant_id == payment_cost.merchant_id
+                && cost.connector == payment_cost.connector
+                && cost.connector_reference == payment_cost.connector_reference
+        }) {
+            Err(errors::StorageError::DatabaseError(report!(
+                storage_models::errors::DatabaseError::UniqueViolation
+            )))?
+        }
+
+        let payment_cost = storage::PaymentCost {
+            #[allow(clippy::as_conversions)]
+            id: payment_costs.len() as i32,
+            cost_id: payment_cost.cost_id,
+            merchant_id: payment_cost.merchant_id,
+            payment_id: payment_cost.payment_id,
+            attempt_id: payment_cost.attempt_id,
+            dispute_id: payment_cost.dispute_id,
+            connector: payment_cost.connector,
+            cost_type: payment_cost.cost_type,
+            amount: payment_cost.amount,
+            currency: payment_cost.currency,
+            connector_reference: payment_cost.connector_reference,
+            created_at: common_utils::date_time::now(),
+        };
+        payment_costs.push(payment_cost.clone());
+        Ok(payment_cost)
+    }
+
+    async fn find_payment_costs_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &str,
+        payment_id: &str,
+    ) -> CustomResult<Vec<storage::PaymentCost>, errors::StorageError> {
+        let payment_costs = self.payment_costs.lock().await;
+
+        Ok(payment_costs
+            .iter()
+            .filter(|cost| cost.merchant_id == merchant_id && cost.payment_id == payment_id)
+            .cloned()
+            .collect())
+    }
+
+    #[cfg(feature = "olap")]
+    async fn aggregate_payment_costs_by_merchant_id_created_between(
+        &self,
+        merchant_id: &str,
+        start: time::PrimitiveDateTime,
+        end: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<storage::PaymentCostAggregate>, errors::StorageError> {
+        let payment_costs = self.payment_costs.lock().await;
+
+        let mut aggregates: Vec<storage::PaymentCostAggregate> = Vec::new();
+        for cost in payment_costs.iter().filter(|cost| {
+            cost.merchant_id == merchant_id && cost.created_at >= start && cost.created_at < end
+        }) {
+            match aggregates.iter_mut().find(|aggregate| {
+                aggregate.cost_type == cost.cost_type && aggregate.currency == cost.currency
+            }) {
+                Some(aggregate) => {
+                    aggregate.amount = Some(aggregate.amount.unwrap_or_default() + cost.amount)
+                }
+                None => aggregates.push(storage::PaymentCostAggregate {
+                    cost_type: cost.cost_type,
+                    currency: cost.currency.clone(),
+                    amount: Some(cost.amount),
+                }),
+            }
+        }
+        Ok(aggregates)
+    }
+}
@@ -35,6 +35,26 @@ pub trait PaymentIntentInterface {
         pc: &api::PaymentListConstraints,
         storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<types::PaymentIntent>, errors::StorageError>;
+
+    /// Removes the references to the customer from their payment intents, returning the
+    /// anonymized payment intents. With the KV storage scheme, the copies of the payment intents
+    /// in Redis are anonymized as well, and the call fails if any of them could not be.
+    async fn anonymize_payment_intents_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<types::PaymentIntent>, errors::StorageError>;
+
+    /// Finds at most `limit` payment intents created by the merchant in the time range
+    /// `[start, end)`.
+    async fn find_payment_intents_by_merchant_id_created_between(
+        &self,
+        merchant_id: &str,
+        start: time::PrimitiveDateTime,
+        end: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<types::PaymentIntent>, errors::StorageError>;
 }
 
 #[cfg(feature = "kv_store")]
@@ -42,20 +62,25 @@ mod storage {
     use common_utils::date_time;
     use error_stack::{IntoReport, ResultExt};
     use redis_interface::{HsetnxReply, RedisEntryId};
+    use router_env::logger;
 
     use super::PaymentIntentInterface;
-    #[cfg(feature = "olap")]
-    use crate::types::api;
     use crate::{
         connection::pg_connection,
         core::errors::{self, CustomResult},
+        db::{outbox_event, payment_audit_log},
         services::Store,
-        types::storage::{enums, kv, payment_intent::*},
+        types::storage::{enums, kv, payment_intent::*, OutboxEventNew},
         utils::{
             self,
             storage_partitioning::{self, KvStorePartition},
         },
     };
+    #[cfg(feature = "olap")]
+    use crate::{
+        db::payment_attempt::PaymentAttemptInterface,
+        types::{api, transformers::ForeignInto},
+    };
 
     #[async_trait::async_trait]
     impl PaymentIntentInterface for Store {
@@ -64,14 +89,34 @@ mod storage {
             new: PaymentIntentNew,
             storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<PaymentIntent, errors::StorageError> {
-            match storage_scheme {
+            let created_intent = match storage_scheme {
                 enums::MerchantStorageScheme::PostgresOnly => {
                     let conn = pg_connection(&self.master_pool).await;
                     new.insert(&conn).await.map_err(Into::into).into_report()
                 }
 
+                enums::MerchantStorageScheme::RedisKv if self.config.write_through => {
+                    let conn = pg_connection(&self.master_pool).await;
+                    let created_intent: PaymentIntent =
+                        new.insert(&conn).await.map_err(Into::into).into_report()?;
+
+                    // Lookups of an intent missing in Redis fall back to the database, so the
+                    // intent is only cached on a best effort basis
+                    if let Err(error) = self.cache_payment_intent(&created_intent).await {
+                        logger::error!(?error, "Failed to cache payment intent in Redis");
+                    }
+                    if let Err(error) = self.add_payment_intent_to_index(&created_intent).await {
+                        logger::error!(?error, "Failed to index payment intent");
+                    }
+                    Ok(created_intent)
+                }
+
                 enums::MerchantStorageScheme::RedisKv => {
-                    let key = format!("{}_{}", new.merchant_id, new.payment_id);
+                    let key = storage_partitioning::payment_hash_key(
+                        &new.merchant_id,
+                        &new.payment_id,
+                        self.config.hash_tagged_keys,
+                    );
                     let created_intent = PaymentIntent {
                         id: 0i32,
                         payment_id: new.payment_id.clone(),
@@ -95,6 +140,10 @@ mod storage {
                         setup_future_usage: new.setup_future_usage,
                         off_session: new.off_session,
                         client_secret: new.client_secret.clone(),
+                        client_secret_expiry: new.client_secret_expiry,
+                        business_country: new.business_country.clone(),
+                        business_label: new.business_label.clone(),
+                        active_attempt_id: new.active_attempt_id.clone(),
                     };
 
                     match self
@@ -111,6 +160,7 @@ mod storage {
                                 op: kv::DBOperation::Insert {
                                     insertable: kv::Insertable::PaymentIntent(new),
                                 },
+                                outbox_event: None,
                             };
                             let stream_name =
                                 self.get_drainer_stream_name(&PaymentIntent::shard_key(
@@ -130,12 +180,21 @@ mod storage {
                                 )
                                 .await
                                 .change_context(errors::StorageError::KVError)?;
+                            if let Err(error) =
+                                self.add_payment_intent_to_index(&created_intent).await
+                            {
+                                logger::error!(?error, %key, "Failed to index payment intent");
+                            }
                             Ok(created_intent)
                         }
                         Err(error) => Err(error.change_context(errors::StorageError::KVError)),
                     }
                 }
-            }
+            }?;
+
+            #[cfg(feature = "kafka_events")]
+            self.kafka_producer.log_event(&created_intent);
+            Ok(created_intent)
         }
 
         async fn update_payment_intent(
@@ -144,17 +203,33 @@ mod storage {
             payment_intent: PaymentIntentUpdate,
             storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<PaymentIntent, errors::StorageError> {
-            match storage_scheme {
+            let previous_status = this.status;
+            let is_payment_succeeded = outbox_event::is_payment_succeeded(&this, &payment_intent);
+            let updated_intent = match storage_scheme {
                 enums::MerchantStorageScheme::PostgresOnly => {
-                    let conn = pg_connection(&self.master_pool).await;
-                    this.update(&conn, payment_intent)
+                    self.update_payment_intent_in_db(this, payment_intent, is_payment_succeeded)
                         .await
-                        .map_err(Into::into)
-                        .into_report()
+                }
+
+                enums::MerchantStorageScheme::RedisKv if self.config.write_through => {
+                    let updated_intent = self
+                        .update_payment_intent_in_db(this, payment_intent, is_payment_succeeded)
+                        .await?;
+
+                    // Unlike a missing intent, a stale intent in Redis would be served by the
+                    // lookups, so a failure to update it fails the update
+                    self.cache_payment_intent(&updated_intent)
+                        .await
+                        .attach_printable("Failed to update payment intent in Redis")?;
+                    Ok(updated_intent)
                 }
 
                 enums::MerchantStorageScheme::RedisKv => {
-                    let key = format!("{}_{}", this.merchant_id, this.payment_id);
+                    let key = storage_partitioning::payment_hash_key(
+                        &this.merchant_id,
+                        &this.payment_id,
+                        self.config.hash_tagged_keys,
+                    );
 
                     let updated_intent = payment_intent.clone().apply_changeset(this.clone());
                     // Check for database presence as well Maybe use a read replica here ?
@@ -170,6 +245,11 @@ mod storage {
                         .map(|_| updated_intent)
                         .change_context(errors::StorageError::KVError)?;
 
+                    let outbox_event = is_payment_succeeded
+                        .then(|| OutboxEventNew::payment_succeeded(&updated_intent))
+                        .transpose()
+                        .into_report()
+                        .change_context(errors::StorageError::SerializationFailed)?;
                     let redis_entry = kv::TypedSql {
                         op: kv::DBOperation::Update {
                             updatable: kv::Updateable::PaymentIntentUpdate(
@@ -179,6 +259,7 @@ mod storage {
                                 },
                             ),
                         },
+                        outbox_event,
                     };
 
                     let stream_name = self.get_drainer_stream_name(&PaymentIntent::shard_key(
@@ -201,7 +282,13 @@ mod storage {
                         .change_context(errors::StorageError::KVError)?;
                     Ok(updated_intent)
                 }
-            }
+            }?;
+
+            payment_audit_log::record_payment_intent_update(self, previous_status, &updated_intent)
+                .await;
+            #[cfg(feature = "kafka_events")]
+            self.kafka_producer.log_event(&updated_intent);
+            Ok(updated_intent)
         }
 
         async fn find_payment_intent_by_payment_id_merchant_id(
@@ -220,22 +307,34 @@ mod storage {
                 }
 
                 enums::MerchantStorageScheme::RedisKv => {
-                    let key = format!("{}_{}", merchant_id, payment_id);
-                    self.redis_conn
+                    let key = storage_partitioning::payment_hash_key(
+                        merchant_id,
+                        payment_id,
+                        self.config.hash_tagged_keys,
+                    );
+                    let result = self
+                        .redis_conn
                         .get_hash_field_and_deserialize::<PaymentIntent>(
                             &key,
                             "pi",
                             "PaymentIntent",
                         )
-                        .await
-                        .map_err(|error| match error.current_context() {
-                            errors::RedisError::NotFound => errors::StorageError::ValueNotFound(
-                                format!("Payment Intent does not exist for {}", key),
-                            )
-                            .into(),
-                            _ => error.change_context(errors::StorageError::KVError),
-                        })
-                    // Check for database presence as well Maybe use a read replica here ?
+                        .await;
+
+                    match result {
+                        Ok(payment_intent) => Ok(payment_intent),
+                        Err(error) => match error.current_context() {
+                            errors::RedisError::NotFound => {
+                                self.find_payment_intent_in_db_and_cache(
+                                    &key,
+                                    payment_id,
+                                    merchant_id,
+                                )
+                                .await
+                            }
+                            _ => Err(error.change_context(errors::StorageError::KVError)),
+                        },
+                    }
                 }
             }
         }
@@ -249,17 +348,419 @@ mod storage {
         ) -> CustomResult<Vec<PaymentIntent>, errors::StorageError> {
             match storage_scheme {
                 enums::MerchantStorageScheme::PostgresOnly => {
-                    let conn = pg_connection(&self.replica_pool).await;
+                    let conn = pg_connection(self.read_pool()).await;
                     PaymentIntent::filter_by_constraints(&conn, merchant_id, pc)
                         .await
                         .map_err(Into::into)
                         .into_report()
                 }
 
-                enums::MerchantStorageScheme::RedisKv => Err(errors::StorageError::KVError.into()),
+                enums::MerchantStorageScheme::RedisKv => {
+                    self.filter_payment_intent_from_index(merchant_id, pc).await
+                }
+            }
+        }
+
+        async fn anonymize_payment_intents_by_merchant_id_customer_id(
+            &self,
+            merchant_id: &str,
+            customer_id: &str,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<PaymentIntent>, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await;
+            let payment_intents = PaymentIntent::anonymize_by_merchant_id_customer_id(
+                &conn,
+                merchant_id,
+                customer_id,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()?;
+
+            if storage_scheme == enums::MerchantStorageScheme::RedisKv {
+                for payment_intent in &payment_intents {
+                    self.anonymize_cached_payment_intent(payment_intent).await?;
+                }
+            }
+
+            Ok(payment_intents)
+        }
+
+        async fn find_payment_intents_by_merchant_id_created_between(
+            &self,
+            merchant_id: &str,
+            start: time::PrimitiveDateTime,
+            end: time::PrimitiveDateTime,
+            limit: i64,
+        ) -> CustomResult<Vec<PaymentIntent>, errors::StorageError> {
+            // The payment intents are listed from the database regardless of the storage scheme,
+            // as it is kept up to date by the drainer
+            let conn = pg_connection(self.read_pool()).await;
+            PaymentIntent::find_by_merchant_id_created_between(
+                &conn,
+                merchant_id,
+                start,
+                end,
+                limit,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        }
+    }
+
+    impl Store {
+        async fn update_payment_intent_in_db(
+            &self,
+            this: PaymentIntent,
+            payment_intent: PaymentIntentUpdate,
+            is_payment_succeeded: bool,
+        ) -> CustomResult<PaymentIntent, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await;
+            let updated_intent = if is_payment_succeeded {
+                this.update_with_payment_succeeded_event(&conn, payment_intent)
+                    .await
+            } else {
+                this.update(&conn, payment_intent).await
+            };
+            updated_intent.map_err(Into::into).into_report()
+        }
+
+        /// Removes the references to the customer from the copy in Redis of a payment intent
+        /// anonymized in the database. The copy is updated in place rather than replaced with the
+        /// anonymized intent, as it may hold changes which the drainer has not written to the
+        /// database yet.
+        async fn anonymize_cached_payment_intent(
+            &self,
+            anonymized_intent: &PaymentIntent,
+        ) -> CustomResult<(), errors::StorageError> {
+            let key = storage_partitioning::payment_hash_key(
+                &anonymized_intent.merchant_id,
+                &anonymized_intent.payment_id,
+                self.config.hash_tagged_keys,
+            );
+            let cached_intent = match self
+                .redis_conn
+                .get_hash_field_and_deserialize::<PaymentIntent>(&key, "pi", "PaymentIntent")
+                .await
+            {
+                Ok(cached_intent) => cached_intent,
+                Err(error) => match error.current_context() {
+                    errors::RedisError::NotFound => return Ok(()),
+                    _ => Err(error.change_context(errors::StorageError::KVError))?,
+                },
+            };
+
+            self.cache_payment_intent(&PaymentIntent {
+                customer_id: anonymized_intent.customer_id.clone(),
+                description: anonymized_intent.description.clone(),
+                metadata: anonymized_intent.metadata.clone(),
+                modified_at: anonymized_intent.modified_at,
+                ..cached_intent
+            })
+            .await
+            .attach_printable_lazy(|| format!("Failed to anonymize payment intent in Redis: {key}"))
+        }
+
+        /// Writes the payment intent to its hash in Redis, overwriting the cached intent if any.
+        async fn cache_payment_intent(
+            &self,
+            payment_intent: &PaymentIntent,
+        ) -> CustomResult<(), errors::StorageError> {
+            let key = storage_partitioning::payment_hash_key(
+                &payment_intent.merchant_id,
+                &payment_intent.payment_id,
+                self.config.hash_tagged_keys,
+            );
+            let redis_value =
+                utils::Encode::<PaymentIntent>::encode_to_string_of_json(payment_intent)
+                    .change_context(errors::StorageError::SerializationFailed)?;
+
+            self.redis_conn
+                .set_hash_fields(&key, ("pi", &redis_value))
+                .await
+                .change_context(errors::StorageError::KVError)
+        }
+
+        /// Looks up a payment intent which is missing in Redis from the database, and caches it
+        /// back in Redis so that the subsequent lookups are served from Redis.
+        async fn find_payment_intent_in_db_and_cache(
+            &self,
+            key: &str,
+            payment_id: &str,
+            merchant_id: &str,
+        ) -> CustomResult<PaymentIntent, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await;
+            let payment_intent =
+                PaymentIntent::find_by_payment_id_merchant_id(&conn, payment_id, merchant_id)
+                    .await
+                    .map_err(Into::<errors::StorageError>::into)
+                    .into_report()?;
+
+            // The value is set only if it doesn't exist, so that a value written to Redis by
+            // a concurrent request is not overwritten with the one read from the database
+            if let Err(error) = self
+                .redis_conn
+                .serialize_and_set_hash_field_if_not_exist(key, "pi", &payment_intent)
+                .await
+            {
+                logger::error!(?error, %key, "Failed to cache payment intent in Redis");
+            }
+            if let Err(error) = self.add_payment_intent_to_index(&payment_intent).await {
+                logger::error!(?error, %key, "Failed to index payment intent");
+            }
+
+            Ok(payment_intent)
+        }
+
+        /// Adds the payment intent to the sorted set of the payment intents of the merchant,
+        /// which is scored on the creation time of the payment intents and used for listing the
+        /// payment intents.
+        async fn add_payment_intent_to_index(
+            &self,
+            payment_intent: &PaymentIntent,
+        ) -> CustomResult<(), errors::StorageError> {
+            #[allow(clippy::as_conversions)]
+            let score = get_payment_intent_score(payment_intent) as f64;
+            self.redis_conn
+                .add_sorted_set_member(
+                    &get_payment_intent_index_key(&payment_intent.merchant_id),
+                    score,
+                    &payment_intent.payment_id,
+                )
+                .await
+                .change_context(errors::StorageError::KVError)
+        }
+
+        #[cfg(feature = "olap")]
+        async fn filter_payment_intent_from_index(
+            &self,
+            merchant_id: &str,
+            pc: &api::PaymentListConstraints,
+        ) -> CustomResult<Vec<PaymentIntent>, errors::StorageError> {
+            let starting_after = self
+                .find_index_cursor(merchant_id, pc.starting_after.as_deref())
+                .await?;
+            let ending_before = self
+                .find_index_cursor(merchant_id, pc.ending_before.as_deref())
+                .await?;
+            let (max, min) = get_score_range(
+                pc,
+                starting_after.map(|(_, score)| score),
+                ending_before.map(|(_, score)| score),
+            );
+
+            // The payment intents are listed from the newest to the oldest, `starting_after`
+            // returns the ones listed after the given payment and `ending_before` the ones
+            // listed before it, closest to it first
+            let is_reversed = ending_before.is_some() && starting_after.is_none();
+            let index_key = get_payment_intent_index_key(merchant_id);
+            let limit = usize::try_from(pc.limit).unwrap_or_default();
+            let mut payment_intents = Vec::with_capacity(limit);
+            let mut offset = 0;
+
+            // The index is read one page at a time, and read further only if some of the payment
+            // intents of the page do not match the constraints
+            while payment_intents.len() < limit {
+                let payment_ids = if is_reversed {
+                    self.redis_conn
+                        .get_sorted_set_members_by_score_asc(
+                            &index_key, &min, &max, offset, pc.limit,
+                        )
+                        .await
+                } else {
+                    self.redis_conn
+                        .get_sorted_set_members_by_score_desc(
+                            &index_key, &max, &min, offset, pc.limit,
+                        )
+                        .await
+                }
+                .change_context(errors::StorageError::KVError)?;
+                offset = offset.saturating_add(pc.limit);
+
+                for payment_id in &payment_ids {
+                    if payment_intents.len() >= limit {
+                        break;
+                    }
+                    let payment_intent = match self
+                        .find_payment_intent_by_payment_id_merchant_id(
+                            payment_id,
+                            merchant_id,
+                            enums::MerchantStorageScheme::RedisKv,
+                        )
+                        .await
+                    {
+                        Ok(payment_intent) => payment_intent,
+                        Err(error) if error.current_context().is_db_not_found() => {
+                            logger::warn!(%payment_id, "Indexed payment intent does not exist");
+                            continue;
+                        }
+                        Err(error) => return Err(error),
+                    };
+
+                    // The payment intents with the same score as a cursor are included in the
+                    // range, they are ordered by their id
+                    let score = get_payment_intent_score(&payment_intent);
+                    let is_after_cursor = starting_after.map_or(true, |(cursor, cursor_score)| {
+                        score < cursor_score || payment_id.as_str() < cursor
+                    });
+                    let is_before_cursor = ending_before.map_or(true, |(cursor, cursor_score)| {
+                        score > cursor_score || payment_id.as_str() > cursor
+                    });
+                    if is_after_cursor
+                        && is_before_cursor
+                        && self
+                            .is_payment_intent_matching_constraints(&payment_intent, pc)
+                            .await?
+                    {
+                        payment_intents.push(payment_intent);
+                    }
+                }
+
+                if payment_ids.len() < limit {
+                    break;
+                }
+            }
+
+            if is_reversed {
+                payment_intents.reverse();
+            }
+
+            Ok(payment_intents)
+        }
+
+        /// Finds the score of the payment intent used as a cursor for listing the payment
+        /// intents. Cursors which do not exist are ignored.
+        #[cfg(feature = "olap")]
+        async fn find_index_cursor<'a>(
+            &self,
+            merchant_id: &str,
+            payment_id: Option<&'a str>,
+        ) -> CustomResult<Option<(&'a str, i64)>, errors::StorageError> {
+            let payment_id = match payment_id {
+                Some(payment_id) => payment_id,
+                None => return Ok(None),
+            };
+            match self
+                .find_payment_intent_by_payment_id_merchant_id(
+                    payment_id,
+                    merchant_id,
+                    enums::MerchantStorageScheme::RedisKv,
+                )
+                .await
+            {
+                Ok(payment_intent) => Ok(Some((
+                    payment_id,
+                    get_payment_intent_score(&payment_intent),
+                ))),
+                Err(error) if error.current_context().is_db_not_found() => Ok(None),
+                Err(error) => Err(error),
+            }
+        }
+
+        /// Applies the constraints which are not covered by the payment intent index.
+        #[cfg(feature = "olap")]
+        async fn is_payment_intent_matching_constraints(
+            &self,
+            payment_intent: &PaymentIntent,
+            pc: &api::PaymentListConstraints,
+        ) -> CustomResult<bool, errors::StorageError> {
+            let is_customer_matching = pc.customer_id.as_ref().map_or(true, |customer_id| {
+                payment_intent.customer_id.as_ref() == Some(customer_id)
+            });
+            let status: Option<enums::IntentStatus> = pc.status.map(ForeignInto::foreign_into);
+            let is_status_matching = status.map_or(true, |status| status == payment_intent.status);
+            let currency: Option<enums::Currency> = pc.currency.map(ForeignInto::foreign_into);
+            let is_currency_matching =
+                currency.map_or(true, |currency| payment_intent.currency == Some(currency));
+            let is_amount_matching = pc.amount_lte.map_or(true, |amount_lte| {
+                payment_intent.amount.get_amount_as_i64() <= amount_lte
+            }) && pc.amount_gte.map_or(true, |amount_gte| {
+                payment_intent.amount.get_amount_as_i64() >= amount_gte
+            });
+            let is_matching = is_customer_matching
+                && is_status_matching
+                && is_currency_matching
+                && is_amount_matching;
+
+            match pc.connector {
+                Some(connector) if is_matching => {
+                    let payment_attempt = self
+                        .find_payment_attempt_by_payment_id_merchant_id(
+                            &payment_intent.payment_id,
+                            &payment_intent.merchant_id,
+                            enums::MerchantStorageScheme::RedisKv,
+                        )
+                        .await?;
+                    Ok(payment_attempt.connector == Some(connector.to_string()))
+                }
+                _ => Ok(is_matching),
             }
         }
     }
+
+    fn get_payment_intent_index_key(merchant_id: &str) -> String {
+        format!("{merchant_id}_payment_intents")
+    }
+
+    /// The score of the payment intent in the payment intent index, which is its creation time.
+    fn get_payment_intent_score(payment_intent: &PaymentIntent) -> i64 {
+        payment_intent.created_at.assume_utc().unix_timestamp()
+    }
+
+    /// Returns the range of the creation time of the payment intents to be listed, as the
+    /// maximum and minimum scores of the payment intent index. The range is narrowed down to the
+    /// scores of the cursors, as the payment intents listed after `starting_after` were created
+    /// at or before it and the ones listed before `ending_before` at or after it.
+    #[cfg(feature = "olap")]
+    fn get_score_range(
+        pc: &api::PaymentListConstraints,
+        starting_after_score: Option<i64>,
+        ending_before_score: Option<i64>,
+    ) -> (String, String) {
+        let timestamp = |time: time::PrimitiveDateTime| time.assume_utc().unix_timestamp();
+
+        // The bounds are pairs of the score and whether the bound is exclusive
+        let max = pc
+            .created
+            .or(pc.created_lte)
+            .map(|created| (timestamp(created), false))
+            .or_else(|| {
+                pc.created_lt
+                    .map(|created_lt| (timestamp(created_lt), true))
+            });
+        let max = match (max, starting_after_score) {
+            (Some(max), Some(score)) => Some(std::cmp::min_by_key(
+                max,
+                (score, false),
+                |&(score, is_exclusive)| (score, !is_exclusive),
+            )),
+            (max, score) => max.or(score.map(|score| (score, false))),
+        };
+        let min = pc
+            .created
+            .or(pc.created_gte)
+            .map(|created| (timestamp(created), false))
+            .or_else(|| {
+                pc.created_gt
+                    .map(|created_gt| (timestamp(created_gt), true))
+            });
+        let min = match (min, ending_before_score) {
+            (Some(min), Some(score)) => Some(std::cmp::max_by_key(
+                min,
+                (score, false),
+                |&(score, is_exclusive)| (score, is_exclusive),
+            )),
+            (min, score) => min.or(score.map(|score| (score, false))),
+        };
+
+        let format_bound = |bound: Option<(i64, bool)>, unbounded: &str| match bound {
+            Some((score, true)) => format!("({score}"),
+            Some((score, false)) => score.to_string(),
+            None => unbounded.to_string(),
+        };
+        (format_bound(max, "+inf"), format_bound(min, "-inf"))
+    }
 }
 
 #[cfg(not(feature = "kv_store"))]
@@ -272,6 +773,7 @@ mod storage {
     use crate::{
         connection::pg_connection,
         core::errors::{self, CustomResult},
+        db::{outbox_event, payment_audit_log},
         services::Store,
         types::storage::{enums, payment_intent::*},
     };
@@ -284,7 +786,11 @@ mod storage {
             _storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<PaymentIntent, errors::StorageError> {
             let conn = pg_connection(&self.master_pool).await;
-            new.insert(&conn).await.map_err(Into::into).into_report()
+            let created_intent = new.insert(&conn).await.map_err(Into::into).into_report()?;
+
+            #[cfg(feature = "kafka_events")]
+            self.kafka_producer.log_event(&created_intent);
+            Ok(created_intent)
         }
 
         async fn update_payment_intent(
@@ -294,10 +800,21 @@ mod storage {
             _storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<PaymentIntent, errors::StorageError> {
             let conn = pg_connection(&self.master_pool).await;
-            this.update(&conn, payment_intent)
-                .await
-                .map_err(Into::into)
-                .into_report()
+            let previous_status = this.status;
+            let updated_intent = if outbox_event::is_payment_succeeded(&this, &payment_intent) {
+                this.update_with_payment_succeeded_event(&conn, payment_intent)
+                    .await
+            } else {
+                this.update(&conn, payment_intent).await
+            }
+            .map_err(Into::into)
+            .into_report()?;
+
+            payment_audit_log::record_payment_intent_update(self, previous_status, &updated_intent)
+                .await;
+            #[cfg(feature = "kafka_events")]
+            self.kafka_producer.log_event(&updated_intent);
+            Ok(updated_intent)
         }
 
         async fn find_payment_intent_by_payment_id_merchant_id(
@@ -320,12 +837,45 @@ mod storage {
             pc: &api::PaymentListConstraints,
             _storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<Vec<PaymentIntent>, errors::StorageError> {
-            let conn = pg_connection(&self.replica_pool).await;
+            let conn = pg_connection(self.read_pool()).await;
             PaymentIntent::filter_by_constraints(&conn, merchant_id, pc)
                 .await
                 .map_err(Into::into)
                 .into_report()
         }
+
+        async fn anonymize_payment_intents_by_merchant_id_customer_id(
+            &self,
+            merchant_id: &str,
+            customer_id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<PaymentIntent>, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await;
+            PaymentIntent::anonymize_by_merchant_id_customer_id(&conn, merchant_id, customer_id)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        async fn find_payment_intents_by_merchant_id_created_between(
+            &self,
+            merchant_id: &str,
+            start: time::PrimitiveDateTime,
+            end: time::PrimitiveDateTime,
+            limit: i64,
+        ) -> CustomResult<Vec<PaymentIntent>, errors::StorageError> {
+            let conn = pg_connection(self.read_pool()).await;
+            PaymentIntent::find_by_merchant_id_created_between(
+                &conn,
+                merchant_id,
+                start,
+                end,
+                limit,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        }
     }
 }
 
@@ -374,6 +924,10 @@ impl PaymentIntentInterface for MockDb {
             setup_future_usage: new.setup_future_usage,
             off_session: new.off_session,
             client_secret: new.client_secret,
+            client_secret_expiry: new.client_secret_expiry,
+            business_country: new.business_country,
+            business_label: new.business_label,
+            active_attempt_id: new.active_attempt_id,
         };
         payment_intents.push(payment_intent.clone());
         Ok(payment_intent)
@@ -392,8 +946,18 @@ impl PaymentIntentInterface for MockDb {
             .iter_mut()
             .find(|item| item.id == this.id)
             .unwrap();
+        let previous_status = this.status;
         *payment_intent = update.apply_changeset(this);
-        Ok(payment_intent.clone())
+        let payment_intent = payment_intent.clone();
+        drop(payment_intents);
+
+        super::payment_audit_log::record_payment_intent_update(
+            self,
+            previous_status,
+            &payment_intent,
+        )
+        .await;
+        Ok(payment_intent)
     }
 
     // safety: only used for testing
@@ -414,4 +978,49 @@ impl PaymentIntentInterface for MockDb {
             .cloned()
             .unwrap())
     }
+
+    async fn anonymize_payment_intents_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<types::PaymentIntent>, errors::StorageError> {
+        let mut payment_intents = self.payment_intents.lock().await;
+
+        Ok(payment_intents
+            .iter_mut()
+            .filter(|payment_intent| {
+                payment_intent.merchant_id == merchant_id
+                    && payment_intent.customer_id.as_deref() == Some(customer_id)
+            })
+            .map(|payment_intent| {
+                payment_intent.customer_id = None;
+                payment_intent.description = None;
+                payment_intent.metadata = None;
+                payment_intent.modified_at = common_utils::date_time::now();
+                payment_intent.clone()
+            })
+            .collect())
+    }
+
+    async fn find_payment_intents_by_merchant_id_created_between(
+        &self,
+        merchant_id: &str,
+        start: time::PrimitiveDateTime,
+        end: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<types::PaymentIntent>, errors::StorageError> {
+        let payment_intents = self.payment_intents.lock().await;
+
+        Ok(payment_intents
+            .iter()
+            .filter(|payment_intent| {
+                payment_intent.merchant_id == merchant_id
+                    && payment_intent.created_at >= start
+                    && payment_intent.created_at < end
+            })
+            .take(usize::try_from(limit).unwrap_or(usize::MAX))
+            .cloned()
+            .collect())
+    }
 }
@@ -0,0 +1,138 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait PaymentLinkInterface {
+    async fn insert_payment_link(
+        &self,
+        payment_link: storage::PaymentLinkNew,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError>;
+
+    async fn find_payment_link_by_merchant_id_payment_link_id(
+        &self,
+        merchant_id: &str,
+        payment_link_id: &str,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError>;
+
+    async fn update_payment_link(
+        &self,
+        this: storage::PaymentLink,
+        payment_link: storage::PaymentLinkUpdate,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PaymentLinkInterface for Store {
+    async fn insert_payment_link(
+        &self,
+        payment_link: storage::PaymentLinkNew,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        payment_link
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_payment_link_by_merchant_id_payment_link_id(
+        &self,
+        merchant_id: &str,
+        payment_link_id: &str,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::PaymentLink::find_by_merchant_id_payment_link_id(
+            &conn,
+            merchant_id,
+            payment_link_id,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn update_payment_link(
+        &self,
+        this: storage::PaymentLink,
+        payment_link: storage::PaymentLinkUpdate,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        this.update(&conn, payment_link)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentLinkInterface for MockDb {
+    async fn insert_payment_link(
+        &self,
+        payment_link: storage::PaymentLinkNew,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
+        let mut payment_links = self.payment_links.lock().await;
+        let current_time = common_utils::date_time::now();
+
+        let payment_link = storage::PaymentLink {
+            #[allow(clippy::as_conversions)]
+            id: payment_links.len() as i32,
+            payment_link_id: payment_link.payment_link_id,
+            payment_id: payment_link.payment_id,
+            merchant_id: payment_link.merchant_id,
+            status: payment_link.status,
+            expires_at: payment_link.expires_at,
+            created_at: current_time,
+            modified_at: current_time,
+        };
+        payment_links.push(payment_link.clone());
+        Ok(payment_link)
+    }
+
+    async fn find_payment_link_by_merchant_id_payment_link_id(
+        &self,
+        merchant_id: &str,
+        payment_link_id: &str,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
+        let payment_links = self.payment_links.lock().await;
+
+        payment_links
+            .iter()
+            .find(|payment_link| {
+                payment_link.merchant_id == merchant_id
+                    && payment_link.payment_link_id == payment_link_id
+            })
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "No payment link found with payment_link_id = {payment_link_id}"
+                ))
+            })
+            .into_report()
+    }
+
+    async fn update_payment_link(
+        &self,
+        this: storage::PaymentLink,
+        payment_link: storage::PaymentLinkUpdate,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
+        let mut payment_links = self.payment_links.lock().await;
+        let item = payment_links
+            .iter_mut()
+            .find(|item| item.id == this.id)
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "No payment link found with payment_link_id = {}",
+                    this.payment_link_id
+                ))
+            })
+            .into_report()?;
+        *item = payment_link.apply_changeset(item.clone());
+        Ok(item.clone())
+    }
+}
@@ -0,0 +1,132 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait PayoutInterface {
+    async fn insert_payout(
+        &self,
+        payout: storage::PayoutNew,
+    ) -> CustomResult<storage::Payout, errors::StorageError>;
+
+    async fn find_payout_by_merchant_id_payout_id(
+        &self,
+        merchant_id: &str,
+        payout_id: &str,
+    ) -> CustomResult<storage::Payout, errors::StorageError>;
+
+    async fn update_payout(
+        &self,
+        this: storage::Payout,
+        payout: storage::PayoutUpdate,
+    ) -> CustomResult<storage::Payout, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PayoutInterface for Store {
+    async fn insert_payout(
+        &self,
+        payout: storage::PayoutNew,
+    ) -> CustomResult<storage::Payout, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        payout.insert(&conn).await.map_err(Into::into).into_report()
+    }
+
+    async fn find_payout_by_merchant_id_payout_id(
+        &self,
+        merchant_id: &str,
+        payout_id: &str,
+    ) -> CustomResult<storage::Payout, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::Payout::find_by_merchant_id_payout_id(&conn, merchant_id, payout_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_payout(
+        &self,
+        this: storage::Payout,
+        payout: storage::PayoutUpdate,
+    ) -> CustomResult<storage::Payout, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        this.update(&conn, payout)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl PayoutInterface for MockDb {
+    async fn insert_payout(
+        &self,
+        payout: storage::PayoutNew,
+    ) -> CustomResult<storage::Payout, errors::StorageError> {
+        let mut payouts = self.payouts.lock().await;
+        let current_time = common_utils::date_time::now();
+
+        let payout = storage::Payout {
+            #[allow(clippy::as_conversions)]
+            id: payouts.len() as i32,
+            payout_id: payout.payout_id,
+            merchant_id: payout.merchant_id,
+            customer_id: payout.customer_id,
+            amount: payout.amount,
+            currency: payout.currency,
+            payout_type: payout.payout_type,
+            status: payout.status,
+            connector: payout.connector,
+            description: payout.description,
+            metadata: payout.metadata,
+            created_at: current_time,
+            modified_at: current_time,
+        };
+        payouts.push(payout.clone());
+        Ok(payout)
+    }
+
+    async fn find_payout_by_merchant_id_payout_id(
+        &self,
+        merchant_id: &str,
+        payout_id: &str,
+    ) -> CustomResult<storage::Payout, errors::StorageError> {
+        let payouts = self.payouts.lock().await;
+
+        payouts
+            .iter()
+            .find(|payout| payout.merchant_id == merchant_id && payout.payout_id == payout_id)
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "No payout found with payout_id = {payout_id}"
+                ))
+            })
+            .into_report()
+    }
+
+    async fn update_payout(
+        &self,
+        this: storage::Payout,
+        payout: storage::PayoutUpdate,
+    ) -> CustomResult<storage::Payout, errors::StorageError> {
+        let mut payouts = self.payouts.lock().await;
+        let item = payouts
+            .iter_mut()
+            .find(|item| item.id == this.id)
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "No payout found with payout_id = {}",
+                    this.payout_id
+                ))
+            })
+            .into_report()?;
+        *item = payout.apply_changeset(item.clone());
+        Ok(item.clone())
+    }
+}
@@ -0,0 +1,141 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait PayoutAttemptInterface {
+    async fn insert_payout_attempt(
+        &self,
+        payout_attempt: storage::PayoutAttemptNew,
+    ) -> CustomResult<storage::PayoutAttempt, errors::StorageError>;
+
+    async fn find_payout_attempt_by_merchant_id_payout_attempt_id(
+        &self,
+        merchant_id: &str,
+        payout_attempt_id: &str,
+    ) -> CustomResult<storage::PayoutAttempt, errors::StorageError>;
+
+    async fn update_payout_attempt(
+        &self,
+        this: storage::PayoutAttempt,
+        payout_attempt: storage::PayoutAttemptUpdate,
+    ) -> CustomResult<storage::PayoutAttempt, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PayoutAttemptInterface for Store {
+    async fn insert_payout_attempt(
+        &self,
+        payout_attempt: storage::PayoutAttemptNew,
+    ) -> CustomResult<storage::PayoutAttempt, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        payout_attempt
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_payout_attempt_by_merchant_id_payout_attempt_id(
+        &self,
+        merchant_id: &str,
+        payout_attempt_id: &str,
+    ) -> CustomResult<storage::PayoutAttempt, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::PayoutAttempt::find_by_merchant_id_payout_attempt_id(
+            &conn,
+            merchant_id,
+            payout_attempt_id,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn update_payout_attempt(
+        &self,
+        this: storage::PayoutAttempt,
+        payout_attempt: storage::PayoutAttemptUpdate,
+    ) -> CustomResult<storage::PayoutAttempt, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        this.update(&conn, payout_attempt)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl PayoutAttemptInterface for MockDb {
+    async fn insert_payout_attempt(
+        &self,
+        payout_attempt: storage::PayoutAttemptNew,
+    ) -> CustomResult<storage::PayoutAttempt, errors::StorageError> {
+        let mut payout_attempts = self.payout_attempts.lock().await;
+        let current_time = common_utils::date_time::now();
+
+        let payout_attempt = storage::PayoutAttempt {
+            #[allow(clippy::as_conversions)]
+            id: payout_attempts.len() as i32,
+            payout_attempt_id: payout_attempt.payout_attempt_id,
+            payout_id: payout_attempt.payout_id,
+            merchant_id: payout_attempt.merchant_id,
+            connector: payout_attempt.connector,
+            connector_payout_id: payout_attempt.connector_payout_id,
+            status: payout_attempt.status,
+            error_code: payout_attempt.error_code,
+            error_message: payout_attempt.error_message,
+            created_at: current_time,
+            modified_at: current_time,
+        };
+        payout_attempts.push(payout_attempt.clone());
+        Ok(payout_attempt)
+    }
+
+    async fn find_payout_attempt_by_merchant_id_payout_attempt_id(
+        &self,
+        merchant_id: &str,
+        payout_attempt_id: &str,
+    ) -> CustomResult<storage::PayoutAttempt, errors::StorageError> {
+        let payout_attempts = self.payout_attempts.lock().await;
+
+        payout_attempts
+            .iter()
+            .find(|payout_attempt| {
+                payout_attempt.merchant_id == merchant_id
+                    && payout_attempt.payout_attempt_id == payout_attempt_id
+            })
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "No payout attempt found with payout_attempt_id = {payout_attempt_id}"
+                ))
+            })
+            .into_report()
+    }
+
+    async fn update_payout_attempt(
+        &self,
+        this: storage::PayoutAttempt,
+        payout_attempt: storage::PayoutAttemptUpdate,
+    ) -> CustomResult<storage::PayoutAttempt, errors::StorageError> {
+        let mut payout_attempts = self.payout_attempts.lock().await;
+        let item = payout_attempts
+            .iter_mut()
+            .find(|item| item.id == this.id)
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "No payout attempt found with payout_attempt_id = {}",
+                    this.payout_attempt_id
+                ))
+            })
+            .into_report()?;
+        *item = payout_attempt.apply_changeset(item.clone());
+        Ok(item.clone())
+    }
+}
@@ -50,6 +50,13 @@ pub trait ProcessTrackerInterface {
         status: enums::ProcessTrackerStatus,
         limit: Option<i64>,
     ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
+
+    async fn find_processes_by_status_business_status(
+        &self,
+        status: enums::ProcessTrackerStatus,
+        business_status: &str,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -97,6 +104,24 @@ impl ProcessTrackerInterface for Store {
         .into_report()
     }
 
+    async fn find_processes_by_status_business_status(
+        &self,
+        status: enums::ProcessTrackerStatus,
+        business_status: &str,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::ProcessTracker::find_processes_by_status_business_status(
+            &conn,
+            status,
+            business_status,
+            limit,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
     async fn insert_process(
         &self,
         new: storage::ProcessTrackerNew,
@@ -182,6 +207,26 @@ impl ProcessTrackerInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    async fn find_processes_by_status_business_status(
+        &self,
+        status: enums::ProcessTrackerStatus,
+        business_status: &str,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        let processes = self.processes.lock().await;
+        let matching_processes = processes
+            .iter()
+            .filter(|process| {
+                process.status == status && process.business_status == business_status
+            })
+            .cloned();
+
+        Ok(match limit.and_then(|limit| usize::try_from(limit).ok()) {
+            Some(limit) => matching_processes.take(limit).collect(),
+            None => matching_processes.collect(),
+        })
+    }
+
     async fn insert_process(
         &self,
         new: storage::ProcessTrackerNew,
@@ -0,0 +1,264 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait ReconInterface {
+    async fn insert_recon_report(
+        &self,
+        recon_report: storage::ReconReportNew,
+    ) -> CustomResult<storage::ReconReport, errors::StorageError>;
+
+    async fn find_recon_report_by_merchant_id_report_id(
+        &self,
+        merchant_id: &str,
+        report_id: &str,
+    ) -> CustomResult<storage::ReconReport, errors::StorageError>;
+
+    async fn filter_recon_reports_by_constraints(
+        &self,
+        merchant_id: &str,
+        constraints: &api_models::recon::ReconReportListConstraints,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::ReconReport>, errors::StorageError>;
+
+    async fn update_recon_report(
+        &self,
+        this: storage::ReconReport,
+        recon_report: storage::ReconReportUpdate,
+    ) -> CustomResult<storage::ReconReport, errors::StorageError>;
+
+    async fn insert_recon_mismatches(
+        &self,
+        recon_mismatches: Vec<storage::ReconMismatchNew>,
+    ) -> CustomResult<(), errors::StorageError>;
+
+    async fn find_recon_mismatches_by_merchant_id_report_id(
+        &self,
+        merchant_id: &str,
+        report_id: &str,
+    ) -> CustomResult<Vec<storage::ReconMismatch>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ReconInterface for Store {
+    async fn insert_recon_report(
+        &self,
+        recon_report: storage::ReconReportNew,
+    ) -> CustomResult<storage::ReconReport, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        recon_report
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_recon_report_by_merchant_id_report_id(
+        &self,
+        merchant_id: &str,
+        report_id: &str,
+    ) -> CustomResult<storage::ReconReport, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::ReconReport::find_by_merchant_id_report_id(&conn, merchant_id, report_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn filter_recon_reports_by_constraints(
+        &self,
+        merchant_id: &str,
+        constraints: &api_models::recon::ReconReportListConstraints,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::ReconReport>, errors::StorageError> {
+        let conn = pg_connection(self.read_pool()).await;
+        <storage::ReconReport as storage::ReconReportDbExt>::filter_by_constraints(
+            &conn,
+            merchant_id,
+            constraints,
+            limit,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn update_recon_report(
+        &self,
+        this: storage::ReconReport,
+        recon_report: storage::ReconReportUpdate,
+    ) -> CustomResult<storage::ReconReport, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        this.update(&conn, recon_report)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn insert_recon_mismatches(
+        &self,
+        recon_mismatches: Vec<storage::ReconMismatchNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::ReconMismatchNew::batch_insert(recon_mismatches, &conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_recon_mismatches_by_merchant_id_report_id(
+        &self,
+        merchant_id: &str,
+        report_id: &str,
+    ) -> CustomResult<Vec<storage::ReconMismatch>, errors::StorageError> {
+        let conn = pg_connection(self.read_pool()).await;
+        storage::ReconMismatch::find_by_merchant_id_report_id(&conn, merchant_id, report_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl ReconInterface for MockDb {
+    async fn insert_recon_report(
+        &self,
+        recon_report: storage::ReconReportNew,
+    ) -> CustomResult<storage::ReconReport, errors::StorageError> {
+        let mut recon_reports = self.recon_reports.lock().await;
+        let current_time = common_utils::date_time::now();
+
+        let recon_report = storage::ReconReport {
+            #[allow(clippy::as_conversions)]
+            id: recon_reports.len() as i32,
+            report_id: recon_report.report_id,
+            merchant_id: recon_report.merchant_id,
+            connector: recon_report.connector,
+            status: recon_report.status,
+            file_content: recon_report.file_content,
+            total_entries: None,
+            matched_entries: None,
+            mismatched_entries: None,
+            error_message: None,
+            created_at: current_time,
+            modified_at: current_time,
+        };
+        recon_reports.push(recon_report.clone());
+        Ok(recon_report)
+    }
+
+    async fn find_recon_report_by_merchant_id_report_id(
+        &self,
+        merchant_id: &str,
+        report_id: &str,
+    ) -> CustomResult<storage::ReconReport, errors::StorageError> {
+        let recon_reports = self.recon_reports.lock().await;
+
+        recon_reports
+            .iter()
+            .find(|recon_report| {
+                recon_report.merchant_id == merchant_id && recon_report.report_id == report_id
+            })
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "No recon report found with report_id = {report_id}"
+                ))
+            })
+            .into_report()
+    }
+
+    async fn filter_recon_reports_by_constraints(
+        &self,
+        merchant_id: &str,
+        constraints: &api_models::recon::ReconReportListConstraints,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::ReconReport>, errors::StorageError> {
+        let recon_reports = self.recon_reports.lock().await;
+
+        Ok(recon_reports
+            .iter()
+            .rev()
+            .filter(|recon_report| {
+                recon_report.merchant_id == merchant_id
+                    && constraints
+                        .connector
+                        .as_ref()
+                        .map_or(true, |connector| &recon_report.connector == connector)
+            })
+            .take(usize::try_from(limit).unwrap_or(0))
+            .cloned()
+            .collect())
+    }
+
+    async fn update_recon_report(
+        &self,
+        this: storage::ReconReport,
+        recon_report: storage::ReconReportUpdate,
+    ) -> CustomResult<storage::ReconReport, errors::StorageError> {
+        let mut recon_reports = self.recon_reports.lock().await;
+        let item = recon_reports
+            .iter_mut()
+            .find(|item| item.id == this.id)
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound(format!(
+                    "No recon report found with report_id = {}",
+                    this.report_id
+                ))
+            })
+            .into_report()?;
+        *item = recon_report.apply_changeset(item.clone());
+        Ok(item.clone())
+    }
+
+    async fn insert_recon_mismatches(
+        &self,
+        recon_mismatches: Vec<storage::ReconMismatchNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        let mut stored_mismatches = self.recon_mismatches.lock().await;
+        let current_time = common_utils::date_time::now();
+
+        for recon_mismatch in recon_mismatches {
+            let recon_mismatch = storage::ReconMismatch {
+                #[allow(clippy::as_conversions)]
+                id: stored_mismatches.len() as i32,
+                report_id: recon_mismatch.report_id,
+                merchant_id: recon_mismatch.merchant_id,
+                entry_type: recon_mismatch.entry_type,
+                connector_transaction_id: recon_mismatch.connector_transaction_id,
+                amount: recon_mismatch.amount,
+                currency: recon_mismatch.currency,
+                reason: recon_mismatch.reason,
+                expected_amount: recon_mismatch.expected_amount,
+                expected_currency: recon_mismatch.expected_currency,
+                payment_id: recon_mismatch.payment_id,
+                refund_id: recon_mismatch.refund_id,
+                created_at: current_time,
+            };
+            stored_mismatches.push(recon_mismatch);
+        }
+        Ok(())
+    }
+
+    async fn find_recon_mismatches_by_merchant_id_report_id(
+        &self,
+        merchant_id: &str,
+        report_id: &str,
+    ) -> CustomResult<Vec<storage::ReconMismatch>, errors::StorageError> {
+        let recon_mismatches = self.recon_mismatches.lock().await;
+
+        Ok(recon_mismatches
+            .iter()
+            .filter(|recon_mismatch| {
+                recon_mismatch.merchant_id == merchant_id && recon_mismatch.report_id == report_id
+            })
+            .cloned()
+            .collect())
+    }
+}
@@ -50,6 +50,14 @@ pub trait RefundInterface {
         storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<storage_types::Refund>, errors::StorageError>;
 
+    async fn find_refund_by_merchant_id_connector_refund_id_connector(
+        &self,
+        merchant_id: &str,
+        connector_refund_id: &str,
+        connector: &str,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Refund, errors::StorageError>;
+
     async fn insert_refund(
         &self,
         new: storage_types::RefundNew,
@@ -64,6 +72,15 @@ pub trait RefundInterface {
         storage_scheme: enums::MerchantStorageScheme,
         limit: i64,
     ) -> CustomResult<Vec<storage_models::refund::Refund>, errors::StorageError>;
+
+    /// Finds at most `limit` refunds created by the merchant in the time range `[start, end)`.
+    async fn find_refunds_by_merchant_id_created_between(
+        &self,
+        merchant_id: &str,
+        start: time::PrimitiveDateTime,
+        end: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<storage_types::Refund>, errors::StorageError>;
 }
 
 #[cfg(not(feature = "kv_store"))]
@@ -103,7 +120,15 @@ mod storage {
             _storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<storage_types::Refund, errors::StorageError> {
             let conn = pg_connection(&self.master_pool).await;
-            new.insert(&conn).await.map_err(Into::into).into_report()
+            let created_refund = new
+                .insert_with_refund_created_event(&conn)
+                .await
+                .map_err(Into::into)
+                .into_report()?;
+
+            #[cfg(feature = "kafka_events")]
+            self.kafka_producer.log_event(&created_refund);
+            Ok(created_refund)
         }
 
         async fn find_refund_by_merchant_id_connector_transaction_id(
@@ -123,6 +148,25 @@ mod storage {
             .into_report()
         }
 
+        async fn find_refund_by_merchant_id_connector_refund_id_connector(
+            &self,
+            merchant_id: &str,
+            connector_refund_id: &str,
+            connector: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Refund, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await;
+            storage_types::Refund::find_by_merchant_id_connector_refund_id_connector(
+                &conn,
+                merchant_id,
+                connector_refund_id,
+                connector,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        }
+
         async fn update_refund(
             &self,
             this: storage_types::Refund,
@@ -130,10 +174,15 @@ mod storage {
             _storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<storage_types::Refund, errors::StorageError> {
             let conn = pg_connection(&self.master_pool).await;
-            this.update(&conn, refund)
+            let updated_refund = this
+                .update(&conn, refund)
                 .await
                 .map_err(Into::into)
-                .into_report()
+                .into_report()?;
+
+            #[cfg(feature = "kafka_events")]
+            self.kafka_producer.log_event(&updated_refund);
+            Ok(updated_refund)
         }
 
         async fn find_refund_by_merchant_id_refund_id(
@@ -181,7 +230,7 @@ mod storage {
             _storage_scheme: enums::MerchantStorageScheme,
             limit: i64,
         ) -> CustomResult<Vec<storage_models::refund::Refund>, errors::StorageError> {
-            let conn = pg_connection(&self.replica_pool).await;
+            let conn = pg_connection(self.read_pool()).await;
             <storage_models::refund::Refund as storage_types::RefundDbExt>::filter_by_constraints(
                 &conn,
                 merchant_id,
@@ -192,6 +241,26 @@ mod storage {
             .map_err(Into::into)
             .into_report()
         }
+
+        async fn find_refunds_by_merchant_id_created_between(
+            &self,
+            merchant_id: &str,
+            start: time::PrimitiveDateTime,
+            end: time::PrimitiveDateTime,
+            limit: i64,
+        ) -> CustomResult<Vec<storage_types::Refund>, errors::StorageError> {
+            let conn = pg_connection(self.read_pool()).await;
+            storage_types::Refund::find_by_merchant_id_created_between(
+                &conn,
+                merchant_id,
+                start,
+                end,
+                limit,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        }
     }
 }
 
@@ -211,7 +280,7 @@ mod storage {
         types::storage::{self as storage_types, enums, kv},
         utils::{
             self, db_utils,
-            storage_partitioning::{KvStorePartition, PartitionKey},
+            storage_partitioning::{self, KvStorePartition, PartitionKey},
         },
     };
     #[async_trait::async_trait]
@@ -260,13 +329,20 @@ mod storage {
             new: storage_types::RefundNew,
             storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<storage_types::Refund, errors::StorageError> {
-            match storage_scheme {
+            let created_refund = match storage_scheme {
                 enums::MerchantStorageScheme::PostgresOnly => {
                     let conn = pg_connection(&self.master_pool).await;
-                    new.insert(&conn).await.map_err(Into::into).into_report()
+                    new.insert_with_refund_created_event(&conn)
+                        .await
+                        .map_err(Into::into)
+                        .into_report()
                 }
                 enums::MerchantStorageScheme::RedisKv => {
-                    let key = format!("{}_{}", new.merchant_id, new.payment_id);
+                    let key = storage_partitioning::payment_hash_key(
+                        &new.merchant_id,
+                        &new.payment_id,
+                        self.config.hash_tagged_keys,
+                    );
                     // TODO: need to add an application generated payment attempt id to distinguish between multiple attempts for the same payment id
                     // Check for database presence as well Maybe use a read replica here ?
                     let created_refund = storage_types::Refund {
@@ -293,6 +369,8 @@ mod storage {
                         updated_at: new.created_at.unwrap_or_else(date_time::now),
                         description: new.description.clone(),
                         refund_reason: new.refund_reason.clone(),
+                        alternate_connector_label: new.alternate_connector_label.clone(),
+                        reconciliation_required: new.reconciliation_required,
                     };
 
                     let field = format!(
@@ -349,10 +427,15 @@ mod storage {
                                 .await
                                 .change_context(errors::StorageError::KVError)?;
 
+                            let outbox_event =
+                                storage_types::OutboxEventNew::refund_created(&created_refund)
+                                    .into_report()
+                                    .change_context(errors::StorageError::SerializationFailed)?;
                             let redis_entry = kv::TypedSql {
                                 op: kv::DBOperation::Insert {
                                     insertable: kv::Insertable::Refund(new),
                                 },
+                                outbox_event: Some(outbox_event),
                             };
 
                             let stream_name =
@@ -378,7 +461,11 @@ mod storage {
                         Err(er) => Err(er).change_context(errors::StorageError::KVError),
                     }
                 }
-            }
+            }?;
+
+            #[cfg(feature = "kafka_events")]
+            self.kafka_producer.log_event(&created_refund);
+            Ok(created_refund)
         }
 
         async fn find_refund_by_merchant_id_connector_transaction_id(
@@ -420,13 +507,46 @@ mod storage {
             }
         }
 
+        async fn find_refund_by_merchant_id_connector_refund_id_connector(
+            &self,
+            merchant_id: &str,
+            connector_refund_id: &str,
+            connector: &str,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Refund, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await;
+            let refund = storage_types::Refund::find_by_merchant_id_connector_refund_id_connector(
+                &conn,
+                merchant_id,
+                connector_refund_id,
+                connector,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()?;
+
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => Ok(refund),
+                // There is no lookup for the connector refund id in Redis, so the refund is looked
+                // up in the database and its latest version is read from Redis
+                enums::MerchantStorageScheme::RedisKv => {
+                    self.find_refund_by_merchant_id_refund_id(
+                        merchant_id,
+                        &refund.refund_id,
+                        storage_scheme,
+                    )
+                    .await
+                }
+            }
+        }
+
         async fn update_refund(
             &self,
             this: storage_types::Refund,
             refund: storage_types::RefundUpdate,
             storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<storage_types::Refund, errors::StorageError> {
-            match storage_scheme {
+            let updated_refund = match storage_scheme {
                 enums::MerchantStorageScheme::PostgresOnly => {
                     let conn = pg_connection(&self.master_pool).await;
                     this.update(&conn, refund)
@@ -435,13 +555,18 @@ mod storage {
                         .into_report()
                 }
                 enums::MerchantStorageScheme::RedisKv => {
-                    let key = format!("{}_{}", this.merchant_id, this.payment_id);
+                    let lookup_id = format!("{}_{}", this.merchant_id, this.payment_id);
+                    let key = storage_partitioning::payment_hash_key(
+                        &this.merchant_id,
+                        &this.payment_id,
+                        self.config.hash_tagged_keys,
+                    );
 
                     let updated_refund = refund.clone().apply_changeset(this.clone());
                     // Check for database presence as well Maybe use a read replica here ?
 
                     let lookup = self
-                        .get_lookup_by_lookup_id(&key)
+                        .get_lookup_by_lookup_id(&lookup_id)
                         .await
                         .map_err(Into::<errors::StorageError>::into)
                         .into_report()?;
@@ -474,6 +599,7 @@ mod storage {
                                 update_data: refund,
                             }),
                         },
+                        outbox_event: None,
                     };
                     self.redis_conn
                         .stream_append_entry(
@@ -487,7 +613,11 @@ mod storage {
                         .change_context(errors::StorageError::KVError)?;
                     Ok(updated_refund)
                 }
-            }
+            }?;
+
+            #[cfg(feature = "kafka_events")]
+            self.kafka_producer.log_event(&updated_refund);
+            Ok(updated_refund)
         }
 
         async fn find_refund_by_merchant_id_refund_id(
@@ -559,9 +689,14 @@ mod storage {
                     .into_report()
                 }
                 enums::MerchantStorageScheme::RedisKv => {
-                    let key = format!("{}_{}", merchant_id, payment_id);
+                    let lookup_id = format!("{merchant_id}_{payment_id}");
+                    let key = storage_partitioning::payment_hash_key(
+                        merchant_id,
+                        payment_id,
+                        self.config.hash_tagged_keys,
+                    );
                     let lookup = self
-                        .get_lookup_by_lookup_id(&key)
+                        .get_lookup_by_lookup_id(&lookup_id)
                         .await
                         .map_err(Into::<errors::StorageError>::into)
                         .into_report()?;
@@ -586,16 +721,62 @@ mod storage {
         ) -> CustomResult<Vec<storage_models::refund::Refund>, errors::StorageError> {
             match storage_scheme {
                 enums::MerchantStorageScheme::PostgresOnly => {
-                    let conn = pg_connection(&self.replica_pool).await;
+                    let conn = pg_connection(self.read_pool()).await;
                     <storage_models::refund::Refund as storage_types::RefundDbExt>::filter_by_constraints(&conn, merchant_id, refund_details, limit)
                         .await
                         .map_err(Into::into)
                         .into_report()
                 }
 
-                enums::MerchantStorageScheme::RedisKv => Err(errors::StorageError::KVError.into()),
+                // The refunds of a payment are read from Redis, the refunds across payments are
+                // listed from the database which is kept up to date by the drainer
+                enums::MerchantStorageScheme::RedisKv => match &refund_details.payment_id {
+                    Some(payment_id) => {
+                        let refunds = self
+                            .find_refund_by_payment_id_merchant_id(
+                                payment_id,
+                                merchant_id,
+                                storage_scheme,
+                            )
+                            .await?;
+                        Ok(storage_types::filter_refunds_by_constraints(
+                            refunds,
+                            refund_details,
+                            limit,
+                        ))
+                    }
+                    None => {
+                        let conn = pg_connection(self.read_pool()).await;
+                        <storage_models::refund::Refund as storage_types::RefundDbExt>::filter_by_constraints(&conn, merchant_id, refund_details, limit)
+                            .await
+                            .map_err(Into::into)
+                            .into_report()
+                    }
+                },
             }
         }
+
+        async fn find_refunds_by_merchant_id_created_between(
+            &self,
+            merchant_id: &str,
+            start: time::PrimitiveDateTime,
+            end: time::PrimitiveDateTime,
+            limit: i64,
+        ) -> CustomResult<Vec<storage_types::Refund>, errors::StorageError> {
+            // The refunds are listed from the database regardless of the storage scheme, as it is
+            // kept up to date by the drainer
+            let conn = pg_connection(self.read_pool()).await;
+            storage_types::Refund::find_by_merchant_id_created_between(
+                &conn,
+                merchant_id,
+                start,
+                end,
+                limit,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        }
     }
 }
 
@@ -644,6 +825,8 @@ impl RefundInterface for MockDb {
             updated_at: current_time,
             description: new.description,
             refund_reason: new.refund_reason.clone(),
+            alternate_connector_label: new.alternate_connector_label,
+            reconciliation_required: new.reconciliation_required,
         };
         refunds.push(refund.clone());
         Ok(refund)
@@ -658,7 +841,7 @@ impl RefundInterface for MockDb {
 
         Ok(refunds
             .iter()
-            .take_while(|refund| {
+            .filter(|refund| {
                 refund.merchant_id == merchant_id
                     && refund.connector_transaction_id == connector_transaction_id
             })
@@ -666,6 +849,28 @@ impl RefundInterface for MockDb {
             .collect::<Vec<_>>())
     }
 
+    async fn find_refund_by_merchant_id_connector_refund_id_connector(
+        &self,
+        merchant_id: &str,
+        connector_refund_id: &str,
+        connector: &str,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Refund, errors::StorageError> {
+        let refunds = self.refunds.lock().await;
+
+        refunds
+            .iter()
+            .find(|refund| {
+                refund.merchant_id == merchant_id
+                    && refund.connector_refund_id.as_deref() == Some(connector_refund_id)
+                    && refund.connector == connector
+            })
+            .cloned()
+            .ok_or_else(|| {
+                errors::StorageError::DatabaseError(DatabaseError::NotFound.into()).into()
+            })
+    }
+
     async fn update_refund(
         &self,
         _this: storage_types::Refund,
@@ -706,12 +911,43 @@ impl RefundInterface for MockDb {
     #[cfg(feature = "olap")]
     async fn filter_refund_by_constraints(
         &self,
-        _merchant_id: &str,
-        _refund_details: &api_models::refunds::RefundListRequest,
+        merchant_id: &str,
+        refund_details: &api_models::refunds::RefundListRequest,
         _storage_scheme: enums::MerchantStorageScheme,
-        _limit: i64,
+        limit: i64,
     ) -> CustomResult<Vec<storage_models::refund::Refund>, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let refunds = self.refunds.lock().await;
+        let merchant_refunds = refunds
+            .iter()
+            .filter(|refund| refund.merchant_id == merchant_id)
+            .cloned()
+            .collect();
+
+        Ok(storage_types::filter_refunds_by_constraints(
+            merchant_refunds,
+            refund_details,
+            limit,
+        ))
+    }
+
+    async fn find_refunds_by_merchant_id_created_between(
+        &self,
+        merchant_id: &str,
+        start: time::PrimitiveDateTime,
+        end: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<storage_types::Refund>, errors::StorageError> {
+        let refunds = self.refunds.lock().await;
+
+        Ok(refunds
+            .iter()
+            .filter(|refund| {
+                refund.merchant_id == merchant_id
+                    && refund.created_at >= start
+                    && refund.created_at < end
+            })
+            .take(usize::try_from(limit).unwrap_or(usize::MAX))
+            .cloned()
+            .collect())
     }
 }
@@ -0,0 +1,58 @@
+use error_stack::ResultExt;
+use redis_interface::RedisConnectionPool;
+
+use super::{MockDb, Store};
+use crate::core::errors::{self, CustomResult};
+
+#[async_trait::async_trait]
+pub trait VelocityInterface {
+    /// Counts a payment attempt on a velocity counter, returns the number of attempts counted in
+    /// the current window of the counter, including this one.
+    async fn increment_velocity_counter(
+        &self,
+        key: &str,
+        window: i64,
+    ) -> CustomResult<i64, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl VelocityInterface for Store {
+    async fn increment_velocity_counter(
+        &self,
+        key: &str,
+        window: i64,
+    ) -> CustomResult<i64, errors::StorageError> {
+        increment_velocity_counter(&self.redis_conn, key, window).await
+    }
+}
+
+#[async_trait::async_trait]
+impl VelocityInterface for MockDb {
+    async fn increment_velocity_counter(
+        &self,
+        key: &str,
+        window: i64,
+    ) -> CustomResult<i64, errors::StorageError> {
+        increment_velocity_counter(&self.redis, key, window).await
+    }
+}
+
+async fn increment_velocity_counter(
+    redis_conn: &RedisConnectionPool,
+    key: &str,
+    window: i64,
+) -> CustomResult<i64, errors::StorageError> {
+    // The counter is created with the window as its expiry before being incremented, so that it
+    // is reset once the window has passed even if the increment fails
+    redis_conn
+        .set_key_if_not_exists_with_expiry(key, 0_i64, window)
+        .await
+        .change_context(errors::StorageError::KVError)
+        .attach_printable("DB error when creating velocity counter")?;
+
+    redis_conn
+        .increment_key(key)
+        .await
+        .change_context(errors::StorageError::KVError)
+        .attach_printable("DB error when incrementing velocity counter")
+}
@@ -0,0 +1,219 @@
+//! gRPC surface of the payments API, for internal services which call the router at a high rate.
+//!
+//! The requests run through the same core flows as those of the REST API, and are authenticated
+//! the same way, with the headers read from the metadata of the request.
+
+pub mod payments;
+
+use std::{future::Future, net::ToSocketAddrs};
+
+use actix_web::{http::header::HeaderMap, ResponseError};
+use error_stack::Report;
+
+use crate::{
+    core::errors::{self, ApplicationError, ApplicationResult, RouterResponse},
+    logger,
+    routes::AppState,
+    services::{self, authentication as auth},
+};
+
+pub mod proto {
+    #![allow(
+        unused_qualifications,
+        rust_2018_idioms,
+        clippy::all,
+        clippy::use_self,
+        clippy::as_conversions
+    )]
+
+    tonic::include_proto!("payments");
+}
+
+/// Serves the gRPC services on the configured address, until the server fails.
+pub async fn start_server(state: AppState) -> ApplicationResult<()> {
+    let conf = state.conf.grpc_server.clone();
+    let address = (conf.host.as_str(), conf.port)
+        .to_socket_addrs()?
+        .next()
+        .ok_or_else(|| {
+            ApplicationError::InvalidConfigurationValueError(
+                "gRPC server address could not be resolved".into(),
+            )
+        })?;
+
+    logger::info!(%address, "gRPC server started");
+    tonic::transport::Server::builder()
+        .add_service(proto::payments_server::PaymentsServer::new(
+            payments::PaymentsService::new(state),
+        ))
+        .serve(address)
+        .await
+        .map_err(ApplicationError::GrpcServerError)
+}
+
+/// Counterpart of `services::api::server_wrap` for gRPC requests, which authenticates the request
+/// with the headers sent in its metadata before running the flow.
+pub(crate) async fn grpc_wrap<'a, T, U, Q, F, Fut>(
+    state: &'a AppState,
+    metadata: &tonic::metadata::MetadataMap,
+    payload: T,
+    func: F,
+    api_auth: &dyn auth::AuthenticateAndFetch<U>,
+) -> Result<Q, tonic::Status>
+where
+    F: FnOnce(&'a AppState, U, T) -> Fut,
+    Fut: Future<Output = RouterResponse<Q>>,
+{
+    let result = async {
+        services::api::shed_load_if_overloaded(state)?;
+
+        let headers = HeaderMap::from(metadata.clone().into_headers());
+        let auth_out = api_auth.authenticate_and_fetch(&headers, state).await?;
+        func(state, auth_out, payload).await
+    }
+    .await;
+
+    match result {
+        Ok(services::ApplicationResponse::Json(response)) => Ok(response),
+        Ok(_) => {
+            logger::error!("Flow returned a response which cannot be sent over gRPC");
+            Err(tonic::Status::internal("Something went wrong"))
+        }
+        Err(error) => Err(to_grpc_status(error)),
+    }
+}
+
+/// Maps the error to the gRPC status closest to the HTTP status code it is returned with by the
+/// REST API. The message is the same error object as in the body of the REST API response.
+fn to_grpc_status(error: Report<errors::ApiErrorResponse>) -> tonic::Status {
+    logger::error!(?error);
+    let api_error = error.current_context();
+
+    let code = match api_error.status_code().as_u16() {
+        401 => tonic::Code::Unauthenticated,
+        403 => tonic::Code::PermissionDenied,
+        404 => tonic::Code::NotFound,
+        409 => tonic::Code::AlreadyExists,
+        412 | 422 => tonic::Code::FailedPrecondition,
+        429 => tonic::Code::ResourceExhausted,
+        501 => tonic::Code::Unimplemented,
+        503 => tonic::Code::Unavailable,
+        504 => tonic::Code::DeadlineExceeded,
+        400..=499 => tonic::Code::InvalidArgument,
+        _ => tonic::Code::Internal,
+    };
+
+    tonic::Status::new(code, api_error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use masking::PeekInterface;
+    use proto::payments_server::Payments;
+
+    use super::*;
+    use crate::{configs::settings::Settings, core::api_keys, db::StorageImpl, types::storage};
+
+    async fn app_state_with_api_key(merchant_id: &str) -> (AppState, String) {
+        let conf = Settings::new().unwrap();
+        let state = AppState::with_storage(conf, StorageImpl::Mock)
+            .await
+            .unwrap();
+        state
+            .store
+            .insert_merchant(storage::MerchantAccountNew {
+                merchant_id: merchant_id.to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        let (_, api_key) = api_keys::insert_api_key(
+            &*state.store,
+            merchant_id.to_string(),
+            "gRPC".to_string(),
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+        (state, api_key.peek().clone())
+    }
+
+    fn metadata_with_api_key(api_key: &str) -> tonic::metadata::MetadataMap {
+        let mut metadata = tonic::metadata::MetadataMap::new();
+        metadata.insert("api-key", api_key.parse().unwrap());
+        metadata
+    }
+
+    #[actix_rt::test]
+    async fn test_grpc_call_is_authenticated_with_api_key() {
+        let (state, api_key) = app_state_with_api_key("grpc_merchant").await;
+        let authenticated_merchant = |metadata| {
+            let state = &state;
+            async move {
+                grpc_wrap(
+                    state,
+                    &metadata,
+                    (),
+                    |_, merchant_account: storage::MerchantAccount, _| async move {
+                        Ok(services::ApplicationResponse::Json(
+                            merchant_account.merchant_id,
+                        ))
+                    },
+                    &auth::ApiKeyAuth,
+                )
+                .await
+            }
+        };
+
+        let merchant_id = authenticated_merchant(metadata_with_api_key(&api_key))
+            .await
+            .unwrap();
+        assert_eq!(merchant_id, "grpc_merchant");
+
+        let status = authenticated_merchant(metadata_with_api_key("not_an_api_key"))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Unauthenticated);
+
+        let status = authenticated_merchant(tonic::metadata::MetadataMap::new())
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Unauthenticated);
+    }
+
+    #[actix_rt::test]
+    async fn test_payments_service_rejects_invalid_api_key() {
+        let (state, _) = app_state_with_api_key("grpc_merchant").await;
+        let service = payments::PaymentsService::new(state);
+
+        let mut request = tonic::Request::new(proto::PaymentsRetrieveRequest {
+            payment_id: "pay_grpc".to_string(),
+            force_sync: false,
+        });
+        request
+            .metadata_mut()
+            .insert("api-key", "not_an_api_key".parse().unwrap());
+
+        let status = service.retrieve(request).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Unauthenticated);
+    }
+
+    #[test]
+    fn test_api_errors_are_mapped_to_grpc_codes() {
+        let status = to_grpc_status(error_stack::report!(
+            errors::ApiErrorResponse::PaymentNotFound
+        ));
+        assert_eq!(status.code(), tonic::Code::NotFound);
+
+        let status = to_grpc_status(error_stack::report!(errors::ApiErrorResponse::Unauthorized));
+        assert_eq!(status.code(), tonic::Code::Unauthenticated);
+
+        let status = to_grpc_status(error_stack::report!(
+            errors::ApiErrorResponse::InternalServerError
+        ));
+        assert_eq!(status.code(), tonic::Code::Internal);
+    }
+}
@@ -0,0 +1,298 @@
+use std::str::FromStr;
+
+use common_utils::types::MinorUnit;
+use masking::Secret;
+use router_env::{instrument, tracing, Flow};
+
+use super::{grpc_wrap, proto};
+use crate::{
+    core::{payments, refunds},
+    routes::{payments as payment_routes, AppState},
+    services::{api, authentication as auth},
+    types::api::{self as api_types, enums as api_enums, payments as payment_types},
+};
+
+pub struct PaymentsService {
+    state: AppState,
+}
+
+impl PaymentsService {
+    pub fn new(state: AppState) -> Self {
+        Self { state }
+    }
+}
+
+#[tonic::async_trait]
+impl proto::payments_server::Payments for PaymentsService {
+    #[instrument(skip_all, fields(flow = ?Flow::PaymentsCreate))]
+    async fn create(
+        &self,
+        request: tonic::Request<proto::PaymentsCreateRequest>,
+    ) -> Result<tonic::Response<proto::PaymentsResponse>, tonic::Status> {
+        let (metadata, _, message) = request.into_parts();
+        let payload = payment_types::PaymentsRequest::try_from(message)?;
+
+        grpc_wrap(
+            &self.state,
+            &metadata,
+            payload,
+            |state, merchant_account, req| {
+                payment_routes::authorize_verify_select(
+                    payments::PaymentCreate,
+                    state,
+                    merchant_account,
+                    req,
+                    api::AuthFlow::Merchant,
+                )
+            },
+            &auth::ApiKeyAuth,
+        )
+        .await
+        .map(|response| tonic::Response::new(response.into()))
+    }
+
+    #[instrument(skip_all, fields(flow = ?Flow::PaymentsConfirm))]
+    async fn confirm(
+        &self,
+        request: tonic::Request<proto::PaymentsConfirmRequest>,
+    ) -> Result<tonic::Response<proto::PaymentsResponse>, tonic::Status> {
+        let (metadata, _, message) = request.into_parts();
+        let payload = payment_types::PaymentsRequest::from(message);
+
+        grpc_wrap(
+            &self.state,
+            &metadata,
+            payload,
+            |state, merchant_account, req| {
+                payment_routes::authorize_verify_select(
+                    payments::PaymentConfirm,
+                    state,
+                    merchant_account,
+                    req,
+                    api::AuthFlow::Merchant,
+                )
+            },
+            &auth::ApiKeyAuth,
+        )
+        .await
+        .map(|response| tonic::Response::new(response.into()))
+    }
+
+    #[instrument(skip_all, fields(flow = ?Flow::PaymentsRetrieve))]
+    async fn retrieve(
+        &self,
+        request: tonic::Request<proto::PaymentsRetrieveRequest>,
+    ) -> Result<tonic::Response<proto::PaymentsResponse>, tonic::Status> {
+        let (metadata, _, message) = request.into_parts();
+        let payload = payment_types::PaymentsRetrieveRequest {
+            resource_id: payment_types::PaymentIdType::PaymentIntentId(message.payment_id),
+            force_sync: message.force_sync,
+            ..Default::default()
+        };
+
+        grpc_wrap(
+            &self.state,
+            &metadata,
+            payload,
+            |state, merchant_account, req| {
+                payments::payments_core::<api_types::PSync, payment_types::PaymentsResponse, _, _, _>(
+                    state,
+                    merchant_account,
+                    payments::PaymentStatus,
+                    req,
+                    api::AuthFlow::Merchant,
+                    payments::CallConnectorAction::Trigger,
+                )
+            },
+            &auth::ApiKeyAuth,
+        )
+        .await
+        .map(|response| tonic::Response::new(response.into()))
+    }
+
+    #[instrument(skip_all, fields(flow = ?Flow::RefundsCreate))]
+    async fn refund(
+        &self,
+        request: tonic::Request<proto::RefundsCreateRequest>,
+    ) -> Result<tonic::Response<proto::RefundsResponse>, tonic::Status> {
+        let (metadata, _, message) = request.into_parts();
+        let payload = api_types::RefundRequest {
+            refund_id: message.refund_id,
+            payment_id: message.payment_id,
+            amount: message.amount,
+            reason: message.reason,
+            ..Default::default()
+        };
+
+        grpc_wrap(
+            &self.state,
+            &metadata,
+            payload,
+            refunds::refund_create_core,
+            &auth::ApiKeyAuth,
+        )
+        .await
+        .map(|response| tonic::Response::new(response.into()))
+    }
+}
+
+fn parse_enum<T: FromStr>(value: &str, field_name: &str) -> Result<T, tonic::Status> {
+    T::from_str(value)
+        .map_err(|_| tonic::Status::invalid_argument(format!("Invalid value for `{field_name}`")))
+}
+
+impl From<proto::Card> for api_types::CCard {
+    fn from(card: proto::Card) -> Self {
+        Self {
+            card_number: Secret::new(card.number),
+            card_exp_month: Secret::new(card.exp_month),
+            card_exp_year: Secret::new(card.exp_year),
+            card_holder_name: Secret::new(card.holder_name),
+            card_cvc: Secret::new(card.cvc),
+        }
+    }
+}
+
+impl TryFrom<proto::PaymentsCreateRequest> for payment_types::PaymentsRequest {
+    type Error = tonic::Status;
+
+    fn try_from(request: proto::PaymentsCreateRequest) -> Result<Self, Self::Error> {
+        let currency = parse_enum::<api_enums::Currency>(&request.currency, "currency")?;
+        let capture_method = request
+            .capture_method
+            .as_deref()
+            .map(|capture_method| parse_enum(capture_method, "capture_method"))
+            .transpose()?;
+
+        Ok(Self {
+            payment_id: request
+                .payment_id
+                .map(payment_types::PaymentIdType::PaymentIntentId),
+            amount: Some(request.amount.into()),
+            currency: Some(currency),
+            capture_method,
+            confirm: Some(request.confirm),
+            customer_id: request.customer_id,
+            description: request.description,
+            return_url: request.return_url,
+            payment_method: request
+                .card
+                .is_some()
+                .then_some(api_enums::PaymentMethodType::Card),
+            payment_method_data: request
+                .card
+                .map(|card| payment_types::PaymentMethod::Card(card.into())),
+            ..Default::default()
+        })
+    }
+}
+
+impl From<proto::PaymentsConfirmRequest> for payment_types::PaymentsRequest {
+    fn from(request: proto::PaymentsConfirmRequest) -> Self {
+        Self {
+            payment_id: Some(payment_types::PaymentIdType::PaymentIntentId(
+                request.payment_id,
+            )),
+            confirm: Some(true),
+            return_url: request.return_url,
+            payment_method: request
+                .card
+                .is_some()
+                .then_some(api_enums::PaymentMethodType::Card),
+            payment_method_data: request
+                .card
+                .map(|card| payment_types::PaymentMethod::Card(card.into())),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<payment_types::PaymentsResponse> for proto::PaymentsResponse {
+    fn from(response: payment_types::PaymentsResponse) -> Self {
+        Self {
+            payment_id: response.payment_id.unwrap_or_default(),
+            merchant_id: response.merchant_id.unwrap_or_default(),
+            status: response.status.to_string(),
+            amount: response.amount.get_amount_as_i64(),
+            amount_capturable: response.amount_capturable.map(MinorUnit::get_amount_as_i64),
+            amount_received: response.amount_received.map(MinorUnit::get_amount_as_i64),
+            currency: response.currency,
+            connector: response.connector,
+            customer_id: response.customer_id,
+            error_code: response.error_code,
+            error_message: response.error_message,
+            redirect_url: response
+                .next_action
+                .and_then(|next_action| next_action.redirect_to_url),
+            created: response
+                .created
+                .map(|created| created.assume_utc().unix_timestamp()),
+        }
+    }
+}
+
+impl From<api_types::RefundResponse> for proto::RefundsResponse {
+    fn from(response: api_types::RefundResponse) -> Self {
+        let status = match response.status {
+            api_types::RefundStatus::Succeeded => "succeeded",
+            api_types::RefundStatus::Failed => "failed",
+            api_types::RefundStatus::Pending => "pending",
+            api_types::RefundStatus::Review => "review",
+        };
+
+        Self {
+            refund_id: response.refund_id,
+            payment_id: response.payment_id,
+            amount: response.amount,
+            currency: response.currency,
+            status: status.to_string(),
+            connector: response.connector,
+            error_message: response.error_message,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_payments_create_request_conversion() {
+        let request = proto::PaymentsCreateRequest {
+            amount: 6540,
+            currency: "USD".to_string(),
+            capture_method: Some("manual".to_string()),
+            confirm: true,
+            card: Some(proto::Card {
+                number: "4242424242424242".to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let request = payment_types::PaymentsRequest::try_from(request).unwrap();
+        assert_eq!(request.amount, Some(api_types::Amount::from(6540)));
+        assert_eq!(request.currency, Some(api_enums::Currency::USD));
+        assert_eq!(
+            request.capture_method,
+            Some(api_enums::CaptureMethod::Manual)
+        );
+        assert_eq!(
+            request.payment_method,
+            Some(api_enums::PaymentMethodType::Card)
+        );
+    }
+
+    #[test]
+    fn test_payments_create_request_with_invalid_currency_is_rejected() {
+        let request = proto::PaymentsCreateRequest {
+            amount: 6540,
+            currency: "DOLLARS".to_string(),
+            ..Default::default()
+        };
+
+        let status = payment_types::PaymentsRequest::try_from(request).unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+}
@@ -1,6 +1,7 @@
 #![forbid(unsafe_code)]
 #![recursion_limit = "256"]
 
+pub mod cache;
 #[cfg(feature = "stripe")]
 pub mod compatibility;
 pub mod configs;
@@ -11,6 +12,8 @@ pub mod core;
 pub mod cors;
 pub mod db;
 pub mod env;
+#[cfg(feature = "grpc")]
+pub mod grpc;
 pub(crate) mod macros;
 pub mod routes;
 pub mod scheduler;
@@ -48,12 +51,25 @@ pub mod headers {
     pub const ACCEPT: &str = "Accept";
     pub const X_API_VERSION: &str = "X-ApiVersion";
     pub const DATE: &str = "Date";
+    pub const X_DEBUG_TRACE: &str = "X-Debug-Trace";
+    pub const X_REQUEST_ID: &str = "X-Request-Id";
+    pub const X_WEBHOOK_SIGNATURE: &str = "X-Webhook-Signature";
+    pub const X_WEBHOOK_TIMESTAMP: &str = "X-Webhook-Timestamp";
+    pub const CKO_SIGNATURE: &str = "Cko-Signature";
+    pub const PAYPAL_AUTH_ALGO: &str = "Paypal-Auth-Algo";
+    pub const PAYPAL_CERT_URL: &str = "Paypal-Cert-Url";
+    pub const PAYPAL_TRANSMISSION_ID: &str = "Paypal-Transmission-Id";
+    pub const PAYPAL_TRANSMISSION_SIG: &str = "Paypal-Transmission-Sig";
+    pub const PAYPAL_TRANSMISSION_TIME: &str = "Paypal-Transmission-Time";
+    pub const WORLDPAY_EVENT_SIGNATURE: &str = "Event-Signature";
+    pub const X_RAZORPAY_SIGNATURE: &str = "X-Razorpay-Signature";
+    pub const X_ANET_SIGNATURE: &str = "X-ANET-Signature";
 }
 
 pub mod pii {
     //! Personal Identifiable Information protection.
 
-    pub(crate) use common_utils::pii::{CardNumber, Email};
+    pub(crate) use common_utils::pii::{CardNumber, Email, IpAddress};
     #[doc(inline)]
     pub use masking::*;
 }
@@ -87,19 +103,35 @@ pub fn mk_app(
     {
         server_app = server_app
             .service(routes::PaymentMethods::server(state.clone()))
+            .service(routes::PaymentLinks::server(state.clone()))
             .service(routes::EphemeralKey::server(state.clone()))
             .service(routes::Webhooks::server(state.clone()));
     }
 
     #[cfg(feature = "olap")]
     {
-        server_app = server_app.service(routes::MerchantAccount::server(state.clone()));
+        server_app = server_app
+            .service(routes::MerchantAccount::server(state.clone()))
+            .service(routes::ApiKeys::server(state.clone()))
+            .service(routes::Ops::server(state.clone()))
+            .service(routes::Organizations::server(state.clone()))
+            .service(routes::Disputes::server(state.clone()))
+            .service(routes::Events::server(state.clone()))
+            .service(routes::Exports::server(state.clone()))
+            .service(routes::Blocklist::server(state.clone()))
+            .service(routes::Recon::server(state.clone()))
+            .service(routes::Analytics::server(state.clone()));
     }
 
     #[cfg(feature = "stripe")]
     {
         server_app = server_app.service(routes::StripeApis::server(state.clone()));
     }
+
+    #[cfg(feature = "dummy_connector")]
+    {
+        server_app = server_app.service(routes::DummyConnector::server(state.clone()));
+    }
     server_app = server_app.service(routes::Health::server(state));
     server_app
 }
@@ -113,13 +145,16 @@ pub fn mk_app(
 pub async fn start_server(conf: settings::Settings) -> ApplicationResult<(Server, AppState)> {
     logger::debug!(startup_config=?conf);
     let server = conf.server.clone();
-    let state = routes::AppState::new(conf).await;
+    let state = routes::AppState::new(conf).await?;
     // Cloning to close connections before shutdown
     let app_state = state.clone();
     let request_body_limit = server.request_body_limit;
+    // On `SIGTERM`, the server stops accepting connections and waits for the in-flight requests
+    // to complete, up to the shutdown timeout
     let server = actix_web::HttpServer::new(move || mk_app(state.clone(), request_body_limit))
         .bind((server.host.as_str(), server.port))?
         .workers(server.workers)
+        .shutdown_timeout(server.shutdown_timeout)
         .run();
 
     Ok((server, app_state))
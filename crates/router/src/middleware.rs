@@ -1,4 +1,13 @@
+use crate::headers;
+
+/// Maximum length of a request ID provided by the caller.
+const MAX_REQUEST_ID_LENGTH: usize = 64;
+
 /// Middleware to include request ID in response header.
+///
+/// The request ID provided by the caller in the `X-Request-Id` header is used if it is valid,
+/// otherwise the ID generated for the request is used. The ID is recorded on the span of the
+/// request, from which it is propagated to the connector calls and outgoing webhooks.
 pub(crate) struct RequestId;
 
 impl<S, B> actix_web::dev::Transform<S, actix_web::dev::ServiceRequest> for RequestId
@@ -44,18 +53,41 @@ where
 
     fn call(&self, req: actix_web::dev::ServiceRequest) -> Self::Future {
         let mut req = req;
+        let incoming_request_id = req
+            .headers()
+            .get(headers::X_REQUEST_ID)
+            .and_then(|value| value.to_str().ok())
+            .filter(|request_id| is_valid_request_id(request_id))
+            .map(ToOwned::to_owned);
+        if let Some(request_id) = &incoming_request_id {
+            // The middleware is called in the span of the request, which records the generated ID
+            router_env::tracing::Span::current().record("request_id", request_id.as_str());
+        }
         let request_id_fut = req.extract::<router_env::tracing_actix_web::RequestId>();
         let response_fut = self.service.call(req);
 
         Box::pin(async move {
-            let request_id = request_id_fut.await?;
+            let request_id = match incoming_request_id {
+                Some(request_id) => request_id,
+                None => request_id_fut.await?.as_hyphenated().to_string(),
+            };
             let mut response = response_fut.await?;
             response.headers_mut().append(
                 http::header::HeaderName::from_static("x-request-id"),
-                http::HeaderValue::from_str(&request_id.as_hyphenated().to_string())?,
+                http::HeaderValue::from_str(&request_id)?,
             );
 
             Ok(response)
         })
     }
 }
+
+/// Whether the request ID provided by the caller can be used, limited to characters which can be
+/// safely logged and sent in headers.
+fn is_valid_request_id(request_id: &str) -> bool {
+    !request_id.is_empty()
+        && request_id.len() <= MAX_REQUEST_ID_LENGTH
+        && request_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+}
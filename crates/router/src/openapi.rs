@@ -64,6 +64,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::AuthenticationType,
         api_models::enums::WalletIssuer,
         api_models::enums::Connector,
+        api_models::enums::AvsResult,
+        api_models::enums::CvvResult,
         api_models::enums::PaymentMethodType,
         api_models::admin::PaymentConnectorCreate,
         api_models::admin::PaymentMethods,
@@ -77,6 +79,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::KlarnaSdkIssuer,
         api_models::payments::NextAction,
         api_models::payments::PayLaterData,
+        api_models::payments::UpiData,
+        api_models::payments::BankDebitData,
+        api_models::payments::PaymentChecks,
         api_models::payments::MandateData,
         api_models::payments::PhoneDetails,
         api_models::payments::PaymentMethod,
@@ -88,10 +93,17 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::CustomerAcceptance,
         api_models::payments::PaymentsRequest,
         api_models::payments::PaymentsResponse,
+        api_models::payments::CaptureResponse,
+        api_models::enums::CaptureStatus,
+        api_models::enums::FrmDecision,
+        api_models::enums::CardNetwork,
         api_models::payment_methods::PaymentExperience,
         crate::types::api::admin::MerchantAccountResponse,
         crate::types::api::admin::MerchantConnectorId,
         crate::types::api::admin::MerchantDetails,
+        crate::types::api::admin::SurchargeConfig,
+        crate::types::api::admin::SurchargeRule,
+        crate::types::api::admin::Surcharge,
         crate::types::api::admin::WebhookDetails,
     ))
 )]
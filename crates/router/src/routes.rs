@@ -1,19 +1,39 @@
 pub mod admin;
+#[cfg(feature = "olap")]
+pub mod analytics;
+#[cfg(feature = "olap")]
+pub mod api_keys;
 pub mod app;
+#[cfg(feature = "olap")]
+pub mod blocklist;
 pub mod customers;
+#[cfg(feature = "olap")]
+pub mod disputes;
+#[cfg(feature = "dummy_connector")]
+pub mod dummy_connector;
 pub mod ephemeral_key;
+#[cfg(feature = "olap")]
+pub mod events;
+#[cfg(feature = "olap")]
+pub mod exports;
 pub mod health;
 pub mod mandates;
 pub mod metrics;
+#[cfg(feature = "olap")]
+pub mod ops;
+pub mod payment_links;
 pub mod payment_methods;
 pub mod payments;
 pub mod payouts;
+#[cfg(feature = "olap")]
+pub mod recon;
 pub mod refunds;
 pub mod webhooks;
 
 pub use self::app::{
-    AppState, Customers, EphemeralKey, Health, Mandates, MerchantAccount, MerchantConnectorAccount,
-    PaymentMethods, Payments, Payouts, Refunds, Webhooks,
+    Analytics, ApiKeys, AppState, Blocklist, Customers, Disputes, DummyConnector, EphemeralKey,
+    Events, Exports, Health, Mandates, MerchantAccount, MerchantConnectorAccount, Ops,
+    Organizations, PaymentLinks, PaymentMethods, Payments, Payouts, Recon, Refunds, Webhooks,
 };
 #[cfg(feature = "stripe")]
 pub use super::compatibility::stripe::StripeApis;
@@ -31,7 +31,7 @@ pub async fn merchant_account_create(
         &state,
         &req,
         json_payload.into_inner(),
-        |state, _, req| create_merchant_account(&*state.store, req),
+        |state, _, req| create_merchant_account(state, req),
         *auth::jwt_auth_or(&auth::AdminApiAuth, req.headers()),
     )
     .await
@@ -71,7 +71,7 @@ pub async fn update_merchant_account(
         &state,
         &req,
         json_payload.into_inner(),
-        |state, _, req| merchant_account_update(&*state.store, &merchant_id, req),
+        |state, _, req| merchant_account_update(state, &merchant_id, req),
         *auth::jwt_auth_or(&auth::AdminApiAuth, req.headers()),
     )
     .await
@@ -98,6 +98,85 @@ pub async fn delete_merchant_account(
     .await
 }
 
+#[instrument(skip_all, fields(flow = ?Flow::MerchantsWebhookSecretRotate))]
+// #[post("/{id}/webhook_signing_secret/rotate")]
+pub async fn rotate_merchant_webhook_signing_secret(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    mid: web::Path<String>,
+) -> HttpResponse {
+    let payload = web::Json(admin::MerchantId {
+        merchant_id: mid.into_inner(),
+    })
+    .into_inner();
+    api::server_wrap(
+        &state,
+        &req,
+        payload,
+        |state, _, req| rotate_webhook_signing_secret(&*state.store, req.merchant_id),
+        *auth::jwt_auth_or(&auth::AdminApiAuth, req.headers()),
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::MerchantsWebhookConfigRetrieve))]
+// #[get("/{id}/webhook_config")]
+pub async fn merchant_webhook_config_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    mid: web::Path<String>,
+) -> HttpResponse {
+    let payload = web::Json(admin::MerchantId {
+        merchant_id: mid.into_inner(),
+    })
+    .into_inner();
+    api::server_wrap(
+        &state,
+        &req,
+        payload,
+        |state, _, req| retrieve_merchant_webhook_config(&*state.store, req.merchant_id),
+        *auth::jwt_auth_or(&auth::AdminApiAuth, req.headers()),
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::MerchantsWebhookConfigUpdate))]
+// #[post("/{id}/webhook_config")]
+pub async fn merchant_webhook_config_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    mid: web::Path<String>,
+    json_payload: web::Json<admin::MerchantWebhookConfigRequest>,
+) -> HttpResponse {
+    let merchant_id = mid.into_inner();
+    api::server_wrap(
+        &state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req| update_merchant_webhook_config(&*state.store, &merchant_id, req),
+        *auth::jwt_auth_or(&auth::AdminApiAuth, req.headers()),
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsAuditLogRetrieve))]
+// #[get("/{id}/payments/{payment_id}/audit_log")]
+pub async fn payment_audit_log_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+    let (merchant_id, payment_id) = path.into_inner();
+    api::server_wrap(
+        &state,
+        &req,
+        payment_id,
+        |state, _, payment_id| retrieve_payment_audit_log(&*state.store, &merchant_id, payment_id),
+        *auth::jwt_auth_or(&auth::AdminApiAuth, req.headers()),
+    )
+    .await
+}
+
 /// PaymentsConnectors - Create
 ///
 /// Create a new Payment Connector for the merchant account. The connector could be a payment processor / facilitator / acquirer or specialized services like Fraud / Accounting etc."
@@ -122,7 +201,14 @@ pub async fn payment_connector_create(
         &state,
         &req,
         json_payload.into_inner(),
-        |state, _, req| create_payment_connector(&*state.store, req, &merchant_id),
+        |state, _, req| {
+            create_payment_connector(
+                &*state.store,
+                &state.connector_credentials,
+                req,
+                &merchant_id,
+            )
+        },
         *auth::jwt_auth_or(&auth::AdminApiAuth, req.headers()),
     )
     .await
@@ -146,7 +232,12 @@ pub async fn payment_connector_retrieve(
         &req,
         payload,
         |state, _, req| {
-            retrieve_payment_connector(&*state.store, req.merchant_id, req.merchant_connector_id)
+            retrieve_payment_connector(
+                &*state.store,
+                &state.connector_credentials,
+                req.merchant_id,
+                req.merchant_connector_id,
+            )
         },
         *auth::jwt_auth_or(&auth::AdminApiAuth, req.headers()),
     )
@@ -165,7 +256,9 @@ pub async fn payment_connector_list(
         &state,
         &req,
         merchant_id,
-        |state, _, merchant_id| list_payment_connectors(&*state.store, merchant_id),
+        |state, _, merchant_id| {
+            list_payment_connectors(&*state.store, &state.connector_credentials, merchant_id)
+        },
         *auth::jwt_auth_or(&auth::AdminApiAuth, req.headers()),
     )
     .await
@@ -185,7 +278,13 @@ pub async fn payment_connector_update(
         &req,
         json_payload.into_inner(),
         |state, _, req| {
-            update_payment_connector(&*state.store, &merchant_id, merchant_connector_id, req)
+            update_payment_connector(
+                &*state.store,
+                &state.connector_credentials,
+                &merchant_id,
+                merchant_connector_id,
+                req,
+            )
         },
         *auth::jwt_auth_or(&auth::AdminApiAuth, req.headers()),
     )
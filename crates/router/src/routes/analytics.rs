@@ -0,0 +1,28 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::analytics,
+    services::{api, authentication as auth},
+    types::api::analytics as analytics_types,
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsAnalytics))]
+// #[get("/payments")]
+pub async fn payments_analytics(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Query<analytics_types::PaymentAnalyticsRequest>,
+) -> HttpResponse {
+    api::server_wrap(
+        &state,
+        &req,
+        payload.into_inner(),
+        |state, merchant_account, req| {
+            analytics::retrieve_payment_analytics(&*state.store, merchant_account, req)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
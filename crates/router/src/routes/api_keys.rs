@@ -0,0 +1,79 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::api_keys,
+    services::{api, authentication as auth},
+    types::api::api_keys as api_key_types,
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::ApiKeyCreate))]
+// #[post("")]
+pub async fn api_key_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_key_types::CreateApiKeyRequest>,
+) -> HttpResponse {
+    api::server_wrap(
+        &state,
+        &req,
+        json_payload.into_inner(),
+        |state, merchant_account, req| {
+            api_keys::create_api_key(&*state.store, merchant_account, req)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ApiKeyList))]
+// #[get("")]
+pub async fn api_key_list(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    api::server_wrap(
+        &state,
+        &req,
+        (),
+        |state, merchant_account, _| api_keys::list_api_keys(&*state.store, merchant_account),
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ApiKeyRetrieve))]
+// #[get("/{key_id}")]
+pub async fn api_key_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        &state,
+        &req,
+        path.into_inner(),
+        |state, merchant_account, key_id| {
+            api_keys::retrieve_api_key(&*state.store, merchant_account, key_id)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ApiKeyRevoke))]
+// #[delete("/{key_id}")]
+pub async fn api_key_revoke(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        &state,
+        &req,
+        path.into_inner(),
+        |state, merchant_account, key_id| {
+            api_keys::revoke_api_key(&*state.store, merchant_account, key_id)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
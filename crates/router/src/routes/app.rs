@@ -1,16 +1,23 @@
 use actix_web::{web, Scope};
 
-#[cfg(feature = "olap")]
-use super::admin::*;
+#[cfg(feature = "dummy_connector")]
+use super::dummy_connector::*;
 use super::health::*;
+#[cfg(feature = "olap")]
+use super::{
+    admin::*, analytics::*, api_keys::*, blocklist::*, disputes::*, events::*, exports::*, ops::*,
+    recon::*,
+};
 #[cfg(any(feature = "olap", feature = "oltp"))]
 use super::{customers::*, mandates::*, payments::*, payouts::*, refunds::*};
 #[cfg(feature = "oltp")]
-use super::{ephemeral_key::*, payment_methods::*, webhooks::*};
+use super::{ephemeral_key::*, payment_links::*, payment_methods::*, webhooks::*};
 use crate::{
+    cache::Cache,
     configs::settings::Settings,
+    core::errors,
     db::{MockDb, StorageImpl, StorageInterface},
-    services::Store,
+    services::{connector_credentials::ConnectorCredentials, Store},
 };
 
 #[derive(Clone)]
@@ -18,10 +25,16 @@ pub struct AppState {
     pub flow_name: String,
     pub store: Box<dyn StorageInterface>,
     pub conf: Settings,
+    pub connector_credentials: ConnectorCredentials,
+    /// Exchange rates fetched from the provider, keyed by currency pair
+    pub forex_rates: Cache<f64>,
 }
 
 impl AppState {
-    pub async fn with_storage(conf: Settings, storage_impl: StorageImpl) -> Self {
+    pub async fn with_storage(
+        conf: Settings,
+        storage_impl: StorageImpl,
+    ) -> errors::ApplicationResult<Self> {
         let testable = storage_impl == StorageImpl::PostgresqlTest;
         let store: Box<dyn StorageInterface> = match storage_impl {
             StorageImpl::Postgresql | StorageImpl::PostgresqlTest => {
@@ -29,16 +42,20 @@ impl AppState {
             }
             StorageImpl::Mock => Box::new(MockDb::new(&conf).await),
         };
+        let connector_credentials = ConnectorCredentials::from_settings(&conf).await?;
+        let forex_rates = Cache::new("forex_rates", &conf.forex.cache);
 
-        Self {
+        Ok(Self {
             flow_name: String::from("default"),
             store,
             conf,
-        }
+            connector_credentials,
+            forex_rates,
+        })
     }
 
     #[allow(unused_variables)]
-    pub async fn new(conf: Settings) -> Self {
+    pub async fn new(conf: Settings) -> errors::ApplicationResult<Self> {
         Self::with_storage(conf, StorageImpl::Postgresql).await
     }
 }
@@ -50,6 +67,8 @@ impl Health {
         web::scope("")
             .app_data(web::Data::new(state))
             .service(web::resource("/health").route(web::get().to(health)))
+            .service(web::resource("/health/deep").route(web::get().to(deep_health)))
+            .service(web::resource("/health/ready").route(web::get().to(ready)))
     }
 }
 
@@ -62,7 +81,11 @@ impl Payments {
 
         #[cfg(feature = "olap")]
         {
-            route = route.service(web::resource("/list").route(web::get().to(payments_list)));
+            route = route.service(
+                web::resource("/list")
+                    .route(web::get().to(payments_list))
+                    .route(web::post().to(payments_list_by_filter)),
+            );
         }
         #[cfg(feature = "oltp")]
         {
@@ -83,6 +106,11 @@ impl Payments {
                 .service(
                     web::resource("/{payment_id}/cancel").route(web::post().to(payments_cancel)),
                 )
+                .service(web::resource("/{payment_id}/clone").route(web::post().to(payments_clone)))
+                .service(
+                    web::resource("/{payment_id}/client_secret")
+                        .route(web::post().to(payments_client_secret_regenerate)),
+                )
                 .service(
                     web::resource("/{payment_id}/capture").route(web::post().to(payments_capture)),
                 )
@@ -90,6 +118,10 @@ impl Payments {
                     web::resource("/start/{payment_id}/{merchant_id}/{attempt_id}")
                         .route(web::get().to(payments_start)),
                 )
+                .service(
+                    web::resource("/redirect/{payment_id}/{merchant_id}")
+                        .route(web::get().to(payments_redirect)),
+                )
                 .service(
                     web::resource("/{payment_id}/{merchant_id}/response/{connector}")
                         .route(web::get().to(payments_response)),
@@ -124,9 +156,16 @@ impl Customers {
                         .route(web::post().to(customers_update))
                         .route(web::delete().to(customers_delete)),
                 )
+                .service(
+                    web::resource("/{customer_id}/redact").route(web::post().to(customers_redact)),
+                )
                 .service(
                     web::resource("/{customer_id}/payment_methods")
                         .route(web::get().to(list_customer_payment_method_api)),
+                )
+                .service(
+                    web::resource("/{customer_id}/payment_methods/{payment_method_id}")
+                        .route(web::delete().to(delete_customer_payment_method_api)),
                 );
         }
         route
@@ -139,22 +178,41 @@ pub struct Refunds;
 impl Refunds {
     pub fn server(state: AppState) -> Scope {
         let mut route = web::scope("/refunds").app_data(web::Data::new(state));
+        // Listing and creating refunds share the same path, so both are routed by one resource
+        let mut refunds_resource = web::resource("");
 
         #[cfg(feature = "olap")]
         {
-            route = route.service(web::resource("/list").route(web::get().to(refunds_list)));
+            refunds_resource = refunds_resource.route(web::get().to(refunds_list));
+            route = route.service(
+                web::resource("/list")
+                    .route(web::get().to(refunds_list))
+                    .route(web::post().to(refunds_list_by_filter)),
+            );
         }
         #[cfg(feature = "oltp")]
         {
+            refunds_resource = refunds_resource.route(web::post().to(refunds_create));
             route = route
-                .service(web::resource("").route(web::post().to(refunds_create)))
+                .service(web::resource("/batch").route(web::post().to(refunds_batch_create)))
+                .service(
+                    web::resource("/batch/{batch_id}").route(web::get().to(refunds_batch_retrieve)),
+                )
+                .service(
+                    web::resource("/batch/{batch_id}/report")
+                        .route(web::post().to(refunds_batch_report)),
+                )
+                .service(
+                    web::resource("/alternate_account")
+                        .route(web::post().to(refunds_alternate_account_create)),
+                )
                 .service(
                     web::resource("/{id}")
                         .route(web::get().to(refunds_retrieve))
                         .route(web::post().to(refunds_update)),
                 );
         }
-        route
+        route.service(refunds_resource)
     }
 }
 
@@ -173,16 +231,43 @@ impl Payouts {
         #[cfg(feature = "oltp")]
         {
             route = route
+                .service(web::resource("").route(web::post().to(payouts_create)))
                 .service(web::resource("/create").route(web::post().to(payouts_create)))
-                .service(web::resource("/retrieve").route(web::get().to(payouts_retrieve)))
                 .service(web::resource("/update").route(web::post().to(payouts_update)))
                 .service(web::resource("/reverse").route(web::post().to(payouts_reverse)))
-                .service(web::resource("/cancel").route(web::post().to(payouts_cancel)));
+                .service(web::resource("/{payout_id}").route(web::get().to(payouts_retrieve)))
+                .service(
+                    web::resource("/{payout_id}/confirm").route(web::post().to(payouts_confirm)),
+                )
+                .service(
+                    web::resource("/{payout_id}/fulfill").route(web::post().to(payouts_fulfill)),
+                )
+                .service(
+                    web::resource("/{payout_id}/cancel").route(web::post().to(payouts_cancel)),
+                );
         }
         route
     }
 }
 
+pub struct PaymentLinks;
+
+#[cfg(feature = "oltp")]
+impl PaymentLinks {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/payment_links")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(payment_links_create)))
+            .service(
+                web::resource("/{payment_link_id}").route(web::get().to(payment_links_retrieve)),
+            )
+            .service(
+                web::resource("/{merchant_id}/{payment_link_id}")
+                    .route(web::get().to(payment_links_initiate)),
+            )
+    }
+}
+
 pub struct PaymentMethods;
 
 #[cfg(feature = "oltp")]
@@ -214,6 +299,198 @@ impl MerchantAccount {
                     .route(web::post().to(update_merchant_account))
                     .route(web::delete().to(delete_merchant_account)),
             )
+            .service(
+                web::resource("/{id}/webhook_signing_secret/rotate")
+                    .route(web::post().to(rotate_merchant_webhook_signing_secret)),
+            )
+            .service(
+                web::resource("/{id}/webhook_config")
+                    .route(web::get().to(merchant_webhook_config_retrieve))
+                    .route(web::post().to(merchant_webhook_config_update)),
+            )
+            .service(
+                web::resource("/{id}/payments/{payment_id}/audit_log")
+                    .route(web::get().to(payment_audit_log_retrieve)),
+            )
+    }
+}
+
+pub struct ApiKeys;
+
+#[cfg(feature = "olap")]
+impl ApiKeys {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/api_keys")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("")
+                    .route(web::post().to(api_key_create))
+                    .route(web::get().to(api_key_list)),
+            )
+            .service(
+                web::resource("/{key_id}")
+                    .route(web::get().to(api_key_retrieve))
+                    .route(web::delete().to(api_key_revoke)),
+            )
+    }
+}
+
+pub struct Organizations;
+
+#[cfg(feature = "olap")]
+impl Organizations {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/organizations")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/{organization_id}/payments")
+                    .route(web::get().to(organization_payments_list)),
+            )
+    }
+}
+
+pub struct Disputes;
+
+#[cfg(feature = "olap")]
+impl Disputes {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/disputes")
+            .app_data(web::Data::new(state))
+            .service(web::resource("/list").route(web::get().to(retrieve_disputes_list)))
+            .service(web::resource("/{dispute_id}").route(web::get().to(retrieve_dispute)))
+            .service(
+                web::resource("/{dispute_id}/evidence")
+                    .route(web::post().to(submit_dispute_evidence)),
+            )
+    }
+}
+
+pub struct Events;
+
+#[cfg(feature = "olap")]
+impl Events {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/events")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::get().to(list_events)))
+            .service(web::resource("/{event_id}").route(web::get().to(retrieve_event)))
+            .service(
+                web::resource("/{event_id}/attempts")
+                    .route(web::get().to(list_event_delivery_attempts)),
+            )
+            .service(web::resource("/{event_id}/retry").route(web::post().to(retry_event)))
+    }
+}
+
+pub struct Exports;
+
+#[cfg(feature = "olap")]
+impl Exports {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/exports")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(exports_create)))
+            .service(web::resource("/{export_id}").route(web::get().to(exports_retrieve)))
+            .service(
+                web::resource("/{merchant_id}/{export_id}/download")
+                    .route(web::get().to(exports_download)),
+            )
+    }
+}
+
+pub struct Blocklist;
+
+#[cfg(feature = "olap")]
+impl Blocklist {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/blocklist")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("")
+                    .route(web::post().to(blocklist_add))
+                    .route(web::get().to(blocklist_list)),
+            )
+            .service(web::resource("/{entry_id}").route(web::delete().to(blocklist_delete)))
+    }
+}
+
+pub struct Recon;
+
+#[cfg(feature = "olap")]
+impl Recon {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/recon")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/reports")
+                    .route(web::post().to(recon_reports_create))
+                    .route(web::get().to(recon_reports_list)),
+            )
+            .service(
+                web::resource("/reports/{report_id}").route(web::get().to(recon_reports_retrieve)),
+            )
+    }
+}
+
+pub struct Analytics;
+
+#[cfg(feature = "olap")]
+impl Analytics {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/analytics")
+            .app_data(web::Data::new(state))
+            .service(web::resource("/payments").route(web::get().to(payments_analytics)))
+    }
+}
+
+pub struct Ops;
+
+#[cfg(feature = "olap")]
+impl Ops {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/ops")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/process_tracker/requeue").route(web::post().to(requeue_processes)),
+            )
+            .service(web::resource("/payments/resync").route(web::post().to(resync_stuck_payments)))
+            .service(web::resource("/events/replay").route(web::post().to(replay_events)))
+            .service(web::resource("/batches/{batch_id}").route(web::get().to(retrieve_ops_batch)))
+    }
+}
+
+pub struct DummyConnector;
+
+#[cfg(feature = "dummy_connector")]
+impl DummyConnector {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/dummy_connector")
+            .app_data(web::Data::new(state))
+            .service(web::resource("/payments").route(web::post().to(dummy_connector_payment)))
+            .service(
+                web::resource("/payments/{payment_id}")
+                    .route(web::get().to(dummy_connector_payment_retrieve)),
+            )
+            .service(
+                web::resource("/payments/{payment_id}/capture")
+                    .route(web::post().to(dummy_connector_payment_capture)),
+            )
+            .service(
+                web::resource("/payments/{payment_id}/void")
+                    .route(web::post().to(dummy_connector_payment_void)),
+            )
+            .service(
+                web::resource("/payments/{payment_id}/refund")
+                    .route(web::post().to(dummy_connector_refund)),
+            )
+            .service(
+                web::resource("/authorize/{payment_id}")
+                    .route(web::get().to(dummy_connector_payment_authorize)),
+            )
+            .service(
+                web::resource("/refunds/{refund_id}")
+                    .route(web::get().to(dummy_connector_refund_retrieve)),
+            )
     }
 }
 
@@ -276,8 +553,9 @@ impl Mandates {
         }
         #[cfg(feature = "oltp")]
         {
-            route =
-                route.service(web::resource("/revoke/{id}").route(web::post().to(revoke_mandate)));
+            route = route
+                .service(web::resource("/revoke/{id}").route(web::post().to(revoke_mandate)))
+                .service(web::resource("/{id}/revoke").route(web::post().to(revoke_mandate)));
         }
         route
     }
@@ -290,9 +568,17 @@ impl Webhooks {
     pub fn server(config: AppState) -> Scope {
         web::scope("/webhooks")
             .app_data(web::Data::new(config))
+            // Registered before the incoming webhooks, as its path would match theirs as well
+            .service(
+                web::resource("/test/{merchant_id}").route(web::post().to(trigger_test_webhook)),
+            )
             .service(
                 web::resource("/{merchant_id}/{connector}")
                     .route(web::post().to(receive_incoming_webhook)),
             )
+            .service(
+                web::resource("/{merchant_id}/{connector}/replay")
+                    .route(web::post().to(replay_incoming_webhook)),
+            )
     }
 }
@@ -0,0 +1,64 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::blocklist,
+    services::{api, authentication as auth},
+    types::api::blocklist as blocklist_types,
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::BlocklistAdd))]
+// #[post("")]
+pub async fn blocklist_add(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<blocklist_types::BlocklistAddRequest>,
+) -> HttpResponse {
+    api::server_wrap(
+        &state,
+        &req,
+        json_payload.into_inner(),
+        |state, merchant_account, req| blocklist::add_blocklist_entry(state, merchant_account, req),
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::BlocklistList))]
+// #[get("")]
+pub async fn blocklist_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<blocklist_types::BlocklistListConstraints>,
+) -> HttpResponse {
+    api::server_wrap(
+        &state,
+        &req,
+        query.into_inner(),
+        |state, merchant_account, constraints| {
+            blocklist::list_blocklist_entries(&*state.store, merchant_account, constraints)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::BlocklistDelete))]
+// #[delete("/{entry_id}")]
+pub async fn blocklist_delete(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        &state,
+        &req,
+        path.into_inner(),
+        |state, merchant_account, entry_id| {
+            blocklist::delete_blocklist_entry(&*state.store, merchant_account, entry_id)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
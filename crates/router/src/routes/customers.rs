@@ -87,6 +87,20 @@ pub async fn customers_delete(
     api::server_wrap(&state, &req, payload, delete_customer, &auth::ApiKeyAuth).await
 }
 
+#[instrument(skip_all, fields(flow = ?Flow::CustomersRedact))]
+// #[post("/{customer_id}/redact")]
+pub async fn customers_redact(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let payload = web::Json(customers::CustomerId {
+        customer_id: path.into_inner(),
+    })
+    .into_inner();
+    api::server_wrap(&state, &req, payload, redact_customer, &auth::ApiKeyAuth).await
+}
+
 #[instrument(skip_all, fields(flow = ?Flow::CustomersGetMandates))]
 // #[get("/{customer_id}/mandates")]
 pub async fn get_customer_mandates(
@@ -0,0 +1,68 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::disputes,
+    services::{api, authentication as auth},
+    types::api::disputes as dispute_types,
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::DisputesRetrieve))]
+// #[get("/{dispute_id}")]
+pub async fn retrieve_dispute(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        &state,
+        &req,
+        path.into_inner(),
+        |state, merchant_account, dispute_id| {
+            disputes::retrieve_dispute(&*state.store, merchant_account, dispute_id)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::DisputesList))]
+// #[get("/list")]
+pub async fn retrieve_disputes_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Query<dispute_types::DisputeListConstraints>,
+) -> HttpResponse {
+    api::server_wrap(
+        &state,
+        &req,
+        payload.into_inner(),
+        |state, merchant_account, constraints| {
+            disputes::retrieve_disputes_list(&*state.store, merchant_account, constraints)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::DisputesEvidenceSubmit))]
+// #[post("/{dispute_id}/evidence")]
+pub async fn submit_dispute_evidence(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<dispute_types::SubmitEvidenceRequest>,
+) -> HttpResponse {
+    let dispute_id = path.into_inner();
+    api::server_wrap(
+        &state,
+        &req,
+        json_payload.into_inner(),
+        |state, merchant_account, req| {
+            disputes::submit_evidence(state, merchant_account, dispute_id.clone(), req)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
@@ -0,0 +1,130 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::dummy_connector,
+    services::{api, authentication as auth},
+    types::api::dummy_connector as dummy_connector_types,
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::DummyConnectorPaymentCreate))]
+// #[post("/payments")]
+pub async fn dummy_connector_payment(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<dummy_connector_types::DummyConnectorPaymentRequest>,
+) -> HttpResponse {
+    api::server_wrap(
+        &state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req| dummy_connector::payment(state, req),
+        &auth::NoAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::DummyConnectorPaymentRetrieve))]
+// #[get("/payments/{payment_id}")]
+pub async fn dummy_connector_payment_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        &state,
+        &req,
+        path.into_inner(),
+        |state, _, payment_id| dummy_connector::payment_retrieve(state, payment_id),
+        &auth::NoAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::DummyConnectorPaymentCapture))]
+// #[post("/payments/{payment_id}/capture")]
+pub async fn dummy_connector_payment_capture(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<dummy_connector_types::DummyConnectorCaptureRequest>,
+) -> HttpResponse {
+    api::server_wrap(
+        &state,
+        &req,
+        (path.into_inner(), json_payload.into_inner()),
+        |state, _, (payment_id, req)| dummy_connector::payment_capture(state, payment_id, req),
+        &auth::NoAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::DummyConnectorPaymentVoid))]
+// #[post("/payments/{payment_id}/void")]
+pub async fn dummy_connector_payment_void(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        &state,
+        &req,
+        path.into_inner(),
+        |state, _, payment_id| dummy_connector::payment_void(state, payment_id),
+        &auth::NoAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::DummyConnectorPaymentAuthorize))]
+// #[get("/authorize/{payment_id}")]
+pub async fn dummy_connector_payment_authorize(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        &state,
+        &req,
+        path.into_inner(),
+        |state, _, payment_id| dummy_connector::payment_authorize(state, payment_id),
+        &auth::NoAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::DummyConnectorRefundCreate))]
+// #[post("/payments/{payment_id}/refund")]
+pub async fn dummy_connector_refund(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<dummy_connector_types::DummyConnectorRefundRequest>,
+) -> HttpResponse {
+    api::server_wrap(
+        &state,
+        &req,
+        (path.into_inner(), json_payload.into_inner()),
+        |state, _, (payment_id, req)| dummy_connector::refund(state, payment_id, req),
+        &auth::NoAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::DummyConnectorRefundRetrieve))]
+// #[get("/refunds/{refund_id}")]
+pub async fn dummy_connector_refund_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        &state,
+        &req,
+        path.into_inner(),
+        |state, _, refund_id| dummy_connector::refund_retrieve(state, refund_id),
+        &auth::NoAuth,
+    )
+    .await
+}
@@ -38,7 +38,9 @@ pub async fn ephemeral_key_delete(
         &state,
         &req,
         payload,
-        |state, _, req| helpers::delete_ephemeral_key(&*state.store, req),
+        |state, merchant_account, req| async move {
+            helpers::delete_ephemeral_key(&*state.store, req, &merchant_account.merchant_id).await
+        },
         &auth::ApiKeyAuth,
     )
     .await
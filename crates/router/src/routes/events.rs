@@ -0,0 +1,83 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::events,
+    services::{api, authentication as auth},
+    types::api::events as event_types,
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::EventsList))]
+// #[get("")]
+pub async fn list_events(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Query<event_types::EventListConstraints>,
+) -> HttpResponse {
+    api::server_wrap(
+        &state,
+        &req,
+        payload.into_inner(),
+        |state, merchant_account, constraints| {
+            events::list_events(&*state.store, merchant_account, constraints)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::EventsRetrieve))]
+// #[get("/{event_id}")]
+pub async fn retrieve_event(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        &state,
+        &req,
+        path.into_inner(),
+        |state, merchant_account, event_id| {
+            events::retrieve_event(&*state.store, merchant_account, event_id)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::EventDeliveryAttemptsList))]
+// #[get("/{event_id}/attempts")]
+pub async fn list_event_delivery_attempts(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        &state,
+        &req,
+        path.into_inner(),
+        |state, merchant_account, event_id| {
+            events::list_event_delivery_attempts(&*state.store, merchant_account, event_id)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::EventsRetry))]
+// #[post("/{event_id}/retry")]
+pub async fn retry_event(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        &state,
+        &req,
+        path.into_inner(),
+        events::retry_event,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
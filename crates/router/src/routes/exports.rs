@@ -0,0 +1,66 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::exports,
+    services::{api, authentication as auth},
+    types::api::exports as export_types,
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::ExportsCreate))]
+// #[post("")]
+pub async fn exports_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<export_types::ExportCreateRequest>,
+) -> HttpResponse {
+    api::server_wrap(
+        &state,
+        &req,
+        json_payload.into_inner(),
+        |state, merchant_account, req| exports::create_export(state, merchant_account, req),
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ExportsRetrieve))]
+// #[get("/{export_id}")]
+pub async fn exports_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        &state,
+        &req,
+        path.into_inner(),
+        |state, merchant_account, export_id| {
+            exports::retrieve_export(state, merchant_account, export_id)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ExportsDownload))]
+// #[get("/{merchant_id}/{export_id}/download")]
+pub async fn exports_download(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+    query: web::Query<export_types::ExportDownloadParams>,
+) -> HttpResponse {
+    let (merchant_id, export_id) = path.into_inner();
+    api::server_wrap(
+        &state,
+        &req,
+        (export_id, query.into_inner()),
+        |state, merchant_account, (export_id, params)| {
+            exports::download_export(state, merchant_account, export_id, params)
+        },
+        &auth::MerchantIdAuth(merchant_id),
+    )
+    .await
+}
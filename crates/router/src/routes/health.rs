@@ -1,6 +1,8 @@
+use actix_web::web;
 use router_env::{instrument, logger, tracing};
 
-use crate::routes::metrics;
+use super::app::AppState;
+use crate::{core::health_check, routes::metrics};
 
 /// .
 // #[logger::instrument(skip_all, name = "name1", level = "warn", fields( key1 = "val1" ))]
@@ -11,3 +13,31 @@ pub async fn health() -> impl actix_web::Responder {
     logger::info!("Health was called");
     actix_web::HttpResponse::Ok().body("health is good")
 }
+
+/// Checks the databases and Redis, responding with `503 Service Unavailable` if any of them is
+/// unhealthy.
+#[instrument(skip_all)]
+// #[actix_web::get("/health/deep")]
+pub async fn deep_health(state: web::Data<AppState>) -> impl actix_web::Responder {
+    let (healthy, response) = health_check::deep_health_check(&*state.store).await;
+    if healthy {
+        actix_web::HttpResponse::Ok().json(response)
+    } else {
+        logger::error!(?response, "Deep health check failed");
+        actix_web::HttpResponse::ServiceUnavailable().json(response)
+    }
+}
+
+/// Checks that the router is ready to serve requests, responding with `503 Service Unavailable`
+/// if it is not, so that the load balancer stops routing requests to it.
+#[instrument(skip_all)]
+// #[actix_web::get("/health/ready")]
+pub async fn ready(state: web::Data<AppState>) -> impl actix_web::Responder {
+    let (ready, response) = health_check::readiness_check(&state).await;
+    if ready {
+        actix_web::HttpResponse::Ok().json(response)
+    } else {
+        logger::error!(?response, "Readiness check failed");
+        actix_web::HttpResponse::ServiceUnavailable().json(response)
+    }
+}
@@ -30,6 +30,7 @@ pub async fn get_mandate(
 
 #[instrument(skip_all, fields(flow = ?Flow::MandatesRevoke))]
 // #[post("/revoke/{id}")]
+// #[post("/{id}/revoke")]
 pub async fn revoke_mandate(
     state: web::Data<AppState>,
     req: HttpRequest,
@@ -42,9 +43,7 @@ pub async fn revoke_mandate(
         &state,
         &req,
         mandate_id,
-        |state, merchant_account, req| {
-            mandate::revoke_mandate(&*state.store, merchant_account, req)
-        },
+        mandate::revoke_mandate,
         &auth::ApiKeyAuth,
     )
     .await
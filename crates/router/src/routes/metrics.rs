@@ -1,7 +1,7 @@
 use once_cell::sync::Lazy;
 use router_env::opentelemetry::{
     global,
-    metrics::{Counter, Meter},
+    metrics::{Counter, Histogram, Meter},
     Context,
 };
 
@@ -10,3 +10,44 @@ static GLOBAL_METER: Lazy<Meter> = Lazy::new(|| global::meter("ROUTER_API"));
 
 pub(crate) static HEALTH_METRIC: Lazy<Counter<u64>> =
     Lazy::new(|| GLOBAL_METER.u64_counter("HEALTH_API").init());
+
+/// Seconds waited for a connection from a Postgres connection pool
+pub(crate) static DB_CONNECTION_WAIT_TIME: Lazy<Histogram<f64>> =
+    Lazy::new(|| GLOBAL_METER.f64_histogram("DB_CONNECTION_WAIT_TIME").init());
+
+/// Requests rejected without being processed because the connection pools were exhausted
+pub(crate) static LOAD_SHED_COUNT: Lazy<Counter<u64>> =
+    Lazy::new(|| GLOBAL_METER.u64_counter("LOAD_SHED_COUNT").init());
+
+/// Connections to Redis which failed, for example while the Redis primary fails over
+pub(crate) static REDIS_CONNECTION_ERROR_COUNT: Lazy<Counter<u64>> = Lazy::new(|| {
+    GLOBAL_METER
+        .u64_counter("REDIS_CONNECTION_ERROR_COUNT")
+        .init()
+});
+
+/// Connections to Redis which were re-established after failing
+pub(crate) static REDIS_RECONNECTION_COUNT: Lazy<Counter<u64>> =
+    Lazy::new(|| GLOBAL_METER.u64_counter("REDIS_RECONNECTION_COUNT").init());
+
+/// Lookups served from an in-memory cache, by cache
+pub(crate) static CACHE_HIT_COUNT: Lazy<Counter<u64>> =
+    Lazy::new(|| GLOBAL_METER.u64_counter("CACHE_HIT_COUNT").init());
+
+/// Lookups which had to be served from the database and cached, by cache
+pub(crate) static CACHE_MISS_COUNT: Lazy<Counter<u64>> =
+    Lazy::new(|| GLOBAL_METER.u64_counter("CACHE_MISS_COUNT").init());
+
+/// Payments declined because they exceeded a velocity rule, by merchant and rule
+pub(crate) static VELOCITY_LIMIT_EXCEEDED_COUNT: Lazy<Counter<u64>> = Lazy::new(|| {
+    GLOBAL_METER
+        .u64_counter("VELOCITY_LIMIT_EXCEEDED_COUNT")
+        .init()
+});
+
+/// Incoming webhooks rejected because their source could not be verified
+pub(crate) static WEBHOOK_SOURCE_VERIFICATION_FAILURE_COUNT: Lazy<Counter<u64>> = Lazy::new(|| {
+    GLOBAL_METER
+        .u64_counter("WEBHOOK_SOURCE_VERIFICATION_FAILURE_COUNT")
+        .init()
+});
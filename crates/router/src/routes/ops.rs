@@ -0,0 +1,76 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::ops,
+    services::{api, authentication as auth},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::OpsProcessRequeue))]
+// #[post("/process_tracker/requeue")]
+pub async fn requeue_processes(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_models::ops::ProcessRequeueRequest>,
+) -> HttpResponse {
+    api::server_wrap(
+        &state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req| ops::requeue_processes(state, req),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::OpsPaymentsResync))]
+// #[post("/payments/resync")]
+pub async fn resync_stuck_payments(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_models::ops::PaymentsResyncRequest>,
+) -> HttpResponse {
+    api::server_wrap(
+        &state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req| ops::resync_stuck_payments(state, req),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::OpsEventsReplay))]
+// #[post("/events/replay")]
+pub async fn replay_events(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_models::ops::EventsReplayRequest>,
+) -> HttpResponse {
+    api::server_wrap(
+        &state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req| ops::replay_events(state, req),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::OpsBatchRetrieve))]
+// #[get("/batches/{batch_id}")]
+pub async fn retrieve_ops_batch(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        &state,
+        &req,
+        path.into_inner(),
+        |state, _, batch_id| ops::retrieve_ops_batch(state, batch_id),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
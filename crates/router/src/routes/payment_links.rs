@@ -0,0 +1,67 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::payment_links,
+    services::{api, authentication as auth},
+    types::api::payment_links as payment_link_types,
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentLinksCreate))]
+// #[post("")]
+pub async fn payment_links_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payment_link_types::PaymentLinkCreateRequest>,
+) -> HttpResponse {
+    api::server_wrap(
+        &state,
+        &req,
+        json_payload.into_inner(),
+        |state, merchant_account, req| {
+            payment_links::create_payment_link(state, merchant_account, req)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentLinksRetrieve))]
+// #[get("/{payment_link_id}")]
+pub async fn payment_links_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        &state,
+        &req,
+        path.into_inner(),
+        |state, merchant_account, payment_link_id| {
+            payment_links::retrieve_payment_link(state, merchant_account, payment_link_id)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentLinksInitiate))]
+// #[get("/{merchant_id}/{payment_link_id}")]
+pub async fn payment_links_initiate(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+    let (merchant_id, payment_link_id) = path.into_inner();
+    api::server_wrap(
+        &state,
+        &req,
+        payment_link_id,
+        |state, merchant_account, payment_link_id| {
+            payment_links::initiate_payment_link(state, merchant_account, payment_link_id)
+        },
+        &auth::MerchantIdAuth(merchant_id),
+    )
+    .await
+}
@@ -149,6 +149,34 @@ pub async fn payment_method_delete_api(
     .await
 }
 
+#[instrument(skip_all, fields(flow = ?Flow::CustomerPaymentMethodsDelete))]
+// #[delete("/{customer_id}/payment_methods/{payment_method_id}")]
+pub async fn delete_customer_payment_method_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+    let (customer_id, payment_method_id) = path.into_inner();
+    let pm = PaymentMethodId { payment_method_id };
+
+    let auth_type = match auth::is_ephemeral_auth(req.headers(), &*state.store, &customer_id).await
+    {
+        Ok(auth_type) => auth_type,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    api::server_wrap(
+        &state,
+        &req,
+        pm,
+        |state, merchant_account, pm| {
+            cards::delete_customer_payment_method(state, merchant_account, &customer_id, pm)
+        },
+        &*auth_type,
+    )
+    .await
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::unwrap_used)]
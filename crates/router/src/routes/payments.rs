@@ -4,9 +4,9 @@ use router_env::{instrument, tracing, Flow};
 
 use crate::{
     self as app,
-    core::{errors::http_not_implemented, payments},
+    core::payments,
     services::{api, authentication as auth},
-    types::api::{self as api_types, enums as api_enums, payments as payment_types},
+    types::api::{self as api_types, payments as payment_types},
 };
 
 /// Payments - Create
@@ -30,10 +30,6 @@ pub async fn payments_create(
 ) -> impl Responder {
     let payload = json_payload.into_inner();
 
-    if let Some(api_enums::CaptureMethod::Scheduled) = payload.capture_method {
-        return http_not_implemented();
-    };
-
     api::server_wrap(
         &state,
         &req,
@@ -62,7 +58,41 @@ pub async fn payments_start(
     let payload = payment_types::PaymentsStartRequest {
         payment_id: payment_id.clone(),
         merchant_id: merchant_id.clone(),
-        txn_id: attempt_id.clone(),
+        txn_id: Some(attempt_id.clone()),
+    };
+    api::server_wrap(
+        &state,
+        &req,
+        payload,
+        |state, merchant_account, req| {
+            payments::payments_core::<api_types::Authorize, payment_types::PaymentsResponse, _, _, _>(
+                state,
+                merchant_account,
+                payments::operations::PaymentStart,
+                req,
+                api::AuthFlow::Client,
+                payments::CallConnectorAction::Trigger,
+            )
+        },
+        &auth::MerchantIdAuth(merchant_id),
+    )
+    .await
+}
+
+/// Forwards the shopper to the connector for authenticating the payment, using the redirection
+/// data stored on the active attempt of the payment.
+#[instrument(skip(state), fields(flow = ?Flow::PaymentsRedirect))]
+// #[get("/redirect/{payment_id}/{merchant_id}")]
+pub async fn payments_redirect(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<(String, String)>,
+) -> impl Responder {
+    let (payment_id, merchant_id) = path.into_inner();
+    let payload = payment_types::PaymentsStartRequest {
+        payment_id,
+        merchant_id: merchant_id.clone(),
+        txn_id: None,
     };
     api::server_wrap(
         &state,
@@ -97,11 +127,13 @@ pub async fn payments_retrieve(
         force_sync: json_payload.force_sync.unwrap_or(false),
         param: None,
         connector: None,
+        client_secret: json_payload.client_secret.clone(),
     };
-    let (auth_type, _auth_flow) = match auth::get_auth_type_and_flow(req.headers()) {
-        Ok(auth) => auth,
-        Err(err) => return api::log_and_return_error_response(report!(err)),
-    };
+    let (auth_type, auth_flow) =
+        match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
+            Ok(auth) => auth,
+            Err(err) => return api::log_and_return_error_response(err),
+        };
 
     api::server_wrap(
         &state,
@@ -113,7 +145,7 @@ pub async fn payments_retrieve(
                 merchant_account,
                 payments::PaymentStatus,
                 req,
-                api::AuthFlow::Merchant,
+                auth_flow,
                 payments::CallConnectorAction::Trigger,
             )
         },
@@ -132,19 +164,10 @@ pub async fn payments_update(
 ) -> impl Responder {
     let mut payload = json_payload.into_inner();
 
-    if let Some(api_enums::CaptureMethod::Scheduled) = payload.capture_method {
-        return http_not_implemented();
-    };
-
     let payment_id = path.into_inner();
 
     payload.payment_id = Some(payment_types::PaymentIdType::PaymentIntentId(payment_id));
 
-    let (auth_type, auth_flow) = match auth::get_auth_type_and_flow(req.headers()) {
-        Ok(auth) => auth,
-        Err(err) => return api::log_and_return_error_response(report!(err)),
-    };
-
     api::server_wrap(
         &state,
         &req,
@@ -155,10 +178,10 @@ pub async fn payments_update(
                 state,
                 merchant_account,
                 req,
-                auth_flow,
+                api::AuthFlow::Merchant,
             )
         },
-        &*auth_type,
+        &auth::ApiKeyAuth,
     )
     .await
 }
@@ -173,10 +196,6 @@ pub async fn payments_confirm(
 ) -> impl Responder {
     let mut payload = json_payload.into_inner();
 
-    if let Some(api_enums::CaptureMethod::Scheduled) = payload.capture_method {
-        return http_not_implemented();
-    };
-
     let payment_id = path.into_inner();
     payload.payment_id = Some(payment_types::PaymentIdType::PaymentIntentId(payment_id));
     payload.confirm = Some(true);
@@ -244,28 +263,17 @@ pub async fn payments_connector_session(
     json_payload: web::Json<payment_types::PaymentsSessionRequest>,
 ) -> impl Responder {
     let sessions_payload = json_payload.into_inner();
+    let auth_type = auth::PublishableKeyAuth {
+        client_secret: sessions_payload.client_secret.clone(),
+        payment_id: Some(sessions_payload.payment_id.clone()),
+    };
 
     api::server_wrap(
         &state,
         &req,
         sessions_payload,
-        |state, merchant_account, payload| {
-            payments::payments_core::<
-                api_types::Session,
-                payment_types::PaymentsSessionResponse,
-                _,
-                _,
-                _,
-            >(
-                state,
-                merchant_account,
-                payments::PaymentSession,
-                payload,
-                api::AuthFlow::Client,
-                payments::CallConnectorAction::Trigger,
-            )
-        },
-        &auth::PublishableKeyAuth,
+        payments::payments_session_core,
+        &auth_type,
     )
     .await
 }
@@ -285,6 +293,7 @@ pub async fn payments_response(
         force_sync: true,
         param: Some(param_string.to_string()),
         connector: Some(connector),
+        client_secret: None,
     };
     api::server_wrap(
         &state,
@@ -333,6 +342,44 @@ pub async fn payments_cancel(
     .await
 }
 
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsClone))]
+// #[post("/{payment_id}/clone")]
+pub async fn payments_clone(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: Option<web::Json<payment_types::PaymentsCloneRequest>>,
+    path: web::Path<String>,
+) -> impl Responder {
+    let mut payload = json_payload.map(web::Json::into_inner).unwrap_or_default();
+    payload.payment_id = path.into_inner();
+
+    api::server_wrap(
+        &state,
+        &req,
+        payload,
+        payments::payments_clone_core,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsClientSecretRegenerate))]
+// #[post("/{payment_id}/client_secret")]
+pub async fn payments_client_secret_regenerate(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    api::server_wrap(
+        &state,
+        &req,
+        path.into_inner(),
+        payments::payments_client_secret_regenerate_core,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
 #[instrument(skip_all, fields(flow = ?Flow::PaymentsList))]
 #[cfg(feature = "olap")]
 // #[get("/list")]
@@ -354,7 +401,58 @@ pub async fn payments_list(
     .await
 }
 
-async fn authorize_verify_select<Op>(
+/// Lists the payments with the constraints given in the request body, which is convenient for
+/// filtering on many fields at once.
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsListByFilter))]
+#[cfg(feature = "olap")]
+// #[post("/list")]
+pub async fn payments_list_by_filter(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    payload: web::Json<payment_types::PaymentListConstraints>,
+) -> impl Responder {
+    let payload = payload.into_inner();
+    api::server_wrap(
+        &state,
+        &req,
+        payload,
+        |state, merchant_account, req| {
+            payments::list_payments(&*state.store, merchant_account, req)
+        },
+        *auth::jwt_auth_or(&auth::ApiKeyAuth, req.headers()),
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::OrganizationPaymentsList))]
+#[cfg(feature = "olap")]
+// #[get("/{organization_id}/payments")]
+pub async fn organization_payments_list(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+    payload: web::Query<payment_types::OrganizationPaymentListConstraints>,
+) -> impl Responder {
+    let organization_id = path.into_inner();
+    let payload = payload.into_inner();
+    api::server_wrap(
+        &state,
+        &req,
+        payload,
+        |state, merchant_account, req| {
+            payments::list_organization_payments(
+                &*state.store,
+                merchant_account,
+                organization_id.clone(),
+                req,
+            )
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+pub(crate) async fn authorize_verify_select<Op>(
     operation: Op,
     state: &app::AppState,
     merchant_account: storage_models::merchant_account::MerchantAccount,
@@ -374,7 +472,23 @@ where
     // the operation are flow agnostic, and the flow is only required in the post_update_tracker
     // Thus the flow can be generated just before calling the connector instead of explicitly passing it here.
 
-    match req.amount.as_ref() {
+    // Payments against a stored mandate are merchant initiated and charge the customer without
+    // them being present, so they cannot be made from the client
+    if matches!(auth_flow, api::AuthFlow::Client) && req.mandate_id.is_some() {
+        return Err(report!(
+            app::core::errors::ApiErrorResponse::PreconditionFailed {
+                message: "Payments using a `mandate_id` must be authenticated with the API key"
+                    .into()
+            }
+        ));
+    }
+
+    // Zero-amount payments only set up the payment method for future usage, which is done
+    // through the verify flow of the connector
+    let amount =
+        payments::helpers::get_payment_amount(&*state.store, &merchant_account, &req).await;
+
+    match amount {
         Some(api_types::Amount::Value(_)) | None => payments::payments_core::<
             api_types::Authorize,
             payment_types::PaymentsResponse,
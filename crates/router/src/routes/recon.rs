@@ -0,0 +1,64 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::recon,
+    services::{api, authentication as auth},
+    types::api::recon as recon_types,
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::ReconReportsCreate))]
+// #[post("/reports")]
+pub async fn recon_reports_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<recon_types::ReconReportCreateRequest>,
+) -> HttpResponse {
+    api::server_wrap(
+        &state,
+        &req,
+        json_payload.into_inner(),
+        |state, merchant_account, req| recon::create_report(state, merchant_account, req),
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ReconReportsList))]
+// #[get("/reports")]
+pub async fn recon_reports_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<recon_types::ReconReportListConstraints>,
+) -> HttpResponse {
+    api::server_wrap(
+        &state,
+        &req,
+        query.into_inner(),
+        |state, merchant_account, constraints| {
+            recon::list_reports(&*state.store, merchant_account, constraints)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ReconReportsRetrieve))]
+// #[get("/reports/{report_id}")]
+pub async fn recon_reports_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        &state,
+        &req,
+        path.into_inner(),
+        |state, merchant_account, report_id| {
+            recon::retrieve_report(&*state.store, merchant_account, report_id)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
@@ -37,6 +37,23 @@ pub async fn refunds_create(
     .await
 }
 
+#[instrument(skip_all, fields(flow = ?Flow::RefundsAlternateAccountCreate))]
+// #[post("/alternate_account")]
+pub async fn refunds_alternate_account_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<refunds::RefundAlternateAccountRequest>,
+) -> HttpResponse {
+    api::server_wrap(
+        &state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req| refund_alternate_account_create_core(state, req),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
 #[instrument(skip_all, fields(flow = ?Flow::RefundsRetrieve))]
 // #[get("/{id}")]
 pub async fn refunds_retrieve(
@@ -79,8 +96,60 @@ pub async fn refunds_update(
     .await
 }
 
+#[instrument(skip_all, fields(flow = ?Flow::RefundsBatchCreate))]
+// #[post("/batch")]
+pub async fn refunds_batch_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_models::refunds::RefundBatchRequest>,
+) -> HttpResponse {
+    api::server_wrap(
+        &state,
+        &req,
+        json_payload.into_inner(),
+        refund_batch_create_core,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::RefundsBatchRetrieve))]
+// #[get("/batch/{batch_id}")]
+pub async fn refunds_batch_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        &state,
+        &req,
+        path.into_inner(),
+        refund_batch_retrieve_core,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::RefundsBatchReport))]
+// #[post("/batch/{batch_id}/report")]
+pub async fn refunds_batch_report(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    api::server_wrap(
+        &state,
+        &req,
+        path.into_inner(),
+        refund_batch_report_core,
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
 #[instrument(skip_all, fields(flow = ?Flow::RefundsList))]
 #[cfg(feature = "olap")]
+// #[get("")]
 // #[get("/list")]
 pub async fn refunds_list(
     state: web::Data<AppState>,
@@ -96,3 +165,23 @@ pub async fn refunds_list(
     )
     .await
 }
+
+/// Lists the refunds with the constraints given in the request body, which is convenient for
+/// filtering on many fields at once.
+#[instrument(skip_all, fields(flow = ?Flow::RefundsListByFilter))]
+#[cfg(feature = "olap")]
+// #[post("/list")]
+pub async fn refunds_list_by_filter(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Json<api_models::refunds::RefundListRequest>,
+) -> HttpResponse {
+    api::server_wrap(
+        &state,
+        &req,
+        payload.into_inner(),
+        |state, merchant_account, req| refund_list(&*state.store, merchant_account, req),
+        *auth::jwt_auth_or(&auth::ApiKeyAuth, req.headers()),
+    )
+    .await
+}
@@ -4,7 +4,9 @@ use router_env::{instrument, tracing, Flow};
 use super::app::AppState;
 use crate::{
     core::webhooks,
+    db::payment_audit_log,
     services::{api, authentication as auth},
+    types::{api::webhooks as webhook_types, storage::enums},
 };
 
 #[instrument(skip_all, fields(flow = ?Flow::IncomingWebhookReceive))]
@@ -21,9 +23,55 @@ pub async fn receive_incoming_webhook(
         &req,
         body,
         |state, merchant_account, body| {
-            webhooks::webhooks_core(state, &req, merchant_account, &connector_name, body)
+            payment_audit_log::with_audit_actor(
+                enums::AuditActor::Webhook,
+                webhooks::webhooks_core(state, &req, merchant_account, &connector_name, body, true),
+            )
         },
         &auth::MerchantIdAuth(merchant_id),
     )
     .await
 }
+
+#[instrument(skip_all, fields(flow = ?Flow::IncomingWebhookReplay))]
+pub async fn replay_incoming_webhook(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    body: web::Bytes,
+    path: web::Path<(String, String)>,
+) -> impl Responder {
+    let (merchant_id, connector_name) = path.into_inner();
+
+    api::server_wrap(
+        &state,
+        &req,
+        body,
+        |state, _, body| {
+            payment_audit_log::with_audit_actor(
+                enums::AuditActor::Webhook,
+                webhooks::replay_incoming_webhook(state, &req, &merchant_id, &connector_name, body),
+            )
+        },
+        *auth::jwt_auth_or(&auth::AdminApiAuth, req.headers()),
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::OutgoingWebhookTest))]
+pub async fn trigger_test_webhook(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<webhook_types::TestWebhookRequest>,
+) -> impl Responder {
+    let merchant_id = path.into_inner();
+
+    api::server_wrap(
+        &state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req| webhooks::trigger_test_webhook(state, &merchant_id, req),
+        *auth::jwt_auth_or(&auth::AdminApiAuth, req.headers()),
+    )
+    .await
+}
@@ -2,6 +2,7 @@
 
 pub mod consumer;
 pub mod metrics;
+pub mod outbox_relay;
 pub mod producer;
 pub mod types;
 pub mod utils;
@@ -33,6 +34,10 @@ pub async fn start_process_tracker(
         SchedulerFlow::Cleaner => {
             error!("This flow has not been implemented yet!");
         }
+        SchedulerFlow::OutboxRelay => {
+            outbox_relay::start_outbox_relay(state, Arc::clone(&options), scheduler_settings)
+                .await?
+        }
     }
     Ok(())
 }
@@ -19,7 +19,7 @@ use super::{
 use crate::{
     configs::settings,
     core::errors::{self, CustomResult},
-    db::StorageInterface,
+    db::{payment_audit_log, StorageInterface},
     logger::{error, info},
     routes::AppState,
     scheduler::utils as pt_utils,
@@ -176,7 +176,11 @@ pub async fn start_workflow(
     _pickup_time: PrimitiveDateTime,
     runner: workflows::PTRunner,
 ) {
-    workflows::perform_workflow_execution(&state, process, runner).await
+    payment_audit_log::with_audit_actor(
+        enums::AuditActor::Scheduler,
+        workflows::perform_workflow_execution(&state, process, runner),
+    )
+    .await
 }
 
 pub async fn run_executor<'a>(
@@ -27,3 +27,4 @@ create_counter!(TASK_CONSUMED, PT_METER); // Tasks consumed by consumer
 create_counter!(TASK_PROCESSED, PT_METER); // Tasks completed processing
 create_counter!(TASK_FINISHED, PT_METER); // Tasks finished
 create_counter!(TASK_RETRIED, PT_METER); // Tasks added for retries
+create_counter!(OUTBOX_EVENTS_PUBLISHED, PT_METER); // Outbox events published by the relay
@@ -0,0 +1,133 @@
+use std::sync::Arc;
+
+use error_stack::{IntoReport, ResultExt};
+use redis_interface::RedisEntryId;
+use router_env::{instrument, tracing};
+
+use super::metrics;
+use crate::{
+    configs::settings::{OutboxRelaySettings, SchedulerSettings},
+    core::errors::{self, CustomResult},
+    db::StorageInterface,
+    logger::{debug, error, info},
+    routes::AppState,
+    scheduler::SchedulerOptions,
+    types::storage,
+};
+
+/// Publishes the domain events recorded in the outbox to the outbox stream, from which they are
+/// consumed by the services interested in them.
+///
+/// An event is marked as published only after it has been added to the stream, so an event is
+/// published at least once. Consumers are expected to discard duplicates using the event ID.
+#[instrument(skip_all)]
+pub async fn start_outbox_relay(
+    state: &AppState,
+    options: Arc<SchedulerOptions>,
+    scheduler_settings: Arc<SchedulerSettings>,
+) -> CustomResult<(), errors::ProcessTrackerError> {
+    let mut interval = tokio::time::interval(std::time::Duration::from_millis(
+        options.looper_interval.milliseconds,
+    ));
+
+    loop {
+        interval.tick().await;
+
+        if options.readiness.is_ready {
+            if let Err(error) = run_outbox_relay_flow(state, &scheduler_settings.outbox_relay).await
+            {
+                // Intentionally not propagating error to caller, the events which could not be
+                // published are retried in the next run of the relay.
+                error!(%error);
+            }
+        } else {
+            info!("Terminating outbox relay");
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+/// Runs the relay if no other instance of the relay is running, so that the events are published
+/// in the order they were recorded.
+#[instrument(skip_all)]
+pub async fn run_outbox_relay_flow(
+    state: &AppState,
+    settings: &OutboxRelaySettings,
+) -> CustomResult<(), errors::ProcessTrackerError> {
+    let tag = "OUTBOX_RELAY_LOCK";
+    let lock_val = "LOCKED";
+    if !state
+        .store
+        .acquire_pt_lock(tag, &settings.lock_key, lock_val, settings.lock_ttl)
+        .await
+    {
+        return Ok(());
+    }
+
+    let result = publish_outbox_events(&*state.store, settings).await;
+    state.store.release_pt_lock(tag, &settings.lock_key).await;
+    result
+}
+
+async fn publish_outbox_events(
+    db: &dyn StorageInterface,
+    settings: &OutboxRelaySettings,
+) -> CustomResult<(), errors::ProcessTrackerError> {
+    let events = db
+        .find_unpublished_outbox_events(settings.batch_size)
+        .await
+        .change_context(errors::ProcessTrackerError::ResourceFetchingFailed {
+            resource_name: "outbox events".to_string(),
+        })?;
+    debug!("Outbox relay count of events {}", events.len());
+
+    let mut published_ids = Vec::with_capacity(events.len());
+    let mut publish_result = Ok(());
+    for event in events {
+        let event_id = event.id;
+        // The remaining events are published in the next run, to keep them in order
+        publish_result = publish_outbox_event(db, &settings.stream, event).await;
+        if publish_result.is_err() {
+            break;
+        }
+        published_ids.push(event_id);
+    }
+
+    if !published_ids.is_empty() {
+        let count = db
+            .mark_outbox_events_published(published_ids)
+            .await
+            .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)
+            .attach_printable("Failed to mark the outbox events as published")?;
+        // Safety: Assuming we won't publish more than `u64::MAX` events at once
+        #[allow(clippy::as_conversions)]
+        metrics::OUTBOX_EVENTS_PUBLISHED.add(&metrics::CONTEXT, count as u64, &[]);
+    }
+
+    publish_result
+}
+
+async fn publish_outbox_event(
+    db: &dyn StorageInterface,
+    stream: &str,
+    event: storage::OutboxEvent,
+) -> CustomResult<(), errors::ProcessTrackerError> {
+    let payload = serde_json::to_string(&event.payload)
+        .into_report()
+        .change_context(errors::ProcessTrackerError::SerializationFailed)?;
+    let fields = vec![
+        ("event_id", event.event_id),
+        ("event_type", event.event_type.to_string()),
+        ("merchant_id", event.merchant_id),
+        ("object_id", event.object_id),
+        ("created_at", event.created_at.to_string()),
+        ("payload", payload),
+    ];
+
+    db.stream_append_entry(stream, &RedisEntryId::AutoGeneratedID, fields)
+        .await
+        .change_context(errors::ProcessTrackerError::BatchInsertionFailed)
+        .attach_printable("Failed to publish the outbox event")
+}
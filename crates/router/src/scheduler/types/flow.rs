@@ -4,4 +4,5 @@ pub enum SchedulerFlow {
     Producer,
     Consumer,
     Cleaner,
+    OutboxRelay,
 }
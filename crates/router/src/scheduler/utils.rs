@@ -12,6 +12,7 @@ use super::{consumer, metrics, process_data, workflows};
 use crate::{
     configs::settings::SchedulerSettings,
     core::errors::{self, CustomResult},
+    db::{get_and_deserialize_key, StorageInterface},
     logger,
     routes::AppState,
     scheduler::{ProcessTrackerBatch, SchedulerFlow},
@@ -291,6 +292,23 @@ pub fn add_histogram_metrics(
     };
 }
 
+/// Retry policy of a task, configured in redis under `key`. The default policy is used when none
+/// is configured.
+pub async fn get_pt_mapping(
+    db: &dyn StorageInterface,
+    key: &str,
+) -> process_data::ConnectorPTMapping {
+    let redis_mapping: CustomResult<process_data::ConnectorPTMapping, errors::RedisError> =
+        get_and_deserialize_key(db, key, "ConnectorPTMapping").await;
+    match redis_mapping {
+        Ok(x) => x,
+        Err(err) => {
+            logger::error!("Redis Mapping Error: {}", err);
+            process_data::ConnectorPTMapping::default()
+        }
+    }
+}
+
 pub fn get_schedule_time(
     mapping: process_data::ConnectorPTMapping,
     merchant_name: &str,
@@ -4,7 +4,12 @@ use strum::EnumString;
 
 use crate::{core::errors, routes::AppState, scheduler::consumer, types::storage};
 
+pub mod export;
+pub mod ops;
+pub mod outgoing_webhook_retry;
+pub mod payment_capture;
 pub mod payment_sync;
+pub mod recon;
 pub mod refund_router;
 
 macro_rules! runners {
@@ -43,7 +48,12 @@ macro_rules! as_item {
 
 runners! {
     PaymentsSyncWorkflow,
-    RefundWorkflowRouter
+    PaymentsCaptureWorkflow,
+    RefundWorkflowRouter,
+    OutgoingWebhookRetryWorkflow,
+    ExportWorkflow,
+    ReconWorkflow,
+    OpsWorkflow
 }
 
 #[async_trait]
@@ -0,0 +1,58 @@
+use router_env::logger;
+
+use super::{ExportWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::exports as exports_core,
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    scheduler::consumer,
+    types::storage::{self, enums, ProcessTrackerExt},
+    utils::ValueExt,
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for ExportWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::ExportTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("ExportTrackingData")?;
+
+        let export = db
+            .find_export_by_merchant_id_export_id(
+                &tracking_data.merchant_id,
+                &tracking_data.export_id,
+            )
+            .await?;
+
+        if export.status == enums::ExportStatus::Pending {
+            let export = exports_core::generate_export(state, export).await?;
+            logger::info!(
+                export_id = %export.export_id,
+                status = %export.status,
+                "Export generated"
+            );
+        }
+
+        let id = process.id.clone();
+        process
+            .finish_with_status(db, format!("COMPLETED_BY_PT_{}", id))
+            .await?;
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::some_error_handler(state, process, error).await
+    }
+}
@@ -0,0 +1,22 @@
+use super::{OpsWorkflow, ProcessTrackerWorkflow};
+use crate::{core::ops as ops_core, errors, routes::AppState, scheduler::consumer, types::storage};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for OpsWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        ops_core::trigger_ops_batch_workflow(state, &process).await
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::some_error_handler(state, process, error).await
+    }
+}
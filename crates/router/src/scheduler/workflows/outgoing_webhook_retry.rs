@@ -0,0 +1,108 @@
+use router_env::logger;
+
+use super::{OutgoingWebhookRetryWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::webhooks as webhooks_core,
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    scheduler::consumer,
+    types::storage::{self, ProcessTrackerExt},
+    utils::ValueExt,
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for OutgoingWebhookRetryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let webhooks_config = &state.conf.webhooks;
+        let tracking_data: storage::OutgoingWebhookTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("OutgoingWebhookTrackingData")?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id)
+            .await?;
+
+        // The first delivery attempt is made before the process is created
+        let attempt = process.retry_count + 2;
+        let result = webhooks_core::trigger_webhook_to_merchant(
+            state,
+            &merchant_account,
+            &tracking_data.event_id,
+            attempt,
+            &tracking_data.webhook,
+        )
+        .await;
+
+        match result {
+            Ok(()) => {
+                webhooks_core::mark_event_as_notified(db, tracking_data.event_id).await;
+                let id = process.id.clone();
+                process
+                    .finish_with_status(db, format!("COMPLETED_BY_PT_{}", id))
+                    .await?
+            }
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    event_id = %tracking_data.event_id,
+                    attempt,
+                    "Outgoing webhook delivery failed"
+                );
+
+                if !webhooks_core::is_retryable_delivery_error(error.current_context())
+                    || attempt >= webhooks_config.outgoing_max_attempts
+                {
+                    process
+                        .finish_with_status(db, "RETRIES_EXCEEDED".to_string())
+                        .await?
+                } else {
+                    let schedule_time = webhooks_core::get_outgoing_webhook_retry_schedule_time(
+                        webhooks_config,
+                        process.retry_count + 1,
+                    );
+                    process.retry(db, schedule_time).await?
+                }
+            }
+        };
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::some_error_handler(state, process, error).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::settings;
+
+    #[test]
+    fn test_outgoing_webhook_retry_delay() {
+        let webhooks_config = settings::WebhooksSettings {
+            outgoing_max_attempts: 5,
+            outgoing_retry_interval: 60,
+            outgoing_max_retry_interval: 300,
+        };
+        let delays: Vec<i64> = (0..5)
+            .map(|retry_count| {
+                webhooks_core::get_outgoing_webhook_retry_delay(&webhooks_config, retry_count)
+                    .whole_seconds()
+            })
+            .collect();
+
+        assert_eq!(delays, vec![60, 120, 240, 300, 300]);
+    }
+}
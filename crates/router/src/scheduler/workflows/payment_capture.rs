@@ -0,0 +1,103 @@
+use super::{PaymentsCaptureWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::payments::{self as payment_flows, operations},
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    scheduler::{consumer, utils},
+    types::{
+        api,
+        storage::{self, enums, ProcessTrackerExt},
+    },
+    utils::{OptionExt, ValueExt},
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for PaymentsCaptureWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: api::PaymentsCaptureRequest = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentsCaptureRequest")?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                tracking_data
+                    .merchant_id
+                    .as_ref()
+                    .get_required_value("merchant_id")?,
+            )
+            .await?;
+
+        let (payment_data, _, _) = payment_flows::payments_operation_core::<api::Capture, _, _, _>(
+            state,
+            merchant_account,
+            operations::PaymentCapture,
+            tracking_data,
+            payment_flows::CallConnectorAction::Trigger,
+        )
+        .await?;
+
+        match payment_data.payment_attempt.status {
+            // The connector did not act on the capture, try again later
+            enums::AttemptStatus::Authorized => {
+                let connector = payment_data
+                    .payment_attempt
+                    .connector
+                    .clone()
+                    .ok_or(errors::ProcessTrackerError::MissingRequiredField)?;
+                retry_capture_task(
+                    db,
+                    &connector,
+                    &payment_data.payment_attempt.merchant_id,
+                    process,
+                )
+                .await?
+            }
+            enums::AttemptStatus::CaptureFailed | enums::AttemptStatus::Failure => {
+                process
+                    .finish_with_status(db, "CAPTURE_FAILED".to_string())
+                    .await?
+            }
+            _ => {
+                let id = process.id.clone();
+                process
+                    .finish_with_status(db, format!("COMPLETED_BY_PT_{}", id))
+                    .await?
+            }
+        };
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::some_error_handler(state, process, error).await
+    }
+}
+
+pub async fn retry_capture_task(
+    db: &dyn StorageInterface,
+    connector: &str,
+    merchant_id: &str,
+    pt: storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let mapping = utils::get_pt_mapping(db, &format!("pt_mapping_capture_{}", connector)).await;
+    let time_delta = utils::get_schedule_time(mapping, merchant_id, pt.retry_count + 1);
+
+    match utils::get_time_from_delta(time_delta) {
+        Some(s_time) => pt.retry(db, s_time).await,
+        None => {
+            pt.finish_with_status(db, "RETRIES_EXCEEDED".to_string())
+                .await
+        }
+    }
+}
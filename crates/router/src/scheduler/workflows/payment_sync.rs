@@ -2,11 +2,15 @@ use router_env::logger;
 
 use super::{PaymentsSyncWorkflow, ProcessTrackerWorkflow};
 use crate::{
-    core::payments::{self as payment_flows, operations},
-    db::{get_and_deserialize_key, StorageInterface},
+    core::{
+        payments::{self as payment_flows, operations},
+        webhooks as webhooks_core,
+    },
+    db::StorageInterface,
     errors,
     routes::AppState,
-    scheduler::{consumer, process_data, utils},
+    scheduler::{consumer, utils},
+    services,
     types::{
         api,
         storage::{self, enums, ProcessTrackerExt},
@@ -36,6 +40,19 @@ impl ProcessTrackerWorkflow for PaymentsSyncWorkflow {
             )
             .await?;
 
+        let previous_status = match &tracking_data.resource_id {
+            api::PaymentIdType::PaymentAttemptId(attempt_id) => db
+                .find_payment_attempt_by_merchant_id_attempt_id(
+                    &merchant_account.merchant_id,
+                    attempt_id,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .ok()
+                .map(|payment_attempt| payment_attempt.status),
+            _ => None,
+        };
+
         let (payment_data, _, _) = payment_flows::payments_operation_core::<api::PSync, _, _, _>(
             state,
             merchant_account.clone(),
@@ -48,14 +65,29 @@ impl ProcessTrackerWorkflow for PaymentsSyncWorkflow {
         let terminal_status = vec![
             enums::AttemptStatus::RouterDeclined,
             enums::AttemptStatus::Charged,
+            enums::AttemptStatus::PartialCharged,
             enums::AttemptStatus::AutoRefunded,
+            enums::AttemptStatus::Authorized,
+            enums::AttemptStatus::AuthenticationFailed,
+            enums::AttemptStatus::AuthorizationFailed,
             enums::AttemptStatus::Voided,
             enums::AttemptStatus::VoidFailed,
             enums::AttemptStatus::CaptureFailed,
             enums::AttemptStatus::Failure,
         ];
-        match &payment_data.payment_attempt.status {
-            status if terminal_status.contains(status) => {
+        match payment_data.payment_attempt.status {
+            status if terminal_status.contains(&status) => {
+                // The merchant is only notified when this sync moved the payment to a final
+                // status, updates made elsewhere notify the merchant themselves
+                if previous_status != Some(status)
+                    && matches!(
+                        payment_data.payment_intent.status,
+                        enums::IntentStatus::Succeeded | enums::IntentStatus::Failed
+                    )
+                {
+                    notify_merchant(state, merchant_account, tracking_data, payment_data).await;
+                }
+
                 let id = process.id.clone();
                 process
                     .finish_with_status(db, format!("COMPLETED_BY_PT_{}", id))
@@ -89,26 +121,49 @@ impl ProcessTrackerWorkflow for PaymentsSyncWorkflow {
     }
 }
 
+async fn notify_merchant(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    tracking_data: api::PaymentsRetrieveRequest,
+    payment_data: payment_flows::PaymentData<api::PSync>,
+) {
+    let payments_response = <api::PaymentsResponse as payment_flows::transformers::ToResponse<
+        _,
+        _,
+        _,
+    >>::generate_response(
+        Some(tracking_data),
+        payment_data,
+        None,
+        services::AuthFlow::Merchant,
+        &state.conf.server,
+        operations::PaymentStatus,
+    );
+
+    match payments_response {
+        Ok(services::ApplicationResponse::Json(payments_response)) => {
+            let result = webhooks_core::trigger_payment_outgoing_webhook(
+                state.clone(),
+                merchant_account,
+                payments_response,
+            )
+            .await;
+            if let Err(error) = result {
+                logger::error!(?error, "Failed to send the payment status webhook");
+            }
+        }
+        Ok(_) => logger::error!("Unexpected response while building the payment status webhook"),
+        Err(error) => logger::error!(?error, "Failed to build the payment status webhook"),
+    }
+}
+
 pub async fn get_sync_process_schedule_time(
     db: &dyn StorageInterface,
     connector: &str,
     merchant_id: &str,
     retry_count: i32,
 ) -> Result<Option<time::PrimitiveDateTime>, errors::ProcessTrackerError> {
-    let redis_mapping: errors::CustomResult<process_data::ConnectorPTMapping, errors::RedisError> =
-        get_and_deserialize_key(
-            db,
-            &format!("pt_mapping_{}", connector),
-            "ConnectorPTMapping",
-        )
-        .await;
-    let mapping = match redis_mapping {
-        Ok(x) => x,
-        Err(err) => {
-            logger::error!("Redis Mapping Error: {}", err);
-            process_data::ConnectorPTMapping::default()
-        }
-    };
+    let mapping = utils::get_pt_mapping(db, &format!("pt_mapping_{}", connector)).await;
     let time_delta = utils::get_schedule_time(mapping, merchant_id, retry_count + 1);
 
     Ok(utils::get_time_from_delta(time_delta))
@@ -136,6 +191,7 @@ pub async fn retry_sync_task(
 mod tests {
     #![allow(clippy::expect_used, clippy::unwrap_used)]
     use super::*;
+    use crate::scheduler::process_data;
 
     #[test]
     fn test_get_default_schedule_time() {
@@ -0,0 +1,58 @@
+use router_env::logger;
+
+use super::{ProcessTrackerWorkflow, ReconWorkflow};
+use crate::{
+    core::recon as recon_core,
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    scheduler::consumer,
+    types::storage::{self, enums, ProcessTrackerExt},
+    utils::ValueExt,
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for ReconWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::ReconTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("ReconTrackingData")?;
+
+        let recon_report = db
+            .find_recon_report_by_merchant_id_report_id(
+                &tracking_data.merchant_id,
+                &tracking_data.report_id,
+            )
+            .await?;
+
+        if recon_report.status == enums::ReconReportStatus::Pending {
+            let recon_report = recon_core::process_report(state, recon_report).await?;
+            logger::info!(
+                report_id = %recon_report.report_id,
+                status = %recon_report.status,
+                "Recon report processed"
+            );
+        }
+
+        let id = process.id.clone();
+        process
+            .finish_with_status(db, format!("COMPLETED_BY_PT_{}", id))
+            .await?;
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::some_error_handler(state, process, error).await
+    }
+}
@@ -1,42 +1,114 @@
 pub mod api;
 pub mod authentication;
+pub mod connector_credentials;
 pub mod encryption;
+#[cfg(feature = "kafka_events")]
+pub mod kafka;
 pub mod logger;
+pub mod secrets_manager;
 
-use std::sync::Arc;
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
 
 pub use self::{api::*, encryption::*};
-use crate::connection::{diesel_make_pg_pool, PgPool};
+use crate::{
+    cache::{self, StoreCache},
+    connection::{diesel_make_pg_pool, monitor_redis_connections, monitor_replica_lag, PgPool},
+};
 
 #[derive(Clone)]
 pub struct Store {
     pub master_pool: PgPool,
-    #[cfg(feature = "olap")]
     pub replica_pool: PgPool,
+    /// Whether the replica lags behind the primary by more than the configured tolerance
+    replica_lagging: Arc<AtomicBool>,
     pub redis_conn: Arc<redis_interface::RedisConnectionPool>,
-    #[cfg(feature = "kv_store")]
+    pub(crate) cache: StoreCache,
     pub(crate) config: StoreConfig,
+    #[cfg(feature = "kafka_events")]
+    pub(crate) kafka_producer: kafka::KafkaProducer,
 }
 
-#[cfg(feature = "kv_store")]
 #[derive(Clone)]
 pub(crate) struct StoreConfig {
+    pub(crate) master_pool_size: u32,
+    pub(crate) replica_pool_size: u32,
+    /// The key with which the data keys of the merchants are encrypted
+    pub(crate) master_enc_key: Vec<u8>,
+    #[cfg(feature = "kv_store")]
     pub(crate) drainer_stream_name: String,
+    #[cfg(feature = "kv_store")]
     pub(crate) drainer_num_partitions: u8,
+    /// Whether the keys of the payment hashes in the KV store are Redis Cluster hash tags
+    #[cfg(feature = "kv_store")]
+    pub(crate) hash_tagged_keys: bool,
+    /// Whether the payment intents of merchants using the KV store are written to the database
+    /// directly, instead of through the drainer
+    #[cfg(feature = "kv_store")]
+    pub(crate) write_through: bool,
 }
 
 impl Store {
+    #[allow(clippy::expect_used)]
     pub async fn new(config: &crate::configs::settings::Settings, test_transaction: bool) -> Self {
+        let replica_pool = diesel_make_pg_pool(&config.replica_database, test_transaction).await;
+        let replica_lagging = Arc::new(AtomicBool::new(false));
+        tokio::spawn(monitor_replica_lag(
+            replica_pool.clone(),
+            replica_lagging.clone(),
+            config.replica_lag.clone(),
+        ));
+        let redis_conn = Arc::new(crate::connection::redis_connection(config).await);
+        tokio::spawn(monitor_redis_connections(redis_conn.connection_events()));
+        let cache = StoreCache::new(&config.cache);
+        let invalidations = redis_conn
+            .subscribe(cache::INVALIDATION_CHANNEL)
+            .await
+            .expect("Failed to subscribe to cache invalidations");
+        tokio::spawn(cache::listen_for_invalidations(
+            cache.clone(),
+            invalidations,
+        ));
+
         Self {
             master_pool: diesel_make_pg_pool(&config.master_database, test_transaction).await,
-            #[cfg(feature = "olap")]
-            replica_pool: diesel_make_pg_pool(&config.replica_database, test_transaction).await,
-            redis_conn: Arc::new(crate::connection::redis_connection(config).await),
-            #[cfg(feature = "kv_store")]
+            replica_pool,
+            replica_lagging,
+            redis_conn,
+            cache,
             config: StoreConfig {
+                master_pool_size: config.master_database.pool_size,
+                replica_pool_size: config.replica_database.pool_size,
+                master_enc_key: hex::decode(&config.secrets.master_enc_key)
+                    .expect("Failed to decode the master encryption key"),
+                #[cfg(feature = "kv_store")]
                 drainer_stream_name: config.drainer.stream_name.clone(),
+                #[cfg(feature = "kv_store")]
                 drainer_num_partitions: config.drainer.num_partitions,
+                #[cfg(feature = "kv_store")]
+                hash_tagged_keys: config.drainer.hash_tagged_keys,
+                #[cfg(feature = "kv_store")]
+                write_through: config.drainer.write_through,
             },
+            #[cfg(feature = "kafka_events")]
+            kafka_producer: crate::connection::kafka_producer(
+                &config.kafka,
+                &config.secrets.anonymization_key,
+            ),
+        }
+    }
+
+    /// The pool from which list and analytics queries are served, which is the replica unless it
+    /// lags behind the primary by more than the configured tolerance. Lookups which payment flows,
+    /// validations or authentication act upon are always made on the primary, along with writes,
+    /// as the replica may not have the latest state of the row yet.
+    pub fn read_pool(&self) -> &PgPool {
+        if self.replica_lagging.load(Ordering::Relaxed) {
+            &self.master_pool
+        } else {
+            &self.replica_pool
         }
     }
 
@@ -1,26 +1,29 @@
 mod client;
 pub(crate) mod request;
 
-use std::{collections::HashMap, fmt::Debug, future::Future, str, time::Instant};
+use std::{borrow::Cow, collections::HashMap, fmt::Debug, future::Future, str, time::Instant};
 
 use actix_web::{body, HttpRequest, HttpResponse, Responder};
 use bytes::Bytes;
+use common_utils::crypto::{self, VerifySignature};
 use error_stack::{report, IntoReport, Report, ResultExt};
-use masking::ExposeOptionInterface;
-use router_env::{instrument, tracing, Tag};
+use masking::{ExposeOptionInterface, Maskable, PeekInterface};
+use router_env::{instrument, tracing, tracing::Instrument, Tag};
 use serde::Serialize;
 
 use self::request::{ContentType, HeaderExt, RequestBuilderExt};
 pub use self::request::{Method, Request, RequestBuilder};
 use crate::{
-    configs::settings::Connectors,
+    configs::settings::{Connectors, DebugTraceSettings},
+    consts,
     core::{
+        api_keys,
         errors::{self, CustomResult, RouterResponse, RouterResult},
         payments,
     },
     db::StorageInterface,
     logger,
-    routes::AppState,
+    routes::{metrics, AppState},
     services::authentication as auth,
     types::{
         self, api,
@@ -121,7 +124,7 @@ pub trait ConnectorIntegration<T, Req, Resp>: ConnectorIntegrationAny<T, Req, Re
     }
 }
 
-#[instrument(skip_all)]
+#[instrument(skip_all, fields(connector = %req.connector, payment_id = %req.payment_id))]
 pub async fn execute_connector_processing_step<
     'b,
     'a,
@@ -156,12 +159,26 @@ where
             Ok(router_data)
         }
         payments::CallConnectorAction::Trigger => {
-            match connector_integration.build_request(req, &state.conf.connectors)? {
+            let connectors = if req.test_mode.unwrap_or(false) {
+                Cow::Owned(state.conf.connectors.sandbox())
+            } else {
+                Cow::Borrowed(&state.conf.connectors)
+            };
+            match connector_integration.build_request(req, &connectors)? {
                 Some(request) => {
+                    let masked_request = mask_connector_request(&request);
                     let response = call_connector_api(state, request).await;
                     match response {
                         Ok(body) => {
-                            let response = match body {
+                            let (Ok(raw_response) | Err(raw_response)) = &body;
+                            let connector_api_log = serde_json::json!({
+                                "request": masked_request,
+                                "response": mask_connector_body(&raw_response.response, false),
+                                "status_code": raw_response.status_code,
+                            });
+                            logger::debug!(%connector_api_log);
+
+                            let mut response = match body {
                                 Ok(body) => connector_integration.handle_response(req, body)?,
                                 Err(body) => {
                                     let error =
@@ -171,11 +188,15 @@ where
                                     router_data
                                 }
                             };
+                            response.connector_api_log = Some(connector_api_log);
                             logger::debug!(?response);
                             Ok(response)
                         }
-                        Err(error) => Err(error
-                            .change_context(errors::ConnectorError::ProcessingStepFailed(None))),
+                        Err(error) => {
+                            logger::debug!(connector_request = %masked_request);
+                            Err(error
+                                .change_context(errors::ConnectorError::ProcessingStepFailed(None)))
+                        }
                     }
                 }
                 None => Ok(router_data),
@@ -199,12 +220,12 @@ pub async fn call_connector_api(
     handle_response(response).await
 }
 
-#[instrument(skip_all)]
+#[instrument(skip_all, fields(method = %request.method))]
 async fn send_request(
     state: &AppState,
     request: Request,
 ) -> CustomResult<reqwest::Response, errors::ApiClientError> {
-    logger::debug!(method=?request.method, headers=?request.headers, payload=?request.payload, ?request);
+    logger::debug!(method=?request.method, headers=?mask_connector_headers(&request.headers));
     let url = &request.url;
     let should_bypass_proxy = client::proxy_bypass_urls(&state.conf.locker).contains(url);
     let client = client::create_client(
@@ -214,7 +235,18 @@ async fn send_request(
         request.certificate,
         request.certificate_key,
     )?;
-    let headers = request.headers.construct_header_map()?;
+    // The trace context and the request ID are propagated to the called service, so that its
+    // spans, if any, are part of the same trace as the payment
+    let headers = request
+        .headers
+        .into_iter()
+        .chain(router_env::trace_context_headers())
+        .chain(
+            router_env::current_request_id()
+                .map(|request_id| (crate::headers::X_REQUEST_ID.to_string(), request_id)),
+        )
+        .collect::<request::Headers>()
+        .construct_header_map()?;
     match request.method {
         Method::Get => client.get(url).add_headers(headers).send().await,
         Method::Post => {
@@ -257,6 +289,8 @@ async fn send_request(
         Method::Delete => client.delete(url).add_headers(headers).send().await,
     }
     .map_err(|error| match error {
+        // The connection could not be established, so the request has not reached the connector
+        error if error.is_connect() => errors::ApiClientError::ConnectionFailed(error.to_string()),
         error if error.is_timeout() => errors::ApiClientError::RequestTimeoutReceived,
         _ => errors::ApiClientError::RequestNotSent(error.to_string()),
     })
@@ -264,6 +298,83 @@ async fn send_request(
     .attach_printable("Unable to send request to connector")
 }
 
+/// Headers whose values are logged as is. The values of all other headers are masked, as they may
+/// hold the credentials of the merchant.
+const UNMASKED_CONNECTOR_HEADERS: [&str; 2] =
+    [crate::headers::CONTENT_TYPE, crate::headers::ACCEPT];
+
+fn mask_connector_headers(headers: &request::Headers) -> Vec<(&str, &str)> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let is_unmasked = UNMASKED_CONNECTOR_HEADERS
+                .iter()
+                .any(|unmasked| name.eq_ignore_ascii_case(unmasked));
+            if is_unmasked {
+                (name.as_str(), value.as_str())
+            } else {
+                (name.as_str(), masking::MASKED_VALUE)
+            }
+        })
+        .collect()
+}
+
+/// Copy of a request to a connector with the sensitive data in its body masked, for logging it
+/// and storing it for supportability.
+fn mask_connector_request(request: &Request) -> serde_json::Value {
+    let is_form_encoded = matches!(request.content_type, Some(ContentType::FormUrlEncoded))
+        || request.headers.iter().any(|(name, value)| {
+            name.eq_ignore_ascii_case(crate::headers::CONTENT_TYPE)
+                && value.contains("application/x-www-form-urlencoded")
+        });
+    let body = request
+        .payload
+        .as_ref()
+        .map(|payload| mask_connector_body(payload.peek().as_bytes(), is_form_encoded))
+        .unwrap_or(serde_json::Value::Null);
+
+    serde_json::json!({
+        "method": request.method,
+        // The query may hold credentials, for connectors which accept them as query parameters
+        "url": request.url.split('?').next(),
+        "body": body,
+    })
+}
+
+/// Masks the sensitive data in a body exchanged with a connector. Bodies which are not JSON or
+/// form encoded cannot be inspected, and are masked entirely.
+fn mask_connector_body(body: &[u8], is_form_encoded: bool) -> serde_json::Value {
+    if body.is_empty() {
+        return serde_json::Value::Null;
+    }
+
+    let masked_body = if is_form_encoded {
+        serde_urlencoded::from_bytes::<Vec<(String, String)>>(body)
+            .ok()
+            .map(|fields| {
+                serde_json::Value::Object(
+                    fields
+                        .into_iter()
+                        .map(|(key, value)| {
+                            let value = if masking::is_sensitive_key(&key) {
+                                masking::MASKED_VALUE.to_string()
+                            } else {
+                                value
+                            };
+                            (key, serde_json::Value::String(value))
+                        })
+                        .collect(),
+                )
+            })
+    } else {
+        serde_json::from_slice::<serde_json::Value>(body)
+            .ok()
+            .map(|value| value.masked())
+    };
+
+    masked_body.unwrap_or_else(|| serde_json::Value::String(masking::MASKED_VALUE.to_string()))
+}
+
 #[instrument(skip_all)]
 async fn handle_response(
     response: CustomResult<reqwest::Response, errors::ApiClientError>,
@@ -273,7 +384,7 @@ async fn handle_response(
             logger::info!(?response);
             let status_code = response.status().as_u16();
             match status_code {
-                200..=202 | 302 => {
+                200..=202 | 204 | 302 => {
                     logger::debug!(response=?response);
                     // If needed add log line
                     // logger:: error!( error_parsing_response=?err);
@@ -339,6 +450,8 @@ pub enum ApplicationResponse<R> {
     TextPlain(String),
     JsonForRedirection(api::RedirectionResponse),
     Form(RedirectForm),
+    PaymentLinkForm(PaymentLinkFormData),
+    FileData(FileData),
 }
 
 #[derive(Debug, Eq, PartialEq, Serialize)]
@@ -350,10 +463,8 @@ impl From<&storage::PaymentAttempt> for ApplicationRedirectResponse {
     fn from(payment_attempt: &storage::PaymentAttempt) -> Self {
         Self {
             url: format!(
-                "/payments/start/{}/{}/{}",
-                &payment_attempt.payment_id,
-                &payment_attempt.merchant_id,
-                &payment_attempt.attempt_id
+                "/payments/redirect/{}/{}",
+                &payment_attempt.payment_id, &payment_attempt.merchant_id
             ),
         }
     }
@@ -376,13 +487,58 @@ impl RedirectForm {
     }
 }
 
+/// The details of a payment which are shown on the hosted checkout page of a payment link.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct PaymentLinkFormData {
+    /// The URL of the payment link, to which the customer returns after authenticating the payment
+    pub link: String,
+    pub base_url: String,
+    pub merchant_name: String,
+    pub payment_id: String,
+    /// The amount of the payment in the major unit of the currency
+    pub amount: String,
+    pub currency: String,
+    pub status: storage::enums::PaymentLinkStatus,
+    pub publishable_key: String,
+    pub client_secret: String,
+}
+
+/// A file which is downloaded as an attachment.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct FileData {
+    pub file_name: String,
+    pub content_type: &'static str,
+    pub content: String,
+}
+
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum AuthFlow {
     Client,
     Merchant,
 }
 
-#[instrument(skip(request, payload, state, func, api_auth))]
+/// Rejects the request right away when it would have to wait for a connection, instead of
+/// queueing it behind the requests holding the connections.
+pub(crate) fn shed_load_if_overloaded(state: &AppState) -> RouterResult<()> {
+    let connection_pool_states = state.store.connection_pool_states();
+    if connection_pool_states.is_any_exhausted() {
+        logger::warn!(
+            ?connection_pool_states,
+            "Shedding load, connection pools exhausted"
+        );
+        metrics::LOAD_SHED_COUNT.add(&metrics::CONTEXT, 1, &[]);
+        Err(report!(errors::ApiErrorResponse::ServiceUnavailable))?
+    }
+    Ok(())
+}
+
+/// Authenticates the request and runs the flow.
+///
+/// A request with a valid `X-Debug-Trace` header, or one sampled for debug tracing as per the
+/// sample rate configured for its merchant, has its payloads logged, and the flow runs in a span
+/// which starts a new trace that is always exported. The trace covers the processing of the
+/// request only, and not the work the flow leaves to the scheduler.
+#[instrument(skip(request, payload, state, func, api_auth), fields(debug_trace_id))]
 pub async fn server_wrap_util<'a, 'b, U, T, Q, F, Fut>(
     state: &'b AppState,
     request: &'a HttpRequest,
@@ -395,11 +551,36 @@ where
     Fut: Future<Output = RouterResponse<Q>>,
     Q: Serialize + Debug + 'a,
     T: Debug,
+    U: auth::AuthInfo,
 {
+    shed_load_if_overloaded(state)?;
+
     let auth_out = api_auth
         .authenticate_and_fetch(request.headers(), state)
         .await?;
-    func(state, auth_out, payload).await
+
+    // Requests without the header can only be sampled once the merchant is known
+    let debug_trace_id =
+        get_debug_trace_id(request, &state.conf.secrets.debug_trace_key).or_else(|| {
+            get_sampled_debug_trace_id(&state.conf.debug_trace, auth_out.get_merchant_id())
+        });
+
+    match debug_trace_id {
+        Some(debug_trace_id) => {
+            tracing::Span::current().record("debug_trace_id", debug_trace_id.as_str());
+            let debug_trace_span =
+                tracing::info_span!("debug_trace", debug_trace_id = debug_trace_id.as_str());
+            let otel_trace_id = logger::start_sampled_trace(&debug_trace_span);
+            logger::info!(debug_trace_id, otel_trace_id, request_payload = ?payload);
+
+            let output = func(state, auth_out, payload)
+                .instrument(debug_trace_span)
+                .await;
+            logger::info!(debug_trace_id, response = ?output);
+            output
+        }
+        None => func(state, auth_out, payload).await,
+    }
 }
 
 #[instrument(
@@ -418,6 +599,7 @@ where
     Fut: Future<Output = RouterResult<ApplicationResponse<Q>>>,
     Q: Serialize + Debug + 'a,
     T: Debug,
+    U: auth::AuthInfo,
 {
     let request_method = request.method().as_str();
     let url_path = request.path();
@@ -427,7 +609,8 @@ where
     let start_instant = Instant::now();
     logger::info!(tag = ?Tag::BeginRequest);
 
-    let res = match server_wrap_util(state, request, payload, func, api_auth).await {
+    let output = server_wrap_util(state, request, payload, func, api_auth).await;
+    let res = match output {
         Ok(ApplicationResponse::Json(response)) => match serde_json::to_string(&response) {
             Ok(res) => http_response_json(res),
             Err(_) => http_response_err(
@@ -455,6 +638,10 @@ where
         Ok(ApplicationResponse::Form(response)) => build_redirection_form(&response)
             .respond_to(request)
             .map_into_boxed_body(),
+        Ok(ApplicationResponse::PaymentLinkForm(response)) => build_payment_link_form(&response)
+            .respond_to(request)
+            .map_into_boxed_body(),
+        Ok(ApplicationResponse::FileData(file_data)) => http_response_file_data(file_data),
 
         Err(error) => log_and_return_error_response(error),
     };
@@ -471,6 +658,56 @@ where
     res
 }
 
+/// Returns the trace ID of a valid `X-Debug-Trace` header, which forces the request and response
+/// payloads to be logged for the request.
+///
+/// The header has the format `<trace_id>.<expires_at>.<signature>`, where `expires_at` is a unix
+/// timestamp and `signature` is the hex encoded HMAC-SHA256 of `<trace_id>.<expires_at>` computed
+/// using the configured debug trace key. The header is ignored if no key is configured.
+fn get_debug_trace_id(request: &HttpRequest, debug_trace_key: &str) -> Option<String> {
+    if debug_trace_key.is_empty() {
+        return None;
+    }
+
+    let header = request
+        .headers()
+        .get(crate::headers::X_DEBUG_TRACE)?
+        .to_str()
+        .ok()?;
+    let debug_trace_id = header.rsplit_once('.').and_then(|(message, signature)| {
+        let (trace_id, expires_at) = message.rsplit_once('.')?;
+        let expires_at = expires_at.parse::<i64>().ok()?;
+        let signature = hex::decode(signature).ok()?;
+        let is_signature_valid = crypto::HmacSha256
+            .verify_signature(debug_trace_key.as_bytes(), &signature, message.as_bytes())
+            .ok()?;
+        let is_expired = expires_at < common_utils::date_time::now().assume_utc().unix_timestamp();
+
+        (is_signature_valid && !is_expired).then(|| trace_id.to_string())
+    });
+
+    if debug_trace_id.is_none() {
+        logger::warn!("Ignoring invalid or expired debug trace header");
+    }
+    debug_trace_id
+}
+
+/// Returns a new trace ID for the requests which are sampled for debug tracing, according to the
+/// sample rate configured for the merchant.
+fn get_sampled_debug_trace_id(
+    debug_trace: &DebugTraceSettings,
+    merchant_id: Option<&str>,
+) -> Option<String> {
+    let merchant_id = merchant_id?;
+    let rule = debug_trace
+        .sampling_rules
+        .iter()
+        .find(|rule| rule.merchant_id == merchant_id)?;
+
+    (rand::random::<f64>() < rule.sample_rate)
+        .then(|| crate::utils::generate_id(consts::ID_LENGTH, "trace"))
+}
+
 pub fn log_and_return_error_response<T>(error: Report<T>) -> HttpResponse
 where
     T: actix_web::ResponseError + error_stack::Context,
@@ -483,10 +720,8 @@ pub async fn authenticate_by_api_key(
     store: &dyn StorageInterface,
     api_key: &str,
 ) -> RouterResult<storage::MerchantAccount> {
-    store
-        .find_merchant_account_by_api_key(api_key)
+    api_keys::find_merchant_account_by_api_key(store, api_key)
         .await
-        .change_context(errors::ApiErrorResponse::Unauthorized)
         .attach_printable("Merchant not authenticated")
 }
 
@@ -504,6 +739,17 @@ pub fn http_response_plaintext<T: body::MessageBody + 'static>(res: T) -> HttpRe
         .body(res)
 }
 
+pub fn http_response_file_data(file_data: FileData) -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type(file_data.content_type)
+        .append_header((
+            "Content-Disposition",
+            format!("attachment; filename=\"{}\"", file_data.file_name),
+        ))
+        .append_header(("Via", "Juspay_router"))
+        .body(file_data.content)
+}
+
 pub fn http_response_ok() -> HttpResponse {
     HttpResponse::Ok().finish()
 }
@@ -583,10 +829,78 @@ pub fn build_redirection_form(form: &RedirectForm) -> maud::Markup {
     }
 }
 
+/// Builds the hosted checkout page of a payment link. The card details entered by the customer
+/// are used for confirming the payment from the browser using the publishable key of the merchant,
+/// and the customer is redirected if the payment requires authentication.
+pub fn build_payment_link_form(form: &PaymentLinkFormData) -> maud::Markup {
+    use maud::PreEscaped;
+
+    maud::html! {
+        (maud::DOCTYPE)
+        html {
+            meta name="viewport" content="width=device-width, initial-scale=1";
+            head {
+                title { "Pay " (form.merchant_name) }
+                style { "input { display: block; width: 100%; box-sizing: border-box; margin-bottom: 12px; padding: 8px; } button { width: 100%; padding: 10px; }" }
+            }
+            body style="background-color: #ffffff; padding: 20px; font-family: Arial, Helvetica, Sans-Serif;" {
+                div style="max-width: 400px; margin-left: auto; margin-right: auto;" {
+                    h3 style="text-align: center;" { (form.merchant_name) }
+                    h2 style="text-align: center;" { (form.amount) " " (form.currency) }
+                    @match form.status {
+                        storage::enums::PaymentLinkStatus::Active => {
+                            form #payment_link_form
+                            data-base-url=(form.base_url)
+                            data-payment-id=(form.payment_id)
+                            data-publishable-key=(form.publishable_key)
+                            data-client-secret=(form.client_secret)
+                            data-link=(form.link) {
+                                input type="text" name="card_holder_name" placeholder="Name on card" autocomplete="cc-name" required;
+                                input type="text" name="card_number" placeholder="Card number" autocomplete="cc-number" inputmode="numeric" required;
+                                input type="text" name="card_exp_month" placeholder="MM" autocomplete="cc-exp-month" inputmode="numeric" required;
+                                input type="text" name="card_exp_year" placeholder="YYYY" autocomplete="cc-exp-year" inputmode="numeric" required;
+                                input type="text" name="card_cvc" placeholder="CVC" autocomplete="cc-csc" inputmode="numeric" required;
+                                button type="submit" { "Pay" }
+                                p #payment_link_message style="text-align: center;" {}
+                            }
+                            (PreEscaped(r#"<script type="text/javascript"> var frm = document.getElementById("payment_link_form"); var msg = document.getElementById("payment_link_message"); var btn = frm.querySelector("button"); frm.addEventListener("submit", function (event) { event.preventDefault(); btn.disabled = true; msg.textContent = ""; var data = frm.dataset; fetch(data.baseUrl + "/payments/" + data.paymentId + "/confirm", { method: "POST", headers: { "Content-Type": "application/json", "api-key": data.publishableKey }, body: JSON.stringify({ client_secret: data.clientSecret, return_url: data.link, payment_method: "card", payment_method_data: { card: { card_number: frm.card_number.value, card_exp_month: frm.card_exp_month.value, card_exp_year: frm.card_exp_year.value, card_holder_name: frm.card_holder_name.value, card_cvc: frm.card_cvc.value } } }) }).then(function (response) { return response.json(); }).then(function (payment) { if (payment.next_action && payment.next_action.redirect_to_url) { window.location.href = payment.next_action.redirect_to_url; } else if (payment.error) { msg.textContent = payment.error.message; btn.disabled = false; } else { window.location.reload(); } }).catch(function () { msg.textContent = "Something went wrong, please try again."; btn.disabled = false; }); }); </script>"#))
+                        }
+                        storage::enums::PaymentLinkStatus::Completed => {
+                            p style="text-align: center;" { "This payment has been completed." }
+                        }
+                        storage::enums::PaymentLinkStatus::Expired => {
+                            p style="text-align: center;" { "This payment link has expired." }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use super::*;
+    use crate::configs::settings::DebugTraceSamplingRule;
+
     #[test]
     fn test_mime_essence() {
         assert_eq!(mime::APPLICATION_JSON.essence_str(), "application/json");
     }
+
+    #[test]
+    fn test_get_sampled_debug_trace_id() {
+        let rule = |merchant_id: &str, sample_rate| DebugTraceSamplingRule {
+            merchant_id: merchant_id.to_string(),
+            sample_rate,
+        };
+        let debug_trace = DebugTraceSettings {
+            sampling_rules: vec![rule("merchant_1", 1.0), rule("merchant_2", 0.0)],
+        };
+
+        assert!(get_sampled_debug_trace_id(&debug_trace, Some("merchant_1")).is_some());
+        assert!(get_sampled_debug_trace_id(&debug_trace, Some("merchant_2")).is_none());
+        assert!(get_sampled_debug_trace_id(&debug_trace, Some("merchant_3")).is_none());
+        assert!(get_sampled_debug_trace_id(&debug_trace, None).is_none());
+    }
 }
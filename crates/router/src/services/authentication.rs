@@ -1,16 +1,22 @@
 use actix_web::http::header::HeaderMap;
-use api_models::{payment_methods::ListPaymentMethodRequest, payments::PaymentsRequest};
+use api_models::{
+    payment_methods::ListPaymentMethodRequest,
+    payments::{PaymentIdType, PaymentsRequest, PaymentsRetrieveRequest},
+};
 use async_trait::async_trait;
 use error_stack::{report, IntoReport, ResultExt};
 use jsonwebtoken::{decode, Algorithm, DecodingKey, Validation};
 
 use crate::{
-    core::errors::{self, RouterResult, StorageErrorExt},
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        payments,
+    },
     db::StorageInterface,
     routes::AppState,
     services::api,
     types::storage,
-    utils::OptionExt,
+    utils::{self, OptionExt},
 };
 
 #[async_trait]
@@ -22,6 +28,24 @@ pub trait AuthenticateAndFetch<T> {
     ) -> RouterResult<T>;
 }
 
+/// Details of the authenticated caller which are needed before the request is handled.
+pub trait AuthInfo {
+    /// The merchant on whose behalf the request is made, if any
+    fn get_merchant_id(&self) -> Option<&str>;
+}
+
+impl AuthInfo for () {
+    fn get_merchant_id(&self) -> Option<&str> {
+        None
+    }
+}
+
+impl AuthInfo for storage::MerchantAccount {
+    fn get_merchant_id(&self) -> Option<&str> {
+        Some(&self.merchant_id)
+    }
+}
+
 #[derive(Debug)]
 pub struct ApiKeyAuth;
 
@@ -34,12 +58,7 @@ impl AuthenticateAndFetch<storage::MerchantAccount> for ApiKeyAuth {
     ) -> RouterResult<storage::MerchantAccount> {
         let api_key =
             get_api_key(request_headers).change_context(errors::ApiErrorResponse::Unauthorized)?;
-        state
-            .store
-            .find_merchant_account_by_api_key(api_key)
-            .await
-            .change_context(errors::ApiErrorResponse::Unauthorized)
-            .attach_printable("Merchant not authenticated")
+        api::authenticate_by_api_key(&*state.store, api_key).await
     }
 }
 
@@ -63,6 +82,21 @@ impl AuthenticateAndFetch<()> for AdminApiAuth {
     }
 }
 
+/// Authentication for the endpoints which anyone may call, such as those of the dummy connector.
+#[derive(Debug)]
+pub struct NoAuth;
+
+#[async_trait]
+impl AuthenticateAndFetch<()> for NoAuth {
+    async fn authenticate_and_fetch(
+        &self,
+        _request_headers: &HeaderMap,
+        _state: &AppState,
+    ) -> RouterResult<()> {
+        Ok(())
+    }
+}
+
 #[derive(Debug)]
 pub struct MerchantIdAuth(pub String);
 
@@ -81,8 +115,17 @@ impl AuthenticateAndFetch<storage::MerchantAccount> for MerchantIdAuth {
     }
 }
 
+/// Authenticates requests made from the client side with the publishable key of a merchant.
+///
+/// Such requests are scoped to a single payment, whose client secret has to be passed along with
+/// the request. The client secret is verified against the payment, and the payment is checked to
+/// be the one which the request operates on, before the merchant account is returned.
 #[derive(Debug)]
-pub struct PublishableKeyAuth;
+pub struct PublishableKeyAuth {
+    pub client_secret: String,
+    /// The payment which the request operates on, if the request is made on a specific payment
+    pub payment_id: Option<String>,
+}
 
 #[async_trait]
 impl AuthenticateAndFetch<storage::MerchantAccount> for PublishableKeyAuth {
@@ -93,12 +136,34 @@ impl AuthenticateAndFetch<storage::MerchantAccount> for PublishableKeyAuth {
     ) -> RouterResult<storage::MerchantAccount> {
         let publishable_key =
             get_api_key(request_headers).change_context(errors::ApiErrorResponse::Unauthorized)?;
-        state
+        let merchant_account = state
             .store
             .find_merchant_account_by_publishable_key(publishable_key)
             .await
             .change_context(errors::ApiErrorResponse::Unauthorized)
-            .attach_printable("Merchant not authenticated")
+            .attach_printable("Merchant not authenticated")?;
+
+        let payment_intent = payments::helpers::verify_client_secret(
+            &*state.store,
+            merchant_account.storage_scheme,
+            Some(self.client_secret.clone()),
+            &merchant_account.merchant_id,
+        )
+        .await?
+        .get_required_value("payment_intent")
+        .change_context(errors::ApiErrorResponse::ClientSecretInvalid)?;
+
+        utils::when(
+            self.payment_id
+                .as_ref()
+                .map_or(false, |payment_id| payment_id != &payment_intent.payment_id),
+            || {
+                Err(report!(errors::ApiErrorResponse::ClientSecretInvalid)
+                    .attach_printable("Client secret does not belong to the requested payment"))
+            },
+        )?;
+
+        Ok(merchant_account)
     }
 }
 
@@ -147,14 +212,41 @@ impl AuthenticateAndFetch<storage::MerchantAccount> for JWTAuth {
     }
 }
 
+/// Requests which can be made from the client side, using the publishable key along with the
+/// client secret of a payment.
 pub trait ClientSecretFetch {
     fn get_client_secret(&self) -> Option<&String>;
+
+    /// The payment which the request operates on, if any
+    fn get_payment_id(&self) -> Option<&String> {
+        None
+    }
 }
 
 impl ClientSecretFetch for PaymentsRequest {
     fn get_client_secret(&self) -> Option<&String> {
         self.client_secret.as_ref()
     }
+
+    fn get_payment_id(&self) -> Option<&String> {
+        match self.payment_id.as_ref() {
+            Some(PaymentIdType::PaymentIntentId(payment_id)) => Some(payment_id),
+            _ => None,
+        }
+    }
+}
+
+impl ClientSecretFetch for PaymentsRetrieveRequest {
+    fn get_client_secret(&self) -> Option<&String> {
+        self.client_secret.as_ref()
+    }
+
+    fn get_payment_id(&self) -> Option<&String> {
+        match &self.resource_id {
+            PaymentIdType::PaymentIntentId(payment_id) => Some(payment_id),
+            _ => None,
+        }
+    }
 }
 
 impl ClientSecretFetch for ListPaymentMethodRequest {
@@ -176,20 +268,9 @@ where
     Box::new(default_auth)
 }
 
-pub fn get_auth_type_and_flow(
-    headers: &HeaderMap,
-) -> RouterResult<(
-    Box<dyn AuthenticateAndFetch<storage::MerchantAccount>>,
-    api::AuthFlow,
-)> {
-    let api_key = get_api_key(headers)?;
-
-    if api_key.starts_with("pk_") {
-        return Ok((Box::new(PublishableKeyAuth), api::AuthFlow::Client));
-    }
-    Ok((Box::new(ApiKeyAuth), api::AuthFlow::Merchant))
-}
-
+/// Picks the authentication for the endpoints which can be called from the client side. Requests
+/// made with the publishable key are scoped to the payment of the client secret passed along with
+/// them, while the client secret is not accepted along with the API key.
 pub fn check_client_secret_and_get_auth(
     headers: &HeaderMap,
     payload: &impl ClientSecretFetch,
@@ -200,13 +281,17 @@ pub fn check_client_secret_and_get_auth(
     let api_key = get_api_key(headers)?;
 
     if api_key.starts_with("pk_") {
-        payload
+        let client_secret = payload
             .get_client_secret()
-            .check_value_present("client_secret")
-            .map_err(|_| errors::ApiErrorResponse::MissingRequiredField {
-                field_name: "client_secret".to_owned(),
-            })?;
-        return Ok((Box::new(PublishableKeyAuth), api::AuthFlow::Client));
+            .cloned()
+            .get_required_value("client_secret")?;
+        return Ok((
+            Box::new(PublishableKeyAuth {
+                client_secret,
+                payment_id: payload.get_payment_id().cloned(),
+            }),
+            api::AuthFlow::Client,
+        ));
     }
 
     if payload.get_client_secret().is_some() {
@@ -0,0 +1,262 @@
+//! Encryption of the credentials of merchant connector accounts.
+//!
+//! The credentials are stored in the `connector_account_details` column as a JSON string holding
+//! the base64 encoded ciphertext, and are only decrypted when a connector is called. Accounts
+//! created before the credentials were encrypted hold the credentials as a JSON object, which are
+//! used as they are until the account is updated.
+
+use std::sync::Arc;
+
+use base64::Engine;
+use error_stack::{report, IntoReport, ResultExt};
+use ring::digest;
+
+use crate::{
+    cache::Cache,
+    configs::settings::{CredentialsEncryptionService, Settings},
+    consts,
+    core::errors::{self, CustomResult},
+    services::encryption,
+};
+
+/// A service with which the credentials of merchant connector accounts are encrypted.
+#[async_trait::async_trait]
+pub trait CredentialsCipher: Send + Sync {
+    async fn encrypt(&self, plaintext: String) -> CustomResult<Vec<u8>, errors::EncryptionError>;
+
+    async fn decrypt(&self, ciphertext: Vec<u8>) -> CustomResult<String, errors::EncryptionError>;
+}
+
+/// Encrypts the credentials with the master encryption key of the application.
+pub struct LocalCipher {
+    key: Vec<u8>,
+}
+
+#[async_trait::async_trait]
+impl CredentialsCipher for LocalCipher {
+    async fn encrypt(&self, plaintext: String) -> CustomResult<Vec<u8>, errors::EncryptionError> {
+        encryption::encrypt(&plaintext, &self.key)
+    }
+
+    async fn decrypt(&self, ciphertext: Vec<u8>) -> CustomResult<String, errors::EncryptionError> {
+        if ciphertext.len() < encryption::ENCRYPTION_OVERHEAD {
+            Err(report!(errors::EncryptionError)).attach_printable("Ciphertext is too short")?
+        }
+        encryption::decrypt(ciphertext, &self.key)
+    }
+}
+
+#[cfg(feature = "kms")]
+mod kms {
+    use aws_config::meta::region::RegionProviderChain;
+    use aws_sdk_kms::{types::Blob, Client, Region};
+
+    use super::*;
+    use crate::configs::settings::Jwekey;
+
+    /// Encrypts the credentials with an AWS KMS key.
+    pub struct KmsCipher {
+        client: Client,
+        key_id: String,
+    }
+
+    impl KmsCipher {
+        pub async fn new(aws_keys: &Jwekey) -> Self {
+            let region_provider =
+                RegionProviderChain::first_try(Region::new(aws_keys.aws_region.clone()));
+            let shared_config = aws_config::from_env().region(region_provider).load().await;
+            Self {
+                client: Client::new(&shared_config),
+                key_id: aws_keys.aws_key_id.clone(),
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl CredentialsCipher for KmsCipher {
+        async fn encrypt(
+            &self,
+            plaintext: String,
+        ) -> CustomResult<Vec<u8>, errors::EncryptionError> {
+            let response = self
+                .client
+                .encrypt()
+                .key_id(&self.key_id)
+                .plaintext(Blob::new(plaintext.into_bytes()))
+                .send()
+                .await
+                .into_report()
+                .change_context(errors::EncryptionError)
+                .attach_printable("Error encrypting data with kms")?;
+            response
+                .ciphertext_blob()
+                .map(|blob| blob.as_ref().to_vec())
+                .ok_or_else(|| report!(errors::EncryptionError))
+                .attach_printable("Missing ciphertext in response")
+        }
+
+        async fn decrypt(
+            &self,
+            ciphertext: Vec<u8>,
+        ) -> CustomResult<String, errors::EncryptionError> {
+            let response = self
+                .client
+                .decrypt()
+                .key_id(&self.key_id)
+                .ciphertext_blob(Blob::new(ciphertext))
+                .send()
+                .await
+                .into_report()
+                .change_context(errors::EncryptionError)
+                .attach_printable("Error decrypting kms encrypted data")?;
+            let plaintext = response
+                .plaintext()
+                .map(|blob| blob.as_ref().to_vec())
+                .ok_or_else(|| report!(errors::EncryptionError))
+                .attach_printable("Missing plaintext in response")?;
+            String::from_utf8(plaintext)
+                .into_report()
+                .change_context(errors::EncryptionError)
+                .attach_printable("Could not convert to UTF-8")
+        }
+    }
+}
+
+#[cfg(feature = "kms")]
+pub use kms::KmsCipher;
+
+/// Encrypts the credentials of merchant connector accounts before they are stored, and decrypts
+/// them when a connector is called. The decrypted credentials are cached, so that the encryption
+/// service is not called for every payment.
+#[derive(Clone)]
+pub struct ConnectorCredentials {
+    cipher: Arc<dyn CredentialsCipher>,
+    cache: Cache<serde_json::Value>,
+}
+
+impl ConnectorCredentials {
+    pub fn new(cipher: Arc<dyn CredentialsCipher>, cache: Cache<serde_json::Value>) -> Self {
+        Self { cipher, cache }
+    }
+
+    /// Builds the encryption service configured in the settings. The configurations for which
+    /// this fails are rejected while validating the settings.
+    pub async fn from_settings(conf: &Settings) -> errors::ApplicationResult<Self> {
+        let cipher: Arc<dyn CredentialsCipher> = match conf.connector_credentials.encryption {
+            CredentialsEncryptionService::Local => Arc::new(LocalCipher {
+                key: hex::decode(&conf.secrets.master_enc_key).map_err(|_| {
+                    errors::ApplicationError::InvalidConfigurationValueError(
+                        "master encryption key must be hex encoded".into(),
+                    )
+                })?,
+            }),
+            #[cfg(feature = "kms")]
+            CredentialsEncryptionService::Kms => Arc::new(KmsCipher::new(&conf.jwekey).await),
+            #[cfg(not(feature = "kms"))]
+            CredentialsEncryptionService::Kms => {
+                Err(errors::ApplicationError::InvalidConfigurationValueError(
+                    "KMS encryption of connector credentials requires the `kms` feature".into(),
+                ))?
+            }
+        };
+        let cache = Cache::new("connector_credentials", &conf.connector_credentials.cache);
+        Ok(Self::new(cipher, cache))
+    }
+
+    /// Encrypts the credentials of a merchant connector account into the value which is stored.
+    pub async fn encrypt(
+        &self,
+        details: &serde_json::Value,
+    ) -> CustomResult<serde_json::Value, errors::EncryptionError> {
+        let plaintext = serde_json::to_string(details)
+            .into_report()
+            .change_context(errors::EncryptionError)
+            .attach_printable("Failed to serialize the connector credentials")?;
+        let ciphertext = self.cipher.encrypt(plaintext).await?;
+        Ok(serde_json::Value::String(
+            consts::BASE64_ENGINE.encode(ciphertext),
+        ))
+    }
+
+    /// Decrypts the stored credentials of a merchant connector account. Credentials stored before
+    /// they were encrypted are returned as they are.
+    pub async fn decrypt(
+        &self,
+        stored: &serde_json::Value,
+    ) -> CustomResult<serde_json::Value, errors::EncryptionError> {
+        let encoded = match stored {
+            serde_json::Value::String(encoded) => encoded,
+            _ => return Ok(stored.clone()),
+        };
+
+        // The ciphertext itself is not used as the key, to bound the memory used by the keys
+        let key = hex::encode(digest::digest(&digest::SHA256, encoded.as_bytes()));
+        self.cache
+            .get_or_populate(&key, || async {
+                let ciphertext = consts::BASE64_ENGINE
+                    .decode(encoded)
+                    .into_report()
+                    .change_context(errors::EncryptionError)
+                    .attach_printable("Error decoding from base64")?;
+                let plaintext = self.cipher.decrypt(ciphertext).await?;
+                serde_json::from_str(&plaintext)
+                    .into_report()
+                    .change_context(errors::EncryptionError)
+                    .attach_printable("Failed to deserialize the connector credentials")
+            })
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::configs::settings::CacheSettings;
+
+    fn connector_credentials() -> ConnectorCredentials {
+        let cipher = Arc::new(LocalCipher {
+            key: (0..32).collect(),
+        });
+        ConnectorCredentials::new(
+            cipher,
+            Cache::new("connector_credentials", &CacheSettings::default()),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_encrypt_decrypt_credentials() {
+        let connector_credentials = connector_credentials();
+        let details = serde_json::json!({"auth_type": "HeaderKey", "api_key": "sk_test"});
+
+        let stored = connector_credentials.encrypt(&details).await.unwrap();
+        assert!(stored.is_string());
+        assert!(!stored.to_string().contains("sk_test"));
+        assert_eq!(
+            connector_credentials.decrypt(&stored).await.unwrap(),
+            details
+        );
+        // Served from the cache
+        assert_eq!(
+            connector_credentials.decrypt(&stored).await.unwrap(),
+            details
+        );
+    }
+
+    #[tokio::test]
+    async fn test_from_settings_with_invalid_master_key() {
+        let mut conf = Settings::new().unwrap();
+        conf.connector_credentials.encryption = CredentialsEncryptionService::Local;
+        conf.secrets.master_enc_key = "not hex encoded".to_string();
+        assert!(ConnectorCredentials::from_settings(&conf).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_decrypt_plaintext_credentials() {
+        let details = serde_json::json!({"auth_type": "HeaderKey", "api_key": "sk_test"});
+        assert_eq!(
+            connector_credentials().decrypt(&details).await.unwrap(),
+            details
+        );
+    }
+}
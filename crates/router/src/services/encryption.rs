@@ -2,12 +2,14 @@ use std::{num::Wrapping, str};
 
 use error_stack::{report, IntoReport, ResultExt};
 use josekit::jwe;
+use masking::{PeekInterface, Secret, Strategy};
 use rand;
 use ring::{aead::*, error::Unspecified};
 
 use crate::{
     configs::settings::Jwekey,
     core::errors::{self, CustomResult},
+    types::storage::Encryptable,
     utils,
 };
 
@@ -51,6 +53,15 @@ impl NonceSequence for NonceGen {
 
 pub struct KeyHandler;
 
+/// Length of the nonce and the tag added to the encrypted messages.
+pub(crate) const ENCRYPTION_OVERHEAD: usize = 12 + 16;
+
+/// Leading byte of the field values encrypted with the data key of the merchant.
+const ENCRYPTED_FIELD_VERSION: u8 = 1;
+/// Leading byte of the field values written in plaintext before the field was encrypted, which
+/// the migration encrypting the field prefixed the existing values with.
+const PLAINTEXT_FIELD_VERSION: u8 = 0;
+
 #[cfg(feature = "kms")]
 mod kms {
     use aws_config::meta::region::RegionProviderChain;
@@ -166,6 +177,53 @@ pub fn decrypt(mut data: Vec<u8>, key: &[u8]) -> CustomResult<String, errors::En
     Ok(response.to_string())
 }
 
+/// Encrypts a field holding personal data with the data key of the merchant owning it.
+pub fn encrypt_field<S>(
+    value: Option<Secret<String, S>>,
+    key: &[u8],
+) -> CustomResult<Option<Encryptable<Secret<String, S>>>, errors::EncryptionError>
+where
+    S: Strategy<String>,
+{
+    value
+        .map(|value| {
+            let mut data = encrypt(value.peek(), key)?;
+            data.insert(0, ENCRYPTED_FIELD_VERSION);
+            Ok(Encryptable::new(data))
+        })
+        .transpose()
+}
+
+/// Decrypts a field holding personal data with the data key of the merchant owning it. Values
+/// written before the field was encrypted are marked as plaintext by their leading byte, and are
+/// returned as they are. Encrypted values which fail to be authenticated are an error.
+pub fn decrypt_field<S>(
+    value: Option<Encryptable<Secret<String, S>>>,
+    key: &[u8],
+) -> CustomResult<Option<Secret<String, S>>, errors::EncryptionError>
+where
+    S: Strategy<String>,
+{
+    value
+        .map(|value| {
+            let mut data = value.into_encrypted();
+            let version = (!data.is_empty()).then(|| data.remove(0));
+            match version {
+                Some(ENCRYPTED_FIELD_VERSION) => decrypt(data, key)
+                    .attach_printable("Failed to decrypt the field")
+                    .map(Secret::new),
+                Some(PLAINTEXT_FIELD_VERSION) => String::from_utf8(data)
+                    .into_report()
+                    .change_context(errors::EncryptionError)
+                    .attach_printable("Failed to read the plaintext field")
+                    .map(Secret::new),
+                _ => Err(report!(errors::EncryptionError))
+                    .attach_printable(format!("Unknown version of the field: {version:?}")),
+            }
+        })
+        .transpose()
+}
+
 pub fn get_key_id(keys: &Jwekey) -> &str {
     let key_identifier = "1"; // [#46]: Fetch this value from redis or external sources
     if key_identifier == "1" {
@@ -262,6 +320,54 @@ mod tests {
         assert_eq!(dec_data, "Test_Encrypt".to_string());
     }
 
+    #[test]
+    fn test_encrypt_field() {
+        let key = generate_key();
+        let value: Secret<String> = Secret::new("221B Baker Street".to_string());
+        let encrypted = encrypt_field(Some(value), &key).unwrap().unwrap();
+        assert_ne!(
+            encrypted.clone().into_encrypted(),
+            b"221B Baker Street".to_vec()
+        );
+
+        let decrypted: Option<Secret<String>> = decrypt_field(Some(encrypted), &key).unwrap();
+        assert_eq!(decrypted.unwrap().peek(), "221B Baker Street");
+    }
+
+    #[test]
+    fn test_decrypt_plaintext_field() {
+        let key = generate_key();
+        for value in ["NW1", "221B Baker Street, Marylebone, London"] {
+            let mut stored = vec![PLAINTEXT_FIELD_VERSION];
+            stored.extend_from_slice(value.as_bytes());
+            let decrypted: Option<Secret<String>> =
+                decrypt_field(Some(Encryptable::new(stored)), &key).unwrap();
+            assert_eq!(decrypted.unwrap().peek(), value);
+        }
+    }
+
+    #[test]
+    fn test_decrypt_field_fails_on_authentication_failure() {
+        let key = generate_key();
+        let value: Secret<String> = Secret::new("221B Baker Street".to_string());
+        let encrypted = encrypt_field(Some(value), &key).unwrap().unwrap();
+
+        let wrong_key = generate_key();
+        assert!(decrypt_field::<masking::WithType>(Some(encrypted.clone()), &wrong_key).is_err());
+
+        let mut tampered = encrypted.into_encrypted();
+        if let Some(last) = tampered.last_mut() {
+            *last ^= 1;
+        }
+        assert!(
+            decrypt_field::<masking::WithType>(Some(Encryptable::new(tampered)), &key).is_err()
+        );
+
+        // Values without a version are neither plaintext written by the migration nor encrypted
+        let unversioned = Encryptable::new(b"221B Baker Street".to_vec());
+        assert!(decrypt_field::<masking::WithType>(Some(unversioned), &key).is_err());
+    }
+
     #[actix_rt::test]
     async fn test_jwe() {
         let conf = settings::Settings::new().unwrap();
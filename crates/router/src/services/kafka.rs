@@ -0,0 +1,143 @@
+use std::{sync::Arc, time::Duration};
+
+use error_stack::{IntoReport, ResultExt};
+use rdkafka::{
+    config::ClientConfig,
+    producer::{BaseRecord, DeliveryResult, Producer, ProducerContext, ThreadedProducer},
+    ClientContext, Message,
+};
+
+use crate::{
+    configs::settings::KafkaSettings,
+    core::errors::{CustomResult, KafkaError},
+    logger,
+    types::storage,
+    utils::anonymization::{Anonymize, Anonymizer},
+};
+
+/// The maximum time to wait for the pending events to be delivered while shutting down.
+const FLUSH_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// An object whose state changes are published to Kafka, for analytics and data lake pipelines.
+pub trait KafkaEvent: serde::Serialize + Anonymize + Clone {
+    fn topic<'a>(&self, settings: &'a KafkaSettings) -> &'a str;
+
+    /// The key of the message, which decides the partition the message is published to.
+    ///
+    /// Messages are keyed by the merchant, so that the state changes of the objects of a merchant
+    /// are consumed in the order they were published.
+    fn key(&self) -> &str;
+}
+
+impl KafkaEvent for storage::PaymentIntent {
+    fn topic<'a>(&self, settings: &'a KafkaSettings) -> &'a str {
+        &settings.intent_analytics_topic
+    }
+
+    fn key(&self) -> &str {
+        &self.merchant_id
+    }
+}
+
+impl KafkaEvent for storage::PaymentAttempt {
+    fn topic<'a>(&self, settings: &'a KafkaSettings) -> &'a str {
+        &settings.attempt_analytics_topic
+    }
+
+    fn key(&self) -> &str {
+        &self.merchant_id
+    }
+}
+
+impl KafkaEvent for storage::Refund {
+    fn topic<'a>(&self, settings: &'a KafkaSettings) -> &'a str {
+        &settings.refund_analytics_topic
+    }
+
+    fn key(&self) -> &str {
+        &self.merchant_id
+    }
+}
+
+/// Logs the messages which could not be delivered to the brokers, as delivery happens in the
+/// background after the message has been queued.
+pub struct DeliveryLogger;
+
+impl ClientContext for DeliveryLogger {}
+
+impl ProducerContext for DeliveryLogger {
+    type DeliveryOpaque = ();
+
+    fn delivery(
+        &self,
+        delivery_result: &DeliveryResult<'_>,
+        _delivery_opaque: Self::DeliveryOpaque,
+    ) {
+        if let Err((error, message)) = delivery_result {
+            logger::error!(%error, topic = message.topic(), "Failed to deliver Kafka message");
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct KafkaProducer {
+    producer: Arc<ThreadedProducer<DeliveryLogger>>,
+    settings: KafkaSettings,
+    /// Anonymizes the events before they are published, when `anonymize_events` is enabled
+    anonymizer: Option<Anonymizer>,
+}
+
+impl KafkaProducer {
+    pub fn create(
+        settings: &KafkaSettings,
+        anonymization_key: &str,
+    ) -> CustomResult<Self, KafkaError> {
+        let producer = ClientConfig::new()
+            .set("bootstrap.servers", settings.brokers.join(","))
+            .create_with_context(DeliveryLogger)
+            .into_report()
+            .change_context(KafkaError)
+            .attach_printable("Failed to create the Kafka producer")?;
+
+        Ok(Self {
+            producer: Arc::new(producer),
+            settings: settings.clone(),
+            anonymizer: settings
+                .anonymize_events
+                .then(|| Anonymizer::new(anonymization_key)),
+        })
+    }
+
+    /// Queues the serialized object for publishing, without waiting for it to be delivered.
+    ///
+    /// Failures are only logged, as publishing the event must not affect the outcome of the
+    /// state change which it describes.
+    pub fn log_event<T: KafkaEvent>(&self, event: &T) {
+        let serialized = match &self.anonymizer {
+            Some(anonymizer) => serde_json::to_string(&event.clone().anonymize(anonymizer)),
+            None => serde_json::to_string(event),
+        };
+        let payload = match serialized {
+            Ok(payload) => payload,
+            Err(error) => {
+                logger::error!(%error, "Failed to serialize Kafka message");
+                return;
+            }
+        };
+        let topic = event.topic(&self.settings);
+
+        if let Err((error, _)) = self
+            .producer
+            .send(BaseRecord::to(topic).key(event.key()).payload(&payload))
+        {
+            logger::error!(%error, %topic, "Failed to queue Kafka message");
+        }
+    }
+
+    /// Waits for the queued messages to be delivered, before the application shuts down.
+    pub fn flush(&self) {
+        if let Err(error) = self.producer.flush(FLUSH_TIMEOUT) {
+            logger::error!(%error, "Failed to flush Kafka messages");
+        }
+    }
+}
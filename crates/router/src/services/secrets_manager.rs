@@ -0,0 +1,231 @@
+//! Fetching of the secrets of the application from a secrets manager at startup.
+//!
+//! When a secrets manager is configured, the settings holding secrets contain the names of the
+//! secrets in the secrets manager instead of their values, and are replaced by the values fetched
+//! from the secrets manager before the settings are validated.
+
+use error_stack::{report, IntoReport, ResultExt};
+
+use crate::{
+    configs::settings::{SecretsManagerBackend, SecretsManagerSettings, Settings},
+    core::errors::{self, CustomResult},
+};
+
+/// A service from which the secrets of the application are fetched by their name.
+#[async_trait::async_trait]
+pub trait SecretsManager: Send + Sync {
+    async fn get_secret(&self, name: &str) -> CustomResult<String, errors::SecretsManagerError>;
+}
+
+/// Reads the secrets from the environment variables named after them.
+pub struct EnvSecretsManager;
+
+#[async_trait::async_trait]
+impl SecretsManager for EnvSecretsManager {
+    async fn get_secret(&self, name: &str) -> CustomResult<String, errors::SecretsManagerError> {
+        std::env::var(name)
+            .into_report()
+            .change_context_lazy(|| errors::SecretsManagerError::SecretNotFound(name.to_string()))
+    }
+}
+
+#[cfg(feature = "aws_secrets_manager")]
+mod aws {
+    use aws_config::meta::region::RegionProviderChain;
+    use aws_sdk_secretsmanager::{Client, Region};
+
+    use super::*;
+
+    /// Fetches the secrets from AWS Secrets Manager, where the name of a secret is its ARN or
+    /// friendly name. Only secrets stored as strings are supported.
+    pub struct AwsSecretsManager {
+        client: Client,
+    }
+
+    impl AwsSecretsManager {
+        pub async fn new(region: &str) -> Self {
+            let region_provider = RegionProviderChain::first_try(Region::new(region.to_string()));
+            let shared_config = aws_config::from_env().region(region_provider).load().await;
+            Self {
+                client: Client::new(&shared_config),
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl SecretsManager for AwsSecretsManager {
+        async fn get_secret(
+            &self,
+            name: &str,
+        ) -> CustomResult<String, errors::SecretsManagerError> {
+            let response = self
+                .client
+                .get_secret_value()
+                .secret_id(name)
+                .send()
+                .await
+                .into_report()
+                .change_context(errors::SecretsManagerError::FetchSecretFailed)
+                .attach_printable_lazy(|| format!("Failed to fetch secret `{name}`"))?;
+            response
+                .secret_string()
+                .map(ToString::to_string)
+                .ok_or_else(|| {
+                    report!(errors::SecretsManagerError::SecretNotFound(
+                        name.to_string()
+                    ))
+                })
+                .attach_printable("Secret is not stored as a string")
+        }
+    }
+}
+
+#[cfg(feature = "aws_secrets_manager")]
+pub use aws::AwsSecretsManager;
+
+/// Fetches the secrets from the KV version 2 secrets engine of HashiCorp Vault, where the name of
+/// a secret is its path in the engine. The value of the secret is read from the `value` key.
+pub struct HashicorpVault {
+    client: reqwest::Client,
+    url: String,
+    token: String,
+    mount: String,
+}
+
+#[derive(serde::Deserialize)]
+struct VaultResponse {
+    data: VaultSecret,
+}
+
+#[derive(serde::Deserialize)]
+struct VaultSecret {
+    data: VaultSecretData,
+}
+
+#[derive(serde::Deserialize)]
+struct VaultSecretData {
+    value: String,
+}
+
+impl HashicorpVault {
+    pub fn new(settings: &SecretsManagerSettings) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            url: settings.vault_url.trim_end_matches('/').to_string(),
+            token: settings.vault_token.clone(),
+            mount: settings.vault_mount.clone(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl SecretsManager for HashicorpVault {
+    async fn get_secret(&self, name: &str) -> CustomResult<String, errors::SecretsManagerError> {
+        let response = self
+            .client
+            .get(format!("{}/v1/{}/data/{name}", self.url, self.mount))
+            .header("X-Vault-Token", &self.token)
+            .send()
+            .await
+            .into_report()
+            .change_context(errors::SecretsManagerError::FetchSecretFailed)
+            .attach_printable_lazy(|| format!("Failed to fetch secret `{name}`"))?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            Err(report!(errors::SecretsManagerError::SecretNotFound(
+                name.to_string()
+            )))?
+        }
+
+        let response: VaultResponse = response
+            .error_for_status()
+            .into_report()
+            .change_context(errors::SecretsManagerError::FetchSecretFailed)
+            .attach_printable_lazy(|| format!("Failed to fetch secret `{name}`"))?
+            .json()
+            .await
+            .into_report()
+            .change_context(errors::SecretsManagerError::FetchSecretFailed)
+            .attach_printable("Failed to deserialize the response of Vault")?;
+        Ok(response.data.data.value)
+    }
+}
+
+/// Builds the secrets manager configured in the settings, if any.
+pub async fn get_secrets_manager(
+    settings: &SecretsManagerSettings,
+) -> Option<Box<dyn SecretsManager>> {
+    match settings.backend {
+        SecretsManagerBackend::None => None,
+        SecretsManagerBackend::Env => Some(Box::new(EnvSecretsManager)),
+        #[cfg(feature = "aws_secrets_manager")]
+        SecretsManagerBackend::AwsSecretsManager => {
+            Some(Box::new(AwsSecretsManager::new(&settings.aws_region).await))
+        }
+        SecretsManagerBackend::HashicorpVault => Some(Box::new(HashicorpVault::new(settings))),
+    }
+}
+
+async fn fetch_secret(
+    secrets_manager: &dyn SecretsManager,
+    value: &mut String,
+) -> CustomResult<(), errors::SecretsManagerError> {
+    *value = secrets_manager.get_secret(value).await?;
+    Ok(())
+}
+
+impl Settings {
+    /// Replaces the names of the secrets in the settings by their values fetched from the
+    /// configured secrets manager. The settings are returned as they are if no secrets manager is
+    /// configured.
+    pub async fn fetch_secrets(mut self) -> CustomResult<Self, errors::SecretsManagerError> {
+        let secrets_manager = match get_secrets_manager(&self.secrets_manager).await {
+            Some(secrets_manager) => secrets_manager,
+            None => return Ok(self),
+        };
+        let secrets_manager = secrets_manager.as_ref();
+
+        fetch_secret(secrets_manager, &mut self.master_database.password).await?;
+        fetch_secret(secrets_manager, &mut self.replica_database.password).await?;
+        if let Some(password) = self.redis.password.as_mut() {
+            fetch_secret(secrets_manager, password).await?;
+        }
+        fetch_secret(secrets_manager, &mut self.secrets.jwt_secret).await?;
+        fetch_secret(secrets_manager, &mut self.secrets.master_enc_key).await?;
+
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[tokio::test]
+    async fn test_fetch_secrets_from_env() {
+        std::env::set_var("TEST_FETCH_SECRETS_VALUE", "secret_value");
+        let mut settings = Settings::default();
+        settings.secrets_manager.backend = SecretsManagerBackend::Env;
+        settings.master_database.password = "TEST_FETCH_SECRETS_VALUE".to_string();
+        settings.replica_database.password = "TEST_FETCH_SECRETS_VALUE".to_string();
+        settings.secrets.jwt_secret = "TEST_FETCH_SECRETS_VALUE".to_string();
+        settings.secrets.master_enc_key = "TEST_FETCH_SECRETS_VALUE".to_string();
+
+        let settings = settings.fetch_secrets().await.unwrap();
+        assert_eq!(settings.secrets.jwt_secret, "secret_value");
+        assert_eq!(settings.redis.password, None);
+    }
+
+    #[tokio::test]
+    async fn test_missing_env_secret() {
+        let error = EnvSecretsManager
+            .get_secret("TEST_FETCH_SECRETS_MISSING")
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::SecretsManagerError::SecretNotFound(_)
+        ));
+    }
+}
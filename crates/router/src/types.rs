@@ -31,6 +31,18 @@ pub type PaymentsSessionRouterData =
 pub type RefundsRouterData<F> = RouterData<F, RefundsData, RefundsResponseData>;
 pub type RefundExecuteRouterData = RouterData<api::Execute, RefundsData, RefundsResponseData>;
 pub type RefundSyncRouterData = RouterData<api::RSync, RefundsData, RefundsResponseData>;
+pub type RefreshTokenRouterData =
+    RouterData<api::AccessTokenAuth, AccessTokenRequestData, AccessToken>;
+pub type PaymentsAuthorizeSessionTokenRouterData = RouterData<
+    api::AuthorizeSessionToken,
+    AuthorizeSessionTokenData,
+    AuthorizeSessionTokenResponse,
+>;
+pub type VerifyWebhookSourceRouterData = RouterData<
+    api::VerifyWebhookSource,
+    VerifyWebhookSourceRequestData,
+    VerifyWebhookSourceResponseData,
+>;
 
 pub type PaymentsResponseRouterData<R> =
     ResponseRouterData<api::Authorize, R, PaymentsAuthorizeData, PaymentsResponseData>;
@@ -60,6 +72,68 @@ pub type RefundExecuteType =
     dyn services::ConnectorIntegration<api::Execute, RefundsData, RefundsResponseData>;
 pub type RefundSyncType =
     dyn services::ConnectorIntegration<api::RSync, RefundsData, RefundsResponseData>;
+pub type RefreshTokenType =
+    dyn services::ConnectorIntegration<api::AccessTokenAuth, AccessTokenRequestData, AccessToken>;
+pub type PaymentsPreAuthorizeType = dyn services::ConnectorIntegration<
+    api::AuthorizeSessionToken,
+    AuthorizeSessionTokenData,
+    AuthorizeSessionTokenResponse,
+>;
+pub type VerifyWebhookSourceType = dyn services::ConnectorIntegration<
+    api::VerifyWebhookSource,
+    VerifyWebhookSourceRequestData,
+    VerifyWebhookSourceResponseData,
+>;
+
+pub type SubmitEvidenceRouterData =
+    RouterData<api::Evidence, SubmitEvidenceRequestData, SubmitEvidenceResponse>;
+pub type DefendDisputeRouterData =
+    RouterData<api::Defend, DefendDisputeRequestData, DefendDisputeResponse>;
+pub type SubmitEvidenceType = dyn services::ConnectorIntegration<
+    api::Evidence,
+    SubmitEvidenceRequestData,
+    SubmitEvidenceResponse,
+>;
+pub type DefendDisputeType = dyn services::ConnectorIntegration<
+    api::Defend,
+    DefendDisputeRequestData,
+    DefendDisputeResponse,
+>;
+
+pub type PayoutsRouterData<F> = RouterData<F, PayoutsData, PayoutsResponseData>;
+pub type PayoutsResponseRouterData<F, R> =
+    ResponseRouterData<F, R, PayoutsData, PayoutsResponseData>;
+pub type PayoutCreateType =
+    dyn services::ConnectorIntegration<api::PoCreate, PayoutsData, PayoutsResponseData>;
+pub type PayoutFulfillType =
+    dyn services::ConnectorIntegration<api::PoFulfill, PayoutsData, PayoutsResponseData>;
+pub type PayoutCancelType =
+    dyn services::ConnectorIntegration<api::PoCancel, PayoutsData, PayoutsResponseData>;
+
+pub type ThreeDsAuthenticationRouterData = RouterData<
+    api::ThreeDsAuthentication,
+    ThreeDsAuthenticationRequestData,
+    ThreeDsAuthenticationData,
+>;
+pub type ThreeDsAuthenticationType = dyn services::ConnectorIntegration<
+    api::ThreeDsAuthentication,
+    ThreeDsAuthenticationRequestData,
+    ThreeDsAuthenticationData,
+>;
+
+pub type FraudCheckRouterData<F> = RouterData<F, FraudCheckRequestData, FraudCheckResponseData>;
+pub type FraudCheckResponseRouterData<F, R> =
+    ResponseRouterData<F, R, FraudCheckRequestData, FraudCheckResponseData>;
+pub type FraudCheckPreType = dyn services::ConnectorIntegration<
+    api::FraudCheckPre,
+    FraudCheckRequestData,
+    FraudCheckResponseData,
+>;
+pub type FraudCheckPostType = dyn services::ConnectorIntegration<
+    api::FraudCheckPost,
+    FraudCheckRequestData,
+    FraudCheckResponseData,
+>;
 
 pub type VerifyRouterData = RouterData<api::Verify, VerifyRequestData, PaymentsResponseData>;
 
@@ -80,6 +154,21 @@ pub struct RouterData<Flow, Request, Response> {
     pub auth_type: storage_enums::AuthenticationType,
     pub connector_meta_data: Option<serde_json::Value>,
     pub amount_captured: Option<i64>,
+    /// Access token obtained from the connector, for connectors which authenticate their API
+    /// calls with a short-lived token instead of the merchant's API keys.
+    pub access_token: Option<AccessToken>,
+    /// Session token obtained from the connector before authorizing the payment, for connectors
+    /// which require an order or session to be created first.
+    pub session_token: Option<String>,
+    /// Results of the address and security code checks performed by the card issuer, as reported
+    /// by the connector.
+    pub payment_checks: Option<api_models::payments::PaymentChecks>,
+    /// Copy of the request sent to and the response received from the connector, with the
+    /// sensitive data in them masked.
+    pub connector_api_log: Option<serde_json::Value>,
+    /// Whether the merchant connector account is in test mode, in which case the connector is
+    /// called at the base URL of its sandbox.
+    pub test_mode: Option<bool>,
 
     /// Contains flow-specific data required to construct a request and send it to the connector.
     pub request: Request,
@@ -107,6 +196,12 @@ pub struct PaymentsAuthorizeData {
     pub setup_mandate_details: Option<payments::MandateData>,
     pub browser_info: Option<BrowserInformation>,
     pub order_details: Option<api_models::payments::OrderDetails>,
+    /// The result of authenticating the cardholder with a standalone 3DS server, if the payment
+    /// was authenticated before being sent to the connector
+    pub authentication_data: Option<ThreeDsAuthenticationData>,
+    /// The outcome of screening the payment with the fraud and risk management service of the
+    /// merchant, if it was screened during this request
+    pub frm_data: Option<FrmData>,
 }
 
 #[derive(Debug, Clone)]
@@ -115,6 +210,7 @@ pub struct PaymentsCaptureData {
     pub currency: storage_enums::Currency,
     pub connector_transaction_id: String,
     pub amount: i64,
+    pub connector_meta: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Clone)]
@@ -208,6 +304,240 @@ pub struct RefundsData {
     pub connector_metadata: Option<serde_json::Value>,
 }
 
+#[derive(Debug, Clone)]
+pub struct AccessTokenRequestData {
+    pub app_id: String,
+    pub id: Option<String>,
+}
+
+impl TryFrom<ConnectorAuthType> for AccessTokenRequestData {
+    type Error = errors::ApiErrorResponse;
+
+    fn try_from(connector_auth: ConnectorAuthType) -> Result<Self, Self::Error> {
+        match connector_auth {
+            ConnectorAuthType::HeaderKey { api_key } => Ok(Self {
+                app_id: api_key,
+                id: None,
+            }),
+            ConnectorAuthType::BodyKey { api_key, key1 }
+            | ConnectorAuthType::SignatureKey { api_key, key1, .. } => Ok(Self {
+                app_id: api_key,
+                id: Some(key1),
+            }),
+            ConnectorAuthType::NoKey => Err(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "connector_account_details",
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct AccessToken {
+    pub token: String,
+    /// Number of seconds after which the token expires
+    pub expires: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct AddAccessTokenResult {
+    pub access_token_result: Result<Option<AccessToken>, ErrorResponse>,
+    pub connector_supports_access_token: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct AuthorizeSessionTokenData {
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub attempt_id: Option<String>,
+    pub capture_method: Option<storage_enums::CaptureMethod>,
+    pub payment_method_data: payments::PaymentMethod,
+}
+
+impl From<&PaymentsAuthorizeRouterData> for AuthorizeSessionTokenData {
+    fn from(router_data: &PaymentsAuthorizeRouterData) -> Self {
+        Self {
+            amount: router_data.request.amount,
+            currency: router_data.request.currency,
+            attempt_id: router_data.attempt_id.clone(),
+            capture_method: router_data.request.capture_method,
+            payment_method_data: router_data.request.payment_method_data.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AuthorizeSessionTokenResponse {
+    pub session_token: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct VerifyWebhookSourceRequestData {
+    pub webhook_headers: actix_web::http::header::HeaderMap,
+    pub webhook_body: Vec<u8>,
+    pub merchant_secret: Vec<u8>,
+}
+
+#[derive(Debug, Clone)]
+pub struct VerifyWebhookSourceResponseData {
+    pub verify_webhook_status: VerifyWebhookStatus,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum VerifyWebhookStatus {
+    SourceVerified,
+    SourceNotVerified,
+}
+
+#[derive(Debug, Clone)]
+pub struct SubmitEvidenceRequestData {
+    pub dispute_id: String,
+    pub connector_dispute_id: String,
+    pub evidence: api::SubmitEvidenceRequest,
+}
+
+#[derive(Debug, Clone)]
+pub struct SubmitEvidenceResponse {
+    pub challenge_status: storage_enums::DisputeChallengeStatus,
+    /// The status of the dispute at the connector, if the connector responds with it
+    pub connector_status: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DefendDisputeRequestData {
+    pub dispute_id: String,
+    pub connector_dispute_id: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct DefendDisputeResponse {
+    pub challenge_status: storage_enums::DisputeChallengeStatus,
+    /// The status of the dispute at the connector, if the connector responds with it
+    pub connector_status: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PayoutsData {
+    pub payout_id: String,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub customer_id: Option<String>,
+    pub email: Option<masking::Secret<String, Email>>,
+    /// The account details of the recipient, which are sent only when submitting the payout
+    pub payout_method_data: Option<api::PayoutMethodData>,
+    /// The identifier for the payout at the connector, once it has been submitted
+    pub connector_payout_id: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PayoutsResponseData {
+    pub status: storage_enums::PayoutStatus,
+    pub connector_payout_id: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ThreeDsAuthenticationRequestData {
+    pub card: api::CCard,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub email: Option<masking::Secret<String, Email>>,
+    pub browser_info: Option<BrowserInformation>,
+}
+
+/// The result of authenticating the cardholder with a 3DS server, which is sent along with the
+/// authorization to connectors accepting pre-authenticated payments.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ThreeDsAuthenticationData {
+    pub trans_status: ThreeDsTransStatus,
+    /// The cardholder authentication verification value generated by the issuer
+    pub cavv: Option<masking::Secret<String>>,
+    /// The electronic commerce indicator, denoting the outcome of the authentication
+    pub eci: Option<String>,
+    /// The identifier assigned to the authentication by the card network's directory server
+    pub ds_trans_id: Option<String>,
+    /// The version of the 3DS protocol used for the authentication
+    pub message_version: String,
+}
+
+/// The outcome of a 3DS authentication, as defined by the EMV 3-D Secure specification.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum ThreeDsTransStatus {
+    /// The cardholder was authenticated
+    #[serde(rename = "Y")]
+    Success,
+    /// The authentication could not be performed, but the issuer provided a proof of the attempt
+    #[serde(rename = "A")]
+    Attempted,
+    #[serde(rename = "N")]
+    Failed,
+    /// The authentication could not be performed due to a technical issue
+    #[serde(rename = "U")]
+    Unavailable,
+    #[serde(rename = "R")]
+    Rejected,
+    /// The cardholder has to complete a challenge with the issuer
+    #[serde(rename = "C")]
+    ChallengeRequired,
+    /// The cardholder has to complete a challenge with the issuer outside of the checkout
+    #[serde(rename = "D")]
+    DecoupledChallengeRequired,
+    #[serde(rename = "I")]
+    InformationalOnly,
+}
+
+impl ThreeDsTransStatus {
+    /// Whether the payment may be authorized using the result of the authentication.
+    pub fn is_authenticated(&self) -> bool {
+        matches!(self, Self::Success | Self::Attempted)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FraudCheckRequestData {
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub payment_method_data: payments::PaymentMethod,
+    pub email: Option<masking::Secret<String, Email>>,
+    pub browser_info: Option<BrowserInformation>,
+    pub order_details: Option<api_models::payments::OrderDetails>,
+    /// The connector which authorizes the payment
+    pub payment_connector: String,
+    /// The identifier of the authorization at the connector, for the checks made after it
+    pub connector_transaction_id: Option<String>,
+    /// The status of the payment after the authorization, for the checks made after it
+    pub payment_status: Option<storage_enums::AttemptStatus>,
+}
+
+#[derive(Debug, Clone)]
+pub struct FraudCheckResponseData {
+    pub decision: api_models::enums::FrmDecision,
+    /// The identifier of the check at the fraud and risk management service
+    pub frm_transaction_id: Option<String>,
+    /// The risk score of the payment, on the scale of the service
+    pub score: Option<f64>,
+    pub reason: Option<String>,
+}
+
+/// Whether a payment is screened by the fraud and risk management service before or after it is
+/// authorized by the connector.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FrmFlow {
+    #[default]
+    Pre,
+    Post,
+}
+
+/// The outcome of screening a payment attempt, which is stored on the attempt.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct FrmData {
+    pub frm_connector: String,
+    pub flow: FrmFlow,
+    pub decision: api_models::enums::FrmDecision,
+    pub frm_transaction_id: Option<String>,
+    pub score: Option<f64>,
+    pub reason: Option<String>,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct BrowserInformation {
     pub color_depth: u8,
@@ -1,9 +1,22 @@
 pub mod admin;
+pub mod analytics;
+pub mod api_keys;
+pub mod authentication;
+pub mod blocklist;
 pub mod customers;
+pub mod disputes;
+#[cfg(feature = "dummy_connector")]
+pub mod dummy_connector;
 pub mod enums;
+pub mod events;
+pub mod exports;
+pub mod fraud_check;
 pub mod mandates;
+pub mod payment_links;
 pub mod payment_methods;
 pub mod payments;
+pub mod payouts;
+pub mod recon;
 pub mod refunds;
 pub mod webhooks;
 
@@ -12,7 +25,11 @@ use std::{fmt::Debug, str::FromStr};
 use bytes::Bytes;
 use error_stack::{report, IntoReport, ResultExt};
 
-pub use self::{admin::*, customers::*, payment_methods::*, payments::*, refunds::*, webhooks::*};
+pub use self::{
+    admin::*, analytics::*, api_keys::*, authentication::*, blocklist::*, customers::*,
+    disputes::*, events::*, exports::*, fraud_check::*, payment_links::*, payment_methods::*,
+    payments::*, payouts::*, recon::*, refunds::*, webhooks::*,
+};
 use super::ErrorResponse;
 use crate::{
     configs::settings::Connectors,
@@ -46,6 +63,12 @@ pub trait ConnectorCommon {
     /// The base URL for interacting with the connector's API.
     fn base_url<'a>(&self, connectors: &'a Connectors) -> &'a str;
 
+    /// Whether a payment declined by the connector with the given error code may be retried with
+    /// another connector, for example because the issuer was unavailable.
+    fn is_retryable_error(&self, _error_code: &str) -> bool {
+        false
+    }
+
     /// common error response for a connector if it is same in all case
     fn build_error_response(
         &self,
@@ -73,10 +96,41 @@ pub trait ConnectorCommonExt<Flow, Req, Resp>:
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct AccessTokenAuth;
+
+pub trait ConnectorAccessToken:
+    ConnectorIntegration<AccessTokenAuth, types::AccessTokenRequestData, types::AccessToken>
+{
+}
+
+#[derive(Debug, Clone)]
+pub struct VerifyWebhookSource;
+
+pub trait ConnectorVerifyWebhookSource:
+    ConnectorIntegration<
+    VerifyWebhookSource,
+    types::VerifyWebhookSourceRequestData,
+    types::VerifyWebhookSourceResponseData,
+>
+{
+}
+
 pub trait Router {}
 
 pub trait Connector:
-    Send + Refund + Payment + Debug + ConnectorRedirectResponse + IncomingWebhook
+    Send
+    + Refund
+    + Payment
+    + Debug
+    + ConnectorRedirectResponse
+    + IncomingWebhook
+    + ConnectorAccessToken
+    + ConnectorVerifyWebhookSource
+    + Dispute
+    + Payout
+    + ExternalAuthentication
+    + FraudCheck
 {
 }
 
@@ -84,8 +138,20 @@ pub struct Re;
 
 pub struct Pe;
 
-impl<T: Refund + Payment + Debug + ConnectorRedirectResponse + Send + IncomingWebhook> Connector
-    for T
+impl<
+        T: Refund
+            + Payment
+            + Debug
+            + ConnectorRedirectResponse
+            + Send
+            + IncomingWebhook
+            + ConnectorAccessToken
+            + ConnectorVerifyWebhookSource
+            + Dispute
+            + Payout
+            + ExternalAuthentication
+            + FraudCheck,
+    > Connector for T
 {
 }
 
@@ -108,6 +174,8 @@ pub enum ConnectorCallType {
     Routing,
     Multiple(Vec<ConnectorData>),
     Single(ConnectorData),
+    /// Connectors to be tried in order, until one of them does not fail with a retryable error.
+    Failover(Vec<ConnectorData>),
 }
 
 impl ConnectorCallType {
@@ -147,12 +215,18 @@ impl ConnectorData {
             "braintree" => Ok(Box::new(&connector::Braintree)),
             "checkout" => Ok(Box::new(&connector::Checkout)),
             "cybersource" => Ok(Box::new(&connector::Cybersource)),
+            #[cfg(feature = "dummy_connector")]
+            "dummy_connector" => Ok(Box::new(&connector::DummyConnector)),
             "fiserv" => Ok(Box::new(&connector::Fiserv)),
             "globalpay" => Ok(Box::new(&connector::Globalpay)),
             "klarna" => Ok(Box::new(&connector::Klarna)),
+            "netcetera" => Ok(Box::new(&connector::Netcetera)),
+            "paypal" => Ok(Box::new(&connector::Paypal)),
             "payu" => Ok(Box::new(&connector::Payu)),
             "rapyd" => Ok(Box::new(&connector::Rapyd)),
+            "razorpay" => Ok(Box::new(&connector::Razorpay)),
             "shift4" => Ok(Box::new(&connector::Shift4)),
+            "signifyd" => Ok(Box::new(&connector::Signifyd)),
             "stripe" => Ok(Box::new(&connector::Stripe)),
             "worldline" => Ok(Box::new(&connector::Worldline)),
             "worldpay" => Ok(Box::new(&connector::Worldpay)),
@@ -1,10 +1,15 @@
 pub use api_models::admin::{
-    CreateMerchantAccount, DeleteMcaResponse, DeleteResponse, MerchantAccountResponse,
-    MerchantConnectorId, MerchantDetails, MerchantId, PaymentConnectorCreate, PaymentMethods,
-    RoutingAlgorithm, WebhookDetails,
+    ConnectorVolumeSplit, CreateMerchantAccount, DeleteMcaResponse, DeleteResponse,
+    MerchantAccountResponse, MerchantConnectorId, MerchantDetails, MerchantId,
+    MerchantWebhookConfigRequest, MerchantWebhookConfigResponse, PaymentAuditLogEntry,
+    PaymentAuditLogResponse, PaymentConnectorCreate, PaymentMethods, RoutingAlgorithm, Surcharge,
+    SurchargeConfig, SurchargeRule, WebhookDetails, WebhookSigningSecretResponse,
 };
 
-use crate::types::{storage, transformers::Foreign};
+use crate::types::{
+    storage,
+    transformers::{Foreign, ForeignInto},
+};
 
 impl From<Foreign<storage::MerchantAccount>> for Foreign<MerchantAccountResponse> {
     fn from(value: Foreign<storage::MerchantAccount>) -> Self {
@@ -20,11 +25,44 @@ impl From<Foreign<storage::MerchantAccount>> for Foreign<MerchantAccountResponse
             merchant_details: item.merchant_details,
             webhook_details: item.webhook_details,
             routing_algorithm: item.routing_algorithm,
+            surcharge_config: item.surcharge_config,
             sub_merchants_enabled: item.sub_merchants_enabled,
             parent_merchant_id: item.parent_merchant_id,
             publishable_key: item.publishable_key,
             metadata: item.metadata,
             locker_id: item.locker_id,
+            webhook_signing_secret: item.webhook_signing_secret,
+        }
+        .into()
+    }
+}
+
+impl From<Foreign<storage::MerchantWebhookConfig>> for Foreign<MerchantWebhookConfigResponse> {
+    fn from(value: Foreign<storage::MerchantWebhookConfig>) -> Self {
+        let item = value.0;
+        MerchantWebhookConfigResponse {
+            merchant_id: item.merchant_id,
+            enabled: item.enabled,
+            webhook_url: item.webhook_url,
+            enabled_events: item.enabled_events.map(|enabled_events| {
+                enabled_events
+                    .into_iter()
+                    .map(ForeignInto::foreign_into)
+                    .collect()
+            }),
+        }
+        .into()
+    }
+}
+
+impl From<Foreign<storage::PaymentAuditLog>> for Foreign<PaymentAuditLogEntry> {
+    fn from(value: Foreign<storage::PaymentAuditLog>) -> Self {
+        let item = value.0;
+        PaymentAuditLogEntry {
+            previous_status: item.previous_status.foreign_into(),
+            status: item.status.foreign_into(),
+            actor: item.actor.foreign_into(),
+            created_at: item.created_at,
         }
         .into()
     }
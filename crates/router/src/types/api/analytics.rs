@@ -0,0 +1,4 @@
+pub use api_models::analytics::{
+    ConnectorMetrics, CurrencyMetrics, DeclineReasonMetric, PaymentAnalyticsRequest,
+    PaymentAnalyticsResponse, PaymentMethodMetrics, PaymentMetrics,
+};
@@ -0,0 +1,3 @@
+pub use api_models::api_keys::{
+    CreateApiKeyRequest, CreateApiKeyResponse, RetrieveApiKeyResponse, RevokeApiKeyResponse,
+};
@@ -0,0 +1,15 @@
+use crate::{services::api, types};
+
+#[derive(Debug, Clone)]
+pub struct ThreeDsAuthentication;
+
+/// Authenticates the cardholder with a standalone 3DS server, independently of the connector
+/// which authorizes the payment.
+pub trait ExternalAuthentication:
+    api::ConnectorIntegration<
+    ThreeDsAuthentication,
+    types::ThreeDsAuthenticationRequestData,
+    types::ThreeDsAuthenticationData,
+>
+{
+}
@@ -0,0 +1,3 @@
+pub use api_models::blocklist::{
+    BlocklistAddRequest, BlocklistDeleteResponse, BlocklistListConstraints, BlocklistResponse,
+};
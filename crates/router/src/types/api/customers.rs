@@ -1,5 +1,7 @@
 use api_models::customers;
-pub use api_models::customers::{CustomerDeleteResponse, CustomerId, CustomerRequest};
+pub use api_models::customers::{
+    CustomerDeleteResponse, CustomerId, CustomerRedactResponse, CustomerRequest,
+};
 use error_stack::ResultExt;
 use serde::Serialize;
 
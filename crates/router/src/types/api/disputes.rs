@@ -0,0 +1,53 @@
+pub use api_models::disputes::{
+    DisputeListConstraints, DisputeListResponse, DisputeResponse, SubmitEvidenceRequest,
+};
+use time::PrimitiveDateTime;
+
+use super::ConnectorCommon;
+use crate::{services::api, types};
+
+/// Details of a dispute, as sent by the connector in its dispute notifications.
+#[derive(Debug, Clone)]
+pub struct DisputePayload {
+    pub amount: i64,
+    pub currency: String,
+    /// The status of the dispute at the connector
+    pub connector_status: String,
+    pub connector_dispute_id: String,
+    pub connector_reason: Option<String>,
+    pub connector_reason_code: Option<String>,
+    pub challenge_required_by: Option<PrimitiveDateTime>,
+    /// The fees charged or refunded by the connector for the dispute so far
+    pub fees: Vec<DisputeFee>,
+}
+
+/// A fee charged by the connector for a dispute, or the refund of one.
+#[derive(Debug, Clone)]
+pub struct DisputeFee {
+    /// The reference of the fee at the connector, which is the same across notifications
+    pub connector_reference: String,
+    /// The amount charged to the merchant, in the lowest denomination of the currency. Negative
+    /// for a fee refunded to the merchant.
+    pub amount: i64,
+    pub currency: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct Evidence;
+#[derive(Debug, Clone)]
+pub struct Defend;
+
+/// Sends the evidence for challenging a dispute to the connector.
+pub trait SubmitEvidence:
+    api::ConnectorIntegration<Evidence, types::SubmitEvidenceRequestData, types::SubmitEvidenceResponse>
+{
+}
+
+/// Submits the challenge of a dispute for review, for connectors which accept the evidence and
+/// the challenge in separate calls.
+pub trait DefendDispute:
+    api::ConnectorIntegration<Defend, types::DefendDisputeRequestData, types::DefendDisputeResponse>
+{
+}
+
+pub trait Dispute: ConnectorCommon + SubmitEvidence + DefendDispute {}
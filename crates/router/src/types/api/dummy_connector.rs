@@ -0,0 +1,95 @@
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+
+use crate::types::storage::{
+    dummy_connector::{
+        DummyConnectorError, DummyConnectorPayment, DummyConnectorRefund,
+        DummyConnectorRefundStatus, DummyConnectorStatus,
+    },
+    enums,
+};
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DummyConnectorPaymentRequest {
+    pub amount: i64,
+    pub currency: enums::Currency,
+    pub card: DummyConnectorCard,
+    /// Whether the payment is to be captured as soon as it is authorized
+    pub capture: bool,
+    /// URL to which the customer is sent back after authenticating the payment, required for
+    /// payments made with the test cards which require authentication
+    pub return_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DummyConnectorCard {
+    pub number: Secret<String>,
+    pub expiry_month: Secret<String>,
+    pub expiry_year: Secret<String>,
+    pub cvc: Secret<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DummyConnectorCaptureRequest {
+    /// Amount to capture, the whole amount of the payment is captured if it is not given
+    pub amount: Option<i64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DummyConnectorRefundRequest {
+    pub amount: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DummyConnectorPaymentResponse {
+    pub id: String,
+    pub status: DummyConnectorStatus,
+    pub amount: i64,
+    pub currency: enums::Currency,
+    pub refundable_amount: i64,
+    /// URL to which the customer has to be sent to authenticate the payment
+    pub redirect_url: Option<String>,
+    pub error: Option<DummyConnectorError>,
+    pub created_at: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DummyConnectorRefundResponse {
+    pub id: String,
+    pub payment_id: String,
+    pub status: DummyConnectorRefundStatus,
+    pub amount: i64,
+    pub currency: enums::Currency,
+    pub created_at: i64,
+}
+
+impl DummyConnectorPaymentResponse {
+    pub fn new(payment: DummyConnectorPayment, redirect_url: Option<String>) -> Self {
+        Self {
+            id: payment.payment_id,
+            status: payment.status,
+            amount: payment.amount,
+            currency: payment.currency,
+            refundable_amount: payment.refundable_amount,
+            redirect_url,
+            // The error is not disclosed until the customer has authenticated the payment
+            error: payment
+                .error
+                .filter(|_| payment.status != DummyConnectorStatus::RequiresAuthentication),
+            created_at: payment.created_at,
+        }
+    }
+}
+
+impl From<DummyConnectorRefund> for DummyConnectorRefundResponse {
+    fn from(refund: DummyConnectorRefund) -> Self {
+        Self {
+            id: refund.refund_id,
+            payment_id: refund.payment_id,
+            status: refund.status,
+            amount: refund.amount,
+            currency: refund.currency,
+            created_at: refund.created_at,
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub use api_models::events::{
+    EventListConstraints, EventListResponse, EventResponse, WebhookDeliveryAttemptListResponse,
+    WebhookDeliveryAttemptResponse,
+};
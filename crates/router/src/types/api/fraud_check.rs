@@ -0,0 +1,30 @@
+use crate::{services::api, types};
+
+#[derive(Debug, Clone)]
+pub struct FraudCheckPre;
+
+#[derive(Debug, Clone)]
+pub struct FraudCheckPost;
+
+/// Screens a payment with a fraud and risk management service before it is authorized.
+pub trait FraudCheckPreAuth:
+    api::ConnectorIntegration<
+    FraudCheckPre,
+    types::FraudCheckRequestData,
+    types::FraudCheckResponseData,
+>
+{
+}
+
+/// Screens a payment with a fraud and risk management service after it has been authorized, and
+/// before it is captured.
+pub trait FraudCheckPostAuth:
+    api::ConnectorIntegration<
+    FraudCheckPost,
+    types::FraudCheckRequestData,
+    types::FraudCheckResponseData,
+>
+{
+}
+
+pub trait FraudCheck: FraudCheckPreAuth + FraudCheckPostAuth {}
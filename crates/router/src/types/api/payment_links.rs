@@ -0,0 +1 @@
+pub use api_models::payment_links::{PaymentLinkCreateRequest, PaymentLinkResponse};
@@ -5,8 +5,9 @@ pub use api_models::payment_methods::{
     DeletePaymentMethodResponse, DeleteTokenizeByDateRequest, DeleteTokenizeByTokenRequest,
     GetTokenizePayloadRequest, GetTokenizePayloadResponse, ListCustomerPaymentMethodsResponse,
     ListPaymentMethod, ListPaymentMethodRequest, ListPaymentMethodResponse, PaymentMethodId,
-    PaymentMethodResponse, TokenizePayloadEncrypted, TokenizePayloadRequest, TokenizedCardValue1,
-    TokenizedCardValue2, TokenizedWalletValue1, TokenizedWalletValue2, UpdatePaymentMethod,
+    PaymentMethodResponse, SurchargeDetailsResponse, TokenizePayloadEncrypted,
+    TokenizePayloadRequest, TokenizedCardValue1, TokenizedCardValue2, TokenizedWalletValue1,
+    TokenizedWalletValue2, UpdatePaymentMethod,
 };
 use error_stack::report;
 use literally::hmap;
@@ -0,0 +1,33 @@
+pub use api_models::payouts::{
+    BankPayout, PayoutConfirmRequest, PayoutCreateRequest, PayoutMethodData, PayoutResponse,
+};
+
+use super::ConnectorCommon;
+use crate::{services::api, types};
+
+#[derive(Debug, Clone)]
+pub struct PoCreate;
+#[derive(Debug, Clone)]
+pub struct PoFulfill;
+#[derive(Debug, Clone)]
+pub struct PoCancel;
+
+/// Submits the payout along with the recipient's account details to the connector.
+pub trait PayoutCreate:
+    api::ConnectorIntegration<PoCreate, types::PayoutsData, types::PayoutsResponseData>
+{
+}
+
+/// Instructs the connector to execute a payout which was submitted earlier.
+pub trait PayoutFulfill:
+    api::ConnectorIntegration<PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+{
+}
+
+/// Cancels a payout which was submitted to the connector, but not yet fulfilled.
+pub trait PayoutCancel:
+    api::ConnectorIntegration<PoCancel, types::PayoutsData, types::PayoutsResponseData>
+{
+}
+
+pub trait Payout: ConnectorCommon + PayoutCreate + PayoutFulfill + PayoutCancel {}
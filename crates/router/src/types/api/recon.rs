@@ -0,0 +1,4 @@
+pub use api_models::recon::{
+    ReconMismatchResponse, ReconReportCreateRequest, ReconReportListConstraints,
+    ReconReportListResponse, ReconReportResponse,
+};
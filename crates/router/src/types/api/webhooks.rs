@@ -1,10 +1,11 @@
 pub use api_models::webhooks::{
     IncomingWebhookDetails, IncomingWebhookEvent, MerchantWebhookConfig, OutgoingWebhook,
-    OutgoingWebhookContent, WebhookFlow,
+    OutgoingWebhookContent, TestWebhookRequest, TestWebhookResponse, WebhookFlow,
 };
 use error_stack::ResultExt;
+use router_env::logger;
 
-use super::ConnectorCommon;
+use super::{disputes, payments, ConnectorCommon};
 use crate::{
     core::errors::{self, CustomResult},
     db::StorageInterface,
@@ -12,6 +13,46 @@ use crate::{
     utils::crypto,
 };
 
+/// The secrets with which the source of the webhooks of a connector is verified.
+///
+/// A secret is rotated by moving the current secret to the `_previous` key in Redis, and then
+/// setting the new secret. Webhooks signed with either secret are accepted until the previous
+/// secret is deleted, so that no webhook is dropped while the connector switches to the new one.
+#[derive(Debug, Clone, Default)]
+pub struct WebhookSourceVerificationSecrets {
+    pub current: Vec<u8>,
+    pub previous: Option<Vec<u8>>,
+}
+
+/// Fetches the webhook source verification secrets of the merchant for the connector, which are
+/// stored in Redis.
+pub async fn get_webhook_source_verification_secrets(
+    db: &dyn StorageInterface,
+    connector: &str,
+    merchant_id: &str,
+) -> CustomResult<WebhookSourceVerificationSecrets, errors::ConnectorError> {
+    let key = format!("whsec_verification_{connector}_{merchant_id}");
+    let current = db
+        .get_key(&key)
+        .await
+        .change_context(errors::ConnectorError::WebhookVerificationSecretNotFound)?;
+
+    // The previous secret only exists while the secret is being rotated, and the webhooks can
+    // still be verified with the current secret if it cannot be fetched
+    let previous = match db.get_key(&format!("{key}_previous")).await {
+        Ok(previous) => Some(previous).filter(|previous| !previous.is_empty()),
+        Err(error) => {
+            logger::warn!(
+                ?error,
+                "Failed to fetch the previous webhook verification secret"
+            );
+            None
+        }
+    };
+
+    Ok(WebhookSourceVerificationSecrets { current, previous })
+}
+
 #[async_trait::async_trait]
 pub trait IncomingWebhook: ConnectorCommon + Sync {
     fn get_webhook_body_decoding_algorithm(
@@ -72,8 +113,8 @@ pub trait IncomingWebhook: ConnectorCommon + Sync {
         &self,
         _db: &dyn StorageInterface,
         _merchant_id: &str,
-    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
-        Ok(Vec::new())
+    ) -> CustomResult<WebhookSourceVerificationSecrets, errors::ConnectorError> {
+        Ok(WebhookSourceVerificationSecrets::default())
     }
 
     fn get_webhook_source_verification_signature(
@@ -109,14 +150,30 @@ pub trait IncomingWebhook: ConnectorCommon + Sync {
         let message = self
             .get_webhook_source_verification_message(headers, body)
             .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
-        let secret = self
+        let secrets = self
             .get_webhook_source_verification_merchant_secret(db, merchant_id)
             .await
             .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
 
-        algorithm
-            .verify_signature(&secret, &signature, &message)
-            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)
+        let verified = algorithm
+            .verify_signature(&secrets.current, &signature, &message)
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+        match secrets.previous {
+            Some(previous) if !verified => {
+                let verified = algorithm
+                    .verify_signature(&previous, &signature, &message)
+                    .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+                if verified {
+                    logger::info!(
+                        connector = self.id(),
+                        merchant_id,
+                        "Webhook verified with the previous secret"
+                    );
+                }
+                Ok(verified)
+            }
+            _ => Ok(verified),
+        }
     }
 
     fn get_webhook_object_reference_id(
@@ -124,16 +181,39 @@ pub trait IncomingWebhook: ConnectorCommon + Sync {
         _body: &[u8],
     ) -> CustomResult<String, errors::ConnectorError>;
 
+    /// Wraps the object reference ID of payment webhooks into the identifier the payment is
+    /// looked up by, which is the connector's transaction ID unless overridden.
+    fn get_webhook_payment_id_type(&self, object_reference_id: String) -> payments::PaymentIdType {
+        payments::PaymentIdType::ConnectorTransactionId(object_reference_id)
+    }
+
     fn get_webhook_event_type(
         &self,
         _body: &[u8],
     ) -> CustomResult<IncomingWebhookEvent, errors::ConnectorError>;
 
+    /// Returns the ID of the event at the connector, which stays the same when the connector
+    /// redelivers the webhook. Webhooks of connectors which do not provide one are processed on
+    /// every delivery.
+    fn get_webhook_event_id(
+        &self,
+        _body: &[u8],
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        Ok(None)
+    }
+
     fn get_webhook_resource_object(
         &self,
         _body: &[u8],
     ) -> CustomResult<serde_json::Value, errors::ConnectorError>;
 
+    fn get_dispute_details(
+        &self,
+        _body: &[u8],
+    ) -> CustomResult<disputes::DisputePayload, errors::ConnectorError> {
+        Err(errors::ConnectorError::NotImplemented("get_dispute_details".to_string()).into())
+    }
+
     fn get_webhook_api_response(
         &self,
     ) -> CustomResult<services::api::ApplicationResponse<serde_json::Value>, errors::ConnectorError>
@@ -1,19 +1,38 @@
 pub mod address;
+pub mod api_keys;
+pub mod blocklist;
+pub mod capture;
 pub mod configs;
 pub mod connector_response;
+pub mod customer_redaction_log;
 pub mod customers;
+pub mod dispute;
+pub mod dummy_connector;
 pub mod enums;
 pub mod ephemeral_key;
 pub mod events;
+pub mod export;
+pub mod incoming_webhook_event;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
 pub mod merchant_connector_account;
+pub mod merchant_key_store;
+pub mod merchant_webhook_config;
+pub mod ops;
+pub mod outbox_event;
 pub mod payment_attempt;
+pub mod payment_audit_log;
+pub mod payment_cost;
 pub mod payment_intent;
+pub mod payment_link;
 pub mod payment_method;
+pub mod payout;
+pub mod payout_attempt;
 pub mod process_tracker;
+pub mod recon;
 pub mod reverse_lookup;
+pub mod webhook_delivery_attempt;
 
 mod query;
 pub mod refund;
@@ -22,7 +41,12 @@ pub mod refund;
 pub mod kv;
 
 pub use self::{
-    address::*, configs::*, connector_response::*, customers::*, events::*, locker_mock_up::*,
-    mandate::*, merchant_account::*, merchant_connector_account::*, payment_attempt::*,
-    payment_intent::*, payment_method::*, process_tracker::*, refund::*, reverse_lookup::*,
+    address::*, api_keys::*, blocklist::*, capture::*, configs::*, connector_response::*,
+    customer_redaction_log::*, customers::*, dispute::*, events::*, export::*,
+    incoming_webhook_event::*, locker_mock_up::*, mandate::*, merchant_account::*,
+    merchant_connector_account::*, merchant_key_store::*, merchant_webhook_config::*, ops::*,
+    outbox_event::*, payment_attempt::*, payment_audit_log::*, payment_cost::*, payment_intent::*,
+    payment_link::*, payment_method::*, payout::*, payout_attempt::*, process_tracker::*, recon::*,
+    refund::*, reverse_lookup::*, webhook_delivery_attempt::*,
 };
+pub use storage_models::encryption::Encryptable;
@@ -1 +1,182 @@
-pub use storage_models::address::{Address, AddressNew, AddressUpdate, AddressUpdateInternal};
+use common_utils::{consts, custom_serde, generate_id};
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use storage_models::address as address_rows;
+use time::PrimitiveDateTime;
+
+use crate::{
+    core::errors::{self, CustomResult},
+    services::encryption::{decrypt_field, encrypt_field},
+};
+
+/// An address with its personal data decrypted. The street, state, zip code, name and phone
+/// number are stored encrypted with the data key of the merchant, and are encrypted and decrypted
+/// by the database layer.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Address {
+    #[serde(skip_serializing)]
+    pub id: i32,
+    #[serde(skip_serializing)]
+    pub address_id: String,
+    pub city: Option<String>,
+    pub country: Option<String>,
+    pub line1: Option<Secret<String>>,
+    pub line2: Option<Secret<String>>,
+    pub line3: Option<Secret<String>>,
+    pub state: Option<Secret<String>>,
+    pub zip: Option<Secret<String>>,
+    pub first_name: Option<Secret<String>>,
+    pub last_name: Option<Secret<String>>,
+    pub phone_number: Option<Secret<String>>,
+    pub country_code: Option<String>,
+    #[serde(skip_serializing)]
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(skip_serializing)]
+    #[serde(with = "custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+    pub customer_id: String,
+    pub merchant_id: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct AddressNew {
+    pub address_id: String,
+    pub city: Option<String>,
+    pub country: Option<String>,
+    pub line1: Option<Secret<String>>,
+    pub line2: Option<Secret<String>>,
+    pub line3: Option<Secret<String>>,
+    pub state: Option<Secret<String>>,
+    pub zip: Option<Secret<String>>,
+    pub first_name: Option<Secret<String>>,
+    pub last_name: Option<Secret<String>>,
+    pub phone_number: Option<Secret<String>>,
+    pub country_code: Option<String>,
+    pub customer_id: String,
+    pub merchant_id: String,
+}
+
+#[derive(Debug)]
+pub enum AddressUpdate {
+    Update {
+        city: Option<String>,
+        country: Option<String>,
+        line1: Option<Secret<String>>,
+        line2: Option<Secret<String>>,
+        line3: Option<Secret<String>>,
+        state: Option<Secret<String>>,
+        zip: Option<Secret<String>>,
+        first_name: Option<Secret<String>>,
+        last_name: Option<Secret<String>>,
+        phone_number: Option<Secret<String>>,
+        country_code: Option<String>,
+    },
+}
+
+impl Default for AddressNew {
+    fn default() -> Self {
+        Self {
+            address_id: generate_id(consts::ID_LENGTH, "add"),
+            city: None,
+            country: None,
+            line1: None,
+            line2: None,
+            line3: None,
+            state: None,
+            zip: None,
+            first_name: None,
+            last_name: None,
+            phone_number: None,
+            country_code: None,
+            customer_id: String::default(),
+            merchant_id: String::default(),
+        }
+    }
+}
+
+impl Address {
+    pub(crate) fn decrypt(
+        address: address_rows::Address,
+        key: &[u8],
+    ) -> CustomResult<Self, errors::EncryptionError> {
+        Ok(Self {
+            id: address.id,
+            address_id: address.address_id,
+            city: address.city,
+            country: address.country,
+            line1: decrypt_field(address.line1, key)?,
+            line2: decrypt_field(address.line2, key)?,
+            line3: decrypt_field(address.line3, key)?,
+            state: decrypt_field(address.state, key)?,
+            zip: decrypt_field(address.zip, key)?,
+            first_name: decrypt_field(address.first_name, key)?,
+            last_name: decrypt_field(address.last_name, key)?,
+            phone_number: decrypt_field(address.phone_number, key)?,
+            country_code: address.country_code,
+            created_at: address.created_at,
+            modified_at: address.modified_at,
+            customer_id: address.customer_id,
+            merchant_id: address.merchant_id,
+        })
+    }
+}
+
+impl AddressNew {
+    pub(crate) fn encrypt(
+        self,
+        key: &[u8],
+    ) -> CustomResult<address_rows::AddressNew, errors::EncryptionError> {
+        Ok(address_rows::AddressNew {
+            address_id: self.address_id,
+            city: self.city,
+            country: self.country,
+            line1: encrypt_field(self.line1, key)?,
+            line2: encrypt_field(self.line2, key)?,
+            line3: encrypt_field(self.line3, key)?,
+            state: encrypt_field(self.state, key)?,
+            zip: encrypt_field(self.zip, key)?,
+            first_name: encrypt_field(self.first_name, key)?,
+            last_name: encrypt_field(self.last_name, key)?,
+            phone_number: encrypt_field(self.phone_number, key)?,
+            country_code: self.country_code,
+            customer_id: self.customer_id,
+            merchant_id: self.merchant_id,
+        })
+    }
+}
+
+impl AddressUpdate {
+    pub(crate) fn encrypt(
+        self,
+        key: &[u8],
+    ) -> CustomResult<address_rows::AddressUpdate, errors::EncryptionError> {
+        match self {
+            Self::Update {
+                city,
+                country,
+                line1,
+                line2,
+                line3,
+                state,
+                zip,
+                first_name,
+                last_name,
+                phone_number,
+                country_code,
+            } => Ok(address_rows::AddressUpdate::Update {
+                city,
+                country,
+                line1: encrypt_field(line1, key)?,
+                line2: encrypt_field(line2, key)?,
+                line3: encrypt_field(line3, key)?,
+                state: encrypt_field(state, key)?,
+                zip: encrypt_field(zip, key)?,
+                first_name: encrypt_field(first_name, key)?,
+                last_name: encrypt_field(last_name, key)?,
+                phone_number: encrypt_field(phone_number, key)?,
+                country_code,
+            }),
+        }
+    }
+}
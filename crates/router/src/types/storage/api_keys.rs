@@ -0,0 +1 @@
+pub use storage_models::api_keys::{ApiKey, ApiKeyNew, ApiKeyUpdate, ApiKeyUpdateInternal};
@@ -0,0 +1 @@
+pub use storage_models::customer_redaction_log::{CustomerRedactionLog, CustomerRedactionLogNew};
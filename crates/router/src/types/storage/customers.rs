@@ -1,3 +1,112 @@
-pub use storage_models::customers::{
-    Customer, CustomerNew, CustomerUpdate, CustomerUpdateInternal,
+use common_utils::pii;
+use masking::Secret;
+use storage_models::customers as customer_rows;
+use time::PrimitiveDateTime;
+
+use crate::{
+    core::errors::{self, CustomResult},
+    services::encryption::{decrypt_field, encrypt_field},
 };
+
+/// A customer with its personal data decrypted. The email and phone number are stored encrypted
+/// with the data key of the merchant, and are encrypted and decrypted by the database layer.
+#[derive(Clone, Debug)]
+pub struct Customer {
+    pub id: i32,
+    pub customer_id: String,
+    pub merchant_id: String,
+    pub name: Option<String>,
+    pub email: Option<Secret<String, pii::Email>>,
+    pub phone: Option<Secret<String>>,
+    pub phone_country_code: Option<String>,
+    pub description: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub metadata: Option<serde_json::Value>,
+}
+
+#[derive(Default, Clone, Debug)]
+pub struct CustomerNew {
+    pub customer_id: String,
+    pub merchant_id: String,
+    pub name: Option<String>,
+    pub email: Option<Secret<String, pii::Email>>,
+    pub phone: Option<Secret<String>>,
+    pub description: Option<String>,
+    pub phone_country_code: Option<String>,
+    pub metadata: Option<serde_json::Value>,
+}
+
+#[derive(Debug)]
+pub enum CustomerUpdate {
+    Update {
+        name: Option<String>,
+        email: Option<Secret<String, pii::Email>>,
+        phone: Option<Secret<String>>,
+        description: Option<String>,
+        phone_country_code: Option<String>,
+        metadata: Option<serde_json::Value>,
+    },
+}
+
+impl Customer {
+    pub(crate) fn decrypt(
+        customer: customer_rows::Customer,
+        key: &[u8],
+    ) -> CustomResult<Self, errors::EncryptionError> {
+        Ok(Self {
+            id: customer.id,
+            customer_id: customer.customer_id,
+            merchant_id: customer.merchant_id,
+            name: customer.name,
+            email: decrypt_field(customer.email, key)?,
+            phone: decrypt_field(customer.phone, key)?,
+            phone_country_code: customer.phone_country_code,
+            description: customer.description,
+            created_at: customer.created_at,
+            metadata: customer.metadata,
+        })
+    }
+}
+
+impl CustomerNew {
+    pub(crate) fn encrypt(
+        self,
+        key: &[u8],
+    ) -> CustomResult<customer_rows::CustomerNew, errors::EncryptionError> {
+        Ok(customer_rows::CustomerNew {
+            customer_id: self.customer_id,
+            merchant_id: self.merchant_id,
+            name: self.name,
+            email: encrypt_field(self.email, key)?,
+            phone: encrypt_field(self.phone, key)?,
+            description: self.description,
+            phone_country_code: self.phone_country_code,
+            metadata: self.metadata,
+        })
+    }
+}
+
+impl CustomerUpdate {
+    pub(crate) fn encrypt(
+        self,
+        key: &[u8],
+    ) -> CustomResult<customer_rows::CustomerUpdate, errors::EncryptionError> {
+        match self {
+            Self::Update {
+                name,
+                email,
+                phone,
+                description,
+                phone_country_code,
+                metadata,
+            } => Ok(customer_rows::CustomerUpdate::Update {
+                name,
+                email: encrypt_field(email, key)?,
+                phone: encrypt_field(phone, key)?,
+                description,
+                phone_country_code,
+                metadata,
+            }),
+        }
+    }
+}
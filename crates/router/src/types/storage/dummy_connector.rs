@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::storage::enums;
+
+/// A payment made with the dummy connector, which is kept in Redis for as long as the keys of
+/// Redis live by default.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DummyConnectorPayment {
+    pub payment_id: String,
+    pub status: DummyConnectorStatus,
+    pub amount: i64,
+    pub currency: enums::Currency,
+    /// Whether the payment is captured as soon as it is authorized
+    pub capture: bool,
+    /// Whether captures of the payment complete only when the payment is next retrieved
+    pub delayed_capture: bool,
+    /// Amount which has been captured and not refunded yet
+    pub refundable_amount: i64,
+    /// URL to which the customer is sent back after authenticating the payment
+    pub return_url: Option<String>,
+    /// Error with which the payment failed, or is to fail once the customer has authenticated it
+    pub error: Option<DummyConnectorError>,
+    pub created_at: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DummyConnectorRefund {
+    pub refund_id: String,
+    pub payment_id: String,
+    pub status: DummyConnectorRefundStatus,
+    pub amount: i64,
+    pub currency: enums::Currency,
+    pub created_at: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DummyConnectorError {
+    pub code: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DummyConnectorStatus {
+    RequiresAuthentication,
+    Authorized,
+    Processing,
+    Succeeded,
+    Failed,
+    Voided,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DummyConnectorRefundStatus {
+    Succeeded,
+}
@@ -1 +1,71 @@
-pub use storage_models::events::{Event, EventNew};
+use async_bb8_diesel::AsyncRunQueryDsl;
+use common_utils::errors::CustomResult;
+use diesel::{associations::HasTable, ExpressionMethods, QueryDsl};
+use error_stack::{IntoReport, ResultExt};
+pub use storage_models::events::{Event, EventNew, EventUpdate};
+use storage_models::{errors, schema::events::dsl};
+
+use crate::{connection::PgPooledConn, logger, types::transformers::ForeignInto};
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct OutgoingWebhookTrackingData {
+    pub merchant_id: String,
+    pub event_id: String,
+    /// The webhook body, as sent to the merchant in the first delivery attempt
+    pub webhook: serde_json::Value,
+}
+
+#[async_trait::async_trait]
+pub trait EventDbExt: Sized {
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        event_list_constraints: &api_models::events::EventListConstraints,
+        limit: i64,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError>;
+}
+
+#[async_trait::async_trait]
+impl EventDbExt for Event {
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        event_list_constraints: &api_models::events::EventListConstraints,
+        limit: i64,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
+        let mut filter = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .order(dsl::created_at.desc())
+            .limit(limit)
+            .into_boxed();
+
+        if let Some(event_type) = event_list_constraints.event_type {
+            let event_type: storage_models::enums::EventType = event_type.foreign_into();
+            filter = filter.filter(dsl::event_type.eq(event_type));
+        }
+        if let Some(is_webhook_notified) = event_list_constraints.is_webhook_notified {
+            filter = filter.filter(dsl::is_webhook_notified.eq(is_webhook_notified));
+        }
+        if let Some(created_lt) = event_list_constraints.created_lt {
+            filter = filter.filter(dsl::created_at.lt(created_lt));
+        }
+        if let Some(created_gt) = event_list_constraints.created_gt {
+            filter = filter.filter(dsl::created_at.gt(created_gt));
+        }
+        if let Some(created_lte) = event_list_constraints.created_lte {
+            filter = filter.filter(dsl::created_at.le(created_lte));
+        }
+        if let Some(created_gte) = event_list_constraints.created_gte {
+            filter = filter.filter(dsl::created_at.ge(created_gte));
+        }
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
+
+        filter
+            .get_results_async(conn)
+            .await
+            .into_report()
+            .change_context(errors::DatabaseError::NotFound)
+            .attach_printable_lazy(|| "Error filtering records by predicate")
+    }
+}
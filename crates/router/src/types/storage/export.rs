@@ -0,0 +1,7 @@
+pub use storage_models::export::{Export, ExportNew, ExportUpdate, ExportUpdateInternal};
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ExportTrackingData {
+    pub merchant_id: String,
+    pub export_id: String,
+}
@@ -0,0 +1 @@
+pub use storage_models::incoming_webhook_event::{IncomingWebhookEvent, IncomingWebhookEventNew};
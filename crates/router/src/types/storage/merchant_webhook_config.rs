@@ -0,0 +1,3 @@
+pub use storage_models::merchant_webhook_config::{
+    MerchantWebhookConfig, MerchantWebhookConfigNew, MerchantWebhookConfigUpdate,
+};
@@ -0,0 +1,32 @@
+use api_models::ops::OpsActionStatus;
+
+/// Tracking data of a batch run of an ops action, which holds the outcome of the action on every
+/// record of the batch.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct OpsBatchWorkflow {
+    pub batch_id: String,
+    pub action: OpsAction,
+    pub items: Vec<OpsBatchItem>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OpsAction {
+    ProcessRequeue,
+    PaymentsResync,
+    EventsReplay,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct OpsBatchItem {
+    /// Identifier of the process, payment attempt or event on which the action is performed
+    pub id: String,
+    pub merchant_id: Option<String>,
+    pub result: Option<OpsBatchItemResult>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct OpsBatchItemResult {
+    pub status: OpsActionStatus,
+    pub message: Option<String>,
+}
@@ -0,0 +1 @@
+pub use storage_models::outbox_event::{OutboxEvent, OutboxEventNew, OutboxEventUpdate};
@@ -1,10 +1,146 @@
+#[cfg(feature = "olap")]
+use async_bb8_diesel::AsyncRunQueryDsl;
+#[cfg(feature = "olap")]
+use diesel::{
+    dsl::{count_star, sql},
+    sql_types::{BigInt, Nullable},
+    ExpressionMethods, QueryDsl,
+};
+#[cfg(feature = "olap")]
+use error_stack::{IntoReport, ResultExt};
+#[cfg(feature = "olap")]
+use router_env::{instrument, tracing};
 pub use storage_models::payment_attempt::{
     PaymentAttempt, PaymentAttemptNew, PaymentAttemptUpdate, PaymentAttemptUpdateInternal,
 };
+#[cfg(feature = "olap")]
+use storage_models::{enums as storage_enums, errors, schema::payment_attempt::dsl};
+
+#[cfg(feature = "olap")]
+use crate::{connection::PgPooledConn, core::errors::CustomResult, logger};
 
 #[cfg(feature = "kv_store")]
 impl crate::utils::storage_partitioning::KvStorePartition for PaymentAttempt {}
 
+/// The number and the total amount of the payment attempts of a merchant which share the same
+/// connector, currency, payment method and status.
+#[cfg(feature = "olap")]
+#[derive(Clone, Debug, diesel::Queryable)]
+pub struct PaymentAttemptAggregate {
+    pub connector: Option<String>,
+    pub currency: Option<storage_enums::Currency>,
+    pub payment_method: Option<storage_enums::PaymentMethodType>,
+    pub status: storage_enums::AttemptStatus,
+    pub count: i64,
+    pub amount: Option<i64>,
+}
+
+/// The number of failed payment attempts of a merchant which share the same error.
+#[cfg(feature = "olap")]
+#[derive(Clone, Debug, diesel::Queryable)]
+pub struct PaymentAttemptDeclineReason {
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub count: i64,
+}
+
+/// The statuses of the payment attempts which were declined, either by the connector or by the
+/// router itself.
+#[cfg(feature = "olap")]
+pub const DECLINED_ATTEMPT_STATUSES: [storage_enums::AttemptStatus; 4] = [
+    storage_enums::AttemptStatus::Failure,
+    storage_enums::AttemptStatus::AuthorizationFailed,
+    storage_enums::AttemptStatus::AuthenticationFailed,
+    storage_enums::AttemptStatus::RouterDeclined,
+];
+
+#[cfg(feature = "olap")]
+#[async_trait::async_trait]
+pub trait PaymentAttemptDbExt: Sized {
+    /// Aggregates the payment attempts created by the merchant in the time range `[start, end)`.
+    async fn aggregate_by_merchant_id_created_between(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        start: time::PrimitiveDateTime,
+        end: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<PaymentAttemptAggregate>, errors::DatabaseError>;
+
+    /// Counts the declined payment attempts created by the merchant in the time range
+    /// `[start, end)`, by the error they were declined with.
+    async fn count_declines_by_merchant_id_created_between(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        start: time::PrimitiveDateTime,
+        end: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<PaymentAttemptDeclineReason>, errors::DatabaseError>;
+}
+
+#[cfg(feature = "olap")]
+#[async_trait::async_trait]
+impl PaymentAttemptDbExt for PaymentAttempt {
+    #[instrument(skip(conn))]
+    async fn aggregate_by_merchant_id_created_between(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        start: time::PrimitiveDateTime,
+        end: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<PaymentAttemptAggregate>, errors::DatabaseError> {
+        let query = dsl::payment_attempt
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(dsl::created_at.ge(start))
+            .filter(dsl::created_at.lt(end))
+            .group_by((
+                dsl::connector,
+                dsl::currency,
+                dsl::payment_method,
+                dsl::status,
+            ))
+            .select((
+                dsl::connector,
+                dsl::currency,
+                dsl::payment_method,
+                dsl::status,
+                count_star(),
+                // `SUM` of a `BIGINT` column is a `NUMERIC`, which does not fit the amounts
+                sql::<Nullable<BigInt>>("CAST(SUM(amount) AS BIGINT)"),
+            ));
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+        query
+            .get_results_async(conn)
+            .await
+            .into_report()
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable_lazy(|| "Error aggregating payment attempts")
+    }
+
+    #[instrument(skip(conn))]
+    async fn count_declines_by_merchant_id_created_between(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        start: time::PrimitiveDateTime,
+        end: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<PaymentAttemptDeclineReason>, errors::DatabaseError> {
+        let query = dsl::payment_attempt
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(dsl::created_at.ge(start))
+            .filter(dsl::created_at.lt(end))
+            .filter(dsl::status.eq_any(DECLINED_ATTEMPT_STATUSES.to_vec()))
+            .group_by((dsl::error_code, dsl::error_message))
+            .select((dsl::error_code, dsl::error_message, count_star()));
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+        query
+            .get_results_async(conn)
+            .await
+            .into_report()
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable_lazy(|| "Error counting declined payment attempts")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::expect_used, clippy::unwrap_used)]
@@ -24,7 +160,9 @@ mod tests {
     async fn test_payment_attempt_insert() {
         let conf = Settings::new().expect("invalid settings");
 
-        let state = routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest).await;
+        let state = routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest)
+            .await
+            .unwrap();
 
         let payment_id = Uuid::new_v4().to_string();
         let current_time = common_utils::date_time::now();
@@ -52,7 +190,9 @@ mod tests {
     async fn test_find_payment_attempt() {
         use crate::configs::settings::Settings;
         let conf = Settings::new().expect("invalid settings");
-        let state = routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest).await;
+        let state = routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest)
+            .await
+            .unwrap();
 
         let current_time = common_utils::date_time::now();
         let payment_id = Uuid::new_v4().to_string();
@@ -94,7 +234,9 @@ mod tests {
         use crate::configs::settings::Settings;
         let conf = Settings::new().expect("invalid settings");
         let uuid = uuid::Uuid::new_v4().to_string();
-        let state = routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest).await;
+        let state = routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest)
+            .await
+            .unwrap();
         let current_time = common_utils::date_time::now();
 
         let payment_attempt = PaymentAttemptNew {
@@ -0,0 +1 @@
+pub use storage_models::payment_audit_log::{PaymentAuditLog, PaymentAuditLogNew};
@@ -0,0 +1,72 @@
+#[cfg(feature = "olap")]
+use async_bb8_diesel::AsyncRunQueryDsl;
+#[cfg(feature = "olap")]
+use diesel::{
+    dsl::sql,
+    sql_types::{BigInt, Nullable},
+    ExpressionMethods, QueryDsl,
+};
+#[cfg(feature = "olap")]
+use error_stack::{IntoReport, ResultExt};
+#[cfg(feature = "olap")]
+use router_env::{instrument, tracing};
+pub use storage_models::payment_cost::{PaymentCost, PaymentCostNew};
+#[cfg(feature = "olap")]
+use storage_models::{enums as storage_enums, errors, schema::payment_cost::dsl};
+
+#[cfg(feature = "olap")]
+use crate::{connection::PgPooledConn, core::errors::CustomResult, logger};
+
+/// The total amount of the costs of a merchant which share the same type and currency.
+#[cfg(feature = "olap")]
+#[derive(Clone, Debug, diesel::Queryable)]
+pub struct PaymentCostAggregate {
+    pub cost_type: storage_enums::PaymentCostType,
+    pub currency: String,
+    pub amount: Option<i64>,
+}
+
+#[cfg(feature = "olap")]
+#[async_trait::async_trait]
+pub trait PaymentCostDbExt: Sized {
+    /// Aggregates the costs of the merchant recorded in the time range `[start, end)`.
+    async fn aggregate_by_merchant_id_created_between(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        start: time::PrimitiveDateTime,
+        end: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<PaymentCostAggregate>, errors::DatabaseError>;
+}
+
+#[cfg(feature = "olap")]
+#[async_trait::async_trait]
+impl PaymentCostDbExt for PaymentCost {
+    #[instrument(skip(conn))]
+    async fn aggregate_by_merchant_id_created_between(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        start: time::PrimitiveDateTime,
+        end: time::PrimitiveDateTime,
+    ) -> CustomResult<Vec<PaymentCostAggregate>, errors::DatabaseError> {
+        let query = dsl::payment_cost
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(dsl::created_at.ge(start))
+            .filter(dsl::created_at.lt(end))
+            .group_by((dsl::cost_type, dsl::currency))
+            .select((
+                dsl::cost_type,
+                dsl::currency,
+                // `SUM` of a `BIGINT` column is a `NUMERIC`, which does not fit the amounts
+                sql::<Nullable<BigInt>>("CAST(SUM(amount) AS BIGINT)"),
+            ));
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+        query
+            .get_results_async(conn)
+            .await
+            .into_report()
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable_lazy(|| "Error aggregating payment costs")
+    }
+}
@@ -1,7 +1,8 @@
 use async_bb8_diesel::AsyncRunQueryDsl;
-use diesel::{associations::HasTable, ExpressionMethods, QueryDsl};
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, QueryDsl};
 use error_stack::{IntoReport, ResultExt};
 use router_env::{instrument, tracing};
+use storage_models::{enums as storage_enums, schema::payment_attempt::dsl as attempt_dsl};
 pub use storage_models::{
     errors,
     payment_intent::{
@@ -10,7 +11,11 @@ pub use storage_models::{
     schema::payment_intent::dsl,
 };
 
-use crate::{connection::PgPooledConn, core::errors::CustomResult, types::api};
+use crate::{
+    connection::PgPooledConn,
+    core::errors::CustomResult,
+    types::{api, transformers::ForeignInto},
+};
 
 #[cfg(feature = "kv_store")]
 impl crate::utils::storage_partitioning::KvStorePartition for PaymentIntent {}
@@ -36,13 +41,23 @@ impl PaymentIntentDbExt for PaymentIntent {
         let starting_after = &pc.starting_after;
         let ending_before = &pc.ending_before;
 
+        // The payment intents are listed from the newest to the oldest. The page before
+        // `ending_before` is fetched in the ascending order so that the payment intents closest
+        // to it are the ones within the limit, and is reversed afterwards.
+        let is_ascending = ending_before.is_some() && starting_after.is_none();
+
         //[#350]: Replace this with Boxable Expression and pass it into generic filter
         // when https://github.com/rust-lang/rust/issues/52662 becomes stable
         let mut filter = <Self as HasTable>::table()
             .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
-            .order(dsl::modified_at.desc())
             .into_boxed();
 
+        filter = if is_ascending {
+            filter.order((dsl::created_at.asc(), dsl::payment_id.asc()))
+        } else {
+            filter.order((dsl::created_at.desc(), dsl::payment_id.desc()))
+        };
+
         if let Some(customer_id) = customer_id {
             filter = filter.filter(dsl::customer_id.eq(customer_id.to_owned()));
         }
@@ -59,30 +74,79 @@ impl PaymentIntentDbExt for PaymentIntent {
             filter = filter.filter(dsl::created_at.le(created_lte));
         }
         if let Some(created_gte) = pc.created_gte {
-            filter = filter.filter(dsl::created_at.gt(created_gte));
+            filter = filter.filter(dsl::created_at.ge(created_gte));
+        }
+        if let Some(status) = pc.status {
+            let status: storage_enums::IntentStatus = status.foreign_into();
+            filter = filter.filter(dsl::status.eq(status));
+        }
+        if let Some(currency) = pc.currency {
+            let currency: storage_enums::Currency = currency.foreign_into();
+            filter = filter.filter(dsl::currency.eq(currency));
+        }
+        if let Some(amount_lte) = pc.amount_lte {
+            filter = filter.filter(dsl::amount.le(amount_lte));
+        }
+        if let Some(amount_gte) = pc.amount_gte {
+            filter = filter.filter(dsl::amount.ge(amount_gte));
+        }
+        if let Some(connector) = pc.connector {
+            filter = filter.filter(
+                dsl::payment_id.eq_any(
+                    attempt_dsl::payment_attempt
+                        .select(attempt_dsl::payment_id)
+                        .filter(attempt_dsl::merchant_id.eq(merchant_id.to_owned()))
+                        .filter(attempt_dsl::connector.eq(connector.to_string())),
+                ),
+            );
+        }
+        if let Some(test_mode) = pc.test_mode {
+            // Payments made before test mode was recorded have no flag on their attempts, and
+            // are treated as live payments
+            let test_payment_ids = attempt_dsl::payment_attempt
+                .select(attempt_dsl::payment_id)
+                .filter(attempt_dsl::merchant_id.eq(merchant_id.to_owned()))
+                .filter(attempt_dsl::test_mode.eq(true));
+            filter = if test_mode {
+                filter.filter(dsl::payment_id.eq_any(test_payment_ids))
+            } else {
+                filter.filter(dsl::payment_id.ne_all(test_payment_ids))
+            };
         }
         if let Some(starting_after) = starting_after {
-            let id = Self::find_by_payment_id_merchant_id(conn, starting_after, merchant_id)
-                .await?
-                .id;
-            filter = filter.filter(dsl::id.gt(id));
+            let cursor =
+                Self::find_by_payment_id_merchant_id(conn, starting_after, merchant_id).await?;
+            filter = filter.filter(
+                dsl::created_at.lt(cursor.created_at).or(dsl::created_at
+                    .eq(cursor.created_at)
+                    .and(dsl::payment_id.lt(cursor.payment_id))),
+            );
         }
         if let Some(ending_before) = ending_before {
-            let id = Self::find_by_payment_id_merchant_id(conn, ending_before, merchant_id)
-                .await?
-                .id;
-            filter = filter.filter(dsl::id.lt(id.to_owned()));
+            let cursor =
+                Self::find_by_payment_id_merchant_id(conn, ending_before, merchant_id).await?;
+            filter = filter.filter(
+                dsl::created_at.gt(cursor.created_at).or(dsl::created_at
+                    .eq(cursor.created_at)
+                    .and(dsl::payment_id.gt(cursor.payment_id))),
+            );
         }
 
         filter = filter.limit(pc.limit);
 
         crate::logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
 
-        filter
+        let mut payment_intents: Vec<Self> = filter
             .get_results_async(conn)
             .await
             .into_report()
             .change_context(errors::DatabaseError::NotFound)
-            .attach_printable_lazy(|| "Error filtering records by predicate")
+            .attach_printable_lazy(|| "Error filtering records by predicate")?;
+
+        if is_ascending {
+            payment_intents.reverse();
+        }
+
+        Ok(payment_intents)
     }
 }
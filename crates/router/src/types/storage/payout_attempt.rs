@@ -0,0 +1,3 @@
+pub use storage_models::payout_attempt::{
+    PayoutAttempt, PayoutAttemptNew, PayoutAttemptUpdate, PayoutAttemptUpdateInternal,
+};
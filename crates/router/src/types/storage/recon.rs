@@ -0,0 +1,60 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use common_utils::errors::CustomResult;
+use diesel::{associations::HasTable, ExpressionMethods, QueryDsl};
+use error_stack::{IntoReport, ResultExt};
+pub use storage_models::recon::{
+    ReconMismatch, ReconMismatchNew, ReconReport, ReconReportNew, ReconReportUpdate,
+    ReconReportUpdateInternal,
+};
+use storage_models::{errors, schema::recon_report::dsl};
+
+use crate::{connection::PgPooledConn, logger, types::transformers::ForeignInto};
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ReconTrackingData {
+    pub merchant_id: String,
+    pub report_id: String,
+}
+
+#[async_trait::async_trait]
+pub trait ReconReportDbExt: Sized {
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        constraints: &api_models::recon::ReconReportListConstraints,
+        limit: i64,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError>;
+}
+
+#[async_trait::async_trait]
+impl ReconReportDbExt for ReconReport {
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        constraints: &api_models::recon::ReconReportListConstraints,
+        limit: i64,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
+        let mut filter = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .order(dsl::created_at.desc())
+            .limit(limit)
+            .into_boxed();
+
+        if let Some(connector) = &constraints.connector {
+            filter = filter.filter(dsl::connector.eq(connector.to_owned()));
+        }
+        if let Some(status) = constraints.status {
+            let status: storage_models::enums::ReconReportStatus = status.foreign_into();
+            filter = filter.filter(dsl::status.eq(status));
+        }
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
+
+        filter
+            .get_results_async(conn)
+            .await
+            .into_report()
+            .change_context(errors::DatabaseError::NotFound)
+            .attach_printable_lazy(|| "Error filtering records by predicate")
+    }
+}
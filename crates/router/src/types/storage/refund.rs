@@ -5,13 +5,26 @@ use error_stack::{IntoReport, ResultExt};
 pub use storage_models::refund::{
     Refund, RefundCoreWorkflow, RefundNew, RefundUpdate, RefundUpdateInternal,
 };
-use storage_models::{errors, schema::refund::dsl};
+use storage_models::{enums, errors, schema::refund::dsl};
 
 use crate::{connection::PgPooledConn, logger};
 
 #[cfg(feature = "kv_store")]
 impl crate::utils::storage_partitioning::KvStorePartition for Refund {}
 
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RefundBatchWorkflow {
+    pub batch_id: String,
+    pub merchant_id: String,
+    pub rows: Vec<RefundBatchRow>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RefundBatchRow {
+    pub request: api_models::refunds::RefundRequest,
+    pub result: Option<api_models::refunds::RefundBatchRowResult>,
+}
+
 #[async_trait::async_trait]
 pub trait RefundDbExt: Sized {
     async fn filter_by_constraints(
@@ -35,14 +48,15 @@ impl RefundDbExt for Refund {
             .order(dsl::modified_at.desc())
             .into_boxed();
 
-        match &refund_list_details.payment_id {
-            Some(pid) => {
-                filter = filter.filter(dsl::payment_id.eq(pid.to_owned()));
-            }
-            None => {
-                filter = filter.limit(limit);
-            }
-        };
+        if let Some(pid) = &refund_list_details.payment_id {
+            filter = filter.filter(dsl::payment_id.eq(pid.to_owned()));
+        }
+        if let Some(status) = &refund_list_details.status {
+            filter = filter.filter(dsl::refund_status.eq_any(get_refund_statuses(status)));
+        }
+        if let Some(connector) = &refund_list_details.connector {
+            filter = filter.filter(dsl::connector.eq(connector.to_owned()));
+        }
 
         if let Some(created) = refund_list_details.created {
             filter = filter.filter(dsl::created_at.eq(created));
@@ -57,9 +71,13 @@ impl RefundDbExt for Refund {
             filter = filter.filter(dsl::created_at.le(created_lte));
         }
         if let Some(created_gte) = refund_list_details.created_gte {
-            filter = filter.filter(dsl::created_at.gt(created_gte));
+            filter = filter.filter(dsl::created_at.ge(created_gte));
         }
 
+        filter = filter
+            .limit(limit)
+            .offset(refund_list_details.offset.unwrap_or_default());
+
         logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
 
         filter
@@ -70,3 +88,61 @@ impl RefundDbExt for Refund {
             .attach_printable_lazy(|| "Error filtering records by predicate")
     }
 }
+
+/// Returns the refund statuses which are shown as the given status in the refund responses.
+pub fn get_refund_statuses(status: &api_models::refunds::RefundStatus) -> Vec<enums::RefundStatus> {
+    match status {
+        api_models::refunds::RefundStatus::Succeeded => vec![enums::RefundStatus::Success],
+        api_models::refunds::RefundStatus::Failed => vec![
+            enums::RefundStatus::Failure,
+            enums::RefundStatus::TransactionFailure,
+        ],
+        api_models::refunds::RefundStatus::Pending => vec![enums::RefundStatus::Pending],
+        api_models::refunds::RefundStatus::Review => vec![enums::RefundStatus::ManualReview],
+    }
+}
+
+/// Applies the constraints of a refund list request to refunds which were not filtered by a
+/// database query, such as the refunds read from Redis, ordered the same way as the query.
+pub fn filter_refunds_by_constraints(
+    mut refunds: Vec<Refund>,
+    refund_list_details: &api_models::refunds::RefundListRequest,
+    limit: i64,
+) -> Vec<Refund> {
+    let statuses = refund_list_details.status.as_ref().map(get_refund_statuses);
+    refunds.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
+
+    refunds
+        .into_iter()
+        .filter(|refund| {
+            refund_list_details
+                .payment_id
+                .as_ref()
+                .map_or(true, |payment_id| &refund.payment_id == payment_id)
+                && statuses
+                    .as_ref()
+                    .map_or(true, |statuses| statuses.contains(&refund.refund_status))
+                && refund_list_details
+                    .connector
+                    .as_ref()
+                    .map_or(true, |connector| &refund.connector == connector)
+                && refund_list_details
+                    .created
+                    .map_or(true, |created| refund.created_at == created)
+                && refund_list_details
+                    .created_lt
+                    .map_or(true, |created_lt| refund.created_at < created_lt)
+                && refund_list_details
+                    .created_gt
+                    .map_or(true, |created_gt| refund.created_at > created_gt)
+                && refund_list_details
+                    .created_lte
+                    .map_or(true, |created_lte| refund.created_at <= created_lte)
+                && refund_list_details
+                    .created_gte
+                    .map_or(true, |created_gte| refund.created_at >= created_gte)
+        })
+        .skip(usize::try_from(refund_list_details.offset.unwrap_or_default()).unwrap_or_default())
+        .take(usize::try_from(limit).unwrap_or_default())
+        .collect()
+}
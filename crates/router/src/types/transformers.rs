@@ -191,8 +191,11 @@ impl From<F<storage_enums::AttemptStatus>> for F<storage_enums::IntentStatus> {
                 storage_enums::IntentStatus::RequiresCustomerAction
             }
 
-            storage_enums::AttemptStatus::PartialCharged
-            | storage_enums::AttemptStatus::Started
+            storage_enums::AttemptStatus::PartialCharged => {
+                storage_enums::IntentStatus::PartiallyCaptured
+            }
+
+            storage_enums::AttemptStatus::Started
             | storage_enums::AttemptStatus::AuthenticationSuccessful
             | storage_enums::AttemptStatus::Authorizing
             | storage_enums::AttemptStatus::CodInitiated
@@ -212,12 +215,44 @@ impl From<F<storage_enums::AttemptStatus>> for F<storage_enums::IntentStatus> {
     }
 }
 
+impl From<F<storage_enums::AttemptStatus>> for F<storage_enums::CaptureStatus> {
+    fn from(s: F<storage_enums::AttemptStatus>) -> Self {
+        match s.0 {
+            storage_enums::AttemptStatus::Charged
+            | storage_enums::AttemptStatus::PartialCharged
+            | storage_enums::AttemptStatus::AutoRefunded => storage_enums::CaptureStatus::Charged,
+
+            storage_enums::AttemptStatus::AuthenticationFailed
+            | storage_enums::AttemptStatus::AuthorizationFailed
+            | storage_enums::AttemptStatus::VoidFailed
+            | storage_enums::AttemptStatus::RouterDeclined
+            | storage_enums::AttemptStatus::CaptureFailed
+            | storage_enums::AttemptStatus::Failure => storage_enums::CaptureStatus::Failed,
+
+            storage_enums::AttemptStatus::Started
+            | storage_enums::AttemptStatus::AuthenticationPending
+            | storage_enums::AttemptStatus::AuthenticationSuccessful
+            | storage_enums::AttemptStatus::Authorized
+            | storage_enums::AttemptStatus::Authorizing
+            | storage_enums::AttemptStatus::CodInitiated
+            | storage_enums::AttemptStatus::Voided
+            | storage_enums::AttemptStatus::VoidInitiated
+            | storage_enums::AttemptStatus::CaptureInitiated
+            | storage_enums::AttemptStatus::ConfirmationAwaited
+            | storage_enums::AttemptStatus::PaymentMethodAwaited
+            | storage_enums::AttemptStatus::Pending => storage_enums::CaptureStatus::Pending,
+        }
+        .into()
+    }
+}
+
 impl TryFrom<F<api_enums::IntentStatus>> for F<storage_enums::EventType> {
     type Error = errors::ValidationError;
 
     fn try_from(value: F<api_enums::IntentStatus>) -> Result<Self, Self::Error> {
         match value.0 {
             api_enums::IntentStatus::Succeeded => Ok(storage_enums::EventType::PaymentSucceeded),
+            api_enums::IntentStatus::Failed => Ok(storage_enums::EventType::PaymentFailed),
             _ => Err(errors::ValidationError::IncorrectValueProvided {
                 field_name: "intent_status",
             }),
@@ -232,6 +267,24 @@ impl From<F<storage_enums::EventType>> for F<api_enums::EventType> {
     }
 }
 
+impl From<F<api_enums::EventType>> for F<storage_enums::EventType> {
+    fn from(event_type: F<api_enums::EventType>) -> Self {
+        Self(frunk::labelled_convert_from(event_type.0))
+    }
+}
+
+impl From<F<storage_enums::EventClass>> for F<api_enums::EventClass> {
+    fn from(event_class: F<storage_enums::EventClass>) -> Self {
+        Self(frunk::labelled_convert_from(event_class.0))
+    }
+}
+
+impl From<F<storage_enums::EventObjectType>> for F<api_enums::EventObjectType> {
+    fn from(event_object_type: F<storage_enums::EventObjectType>) -> Self {
+        Self(frunk::labelled_convert_from(event_object_type.0))
+    }
+}
+
 impl From<F<api_enums::FutureUsage>> for F<storage_enums::FutureUsage> {
     fn from(future_usage: F<api_enums::FutureUsage>) -> Self {
         Self(frunk::labelled_convert_from(future_usage.0))
@@ -250,6 +303,273 @@ impl From<F<storage_enums::RefundStatus>> for F<api_enums::RefundStatus> {
     }
 }
 
+impl From<F<api_enums::DisputeStatus>> for F<storage_enums::DisputeStatus> {
+    fn from(status: F<api_enums::DisputeStatus>) -> Self {
+        Self(frunk::labelled_convert_from(status.0))
+    }
+}
+
+impl From<F<storage_enums::DisputeStatus>> for F<api_enums::DisputeStatus> {
+    fn from(status: F<storage_enums::DisputeStatus>) -> Self {
+        Self(frunk::labelled_convert_from(status.0))
+    }
+}
+
+impl From<F<storage_enums::DisputeChallengeStatus>> for F<api_enums::DisputeChallengeStatus> {
+    fn from(status: F<storage_enums::DisputeChallengeStatus>) -> Self {
+        Self(frunk::labelled_convert_from(status.0))
+    }
+}
+
+impl From<F<storage_enums::DisputeStatus>> for F<storage_enums::EventType> {
+    fn from(status: F<storage_enums::DisputeStatus>) -> Self {
+        match status.0 {
+            storage_enums::DisputeStatus::DisputeOpened => storage_enums::EventType::DisputeOpened,
+            storage_enums::DisputeStatus::DisputeChallenged => {
+                storage_enums::EventType::DisputeChallenged
+            }
+            storage_enums::DisputeStatus::DisputeWon => storage_enums::EventType::DisputeWon,
+            storage_enums::DisputeStatus::DisputeLost => storage_enums::EventType::DisputeLost,
+        }
+        .into()
+    }
+}
+
+impl From<F<storage::Dispute>> for F<api_models::disputes::DisputeResponse> {
+    fn from(dispute: F<storage::Dispute>) -> Self {
+        let dispute = dispute.0;
+        api_models::disputes::DisputeResponse {
+            dispute_id: dispute.dispute_id,
+            payment_id: dispute.payment_id,
+            attempt_id: dispute.attempt_id,
+            amount: dispute.amount,
+            currency: dispute.currency,
+            dispute_status: dispute.dispute_status.foreign_into(),
+            connector: dispute.connector,
+            connector_status: dispute.connector_status,
+            connector_dispute_id: dispute.connector_dispute_id,
+            connector_reason: dispute.connector_reason,
+            connector_reason_code: dispute.connector_reason_code,
+            challenge_required_by: dispute.challenge_required_by,
+            created_at: dispute.created_at,
+            modified_at: dispute.modified_at,
+            challenge_status: dispute.challenge_status.map(ForeignInto::foreign_into),
+        }
+        .into()
+    }
+}
+
+impl From<F<storage_enums::CaptureStatus>> for F<api_enums::CaptureStatus> {
+    fn from(status: F<storage_enums::CaptureStatus>) -> Self {
+        Self(frunk::labelled_convert_from(status.0))
+    }
+}
+
+impl From<F<storage_enums::PaymentLinkStatus>> for F<api_enums::PaymentLinkStatus> {
+    fn from(status: F<storage_enums::PaymentLinkStatus>) -> Self {
+        Self(frunk::labelled_convert_from(status.0))
+    }
+}
+
+impl From<F<api_enums::ExportObject>> for F<storage_enums::ExportObject> {
+    fn from(object: F<api_enums::ExportObject>) -> Self {
+        Self(frunk::labelled_convert_from(object.0))
+    }
+}
+
+impl From<F<storage_enums::ExportObject>> for F<api_enums::ExportObject> {
+    fn from(object: F<storage_enums::ExportObject>) -> Self {
+        Self(frunk::labelled_convert_from(object.0))
+    }
+}
+
+impl From<F<api_enums::ExportFormat>> for F<storage_enums::ExportFormat> {
+    fn from(format: F<api_enums::ExportFormat>) -> Self {
+        Self(frunk::labelled_convert_from(format.0))
+    }
+}
+
+impl From<F<storage_enums::ExportFormat>> for F<api_enums::ExportFormat> {
+    fn from(format: F<storage_enums::ExportFormat>) -> Self {
+        Self(frunk::labelled_convert_from(format.0))
+    }
+}
+
+impl From<F<storage_enums::ExportStatus>> for F<api_enums::ExportStatus> {
+    fn from(status: F<storage_enums::ExportStatus>) -> Self {
+        Self(frunk::labelled_convert_from(status.0))
+    }
+}
+
+impl From<F<api_enums::BlocklistKind>> for F<storage_enums::BlocklistKind> {
+    fn from(kind: F<api_enums::BlocklistKind>) -> Self {
+        Self(frunk::labelled_convert_from(kind.0))
+    }
+}
+
+impl From<F<storage_enums::BlocklistKind>> for F<api_enums::BlocklistKind> {
+    fn from(kind: F<storage_enums::BlocklistKind>) -> Self {
+        Self(frunk::labelled_convert_from(kind.0))
+    }
+}
+
+impl From<F<api_enums::ReconReportStatus>> for F<storage_enums::ReconReportStatus> {
+    fn from(status: F<api_enums::ReconReportStatus>) -> Self {
+        Self(frunk::labelled_convert_from(status.0))
+    }
+}
+
+impl From<F<storage_enums::ReconReportStatus>> for F<api_enums::ReconReportStatus> {
+    fn from(status: F<storage_enums::ReconReportStatus>) -> Self {
+        Self(frunk::labelled_convert_from(status.0))
+    }
+}
+
+impl From<F<storage_enums::ReconEntryType>> for F<api_enums::ReconEntryType> {
+    fn from(entry_type: F<storage_enums::ReconEntryType>) -> Self {
+        Self(frunk::labelled_convert_from(entry_type.0))
+    }
+}
+
+impl From<F<storage_enums::ReconMismatchReason>> for F<api_enums::ReconMismatchReason> {
+    fn from(reason: F<storage_enums::ReconMismatchReason>) -> Self {
+        Self(frunk::labelled_convert_from(reason.0))
+    }
+}
+
+impl From<F<storage_enums::AuditActor>> for F<api_enums::AuditActor> {
+    fn from(actor: F<storage_enums::AuditActor>) -> Self {
+        Self(frunk::labelled_convert_from(actor.0))
+    }
+}
+
+impl From<F<storage::Blocklist>> for F<api_models::blocklist::BlocklistResponse> {
+    fn from(blocklist_entry: F<storage::Blocklist>) -> Self {
+        let blocklist_entry = blocklist_entry.0;
+        api_models::blocklist::BlocklistResponse {
+            entry_id: blocklist_entry.entry_id,
+            kind: blocklist_entry.kind.foreign_into(),
+            data: blocklist_entry.data,
+            expires_at: blocklist_entry.expires_at,
+            created_at: blocklist_entry.created_at,
+        }
+        .into()
+    }
+}
+
+impl From<F<storage::ReconMismatch>> for F<api_models::recon::ReconMismatchResponse> {
+    fn from(recon_mismatch: F<storage::ReconMismatch>) -> Self {
+        let recon_mismatch = recon_mismatch.0;
+        api_models::recon::ReconMismatchResponse {
+            entry_type: recon_mismatch.entry_type.foreign_into(),
+            connector_transaction_id: recon_mismatch.connector_transaction_id,
+            amount: recon_mismatch.amount,
+            currency: recon_mismatch.currency.foreign_into(),
+            reason: recon_mismatch.reason.foreign_into(),
+            expected_amount: recon_mismatch.expected_amount,
+            expected_currency: recon_mismatch
+                .expected_currency
+                .map(ForeignInto::foreign_into),
+            payment_id: recon_mismatch.payment_id,
+            refund_id: recon_mismatch.refund_id,
+        }
+        .into()
+    }
+}
+
+impl From<F<storage::Capture>> for F<api_models::payments::CaptureResponse> {
+    fn from(capture: F<storage::Capture>) -> Self {
+        let capture = capture.0;
+        api_models::payments::CaptureResponse {
+            capture_id: capture.capture_id,
+            status: capture.status.foreign_into(),
+            amount: capture.amount,
+            currency: capture.currency.foreign_into(),
+            connector: capture.connector,
+            connector_capture_id: capture.connector_capture_id,
+            error_message: capture.error_message,
+            error_code: capture.error_code,
+            created_at: capture.created_at,
+        }
+        .into()
+    }
+}
+
+impl From<F<api_enums::PayoutType>> for F<storage_enums::PayoutType> {
+    fn from(payout_type: F<api_enums::PayoutType>) -> Self {
+        Self(frunk::labelled_convert_from(payout_type.0))
+    }
+}
+
+impl From<F<storage_enums::PayoutType>> for F<api_enums::PayoutType> {
+    fn from(payout_type: F<storage_enums::PayoutType>) -> Self {
+        Self(frunk::labelled_convert_from(payout_type.0))
+    }
+}
+
+impl From<F<storage_enums::PayoutStatus>> for F<api_enums::PayoutStatus> {
+    fn from(status: F<storage_enums::PayoutStatus>) -> Self {
+        Self(frunk::labelled_convert_from(status.0))
+    }
+}
+
+impl From<F<(storage::Payout, storage::PayoutAttempt)>> for F<api_models::payouts::PayoutResponse> {
+    fn from(item: F<(storage::Payout, storage::PayoutAttempt)>) -> Self {
+        let (payout, payout_attempt) = item.0;
+        api_models::payouts::PayoutResponse {
+            payout_id: payout.payout_id,
+            merchant_id: payout.merchant_id,
+            customer_id: payout.customer_id,
+            amount: payout.amount,
+            currency: payout.currency.to_string(),
+            payout_type: payout.payout_type.foreign_into(),
+            status: payout.status.foreign_into(),
+            connector: payout.connector,
+            connector_payout_id: payout_attempt.connector_payout_id,
+            error_code: payout_attempt.error_code,
+            error_message: payout_attempt.error_message,
+            description: payout.description,
+            metadata: payout.metadata,
+            created_at: payout.created_at,
+        }
+        .into()
+    }
+}
+
+impl From<F<storage::Event>> for F<api_models::events::EventResponse> {
+    fn from(event: F<storage::Event>) -> Self {
+        let event = event.0;
+        api_models::events::EventResponse {
+            event_id: event.event_id,
+            event_type: event.event_type.foreign_into(),
+            event_class: event.event_class.foreign_into(),
+            is_webhook_notified: event.is_webhook_notified,
+            intent_reference_id: event.intent_reference_id,
+            primary_object_id: event.primary_object_id,
+            primary_object_type: event.primary_object_type.foreign_into(),
+            content: event.content,
+            created_at: event.created_at,
+        }
+        .into()
+    }
+}
+
+impl From<F<storage::WebhookDeliveryAttempt>>
+    for F<api_models::events::WebhookDeliveryAttemptResponse>
+{
+    fn from(attempt: F<storage::WebhookDeliveryAttempt>) -> Self {
+        let attempt = attempt.0;
+        api_models::events::WebhookDeliveryAttemptResponse {
+            attempt_number: attempt.attempt_number,
+            response_code: attempt.response_code,
+            latency_ms: attempt.latency_ms,
+            error_message: attempt.error_message,
+            created_at: attempt.created_at,
+        }
+        .into()
+    }
+}
+
 impl From<F<api_enums::CaptureMethod>> for F<storage_enums::CaptureMethod> {
     fn from(capture_method: F<api_enums::CaptureMethod>) -> Self {
         Self(frunk::labelled_convert_from(capture_method.0))
@@ -354,15 +674,17 @@ impl TryFrom<F<storage::MerchantConnectorAccount>>
             disabled: merchant_ca.disabled,
             metadata: merchant_ca.metadata,
             payment_methods_enabled,
+            business_country: merchant_ca.business_country,
+            business_label: merchant_ca.business_label,
         }
         .into())
     }
 }
 
-impl From<F<api_models::payments::AddressDetails>> for F<storage_models::address::AddressNew> {
+impl From<F<api_models::payments::AddressDetails>> for F<storage::AddressNew> {
     fn from(item: F<api_models::payments::AddressDetails>) -> Self {
         let address = item.0;
-        storage_models::address::AddressNew {
+        storage::AddressNew {
             city: address.city,
             country: address.country,
             line1: address.line1,
@@ -0,0 +1,138 @@
+//! Anonymization of the payment data which merchants share with analysts and vendors.
+
+use ring::hmac;
+
+use crate::types::storage;
+
+/// Replaces identifiers and card data with pseudonyms computed with a keyed hash. A value is
+/// always replaced with the same pseudonym for a merchant, so that anonymized records of the same
+/// customer or card can still be correlated, while the value cannot be recovered from the
+/// pseudonym, nor guessed by hashing candidate values, without the key.
+#[derive(Clone)]
+pub struct Anonymizer {
+    key: hmac::Key,
+}
+
+impl Anonymizer {
+    pub fn new(key: &str) -> Self {
+        Self {
+            key: hmac::Key::new(hmac::HMAC_SHA256, key.as_bytes()),
+        }
+    }
+
+    /// Computes the pseudonym of a value of the merchant. The kind of the value is hashed along
+    /// with it, so that equal values of different kinds do not share their pseudonym.
+    pub fn pseudonymize(&self, merchant_id: &str, kind: &str, value: &str) -> String {
+        let message = [merchant_id, kind, value].join("\0");
+        hex::encode(hmac::sign(&self.key, message.as_bytes()))
+    }
+
+    fn pseudonymize_option(
+        &self,
+        merchant_id: &str,
+        kind: &str,
+        value: Option<String>,
+    ) -> Option<String> {
+        value.map(|value| self.pseudonymize(merchant_id, kind, &value))
+    }
+}
+
+/// A record whose personal data can be removed before it is shared.
+pub trait Anonymize {
+    /// Pseudonymizes the identifiers of the customer and the card data in the record, and drops
+    /// the fields which may hold personal data of the customer in free form.
+    fn anonymize(self, anonymizer: &Anonymizer) -> Self;
+}
+
+impl Anonymize for storage::PaymentIntent {
+    fn anonymize(self, anonymizer: &Anonymizer) -> Self {
+        let merchant_id = self.merchant_id.as_str();
+        Self {
+            customer_id: anonymizer.pseudonymize_option(
+                merchant_id,
+                "customer_id",
+                self.customer_id,
+            ),
+            shipping_address_id: anonymizer.pseudonymize_option(
+                merchant_id,
+                "address_id",
+                self.shipping_address_id,
+            ),
+            billing_address_id: anonymizer.pseudonymize_option(
+                merchant_id,
+                "address_id",
+                self.billing_address_id,
+            ),
+            description: None,
+            return_url: None,
+            metadata: None,
+            client_secret: None,
+            ..self
+        }
+    }
+}
+
+impl Anonymize for storage::PaymentAttempt {
+    fn anonymize(self, anonymizer: &Anonymizer) -> Self {
+        let merchant_id = self.merchant_id.as_str();
+        Self {
+            payment_method_id: anonymizer.pseudonymize_option(
+                merchant_id,
+                "payment_method_id",
+                self.payment_method_id,
+            ),
+            card_bin: anonymizer.pseudonymize_option(merchant_id, "card_bin", self.card_bin),
+            card_last4: anonymizer.pseudonymize_option(merchant_id, "card_last4", self.card_last4),
+            // Holds the IP address and the user agent of the customer
+            browser_info: None,
+            payment_token: None,
+            connector_metadata: None,
+            three_ds_authentication_data: None,
+            // The sensitive fields are masked, but the name, email and address of the customer
+            // are kept
+            connector_api_log: None,
+            ..self
+        }
+    }
+}
+
+impl Anonymize for storage::Refund {
+    fn anonymize(self, _anonymizer: &Anonymizer) -> Self {
+        Self {
+            description: None,
+            refund_reason: None,
+            metadata: None,
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pseudonymize() {
+        let anonymizer = Anonymizer::new("anonymization_secret");
+        let pseudonym = anonymizer.pseudonymize("merchant_1", "customer_id", "cus_1");
+
+        assert_eq!(pseudonym.len(), 64);
+        assert_ne!(pseudonym, "cus_1");
+        assert_eq!(
+            pseudonym,
+            anonymizer.pseudonymize("merchant_1", "customer_id", "cus_1")
+        );
+        assert_ne!(
+            pseudonym,
+            anonymizer.pseudonymize("merchant_2", "customer_id", "cus_1")
+        );
+        assert_ne!(
+            pseudonym,
+            anonymizer.pseudonymize("merchant_1", "payment_method_id", "cus_1")
+        );
+        assert_ne!(
+            pseudonym,
+            Anonymizer::new("other_secret").pseudonymize("merchant_1", "customer_id", "cus_1")
+        );
+    }
+}
@@ -25,3 +25,19 @@ impl<'a> std::fmt::Display for PartitionKey<'a> {
         }
     }
 }
+
+/// The Redis key of the hash holding a payment intent, along with its attempts and refunds, in the
+/// KV store.
+///
+/// With `hash_tagged` set, the key is enclosed in braces, which makes it a Redis Cluster hash tag:
+/// every key containing it maps to the same slot as the hash, so that the keys related to a payment
+/// are stored on the same node and can be used together in multi-key commands and transactions.
+/// The hashes written with one format are not found with the other, see the `hash_tagged_keys`
+/// drainer setting for switching between them.
+pub(crate) fn payment_hash_key(merchant_id: &str, payment_id: &str, hash_tagged: bool) -> String {
+    if hash_tagged {
+        format!("{{{merchant_id}_{payment_id}}}")
+    } else {
+        format!("{merchant_id}_{payment_id}")
+    }
+}
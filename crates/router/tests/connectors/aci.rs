@@ -49,6 +49,8 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
             capture_method: None,
             browser_info: None,
             order_details: None,
+            authentication_data: None,
+            frm_data: None,
             email: None,
         },
         response: Err(types::ErrorResponse::default()),
@@ -56,6 +58,11 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
         address: PaymentAddress::default(),
         connector_meta_data: None,
         amount_captured: None,
+        access_token: None,
+        session_token: None,
+        payment_checks: None,
+        connector_api_log: None,
+        test_mode: None,
     }
 }
 
@@ -92,6 +99,11 @@ fn construct_refund_router_data<F>() -> types::RefundsRouterData<F> {
         address: PaymentAddress::default(),
         connector_meta_data: None,
         amount_captured: None,
+        access_token: None,
+        session_token: None,
+        payment_checks: None,
+        connector_api_log: None,
+        test_mode: None,
     }
 }
 
@@ -99,7 +111,9 @@ fn construct_refund_router_data<F>() -> types::RefundsRouterData<F> {
 
 async fn payments_create_success() {
     let conf = Settings::new().unwrap();
-    let state = routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest).await;
+    let state = routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest)
+        .await
+        .unwrap();
 
     static CV: aci::Aci = aci::Aci;
     let connector = types::api::ConnectorData {
@@ -134,7 +148,9 @@ async fn payments_create_failure() {
     {
         let conf = Settings::new().unwrap();
         static CV: aci::Aci = aci::Aci;
-        let state = routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest).await;
+        let state = routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest)
+            .await
+            .unwrap();
         let connector = types::api::ConnectorData {
             connector: Box::new(&CV),
             connector_name: types::Connector::Aci,
@@ -178,7 +194,9 @@ async fn refund_for_successful_payments() {
         connector_name: types::Connector::Aci,
         get_token: types::api::GetToken::Connector,
     };
-    let state = routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest).await;
+    let state = routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest)
+        .await
+        .unwrap();
     let connector_integration: services::BoxedConnectorIntegration<
         '_,
         types::api::Authorize,
@@ -236,7 +254,9 @@ async fn refunds_create_failure() {
         connector_name: types::Connector::Aci,
         get_token: types::api::GetToken::Connector,
     };
-    let state = routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest).await;
+    let state = routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest)
+        .await
+        .unwrap();
     let connector_integration: services::BoxedConnectorIntegration<
         '_,
         types::api::Execute,
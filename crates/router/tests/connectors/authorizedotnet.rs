@@ -49,6 +49,8 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
             capture_method: None,
             browser_info: None,
             order_details: None,
+            authentication_data: None,
+            frm_data: None,
             email: None,
         },
         payment_method_id: None,
@@ -56,6 +58,11 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
         address: PaymentAddress::default(),
         connector_meta_data: None,
         amount_captured: None,
+        access_token: None,
+        session_token: None,
+        payment_checks: None,
+        connector_api_log: None,
+        test_mode: None,
     }
 }
 
@@ -91,6 +98,11 @@ fn construct_refund_router_data<F>() -> types::RefundsRouterData<F> {
         payment_method_id: None,
         address: PaymentAddress::default(),
         amount_captured: None,
+        access_token: None,
+        session_token: None,
+        payment_checks: None,
+        connector_api_log: None,
+        test_mode: None,
     }
 }
 
@@ -98,7 +110,9 @@ fn construct_refund_router_data<F>() -> types::RefundsRouterData<F> {
 #[ignore]
 async fn payments_create_success() {
     let conf = Settings::new().unwrap();
-    let state = routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest).await;
+    let state = routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest)
+        .await
+        .unwrap();
     static CV: Authorizedotnet = Authorizedotnet;
     let connector = types::api::ConnectorData {
         connector: Box::new(&CV),
@@ -141,7 +155,9 @@ async fn payments_create_failure() {
             connector_name: types::Connector::Authorizedotnet,
             get_token: types::api::GetToken::Connector,
         };
-        let state = routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest).await;
+        let state = routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest)
+            .await
+            .unwrap();
         let connector_integration: services::BoxedConnectorIntegration<
             '_,
             types::api::Authorize,
@@ -186,7 +202,9 @@ async fn refunds_create_success() {
         connector_name: types::Connector::Authorizedotnet,
         get_token: types::api::GetToken::Connector,
     };
-    let state = routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest).await;
+    let state = routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest)
+        .await
+        .unwrap();
     let connector_integration: services::BoxedConnectorIntegration<
         '_,
         types::api::Execute,
@@ -223,7 +241,9 @@ async fn refunds_create_failure() {
         connector_name: types::Connector::Authorizedotnet,
         get_token: types::api::GetToken::Connector,
     };
-    let state = routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest).await;
+    let state = routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest)
+        .await
+        .unwrap();
     let connector_integration: services::BoxedConnectorIntegration<
         '_,
         types::api::Execute,
@@ -46,6 +46,8 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
             capture_method: None,
             browser_info: None,
             order_details: None,
+            authentication_data: None,
+            frm_data: None,
             email: None,
         },
         response: Err(types::ErrorResponse::default()),
@@ -53,6 +55,11 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
         address: PaymentAddress::default(),
         connector_meta_data: None,
         amount_captured: None,
+        access_token: None,
+        session_token: None,
+        payment_checks: None,
+        connector_api_log: None,
+        test_mode: None,
     }
 }
 
@@ -88,6 +95,11 @@ fn construct_refund_router_data<F>() -> types::RefundsRouterData<F> {
         payment_method_id: None,
         address: PaymentAddress::default(),
         amount_captured: None,
+        access_token: None,
+        session_token: None,
+        payment_checks: None,
+        connector_api_log: None,
+        test_mode: None,
     }
 }
 
@@ -103,7 +115,9 @@ async fn test_checkout_payment_success() {
         connector_name: types::Connector::Checkout,
         get_token: types::api::GetToken::Connector,
     };
-    let state = routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest).await;
+    let state = routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest)
+        .await
+        .unwrap();
     let connector_integration: services::BoxedConnectorIntegration<
         '_,
         types::api::Authorize,
@@ -136,7 +150,9 @@ async fn test_checkout_refund_success() {
     use router::{configs::settings::Settings, connector::Checkout, services};
 
     let conf = Settings::new().expect("invalid settings");
-    let state = routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest).await;
+    let state = routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest)
+        .await
+        .unwrap();
     static CV: Checkout = Checkout;
     let connector = types::api::ConnectorData {
         connector: Box::new(&CV),
@@ -204,7 +220,9 @@ async fn test_checkout_payment_failure() {
     use router::{configs::settings::Settings, connector::Checkout, services};
 
     let conf = Settings::new().expect("invalid settings");
-    let state = routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest).await;
+    let state = routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest)
+        .await
+        .unwrap();
     static CV: Checkout = Checkout;
     let connector = types::api::ConnectorData {
         connector: Box::new(&CV),
@@ -237,7 +255,9 @@ async fn test_checkout_refund_failure() {
     use router::{configs::settings::Settings, connector::Checkout, services};
 
     let conf = Settings::new().expect("invalid settings");
-    let state = routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest).await;
+    let state = routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest)
+        .await
+        .unwrap();
     static CV: Checkout = Checkout;
     let connector = types::api::ConnectorData {
         connector: Box::new(&CV),
@@ -0,0 +1,148 @@
+use futures::future::OptionFuture;
+use masking::Secret;
+use router::types::{self, api, storage::enums};
+
+use crate::{
+    connector_auth,
+    utils::{self, ConnectorActions},
+};
+
+struct DummyConnector;
+impl ConnectorActions for DummyConnector {}
+impl utils::Connector for DummyConnector {
+    fn get_data(&self) -> types::api::ConnectorData {
+        use router::connector::DummyConnector;
+        types::api::ConnectorData {
+            connector: Box::new(&DummyConnector),
+            connector_name: types::Connector::DummyConnector,
+            get_token: types::api::GetToken::Connector,
+        }
+    }
+
+    fn get_auth_token(&self) -> types::ConnectorAuthType {
+        types::ConnectorAuthType::from(
+            connector_auth::ConnectorAuthentication::new()
+                .dummy_connector
+                .expect("Missing connector authentication configuration"),
+        )
+    }
+
+    fn get_name(&self) -> String {
+        "dummy_connector".to_string()
+    }
+}
+
+fn get_payment_data(card_number: &str, amount: i64) -> types::PaymentsAuthorizeData {
+    types::PaymentsAuthorizeData {
+        payment_method_data: types::api::PaymentMethod::Card(api::CCard {
+            card_number: Secret::new(card_number.to_string()),
+            ..utils::CCardType::default().0
+        }),
+        amount,
+        ..utils::PaymentAuthorizeType::default().0
+    }
+}
+
+#[actix_web::test]
+async fn should_only_authorize_payment() {
+    let response = DummyConnector {}
+        .authorize_payment(
+            Some(types::PaymentsAuthorizeData {
+                capture_method: Some(enums::CaptureMethod::Manual),
+                ..get_payment_data("4242424242424242", 100)
+            }),
+            None,
+        )
+        .await;
+    assert_eq!(response.status, enums::AttemptStatus::Authorized);
+}
+
+#[actix_web::test]
+async fn should_authorize_and_capture_payment() {
+    let response = DummyConnector {}
+        .make_payment(Some(get_payment_data("4242424242424242", 100)), None)
+        .await;
+    assert_eq!(response.status, enums::AttemptStatus::Charged);
+}
+
+#[actix_web::test]
+async fn should_capture_already_authorized_payment() {
+    let connector = DummyConnector {};
+    let authorize_response = connector
+        .authorize_payment(
+            Some(types::PaymentsAuthorizeData {
+                capture_method: Some(enums::CaptureMethod::Manual),
+                ..get_payment_data("4242424242424242", 100)
+            }),
+            None,
+        )
+        .await;
+    assert_eq!(authorize_response.status, enums::AttemptStatus::Authorized);
+    let txn_id = utils::get_connector_transaction_id(authorize_response);
+    let response: OptionFuture<_> = txn_id
+        .map(|transaction_id| async move {
+            connector
+                .capture_payment(transaction_id, None, None)
+                .await
+                .status
+        })
+        .into();
+    assert_eq!(response.await, Some(enums::AttemptStatus::Charged));
+}
+
+#[actix_web::test]
+async fn should_void_authorized_payment() {
+    let connector = DummyConnector {};
+    let authorize_response = connector
+        .authorize_payment(
+            Some(types::PaymentsAuthorizeData {
+                capture_method: Some(enums::CaptureMethod::Manual),
+                ..get_payment_data("4242424242424242", 100)
+            }),
+            None,
+        )
+        .await;
+    let txn_id = utils::get_connector_transaction_id(authorize_response);
+    let response: OptionFuture<_> = txn_id
+        .map(|transaction_id| async move {
+            connector
+                .void_payment(transaction_id, None, None)
+                .await
+                .status
+        })
+        .into();
+    assert_eq!(response.await, Some(enums::AttemptStatus::Voided));
+}
+
+#[actix_web::test]
+async fn should_fail_payment_for_declined_card() {
+    let response = DummyConnector {}
+        .make_payment(Some(get_payment_data("4000000000000002", 100)), None)
+        .await;
+    assert_eq!(response.status, enums::AttemptStatus::Failure);
+    assert_eq!(response.response.unwrap_err().code, "card_declined");
+}
+
+#[actix_web::test]
+async fn should_delay_capture_for_magic_amount() {
+    let response = DummyConnector {}
+        .make_payment(Some(get_payment_data("4242424242424242", 177)), None)
+        .await;
+    assert_eq!(response.status, enums::AttemptStatus::CaptureInitiated);
+}
+
+#[actix_web::test]
+async fn should_refund_succeeded_payment() {
+    let connector = DummyConnector {};
+    let response = connector
+        .make_payment(Some(get_payment_data("4242424242424242", 100)), None)
+        .await;
+
+    if let Some(transaction_id) = utils::get_connector_transaction_id(response) {
+        let response = connector.refund_payment(transaction_id, None, None).await;
+        assert_eq!(
+            response.response.unwrap().refund_status,
+            enums::RefundStatus::Success,
+        );
+    }
+}
@@ -5,6 +5,8 @@ mod authorizedotnet;
 mod checkout;
 mod connector_auth;
 mod cybersource;
+#[cfg(feature = "dummy_connector")]
+mod dummy_connector;
 mod fiserv;
 mod globalpay;
 mod payu;
@@ -81,6 +81,7 @@ pub trait ConnectorActions: Connector {
                 currency: enums::Currency::USD,
                 connector_transaction_id: transaction_id,
                 amount: 100,
+                connector_meta: None,
             }),
             payment_info,
         );
@@ -177,6 +178,11 @@ pub trait ConnectorActions: Connector {
             address: info.map_or(PaymentAddress::default(), |a| a.address.unwrap()),
             connector_meta_data: self.get_connector_meta(),
             amount_captured: None,
+            access_token: None,
+            session_token: None,
+            payment_checks: None,
+            connector_api_log: None,
+            test_mode: None,
         }
     }
 }
@@ -191,7 +197,9 @@ async fn call_connector<
 ) -> types::RouterData<T, Req, Resp> {
     use router::configs::settings::Settings;
     let conf = Settings::new().unwrap();
-    let state = routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest).await;
+    let state = routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest)
+        .await
+        .unwrap();
     services::api::execute_connector_processing_step(
         &state,
         integration,
@@ -259,6 +267,8 @@ impl Default for PaymentAuthorizeType {
             setup_mandate_details: None,
             browser_info: Some(BrowserInfoType::default().0),
             order_details: None,
+            authentication_data: None,
+            frm_data: None,
             email: None,
         };
         Self(data)
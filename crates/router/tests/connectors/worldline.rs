@@ -78,6 +78,8 @@ impl WorldlineTest {
             capture_method: Some(capture_method),
             browser_info: None,
             order_details: None,
+            authentication_data: None,
+            frm_data: None,
             email: None,
         })
     }
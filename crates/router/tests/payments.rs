@@ -2,6 +2,7 @@
 
 mod utils;
 
+use common_utils::types::MinorUnit;
 use router::{
     configs,
     core::payments,
@@ -274,7 +275,9 @@ async fn payments_create_core() {
     use configs::settings::Settings;
     let conf = Settings::new().expect("invalid settings");
 
-    let state = routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest).await;
+    let state = routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest)
+        .await
+        .unwrap();
 
     let merchant_account = services::authenticate_by_api_key(&*state.store, "MySecretApiKey")
         .await
@@ -332,7 +335,7 @@ async fn payments_create_core() {
     let expected_response = api::PaymentsResponse {
         payment_id: Some("pay_mbabizu24mvu3mela5njyhpit10".to_string()),
         status: api_enums::IntentStatus::Succeeded,
-        amount: 6540,
+        amount: MinorUnit::new(6540),
         amount_capturable: None,
         amount_received: None,
         client_secret: None,
@@ -427,7 +430,9 @@ async fn payments_create_core_adyen_no_redirect() {
     use crate::configs::settings::Settings;
     let conf = Settings::new().expect("invalid settings");
 
-    let state = routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest).await;
+    let state = routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest)
+        .await
+        .unwrap();
 
     let customer_id = format!("cust_{}", Uuid::new_v4());
     let merchant_id = "arunraj".to_string();
@@ -487,7 +492,7 @@ async fn payments_create_core_adyen_no_redirect() {
     let expected_response = services::ApplicationResponse::Json(api::PaymentsResponse {
         payment_id: Some(payment_id.clone()),
         status: api_enums::IntentStatus::Processing,
-        amount: 6540,
+        amount: MinorUnit::new(6540),
         amount_capturable: None,
         amount_received: None,
         client_secret: None,
@@ -0,0 +1,112 @@
+#![allow(clippy::unwrap_used)]
+
+mod utils;
+
+use std::{io::Read, net::TcpListener};
+
+use router::configs::settings::Settings;
+use serde_json::json;
+use utils::{mk_service_with_conf, ApiKey, AppClient, MerchantId};
+use wiremock::{matchers::any, Mock, MockServer, ResponseTemplate};
+
+/// Connector server which responds to every request with the given status code
+async fn mock_connector(status_code: u16) -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(any())
+        .respond_with(ResponseTemplate::new(status_code))
+        .mount(&server)
+        .await;
+    server
+}
+
+async fn received_requests(server: &MockServer) -> usize {
+    server.received_requests().await.unwrap().len()
+}
+
+/// Creates a payment for a merchant which routes payments to stripe first and to shift4 next,
+/// with the connectors reachable at the URLs given in the settings.
+async fn create_payment_with_failover(conf: Settings) -> serde_json::Value {
+    let server = mk_service_with_conf(conf).await;
+    let client = AppClient::guest();
+    let admin_client = client.admin("test_admin");
+
+    let hlist_pat![merchant_id, api_key]: HList![MerchantId, ApiKey] = admin_client
+        .create_merchant_account_with_routing(
+            &server,
+            json!({ "type": "priority", "data": ["stripe", "shift4"] }),
+        )
+        .await;
+    for connector_name in ["stripe", "shift4"] {
+        let _connector: serde_json::Value = admin_client
+            .create_connector(&server, &merchant_id, connector_name, "test_api_key")
+            .await;
+    }
+
+    client
+        .user(&api_key)
+        .create_payment(&server, 100, 100)
+        .await
+}
+
+#[actix_web::test]
+async fn should_fail_over_when_connector_is_unavailable() {
+    let stripe = mock_connector(503).await;
+    let shift4 = mock_connector(503).await;
+    let mut conf = Settings::new().unwrap();
+    conf.connectors.stripe.base_url = format!("{}/", stripe.uri());
+    conf.connectors.shift4.base_url = format!("{}/", shift4.uri());
+
+    let _payment = create_payment_with_failover(conf).await;
+
+    assert_eq!(received_requests(&stripe).await, 1);
+    assert_eq!(received_requests(&shift4).await, 1);
+}
+
+#[actix_web::test]
+async fn should_fail_over_when_connection_to_connector_fails() {
+    let shift4 = mock_connector(503).await;
+    let mut conf = Settings::new().unwrap();
+    // Nothing listens on the port, so the connection is refused
+    conf.connectors.stripe.base_url = "http://127.0.0.1:1/".to_string();
+    conf.connectors.shift4.base_url = format!("{}/", shift4.uri());
+
+    let _payment = create_payment_with_failover(conf).await;
+
+    assert_eq!(received_requests(&shift4).await, 1);
+}
+
+#[actix_web::test]
+async fn should_not_fail_over_when_connector_times_out() {
+    let stripe = mock_connector(504).await;
+    let shift4 = mock_connector(200).await;
+    let mut conf = Settings::new().unwrap();
+    conf.connectors.stripe.base_url = format!("{}/", stripe.uri());
+    conf.connectors.shift4.base_url = format!("{}/", shift4.uri());
+
+    let _payment = create_payment_with_failover(conf).await;
+
+    assert_eq!(received_requests(&stripe).await, 1);
+    assert_eq!(received_requests(&shift4).await, 0);
+}
+
+#[actix_web::test]
+async fn should_not_fail_over_when_connection_is_closed_after_request_is_sent() {
+    // Reads the request and closes the connection without responding, as a connector which fails
+    // while processing the payment would
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let stripe_address = listener.local_addr().unwrap();
+    std::thread::spawn(move || {
+        for mut stream in listener.incoming().flatten() {
+            let mut buffer = [0; 4096];
+            let _ = stream.read(&mut buffer);
+        }
+    });
+    let shift4 = mock_connector(200).await;
+    let mut conf = Settings::new().unwrap();
+    conf.connectors.stripe.base_url = format!("http://{stripe_address}/");
+    conf.connectors.shift4.base_url = format!("{}/", shift4.uri());
+
+    let _payment = create_payment_with_failover(conf).await;
+
+    assert_eq!(received_requests(&shift4).await, 0);
+}
@@ -42,13 +42,21 @@ async fn stripemock() -> Option<String> {
 pub async fn mk_service(
 ) -> impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = actix_web::Error> {
     let mut conf = Settings::new().unwrap();
-    let request_body_limit = conf.server.request_body_limit;
 
     if let Some(url) = stripemock().await {
         conf.connectors.stripe.base_url = url;
     }
 
-    let app_state = AppState::with_storage(conf, router::db::StorageImpl::Mock).await;
+    mk_service_with_conf(conf).await
+}
+
+pub async fn mk_service_with_conf(
+    conf: Settings,
+) -> impl Service<Request, Response = ServiceResponse<impl MessageBody>, Error = actix_web::Error> {
+    let request_body_limit = conf.server.request_body_limit;
+    let app_state = AppState::with_storage(conf, router::db::StorageImpl::Mock)
+        .await
+        .unwrap();
     actix_web::test::init_service(router::mk_app(app_state, request_body_limit)).await
 }
 
@@ -92,6 +100,26 @@ impl AppClient<Admin> {
         call_and_read_body_json(app, request).await
     }
 
+    pub async fn create_merchant_account_with_routing<T: DeserializeOwned, S, B>(
+        &self,
+        app: &S,
+        routing_algorithm: Value,
+    ) -> T
+    where
+        S: Service<Request, Response = ServiceResponse<B>, Error = actix_web::Error>,
+        B: MessageBody,
+    {
+        let mut merchant_account = mk_merchant_account(None);
+        merchant_account["routing_algorithm"] = routing_algorithm;
+        let request = TestRequest::post()
+            .uri("/accounts")
+            .append_header(("api-key".to_owned(), self.state.authkey.clone()))
+            .set_json(merchant_account)
+            .to_request();
+
+        call_and_read_body_json(app, request).await
+    }
+
     pub async fn create_connector<T: DeserializeOwned, S, B>(
         &self,
         app: &S,
@@ -118,7 +146,7 @@ impl AppClient<User> {
         &self,
         app: &S,
         amount: i64,
-        amount_to_capture: i32,
+        amount_to_capture: i64,
     ) -> T
     where
         S: Service<Request, Response = ServiceResponse<B>, Error = actix_web::Error>,
@@ -225,7 +253,7 @@ fn mk_merchant_account(merchant_id: Option<String>) -> Value {
     })
 }
 
-fn mk_payment(amount: i64, amount_to_capture: i32) -> Value {
+fn mk_payment(amount: i64, amount_to_capture: i64) -> Value {
     json!({
       "amount": amount,
       "currency": "USD",
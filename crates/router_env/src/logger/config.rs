@@ -90,6 +90,11 @@ pub struct LogTelemetry {
     pub enabled: bool,
     /// Sampling rate for traces
     pub sampling_rate: Option<f64>,
+    /// Endpoint of the OTLP collector to which traces are exported. The
+    /// `OTEL_EXPORTER_OTLP_ENDPOINT` environment variable is used if not set.
+    pub otel_exporter_otlp_endpoint: Option<String>,
+    /// Timeout in milliseconds for exporting traces to the OTLP collector.
+    pub otel_exporter_otlp_timeout: Option<u64>,
 }
 
 /// Telemetry / tracing.
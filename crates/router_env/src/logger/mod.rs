@@ -16,6 +16,9 @@ pub use types::{Category, Flow, Level, Tag};
 mod setup;
 pub use setup::{setup, TelemetryGuard};
 
+mod propagation;
+pub use propagation::{current_request_id, start_sampled_trace, trace_context_headers};
+
 pub mod formatter;
 pub use formatter::FormattingLayer;
 
@@ -0,0 +1,76 @@
+//!
+//! Propagation of the trace context to the services called by the application.
+//!
+
+use std::collections::HashMap;
+
+use opentelemetry::{
+    global,
+    propagation::TextMapPropagator,
+    sdk::trace::{IdGenerator, RandomIdGenerator},
+    trace::{SpanContext, TraceContextExt, TraceFlags, TraceState},
+    Context,
+};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+use tracing_subscriber::registry::LookupSpan;
+
+use super::storage::Storage;
+
+/// Name of the span field holding the ID of the request being processed.
+const REQUEST_ID: &str = "request_id";
+
+///
+/// Headers carrying the trace context of the current span, as per the globally configured
+/// propagator. Adding them to an outgoing request lets the called service continue the same trace.
+///
+pub fn trace_context_headers() -> HashMap<String, String> {
+    let context = tracing::Span::current().context();
+    let mut headers = HashMap::new();
+    global::get_text_map_propagator(|propagator| {
+        propagator.inject_context(&context, &mut headers);
+    });
+    headers
+}
+
+///
+/// The ID of the request being processed, as recorded on the span of the request. The spans
+/// created while processing the request inherit its fields, including the ones of the tasks
+/// spawned in the span.
+///
+pub fn current_request_id() -> Option<String> {
+    tracing::Span::current()
+        .with_subscriber(|(id, dispatch)| {
+            let registry = dispatch.downcast_ref::<tracing_subscriber::Registry>()?;
+            let span = registry.span(id)?;
+            let extensions = span.extensions();
+            extensions
+                .get::<Storage<'_>>()?
+                .values
+                .get(REQUEST_ID)?
+                .as_str()
+                .map(ToOwned::to_owned)
+        })
+        .flatten()
+}
+
+///
+/// Makes the span the root of a new trace which is exported whatever the configured sampling
+/// rate, as the parent based sampler follows the sampled flag of the remote parent set here.
+/// Returns the ID of the new trace.
+///
+/// Must be called before any child span of the span is created, as the sampling decision of a
+/// span is made when it is first needed.
+///
+pub fn start_sampled_trace(span: &tracing::Span) -> String {
+    let id_generator = RandomIdGenerator::default();
+    let span_context = SpanContext::new(
+        id_generator.new_trace_id(),
+        id_generator.new_span_id(),
+        TraceFlags::SAMPLED,
+        true,
+        TraceState::default(),
+    );
+    let trace_id = span_context.trace_id().to_string();
+    span.set_parent(Context::new().with_remote_span_context(span_context));
+    trace_id
+}
@@ -41,14 +41,25 @@ pub fn setup<Str: AsRef<str>>(
     global::set_text_map_propagator(TraceContextPropagator::new());
 
     let telemetry = if conf.telemetry.enabled {
+        // Spans with a sampled remote parent, such as those of debug traces, are always sampled
         let trace_config = trace::config()
-            .with_sampler(trace::Sampler::TraceIdRatioBased(
-                conf.telemetry.sampling_rate.unwrap_or(1.0),
-            ))
-            .with_resource(Resource::new(vec![KeyValue::new("service.name", "router")]));
+            .with_sampler(trace::Sampler::ParentBased(Box::new(
+                trace::Sampler::TraceIdRatioBased(conf.telemetry.sampling_rate.unwrap_or(1.0)),
+            )))
+            .with_resource(Resource::new(vec![KeyValue::new(
+                "service.name",
+                service_name.to_string(),
+            )]));
+        let mut exporter = opentelemetry_otlp::new_exporter().tonic().with_env();
+        if let Some(endpoint) = &conf.telemetry.otel_exporter_otlp_endpoint {
+            exporter = exporter.with_endpoint(endpoint);
+        }
+        if let Some(timeout) = conf.telemetry.otel_exporter_otlp_timeout {
+            exporter = exporter.with_timeout(Duration::from_millis(timeout));
+        }
         let tracer = opentelemetry_otlp::new_pipeline()
             .tracing()
-            .with_exporter(opentelemetry_otlp::new_exporter().tonic().with_env())
+            .with_exporter(exporter)
             .with_trace_config(trace_config)
             .install_simple();
 
@@ -49,6 +49,9 @@ pub enum Tag {
 
     /// Event: general.
     Event,
+
+    /// Audit: security relevant events, such as rejected requests.
+    Audit,
 }
 
 /// API Flow
@@ -62,6 +65,22 @@ pub enum Flow {
     MerchantsAccountUpdate,
     /// Merchants account delete flow.
     MerchantsAccountDelete,
+    /// Merchants webhook signing secret rotate flow.
+    MerchantsWebhookSecretRotate,
+    /// Merchants webhook config retrieve flow.
+    MerchantsWebhookConfigRetrieve,
+    /// Merchants webhook config update flow.
+    MerchantsWebhookConfigUpdate,
+    /// Payments audit log retrieve flow.
+    PaymentsAuditLogRetrieve,
+    /// API keys create flow.
+    ApiKeyCreate,
+    /// API keys retrieve flow.
+    ApiKeyRetrieve,
+    /// API keys list flow.
+    ApiKeyList,
+    /// API keys revoke flow.
+    ApiKeyRevoke,
     /// Payment connectors create flow.
     PaymentConnectorsCreate,
     /// Payment connectors retrieve flow.
@@ -80,6 +99,8 @@ pub enum Flow {
     CustomersUpdate,
     /// Customers delete flow.
     CustomersDelete,
+    /// Customers redact flow.
+    CustomersRedact,
     /// Customers get mandates flow.
     CustomersGetMandates,
     /// Create an Ephemeral Key.
@@ -96,6 +117,8 @@ pub enum Flow {
     PaymentMethodsList,
     /// Customer payment methods list flow.
     CustomerPaymentMethodsList,
+    /// Customer payment methods delete flow.
+    CustomerPaymentMethodsDelete,
     /// Payment methods retrieve flow.
     PaymentMethodsRetrieve,
     /// Payment methods update flow.
@@ -118,8 +141,24 @@ pub enum Flow {
     PaymentsSessionToken,
     /// Payments start flow.
     PaymentsStart,
+    /// Payments redirect flow.
+    PaymentsRedirect,
     /// Payments list flow.
     PaymentsList,
+    /// Payments list by filter flow.
+    PaymentsListByFilter,
+    /// Payments clone flow.
+    PaymentsClone,
+    /// Payments client secret regeneration flow.
+    PaymentsClientSecretRegenerate,
+    /// Organization payments list flow.
+    OrganizationPaymentsList,
+    /// Payment links create flow.
+    PaymentLinksCreate,
+    /// Payment links retrieve flow.
+    PaymentLinksRetrieve,
+    /// Payment links hosted checkout page flow.
+    PaymentLinksInitiate,
     /// Payouts create flow
     PayoutsCreate,
     /// Payouts retrieve flow.
@@ -128,22 +167,96 @@ pub enum Flow {
     PayoutsUpdate,
     /// Payouts reverse flow.
     PayoutsReverse,
+    /// Payouts confirm flow.
+    PayoutsConfirm,
+    /// Payouts fulfill flow.
+    PayoutsFulfill,
     /// Payouts cancel flow.
     PayoutsCancel,
     /// Payouts accounts flow.
     PayoutsAccounts,
     /// Refunds create flow.
     RefundsCreate,
+    /// Refunds create through an alternate connector account flow.
+    RefundsAlternateAccountCreate,
     /// Refunds retrieve flow.
     RefundsRetrieve,
     /// Refunds update flow.
     RefundsUpdate,
     /// Refunds list flow.
     RefundsList,
+    /// Refunds list by filter flow.
+    RefundsListByFilter,
+    /// Refunds batch create flow.
+    RefundsBatchCreate,
+    /// Refunds batch retrieve flow.
+    RefundsBatchRetrieve,
+    /// Refunds batch report flow.
+    RefundsBatchReport,
+    /// Disputes retrieve flow.
+    DisputesRetrieve,
+    /// Disputes list flow.
+    DisputesList,
+    /// Disputes evidence submission flow.
+    DisputesEvidenceSubmit,
+    /// Events list flow.
+    EventsList,
+    /// Events retrieve flow.
+    EventsRetrieve,
+    /// Event delivery attempts list flow.
+    EventDeliveryAttemptsList,
+    /// Events retry flow.
+    EventsRetry,
+    /// Exports create flow.
+    ExportsCreate,
+    /// Exports retrieve flow.
+    ExportsRetrieve,
+    /// Exports download flow.
+    ExportsDownload,
+    /// Blocklist add flow.
+    BlocklistAdd,
+    /// Blocklist list flow.
+    BlocklistList,
+    /// Blocklist delete flow.
+    BlocklistDelete,
+    /// Recon reports create flow.
+    ReconReportsCreate,
+    /// Recon reports list flow.
+    ReconReportsList,
+    /// Recon reports retrieve flow.
+    ReconReportsRetrieve,
     /// Incoming Webhook Receive
     IncomingWebhookReceive,
+    /// Incoming Webhook Replay
+    IncomingWebhookReplay,
+    /// Outgoing Webhook Test
+    OutgoingWebhookTest,
     /// Validate payment method flow
     ValidatePaymentMethod,
+    /// Ops process tracker requeue flow.
+    OpsProcessRequeue,
+    /// Ops payments resync flow.
+    OpsPaymentsResync,
+    /// Ops events replay flow.
+    OpsEventsReplay,
+    /// Ops batch retrieve flow.
+    OpsBatchRetrieve,
+    /// Payments analytics flow.
+    PaymentsAnalytics,
+    /// Dummy connector payment create flow.
+    DummyConnectorPaymentCreate,
+    /// Dummy connector payment retrieve flow.
+    DummyConnectorPaymentRetrieve,
+    /// Dummy connector payment capture flow.
+    DummyConnectorPaymentCapture,
+    /// Dummy connector payment void flow.
+    DummyConnectorPaymentVoid,
+    /// Dummy connector payment authorize flow.
+    DummyConnectorPaymentAuthorize,
+    /// Dummy connector refund create flow.
+    DummyConnectorRefundCreate,
+    /// Dummy connector refund retrieve flow.
+    DummyConnectorRefundRetrieve,
 }
 
 /// Category of log event.
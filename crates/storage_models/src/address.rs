@@ -4,7 +4,7 @@ use masking::Secret;
 use serde::{Deserialize, Serialize};
 use time::{OffsetDateTime, PrimitiveDateTime};
 
-use crate::schema::address;
+use crate::{encryption::Encryptable, schema::address};
 
 #[derive(Clone, Debug, Deserialize, Serialize, Insertable, router_derive::DebugAsDisplay)]
 #[diesel(table_name = address)]
@@ -13,14 +13,14 @@ pub struct AddressNew {
     pub address_id: String,
     pub city: Option<String>,
     pub country: Option<String>,
-    pub line1: Option<Secret<String>>,
-    pub line2: Option<Secret<String>>,
-    pub line3: Option<Secret<String>>,
-    pub state: Option<Secret<String>>,
-    pub zip: Option<Secret<String>>,
-    pub first_name: Option<Secret<String>>,
-    pub last_name: Option<Secret<String>>,
-    pub phone_number: Option<Secret<String>>,
+    pub line1: Option<Encryptable<Secret<String>>>,
+    pub line2: Option<Encryptable<Secret<String>>>,
+    pub line3: Option<Encryptable<Secret<String>>>,
+    pub state: Option<Encryptable<Secret<String>>>,
+    pub zip: Option<Encryptable<Secret<String>>>,
+    pub first_name: Option<Encryptable<Secret<String>>>,
+    pub last_name: Option<Encryptable<Secret<String>>>,
+    pub phone_number: Option<Encryptable<Secret<String>>>,
     pub country_code: Option<String>,
     pub customer_id: String,
     pub merchant_id: String,
@@ -35,14 +35,14 @@ pub struct Address {
     pub address_id: String,
     pub city: Option<String>,
     pub country: Option<String>,
-    pub line1: Option<Secret<String>>,
-    pub line2: Option<Secret<String>>,
-    pub line3: Option<Secret<String>>,
-    pub state: Option<Secret<String>>,
-    pub zip: Option<Secret<String>>,
-    pub first_name: Option<Secret<String>>,
-    pub last_name: Option<Secret<String>>,
-    pub phone_number: Option<Secret<String>>,
+    pub line1: Option<Encryptable<Secret<String>>>,
+    pub line2: Option<Encryptable<Secret<String>>>,
+    pub line3: Option<Encryptable<Secret<String>>>,
+    pub state: Option<Encryptable<Secret<String>>>,
+    pub zip: Option<Encryptable<Secret<String>>>,
+    pub first_name: Option<Encryptable<Secret<String>>>,
+    pub last_name: Option<Encryptable<Secret<String>>>,
+    pub phone_number: Option<Encryptable<Secret<String>>>,
     pub country_code: Option<String>,
     #[serde(skip_serializing)]
     #[serde(with = "custom_serde::iso8601")]
@@ -59,14 +59,14 @@ pub enum AddressUpdate {
     Update {
         city: Option<String>,
         country: Option<String>,
-        line1: Option<Secret<String>>,
-        line2: Option<Secret<String>>,
-        line3: Option<Secret<String>>,
-        state: Option<Secret<String>>,
-        zip: Option<Secret<String>>,
-        first_name: Option<Secret<String>>,
-        last_name: Option<Secret<String>>,
-        phone_number: Option<Secret<String>>,
+        line1: Option<Encryptable<Secret<String>>>,
+        line2: Option<Encryptable<Secret<String>>>,
+        line3: Option<Encryptable<Secret<String>>>,
+        state: Option<Encryptable<Secret<String>>>,
+        zip: Option<Encryptable<Secret<String>>>,
+        first_name: Option<Encryptable<Secret<String>>>,
+        last_name: Option<Encryptable<Secret<String>>>,
+        phone_number: Option<Encryptable<Secret<String>>>,
         country_code: Option<String>,
     },
 }
@@ -76,14 +76,14 @@ pub enum AddressUpdate {
 pub struct AddressUpdateInternal {
     city: Option<String>,
     country: Option<String>,
-    line1: Option<Secret<String>>,
-    line2: Option<Secret<String>>,
-    line3: Option<Secret<String>>,
-    state: Option<Secret<String>>,
-    zip: Option<Secret<String>>,
-    first_name: Option<Secret<String>>,
-    last_name: Option<Secret<String>>,
-    phone_number: Option<Secret<String>>,
+    line1: Option<Encryptable<Secret<String>>>,
+    line2: Option<Encryptable<Secret<String>>>,
+    line3: Option<Encryptable<Secret<String>>>,
+    state: Option<Encryptable<Secret<String>>>,
+    zip: Option<Encryptable<Secret<String>>>,
+    first_name: Option<Encryptable<Secret<String>>>,
+    last_name: Option<Encryptable<Secret<String>>>,
+    phone_number: Option<Encryptable<Secret<String>>>,
     country_code: Option<String>,
     modified_at: PrimitiveDateTime,
 }
@@ -0,0 +1,65 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::schema::api_keys;
+
+/// An API key of a merchant. Only the hash of the key is stored, the plaintext key is shown to
+/// the merchant once, when the key is created.
+#[derive(Clone, Debug, Eq, Identifiable, Queryable, PartialEq)]
+#[diesel(table_name = api_keys)]
+#[diesel(primary_key(key_id))]
+pub struct ApiKey {
+    pub key_id: String,
+    pub merchant_id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub hashed_api_key: String,
+    /// The first few characters of the plaintext key, which help the merchant identify the key
+    pub prefix: String,
+    pub created_at: PrimitiveDateTime,
+    pub expires_at: Option<PrimitiveDateTime>,
+    pub last_used: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = api_keys)]
+pub struct ApiKeyNew {
+    pub key_id: String,
+    pub merchant_id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub hashed_api_key: String,
+    pub prefix: String,
+    pub expires_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone)]
+pub enum ApiKeyUpdate {
+    LastUsedUpdate { last_used: PrimitiveDateTime },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = api_keys)]
+pub struct ApiKeyUpdateInternal {
+    last_used: Option<PrimitiveDateTime>,
+}
+
+impl ApiKeyUpdate {
+    pub fn apply_changeset(self, source: ApiKey) -> ApiKey {
+        let update_internal: ApiKeyUpdateInternal = self.into();
+        ApiKey {
+            last_used: update_internal.last_used.or(source.last_used),
+            ..source
+        }
+    }
+}
+
+impl From<ApiKeyUpdate> for ApiKeyUpdateInternal {
+    fn from(api_key_update: ApiKeyUpdate) -> Self {
+        match api_key_update {
+            ApiKeyUpdate::LastUsedUpdate { last_used } => Self {
+                last_used: Some(last_used),
+            },
+        }
+    }
+}
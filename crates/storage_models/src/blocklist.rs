@@ -0,0 +1,37 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::blocklist};
+
+/// An entry of the blocklist of a merchant. Card numbers are never stored, only their
+/// fingerprint is.
+#[derive(Clone, Debug, Eq, Identifiable, Queryable, PartialEq)]
+#[diesel(table_name = blocklist)]
+pub struct Blocklist {
+    pub id: i32,
+    pub entry_id: String,
+    pub merchant_id: String,
+    pub kind: storage_enums::BlocklistKind,
+    pub data: String,
+    pub expires_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = blocklist)]
+pub struct BlocklistNew {
+    pub entry_id: String,
+    pub merchant_id: String,
+    pub kind: storage_enums::BlocklistKind,
+    pub data: String,
+    pub expires_at: Option<PrimitiveDateTime>,
+}
+
+impl Blocklist {
+    /// Whether the entry has expired at the given time, entries without an expiry never expire.
+    pub fn is_expired_at(&self, time: PrimitiveDateTime) -> bool {
+        self.expires_at
+            .map(|expires_at| expires_at <= time)
+            .unwrap_or(false)
+    }
+}
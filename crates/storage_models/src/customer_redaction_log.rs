@@ -0,0 +1,25 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::schema::customer_redaction_log;
+
+/// An immutable record of the redaction of a customer's personal data.
+#[derive(Clone, Debug, Eq, Identifiable, Queryable, PartialEq, serde::Serialize)]
+#[diesel(table_name = customer_redaction_log)]
+pub struct CustomerRedactionLog {
+    pub id: i32,
+    pub customer_id: String,
+    pub merchant_id: String,
+    pub payment_methods_deleted: i32,
+    pub payment_intents_anonymized: i32,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = customer_redaction_log)]
+pub struct CustomerRedactionLogNew {
+    pub customer_id: String,
+    pub merchant_id: String,
+    pub payment_methods_deleted: i32,
+    pub payment_intents_anonymized: i32,
+}
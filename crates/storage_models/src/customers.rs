@@ -3,7 +3,7 @@ use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use masking::Secret;
 use time::PrimitiveDateTime;
 
-use crate::schema::customers;
+use crate::{encryption::Encryptable, schema::customers};
 
 #[derive(Default, Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
 #[diesel(table_name = customers)]
@@ -11,8 +11,8 @@ pub struct CustomerNew {
     pub customer_id: String,
     pub merchant_id: String,
     pub name: Option<String>,
-    pub email: Option<Secret<String, pii::Email>>,
-    pub phone: Option<Secret<String>>,
+    pub email: Option<Encryptable<Secret<String, pii::Email>>>,
+    pub phone: Option<Encryptable<Secret<String>>>,
     pub description: Option<String>,
     pub phone_country_code: Option<String>,
     pub metadata: Option<serde_json::Value>,
@@ -25,8 +25,8 @@ pub struct Customer {
     pub customer_id: String,
     pub merchant_id: String,
     pub name: Option<String>,
-    pub email: Option<Secret<String, pii::Email>>,
-    pub phone: Option<Secret<String>>,
+    pub email: Option<Encryptable<Secret<String, pii::Email>>>,
+    pub phone: Option<Encryptable<Secret<String>>>,
     pub phone_country_code: Option<String>,
     pub description: Option<String>,
     pub created_at: PrimitiveDateTime,
@@ -37,8 +37,8 @@ pub struct Customer {
 pub enum CustomerUpdate {
     Update {
         name: Option<String>,
-        email: Option<Secret<String, pii::Email>>,
-        phone: Option<Secret<String>>,
+        email: Option<Encryptable<Secret<String, pii::Email>>>,
+        phone: Option<Encryptable<Secret<String>>>,
         description: Option<String>,
         phone_country_code: Option<String>,
         metadata: Option<serde_json::Value>,
@@ -49,8 +49,8 @@ pub enum CustomerUpdate {
 #[diesel(table_name = customers)]
 pub struct CustomerUpdateInternal {
     name: Option<String>,
-    email: Option<Secret<String, pii::Email>>,
-    phone: Option<Secret<String>>,
+    email: Option<Encryptable<Secret<String, pii::Email>>>,
+    phone: Option<Encryptable<Secret<String>>>,
     description: Option<String>,
     phone_country_code: Option<String>,
     metadata: Option<serde_json::Value>,
@@ -1 +1,114 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
 
+use crate::{enums as storage_enums, schema::dispute};
+
+#[derive(Clone, Debug, Eq, Identifiable, Queryable, PartialEq, serde::Serialize)]
+#[diesel(table_name = dispute)]
+pub struct Dispute {
+    pub id: i32,
+    pub dispute_id: String,
+    pub amount: i64,
+    pub currency: String,
+    pub dispute_status: storage_enums::DisputeStatus,
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub merchant_id: String,
+    pub connector: String,
+    pub connector_status: String,
+    pub connector_dispute_id: String,
+    pub connector_reason: Option<String>,
+    pub connector_reason_code: Option<String>,
+    pub challenge_required_by: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+    /// The evidence last submitted to the connector for challenging the dispute
+    pub evidence: Option<serde_json::Value>,
+    pub challenge_status: Option<storage_enums::DisputeChallengeStatus>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = dispute)]
+pub struct DisputeNew {
+    pub dispute_id: String,
+    pub amount: i64,
+    pub currency: String,
+    pub dispute_status: storage_enums::DisputeStatus,
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub merchant_id: String,
+    pub connector: String,
+    pub connector_status: String,
+    pub connector_dispute_id: String,
+    pub connector_reason: Option<String>,
+    pub connector_reason_code: Option<String>,
+    pub challenge_required_by: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone)]
+pub enum DisputeUpdate {
+    Update {
+        dispute_status: storage_enums::DisputeStatus,
+        connector_status: String,
+        connector_reason: Option<String>,
+        connector_reason_code: Option<String>,
+        challenge_required_by: Option<PrimitiveDateTime>,
+        challenge_status: Option<storage_enums::DisputeChallengeStatus>,
+    },
+    EvidenceUpdate {
+        dispute_status: storage_enums::DisputeStatus,
+        connector_status: Option<String>,
+        evidence: serde_json::Value,
+        challenge_status: storage_enums::DisputeChallengeStatus,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = dispute)]
+pub struct DisputeUpdateInternal {
+    dispute_status: Option<storage_enums::DisputeStatus>,
+    connector_status: Option<String>,
+    connector_reason: Option<String>,
+    connector_reason_code: Option<String>,
+    challenge_required_by: Option<PrimitiveDateTime>,
+    evidence: Option<serde_json::Value>,
+    challenge_status: Option<storage_enums::DisputeChallengeStatus>,
+    modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<DisputeUpdate> for DisputeUpdateInternal {
+    fn from(dispute_update: DisputeUpdate) -> Self {
+        match dispute_update {
+            DisputeUpdate::Update {
+                dispute_status,
+                connector_status,
+                connector_reason,
+                connector_reason_code,
+                challenge_required_by,
+                challenge_status,
+            } => Self {
+                dispute_status: Some(dispute_status),
+                connector_status: Some(connector_status),
+                connector_reason,
+                connector_reason_code,
+                challenge_required_by,
+                challenge_status,
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            DisputeUpdate::EvidenceUpdate {
+                dispute_status,
+                connector_status,
+                evidence,
+                challenge_status,
+            } => Self {
+                dispute_status: Some(dispute_status),
+                connector_status,
+                evidence: Some(evidence),
+                challenge_status: Some(challenge_status),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+        }
+    }
+}
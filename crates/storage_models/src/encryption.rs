@@ -0,0 +1,115 @@
+use std::{fmt, marker::PhantomData};
+
+use diesel::{
+    backend::{Backend, RawValue},
+    deserialize::{self, FromSql, Queryable},
+    expression::AsExpression,
+    internal::derives::as_expression::Bound,
+    serialize::{self, Output, ToSql},
+    sql_types,
+};
+use masking::{ExposeInterface, Secret};
+use serde::{Deserialize, Serialize};
+
+/// A column value which is stored encrypted with the data key of the merchant owning the row,
+/// where `T` is the type of the value once decrypted. Values are encrypted before being written
+/// and decrypted after being read by the database layer of the router, so that personal data is
+/// never stored in plaintext. The leading byte of the stored value tells the encrypted values apart
+/// from the plaintext ones the column held before it was encrypted.
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct Encryptable<T> {
+    encrypted: Secret<Vec<u8>>,
+    #[serde(skip)]
+    value: PhantomData<T>,
+}
+
+impl<T> Encryptable<T> {
+    pub fn new(encrypted: Vec<u8>) -> Self {
+        Self {
+            encrypted: Secret::new(encrypted),
+            value: PhantomData,
+        }
+    }
+
+    pub fn into_encrypted(self) -> Vec<u8> {
+        self.encrypted.expose()
+    }
+}
+
+impl<T> Clone for Encryptable<T> {
+    fn clone(&self) -> Self {
+        Self {
+            encrypted: self.encrypted.clone(),
+            value: PhantomData,
+        }
+    }
+}
+
+impl<T> fmt::Debug for Encryptable<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("*** encrypted ***")
+    }
+}
+
+impl<'expr, T, ST> AsExpression<ST> for &'expr Encryptable<T>
+where
+    ST: sql_types::SingleValue,
+{
+    type Expression = Bound<ST, Self>;
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
+
+impl<'expr2, 'expr, T, ST> AsExpression<ST> for &'expr2 &'expr Encryptable<T>
+where
+    ST: sql_types::SingleValue,
+{
+    type Expression = Bound<ST, Self>;
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
+
+impl<T, ST> AsExpression<ST> for Encryptable<T>
+where
+    ST: sql_types::SingleValue,
+{
+    type Expression = Bound<ST, Self>;
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
+
+impl<T, ST, DB> ToSql<ST, DB> for Encryptable<T>
+where
+    DB: Backend,
+    Secret<Vec<u8>>: ToSql<ST, DB>,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        ToSql::<ST, DB>::to_sql(&self.encrypted, out)
+    }
+}
+
+impl<T, ST, DB> FromSql<ST, DB> for Encryptable<T>
+where
+    DB: Backend,
+    Vec<u8>: FromSql<ST, DB>,
+{
+    fn from_sql(bytes: RawValue<'_, DB>) -> deserialize::Result<Self> {
+        Vec::<u8>::from_sql(bytes).map(Self::new)
+    }
+}
+
+impl<T, ST, DB> Queryable<ST, DB> for Encryptable<T>
+where
+    DB: Backend,
+    ST: sql_types::SingleValue,
+    Self: FromSql<ST, DB>,
+{
+    type Row = Self;
+    fn build(row: Self::Row) -> deserialize::Result<Self> {
+        Ok(row)
+    }
+}
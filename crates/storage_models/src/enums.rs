@@ -1,16 +1,25 @@
 #[doc(hidden)]
 pub mod diesel_exports {
     pub use super::{
-        DbAttemptStatus as AttemptStatus, DbAuthenticationType as AuthenticationType,
-        DbCaptureMethod as CaptureMethod, DbConnectorType as ConnectorType, DbCurrency as Currency,
+        DbAttemptStatus as AttemptStatus, DbAuditActor as AuditActor,
+        DbAuthenticationType as AuthenticationType, DbBlocklistKind as BlocklistKind,
+        DbCaptureMethod as CaptureMethod, DbCaptureStatus as CaptureStatus,
+        DbConnectorType as ConnectorType, DbCurrency as Currency,
+        DbDisputeChallengeStatus as DisputeChallengeStatus, DbDisputeStatus as DisputeStatus,
         DbEventClass as EventClass, DbEventObjectType as EventObjectType, DbEventType as EventType,
-        DbFutureUsage as FutureUsage, DbIntentStatus as IntentStatus,
-        DbMandateStatus as MandateStatus, DbMandateType as MandateType,
-        DbMerchantStorageScheme as MerchantStorageScheme, DbPaymentFlow as PaymentFlow,
+        DbExportFormat as ExportFormat, DbExportObject as ExportObject,
+        DbExportStatus as ExportStatus, DbFutureUsage as FutureUsage,
+        DbIntentStatus as IntentStatus, DbMandateStatus as MandateStatus,
+        DbMandateType as MandateType, DbMerchantStorageScheme as MerchantStorageScheme,
+        DbOutboxEventType as OutboxEventType, DbPaymentCostType as PaymentCostType,
+        DbPaymentFlow as PaymentFlow, DbPaymentLinkStatus as PaymentLinkStatus,
         DbPaymentMethodIssuerCode as PaymentMethodIssuerCode,
         DbPaymentMethodSubType as PaymentMethodSubType, DbPaymentMethodType as PaymentMethodType,
-        DbProcessTrackerStatus as ProcessTrackerStatus, DbRefundStatus as RefundStatus,
-        DbRefundType as RefundType, DbRoutingAlgorithm as RoutingAlgorithm,
+        DbPayoutStatus as PayoutStatus, DbPayoutType as PayoutType,
+        DbProcessTrackerStatus as ProcessTrackerStatus, DbReconEntryType as ReconEntryType,
+        DbReconMismatchReason as ReconMismatchReason, DbReconReportStatus as ReconReportStatus,
+        DbRefundStatus as RefundStatus, DbRefundType as RefundType,
+        DbRoutingAlgorithm as RoutingAlgorithm,
     };
 }
 
@@ -134,6 +143,10 @@ pub enum ConnectorType {
     BankingEntities,
     /// All types of non-banking financial institutions including Insurance, Credit / Lending etc
     NonBankingFinance,
+    /// 3DS servers which authenticate cardholders independently of the payment processor
+    AuthenticationProcessor,
+    /// Fraud and risk management services which screen payments before or after authorization
+    FrmProcessor,
 }
 
 #[allow(clippy::upper_case_acronyms)]
@@ -260,6 +273,133 @@ pub enum Currency {
     ZAR,
 }
 
+impl Currency {
+    /// The three-digit numeric code of the currency, as defined in ISO 4217.
+    pub fn iso_4217_numeric_code(&self) -> &'static str {
+        match self {
+            Self::AED => "784",
+            Self::ALL => "008",
+            Self::AMD => "051",
+            Self::ARS => "032",
+            Self::AUD => "036",
+            Self::AWG => "533",
+            Self::AZN => "944",
+            Self::BBD => "052",
+            Self::BDT => "050",
+            Self::BHD => "048",
+            Self::BMD => "060",
+            Self::BND => "096",
+            Self::BOB => "068",
+            Self::BRL => "986",
+            Self::BSD => "044",
+            Self::BWP => "072",
+            Self::BZD => "084",
+            Self::CAD => "124",
+            Self::CHF => "756",
+            Self::CNY => "156",
+            Self::COP => "170",
+            Self::CRC => "188",
+            Self::CUP => "192",
+            Self::CZK => "203",
+            Self::DKK => "208",
+            Self::DOP => "214",
+            Self::DZD => "012",
+            Self::EGP => "818",
+            Self::ETB => "230",
+            Self::EUR => "978",
+            Self::FJD => "242",
+            Self::GBP => "826",
+            Self::GHS => "936",
+            Self::GIP => "292",
+            Self::GMD => "270",
+            Self::GTQ => "320",
+            Self::GYD => "328",
+            Self::HKD => "344",
+            Self::HNL => "340",
+            Self::HRK => "191",
+            Self::HTG => "332",
+            Self::HUF => "348",
+            Self::IDR => "360",
+            Self::ILS => "376",
+            Self::INR => "356",
+            Self::JMD => "388",
+            Self::JOD => "400",
+            Self::JPY => "392",
+            Self::KES => "404",
+            Self::KGS => "417",
+            Self::KHR => "116",
+            Self::KRW => "410",
+            Self::KWD => "414",
+            Self::KYD => "136",
+            Self::KZT => "398",
+            Self::LAK => "418",
+            Self::LBP => "422",
+            Self::LKR => "144",
+            Self::LRD => "430",
+            Self::LSL => "426",
+            Self::MAD => "504",
+            Self::MDL => "498",
+            Self::MKD => "807",
+            Self::MMK => "104",
+            Self::MNT => "496",
+            Self::MOP => "446",
+            Self::MUR => "480",
+            Self::MVR => "462",
+            Self::MWK => "454",
+            Self::MXN => "484",
+            Self::MYR => "458",
+            Self::NAD => "516",
+            Self::NGN => "566",
+            Self::NIO => "558",
+            Self::NOK => "578",
+            Self::NPR => "524",
+            Self::NZD => "554",
+            Self::OMR => "512",
+            Self::PEN => "604",
+            Self::PGK => "598",
+            Self::PHP => "608",
+            Self::PKR => "586",
+            Self::PLN => "985",
+            Self::QAR => "634",
+            Self::RUB => "643",
+            Self::SAR => "682",
+            Self::SCR => "690",
+            Self::SEK => "752",
+            Self::SGD => "702",
+            Self::SLL => "694",
+            Self::SOS => "706",
+            Self::SSP => "728",
+            Self::SVC => "222",
+            Self::SZL => "748",
+            Self::THB => "764",
+            Self::TTD => "780",
+            Self::TWD => "901",
+            Self::TZS => "834",
+            Self::USD => "840",
+            Self::UYU => "858",
+            Self::UZS => "860",
+            Self::YER => "886",
+            Self::ZAR => "710",
+        }
+    }
+
+    /// The number of digits after the decimal point in the major unit of the currency, which is
+    /// the exponent relating the major unit to the minor unit that amounts are specified in.
+    pub fn number_of_digits_after_decimal_point(&self) -> u8 {
+        match self {
+            Self::JPY | Self::KRW => 0,
+            Self::BHD | Self::JOD | Self::KWD | Self::OMR => 3,
+            _ => 2,
+        }
+    }
+}
+
+impl common_utils::types::CurrencyUnit for Currency {
+    fn number_of_digits_after_decimal_point(&self) -> u8 {
+        Self::number_of_digits_after_decimal_point(self)
+    }
+}
+
 #[derive(
     Clone,
     Copy,
@@ -271,12 +411,17 @@ pub enum Currency {
     strum::Display,
     strum::EnumString,
     router_derive::DieselEnum,
+    frunk::LabelledGeneric,
 )]
 #[router_derive::diesel_enum]
 #[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
 pub enum EventClass {
     Payments,
+    Refunds,
+    Disputes,
+    Mandates,
+    Payouts,
 }
 
 #[derive(
@@ -290,12 +435,17 @@ pub enum EventClass {
     strum::Display,
     strum::EnumString,
     router_derive::DieselEnum,
+    frunk::LabelledGeneric,
 )]
 #[router_derive::diesel_enum]
 #[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
 pub enum EventObjectType {
     PaymentDetails,
+    RefundDetails,
+    DisputeDetails,
+    MandateDetails,
+    PayoutDetails,
 }
 
 #[derive(
@@ -316,6 +466,18 @@ pub enum EventObjectType {
 #[strum(serialize_all = "snake_case")]
 pub enum EventType {
     PaymentSucceeded,
+    PaymentFailed,
+    RefundSucceeded,
+    RefundFailed,
+    DisputeOpened,
+    DisputeChallenged,
+    DisputeWon,
+    DisputeLost,
+    MandateActive,
+    MandateRevoked,
+    PayoutSucceeded,
+    PayoutFailed,
+    PayoutCancelled,
 }
 
 #[derive(
@@ -345,6 +507,7 @@ pub enum IntentStatus {
     #[default]
     RequiresConfirmation,
     RequiresCapture,
+    PartiallyCaptured,
 }
 
 #[derive(
@@ -672,3 +835,392 @@ pub enum MandateStatus {
     Pending,
     Revoked,
 }
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    router_derive::DieselEnum,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DisputeStatus {
+    #[default]
+    DisputeOpened,
+    DisputeChallenged,
+    DisputeWon,
+    DisputeLost,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    router_derive::DieselEnum,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DisputeChallengeStatus {
+    #[default]
+    Submitted,
+    UnderReview,
+    Won,
+    Lost,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    router_derive::DieselEnum,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutStatus {
+    #[default]
+    RequiresConfirmation,
+    RequiresFulfillment,
+    Pending,
+    Success,
+    Failed,
+    Cancelled,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    router_derive::DieselEnum,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutType {
+    #[default]
+    Bank,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    router_derive::DieselEnum,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CaptureStatus {
+    #[default]
+    Started,
+    Charged,
+    Pending,
+    Failed,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    router_derive::DieselEnum,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentLinkStatus {
+    #[default]
+    Active,
+    Completed,
+    Expired,
+}
+
+/// The actor on whose behalf a payment was updated, as recorded in the payment audit log.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    router_derive::DieselEnum,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditActor {
+    #[default]
+    Api,
+    Webhook,
+    Scheduler,
+}
+
+/// The domain events recorded in the outbox along with the state change they announce.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    router_derive::DieselEnum,
+)]
+#[router_derive::diesel_enum]
+pub enum OutboxEventType {
+    #[serde(rename = "payment.succeeded")]
+    #[strum(serialize = "payment.succeeded")]
+    PaymentSucceeded,
+    #[serde(rename = "refund.created")]
+    #[strum(serialize = "refund.created")]
+    RefundCreated,
+    #[serde(rename = "dispute.opened")]
+    #[strum(serialize = "dispute.opened")]
+    DisputeOpened,
+}
+
+/// The records which are included in an export.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    router_derive::DieselEnum,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ExportObject {
+    #[default]
+    Payments,
+    Refunds,
+    Disputes,
+    /// The rows of a refund batch, which are exported through the refund batch report
+    RefundBatch,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    router_derive::DieselEnum,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ExportFormat {
+    #[default]
+    Csv,
+    Json,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    router_derive::DieselEnum,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ExportStatus {
+    #[default]
+    Pending,
+    Succeeded,
+    Failed,
+}
+
+/// The kind of data which is blocked by a blocklist entry.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    router_derive::DieselEnum,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum BlocklistKind {
+    CardFingerprint,
+    Email,
+    IpAddress,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    router_derive::DieselEnum,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReconReportStatus {
+    #[default]
+    Pending,
+    Completed,
+    Failed,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    router_derive::DieselEnum,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReconEntryType {
+    Payment,
+    Refund,
+}
+
+/// The reason an entry of a settlement report does not match the payment or refund it settles.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    router_derive::DieselEnum,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReconMismatchReason {
+    NotFound,
+    AmountMismatch,
+    CurrencyMismatch,
+    StatusMismatch,
+}
+
+/// The kind of a cost incurred by a merchant for a payment.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    router_derive::DieselEnum,
+)]
+#[router_derive::diesel_enum]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentCostType {
+    /// A fee charged by the connector for a dispute raised against the payment
+    DisputeFee,
+    /// A dispute fee refunded by the connector, usually because the dispute was won
+    DisputeFeeReversal,
+}
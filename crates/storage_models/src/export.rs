@@ -0,0 +1,103 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::export};
+
+/// An export of the payments, refunds or disputes of a merchant created in a time range, which is
+/// generated in the background.
+#[derive(Clone, Debug, Eq, Identifiable, Queryable, PartialEq)]
+#[diesel(table_name = export)]
+pub struct Export {
+    pub id: i32,
+    pub export_id: String,
+    pub merchant_id: String,
+    pub object: storage_enums::ExportObject,
+    pub format: storage_enums::ExportFormat,
+    pub start_time: PrimitiveDateTime,
+    pub end_time: PrimitiveDateTime,
+    pub status: storage_enums::ExportStatus,
+    /// The number of records in the generated file
+    pub row_count: Option<i32>,
+    /// The generated file, once the export has succeeded
+    pub file_content: Option<String>,
+    /// The reason the export failed
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+    /// The refund batch whose rows are exported, for refund batch exports
+    pub batch_id: Option<String>,
+    /// Whether the customer identifiers and card data are pseudonymized in the generated file
+    pub anonymized: bool,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = export)]
+pub struct ExportNew {
+    pub export_id: String,
+    pub merchant_id: String,
+    pub object: storage_enums::ExportObject,
+    pub format: storage_enums::ExportFormat,
+    pub start_time: PrimitiveDateTime,
+    pub end_time: PrimitiveDateTime,
+    pub status: storage_enums::ExportStatus,
+    pub batch_id: Option<String>,
+    pub anonymized: bool,
+}
+
+#[derive(Debug, Clone)]
+pub enum ExportUpdate {
+    SucceededUpdate {
+        row_count: i32,
+        file_content: String,
+    },
+    FailedUpdate {
+        error_message: String,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = export)]
+pub struct ExportUpdateInternal {
+    status: Option<storage_enums::ExportStatus>,
+    row_count: Option<i32>,
+    file_content: Option<String>,
+    error_message: Option<String>,
+    modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<ExportUpdate> for ExportUpdateInternal {
+    fn from(export_update: ExportUpdate) -> Self {
+        match export_update {
+            ExportUpdate::SucceededUpdate {
+                row_count,
+                file_content,
+            } => Self {
+                status: Some(storage_enums::ExportStatus::Succeeded),
+                row_count: Some(row_count),
+                file_content: Some(file_content),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            ExportUpdate::FailedUpdate { error_message } => Self {
+                status: Some(storage_enums::ExportStatus::Failed),
+                error_message: Some(error_message),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl ExportUpdate {
+    pub fn apply_changeset(self, source: Export) -> Export {
+        let update_internal: ExportUpdateInternal = self.into();
+        Export {
+            status: update_internal.status.unwrap_or(source.status),
+            row_count: update_internal.row_count.or(source.row_count),
+            file_content: update_internal.file_content.or(source.file_content),
+            error_message: update_internal.error_message.or(source.error_message),
+            modified_at: common_utils::date_time::now(),
+            ..source
+        }
+    }
+}
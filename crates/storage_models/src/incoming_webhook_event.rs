@@ -0,0 +1,25 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::schema::incoming_webhook_event;
+
+/// An incoming webhook which has been processed, recorded for discarding redeliveries of the same
+/// event by the connector.
+#[derive(Clone, Debug, Eq, Identifiable, Queryable, PartialEq)]
+#[diesel(table_name = incoming_webhook_event)]
+pub struct IncomingWebhookEvent {
+    pub id: i32,
+    pub merchant_id: String,
+    pub connector_name: String,
+    /// The identifier of the event at the connector
+    pub connector_event_id: String,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = incoming_webhook_event)]
+pub struct IncomingWebhookEventNew {
+    pub merchant_id: String,
+    pub connector_name: String,
+    pub connector_event_id: String,
+}
@@ -3,12 +3,13 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     errors,
+    outbox_event::OutboxEventNew,
     payment_attempt::{PaymentAttempt, PaymentAttemptNew, PaymentAttemptUpdate},
     payment_intent::{PaymentIntent, PaymentIntentNew, PaymentIntentUpdate},
     refund::{Refund, RefundNew, RefundUpdate},
 };
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case", tag = "db_op", content = "data")]
 pub enum DBOperation {
     Insert { insertable: Insertable },
@@ -16,10 +17,14 @@ pub enum DBOperation {
     Delete,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct TypedSql {
     #[serde(flatten)]
     pub op: DBOperation,
+    /// The outbox event announcing the operation, which is recorded in the same stream entry as
+    /// the operation so that it is applied to the database if and only if the operation is
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub outbox_event: Option<OutboxEventNew>,
 }
 
 impl TypedSql {
@@ -33,7 +38,7 @@ impl TypedSql {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case", tag = "table", content = "data")]
 pub enum Insertable {
     PaymentIntent(PaymentIntentNew),
@@ -41,7 +46,7 @@ pub enum Insertable {
     Refund(RefundNew),
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case", tag = "table", content = "data")]
 pub enum Updateable {
     PaymentIntentUpdate(PaymentIntentUpdateMems),
@@ -49,19 +54,19 @@ pub enum Updateable {
     RefundUpdate(RefundUpdateMems),
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct PaymentIntentUpdateMems {
     pub orig: PaymentIntent,
     pub update_data: PaymentIntentUpdate,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct PaymentAttemptUpdateMems {
     pub orig: PaymentAttempt,
     pub update_data: PaymentAttemptUpdate,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct RefundUpdateMems {
     pub orig: Refund,
     pub update_data: RefundUpdate,
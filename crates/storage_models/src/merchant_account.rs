@@ -23,6 +23,9 @@ pub struct MerchantAccount {
     pub locker_id: Option<String>,
     pub metadata: Option<serde_json::Value>,
     pub routing_algorithm: Option<serde_json::Value>,
+    pub webhook_signing_secret: Option<StrongSecret<String>>,
+    pub outgoing_webhook_custom_headers: Option<Vec<u8>>,
+    pub surcharge_config: Option<serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Default, Insertable, router_derive::DebugAsDisplay)]
@@ -43,6 +46,9 @@ pub struct MerchantAccountNew {
     pub locker_id: Option<String>,
     pub metadata: Option<serde_json::Value>,
     pub routing_algorithm: Option<serde_json::Value>,
+    pub webhook_signing_secret: Option<StrongSecret<String>>,
+    pub outgoing_webhook_custom_headers: Option<Vec<u8>>,
+    pub surcharge_config: Option<serde_json::Value>,
 }
 
 #[derive(Debug)]
@@ -63,6 +69,11 @@ pub enum MerchantAccountUpdate {
         locker_id: Option<String>,
         metadata: Option<serde_json::Value>,
         routing_algorithm: Option<serde_json::Value>,
+        outgoing_webhook_custom_headers: Option<Vec<u8>>,
+        surcharge_config: Option<serde_json::Value>,
+    },
+    WebhookSigningSecretUpdate {
+        webhook_signing_secret: StrongSecret<String>,
     },
 }
 
@@ -84,6 +95,9 @@ pub struct MerchantAccountUpdateInternal {
     locker_id: Option<String>,
     metadata: Option<serde_json::Value>,
     routing_algorithm: Option<serde_json::Value>,
+    webhook_signing_secret: Option<StrongSecret<String>>,
+    outgoing_webhook_custom_headers: Option<Vec<u8>>,
+    surcharge_config: Option<serde_json::Value>,
 }
 
 impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
@@ -105,6 +119,8 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 publishable_key,
                 locker_id,
                 metadata,
+                outgoing_webhook_custom_headers,
+                surcharge_config,
             } => Self {
                 merchant_id: Some(merchant_id),
                 merchant_name,
@@ -121,6 +137,15 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 publishable_key,
                 locker_id,
                 metadata,
+                outgoing_webhook_custom_headers,
+                surcharge_config,
+                ..Default::default()
+            },
+            MerchantAccountUpdate::WebhookSigningSecretUpdate {
+                webhook_signing_secret,
+            } => Self {
+                webhook_signing_secret: Some(webhook_signing_secret),
+                ..Default::default()
             },
         }
     }
@@ -17,6 +17,11 @@ pub struct MerchantConnectorAccount {
     pub payment_methods_enabled: Option<Vec<serde_json::Value>>,
     pub connector_type: storage_enums::ConnectorType,
     pub metadata: Option<serde_json::Value>,
+    pub business_country: Option<String>,
+    pub business_label: Option<String>,
+    /// Distinguishes the accounts of a merchant for the same connector, derived from the
+    /// connector name and the business details of the account
+    pub connector_label: String,
 }
 
 #[derive(Clone, Debug, Default, Insertable, router_derive::DebugAsDisplay)]
@@ -31,6 +36,9 @@ pub struct MerchantConnectorAccountNew {
     pub merchant_connector_id: Option<i32>,
     pub payment_methods_enabled: Option<Vec<serde_json::Value>>,
     pub metadata: Option<serde_json::Value>,
+    pub business_country: Option<String>,
+    pub business_label: Option<String>,
+    pub connector_label: Option<String>,
 }
 
 #[derive(Debug)]
@@ -45,6 +53,9 @@ pub enum MerchantConnectorAccountUpdate {
         merchant_connector_id: Option<i32>,
         payment_methods_enabled: Option<Vec<serde_json::Value>>,
         metadata: Option<serde_json::Value>,
+        business_country: Option<String>,
+        business_label: Option<String>,
+        connector_label: Option<String>,
     },
 }
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -59,6 +70,9 @@ pub struct MerchantConnectorAccountUpdateInternal {
     merchant_connector_id: Option<i32>,
     payment_methods_enabled: Option<Vec<serde_json::Value>>,
     metadata: Option<serde_json::Value>,
+    business_country: Option<String>,
+    business_label: Option<String>,
+    connector_label: Option<String>,
 }
 
 impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInternal {
@@ -74,6 +88,9 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 merchant_connector_id,
                 payment_methods_enabled,
                 metadata,
+                business_country,
+                business_label,
+                connector_label,
             } => Self {
                 merchant_id,
                 connector_type,
@@ -84,6 +101,9 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 merchant_connector_id,
                 payment_methods_enabled,
                 metadata,
+                business_country,
+                business_label,
+                connector_label,
             },
         }
     }
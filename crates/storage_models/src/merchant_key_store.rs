@@ -0,0 +1,21 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::schema::merchant_key_store;
+
+/// The data key of a merchant, used for encrypting the personal data stored for the merchant.
+#[derive(Clone, Debug, Identifiable, Queryable)]
+#[diesel(table_name = merchant_key_store, primary_key(merchant_id))]
+pub struct MerchantKeyStore {
+    pub merchant_id: String,
+    /// The data key, encrypted with the master encryption key
+    pub key: Vec<u8>,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = merchant_key_store)]
+pub struct MerchantKeyStoreNew {
+    pub merchant_id: String,
+    pub key: Vec<u8>,
+}
@@ -0,0 +1,80 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::merchant_webhook_config};
+
+/// The preferences of a merchant for the outgoing webhooks sent to them.
+#[derive(Clone, Debug, Eq, Identifiable, Queryable, PartialEq)]
+#[diesel(table_name = merchant_webhook_config)]
+pub struct MerchantWebhookConfig {
+    pub id: i32,
+    pub merchant_id: String,
+    pub enabled: bool,
+    /// Overrides the webhook URL in the webhook details of the merchant account
+    pub webhook_url: Option<String>,
+    /// The event types for which webhooks are sent, webhooks are sent for all event types if
+    /// this is not set
+    #[diesel(deserialize_as = super::OptionalDieselArray<storage_enums::EventType>)]
+    pub enabled_events: Option<Vec<storage_enums::EventType>>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = merchant_webhook_config)]
+pub struct MerchantWebhookConfigNew {
+    pub merchant_id: String,
+    pub enabled: bool,
+    pub webhook_url: Option<String>,
+    pub enabled_events: Option<Vec<storage_enums::EventType>>,
+}
+
+#[derive(Debug, Clone)]
+pub enum MerchantWebhookConfigUpdate {
+    Update {
+        enabled: bool,
+        webhook_url: Option<String>,
+        enabled_events: Option<Vec<storage_enums::EventType>>,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = merchant_webhook_config)]
+pub struct MerchantWebhookConfigUpdateInternal {
+    enabled: Option<bool>,
+    webhook_url: Option<Option<String>>,
+    enabled_events: Option<Option<Vec<storage_enums::EventType>>>,
+    modified_at: Option<PrimitiveDateTime>,
+}
+
+impl MerchantWebhookConfigUpdate {
+    pub fn apply_changeset(self, source: MerchantWebhookConfig) -> MerchantWebhookConfig {
+        let update_internal: MerchantWebhookConfigUpdateInternal = self.into();
+        MerchantWebhookConfig {
+            enabled: update_internal.enabled.unwrap_or(source.enabled),
+            webhook_url: update_internal.webhook_url.unwrap_or(source.webhook_url),
+            enabled_events: update_internal
+                .enabled_events
+                .unwrap_or(source.enabled_events),
+            modified_at: common_utils::date_time::now(),
+            ..source
+        }
+    }
+}
+
+impl From<MerchantWebhookConfigUpdate> for MerchantWebhookConfigUpdateInternal {
+    fn from(merchant_webhook_config_update: MerchantWebhookConfigUpdate) -> Self {
+        match merchant_webhook_config_update {
+            MerchantWebhookConfigUpdate::Update {
+                enabled,
+                webhook_url,
+                enabled_events,
+            } => Self {
+                enabled: Some(enabled),
+                webhook_url: Some(webhook_url),
+                enabled_events: Some(enabled_events),
+                modified_at: Some(common_utils::date_time::now()),
+            },
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{
+    dispute::Dispute, enums as storage_enums, payment_intent::PaymentIntent, refund::Refund,
+    schema::outbox_event,
+};
+
+/// A domain event recorded in the same transaction as the state change it announces, which is
+/// published by the outbox relay once the transaction has been committed.
+#[derive(Clone, Debug, Eq, Identifiable, Queryable, PartialEq, serde::Serialize)]
+#[diesel(table_name = outbox_event)]
+pub struct OutboxEvent {
+    pub id: i32,
+    pub event_id: String,
+    pub event_type: storage_enums::OutboxEventType,
+    pub merchant_id: String,
+    /// The ID of the payment, refund or dispute which the event is about
+    pub object_id: String,
+    /// The object which the event is about, as of the state change
+    pub payload: serde_json::Value,
+    pub created_at: PrimitiveDateTime,
+    pub published_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    Insertable,
+    router_derive::DebugAsDisplay,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[diesel(table_name = outbox_event)]
+pub struct OutboxEventNew {
+    pub event_id: String,
+    pub event_type: storage_enums::OutboxEventType,
+    pub merchant_id: String,
+    pub object_id: String,
+    pub payload: serde_json::Value,
+}
+
+impl OutboxEventNew {
+    fn new<T: serde::Serialize>(
+        event_type: storage_enums::OutboxEventType,
+        merchant_id: &str,
+        object_id: &str,
+        object: &T,
+    ) -> Result<Self, serde_json::Error> {
+        Ok(Self {
+            event_id: common_utils::generate_id_with_default_len("evt"),
+            event_type,
+            merchant_id: merchant_id.to_owned(),
+            object_id: object_id.to_owned(),
+            payload: serde_json::to_value(object)?,
+        })
+    }
+
+    pub fn payment_succeeded(payment_intent: &PaymentIntent) -> Result<Self, serde_json::Error> {
+        Self::new(
+            storage_enums::OutboxEventType::PaymentSucceeded,
+            &payment_intent.merchant_id,
+            &payment_intent.payment_id,
+            payment_intent,
+        )
+    }
+
+    pub fn refund_created(refund: &Refund) -> Result<Self, serde_json::Error> {
+        Self::new(
+            storage_enums::OutboxEventType::RefundCreated,
+            &refund.merchant_id,
+            &refund.refund_id,
+            refund,
+        )
+    }
+
+    pub fn dispute_opened(dispute: &Dispute) -> Result<Self, serde_json::Error> {
+        Self::new(
+            storage_enums::OutboxEventType::DisputeOpened,
+            &dispute.merchant_id,
+            &dispute.dispute_id,
+            dispute,
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum OutboxEventUpdate {
+    Published,
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = outbox_event)]
+pub struct OutboxEventUpdateInternal {
+    published_at: Option<PrimitiveDateTime>,
+}
+
+impl From<OutboxEventUpdate> for OutboxEventUpdateInternal {
+    fn from(outbox_event_update: OutboxEventUpdate) -> Self {
+        match outbox_event_update {
+            OutboxEventUpdate::Published => Self {
+                published_at: Some(common_utils::date_time::now()),
+            },
+        }
+    }
+}
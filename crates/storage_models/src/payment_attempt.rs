@@ -39,6 +39,29 @@ pub struct PaymentAttempt {
     pub error_code: Option<String>,
     pub payment_token: Option<String>,
     pub connector_metadata: Option<serde_json::Value>,
+    pub payment_checks: Option<serde_json::Value>,
+    pub three_ds_authentication_data: Option<serde_json::Value>,
+    /// Copy of the last request sent to and response received from the connector for the
+    /// attempt, with the sensitive data in them masked.
+    pub connector_api_log: Option<serde_json::Value>,
+    /// The outcome of screening the attempt with the fraud and risk management service of the
+    /// merchant
+    pub frm_data: Option<serde_json::Value>,
+    /// The currency in which the attempt is made with the connector, when it differs from the
+    /// currency in which the payment is presented to the customer
+    pub settlement_currency: Option<storage_enums::Currency>,
+    /// The amount of the attempt converted to the settlement currency
+    pub settlement_amount: Option<i64>,
+    /// The exchange rate from the presentment currency to the settlement currency with which the
+    /// settlement amount was converted
+    pub fx_rate: Option<String>,
+    /// Whether the attempt was made with a merchant connector account in test mode, against the
+    /// sandbox of the connector
+    pub test_mode: Option<bool>,
+    /// The first digits of the card number the attempt was made with, identifying its issuer
+    pub card_bin: Option<String>,
+    /// The last four digits of the card number the attempt was made with
+    pub card_last4: Option<String>,
 }
 
 #[derive(
@@ -78,6 +101,24 @@ pub struct PaymentAttemptNew {
     pub payment_token: Option<String>,
     pub error_code: Option<String>,
     pub connector_metadata: Option<serde_json::Value>,
+    pub payment_checks: Option<serde_json::Value>,
+    pub three_ds_authentication_data: Option<serde_json::Value>,
+    /// Copy of the last request sent to and response received from the connector for the
+    /// attempt, with the sensitive data in them masked.
+    pub connector_api_log: Option<serde_json::Value>,
+    /// The outcome of screening the attempt with the fraud and risk management service of the
+    /// merchant
+    pub frm_data: Option<serde_json::Value>,
+    /// The currency in which the attempt is made with the connector, when it differs from the
+    /// currency in which the payment is presented to the customer
+    pub settlement_currency: Option<storage_enums::Currency>,
+    /// The amount of the attempt converted to the settlement currency
+    pub settlement_amount: Option<i64>,
+    /// The exchange rate from the presentment currency to the settlement currency with which the
+    /// settlement amount was converted
+    pub fx_rate: Option<String>,
+    pub card_bin: Option<String>,
+    pub card_last4: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -105,6 +146,13 @@ pub enum PaymentAttemptUpdate {
         browser_info: Option<serde_json::Value>,
         connector: Option<String>,
         payment_token: Option<String>,
+        settlement_currency: Option<storage_enums::Currency>,
+        settlement_amount: Option<i64>,
+        fx_rate: Option<String>,
+        surcharge_amount: Option<i64>,
+        tax_amount: Option<i64>,
+        card_bin: Option<String>,
+        card_last4: Option<String>,
     },
     VoidUpdate {
         status: storage_enums::AttemptStatus,
@@ -119,6 +167,9 @@ pub enum PaymentAttemptUpdate {
         redirect: Option<bool>,
         mandate_id: Option<String>,
         connector_metadata: Option<serde_json::Value>,
+        payment_checks: Option<serde_json::Value>,
+        connector_api_log: Option<serde_json::Value>,
+        test_mode: Option<bool>,
     },
     StatusUpdate {
         status: storage_enums::AttemptStatus,
@@ -128,6 +179,15 @@ pub enum PaymentAttemptUpdate {
         status: storage_enums::AttemptStatus,
         error_code: Option<String>,
         error_message: Option<String>,
+        connector_api_log: Option<serde_json::Value>,
+        test_mode: Option<bool>,
+    },
+    ThreeDsAuthenticationDataUpdate {
+        three_ds_authentication_data: serde_json::Value,
+    },
+    FrmDataUpdate {
+        frm_data: serde_json::Value,
+        capture_method: Option<storage_enums::CaptureMethod>,
     },
 }
 
@@ -151,6 +211,19 @@ pub struct PaymentAttemptUpdateInternal {
     payment_token: Option<String>,
     error_code: Option<String>,
     connector_metadata: Option<serde_json::Value>,
+    payment_checks: Option<serde_json::Value>,
+    three_ds_authentication_data: Option<serde_json::Value>,
+    capture_method: Option<storage_enums::CaptureMethod>,
+    connector_api_log: Option<serde_json::Value>,
+    frm_data: Option<serde_json::Value>,
+    settlement_currency: Option<storage_enums::Currency>,
+    settlement_amount: Option<i64>,
+    fx_rate: Option<String>,
+    surcharge_amount: Option<i64>,
+    tax_amount: Option<i64>,
+    test_mode: Option<bool>,
+    card_bin: Option<String>,
+    card_last4: Option<String>,
 }
 
 impl PaymentAttemptUpdate {
@@ -173,6 +246,21 @@ impl PaymentAttemptUpdate {
             browser_info: pa_update.browser_info,
             modified_at: common_utils::date_time::now(),
             payment_token: pa_update.payment_token,
+            payment_checks: pa_update.payment_checks.or(source.payment_checks),
+            three_ds_authentication_data: pa_update
+                .three_ds_authentication_data
+                .or(source.three_ds_authentication_data),
+            frm_data: pa_update.frm_data.or(source.frm_data),
+            capture_method: pa_update.capture_method.or(source.capture_method),
+            connector_api_log: pa_update.connector_api_log.or(source.connector_api_log),
+            settlement_currency: pa_update.settlement_currency.or(source.settlement_currency),
+            settlement_amount: pa_update.settlement_amount.or(source.settlement_amount),
+            fx_rate: pa_update.fx_rate.or(source.fx_rate),
+            surcharge_amount: pa_update.surcharge_amount.or(source.surcharge_amount),
+            tax_amount: pa_update.tax_amount.or(source.tax_amount),
+            test_mode: pa_update.test_mode.or(source.test_mode),
+            card_bin: pa_update.card_bin.or(source.card_bin),
+            card_last4: pa_update.card_last4.or(source.card_last4),
             ..source
         }
     }
@@ -214,6 +302,13 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 browser_info,
                 connector,
                 payment_token,
+                settlement_currency,
+                settlement_amount,
+                fx_rate,
+                surcharge_amount,
+                tax_amount,
+                card_bin,
+                card_last4,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -224,6 +319,13 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 browser_info,
                 connector,
                 payment_token,
+                settlement_currency,
+                settlement_amount,
+                fx_rate,
+                surcharge_amount,
+                tax_amount,
+                card_bin,
+                card_last4,
                 ..Default::default()
             },
             PaymentAttemptUpdate::VoidUpdate {
@@ -243,6 +345,9 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 redirect,
                 mandate_id,
                 connector_metadata,
+                payment_checks,
+                connector_api_log,
+                test_mode,
             } => Self {
                 status: Some(status),
                 connector,
@@ -253,6 +358,9 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 redirect,
                 mandate_id,
                 connector_metadata,
+                payment_checks,
+                connector_api_log,
+                test_mode,
                 ..Default::default()
             },
             PaymentAttemptUpdate::ErrorUpdate {
@@ -260,11 +368,15 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 status,
                 error_code,
                 error_message,
+                connector_api_log,
+                test_mode,
             } => Self {
                 connector,
                 status: Some(status),
                 error_message,
                 error_code,
+                connector_api_log,
+                test_mode,
                 modified_at: Some(common_utils::date_time::now()),
                 ..Default::default()
             },
@@ -280,6 +392,22 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 connector,
                 ..Default::default()
             },
+            PaymentAttemptUpdate::ThreeDsAuthenticationDataUpdate {
+                three_ds_authentication_data,
+            } => Self {
+                three_ds_authentication_data: Some(three_ds_authentication_data),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            PaymentAttemptUpdate::FrmDataUpdate {
+                frm_data,
+                capture_method,
+            } => Self {
+                frm_data: Some(frm_data),
+                capture_method,
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
         }
     }
 }
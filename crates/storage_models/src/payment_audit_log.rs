@@ -0,0 +1,27 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::payment_audit_log};
+
+/// An immutable record of an update to a payment intent.
+#[derive(Clone, Debug, Eq, Identifiable, Queryable, PartialEq, serde::Serialize)]
+#[diesel(table_name = payment_audit_log)]
+pub struct PaymentAuditLog {
+    pub id: i32,
+    pub payment_id: String,
+    pub merchant_id: String,
+    pub previous_status: storage_enums::IntentStatus,
+    pub status: storage_enums::IntentStatus,
+    pub actor: storage_enums::AuditActor,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_audit_log)]
+pub struct PaymentAuditLogNew {
+    pub payment_id: String,
+    pub merchant_id: String,
+    pub previous_status: storage_enums::IntentStatus,
+    pub status: storage_enums::IntentStatus,
+    pub actor: storage_enums::AuditActor,
+}
@@ -0,0 +1,43 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::payment_cost};
+
+/// An immutable entry in the ledger of the costs incurred by a merchant for its payments, such as
+/// the fees charged by connectors for disputes. Reversals of costs are recorded as entries of
+/// their own, with a negative amount.
+#[derive(Clone, Debug, Eq, Identifiable, Queryable, PartialEq, serde::Serialize)]
+#[diesel(table_name = payment_cost)]
+pub struct PaymentCost {
+    pub id: i32,
+    pub cost_id: String,
+    pub merchant_id: String,
+    pub payment_id: String,
+    pub attempt_id: String,
+    /// The dispute the cost was incurred for, if any
+    pub dispute_id: Option<String>,
+    pub connector: String,
+    pub cost_type: storage_enums::PaymentCostType,
+    /// The amount charged to the merchant, in the lowest denomination of the currency. Negative
+    /// for amounts refunded to the merchant.
+    pub amount: i64,
+    pub currency: String,
+    /// The reference of the cost at the connector, which a cost is recorded at most once for
+    pub connector_reference: String,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_cost)]
+pub struct PaymentCostNew {
+    pub cost_id: String,
+    pub merchant_id: String,
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub dispute_id: Option<String>,
+    pub connector: String,
+    pub cost_type: storage_enums::PaymentCostType,
+    pub amount: i64,
+    pub currency: String,
+    pub connector_reference: String,
+}
@@ -1,3 +1,4 @@
+use common_utils::types::MinorUnit;
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
@@ -11,9 +12,9 @@ pub struct PaymentIntent {
     pub payment_id: String,
     pub merchant_id: String,
     pub status: storage_enums::IntentStatus,
-    pub amount: i64,
+    pub amount: MinorUnit,
     pub currency: Option<storage_enums::Currency>,
-    pub amount_captured: Option<i64>,
+    pub amount_captured: Option<MinorUnit>,
     pub customer_id: Option<String>,
     pub description: Option<String>,
     pub return_url: Option<String>,
@@ -29,6 +30,14 @@ pub struct PaymentIntent {
     pub setup_future_usage: Option<storage_enums::FutureUsage>,
     pub off_session: Option<bool>,
     pub client_secret: Option<String>,
+    /// The time after which the client secret can no longer be used for client side operations
+    pub client_secret_expiry: Option<PrimitiveDateTime>,
+    /// The business details of the payment, used for selecting the connector account
+    pub business_country: Option<String>,
+    pub business_label: Option<String>,
+    /// The attempt with which the payment is currently being processed, which is its latest
+    /// attempt
+    pub active_attempt_id: Option<String>,
 }
 
 #[derive(
@@ -47,9 +56,9 @@ pub struct PaymentIntentNew {
     pub payment_id: String,
     pub merchant_id: String,
     pub status: storage_enums::IntentStatus,
-    pub amount: i64,
+    pub amount: MinorUnit,
     pub currency: Option<storage_enums::Currency>,
-    pub amount_captured: Option<i64>,
+    pub amount_captured: Option<MinorUnit>,
     pub customer_id: Option<String>,
     pub description: Option<String>,
     pub return_url: Option<String>,
@@ -63,15 +72,19 @@ pub struct PaymentIntentNew {
     pub modified_at: Option<PrimitiveDateTime>,
     pub last_synced: Option<PrimitiveDateTime>,
     pub client_secret: Option<String>,
+    pub client_secret_expiry: Option<PrimitiveDateTime>,
     pub setup_future_usage: Option<storage_enums::FutureUsage>,
     pub off_session: Option<bool>,
+    pub business_country: Option<String>,
+    pub business_label: Option<String>,
+    pub active_attempt_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum PaymentIntentUpdate {
     ResponseUpdate {
         status: storage_enums::IntentStatus,
-        amount_captured: Option<i64>,
+        amount_captured: Option<MinorUnit>,
         return_url: Option<String>,
     },
     MetadataUpdate {
@@ -92,8 +105,15 @@ pub enum PaymentIntentUpdate {
     PGStatusUpdate {
         status: storage_enums::IntentStatus,
     },
+    /// Cancels a payment which has not been processed by the connector yet
+    CancelUpdate,
+    /// Replaces the client secret of a payment, along with the time at which it expires
+    ClientSecretUpdate {
+        client_secret: String,
+        client_secret_expiry: PrimitiveDateTime,
+    },
     Update {
-        amount: i64,
+        amount: MinorUnit,
         currency: storage_enums::Currency,
         status: storage_enums::IntentStatus,
         customer_id: Option<String>,
@@ -101,25 +121,31 @@ pub enum PaymentIntentUpdate {
         billing_address_id: Option<String>,
         return_url: Option<String>,
     },
+    /// Makes a newly created attempt the one with which the payment is processed
+    ActiveAttemptUpdate {
+        active_attempt_id: String,
+    },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
 #[diesel(table_name = payment_intent)]
 
 pub struct PaymentIntentUpdateInternal {
-    pub amount: Option<i64>,
+    pub amount: Option<MinorUnit>,
     pub currency: Option<storage_enums::Currency>,
     pub status: Option<storage_enums::IntentStatus>,
-    pub amount_captured: Option<i64>,
+    pub amount_captured: Option<MinorUnit>,
     pub customer_id: Option<String>,
     pub return_url: Option<String>,
     pub setup_future_usage: Option<storage_enums::FutureUsage>,
     pub off_session: Option<bool>,
     pub metadata: Option<serde_json::Value>,
     pub client_secret: Option<Option<String>>,
+    pub client_secret_expiry: Option<PrimitiveDateTime>,
     pub billing_address_id: Option<String>,
     pub shipping_address_id: Option<String>,
     pub modified_at: Option<PrimitiveDateTime>,
+    pub active_attempt_id: Option<String>,
 }
 
 impl PaymentIntentUpdate {
@@ -140,12 +166,18 @@ impl PaymentIntentUpdate {
             client_secret: internal_update
                 .client_secret
                 .unwrap_or(source.client_secret),
+            client_secret_expiry: internal_update
+                .client_secret_expiry
+                .or(source.client_secret_expiry),
             billing_address_id: internal_update
                 .billing_address_id
                 .or(source.billing_address_id),
             shipping_address_id: internal_update
                 .shipping_address_id
                 .or(source.shipping_address_id),
+            active_attempt_id: internal_update
+                .active_attempt_id
+                .or(source.active_attempt_id),
             modified_at: common_utils::date_time::now(),
             ..source
         }
@@ -201,6 +233,25 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 modified_at: Some(common_utils::date_time::now()),
                 ..Default::default()
             },
+            PaymentIntentUpdate::ActiveAttemptUpdate { active_attempt_id } => Self {
+                active_attempt_id: Some(active_attempt_id),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            PaymentIntentUpdate::CancelUpdate => Self {
+                status: Some(storage_enums::IntentStatus::Cancelled),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            PaymentIntentUpdate::ClientSecretUpdate {
+                client_secret,
+                client_secret_expiry,
+            } => Self {
+                client_secret: Some(Some(client_secret)),
+                client_secret_expiry: Some(client_secret_expiry),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
             PaymentIntentUpdate::MerchantStatusUpdate {
                 status,
                 shipping_address_id,
@@ -238,7 +289,12 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
 fn make_client_secret_null_if_success(
     status: Option<storage_enums::IntentStatus>,
 ) -> Option<Option<String>> {
-    if status == Some(storage_enums::IntentStatus::Succeeded) {
+    if matches!(
+        status,
+        Some(
+            storage_enums::IntentStatus::Succeeded | storage_enums::IntentStatus::PartiallyCaptured
+        )
+    ) {
         Some(None)
     } else {
         None
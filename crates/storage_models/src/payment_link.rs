@@ -0,0 +1,63 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::payment_link};
+
+#[derive(Clone, Debug, Eq, Identifiable, Queryable, PartialEq, serde::Serialize)]
+#[diesel(table_name = payment_link)]
+pub struct PaymentLink {
+    pub id: i32,
+    pub payment_link_id: String,
+    pub payment_id: String,
+    pub merchant_id: String,
+    pub status: storage_enums::PaymentLinkStatus,
+    pub expires_at: PrimitiveDateTime,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_link)]
+pub struct PaymentLinkNew {
+    pub payment_link_id: String,
+    pub payment_id: String,
+    pub merchant_id: String,
+    pub status: storage_enums::PaymentLinkStatus,
+    pub expires_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone)]
+pub enum PaymentLinkUpdate {
+    StatusUpdate {
+        status: storage_enums::PaymentLinkStatus,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_link)]
+pub struct PaymentLinkUpdateInternal {
+    status: Option<storage_enums::PaymentLinkStatus>,
+    modified_at: Option<PrimitiveDateTime>,
+}
+
+impl PaymentLinkUpdate {
+    pub fn apply_changeset(self, source: PaymentLink) -> PaymentLink {
+        let update_internal: PaymentLinkUpdateInternal = self.into();
+        PaymentLink {
+            status: update_internal.status.unwrap_or(source.status),
+            modified_at: common_utils::date_time::now(),
+            ..source
+        }
+    }
+}
+
+impl From<PaymentLinkUpdate> for PaymentLinkUpdateInternal {
+    fn from(payment_link_update: PaymentLinkUpdate) -> Self {
+        match payment_link_update {
+            PaymentLinkUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                modified_at: Some(common_utils::date_time::now()),
+            },
+        }
+    }
+}
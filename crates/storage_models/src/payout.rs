@@ -0,0 +1,71 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::payouts};
+
+#[derive(Clone, Debug, Eq, Identifiable, Queryable, PartialEq, serde::Serialize)]
+#[diesel(table_name = payouts)]
+pub struct Payout {
+    pub id: i32,
+    pub payout_id: String,
+    pub merchant_id: String,
+    pub customer_id: Option<String>,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub payout_type: storage_enums::PayoutType,
+    pub status: storage_enums::PayoutStatus,
+    pub connector: String,
+    pub description: Option<String>,
+    pub metadata: Option<serde_json::Value>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payouts)]
+pub struct PayoutNew {
+    pub payout_id: String,
+    pub merchant_id: String,
+    pub customer_id: Option<String>,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub payout_type: storage_enums::PayoutType,
+    pub status: storage_enums::PayoutStatus,
+    pub connector: String,
+    pub description: Option<String>,
+    pub metadata: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone)]
+pub enum PayoutUpdate {
+    StatusUpdate { status: storage_enums::PayoutStatus },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payouts)]
+pub struct PayoutUpdateInternal {
+    status: Option<storage_enums::PayoutStatus>,
+    modified_at: Option<PrimitiveDateTime>,
+}
+
+impl PayoutUpdate {
+    pub fn apply_changeset(self, source: Payout) -> Payout {
+        let update_internal: PayoutUpdateInternal = self.into();
+        Payout {
+            status: update_internal.status.unwrap_or(source.status),
+            modified_at: common_utils::date_time::now(),
+            ..source
+        }
+    }
+}
+
+impl From<PayoutUpdate> for PayoutUpdateInternal {
+    fn from(payout_update: PayoutUpdate) -> Self {
+        match payout_update {
+            PayoutUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                modified_at: Some(common_utils::date_time::now()),
+            },
+        }
+    }
+}
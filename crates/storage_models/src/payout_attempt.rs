@@ -0,0 +1,99 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::payout_attempts};
+
+#[derive(Clone, Debug, Eq, Identifiable, Queryable, PartialEq, serde::Serialize)]
+#[diesel(table_name = payout_attempts)]
+pub struct PayoutAttempt {
+    pub id: i32,
+    pub payout_attempt_id: String,
+    pub payout_id: String,
+    pub merchant_id: String,
+    pub connector: String,
+    pub connector_payout_id: Option<String>,
+    pub status: storage_enums::PayoutStatus,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_attempts)]
+pub struct PayoutAttemptNew {
+    pub payout_attempt_id: String,
+    pub payout_id: String,
+    pub merchant_id: String,
+    pub connector: String,
+    pub connector_payout_id: Option<String>,
+    pub status: storage_enums::PayoutStatus,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub enum PayoutAttemptUpdate {
+    ResponseUpdate {
+        status: storage_enums::PayoutStatus,
+        connector_payout_id: Option<String>,
+    },
+    ErrorUpdate {
+        status: storage_enums::PayoutStatus,
+        error_code: Option<String>,
+        error_message: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_attempts)]
+pub struct PayoutAttemptUpdateInternal {
+    status: Option<storage_enums::PayoutStatus>,
+    connector_payout_id: Option<String>,
+    error_code: Option<String>,
+    error_message: Option<String>,
+    modified_at: Option<PrimitiveDateTime>,
+}
+
+impl PayoutAttemptUpdate {
+    pub fn apply_changeset(self, source: PayoutAttempt) -> PayoutAttempt {
+        let update_internal: PayoutAttemptUpdateInternal = self.into();
+        PayoutAttempt {
+            status: update_internal.status.unwrap_or(source.status),
+            connector_payout_id: update_internal
+                .connector_payout_id
+                .or(source.connector_payout_id),
+            error_code: update_internal.error_code.or(source.error_code),
+            error_message: update_internal.error_message.or(source.error_message),
+            modified_at: common_utils::date_time::now(),
+            ..source
+        }
+    }
+}
+
+impl From<PayoutAttemptUpdate> for PayoutAttemptUpdateInternal {
+    fn from(payout_attempt_update: PayoutAttemptUpdate) -> Self {
+        match payout_attempt_update {
+            PayoutAttemptUpdate::ResponseUpdate {
+                status,
+                connector_payout_id,
+            } => Self {
+                status: Some(status),
+                connector_payout_id,
+                modified_at: Some(common_utils::date_time::now()),
+                ..Self::default()
+            },
+            PayoutAttemptUpdate::ErrorUpdate {
+                status,
+                error_code,
+                error_message,
+            } => Self {
+                status: Some(status),
+                error_code,
+                error_message,
+                modified_at: Some(common_utils::date_time::now()),
+                ..Self::default()
+            },
+        }
+    }
+}
@@ -1,16 +1,33 @@
 pub mod address;
+pub mod api_keys;
+pub mod blocklist;
+pub mod capture;
 pub mod configs;
 pub mod connector_response;
+pub mod customer_redaction_log;
 pub mod customers;
+pub mod dispute;
 pub mod events;
+pub mod export;
 pub mod generics;
+pub mod incoming_webhook_event;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
 pub mod merchant_connector_account;
+pub mod merchant_key_store;
+pub mod merchant_webhook_config;
+pub mod outbox_event;
 pub mod payment_attempt;
+pub mod payment_audit_log;
+pub mod payment_cost;
 pub mod payment_intent;
+pub mod payment_link;
 pub mod payment_method;
+pub mod payout;
+pub mod payout_attempt;
 pub mod process_tracker;
+pub mod recon;
 pub mod refund;
 pub mod reverse_lookup;
+pub mod webhook_delivery_attempt;
@@ -0,0 +1,34 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    customer_redaction_log::{CustomerRedactionLog, CustomerRedactionLogNew},
+    schema::customer_redaction_log::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl CustomerRedactionLogNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<CustomerRedactionLog> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl CustomerRedactionLog {
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_customer_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        customer_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, Self>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.eq(customer_id.to_owned())),
+            None,
+        )
+        .await
+    }
+}
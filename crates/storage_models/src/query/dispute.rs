@@ -0,0 +1,110 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, RunQueryDsl};
+use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
+
+use super::{generics, outbox_event};
+use crate::{
+    dispute::{Dispute, DisputeNew, DisputeUpdate, DisputeUpdateInternal},
+    errors,
+    outbox_event::OutboxEventNew,
+    schema::dispute::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl DisputeNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<Dispute> {
+        generics::generic_insert(conn, self).await
+    }
+
+    /// Inserts the dispute and records the `dispute.opened` event in the outbox, in a single
+    /// transaction.
+    #[instrument(skip(conn))]
+    pub async fn insert_with_dispute_opened_event(
+        self,
+        conn: &PgPooledConn,
+    ) -> StorageResult<Dispute> {
+        outbox_event::with_outbox_event(
+            conn,
+            move |conn| {
+                diesel::insert_into(<Dispute as HasTable>::table())
+                    .values(self)
+                    .get_result(conn)
+            },
+            OutboxEventNew::dispute_opened,
+        )
+        .await
+    }
+}
+
+impl Dispute {
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_connector_connector_dispute_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        connector: &str,
+        connector_dispute_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::connector.eq(connector.to_owned()))
+                .and(dsl::connector_dispute_id.eq(connector_dispute_id.to_owned())),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_dispute_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        dispute_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::dispute_id.eq(dispute_id.to_owned())),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn update(self, conn: &PgPooledConn, dispute: DisputeUpdate) -> StorageResult<Self> {
+        match generics::generic_update_by_id::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            self.id,
+            DisputeUpdateInternal::from(dispute),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+
+    /// Finds the disputes created by the merchant in the time range `[start, end)`, returning at
+    /// most `limit` of them.
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_created_between(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        start: PrimitiveDateTime,
+        end: PrimitiveDateTime,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::created_at.ge(start))
+                .and(dsl::created_at.lt(end)),
+            Some(limit),
+        )
+        .await
+    }
+}
@@ -0,0 +1,52 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    errors,
+    export::{Export, ExportNew, ExportUpdate, ExportUpdateInternal},
+    schema::export::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl ExportNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<Export> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl Export {
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_export_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        export_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::export_id.eq(export_id.to_owned())),
+        )
+        .await
+    }
+
+    // The update holds the generated file, which is not worth recording
+    #[instrument(skip(conn, export))]
+    pub async fn update(self, conn: &PgPooledConn, export: ExportUpdate) -> StorageResult<Self> {
+        match generics::generic_update_by_id::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            self.id,
+            ExportUpdateInternal::from(export),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
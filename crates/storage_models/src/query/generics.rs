@@ -23,7 +23,7 @@ use router_env::{instrument, logger, tracing};
 
 use crate::{errors, PgPooledConn, StorageResult};
 
-#[instrument(level = "DEBUG", skip_all)]
+#[instrument(level = "DEBUG", skip_all, fields(table = std::any::type_name::<T>()))]
 pub async fn generic_insert<T, V, R>(conn: &PgPooledConn, values: V) -> StorageResult<R>
 where
     T: HasTable<Table = T> + Table + 'static,
@@ -52,7 +52,7 @@ where
     .attach_printable_lazy(|| format!("Error while inserting {}", debug_values))
 }
 
-#[instrument(level = "DEBUG", skip_all)]
+#[instrument(level = "DEBUG", skip_all, fields(table = std::any::type_name::<T>()))]
 pub async fn generic_update<T, V, P>(
     conn: &PgPooledConn,
     predicate: P,
@@ -81,7 +81,7 @@ where
         .attach_printable_lazy(|| format!("Error while updating {}", debug_values))
 }
 
-#[instrument(level = "DEBUG", skip_all)]
+#[instrument(level = "DEBUG", skip_all, fields(table = std::any::type_name::<T>()))]
 pub async fn generic_update_with_results<T, V, P, R>(
     conn: &PgPooledConn,
     predicate: P,
@@ -111,7 +111,7 @@ where
         .attach_printable_lazy(|| format!("Error while updating {}", debug_values))
 }
 
-#[instrument(level = "DEBUG", skip_all)]
+#[instrument(level = "DEBUG", skip_all, fields(table = std::any::type_name::<T>()))]
 pub async fn generic_update_by_id<T, V, Pk, R>(
     conn: &PgPooledConn,
     id: Pk,
@@ -159,7 +159,7 @@ where
     }
 }
 
-#[instrument(level = "DEBUG", skip_all)]
+#[instrument(level = "DEBUG", skip_all, fields(table = std::any::type_name::<T>()))]
 pub async fn generic_delete<T, P>(conn: &PgPooledConn, predicate: P) -> StorageResult<bool>
 where
     T: FilterDsl<P> + HasTable<Table = T> + Table + 'static,
@@ -190,7 +190,7 @@ where
         })
 }
 
-#[instrument(level = "DEBUG", skip_all)]
+#[instrument(level = "DEBUG", skip_all, fields(table = std::any::type_name::<T>()))]
 pub async fn generic_delete_one_with_result<T, P, R>(
     conn: &PgPooledConn,
     predicate: P,
@@ -221,7 +221,7 @@ where
         })
 }
 
-#[instrument(level = "DEBUG", skip_all)]
+#[instrument(level = "DEBUG", skip_all, fields(table = std::any::type_name::<T>()))]
 async fn generic_find_by_id_core<T, Pk, R>(conn: &PgPooledConn, id: Pk) -> StorageResult<R>
 where
     T: FindDsl<Pk> + HasTable<Table = T> + LimitDsl + Table + 'static,
@@ -246,7 +246,7 @@ where
     .attach_printable_lazy(|| format!("Error finding record by primary key: {:?}", id))
 }
 
-#[instrument(level = "DEBUG", skip_all)]
+#[instrument(level = "DEBUG", skip_all, fields(table = std::any::type_name::<T>()))]
 pub async fn generic_find_by_id<T, Pk, R>(conn: &PgPooledConn, id: Pk) -> StorageResult<R>
 where
     T: FindDsl<Pk> + HasTable<Table = T> + LimitDsl + Table + 'static,
@@ -259,7 +259,7 @@ where
     generic_find_by_id_core::<T, _, _>(conn, id).await
 }
 
-#[instrument(level = "DEBUG", skip_all)]
+#[instrument(level = "DEBUG", skip_all, fields(table = std::any::type_name::<T>()))]
 pub async fn generic_find_by_id_optional<T, Pk, R>(
     conn: &PgPooledConn,
     id: Pk,
@@ -277,7 +277,7 @@ where
     to_optional(generic_find_by_id_core::<T, _, _>(conn, id).await)
 }
 
-#[instrument(level = "DEBUG", skip_all)]
+#[instrument(level = "DEBUG", skip_all, fields(table = std::any::type_name::<T>()))]
 async fn generic_find_one_core<T, P, R>(conn: &PgPooledConn, predicate: P) -> StorageResult<R>
 where
     T: FilterDsl<P> + HasTable<Table = T> + Table + 'static,
@@ -301,7 +301,7 @@ where
         .attach_printable_lazy(|| "Error finding record by predicate")
 }
 
-#[instrument(level = "DEBUG", skip_all)]
+#[instrument(level = "DEBUG", skip_all, fields(table = std::any::type_name::<T>()))]
 pub async fn generic_find_one<T, P, R>(conn: &PgPooledConn, predicate: P) -> StorageResult<R>
 where
     T: FilterDsl<P> + HasTable<Table = T> + Table + 'static,
@@ -312,7 +312,7 @@ where
     generic_find_one_core::<T, _, _>(conn, predicate).await
 }
 
-#[instrument(level = "DEBUG", skip_all)]
+#[instrument(level = "DEBUG", skip_all, fields(table = std::any::type_name::<T>()))]
 pub async fn generic_find_one_optional<T, P, R>(
     conn: &PgPooledConn,
     predicate: P,
@@ -326,7 +326,7 @@ where
     to_optional(generic_find_one_core::<T, _, _>(conn, predicate).await)
 }
 
-#[instrument(level = "DEBUG", skip_all)]
+#[instrument(level = "DEBUG", skip_all, fields(table = std::any::type_name::<T>()))]
 pub async fn generic_filter<T, P, R>(
     conn: &PgPooledConn,
     predicate: P,
@@ -0,0 +1,35 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    incoming_webhook_event::{IncomingWebhookEvent, IncomingWebhookEventNew},
+    schema::incoming_webhook_event::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl IncomingWebhookEventNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<IncomingWebhookEvent> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl IncomingWebhookEvent {
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_connector_name_connector_event_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        connector_name: &str,
+        connector_event_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::connector_name.eq(connector_name.to_owned()))
+                .and(dsl::connector_event_id.eq(connector_event_id.to_owned())),
+        )
+        .await
+    }
+}
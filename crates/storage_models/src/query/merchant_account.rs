@@ -63,15 +63,6 @@ impl MerchantAccount {
         .await
     }
 
-    #[instrument(skip(conn))]
-    pub async fn find_by_api_key(conn: &PgPooledConn, api_key: &str) -> StorageResult<Self> {
-        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
-            conn,
-            dsl::api_key.eq(api_key.to_owned()),
-        )
-        .await
-    }
-
     #[instrument(skip_all)]
     pub async fn find_by_publishable_key(
         conn: &PgPooledConn,
@@ -83,4 +74,17 @@ impl MerchantAccount {
         )
         .await
     }
+
+    #[instrument(skip(conn))]
+    pub async fn find_by_parent_merchant_id(
+        conn: &PgPooledConn,
+        parent_merchant_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::parent_merchant_id.eq(parent_merchant_id.to_owned()),
+            None,
+        )
+        .await
+    }
 }
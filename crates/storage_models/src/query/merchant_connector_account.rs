@@ -56,16 +56,16 @@ impl MerchantConnectorAccount {
     }
 
     #[instrument(skip(conn))]
-    pub async fn find_by_merchant_id_connector(
+    pub async fn find_by_merchant_id_connector_label(
         conn: &PgPooledConn,
         merchant_id: &str,
-        connector: &str,
+        connector_label: &str,
     ) -> StorageResult<Self> {
         generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
             conn,
             dsl::merchant_id
                 .eq(merchant_id.to_owned())
-                .and(dsl::connector_name.eq(connector.to_owned())),
+                .and(dsl::connector_label.eq(connector_label.to_owned())),
         )
         .await
     }
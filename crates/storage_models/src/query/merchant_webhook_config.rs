@@ -0,0 +1,55 @@
+use diesel::{associations::HasTable, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    errors,
+    merchant_webhook_config::{
+        MerchantWebhookConfig, MerchantWebhookConfigNew, MerchantWebhookConfigUpdate,
+        MerchantWebhookConfigUpdateInternal,
+    },
+    schema::merchant_webhook_config::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl MerchantWebhookConfigNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<MerchantWebhookConfig> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl MerchantWebhookConfig {
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        merchant_webhook_config: MerchantWebhookConfigUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_by_id::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            self.id,
+            MerchantWebhookConfigUpdateInternal::from(merchant_webhook_config),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use async_bb8_diesel::AsyncConnection;
+use diesel::{
+    associations::HasTable,
+    pg::PgConnection,
+    result::{DatabaseErrorKind, Error as DieselError},
+    Connection, ExpressionMethods, QueryResult, RunQueryDsl,
+};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    errors,
+    outbox_event::{OutboxEvent, OutboxEventNew, OutboxEventUpdate, OutboxEventUpdateInternal},
+    schema::outbox_event::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl OutboxEventNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<OutboxEvent> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl OutboxEvent {
+    /// Fetches up to `limit` events which are yet to be published, in the order they were recorded.
+    #[instrument(skip(conn))]
+    pub async fn find_unpublished(conn: &PgPooledConn, limit: i64) -> StorageResult<Vec<Self>> {
+        let mut events = generics::generic_filter::<<Self as HasTable>::Table, _, Self>(
+            conn,
+            dsl::published_at.is_null(),
+            Some(limit),
+        )
+        .await?;
+        // perform ordering on the application level instead of database level
+        events.sort_by_key(|event| event.id);
+        Ok(events)
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn mark_published(conn: &PgPooledConn, ids: Vec<i32>) -> StorageResult<usize> {
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::id.eq_any(ids),
+            OutboxEventUpdateInternal::from(OutboxEventUpdate::Published),
+        )
+        .await
+    }
+}
+
+/// Applies a state change and records the outbox event announcing it in a single transaction, so
+/// that the event is recorded if and only if the state change is.
+///
+/// The outbox event is built from the result of the state change, as it is seen by the
+/// transaction.
+#[instrument(level = "DEBUG", skip_all)]
+pub(crate) async fn with_outbox_event<R, F, G>(
+    conn: &PgPooledConn,
+    state_change: F,
+    outbox_event: G,
+) -> StorageResult<R>
+where
+    F: FnOnce(&mut PgConnection) -> QueryResult<R> + Send + 'static,
+    G: FnOnce(&R) -> Result<OutboxEventNew, serde_json::Error> + Send + 'static,
+    R: Send + 'static,
+{
+    conn.run(move |conn| {
+        conn.transaction(|conn| {
+            let result = state_change(conn)?;
+            let outbox_event = outbox_event(&result)
+                .map_err(|error| DieselError::SerializationError(Box::new(error)))?;
+            diesel::insert_into(<OutboxEvent as HasTable>::table())
+                .values(outbox_event)
+                .execute(conn)?;
+            Ok(result)
+        })
+    })
+    .await
+    .map_err(|error| match error {
+        DieselError::NotFound => report!(errors::DatabaseError::NotFound),
+        DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, _) => {
+            report!(error).change_context(errors::DatabaseError::UniqueViolation)
+        }
+        error => report!(error).change_context(errors::DatabaseError::Others),
+    })
+    .attach_printable("Error while applying a state change along with its outbox event")
+}
@@ -1,6 +1,7 @@
 use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
 use error_stack::IntoReport;
 use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
 
 use super::generics;
 use crate::{
@@ -28,8 +29,8 @@ impl PaymentAttempt {
     ) -> StorageResult<Self> {
         match generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
             conn,
-            dsl::payment_id
-                .eq(self.payment_id.to_owned())
+            dsl::attempt_id
+                .eq(self.attempt_id.to_owned())
                 .and(dsl::merchant_id.eq(self.merchant_id.to_owned())),
             PaymentAttemptUpdateInternal::from(payment_attempt),
         )
@@ -45,19 +46,18 @@ impl PaymentAttempt {
         }
     }
 
+    /// Finds the latest attempt of the payment, a payment may have more than one attempt if it
+    /// was retried with another connector.
     #[instrument(skip(conn))]
     pub async fn find_by_payment_id_merchant_id(
         conn: &PgPooledConn,
         payment_id: &str,
         merchant_id: &str,
     ) -> StorageResult<Self> {
-        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
-            conn,
-            dsl::merchant_id
-                .eq(merchant_id.to_owned())
-                .and(dsl::payment_id.eq(payment_id.to_owned())),
-        )
-        .await
+        Self::find_optional_by_payment_id_merchant_id(conn, payment_id, merchant_id)
+            .await?
+            .ok_or(errors::DatabaseError::NotFound)
+            .into_report()
     }
 
     #[instrument(skip(conn))]
@@ -66,13 +66,20 @@ impl PaymentAttempt {
         payment_id: &str,
         merchant_id: &str,
     ) -> StorageResult<Option<Self>> {
-        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
-            conn,
-            dsl::merchant_id
-                .eq(merchant_id.to_owned())
-                .and(dsl::payment_id.eq(payment_id.to_owned())),
+        // perform ordering on the application level instead of database level, the attempts are
+        // ordered by their IDs as well, as the attempts of a failover can share a creation time
+        Ok(
+            generics::generic_filter::<<Self as HasTable>::Table, _, Self>(
+                conn,
+                dsl::merchant_id
+                    .eq(merchant_id.to_owned())
+                    .and(dsl::payment_id.eq(payment_id.to_owned())),
+                None,
+            )
+            .await?
+            .into_iter()
+            .max_by_key(|payment_attempt| (payment_attempt.created_at, payment_attempt.id)),
         )
-        .await
     }
 
     #[instrument(skip(conn))]
@@ -103,7 +110,10 @@ impl PaymentAttempt {
             dsl::payment_id
                 .eq(payment_id.to_owned())
                 .and(dsl::merchant_id.eq(merchant_id.to_owned()))
-                .and(dsl::status.eq(enums::AttemptStatus::Charged)),
+                .and(dsl::status.eq_any(vec![
+                    enums::AttemptStatus::Charged,
+                    enums::AttemptStatus::PartialCharged,
+                ])),
             None,
         )
         .await?
@@ -146,4 +156,37 @@ impl PaymentAttempt {
         )
         .await
     }
+
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_payment_ids(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        payment_ids: Vec<String>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, Self>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_id.eq_any(payment_ids)),
+            None,
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn find_by_statuses_modified_before(
+        conn: &PgPooledConn,
+        statuses: Vec<enums::AttemptStatus>,
+        modified_before: PrimitiveDateTime,
+        limit: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, Self>(
+            conn,
+            dsl::status
+                .eq_any(statuses)
+                .and(dsl::modified_at.lt(modified_before)),
+            limit,
+        )
+        .await
+    }
 }
@@ -1,9 +1,13 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use diesel::{
+    associations::HasTable, BoolExpressionMethods, ExpressionMethods, QueryDsl, RunQueryDsl,
+};
 use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
 
-use super::generics;
+use super::{generics, outbox_event};
 use crate::{
     errors,
+    outbox_event::OutboxEventNew,
     payment_intent::{
         PaymentIntent, PaymentIntentNew, PaymentIntentUpdate, PaymentIntentUpdateInternal,
     },
@@ -44,6 +48,27 @@ impl PaymentIntent {
         }
     }
 
+    /// Updates the payment intent and records the `payment.succeeded` event in the outbox, in a
+    /// single transaction.
+    #[instrument(skip(conn))]
+    pub async fn update_with_payment_succeeded_event(
+        self,
+        conn: &PgPooledConn,
+        payment_intent: PaymentIntentUpdate,
+    ) -> StorageResult<Self> {
+        let update = PaymentIntentUpdateInternal::from(payment_intent);
+        outbox_event::with_outbox_event(
+            conn,
+            move |conn| {
+                diesel::update(<Self as HasTable>::table().find(self.id))
+                    .set(update)
+                    .get_result(conn)
+            },
+            OutboxEventNew::payment_succeeded,
+        )
+        .await
+    }
+
     #[instrument(skip(conn))]
     pub async fn find_by_payment_id_merchant_id(
         conn: &PgPooledConn,
@@ -73,4 +98,48 @@ impl PaymentIntent {
         )
         .await
     }
+
+    /// Removes the reference to the customer, and the free-form fields which may contain their
+    /// personal data, from all the payment intents of the customer.
+    #[instrument(skip(conn))]
+    pub async fn anonymize_by_merchant_id_customer_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        customer_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.eq(customer_id.to_owned())),
+            (
+                dsl::customer_id.eq(None::<String>),
+                dsl::description.eq(None::<String>),
+                dsl::metadata.eq(None::<serde_json::Value>),
+                dsl::modified_at.eq(common_utils::date_time::now()),
+            ),
+        )
+        .await
+    }
+
+    /// Finds the payment intents created by the merchant in the time range `[start, end)`, returning at
+    /// most `limit` of them.
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_created_between(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        start: PrimitiveDateTime,
+        end: PrimitiveDateTime,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::created_at.ge(start))
+                .and(dsl::created_at.lt(end)),
+            Some(limit),
+        )
+        .await
+    }
 }
@@ -0,0 +1,57 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    errors,
+    payout_attempt::{
+        PayoutAttempt, PayoutAttemptNew, PayoutAttemptUpdate, PayoutAttemptUpdateInternal,
+    },
+    schema::payout_attempts::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl PayoutAttemptNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PayoutAttempt> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl PayoutAttempt {
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_payout_attempt_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        payout_attempt_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payout_attempt_id.eq(payout_attempt_id.to_owned())),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        payout_attempt: PayoutAttemptUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_by_id::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            self.id,
+            PayoutAttemptUpdateInternal::from(payout_attempt),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
@@ -122,4 +122,21 @@ impl ProcessTracker {
         )
         .await
     }
+
+    #[instrument(skip(conn))]
+    pub async fn find_processes_by_status_business_status(
+        conn: &PgPooledConn,
+        status: enums::ProcessTrackerStatus,
+        business_status: &str,
+        limit: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::status
+                .eq(status)
+                .and(dsl::business_status.eq(business_status.to_owned())),
+            limit,
+        )
+        .await
+    }
 }
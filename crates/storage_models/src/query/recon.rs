@@ -0,0 +1,88 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    errors,
+    recon::{
+        ReconMismatch, ReconMismatchNew, ReconReport, ReconReportNew, ReconReportUpdate,
+        ReconReportUpdateInternal,
+    },
+    schema::{recon_mismatch, recon_report::dsl},
+    PgPooledConn, StorageResult,
+};
+
+impl ReconReportNew {
+    // The report holds the uploaded file, which is not worth recording
+    #[instrument(skip_all)]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ReconReport> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ReconReport {
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_report_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        report_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::report_id.eq(report_id.to_owned())),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn, self))]
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        recon_report: ReconReportUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_by_id::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            self.id,
+            ReconReportUpdateInternal::from(recon_report),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
+
+impl ReconMismatchNew {
+    #[instrument(skip(conn))]
+    pub async fn batch_insert(
+        recon_mismatches: Vec<Self>,
+        conn: &PgPooledConn,
+    ) -> StorageResult<()> {
+        generics::generic_insert::<_, _, ReconMismatch>(conn, recon_mismatches).await?;
+        Ok(())
+    }
+}
+
+impl ReconMismatch {
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_report_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        report_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _>(
+            conn,
+            recon_mismatch::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(recon_mismatch::dsl::report_id.eq(report_id.to_owned())),
+            None,
+        )
+        .await
+    }
+}
@@ -1,9 +1,11 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, RunQueryDsl};
 use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
 
-use super::generics;
+use super::{generics, outbox_event};
 use crate::{
     errors,
+    outbox_event::OutboxEventNew,
     refund::{Refund, RefundNew, RefundUpdate, RefundUpdateInternal},
     schema::refund::dsl,
     PgPooledConn, StorageResult,
@@ -14,6 +16,25 @@ impl RefundNew {
     pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<Refund> {
         generics::generic_insert(conn, self).await
     }
+
+    /// Inserts the refund and records the `refund.created` event in the outbox, in a single
+    /// transaction.
+    #[instrument(skip(conn))]
+    pub async fn insert_with_refund_created_event(
+        self,
+        conn: &PgPooledConn,
+    ) -> StorageResult<Refund> {
+        outbox_event::with_outbox_event(
+            conn,
+            move |conn| {
+                diesel::insert_into(<Refund as HasTable>::table())
+                    .values(self)
+                    .get_result(conn)
+            },
+            OutboxEventNew::refund_created,
+        )
+        .await
+    }
 }
 
 impl Refund {
@@ -50,6 +71,23 @@ impl Refund {
         .await
     }
 
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_connector_refund_id_connector(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        connector_refund_id: &str,
+        connector: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::connector_refund_id.eq(connector_refund_id.to_owned()))
+                .and(dsl::connector.eq(connector.to_owned())),
+        )
+        .await
+    }
+
     #[instrument(skip(conn))]
     pub async fn find_by_internal_reference_id_merchant_id(
         conn: &PgPooledConn,
@@ -96,4 +134,25 @@ impl Refund {
         )
         .await
     }
+
+    /// Finds the refunds created by the merchant in the time range `[start, end)`, returning at
+    /// most `limit` of them.
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_created_between(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        start: PrimitiveDateTime,
+        end: PrimitiveDateTime,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::created_at.ge(start))
+                .and(dsl::created_at.lt(end)),
+            Some(limit),
+        )
+        .await
+    }
 }
@@ -0,0 +1,34 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    schema::webhook_delivery_attempts::dsl,
+    webhook_delivery_attempt::{WebhookDeliveryAttempt, WebhookDeliveryAttemptNew},
+    PgPooledConn, StorageResult,
+};
+
+impl WebhookDeliveryAttemptNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<WebhookDeliveryAttempt> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl WebhookDeliveryAttempt {
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_event_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        event_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::event_id.eq(event_id.to_owned())),
+            None,
+        )
+        .await
+    }
+}
@@ -0,0 +1,141 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    schema::{recon_mismatch, recon_report},
+};
+
+/// A settlement report of a connector uploaded by a merchant, whose entries are reconciled with
+/// the payments and refunds of the merchant in the background.
+#[derive(Clone, Debug, Eq, Identifiable, Queryable, PartialEq)]
+#[diesel(table_name = recon_report)]
+pub struct ReconReport {
+    pub id: i32,
+    pub report_id: String,
+    pub merchant_id: String,
+    pub connector: String,
+    pub status: storage_enums::ReconReportStatus,
+    /// The settlement report as uploaded
+    pub file_content: String,
+    pub total_entries: Option<i32>,
+    pub matched_entries: Option<i32>,
+    pub mismatched_entries: Option<i32>,
+    /// The reason the report could not be reconciled
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = recon_report)]
+pub struct ReconReportNew {
+    pub report_id: String,
+    pub merchant_id: String,
+    pub connector: String,
+    pub status: storage_enums::ReconReportStatus,
+    pub file_content: String,
+}
+
+#[derive(Debug, Clone)]
+pub enum ReconReportUpdate {
+    CompletedUpdate {
+        total_entries: i32,
+        matched_entries: i32,
+        mismatched_entries: i32,
+    },
+    FailedUpdate {
+        error_message: String,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = recon_report)]
+pub struct ReconReportUpdateInternal {
+    status: Option<storage_enums::ReconReportStatus>,
+    total_entries: Option<i32>,
+    matched_entries: Option<i32>,
+    mismatched_entries: Option<i32>,
+    error_message: Option<String>,
+    modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<ReconReportUpdate> for ReconReportUpdateInternal {
+    fn from(recon_report_update: ReconReportUpdate) -> Self {
+        match recon_report_update {
+            ReconReportUpdate::CompletedUpdate {
+                total_entries,
+                matched_entries,
+                mismatched_entries,
+            } => Self {
+                status: Some(storage_enums::ReconReportStatus::Completed),
+                total_entries: Some(total_entries),
+                matched_entries: Some(matched_entries),
+                mismatched_entries: Some(mismatched_entries),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            ReconReportUpdate::FailedUpdate { error_message } => Self {
+                status: Some(storage_enums::ReconReportStatus::Failed),
+                error_message: Some(error_message),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl ReconReportUpdate {
+    pub fn apply_changeset(self, source: ReconReport) -> ReconReport {
+        let update_internal: ReconReportUpdateInternal = self.into();
+        ReconReport {
+            status: update_internal.status.unwrap_or(source.status),
+            total_entries: update_internal.total_entries.or(source.total_entries),
+            matched_entries: update_internal.matched_entries.or(source.matched_entries),
+            mismatched_entries: update_internal
+                .mismatched_entries
+                .or(source.mismatched_entries),
+            error_message: update_internal.error_message.or(source.error_message),
+            modified_at: common_utils::date_time::now(),
+            ..source
+        }
+    }
+}
+
+/// An entry of a settlement report which does not match the payment or refund it settles.
+#[derive(Clone, Debug, Eq, Identifiable, Queryable, PartialEq)]
+#[diesel(table_name = recon_mismatch)]
+pub struct ReconMismatch {
+    pub id: i32,
+    pub report_id: String,
+    pub merchant_id: String,
+    pub entry_type: storage_enums::ReconEntryType,
+    pub connector_transaction_id: String,
+    /// The amount settled by the connector
+    pub amount: i64,
+    /// The currency in which the connector settled the amount
+    pub currency: storage_enums::Currency,
+    pub reason: storage_enums::ReconMismatchReason,
+    /// The amount which was expected to be settled, if the payment or refund was found
+    pub expected_amount: Option<i64>,
+    pub expected_currency: Option<storage_enums::Currency>,
+    pub payment_id: Option<String>,
+    pub refund_id: Option<String>,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = recon_mismatch)]
+pub struct ReconMismatchNew {
+    pub report_id: String,
+    pub merchant_id: String,
+    pub entry_type: storage_enums::ReconEntryType,
+    pub connector_transaction_id: String,
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub reason: storage_enums::ReconMismatchReason,
+    pub expected_amount: Option<i64>,
+    pub expected_currency: Option<storage_enums::Currency>,
+    pub payment_id: Option<String>,
+    pub refund_id: Option<String>,
+}
@@ -32,6 +32,8 @@ pub struct Refund {
     pub description: Option<String>,
     pub attempt_id: String,
     pub refund_reason: Option<String>,
+    pub alternate_connector_label: Option<String>,
+    pub reconciliation_required: bool,
 }
 
 #[derive(
@@ -70,6 +72,8 @@ pub struct RefundNew {
     pub description: Option<String>,
     pub attempt_id: String,
     pub refund_reason: Option<String>,
+    pub alternate_connector_label: Option<String>,
+    pub reconciliation_required: bool,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
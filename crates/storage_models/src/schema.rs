@@ -9,14 +9,14 @@ diesel::table! {
         address_id -> Varchar,
         city -> Nullable<Varchar>,
         country -> Nullable<Varchar>,
-        line1 -> Nullable<Varchar>,
-        line2 -> Nullable<Varchar>,
-        line3 -> Nullable<Varchar>,
-        state -> Nullable<Varchar>,
-        zip -> Nullable<Varchar>,
-        first_name -> Nullable<Varchar>,
-        last_name -> Nullable<Varchar>,
-        phone_number -> Nullable<Varchar>,
+        line1 -> Nullable<Bytea>,
+        line2 -> Nullable<Bytea>,
+        line3 -> Nullable<Bytea>,
+        state -> Nullable<Bytea>,
+        zip -> Nullable<Bytea>,
+        first_name -> Nullable<Bytea>,
+        last_name -> Nullable<Bytea>,
+        phone_number -> Nullable<Bytea>,
         country_code -> Nullable<Varchar>,
         created_at -> Timestamp,
         modified_at -> Timestamp,
@@ -25,6 +25,60 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    api_keys (key_id) {
+        key_id -> Varchar,
+        merchant_id -> Varchar,
+        name -> Varchar,
+        description -> Nullable<Varchar>,
+        hashed_api_key -> Varchar,
+        prefix -> Varchar,
+        created_at -> Timestamp,
+        expires_at -> Nullable<Timestamp>,
+        last_used -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    blocklist (id) {
+        id -> Int4,
+        entry_id -> Varchar,
+        merchant_id -> Varchar,
+        kind -> BlocklistKind,
+        data -> Varchar,
+        expires_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    captures (id) {
+        id -> Int4,
+        capture_id -> Varchar,
+        payment_id -> Varchar,
+        merchant_id -> Varchar,
+        attempt_id -> Varchar,
+        status -> CaptureStatus,
+        amount -> Int8,
+        currency -> Currency,
+        connector -> Varchar,
+        connector_capture_id -> Nullable<Varchar>,
+        error_message -> Nullable<Text>,
+        error_code -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -54,6 +108,20 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    customer_redaction_log (id) {
+        id -> Int4,
+        customer_id -> Varchar,
+        merchant_id -> Varchar,
+        payment_methods_deleted -> Int4,
+        payment_intents_anonymized -> Int4,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -63,8 +131,8 @@ diesel::table! {
         customer_id -> Varchar,
         merchant_id -> Varchar,
         name -> Nullable<Varchar>,
-        email -> Nullable<Varchar>,
-        phone -> Nullable<Varchar>,
+        email -> Nullable<Bytea>,
+        phone -> Nullable<Bytea>,
         phone_country_code -> Nullable<Varchar>,
         description -> Nullable<Varchar>,
         created_at -> Timestamp,
@@ -72,6 +140,32 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    dispute (id) {
+        id -> Int4,
+        dispute_id -> Varchar,
+        amount -> Int8,
+        currency -> Varchar,
+        dispute_status -> DisputeStatus,
+        payment_id -> Varchar,
+        attempt_id -> Varchar,
+        merchant_id -> Varchar,
+        connector -> Varchar,
+        connector_status -> Varchar,
+        connector_dispute_id -> Varchar,
+        connector_reason -> Nullable<Varchar>,
+        connector_reason_code -> Nullable<Varchar>,
+        challenge_required_by -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+        evidence -> Nullable<Jsonb>,
+        challenge_status -> Nullable<DisputeChallengeStatus>,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -86,6 +180,44 @@ diesel::table! {
         primary_object_id -> Varchar,
         primary_object_type -> EventObjectType,
         created_at -> Timestamp,
+        merchant_id -> Nullable<Varchar>,
+        content -> Nullable<Json>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    export (id) {
+        id -> Int4,
+        export_id -> Varchar,
+        merchant_id -> Varchar,
+        object -> ExportObject,
+        format -> ExportFormat,
+        start_time -> Timestamp,
+        end_time -> Timestamp,
+        status -> ExportStatus,
+        row_count -> Nullable<Int4>,
+        file_content -> Nullable<Text>,
+        error_message -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+        batch_id -> Nullable<Varchar>,
+        anonymized -> Bool,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    incoming_webhook_event (id) {
+        id -> Int4,
+        merchant_id -> Varchar,
+        connector_name -> Varchar,
+        connector_event_id -> Varchar,
+        created_at -> Timestamp,
     }
 }
 
@@ -160,6 +292,9 @@ diesel::table! {
         locker_id -> Nullable<Varchar>,
         metadata -> Nullable<Jsonb>,
         routing_algorithm -> Nullable<Json>,
+        webhook_signing_secret -> Nullable<Varchar>,
+        outgoing_webhook_custom_headers -> Nullable<Bytea>,
+        surcharge_config -> Nullable<Json>,
     }
 }
 
@@ -178,6 +313,51 @@ diesel::table! {
         payment_methods_enabled -> Nullable<Array<Nullable<Json>>>,
         connector_type -> ConnectorType,
         metadata -> Nullable<Jsonb>,
+        business_country -> Nullable<Varchar>,
+        business_label -> Nullable<Varchar>,
+        connector_label -> Varchar,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    merchant_key_store (merchant_id) {
+        merchant_id -> Varchar,
+        key -> Bytea,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    merchant_webhook_config (id) {
+        id -> Int4,
+        merchant_id -> Varchar,
+        enabled -> Bool,
+        webhook_url -> Nullable<Varchar>,
+        enabled_events -> Nullable<Array<Nullable<EventType>>>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    outbox_event (id) {
+        id -> Int4,
+        event_id -> Varchar,
+        event_type -> OutboxEventType,
+        merchant_id -> Varchar,
+        object_id -> Varchar,
+        payload -> Jsonb,
+        created_at -> Timestamp,
+        published_at -> Nullable<Timestamp>,
     }
 }
 
@@ -218,6 +398,51 @@ diesel::table! {
         error_code -> Nullable<Varchar>,
         payment_token -> Nullable<Varchar>,
         connector_metadata -> Nullable<Jsonb>,
+        payment_checks -> Nullable<Jsonb>,
+        three_ds_authentication_data -> Nullable<Jsonb>,
+        connector_api_log -> Nullable<Jsonb>,
+        frm_data -> Nullable<Jsonb>,
+        settlement_currency -> Nullable<Currency>,
+        settlement_amount -> Nullable<Int8>,
+        fx_rate -> Nullable<Varchar>,
+        test_mode -> Nullable<Bool>,
+        card_bin -> Nullable<Varchar>,
+        card_last4 -> Nullable<Varchar>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_audit_log (id) {
+        id -> Int4,
+        payment_id -> Varchar,
+        merchant_id -> Varchar,
+        previous_status -> IntentStatus,
+        status -> IntentStatus,
+        actor -> AuditActor,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_cost (id) {
+        id -> Int4,
+        cost_id -> Varchar,
+        merchant_id -> Varchar,
+        payment_id -> Varchar,
+        attempt_id -> Varchar,
+        dispute_id -> Nullable<Varchar>,
+        connector -> Varchar,
+        cost_type -> PaymentCostType,
+        amount -> Int8,
+        currency -> Varchar,
+        connector_reference -> Varchar,
+        created_at -> Timestamp,
     }
 }
 
@@ -248,6 +473,26 @@ diesel::table! {
         setup_future_usage -> Nullable<FutureUsage>,
         off_session -> Nullable<Bool>,
         client_secret -> Nullable<Varchar>,
+        client_secret_expiry -> Nullable<Timestamp>,
+        business_country -> Nullable<Varchar>,
+        business_label -> Nullable<Varchar>,
+        active_attempt_id -> Nullable<Varchar>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_link (id) {
+        id -> Int4,
+        payment_link_id -> Varchar,
+        payment_id -> Varchar,
+        merchant_id -> Varchar,
+        status -> PaymentLinkStatus,
+        expires_at -> Timestamp,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
     }
 }
 
@@ -280,6 +525,46 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payout_attempts (id) {
+        id -> Int4,
+        payout_attempt_id -> Varchar,
+        payout_id -> Varchar,
+        merchant_id -> Varchar,
+        connector -> Varchar,
+        connector_payout_id -> Nullable<Varchar>,
+        status -> PayoutStatus,
+        error_code -> Nullable<Varchar>,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payouts (id) {
+        id -> Int4,
+        payout_id -> Varchar,
+        merchant_id -> Varchar,
+        customer_id -> Nullable<Varchar>,
+        amount -> Int8,
+        currency -> Currency,
+        payout_type -> PayoutType,
+        status -> PayoutStatus,
+        connector -> Varchar,
+        description -> Nullable<Varchar>,
+        metadata -> Nullable<Jsonb>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -301,6 +586,47 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    recon_mismatch (id) {
+        id -> Int4,
+        report_id -> Varchar,
+        merchant_id -> Varchar,
+        entry_type -> ReconEntryType,
+        connector_transaction_id -> Varchar,
+        amount -> Int8,
+        currency -> Currency,
+        reason -> ReconMismatchReason,
+        expected_amount -> Nullable<Int8>,
+        expected_currency -> Nullable<Currency>,
+        payment_id -> Nullable<Varchar>,
+        refund_id -> Nullable<Varchar>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    recon_report (id) {
+        id -> Int4,
+        report_id -> Varchar,
+        merchant_id -> Varchar,
+        connector -> Varchar,
+        status -> ReconReportStatus,
+        file_content -> Text,
+        total_entries -> Nullable<Int4>,
+        matched_entries -> Nullable<Int4>,
+        mismatched_entries -> Nullable<Int4>,
+        error_message -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -329,6 +655,8 @@ diesel::table! {
         description -> Nullable<Varchar>,
         attempt_id -> Varchar,
         refund_reason -> Nullable<Varchar>,
+        alternate_connector_label -> Nullable<Varchar>,
+        reconciliation_required -> Bool,
     }
 }
 
@@ -344,20 +672,54 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    webhook_delivery_attempts (id) {
+        id -> Int4,
+        event_id -> Varchar,
+        merchant_id -> Varchar,
+        attempt_number -> Int4,
+        response_code -> Nullable<Int4>,
+        latency_ms -> Int8,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::allow_tables_to_appear_in_same_query!(
     address,
+    api_keys,
+    blocklist,
+    captures,
     configs,
     connector_response,
+    customer_redaction_log,
     customers,
+    dispute,
     events,
+    export,
+    incoming_webhook_event,
     locker_mock_up,
     mandate,
     merchant_account,
     merchant_connector_account,
+    merchant_key_store,
+    merchant_webhook_config,
+    outbox_event,
     payment_attempt,
+    payment_audit_log,
+    payment_cost,
     payment_intent,
+    payment_link,
     payment_methods,
+    payout_attempts,
+    payouts,
     process_tracker,
+    recon_mismatch,
+    recon_report,
     refund,
     reverse_lookup,
+    webhook_delivery_attempts,
 );
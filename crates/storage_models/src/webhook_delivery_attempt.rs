@@ -0,0 +1,29 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::schema::webhook_delivery_attempts;
+
+#[derive(Clone, Debug, Eq, Identifiable, Queryable, PartialEq, serde::Serialize)]
+#[diesel(table_name = webhook_delivery_attempts)]
+pub struct WebhookDeliveryAttempt {
+    pub id: i32,
+    pub event_id: String,
+    pub merchant_id: String,
+    pub attempt_number: i32,
+    /// The HTTP status code of the merchant's response, if the merchant could be reached
+    pub response_code: Option<i32>,
+    pub latency_ms: i64,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = webhook_delivery_attempts)]
+pub struct WebhookDeliveryAttemptNew {
+    pub event_id: String,
+    pub merchant_id: String,
+    pub attempt_number: i32,
+    pub response_code: Option<i32>,
+    pub latency_ms: i64,
+    pub error_message: Option<String>,
+}
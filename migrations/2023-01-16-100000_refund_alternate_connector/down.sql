@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE refund DROP COLUMN alternate_connector_label;
+ALTER TABLE refund DROP COLUMN reconciliation_required;
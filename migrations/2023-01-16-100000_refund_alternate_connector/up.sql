@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE refund ADD COLUMN alternate_connector_label VARCHAR(255) DEFAULT NULL;
+ALTER TABLE refund ADD COLUMN reconciliation_required BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,22 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE dispute;
+
+DROP TYPE "DisputeStatus";
+
+DELETE FROM pg_enum
+WHERE enumlabel = 'disputes'
+AND enumtypid = (
+  SELECT oid FROM pg_type WHERE typname = 'EventClass'
+);
+
+DELETE FROM pg_enum
+WHERE enumlabel = 'dispute_details'
+AND enumtypid = (
+  SELECT oid FROM pg_type WHERE typname = 'EventObjectType'
+);
+
+DELETE FROM pg_enum
+WHERE enumlabel IN ('dispute_opened', 'dispute_challenged', 'dispute_won', 'dispute_lost')
+AND enumtypid = (
+  SELECT oid FROM pg_type WHERE typname = 'EventType'
+);
@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TABLE events
+ADD COLUMN merchant_id VARCHAR(64),
+ADD COLUMN content JSON;
+
+CREATE INDEX events_merchant_id_created_at_index ON events (merchant_id, created_at);
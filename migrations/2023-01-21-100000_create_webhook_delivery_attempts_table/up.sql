@@ -0,0 +1,13 @@
+-- Your SQL goes here
+CREATE TABLE webhook_delivery_attempts (
+    id SERIAL PRIMARY KEY,
+    event_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    attempt_number INTEGER NOT NULL,
+    response_code INTEGER,
+    latency_ms BIGINT NOT NULL,
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX webhook_delivery_attempts_merchant_id_event_id_index ON webhook_delivery_attempts (merchant_id, event_id);
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+DROP INDEX payment_attempt_payment_id_merchant_id_index;
+CREATE INDEX payment_attempt_payment_id_merchant_id_index ON payment_attempt (payment_id, merchant_id);
+CREATE UNIQUE INDEX payment_attempt_merchant_id_attempt_id_index ON payment_attempt (merchant_id, attempt_id);
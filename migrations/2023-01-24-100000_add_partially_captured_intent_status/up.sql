@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TYPE "IntentStatus" ADD VALUE 'partially_captured';
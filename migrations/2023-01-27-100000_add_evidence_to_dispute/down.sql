@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE dispute DROP COLUMN challenge_status;
+
+ALTER TABLE dispute DROP COLUMN evidence;
+
+DROP TYPE "DisputeChallengeStatus";
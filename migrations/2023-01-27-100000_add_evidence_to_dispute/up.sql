@@ -0,0 +1,6 @@
+-- Your SQL goes here
+CREATE TYPE "DisputeChallengeStatus" AS ENUM ('submitted', 'under_review', 'won', 'lost');
+
+ALTER TABLE dispute ADD COLUMN evidence JSONB;
+
+ALTER TABLE dispute ADD COLUMN challenge_status "DisputeChallengeStatus";
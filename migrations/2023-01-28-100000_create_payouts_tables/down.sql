@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE payout_attempts;
+
+DROP TABLE payouts;
+
+DROP TYPE "PayoutType";
+
+DROP TYPE "PayoutStatus";
@@ -0,0 +1,47 @@
+-- Your SQL goes here
+CREATE TYPE "PayoutStatus" AS ENUM (
+    'requires_confirmation',
+    'requires_fulfillment',
+    'pending',
+    'success',
+    'failed',
+    'cancelled'
+);
+
+CREATE TYPE "PayoutType" AS ENUM ('bank');
+
+CREATE TABLE payouts (
+    id SERIAL PRIMARY KEY,
+    payout_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64),
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    payout_type "PayoutType" NOT NULL,
+    status "PayoutStatus" NOT NULL,
+    connector VARCHAR(64) NOT NULL,
+    description VARCHAR(255),
+    metadata JSONB,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX payouts_payout_id_merchant_id_index ON payouts (payout_id, merchant_id);
+
+CREATE TABLE payout_attempts (
+    id SERIAL PRIMARY KEY,
+    payout_attempt_id VARCHAR(64) NOT NULL,
+    payout_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    connector VARCHAR(64) NOT NULL,
+    connector_payout_id VARCHAR(255),
+    status "PayoutStatus" NOT NULL,
+    error_code VARCHAR(255),
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX payout_attempts_payout_attempt_id_merchant_id_index ON payout_attempts (payout_attempt_id, merchant_id);
+
+CREATE INDEX payout_attempts_payout_id_merchant_id_index ON payout_attempts (payout_id, merchant_id);
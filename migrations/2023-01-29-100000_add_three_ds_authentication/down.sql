@@ -0,0 +1,7 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_attempt DROP COLUMN three_ds_authentication_data;
+DELETE FROM pg_enum
+WHERE enumlabel = 'authentication_processor'
+AND enumtypid = (
+  SELECT oid FROM pg_type WHERE typname = 'ConnectorType'
+);
@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TYPE "ConnectorType" ADD VALUE 'authentication_processor';
+ALTER TABLE payment_attempt ADD COLUMN three_ds_authentication_data JSONB;
@@ -0,0 +1,17 @@
+-- Your SQL goes here
+CREATE TYPE "PaymentLinkStatus" AS ENUM ('active', 'completed', 'expired');
+
+CREATE TABLE payment_link (
+    id SERIAL PRIMARY KEY,
+    payment_link_id VARCHAR(64) NOT NULL,
+    payment_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    status "PaymentLinkStatus" NOT NULL,
+    expires_at TIMESTAMP NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX payment_link_payment_link_id_merchant_id_index ON payment_link (payment_link_id, merchant_id);
+
+CREATE INDEX payment_link_payment_id_merchant_id_index ON payment_link (payment_id, merchant_id);
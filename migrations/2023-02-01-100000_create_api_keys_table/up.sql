@@ -0,0 +1,30 @@
+-- Your SQL goes here
+CREATE TABLE api_keys (
+    key_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    name VARCHAR(64) NOT NULL,
+    description VARCHAR(256),
+    hashed_api_key VARCHAR(128) NOT NULL,
+    prefix VARCHAR(16) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    expires_at TIMESTAMP,
+    last_used TIMESTAMP
+);
+
+CREATE UNIQUE INDEX api_keys_hashed_api_key_index ON api_keys (hashed_api_key);
+
+CREATE INDEX api_keys_merchant_id_index ON api_keys (merchant_id);
+
+-- Move the existing API keys of merchant accounts to the new table, storing only their hashes
+INSERT INTO api_keys (key_id, merchant_id, name, hashed_api_key, prefix)
+SELECT
+    'key_' || substr(md5(random()::TEXT), 1, 20),
+    merchant_id,
+    'Default API key',
+    encode(sha256(convert_to(api_key, 'UTF8')), 'hex'),
+    left(api_key, 12)
+FROM merchant_account
+WHERE api_key IS NOT NULL;
+
+-- The plaintext API keys are kept until the `clear_merchant_account_api_key` migration, so that
+-- this migration can still be reverted
@@ -0,0 +1,13 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_intent
+DROP COLUMN business_country,
+DROP COLUMN business_label;
+
+DROP INDEX merchant_connector_account_merchant_id_connector_label_index;
+
+CREATE UNIQUE INDEX merchant_connector_account_merchant_id_connector_name_index ON merchant_connector_account (merchant_id, connector_name);
+
+ALTER TABLE merchant_connector_account
+DROP COLUMN business_country,
+DROP COLUMN business_label,
+DROP COLUMN connector_label;
@@ -0,0 +1,17 @@
+-- Your SQL goes here
+ALTER TABLE merchant_connector_account
+ADD COLUMN business_country VARCHAR(2),
+ADD COLUMN business_label VARCHAR(64),
+ADD COLUMN connector_label VARCHAR(255);
+
+UPDATE merchant_connector_account SET connector_label = connector_name;
+
+ALTER TABLE merchant_connector_account ALTER COLUMN connector_label SET NOT NULL;
+
+DROP INDEX merchant_connector_account_merchant_id_connector_name_index;
+
+CREATE UNIQUE INDEX merchant_connector_account_merchant_id_connector_label_index ON merchant_connector_account (merchant_id, connector_label);
+
+ALTER TABLE payment_intent
+ADD COLUMN business_country VARCHAR(2),
+ADD COLUMN business_label VARCHAR(64);
@@ -0,0 +1,10 @@
+-- Your SQL goes here
+CREATE TABLE incoming_webhook_event (
+    id SERIAL PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    connector_name VARCHAR(64) NOT NULL,
+    connector_event_id VARCHAR(255) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX incoming_webhook_event_merchant_id_connector_name_connector_event_id_index ON incoming_webhook_event (merchant_id, connector_name, connector_event_id);
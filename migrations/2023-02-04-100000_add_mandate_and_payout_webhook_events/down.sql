@@ -0,0 +1,18 @@
+-- This file should undo anything in `up.sql`
+DELETE FROM pg_enum
+WHERE enumlabel IN ('mandates', 'payouts')
+AND enumtypid = (
+  SELECT oid FROM pg_type WHERE typname = 'EventClass'
+);
+
+DELETE FROM pg_enum
+WHERE enumlabel IN ('mandate_details', 'payout_details')
+AND enumtypid = (
+  SELECT oid FROM pg_type WHERE typname = 'EventObjectType'
+);
+
+DELETE FROM pg_enum
+WHERE enumlabel IN ('mandate_active', 'mandate_revoked', 'payout_succeeded', 'payout_failed', 'payout_cancelled')
+AND enumtypid = (
+  SELECT oid FROM pg_type WHERE typname = 'EventType'
+);
@@ -0,0 +1,12 @@
+-- Your SQL goes here
+ALTER TYPE "EventClass" ADD VALUE 'mandates';
+ALTER TYPE "EventClass" ADD VALUE 'payouts';
+
+ALTER TYPE "EventObjectType" ADD VALUE 'mandate_details';
+ALTER TYPE "EventObjectType" ADD VALUE 'payout_details';
+
+ALTER TYPE "EventType" ADD VALUE 'mandate_active';
+ALTER TYPE "EventType" ADD VALUE 'mandate_revoked';
+ALTER TYPE "EventType" ADD VALUE 'payout_succeeded';
+ALTER TYPE "EventType" ADD VALUE 'payout_failed';
+ALTER TYPE "EventType" ADD VALUE 'payout_cancelled';
@@ -0,0 +1,12 @@
+-- Your SQL goes here
+CREATE TABLE merchant_webhook_config (
+    id SERIAL PRIMARY KEY,
+    merchant_id VARCHAR(255) NOT NULL,
+    enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    webhook_url VARCHAR(255),
+    enabled_events "EventType" [ ],
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX merchant_webhook_config_merchant_id_index ON merchant_webhook_config (merchant_id);
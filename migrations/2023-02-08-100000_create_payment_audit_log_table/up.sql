@@ -0,0 +1,14 @@
+-- Your SQL goes here
+CREATE TYPE "AuditActor" AS ENUM ('api', 'webhook', 'scheduler');
+
+CREATE TABLE payment_audit_log (
+    id SERIAL PRIMARY KEY,
+    payment_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    previous_status "IntentStatus" NOT NULL,
+    status "IntentStatus" NOT NULL,
+    actor "AuditActor" NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX payment_audit_log_merchant_id_payment_id_index ON payment_audit_log (merchant_id, payment_id);
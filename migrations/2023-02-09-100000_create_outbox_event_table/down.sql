@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE outbox_event;
+
+DROP TYPE "OutboxEventType";
@@ -0,0 +1,17 @@
+-- Your SQL goes here
+CREATE TYPE "OutboxEventType" AS ENUM ('payment.succeeded', 'refund.created', 'dispute.opened');
+
+CREATE TABLE outbox_event (
+    id SERIAL PRIMARY KEY,
+    event_id VARCHAR(64) NOT NULL,
+    event_type "OutboxEventType" NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    object_id VARCHAR(64) NOT NULL,
+    payload JSONB NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    published_at TIMESTAMP
+);
+
+CREATE UNIQUE INDEX outbox_event_event_id_index ON outbox_event (event_id);
+
+CREATE INDEX outbox_event_unpublished_index ON outbox_event (id) WHERE published_at IS NULL;
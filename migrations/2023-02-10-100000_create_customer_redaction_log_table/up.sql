@@ -0,0 +1,11 @@
+-- Your SQL goes here
+CREATE TABLE customer_redaction_log (
+    id SERIAL PRIMARY KEY,
+    customer_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    payment_methods_deleted INTEGER NOT NULL,
+    payment_intents_anonymized INTEGER NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX customer_redaction_log_merchant_id_customer_id_index ON customer_redaction_log (merchant_id, customer_id);
@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE export;
+
+DROP TYPE "ExportStatus";
+
+DROP TYPE "ExportFormat";
+
+DROP TYPE "ExportObject";
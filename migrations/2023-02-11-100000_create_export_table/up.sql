@@ -0,0 +1,24 @@
+-- Your SQL goes here
+CREATE TYPE "ExportObject" AS ENUM ('payments', 'refunds', 'disputes');
+
+CREATE TYPE "ExportFormat" AS ENUM ('csv', 'json');
+
+CREATE TYPE "ExportStatus" AS ENUM ('pending', 'succeeded', 'failed');
+
+CREATE TABLE export (
+    id SERIAL PRIMARY KEY,
+    export_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    object "ExportObject" NOT NULL,
+    format "ExportFormat" NOT NULL,
+    start_time TIMESTAMP NOT NULL,
+    end_time TIMESTAMP NOT NULL,
+    status "ExportStatus" NOT NULL,
+    row_count INTEGER,
+    file_content TEXT,
+    error_message VARCHAR(255),
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX export_merchant_id_export_id_index ON export (merchant_id, export_id);
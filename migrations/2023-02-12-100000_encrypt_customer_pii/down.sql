@@ -0,0 +1,17 @@
+-- This file should undo anything in `up.sql`
+-- The encrypted values cannot be decrypted in SQL, and must be cleared before reverting
+ALTER TABLE address
+    ALTER COLUMN line1 TYPE VARCHAR(255) USING convert_from(substring(line1 FROM 2), 'UTF8'),
+    ALTER COLUMN line2 TYPE VARCHAR(255) USING convert_from(substring(line2 FROM 2), 'UTF8'),
+    ALTER COLUMN line3 TYPE VARCHAR(255) USING convert_from(substring(line3 FROM 2), 'UTF8'),
+    ALTER COLUMN state TYPE VARCHAR(128) USING convert_from(substring(state FROM 2), 'UTF8'),
+    ALTER COLUMN zip TYPE VARCHAR(16) USING convert_from(substring(zip FROM 2), 'UTF8'),
+    ALTER COLUMN first_name TYPE VARCHAR(255) USING convert_from(substring(first_name FROM 2), 'UTF8'),
+    ALTER COLUMN last_name TYPE VARCHAR(255) USING convert_from(substring(last_name FROM 2), 'UTF8'),
+    ALTER COLUMN phone_number TYPE VARCHAR(32) USING convert_from(substring(phone_number FROM 2), 'UTF8');
+
+ALTER TABLE customers
+    ALTER COLUMN email TYPE VARCHAR(255) USING convert_from(substring(email FROM 2), 'UTF8'),
+    ALTER COLUMN phone TYPE VARCHAR(32) USING convert_from(substring(phone FROM 2), 'UTF8');
+
+DROP TABLE merchant_key_store;
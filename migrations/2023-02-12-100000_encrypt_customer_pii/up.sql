@@ -0,0 +1,22 @@
+-- Your SQL goes here
+CREATE TABLE merchant_key_store (
+    merchant_id VARCHAR(64) PRIMARY KEY,
+    key BYTEA NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+-- The existing values are kept in plaintext, marked by a leading zero byte, and are encrypted
+-- when they are next written
+ALTER TABLE customers
+    ALTER COLUMN email TYPE BYTEA USING '\x00'::bytea || convert_to(email, 'UTF8'),
+    ALTER COLUMN phone TYPE BYTEA USING '\x00'::bytea || convert_to(phone, 'UTF8');
+
+ALTER TABLE address
+    ALTER COLUMN line1 TYPE BYTEA USING '\x00'::bytea || convert_to(line1, 'UTF8'),
+    ALTER COLUMN line2 TYPE BYTEA USING '\x00'::bytea || convert_to(line2, 'UTF8'),
+    ALTER COLUMN line3 TYPE BYTEA USING '\x00'::bytea || convert_to(line3, 'UTF8'),
+    ALTER COLUMN state TYPE BYTEA USING '\x00'::bytea || convert_to(state, 'UTF8'),
+    ALTER COLUMN zip TYPE BYTEA USING '\x00'::bytea || convert_to(zip, 'UTF8'),
+    ALTER COLUMN first_name TYPE BYTEA USING '\x00'::bytea || convert_to(first_name, 'UTF8'),
+    ALTER COLUMN last_name TYPE BYTEA USING '\x00'::bytea || convert_to(last_name, 'UTF8'),
+    ALTER COLUMN phone_number TYPE BYTEA USING '\x00'::bytea || convert_to(phone_number, 'UTF8');
@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TYPE "ConnectorType" ADD VALUE 'frm_processor';
+ALTER TABLE payment_attempt ADD COLUMN frm_data JSONB;
@@ -0,0 +1,16 @@
+-- Your SQL goes here
+CREATE TYPE "BlocklistKind" AS ENUM ('card_fingerprint', 'email', 'ip_address');
+
+CREATE TABLE blocklist (
+    id SERIAL PRIMARY KEY,
+    entry_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    kind "BlocklistKind" NOT NULL,
+    data VARCHAR(255) NOT NULL,
+    expires_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX blocklist_merchant_id_entry_id_index ON blocklist (merchant_id, entry_id);
+
+CREATE UNIQUE INDEX blocklist_merchant_id_kind_data_index ON blocklist (merchant_id, kind, data);
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_attempt
+DROP COLUMN settlement_currency,
+DROP COLUMN settlement_amount,
+DROP COLUMN fx_rate;
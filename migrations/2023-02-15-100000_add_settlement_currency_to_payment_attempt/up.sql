@@ -0,0 +1,5 @@
+-- Your SQL goes here
+ALTER TABLE payment_attempt
+ADD COLUMN settlement_currency "Currency",
+ADD COLUMN settlement_amount BIGINT,
+ADD COLUMN fx_rate VARCHAR(32);
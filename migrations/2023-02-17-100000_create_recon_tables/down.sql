@@ -0,0 +1,10 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE recon_mismatch;
+
+DROP TABLE recon_report;
+
+DROP TYPE "ReconMismatchReason";
+
+DROP TYPE "ReconEntryType";
+
+DROP TYPE "ReconReportStatus";
@@ -0,0 +1,46 @@
+-- Your SQL goes here
+CREATE TYPE "ReconReportStatus" AS ENUM ('pending', 'completed', 'failed');
+
+CREATE TYPE "ReconEntryType" AS ENUM ('payment', 'refund');
+
+CREATE TYPE "ReconMismatchReason" AS ENUM (
+    'not_found',
+    'amount_mismatch',
+    'currency_mismatch',
+    'status_mismatch'
+);
+
+CREATE TABLE recon_report (
+    id SERIAL PRIMARY KEY,
+    report_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    connector VARCHAR(64) NOT NULL,
+    status "ReconReportStatus" NOT NULL,
+    file_content TEXT NOT NULL,
+    total_entries INTEGER,
+    matched_entries INTEGER,
+    mismatched_entries INTEGER,
+    error_message VARCHAR(255),
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX recon_report_merchant_id_report_id_index ON recon_report (merchant_id, report_id);
+
+CREATE TABLE recon_mismatch (
+    id SERIAL PRIMARY KEY,
+    report_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    entry_type "ReconEntryType" NOT NULL,
+    connector_transaction_id VARCHAR(128) NOT NULL,
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    reason "ReconMismatchReason" NOT NULL,
+    expected_amount BIGINT,
+    expected_currency "Currency",
+    payment_id VARCHAR(64),
+    refund_id VARCHAR(64),
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX recon_mismatch_merchant_id_report_id_index ON recon_mismatch (merchant_id, report_id);
@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE export DROP COLUMN batch_id;
+
+DELETE FROM pg_enum
+WHERE enumlabel = 'refund_batch'
+AND enumtypid = (
+  SELECT oid FROM pg_type WHERE typname = 'ExportObject'
+);
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TYPE "ExportObject" ADD VALUE 'refund_batch';
+
+ALTER TABLE export ADD COLUMN batch_id VARCHAR(64);
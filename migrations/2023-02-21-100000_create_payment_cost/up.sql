@@ -0,0 +1,25 @@
+-- Your SQL goes here
+CREATE TYPE "PaymentCostType" AS ENUM ('dispute_fee', 'dispute_fee_reversal');
+
+CREATE TABLE payment_cost (
+    id SERIAL PRIMARY KEY,
+    cost_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(255) NOT NULL,
+    payment_id VARCHAR(255) NOT NULL,
+    attempt_id VARCHAR(64) NOT NULL,
+    dispute_id VARCHAR(64),
+    connector VARCHAR(255) NOT NULL,
+    cost_type "PaymentCostType" NOT NULL,
+    amount BIGINT NOT NULL,
+    currency VARCHAR(255) NOT NULL,
+    connector_reference VARCHAR(255) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX payment_cost_cost_id_merchant_id_index ON payment_cost (cost_id, merchant_id);
+
+CREATE UNIQUE INDEX payment_cost_merchant_id_connector_reference_index ON payment_cost (merchant_id, connector, connector_reference);
+
+CREATE INDEX payment_cost_merchant_id_payment_id_index ON payment_cost (merchant_id, payment_id);
+
+CREATE INDEX payment_cost_merchant_id_created_at_index ON payment_cost (merchant_id, created_at);
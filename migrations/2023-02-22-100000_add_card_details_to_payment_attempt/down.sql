@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_attempt DROP COLUMN card_bin;
+
+ALTER TABLE payment_attempt DROP COLUMN card_last4;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE payment_attempt ADD COLUMN card_bin VARCHAR(8);
+
+ALTER TABLE payment_attempt ADD COLUMN card_last4 VARCHAR(4);
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE export ADD COLUMN anonymized BOOLEAN NOT NULL DEFAULT FALSE;
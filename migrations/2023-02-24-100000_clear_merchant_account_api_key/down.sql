@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+-- Only the hashes of the cleared API keys are stored, so they cannot be restored
+DO $$
+BEGIN
+    RAISE EXCEPTION 'The plaintext API keys of merchant accounts cannot be restored';
+END $$;
@@ -0,0 +1,3 @@
+-- Your SQL goes here
+-- The API keys of merchant accounts are authenticated with their hashes in the api_keys table
+UPDATE merchant_account SET api_key = NULL;
@@ -0,0 +1,12 @@
+-- Your SQL goes here
+ALTER TABLE payment_intent ADD COLUMN active_attempt_id VARCHAR(64);
+
+UPDATE payment_intent
+SET active_attempt_id = (
+    SELECT attempt_id
+    FROM payment_attempt
+    WHERE payment_attempt.merchant_id = payment_intent.merchant_id
+        AND payment_attempt.payment_id = payment_intent.payment_id
+    ORDER BY payment_attempt.created_at DESC, payment_attempt.id DESC
+    LIMIT 1
+);